@@ -0,0 +1,88 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+};
+
+/// Benchmark for styling many overlapping areas of a buffer, one after the other with
+/// `Buffer::set_style` or all at once with `Buffer::set_styles`.
+pub fn buffer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("buffer");
+
+    for buffer_size in &[
+        Rect::new(0, 0, 100, 50),  // vertically split screen
+        Rect::new(0, 0, 200, 50),  // 1080p fullscreen with medium font
+        Rect::new(0, 0, 256, 256), // Max sized area
+    ] {
+        let buffer_area = buffer_size.area();
+        for (name, styles) in [
+            ("table", table_styles(*buffer_size)),
+            ("nested", nested_styles(*buffer_size)),
+        ] {
+            group.bench_with_input(
+                BenchmarkId::new(format!("set_style/{name}"), buffer_area),
+                &styles,
+                |b, styles| {
+                    let mut buffer = Buffer::empty(*buffer_size);
+                    b.iter(|| {
+                        for &(area, style) in styles {
+                            buffer.set_style(area, style);
+                        }
+                    });
+                },
+            );
+            group.bench_with_input(
+                BenchmarkId::new(format!("set_styles/{name}"), buffer_area),
+                &styles,
+                |b, styles| {
+                    let mut buffer = Buffer::empty(*buffer_size);
+                    b.iter(|| buffer.set_styles(styles));
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+/// Returns the styles of a table: the whole area, every other row, a few columns and a selected
+/// row.
+fn table_styles(area: Rect) -> Vec<(Rect, Style)> {
+    let mut styles = vec![(area, Style::new().fg(Color::White).bg(Color::Black))];
+    for y in (area.top()..area.bottom()).step_by(2) {
+        styles.push((
+            Rect::new(area.x, y, area.width, 1),
+            Style::new().bg(Color::DarkGray),
+        ));
+    }
+    for x in (area.left()..area.right()).step_by(20) {
+        styles.push((
+            Rect::new(x, area.y, 10, area.height).intersection(area),
+            Style::new().fg(Color::Cyan),
+        ));
+    }
+    styles.push((
+        Rect::new(area.x, area.y + area.height / 2, area.width, 1),
+        Style::new().add_modifier(Modifier::REVERSED),
+    ));
+    styles
+}
+
+/// Returns the styles of nested containers, each one inside the previous one.
+fn nested_styles(area: Rect) -> Vec<(Rect, Style)> {
+    (0..20)
+        .map(|i| {
+            let inner = Rect {
+                x: area.x + i,
+                y: area.y + i / 2,
+                width: area.width.saturating_sub(2 * i),
+                height: area.height.saturating_sub(i),
+            };
+            (inner, Style::new().bg(Color::Indexed(i as u8)))
+        })
+        .collect()
+}
+
+criterion_group!(benches, buffer);
+criterion_main!(benches);
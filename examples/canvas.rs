@@ -188,7 +188,7 @@ impl App {
 fn init_terminal() -> io::Result<Terminal<CrosstermBackend<Stdout>>> {
     enable_raw_mode()?;
     stdout().execute(EnterAlternateScreen)?;
-    Terminal::new(CrosstermBackend::new(stdout()))
+    Ok(Terminal::new(CrosstermBackend::new(stdout()))?)
 }
 
 fn restore_terminal() -> io::Result<()> {
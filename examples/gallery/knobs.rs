@@ -0,0 +1,163 @@
+use ratatui::{
+    input::{Key, KeyEvent},
+    prelude::*,
+    widgets::*,
+};
+
+/// A builder option of the previewed widget, cycling through a fixed set of values.
+#[derive(Debug, Clone)]
+pub struct Knob {
+    name: &'static str,
+    options: &'static [&'static str],
+    selected: usize,
+}
+
+impl Knob {
+    /// Creates a knob choosing one of the given options, the first one by default.
+    pub fn new(name: &'static str, options: &'static [&'static str]) -> Self {
+        Self {
+            name,
+            options,
+            selected: 0,
+        }
+    }
+
+    /// Creates a knob switching an option off and on, off by default.
+    pub fn toggle(name: &'static str) -> Self {
+        Self::new(name, &["off", "on"])
+    }
+
+    /// Selects the given option by default.
+    pub fn with_selected(mut self, selected: usize) -> Self {
+        self.selected = selected.min(self.options.len() - 1);
+        self
+    }
+
+    fn next(&mut self) {
+        self.selected = (self.selected + 1) % self.options.len();
+    }
+
+    fn previous(&mut self) {
+        self.selected = (self.selected + self.options.len() - 1) % self.options.len();
+    }
+}
+
+/// The knobs of a page, with the one being adjusted.
+#[derive(Debug, Clone, Default)]
+pub struct Knobs {
+    knobs: Vec<Knob>,
+    focused: usize,
+}
+
+impl Knobs {
+    pub fn new(knobs: Vec<Knob>) -> Self {
+        Self { knobs, focused: 0 }
+    }
+
+    fn get(&self, name: &str) -> &Knob {
+        self.knobs
+            .iter()
+            .find(|knob| knob.name == name)
+            .unwrap_or_else(|| panic!("no knob named {name}"))
+    }
+
+    /// Returns the index of the selected option of a knob.
+    pub fn index(&self, name: &str) -> usize {
+        self.get(name).selected
+    }
+
+    /// Returns the selected option of a knob.
+    pub fn value(&self, name: &str) -> &'static str {
+        let knob = self.get(name);
+        knob.options[knob.selected]
+    }
+
+    /// Returns `true` if a toggle knob is on.
+    pub fn is_on(&self, name: &str) -> bool {
+        self.value(name) == "on"
+    }
+
+    /// Returns the alignment selected by an `alignment` knob, whose options are `left`, `center`
+    /// and `right`.
+    pub fn alignment(&self) -> Alignment {
+        match self.value("alignment") {
+            "center" => Alignment::Center,
+            "right" => Alignment::Right,
+            _ => Alignment::Left,
+        }
+    }
+
+    /// Returns the block selected by a `block` knob (see [`block_knob`]), with the given title.
+    pub fn block<'a>(&self, title: &'a str) -> Option<Block<'a>> {
+        let border_type = match self.value("block") {
+            "plain" => BorderType::Plain,
+            "rounded" => BorderType::Rounded,
+            "double" => BorderType::Double,
+            "thick" => BorderType::Thick,
+            _ => return None,
+        };
+        Some(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(border_type)
+                .title(title),
+        )
+    }
+
+    /// Moves the focus between the knobs with `Up` and `Down`, and changes the value of the
+    /// focused knob with `Left` and `Right`. Returns `true` if the key was handled.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let count = self.knobs.len();
+        if count == 0 {
+            return false;
+        }
+        match key.key {
+            Key::Up => self.focused = (self.focused + count - 1) % count,
+            Key::Down => self.focused = (self.focused + 1) % count,
+            Key::Left => self.knobs[self.focused].previous(),
+            Key::Right => self.knobs[self.focused].next(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Returns the number of rows taken by the knobs, below a title.
+    pub fn height(&self) -> u16 {
+        self.knobs.len() as u16 + 1
+    }
+
+    /// Renders the knobs as a list of options, highlighting the focused knob.
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        let items: Vec<ListItem> = self
+            .knobs
+            .iter()
+            .map(|knob| {
+                let mut spans = vec![format!("{:<12}", knob.name).bold()];
+                for (i, option) in knob.options.iter().enumerate() {
+                    let option = format!(" {option} ");
+                    spans.push(if i == knob.selected {
+                        option.reversed()
+                    } else {
+                        option.dim()
+                    });
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let mut state = ListState::default().with_selected(Some(self.focused));
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::TOP).title("Knobs"))
+            .highlight_symbol("> ");
+        StatefulWidget::render(list, area, buf, &mut state);
+    }
+}
+
+/// A knob choosing whether the widget is surrounded by a block, and its border type.
+pub fn block_knob() -> Knob {
+    Knob::new("block", &["none", "plain", "rounded", "double", "thick"])
+}
+
+/// A knob choosing an alignment.
+pub fn alignment_knob() -> Knob {
+    Knob::new("alignment", &["left", "center", "right"])
+}
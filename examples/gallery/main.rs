@@ -0,0 +1,186 @@
+//! A gallery of the built-in widgets, each rendered with knobs changing its builder options.
+//!
+//! It serves as a living reference of the widgets, and as a manual regression test when
+//! changing how they are rendered.
+//!
+//! Run it with:
+//!
+//! ```shell
+//! cargo run --example=gallery --features=crossterm,gallery,all-widgets
+//! ```
+//!
+//! - `Tab` / `BackTab` show the next and previous widgets.
+//! - `Up` / `Down` move between the knobs, `Left` / `Right` change the value of a knob.
+//! - `Enter` forwards the keys and the mouse events to the widget, until `Esc` is pressed.
+//! - `q` quits.
+
+use std::{error::Error, io, time::Duration};
+
+use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    input::{self, Event, Key, KeyEventKind},
+    prelude::*,
+    widgets::*,
+};
+
+mod knobs;
+mod pages;
+
+use pages::Page;
+
+struct App {
+    pages: Vec<Page>,
+    selected: usize,
+    /// Whether the events are forwarded to the previewed widget
+    interacting: bool,
+}
+
+impl App {
+    fn new() -> Self {
+        Self {
+            pages: pages::all(),
+            selected: 0,
+            interacting: false,
+        }
+    }
+
+    /// Handles an event and returns `false` when the gallery should quit.
+    fn handle_event(&mut self, event: &Event) -> bool {
+        let page = &mut self.pages[self.selected];
+        if self.interacting {
+            match event {
+                Event::Key(key) if key.key == Key::Esc && key.kind == KeyEventKind::Press => {
+                    self.interacting = false;
+                }
+                _ => {
+                    page.preview.handle_event(event);
+                }
+            }
+            return true;
+        }
+        let Event::Key(key) = event else {
+            return true;
+        };
+        if key.kind == KeyEventKind::Release {
+            return true;
+        }
+        match key.key {
+            Key::Char('q') | Key::Esc => return false,
+            Key::Tab | Key::PageDown => self.selected = (self.selected + 1) % self.pages.len(),
+            Key::BackTab | Key::PageUp => {
+                self.selected = (self.selected + self.pages.len() - 1) % self.pages.len();
+            }
+            Key::Enter => self.interacting = page.preview.is_interactive(),
+            _ => {
+                page.knobs.handle_key(*key);
+            }
+        }
+        true
+    }
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    // setup terminal
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    // create app and run it
+    let res = run_app(&mut terminal, App::new());
+
+    // restore terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    if let Err(err) = res {
+        println!("{err:?}");
+    }
+
+    Ok(())
+}
+
+fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<()> {
+    loop {
+        terminal.draw(|f| ui(f, &mut app))?;
+
+        // redraw regularly for the animated widgets, e.g. the clock
+        if input::poll(Duration::from_millis(100))? && !app.handle_event(&input::read()?) {
+            return Ok(());
+        }
+    }
+}
+
+fn ui(f: &mut Frame, app: &mut App) {
+    let [sidebar, main] = *Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(24), Constraint::Min(0)])
+        .split(f.size())
+    else {
+        return;
+    };
+
+    let names: Vec<ListItem> = app
+        .pages
+        .iter()
+        .map(|page| ListItem::new(page.name))
+        .collect();
+    let mut state = ListState::default().with_selected(Some(app.selected));
+    let list = List::new(names)
+        .block(Block::default().borders(Borders::RIGHT).title("Widgets"))
+        .highlight_style(Style::new().reversed());
+    f.render_stateful_widget(list, sidebar, &mut state);
+
+    let page = &mut app.pages[app.selected];
+    let [header, preview, knobs, help] = *Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Min(0),
+            Constraint::Length(page.knobs.height()),
+            Constraint::Length(1),
+        ])
+        .split(main)
+    else {
+        return;
+    };
+
+    let title = Line::from(vec![
+        page.name.bold(),
+        " - ".into(),
+        page.description.into(),
+    ]);
+    f.render_widget(Paragraph::new(title), header);
+    let preview = preview.inner(&Margin::new(1, 0));
+    page.render(app.interacting, preview, f.buffer_mut());
+    page.knobs.render(knobs, f.buffer_mut());
+
+    let help_line = if app.interacting {
+        Line::from(vec!["Esc".bold(), " stop interacting".into()])
+    } else {
+        let mut help = vec![
+            "Tab".bold(),
+            " next widget  ".into(),
+            "↑↓".bold(),
+            " knob  ".into(),
+            "←→".bold(),
+            " value  ".into(),
+        ];
+        if page.preview.is_interactive() {
+            help.extend(["Enter".bold(), " interact  ".into()]);
+        }
+        help.extend(["q".bold(), " quit".into()]);
+        Line::from(help)
+    };
+    f.render_widget(Paragraph::new(help_line).dim(), help);
+}
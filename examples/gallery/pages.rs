@@ -0,0 +1,89 @@
+use ratatui::{input::Event, prelude::*};
+
+use crate::knobs::{Knob, Knobs};
+
+mod display;
+mod inputs;
+
+/// The smallest area in which the widgets are previewed.
+const MIN_PREVIEW_WIDTH: u16 = 32;
+const MIN_PREVIEW_HEIGHT: u16 = 10;
+
+/// Renders a widget with the options selected by the knobs of its page.
+pub trait Preview {
+    /// Renders the widget. `focused` is `true` while the events are forwarded to the widget.
+    fn render(&mut self, knobs: &Knobs, focused: bool, area: Rect, buf: &mut Buffer);
+
+    /// Handles an event forwarded to the widget. Returns `true` if it was handled.
+    fn handle_event(&mut self, _event: &Event) -> bool {
+        false
+    }
+
+    /// Returns `true` if the widget handles events, so that they can be forwarded to it.
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
+
+/// The widgets without any state are previewed with a closure.
+impl<F> Preview for F
+where
+    F: FnMut(&Knobs, Rect, &mut Buffer),
+{
+    fn render(&mut self, knobs: &Knobs, _focused: bool, area: Rect, buf: &mut Buffer) {
+        self(knobs, area, buf);
+    }
+}
+
+/// A page of the gallery: a widget, its knobs and a short description.
+pub struct Page {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub knobs: Knobs,
+    pub preview: Box<dyn Preview>,
+}
+
+impl Page {
+    pub fn new<P>(
+        name: &'static str,
+        description: &'static str,
+        knobs: Vec<Knob>,
+        preview: P,
+    ) -> Self
+    where
+        P: Preview + 'static,
+    {
+        Self {
+            name,
+            description,
+            knobs: Knobs::new(knobs),
+            preview: Box::new(preview),
+        }
+    }
+
+    pub fn render(&mut self, focused: bool, area: Rect, buf: &mut Buffer) {
+        // some widgets panic when rendered in a very small area, e.g. the calendar
+        if area.width < MIN_PREVIEW_WIDTH || area.height < MIN_PREVIEW_HEIGHT {
+            if !area.is_empty() {
+                let message = "The terminal is too small to preview the widget";
+                buf.set_stringn(
+                    area.x,
+                    area.y,
+                    message,
+                    area.width as usize,
+                    Style::new().dim(),
+                );
+            }
+            return;
+        }
+        self.preview.render(&self.knobs, focused, area, buf);
+    }
+}
+
+/// Returns the pages of all the built-in widgets, sorted by name.
+pub fn all() -> Vec<Page> {
+    let mut pages = display::pages();
+    pages.extend(inputs::pages());
+    pages.sort_by_key(|page| page.name);
+    pages
+}
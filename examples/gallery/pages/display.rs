@@ -0,0 +1,987 @@
+//! The pages of the widgets displaying data.
+
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    input::{Event, Key, KeyEventKind},
+    prelude::*,
+    widgets::{
+        canvas::{Canvas, Circle, Line as CanvasLine, Rectangle},
+        decorator::{ScrollableState, TooltipPopup, TooltipState},
+        *,
+    },
+};
+
+use super::{Page, Preview};
+use crate::knobs::{alignment_knob, block_knob, Knob, Knobs};
+
+const LOREM: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod \
+                     tempor incididunt ut labore et dolore magna aliqua.\n\
+                     Ut enim ad minim veniam, quis nostrud exercitation ullamco laboris nisi ut \
+                     aliquip ex ea commodo consequat.";
+
+const FRUITS: [&str; 8] = [
+    "Apple",
+    "Banana",
+    "Cherry",
+    "Date",
+    "Elderberry",
+    "Fig",
+    "Grape",
+    "Honeydew",
+];
+
+pub fn pages() -> Vec<Page> {
+    #[allow(unused_mut)] // only extended with the optional widgets
+    let mut pages = vec![
+        block(),
+        paragraph(),
+        list(),
+        table(),
+        tabs(),
+        gauge(),
+        line_gauge(),
+        sparkline(),
+        bar_chart(),
+        chart(),
+        canvas(),
+        scrollbar(),
+        clear(),
+        clock(),
+        decorator(),
+        property_list(),
+        task_list(),
+        week_view(),
+        graph_view(),
+        tree_map(),
+    ];
+    #[cfg(feature = "widget-calendar")]
+    pages.push(calendar());
+    #[cfg(feature = "widget-json")]
+    pages.push(json_view());
+    pages
+}
+
+/// Returns the key of a key press event.
+fn pressed(event: &Event) -> Option<Key> {
+    match event {
+        Event::Key(key) if key.kind != KeyEventKind::Release => Some(key.key),
+        _ => None,
+    }
+}
+
+/// Moves a selection among `len` items with `Up` and `Down`.
+fn select(selected: Option<usize>, len: usize, key: Key) -> Option<Option<usize>> {
+    let selected = selected.unwrap_or(0);
+    match key {
+        Key::Up => Some(Some(selected.saturating_sub(1))),
+        Key::Down => Some(Some((selected + 1).min(len - 1))),
+        _ => None,
+    }
+}
+
+fn block() -> Page {
+    Page::new(
+        "Block",
+        "Borders, titles and padding around other widgets",
+        vec![
+            Knob::new("borders", &["all", "none", "top+bottom", "left+right"]),
+            Knob::new("border type", &["plain", "rounded", "double", "thick"]),
+            alignment_knob(),
+            Knob::new("padding", &["0", "1", "2"]),
+        ],
+        |knobs: &Knobs, area: Rect, buf: &mut Buffer| {
+            let borders = match knobs.value("borders") {
+                "none" => Borders::NONE,
+                "top+bottom" => Borders::TOP | Borders::BOTTOM,
+                "left+right" => Borders::LEFT | Borders::RIGHT,
+                _ => Borders::ALL,
+            };
+            let border_type = match knobs.value("border type") {
+                "rounded" => BorderType::Rounded,
+                "double" => BorderType::Double,
+                "thick" => BorderType::Thick,
+                _ => BorderType::Plain,
+            };
+            let padding = knobs.index("padding") as u16;
+            let block = Block::default()
+                .borders(borders)
+                .border_type(border_type)
+                .title("Title")
+                .title_alignment(knobs.alignment())
+                .padding(Padding::uniform(padding));
+            let inner = block.inner(area);
+            block.render(area, buf);
+            Paragraph::new("Content").render(inner, buf);
+        },
+    )
+}
+
+fn paragraph() -> Page {
+    Page::new(
+        "Paragraph",
+        "Styled text, optionally wrapped and scrolled",
+        vec![
+            Knob::new("wrap", &["off", "trim", "keep indent"]).with_selected(1),
+            alignment_knob(),
+            Knob::new("scroll", &["0", "1", "2", "3"]),
+            block_knob(),
+        ],
+        |knobs: &Knobs, area: Rect, buf: &mut Buffer| {
+            let mut paragraph = Paragraph::new(LOREM)
+                .alignment(knobs.alignment())
+                .scroll((knobs.index("scroll") as u16, 0));
+            match knobs.value("wrap") {
+                "trim" => paragraph = paragraph.wrap(Wrap { trim: true }),
+                "keep indent" => paragraph = paragraph.wrap(Wrap { trim: false }),
+                _ => {}
+            }
+            if let Some(block) = knobs.block("Paragraph") {
+                paragraph = paragraph.block(block);
+            }
+            paragraph.render(area, buf);
+        },
+    )
+}
+
+#[derive(Default)]
+struct ListPreview {
+    state: ListState,
+}
+
+impl Preview for ListPreview {
+    fn render(&mut self, knobs: &Knobs, _focused: bool, area: Rect, buf: &mut Buffer) {
+        let direction = match knobs.value("direction") {
+            "bottom to top" => ListDirection::BottomToTop,
+            _ => ListDirection::TopToBottom,
+        };
+        let mut list = List::new(FRUITS)
+            .direction(direction)
+            .highlight_symbol(knobs.value("symbol"))
+            .highlight_style(Style::new().reversed());
+        if let Some(block) = knobs.block("List") {
+            list = list.block(block);
+        }
+        StatefulWidget::render(list, area, buf, &mut self.state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        let Some(key) = pressed(event) else {
+            return false;
+        };
+        match select(self.state.selected(), FRUITS.len(), key) {
+            Some(selected) => {
+                self.state.select(selected);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn list() -> Page {
+    Page::new(
+        "List",
+        "A list of items, with a selection moved by Up and Down",
+        vec![
+            Knob::new("direction", &["top to bottom", "bottom to top"]),
+            Knob::new("symbol", &["", ">> ", "• "]).with_selected(1),
+            block_knob(),
+        ],
+        ListPreview::default(),
+    )
+}
+
+#[derive(Default)]
+struct TablePreview {
+    state: TableState,
+    tooltip: TooltipState,
+}
+
+impl Preview for TablePreview {
+    fn render(&mut self, knobs: &Knobs, _focused: bool, area: Rect, buf: &mut Buffer) {
+        let rows = FRUITS.iter().enumerate().map(|(i, fruit)| {
+            Row::new(vec![
+                (i + 1).to_string(),
+                fruit.to_string(),
+                format!("{:.2}", (i as f64 + 1.0) * 0.75),
+            ])
+        });
+        let spacing = match knobs.value("column spacing") {
+            "0" => 0,
+            "3" => 3,
+            _ => 1,
+        };
+        let highlight_spacing = match knobs.value("highlight spacing") {
+            "always" => HighlightSpacing::Always,
+            "never" => HighlightSpacing::Never,
+            _ => HighlightSpacing::WhenSelected,
+        };
+        let fruit_width = knobs.value("fruit width").parse().unwrap_or(10);
+        let mut table = Table::new(
+            rows,
+            [
+                Constraint::Length(3),
+                Constraint::Min(fruit_width),
+                Constraint::Length(6),
+            ],
+        )
+        .column_spacing(spacing)
+        .highlight_spacing(highlight_spacing)
+        .highlight_symbol("> ")
+        .highlight_style(Style::new().reversed())
+        .ellipsis(knobs.is_on("ellipsis"));
+        if knobs.is_on("header") {
+            table = table.header(Row::new(vec!["#", "Fruit", "Price"]).bold());
+        }
+        if let Some(block) = knobs.block("Table") {
+            table = table.block(block);
+        }
+        StatefulWidget::render(table, area, buf, &mut self.state);
+        self.tooltip.tick(Instant::now());
+        self.state.register_truncated(&mut self.tooltip);
+        StatefulWidget::render(TooltipPopup::new(), area, buf, &mut self.tooltip);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        if let Event::Mouse(mouse) = event {
+            return self.tooltip.handle_mouse(*mouse);
+        }
+        let Some(key) = pressed(event) else {
+            return false;
+        };
+        if key == Key::Enter {
+            return self.state.expand_selected(&mut self.tooltip);
+        }
+        self.tooltip.hide();
+        match select(self.state.selected(), FRUITS.len(), key) {
+            Some(selected) => {
+                self.state.select(selected);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn table() -> Page {
+    Page::new(
+        "Table",
+        "Rows and columns, with a selection moved by Up and Down, and Enter expanding its elided cells",
+        vec![
+            Knob::toggle("header").with_selected(1),
+            Knob::new("column spacing", &["0", "1", "3"]).with_selected(1),
+            Knob::toggle("ellipsis").with_selected(1),
+            Knob::new("fruit width", &["10", "5"]),
+            Knob::new("highlight spacing", &["when selected", "always", "never"]),
+            block_knob(),
+        ],
+        TablePreview::default(),
+    )
+}
+
+fn tabs() -> Page {
+    Page::new(
+        "Tabs",
+        "A tab bar with the selected tab highlighted",
+        vec![
+            Knob::new("selected", &["0", "1", "2", "3"]),
+            Knob::new("divider", &["|", "•", "/"]),
+            block_knob(),
+        ],
+        |knobs: &Knobs, area: Rect, buf: &mut Buffer| {
+            let mut tabs = Tabs::new(vec!["Home", "Files", "Search", "Settings"])
+                .select(knobs.index("selected"))
+                .divider(knobs.value("divider"))
+                .highlight_style(Style::new().yellow().bold());
+            if let Some(block) = knobs.block("Tabs") {
+                tabs = tabs.block(block);
+            }
+            tabs.render(area, buf);
+        },
+    )
+}
+
+/// A knob choosing the ratio of a gauge.
+fn ratio_knob() -> Knob {
+    Knob::new("ratio", &["0", "0.25", "0.5", "0.75", "1"]).with_selected(2)
+}
+
+fn gauge() -> Page {
+    Page::new(
+        "Gauge",
+        "Progress drawn with block characters",
+        vec![
+            ratio_knob(),
+            Knob::toggle("unicode"),
+            Knob::toggle("label").with_selected(1),
+            block_knob(),
+        ],
+        |knobs: &Knobs, area: Rect, buf: &mut Buffer| {
+            let ratio = knobs.value("ratio").parse().unwrap_or_default();
+            let mut gauge = Gauge::default()
+                .ratio(ratio)
+                .use_unicode(knobs.is_on("unicode"))
+                .gauge_style(Style::new().cyan());
+            if !knobs.is_on("label") {
+                gauge = gauge.label("");
+            }
+            if let Some(block) = knobs.block("Gauge") {
+                gauge = gauge.block(block);
+            }
+            gauge.render(area, buf);
+        },
+    )
+}
+
+fn line_gauge() -> Page {
+    Page::new(
+        "LineGauge",
+        "Progress drawn as a line",
+        vec![
+            ratio_knob(),
+            Knob::new("line set", &["normal", "thick", "double"]),
+            block_knob(),
+        ],
+        |knobs: &Knobs, area: Rect, buf: &mut Buffer| {
+            let line_set = match knobs.value("line set") {
+                "thick" => symbols::line::THICK,
+                "double" => symbols::line::DOUBLE,
+                _ => symbols::line::NORMAL,
+            };
+            let mut gauge = LineGauge::default()
+                .ratio(knobs.value("ratio").parse().unwrap_or_default())
+                .line_set(line_set)
+                .gauge_style(Style::new().cyan());
+            if let Some(block) = knobs.block("LineGauge") {
+                gauge = gauge.block(block);
+            }
+            gauge.render(area, buf);
+        },
+    )
+}
+
+const SAMPLES: [u64; 24] = [
+    3, 5, 8, 13, 9, 4, 2, 6, 11, 15, 12, 7, 5, 3, 8, 14, 10, 6, 4, 9, 13, 11, 7, 2,
+];
+
+fn sparkline() -> Page {
+    Page::new(
+        "Sparkline",
+        "A data set drawn as a sparkline",
+        vec![
+            Knob::new("direction", &["left to right", "right to left"]),
+            Knob::new("max", &["auto", "30"]),
+            block_knob(),
+        ],
+        |knobs: &Knobs, area: Rect, buf: &mut Buffer| {
+            let direction = match knobs.value("direction") {
+                "right to left" => RenderDirection::RightToLeft,
+                _ => RenderDirection::LeftToRight,
+            };
+            let mut sparkline = Sparkline::default()
+                .data(&SAMPLES)
+                .direction(direction)
+                .style(Style::new().green());
+            if knobs.value("max") == "30" {
+                sparkline = sparkline.max(30);
+            }
+            if let Some(block) = knobs.block("Sparkline") {
+                sparkline = sparkline.block(block);
+            }
+            sparkline.render(area, buf);
+        },
+    )
+}
+
+fn bar_chart() -> Page {
+    Page::new(
+        "BarChart",
+        "Bars with labels and values",
+        vec![
+            Knob::new("direction", &["vertical", "horizontal"]),
+            Knob::new("bar width", &["1", "3", "5"]).with_selected(1),
+            Knob::new("bar gap", &["0", "1", "2"]).with_selected(1),
+            block_knob(),
+        ],
+        |knobs: &Knobs, area: Rect, buf: &mut Buffer| {
+            let direction = match knobs.value("direction") {
+                "horizontal" => Direction::Horizontal,
+                _ => Direction::Vertical,
+            };
+            let data = [("Mon", 4), ("Tue", 7), ("Wed", 3), ("Thu", 9), ("Fri", 6)];
+            let mut chart = BarChart::default()
+                .data(&data)
+                .direction(direction)
+                .bar_width(knobs.value("bar width").parse().unwrap_or(1))
+                .bar_gap(knobs.index("bar gap") as u16)
+                .bar_style(Style::new().magenta());
+            if let Some(block) = knobs.block("BarChart") {
+                chart = chart.block(block);
+            }
+            chart.render(area, buf);
+        },
+    )
+}
+
+/// A knob choosing the marker of a chart or a canvas.
+fn marker_knob() -> Knob {
+    Knob::new("marker", &["braille", "dot", "block", "bar", "half block"])
+}
+
+fn marker(knobs: &Knobs) -> symbols::Marker {
+    match knobs.value("marker") {
+        "dot" => symbols::Marker::Dot,
+        "block" => symbols::Marker::Block,
+        "bar" => symbols::Marker::Bar,
+        "half block" => symbols::Marker::HalfBlock,
+        _ => symbols::Marker::Braille,
+    }
+}
+
+fn chart() -> Page {
+    Page::new(
+        "Chart",
+        "Data sets drawn as lines or scatter plots, with axes and a legend",
+        vec![
+            Knob::new("graph type", &["line", "scatter"]),
+            marker_knob(),
+            Knob::new("legend", &["top right", "bottom left", "hidden"]),
+            block_knob(),
+        ],
+        |knobs: &Knobs, area: Rect, buf: &mut Buffer| {
+            let graph_type = match knobs.value("graph type") {
+                "scatter" => GraphType::Scatter,
+                _ => GraphType::Line,
+            };
+            let sine: Vec<(f64, f64)> = (0..=40)
+                .map(|x| {
+                    let x = f64::from(x) / 4.0;
+                    (x, x.sin())
+                })
+                .collect();
+            let cosine: Vec<(f64, f64)> = sine.iter().map(|&(x, _)| (x, x.cos())).collect();
+            let datasets = vec![
+                Dataset::default()
+                    .name("sin")
+                    .data(&sine)
+                    .marker(marker(knobs))
+                    .graph_type(graph_type)
+                    .style(Style::new().cyan()),
+                Dataset::default()
+                    .name("cos")
+                    .data(&cosine)
+                    .marker(marker(knobs))
+                    .graph_type(graph_type)
+                    .style(Style::new().yellow()),
+            ];
+            let legend_position = match knobs.value("legend") {
+                "bottom left" => Some(LegendPosition::BottomLeft),
+                "hidden" => None,
+                _ => Some(LegendPosition::TopRight),
+            };
+            let mut chart = Chart::new(datasets)
+                .x_axis(Axis::default().bounds([0.0, 10.0]).labels(vec![
+                    "0".into(),
+                    "5".into(),
+                    "10".into(),
+                ]))
+                .y_axis(Axis::default().bounds([-1.0, 1.0]).labels(vec![
+                    "-1".into(),
+                    "0".into(),
+                    "1".into(),
+                ]))
+                .legend_position(legend_position);
+            if let Some(block) = knobs.block("Chart") {
+                chart = chart.block(block);
+            }
+            chart.render(area, buf);
+        },
+    )
+}
+
+fn canvas() -> Page {
+    Page::new(
+        "Canvas",
+        "Arbitrary shapes drawn with a marker",
+        vec![marker_knob(), block_knob()],
+        |knobs: &Knobs, area: Rect, buf: &mut Buffer| {
+            let mut canvas = Canvas::default()
+                .marker(marker(knobs))
+                .x_bounds([0.0, 100.0])
+                .y_bounds([0.0, 50.0])
+                .paint(|ctx| {
+                    ctx.draw(&Rectangle {
+                        x: 10.0,
+                        y: 10.0,
+                        width: 30.0,
+                        height: 30.0,
+                        color: Color::Yellow,
+                    });
+                    ctx.draw(&Circle {
+                        x: 70.0,
+                        y: 25.0,
+                        radius: 15.0,
+                        color: Color::Cyan,
+                    });
+                    ctx.draw(&CanvasLine {
+                        x1: 0.0,
+                        y1: 0.0,
+                        x2: 100.0,
+                        y2: 50.0,
+                        color: Color::Magenta,
+                    });
+                });
+            if let Some(block) = knobs.block("Canvas") {
+                canvas = canvas.block(block);
+            }
+            canvas.render(area, buf);
+        },
+    )
+}
+
+fn scrollbar() -> Page {
+    Page::new(
+        "Scrollbar",
+        "A scrollbar showing the position in some content",
+        vec![
+            Knob::new(
+                "orientation",
+                &[
+                    "vertical right",
+                    "vertical left",
+                    "horizontal bottom",
+                    "horizontal top",
+                ],
+            ),
+            Knob::new("position", &["0", "25", "50", "75", "99"]).with_selected(2),
+        ],
+        |knobs: &Knobs, area: Rect, buf: &mut Buffer| {
+            let orientation = match knobs.value("orientation") {
+                "vertical left" => ScrollbarOrientation::VerticalLeft,
+                "horizontal bottom" => ScrollbarOrientation::HorizontalBottom,
+                "horizontal top" => ScrollbarOrientation::HorizontalTop,
+                _ => ScrollbarOrientation::VerticalRight,
+            };
+            let mut state = ScrollbarState::new(100)
+                .position(knobs.value("position").parse().unwrap_or_default());
+            StatefulWidget::render(Scrollbar::new(orientation), area, buf, &mut state);
+        },
+    )
+}
+
+fn clear() -> Page {
+    Page::new(
+        "Clear",
+        "Clears its area, e.g. below a popup",
+        vec![Knob::toggle("clear").with_selected(1)],
+        |knobs: &Knobs, area: Rect, buf: &mut Buffer| {
+            Paragraph::new(LOREM.repeat(4))
+                .wrap(Wrap { trim: true })
+                .render(area, buf);
+            let popup = Rect::new(
+                area.x + area.width / 4,
+                area.y + area.height / 4,
+                area.width / 2,
+                area.height / 2,
+            );
+            if knobs.is_on("clear") {
+                Clear.render(popup, buf);
+            }
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Popup")
+                .render(popup, buf);
+        },
+    )
+}
+
+struct ClockPreview {
+    start: Instant,
+}
+
+impl Preview for ClockPreview {
+    fn render(&mut self, knobs: &Knobs, _focused: bool, area: Rect, buf: &mut Buffer) {
+        let mut clock = Clock::new(self.start.elapsed())
+            .big(knobs.is_on("big"))
+            .blink_separator(knobs.is_on("blink"))
+            .alignment(knobs.alignment());
+        if knobs.is_on("countdown") {
+            clock = clock.countdown(Duration::from_secs(5 * 60));
+        }
+        if let Some(block) = knobs.block("Clock") {
+            clock = clock.block(block);
+        }
+        clock.render(area, buf);
+    }
+}
+
+fn clock() -> Page {
+    Page::new(
+        "Clock",
+        "An elapsed or remaining duration",
+        vec![
+            Knob::toggle("big"),
+            Knob::toggle("countdown"),
+            Knob::toggle("blink"),
+            alignment_knob(),
+            block_knob(),
+        ],
+        ClockPreview {
+            start: Instant::now(),
+        },
+    )
+}
+
+#[derive(Default)]
+struct DecoratorPreview {
+    scroll: ScrollableState,
+    tooltip: TooltipState,
+}
+
+impl Preview for DecoratorPreview {
+    fn render(&mut self, knobs: &Knobs, _focused: bool, area: Rect, buf: &mut Buffer) {
+        let text: Vec<Line> = (1..=30).map(|i| Line::from(format!("Line {i}"))).collect();
+        self.scroll.set_content_height(text.len());
+        let paragraph = Paragraph::new(text);
+        match (knobs.is_on("bordered"), knobs.is_on("titled")) {
+            (true, true) => paragraph
+                .scrollable(&mut self.scroll)
+                .bordered()
+                .titled("Decorated")
+                .render(area, buf),
+            (true, false) => paragraph
+                .scrollable(&mut self.scroll)
+                .bordered()
+                .render(area, buf),
+            (false, true) => paragraph
+                .scrollable(&mut self.scroll)
+                .titled("Decorated")
+                .render(area, buf),
+            (false, false) => paragraph.scrollable(&mut self.scroll).render(area, buf),
+        }
+        if knobs.is_on("tooltip") {
+            self.tooltip.tick(Instant::now());
+            self.tooltip
+                .register(area, "Scroll with the mouse wheel or the arrow keys");
+        }
+        StatefulWidget::render(TooltipPopup::new(), area, buf, &mut self.tooltip);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Key(key) => self.scroll.handle_key(*key),
+            Event::Mouse(mouse) => {
+                self.tooltip.handle_mouse(*mouse) | self.scroll.handle_mouse(*mouse)
+            }
+            _ => false,
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn decorator() -> Page {
+    Page::new(
+        "Decorator",
+        "Borders, a title, scrolling and a tooltip added to any widget",
+        vec![
+            Knob::toggle("bordered").with_selected(1),
+            Knob::toggle("titled").with_selected(1),
+            Knob::toggle("tooltip"),
+        ],
+        DecoratorPreview::default(),
+    )
+}
+
+fn property_list() -> Page {
+    Page::new(
+        "PropertyList",
+        "Key-value pairs in two aligned columns",
+        vec![
+            Knob::new("separator", &[" ", ": ", " = "]),
+            Knob::new("max key width", &["none", "6"]),
+            block_knob(),
+        ],
+        |knobs: &Knobs, area: Rect, buf: &mut Buffer| {
+            let mut list = PropertyList::new(vec![
+                Property::new("name", "nginx"),
+                Property::number("replicas", "3"),
+                Property::boolean("enabled", true),
+                Property::new("description", ""),
+            ])
+            .group(
+                "Resources",
+                vec![
+                    Property::number("cpu", "500m"),
+                    Property::number("memory", "256Mi"),
+                ],
+            )
+            .separator(knobs.value("separator"))
+            .placeholder("-");
+            if knobs.value("max key width") == "6" {
+                list = list.max_key_width(6);
+            }
+            if let Some(block) = knobs.block("PropertyList") {
+                list = list.block(block);
+            }
+            list.render(area, buf);
+        },
+    )
+}
+
+#[derive(Default)]
+struct TaskListPreview {
+    state: TaskListState,
+}
+
+impl Preview for TaskListPreview {
+    fn render(&mut self, knobs: &Knobs, _focused: bool, area: Rect, buf: &mut Buffer) {
+        self.state.tick();
+        let tasks = vec![
+            Task::new("Fetch sources").status(TaskStatus::Ok),
+            Task::new("Compile")
+                .status(TaskStatus::Running)
+                .progress(0.6)
+                .output("Compiling ratatui\nCompiling gallery")
+                .expanded(knobs.is_on("expanded")),
+            Task::new("Lint").status(TaskStatus::Failed),
+            Task::new("Publish").status(TaskStatus::Skipped),
+            Task::new("Notify"),
+        ];
+        StatefulWidget::render(TaskList::new(tasks), area, buf, &mut self.state);
+    }
+}
+
+fn task_list() -> Page {
+    Page::new(
+        "TaskList",
+        "The status and progress of tasks, with spinners",
+        vec![Knob::toggle("expanded").with_selected(1)],
+        TaskListPreview::default(),
+    )
+}
+
+fn week_view() -> Page {
+    Page::new(
+        "WeekView",
+        "The events of a week in a grid of days and time slots",
+        vec![
+            Knob::new("rows per hour", &["1", "2"]),
+            Knob::new("hours", &["8-18", "0-24"]),
+            block_knob(),
+        ],
+        |knobs: &Knobs, area: Rect, buf: &mut Buffer| {
+            let hour = |h: u64| Duration::from_secs(h * 3600);
+            let events = vec![
+                WeekEvent::new(0, hour(9), hour(1), "Standup").style(Style::new().on_blue()),
+                WeekEvent::new(2, hour(13), hour(2), "Review").style(Style::new().on_magenta()),
+                WeekEvent::new(4, hour(16), hour(1), "Demo").style(Style::new().on_green()),
+            ];
+            let hours = match knobs.value("hours") {
+                "0-24" => 0..24,
+                _ => 8..18,
+            };
+            let mut view = WeekView::new(events)
+                .hours(hours)
+                .rows_per_hour(knobs.value("rows per hour").parse().unwrap_or(1));
+            if let Some(block) = knobs.block("WeekView") {
+                view = view.block(block);
+            }
+            view.render(area, buf);
+        },
+    )
+}
+
+#[derive(Default)]
+struct GraphViewPreview {
+    state: GraphViewState,
+}
+
+impl Preview for GraphViewPreview {
+    fn render(&mut self, knobs: &Knobs, _focused: bool, area: Rect, buf: &mut Buffer) {
+        let nodes = ["parse", "check", "lower", "optimize", "emit"]
+            .into_iter()
+            .map(GraphNode::new)
+            .collect();
+        let edges = vec![(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)];
+        let mut view = GraphView::new(nodes, edges)
+            .marker(marker(knobs))
+            .layer_spacing(knobs.value("layer spacing").parse().unwrap_or(4));
+        if let Some(block) = knobs.block("GraphView") {
+            view = view.block(block);
+        }
+        StatefulWidget::render(view, area, buf, &mut self.state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Key(key) => self.state.handle_key(*key),
+            Event::Mouse(mouse) => self.state.handle_mouse(*mouse),
+            _ => false,
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn graph_view() -> Page {
+    Page::new(
+        "GraphView",
+        "A directed graph of labeled nodes connected by arrows",
+        vec![
+            marker_knob(),
+            Knob::new("layer spacing", &["2", "4", "8"]).with_selected(1),
+            block_knob(),
+        ],
+        GraphViewPreview::default(),
+    )
+}
+
+struct TreeMapPreview {
+    root: TreeMapNode<'static>,
+    state: TreeMapState,
+}
+
+impl Preview for TreeMapPreview {
+    fn render(&mut self, knobs: &Knobs, _focused: bool, area: Rect, buf: &mut Buffer) {
+        let mut tree_map = TreeMap::new(&self.root);
+        if let Some(block) = knobs.block("TreeMap") {
+            tree_map = tree_map.block(block);
+        }
+        StatefulWidget::render(tree_map, area, buf, &mut self.state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Key(key) => self.state.handle_key(*key),
+            Event::Mouse(mouse) => self.state.handle_mouse(*mouse),
+            _ => false,
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn tree_map() -> Page {
+    let root = TreeMapNode::parent(
+        "disk",
+        vec![
+            TreeMapNode::parent(
+                "src",
+                vec![
+                    TreeMapNode::new("widgets", 60.0),
+                    TreeMapNode::new("layout", 15.0),
+                    TreeMapNode::new("style", 10.0),
+                ],
+            ),
+            TreeMapNode::new("target", 120.0),
+            TreeMapNode::new("docs", 20.0),
+        ],
+    );
+    Page::new(
+        "TreeMap",
+        "Weighted hierarchical data as nested rectangles",
+        vec![block_knob()],
+        TreeMapPreview {
+            root,
+            state: TreeMapState::default(),
+        },
+    )
+}
+
+#[cfg(feature = "widget-calendar")]
+fn calendar() -> Page {
+    use ratatui::widgets::calendar::{CalendarEventStore, Monthly};
+    use time::{Date, Month};
+
+    Page::new(
+        "calendar::Monthly",
+        "A month, with styled dates",
+        vec![
+            Knob::toggle("month header").with_selected(1),
+            Knob::toggle("weekdays header").with_selected(1),
+            Knob::toggle("surrounding days"),
+        ],
+        |knobs: &Knobs, area: Rect, buf: &mut Buffer| {
+            let date = Date::from_calendar_date(2024, Month::February, 14).unwrap();
+            let mut events = CalendarEventStore::default();
+            events.add(date, Style::new().red().bold());
+            let mut calendar = Monthly::new(date, events);
+            if knobs.is_on("month header") {
+                calendar = calendar.show_month_header(Style::new().bold());
+            }
+            if knobs.is_on("weekdays header") {
+                calendar = calendar.show_weekdays_header(Style::new().italic());
+            }
+            if knobs.is_on("surrounding days") {
+                calendar = calendar.show_surrounding(Style::new().dim());
+            }
+            calendar.render(area, buf);
+        },
+    )
+}
+
+#[cfg(feature = "widget-json")]
+struct JsonViewPreview {
+    value: serde_json::Value,
+    state: json_view::JsonViewState,
+}
+
+#[cfg(feature = "widget-json")]
+impl Preview for JsonViewPreview {
+    fn render(&mut self, knobs: &Knobs, _focused: bool, area: Rect, buf: &mut Buffer) {
+        let mut view = json_view::JsonView::new(&self.value);
+        if let Some(block) = knobs.block("JsonView") {
+            view = view.block(block);
+        }
+        StatefulWidget::render(view, area, buf, &mut self.state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match pressed(event) {
+            Some(Key::Up) => self.state.select_previous(),
+            Some(Key::Down) => self.state.select_next(&self.value),
+            Some(Key::Enter) => return self.state.toggle(&self.value),
+            _ => return false,
+        }
+        true
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "widget-json")]
+fn json_view() -> Page {
+    let value = serde_json::json!({
+        "name": "nginx",
+        "replicas": 3,
+        "ports": [80, 443],
+        "tls": { "enabled": true, "certificate": null },
+    });
+    Page::new(
+        "json_view::JsonView",
+        "A JSON value as a tree, expanded and collapsed with Enter",
+        vec![block_knob()],
+        JsonViewPreview {
+            value,
+            state: json_view::JsonViewState::default(),
+        },
+    )
+}
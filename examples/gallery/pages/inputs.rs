@@ -0,0 +1,919 @@
+//! The pages of the widgets editing values, whose events are forwarded from the gallery.
+
+use ratatui::{
+    input::{Event, Input, NonEmpty},
+    prelude::*,
+    widgets::{
+        dialogs::{self, ConfirmState, PromptState},
+        *,
+    },
+};
+
+use super::{Page, Preview};
+use crate::knobs::{alignment_knob, block_knob, Knob, Knobs};
+
+pub fn pages() -> Vec<Page> {
+    #[allow(unused_mut)] // only extended with the optional widgets
+    let mut pages = vec![
+        text_input(),
+        text_area(),
+        number_input(),
+        prompt(),
+        form(),
+        autocomplete(),
+        select(),
+        checkbox(),
+        keybind_input(),
+        tag_input(),
+        color_picker(),
+        radio_group(),
+        slider(),
+        button(),
+        dialogs(),
+    ];
+    #[cfg(feature = "widget-calendar")]
+    pages.extend([date_picker(), time_picker()]);
+    #[cfg(feature = "fs")]
+    pages.push(path_input());
+    pages
+}
+
+#[derive(Default)]
+struct TextInputPreview {
+    state: TextInputState,
+}
+
+impl Preview for TextInputPreview {
+    fn render(&mut self, knobs: &Knobs, focused: bool, area: Rect, buf: &mut Buffer) {
+        self.state.set_focused(focused);
+        let mut input = TextInput::new()
+            .placeholder("Type something")
+            .read_only(knobs.is_on("read-only"))
+            .disabled(knobs.is_on("disabled"));
+        if knobs.is_on("mask") {
+            input = input.mask_char('*');
+        }
+        if knobs.is_on("validator") {
+            input = input.validator(NonEmpty);
+        }
+        if let Some(block) = knobs.block("TextInput") {
+            input = input.block(block);
+        }
+        let height = if knobs.value("block") == "none" { 1 } else { 3 };
+        let input_area = Rect {
+            height: height.min(area.height),
+            ..area
+        };
+        StatefulWidget::render(input, input_area, buf, &mut self.state);
+        if let (Some(error), true) = (self.state.error(), input_area.bottom() < area.bottom()) {
+            buf.set_string(area.x, input_area.bottom(), error, Style::new().red());
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        self.state.handle_event(event)
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn text_input() -> Page {
+    Page::new(
+        "TextInput",
+        "A single line text input",
+        vec![
+            Knob::toggle("mask"),
+            Knob::toggle("validator"),
+            Knob::toggle("read-only"),
+            Knob::toggle("disabled"),
+            block_knob().with_selected(1),
+        ],
+        TextInputPreview::default(),
+    )
+}
+
+struct TextAreaPreview {
+    state: TextAreaState,
+}
+
+impl Preview for TextAreaPreview {
+    fn render(&mut self, knobs: &Knobs, focused: bool, area: Rect, buf: &mut Buffer) {
+        self.state.set_focused(focused);
+        let line_numbers = match knobs.value("line numbers") {
+            "absolute" => LineNumbers::Absolute,
+            "relative" => LineNumbers::Relative,
+            _ => LineNumbers::None,
+        };
+        let wrap_navigation = match knobs.value("navigation") {
+            "visual" => WrapNavigation::Visual,
+            _ => WrapNavigation::Logical,
+        };
+        let mut text_area = TextArea::new()
+            .placeholder("Type some text")
+            .line_numbers(line_numbers)
+            .wrap_navigation(wrap_navigation)
+            .read_only(knobs.is_on("read-only"))
+            .disabled(knobs.is_on("disabled"));
+        if let Some(block) = knobs.block("TextArea") {
+            text_area = text_area.block(block);
+        }
+        StatefulWidget::render(text_area, area, buf, &mut self.state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        self.state.handle_event(event)
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn text_area() -> Page {
+    Page::new(
+        "TextArea",
+        "A multi-line text editor with soft word wrap",
+        vec![
+            Knob::new("line numbers", &["none", "absolute", "relative"]),
+            Knob::new("navigation", &["logical", "visual"]),
+            Knob::toggle("read-only"),
+            Knob::toggle("disabled"),
+            block_knob().with_selected(1),
+        ],
+        TextAreaPreview {
+            state: TextAreaState::new(
+                "The quick brown fox jumps over the lazy dog.\nPack my box with five dozen \
+                 liquor jugs.",
+            ),
+        },
+    )
+}
+
+struct NumberInputPreview {
+    state: NumberInputState<f64>,
+}
+
+impl Preview for NumberInputPreview {
+    fn render(&mut self, knobs: &Knobs, focused: bool, area: Rect, buf: &mut Buffer) {
+        self.state.set_focused(focused);
+        let mut input = NumberInput::new()
+            .alignment(knobs.alignment())
+            .decimals(knobs.value("decimals").parse().unwrap_or_default())
+            .read_only(knobs.is_on("read-only"));
+        if knobs.is_on("thousands") {
+            input = input.thousands_separator(',');
+        }
+        if let Some(block) = knobs.block("NumberInput") {
+            input = input.block(block);
+        }
+        StatefulWidget::render(input, area, buf, &mut self.state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        self.state.handle_event(event)
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn number_input() -> Page {
+    Page::new(
+        "NumberInput",
+        "A number with a range, stepped with Up and Down",
+        vec![
+            alignment_knob().with_selected(2),
+            Knob::new("decimals", &["0", "1", "2"]).with_selected(2),
+            Knob::toggle("thousands").with_selected(1),
+            Knob::toggle("read-only"),
+            block_knob().with_selected(1),
+        ],
+        NumberInputPreview {
+            state: NumberInputState::new(12_345.5)
+                .with_range(0.0, 1_000_000.0)
+                .with_step(0.5),
+        },
+    )
+}
+
+#[derive(Default)]
+struct PromptPreview {
+    state: TextInputState,
+}
+
+impl Preview for PromptPreview {
+    fn render(&mut self, knobs: &Knobs, focused: bool, area: Rect, buf: &mut Buffer) {
+        self.state.set_focused(focused);
+        let mut input = TextInput::new();
+        if knobs.is_on("validator") {
+            input = input.validator(NonEmpty);
+        }
+        let mut prompt = Prompt::new("What is your name?".bold()).input(input);
+        if knobs.is_on("hint") {
+            prompt = prompt.hint("Press Enter to continue");
+        }
+        if let Some(block) = knobs.block("Prompt") {
+            prompt = prompt.block(block);
+        }
+        let area = Rect {
+            height: prompt.height().min(area.height),
+            ..area
+        };
+        StatefulWidget::render(prompt, area, buf, &mut self.state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        self.state.handle_event(event)
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn prompt() -> Page {
+    Page::new(
+        "Prompt",
+        "A label, a text input, its validation error and a hint",
+        vec![
+            Knob::toggle("validator").with_selected(1),
+            Knob::toggle("hint").with_selected(1),
+            block_knob(),
+        ],
+        PromptPreview::default(),
+    )
+}
+
+#[derive(Default)]
+struct FormPreview {
+    state: FormState,
+    submitted: Option<String>,
+}
+
+impl Preview for FormPreview {
+    fn render(&mut self, knobs: &Knobs, _focused: bool, area: Rect, buf: &mut Buffer) {
+        let mut form = Form::new(vec![
+            FormField::new("user", "User"),
+            FormField::new("password", "Password").input(TextInput::new().mask_char('*')),
+            FormField::new("email", "E-mail").input(
+                TextInput::new()
+                    .validator(NonEmpty)
+                    .disabled(knobs.is_on("disable e-mail")),
+            ),
+        ])
+        .spacing(knobs.value("spacing").parse().unwrap_or(1));
+        if let Some(block) = knobs.block("Form") {
+            form = form.block(block);
+        }
+        StatefulWidget::render(form, area, buf, &mut self.state);
+        if let Some(submitted) = &self.submitted {
+            let y = area.bottom().saturating_sub(1);
+            buf.set_string(area.x, y, submitted, Style::new().green());
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Key(key) => {
+                if let Some(values) = self.state.handle_key(*key) {
+                    self.submitted = Some(format!("Submitted {values:?}"));
+                }
+                true
+            }
+            Event::Mouse(mouse) => self.state.handle_mouse(*mouse),
+            Event::Paste(text) => self.state.handle_paste(text),
+            _ => false,
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn form() -> Page {
+    Page::new(
+        "Form",
+        "Labeled text inputs, moved between with Tab and submitted with Enter",
+        vec![
+            Knob::new("spacing", &["0", "1", "2"]).with_selected(1),
+            Knob::toggle("disable e-mail"),
+            block_knob(),
+        ],
+        FormPreview::default(),
+    )
+}
+
+struct AutocompletePreview {
+    input: TextInputState,
+    completions: AutocompleteState,
+}
+
+impl Preview for AutocompletePreview {
+    fn render(&mut self, knobs: &Knobs, focused: bool, area: Rect, buf: &mut Buffer) {
+        self.input.set_focused(focused);
+        let input_area = Rect { height: 1, ..area };
+        StatefulWidget::render(TextInput::new(), input_area, buf, &mut self.input);
+        let mut autocomplete = Autocomplete::new(input_area)
+            .max_height(knobs.value("max height").parse().unwrap_or(5));
+        if let Some(block) = knobs.block("") {
+            autocomplete = autocomplete.block(block);
+        }
+        StatefulWidget::render(autocomplete, area, buf, &mut self.completions);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Key(key) => self.completions.handle_key(&mut self.input, *key),
+            _ => self.input.handle_event(event),
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn autocomplete() -> Page {
+    Page::new(
+        "Autocomplete",
+        "Fuzzy matched completions of a text input, opened with Tab",
+        vec![
+            Knob::new("max height", &["3", "5", "8"]).with_selected(1),
+            block_knob().with_selected(1),
+        ],
+        AutocompletePreview {
+            input: TextInputState::default(),
+            completions: AutocompleteState::new([
+                "cargo build",
+                "cargo check",
+                "cargo clippy",
+                "cargo doc",
+                "cargo fmt",
+                "cargo run",
+                "cargo test",
+            ]),
+        },
+    )
+}
+
+#[derive(Default)]
+struct SelectPreview {
+    state: SelectState,
+}
+
+impl Preview for SelectPreview {
+    fn render(&mut self, knobs: &Knobs, focused: bool, area: Rect, buf: &mut Buffer) {
+        self.state.set_focused(focused);
+        let options = vec![
+            SelectOption::new("Small"),
+            SelectOption::new("Medium"),
+            SelectOption::new("Large").disabled(knobs.is_on("disable large")),
+            SelectOption::new("Extra large"),
+        ];
+        let select = Select::new(options)
+            .placeholder("Pick a size")
+            .symbol(knobs.value("symbol"));
+        StatefulWidget::render(select, area, buf, &mut self.state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Key(key) => self.state.handle_key(*key),
+            _ => false,
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn select() -> Page {
+    Page::new(
+        "Select",
+        "A dropdown of options, opened with Enter and searched by typing",
+        vec![
+            Knob::new("symbol", &["▼", "v"]),
+            Knob::toggle("disable large"),
+        ],
+        SelectPreview::default(),
+    )
+}
+
+/// A knob choosing the symbols of a checkbox or a radio group.
+fn symbols_knob() -> Knob {
+    Knob::new("symbols", &["brackets", "ballot box", "parentheses"])
+}
+
+fn checkbox_symbols(knobs: &Knobs) -> symbols::checkbox::Set {
+    match knobs.value("symbols") {
+        "ballot box" => symbols::checkbox::BALLOT_BOX,
+        "parentheses" => symbols::checkbox::PARENTHESES,
+        _ => symbols::checkbox::BRACKETS,
+    }
+}
+
+#[derive(Default)]
+struct CheckboxPreview {
+    state: CheckboxState,
+}
+
+impl Preview for CheckboxPreview {
+    fn render(&mut self, knobs: &Knobs, focused: bool, area: Rect, buf: &mut Buffer) {
+        self.state.set_focused(focused);
+        let checkbox = Checkbox::new("Remember me").symbols(checkbox_symbols(knobs));
+        StatefulWidget::render(checkbox, area, buf, &mut self.state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Key(key) => self.state.handle_key(*key),
+            Event::Mouse(mouse) => self.state.handle_mouse(*mouse),
+            _ => false,
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn checkbox() -> Page {
+    Page::new(
+        "Checkbox",
+        "A checkbox with a label, toggled with Space",
+        vec![symbols_knob()],
+        CheckboxPreview::default(),
+    )
+}
+
+#[derive(Default)]
+struct KeybindInputPreview {
+    state: KeybindInputState,
+}
+
+impl Preview for KeybindInputPreview {
+    fn render(&mut self, knobs: &Knobs, focused: bool, area: Rect, buf: &mut Buffer) {
+        self.state.set_focused(focused);
+        let mut input = KeybindInput::new();
+        let mut height = 1;
+        if let Some(block) = knobs.block("Command palette") {
+            input = input.block(block);
+            height = 3;
+        }
+        let area = Rect {
+            width: area.width.min(30),
+            height: area.height.min(height),
+            ..area
+        };
+        StatefulWidget::render(input, area, buf, &mut self.state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Key(key) => self.state.handle_key(*key),
+            Event::Mouse(mouse) => self.state.handle_mouse(*mouse),
+            _ => false,
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn keybind_input() -> Page {
+    Page::new(
+        "KeybindInput",
+        "An input recording a key chord, started with Enter or a click",
+        vec![block_knob().with_selected(2)],
+        KeybindInputPreview::default(),
+    )
+}
+
+struct TagInputPreview {
+    state: TagInputState,
+}
+
+impl Default for TagInputPreview {
+    fn default() -> Self {
+        Self {
+            state: TagInputState::new(["bug", "help wanted"]),
+        }
+    }
+}
+
+impl Preview for TagInputPreview {
+    fn render(&mut self, knobs: &Knobs, focused: bool, area: Rect, buf: &mut Buffer) {
+        self.state.set_focused(focused);
+        let mut input =
+            TagInput::new().input(TextInput::new().placeholder("Type a label and press Enter"));
+        let width = area.width.min(40);
+        let mut height = self.state.height(width);
+        if let Some(block) = knobs.block("Labels") {
+            input = input.block(block);
+            height = self.state.height(width.saturating_sub(2)) + 2;
+        }
+        let area = Rect {
+            width,
+            height: area.height.min(height),
+            ..area
+        };
+        StatefulWidget::render(input, area, buf, &mut self.state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        self.state.handle_event(event)
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn tag_input() -> Page {
+    Page::new(
+        "TagInput",
+        "An input turning the text into tags with Enter or a comma",
+        vec![block_knob().with_selected(2)],
+        TagInputPreview::default(),
+    )
+}
+
+#[derive(Default)]
+struct ColorPickerPreview {
+    state: ColorPickerState,
+}
+
+impl Preview for ColorPickerPreview {
+    fn render(&mut self, knobs: &Knobs, focused: bool, area: Rect, buf: &mut Buffer) {
+        self.state.set_focused(focused);
+        let mut picker = ColorPicker::new();
+        let mut size = (40, 17);
+        if let Some(block) = knobs.block("Accent color") {
+            picker = picker.block(block);
+            size = (42, 19);
+        }
+        let area = Rect {
+            width: area.width.min(size.0),
+            height: area.height.min(size.1),
+            ..area
+        };
+        StatefulWidget::render(picker, area, buf, &mut self.state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        self.state.handle_event(event)
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn color_picker() -> Page {
+    Page::new(
+        "ColorPicker",
+        "Picks an ANSI, indexed or RGB color, switching palettes with Ctrl+Left/Right",
+        vec![block_knob()],
+        ColorPickerPreview::default(),
+    )
+}
+
+#[derive(Default)]
+struct RadioGroupPreview {
+    state: RadioGroupState,
+}
+
+impl Preview for RadioGroupPreview {
+    fn render(&mut self, knobs: &Knobs, focused: bool, area: Rect, buf: &mut Buffer) {
+        self.state.set_focused(focused);
+        let direction = match knobs.value("direction") {
+            "horizontal" => Direction::Horizontal,
+            _ => Direction::Vertical,
+        };
+        let group = RadioGroup::new(vec!["Small", "Medium", "Large"])
+            .direction(direction)
+            .symbols(checkbox_symbols(knobs));
+        StatefulWidget::render(group, area, buf, &mut self.state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Key(key) => self.state.handle_key(*key),
+            _ => false,
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn radio_group() -> Page {
+    Page::new(
+        "RadioGroup",
+        "One of several options, selected with the arrows and Space",
+        vec![
+            Knob::new("direction", &["vertical", "horizontal"]),
+            symbols_knob().with_selected(2),
+        ],
+        RadioGroupPreview::default(),
+    )
+}
+
+struct SliderPreview {
+    state: SliderState,
+}
+
+impl Preview for SliderPreview {
+    fn render(&mut self, knobs: &Knobs, focused: bool, area: Rect, buf: &mut Buffer) {
+        self.state.set_focused(focused);
+        let mut slider = Slider::default().use_unicode(knobs.is_on("unicode"));
+        if knobs.is_on("label") {
+            slider = slider.label(format!("{:.0}%", self.state.value()));
+        }
+        if let Some(block) = knobs.block("Slider") {
+            slider = slider.block(block);
+        }
+        StatefulWidget::render(slider, area, buf, &mut self.state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Key(key) => self.state.handle_key(*key),
+            Event::Mouse(mouse) => self.state.handle_mouse(*mouse),
+            _ => false,
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn slider() -> Page {
+    Page::new(
+        "Slider",
+        "A bounded value adjusted with Left and Right",
+        vec![
+            Knob::toggle("unicode").with_selected(1),
+            Knob::toggle("label").with_selected(1),
+            block_knob().with_selected(1),
+        ],
+        SliderPreview {
+            state: SliderState::new(0.0, 100.0).with_value(40.0).with_step(5.0),
+        },
+    )
+}
+
+#[derive(Default)]
+struct ButtonPreview {
+    state: ButtonRowState,
+    pressed: Option<usize>,
+}
+
+impl Preview for ButtonPreview {
+    fn render(&mut self, knobs: &Knobs, focused: bool, area: Rect, buf: &mut Buffer) {
+        if !focused {
+            self.state.focus(None);
+        }
+        let mut save = Button::new("&Save");
+        if knobs.is_on("icons") {
+            save = save.icon("💾");
+        }
+        let row = ButtonRow::new(vec![Button::new("&Cancel"), save, Button::new("&Help")])
+            .alignment(knobs.alignment())
+            .spacing(knobs.value("spacing").parse().unwrap_or(2))
+            .default_button(1);
+        StatefulWidget::render(row, Rect { height: 1, ..area }, buf, &mut self.state);
+        if let (Some(pressed), true) = (self.pressed, area.height > 2) {
+            let text = format!("Pressed button {pressed}");
+            buf.set_string(area.x, area.y + 2, text, Style::new().green());
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        let pressed = match event {
+            Event::Key(key) => self.state.handle_key(*key),
+            Event::Mouse(mouse) => self.state.handle_mouse(*mouse),
+            _ => None,
+        };
+        if pressed.is_some() {
+            self.pressed = pressed;
+        }
+        true
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn button() -> Page {
+    Page::new(
+        "Button",
+        "A row of clickable buttons, activated with Enter or Alt and their mnemonic",
+        vec![
+            alignment_knob().with_selected(1),
+            Knob::new("spacing", &["1", "2", "4"]).with_selected(1),
+            Knob::toggle("icons"),
+        ],
+        ButtonPreview::default(),
+    )
+}
+
+#[derive(Default)]
+struct DialogsPreview {
+    confirm: ConfirmState,
+    confirm_dialog: ConfirmDialogState,
+    prompt: PromptState,
+    outcome: Option<String>,
+    dialog: &'static str,
+}
+
+impl Preview for DialogsPreview {
+    fn render(&mut self, knobs: &Knobs, _focused: bool, area: Rect, buf: &mut Buffer) {
+        self.dialog = knobs.value("dialog");
+        if let Some(outcome) = &self.outcome {
+            buf.set_string(area.x, area.y, outcome, Style::new().green());
+        }
+        match self.dialog {
+            "prompt" => {
+                let prompt = dialogs::prompt("What is your name?");
+                StatefulWidget::render(prompt, area, buf, &mut self.prompt);
+            }
+            "yes/no/cancel" => {
+                let dialog = ConfirmDialog::new("Save the changes before closing?")
+                    .label(ConfirmAction::No, "Don't save")
+                    .default_action(ConfirmAction::Yes);
+                StatefulWidget::render(dialog, area, buf, &mut self.confirm_dialog);
+            }
+            _ => {
+                let confirm = dialogs::confirm("Delete the file?");
+                StatefulWidget::render(confirm, area, buf, &mut self.confirm);
+            }
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        let Event::Key(key) = event else {
+            return false;
+        };
+        let outcome = match self.dialog {
+            "prompt" => self.prompt.handle_key(*key).map(|outcome| {
+                self.prompt = PromptState::default();
+                format!("{outcome:?}")
+            }),
+            "yes/no/cancel" => self.confirm_dialog.handle_key(*key).map(|action| {
+                self.confirm_dialog.reset();
+                format!("{action:?}")
+            }),
+            _ => self
+                .confirm
+                .handle_key(*key)
+                .map(|outcome| format!("{outcome:?}")),
+        };
+        if outcome.is_some() {
+            self.outcome = outcome;
+        }
+        true
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+fn dialogs() -> Page {
+    Page::new(
+        "dialogs",
+        "Modal dialogs asking a question or a line of text",
+        vec![Knob::new("dialog", &["confirm", "yes/no/cancel", "prompt"])],
+        DialogsPreview::default(),
+    )
+}
+
+#[cfg(feature = "widget-calendar")]
+#[derive(Default)]
+struct DatePickerPreview {
+    state: DatePickerState,
+}
+
+#[cfg(feature = "widget-calendar")]
+impl Preview for DatePickerPreview {
+    fn render(&mut self, knobs: &Knobs, focused: bool, area: Rect, buf: &mut Buffer) {
+        self.state.set_focused(focused);
+        let picker = DatePicker::new().calendar(knobs.is_on("calendar"));
+        StatefulWidget::render(picker, area, buf, &mut self.state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Key(key) => self.state.handle_key(*key),
+            _ => false,
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "widget-calendar")]
+fn date_picker() -> Page {
+    Page::new(
+        "DatePicker",
+        "A date edited segment by segment",
+        vec![Knob::toggle("calendar").with_selected(1)],
+        DatePickerPreview::default(),
+    )
+}
+
+#[cfg(feature = "widget-calendar")]
+#[derive(Default)]
+struct TimePickerPreview {
+    state: TimePickerState,
+}
+
+#[cfg(feature = "widget-calendar")]
+impl Preview for TimePickerPreview {
+    fn render(&mut self, knobs: &Knobs, focused: bool, area: Rect, buf: &mut Buffer) {
+        self.state.set_focused(focused);
+        let seconds = knobs.is_on("seconds");
+        if self.state.with_seconds(seconds) != self.state {
+            self.state = self.state.with_seconds(seconds);
+        }
+        StatefulWidget::render(TimePicker::new(), area, buf, &mut self.state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Key(key) => self.state.handle_key(*key),
+            _ => false,
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "widget-calendar")]
+fn time_picker() -> Page {
+    Page::new(
+        "TimePicker",
+        "A time of the day edited segment by segment",
+        vec![Knob::toggle("seconds")],
+        TimePickerPreview::default(),
+    )
+}
+
+#[cfg(feature = "fs")]
+struct PathInputPreview {
+    state: PathInputState,
+}
+
+#[cfg(feature = "fs")]
+impl Preview for PathInputPreview {
+    fn render(&mut self, knobs: &Knobs, focused: bool, area: Rect, buf: &mut Buffer) {
+        self.state.set_focused(focused);
+        let kind = match knobs.value("kind") {
+            "file" => PathKind::File,
+            "directory" => PathKind::Directory,
+            _ => PathKind::Any,
+        };
+        let validator = PathValidator::new()
+            .kind(kind)
+            .must_exist(knobs.is_on("must exist"));
+        let input = PathInput::new().validator(validator);
+        StatefulWidget::render(input, Rect { height: 1, ..area }, buf, &mut self.state);
+    }
+
+    fn handle_event(&mut self, event: &Event) -> bool {
+        self.state.handle_event(event)
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "fs")]
+fn path_input() -> Page {
+    Page::new(
+        "PathInput",
+        "A file path completed from the filesystem with Tab",
+        vec![
+            Knob::new("kind", &["any", "file", "directory"]),
+            Knob::toggle("must exist").with_selected(1),
+        ],
+        PathInputPreview {
+            state: PathInputState::new("~/"),
+        },
+    )
+}
@@ -62,7 +62,10 @@ pub fn init() -> io::Result<Terminal<impl Backend>> {
     let options = TerminalOptions {
         viewport: Viewport::Inline(3),
     };
-    Terminal::with_options(CrosstermBackend::new(stdout()), options)
+    Ok(Terminal::with_options(
+        CrosstermBackend::new(stdout()),
+        options,
+    )?)
 }
 
 pub fn restore() -> io::Result<()> {
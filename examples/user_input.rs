@@ -15,11 +15,15 @@ use std::{error::Error, io};
 /// **Note: ** as this is a relatively simple example unicode characters are unsupported and
 /// their use will result in undefined behaviour.
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{DisableMouseCapture, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use ratatui::{prelude::*, widgets::*};
+use ratatui::{
+    input::{self, Event, Key, KeyEventKind},
+    prelude::*,
+    widgets::*,
+};
 
 enum InputMode {
     Normal,
@@ -136,32 +140,32 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<(
     loop {
         terminal.draw(|f| ui(f, &app))?;
 
-        if let Event::Key(key) = event::read()? {
+        if let Event::Key(key) = input::read()? {
             match app.input_mode {
-                InputMode::Normal => match key.code {
-                    KeyCode::Char('e') => {
+                InputMode::Normal => match key.key {
+                    Key::Char('e') => {
                         app.input_mode = InputMode::Editing;
                     }
-                    KeyCode::Char('q') => {
+                    Key::Char('q') => {
                         return Ok(());
                     }
                     _ => {}
                 },
-                InputMode::Editing if key.kind == KeyEventKind::Press => match key.code {
-                    KeyCode::Enter => app.submit_message(),
-                    KeyCode::Char(to_insert) => {
+                InputMode::Editing if key.kind == KeyEventKind::Press => match key.key {
+                    Key::Enter => app.submit_message(),
+                    Key::Char(to_insert) => {
                         app.enter_char(to_insert);
                     }
-                    KeyCode::Backspace => {
+                    Key::Backspace => {
                         app.delete_char();
                     }
-                    KeyCode::Left => {
+                    Key::Left => {
                         app.move_cursor_left();
                     }
-                    KeyCode::Right => {
+                    Key::Right => {
                         app.move_cursor_right();
                     }
-                    KeyCode::Esc => {
+                    Key::Esc => {
                         app.input_mode = InputMode::Normal;
                     }
                     _ => {}
@@ -0,0 +1,329 @@
+//! Derive macros for [Ratatui](https://docs.rs/ratatui), re-exported by the `ratatui` crate when
+//! its `derive` feature is enabled.
+#![deny(missing_docs)]
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Expr, Fields, GenericArgument, LitChar,
+    LitStr, PathArguments, Type,
+};
+
+/// Derives `FormData` for a struct with named fields, generating a `Form` with a labeled
+/// `TextInput` per field.
+///
+/// Each field of the form is named after the field of the struct, and its value is converted
+/// with the [`ToString`] and [`FromStr`](std::str::FromStr) implementations of the type of the
+/// field: the input is validated by parsing its value, and `FormData::update` writes the parsed
+/// values back into the struct once the form is submitted. An empty value sets an `Option` field
+/// to `None`.
+///
+/// The fields are customized with the `#[form(...)]` attribute:
+/// - `label = "..."` sets the label, which defaults to the name of the field with its first
+///   letter capitalized and the underscores replaced by spaces.
+/// - `placeholder = "..."` sets the placeholder shown while the value is empty.
+/// - `mask = '*'` hides the value behind the given character, e.g. for a password.
+/// - `validator = ...` validates the parsed value further with the given `Validator`, e.g.
+///   `NonEmpty` or `MaxLength(16)`.
+/// - `read_only` and `disabled` make the input read-only or disabled.
+/// - `skip` leaves the field out of the form.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     input::NonEmpty,
+///     prelude::*,
+///     widgets::{Form, FormData, FormState},
+/// };
+///
+/// #[derive(Form)]
+/// struct Account {
+///     #[form(validator = NonEmpty)]
+///     user_name: String,
+///     #[form(mask = '*')]
+///     password: String,
+///     age: Option<u8>,
+///     #[form(skip)]
+///     id: u64,
+/// }
+///
+/// let mut account = Account {
+///     user_name: "admin".into(),
+///     password: String::new(),
+///     age: None,
+///     id: 1,
+/// };
+/// # fn ui(frame: &mut Frame, state: &mut FormState) {
+/// frame.render_stateful_widget(Account::form(), frame.size(), state);
+/// # }
+/// let mut state = account.form_state();
+/// state.set_value("age", "42");
+/// account.update(&state.values()).unwrap();
+/// assert_eq!(account.age, Some(42));
+///
+/// state.set_value("age", "forty-two");
+/// assert_eq!(account.update(&state.values()).unwrap_err().name(), "age");
+/// ```
+#[proc_macro_derive(Form, attributes(form))]
+pub fn derive_form(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_form(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// A field of the struct, with the options of its `#[form(...)]` attribute.
+struct FormField<'a> {
+    ident: &'a syn::Ident,
+    ty: &'a Type,
+    label: Option<LitStr>,
+    placeholder: Option<LitStr>,
+    mask: Option<LitChar>,
+    validator: Option<Expr>,
+    read_only: bool,
+    disabled: bool,
+}
+
+impl<'a> FormField<'a> {
+    /// Parses the `#[form(...)]` attributes of the field, and returns `None` if it is skipped.
+    fn parse(field: &'a syn::Field) -> syn::Result<Option<Self>> {
+        let ident = field
+            .ident
+            .as_ref()
+            .ok_or_else(|| syn::Error::new(field.span(), "expected a named field"))?;
+        let mut form_field = Self {
+            ident,
+            ty: &field.ty,
+            label: None,
+            placeholder: None,
+            mask: None,
+            validator: None,
+            read_only: false,
+            disabled: false,
+        };
+        let mut skip = false;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("form"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("label") {
+                    form_field.label = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("placeholder") {
+                    form_field.placeholder = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("mask") {
+                    form_field.mask = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("validator") {
+                    form_field.validator = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("read_only") {
+                    form_field.read_only = true;
+                } else if meta.path.is_ident("disabled") {
+                    form_field.disabled = true;
+                } else if meta.path.is_ident("skip") {
+                    skip = true;
+                } else {
+                    return Err(meta.error("unknown form attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok((!skip).then_some(form_field))
+    }
+
+    /// Returns the name of the field in the form.
+    fn name(&self) -> String {
+        self.ident.to_string().trim_start_matches("r#").to_string()
+    }
+
+    /// Returns the label, or the name with its first letter capitalized and spaces instead of
+    /// underscores.
+    fn label(&self) -> String {
+        if let Some(label) = &self.label {
+            return label.value();
+        }
+        let name = self.name().replace('_', " ");
+        let mut chars = name.trim().chars();
+        chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns an expression parsing `value`, a `&str`, into the type of the field.
+    fn parse_value(&self, value: &TokenStream2) -> TokenStream2 {
+        match option_inner(self.ty) {
+            Some(inner) => quote! {
+                if #value.is_empty() {
+                    ::std::result::Result::Ok(::std::option::Option::None)
+                } else {
+                    <#inner as ::std::str::FromStr>::from_str(#value)
+                        .map(::std::option::Option::Some)
+                        .map_err(|error| ::std::string::ToString::to_string(&error))
+                }
+            },
+            None => {
+                let ty = self.ty;
+                quote! {
+                    <#ty as ::std::str::FromStr>::from_str(#value)
+                        .map_err(|error| ::std::string::ToString::to_string(&error))
+                }
+            }
+        }
+    }
+
+    /// Returns an expression converting the field of `self` into the value of the input.
+    fn format_value(&self) -> TokenStream2 {
+        let ident = self.ident;
+        match option_inner(self.ty) {
+            Some(_) => quote! {
+                self.#ident
+                    .as_ref()
+                    .map(::std::string::ToString::to_string)
+                    .unwrap_or_default()
+            },
+            None => quote! { ::std::string::ToString::to_string(&self.#ident) },
+        }
+    }
+
+    /// Returns an expression creating the `FormField` of the field.
+    fn form_field(&self) -> TokenStream2 {
+        let name = self.name();
+        let label = self.label();
+        let value = quote!(value);
+        let parse = self.parse_value(&value);
+        let validate = match &self.validator {
+            Some(validator) => quote! {
+                let validator = #validator;
+                move |#value: &str| -> ::std::result::Result<(), ::std::string::String> {
+                    (#parse)?;
+                    ::ratatui::input::Validator::validate(&validator, #value)
+                }
+            },
+            None => quote! {
+                |#value: &str| -> ::std::result::Result<(), ::std::string::String> {
+                    (#parse).map(|_| ())
+                }
+            },
+        };
+        let mut input = quote! {
+            ::ratatui::widgets::TextInput::new().validator({ #validate })
+        };
+        if let Some(placeholder) = &self.placeholder {
+            input = quote!(#input.placeholder(#placeholder));
+        }
+        if let Some(mask) = &self.mask {
+            input = quote!(#input.mask_char(#mask));
+        }
+        if self.read_only {
+            input = quote!(#input.read_only(true));
+        }
+        if self.disabled {
+            input = quote!(#input.disabled(true));
+        }
+        quote! {
+            ::ratatui::widgets::FormField::new(#name, #label).input(#input)
+        }
+    }
+}
+
+/// Returns the type wrapped in an `Option`, if the type is an `Option`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) if arguments.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+fn expand_form(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let named = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "`Form` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "`Form` can only be derived for structs",
+            ))
+        }
+    };
+    let fields = named
+        .iter()
+        .filter_map(|field| FormField::parse(field).transpose())
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let form_fields = fields.iter().map(FormField::form_field);
+    let states = fields.iter().map(|field| {
+        let name = field.name();
+        let value = field.format_value();
+        quote!(.with_value(#name, #value))
+    });
+    let value = quote!(value);
+    // every value is parsed before any field is written, so that an error leaves the struct
+    // unchanged
+    let parsed = fields.iter().map(|field| {
+        let parsed = format_ident!("parsed_{}", field.ident);
+        let name = field.name();
+        let parse = field.parse_value(&value);
+        quote! {
+            let #parsed = match values.get(#name) {
+                ::std::option::Option::Some(#value) => ::std::option::Option::Some(
+                    (#parse).map_err(|error| ::ratatui::widgets::FieldError::new(#name, error))?,
+                ),
+                ::std::option::Option::None => ::std::option::Option::None,
+            };
+        }
+    });
+    let updates = fields.iter().map(|field| {
+        let ident = field.ident;
+        let parsed = format_ident!("parsed_{}", field.ident);
+        quote! {
+            if let ::std::option::Option::Some(#parsed) = #parsed {
+                self.#ident = #parsed;
+            }
+        }
+    });
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::ratatui::widgets::FormData for #ident #ty_generics #where_clause {
+            fn form<'a>() -> ::ratatui::widgets::Form<'a> {
+                ::ratatui::widgets::Form::new(::std::vec![#(#form_fields),*])
+            }
+
+            fn form_state(&self) -> ::ratatui::widgets::FormState {
+                ::ratatui::widgets::FormState::default()#(#states)*
+            }
+
+            fn update(
+                &mut self,
+                values: &::std::collections::BTreeMap<
+                    ::std::string::String,
+                    ::std::string::String,
+                >,
+            ) -> ::std::result::Result<(), ::ratatui::widgets::FieldError> {
+                #(#parsed)*
+                #(#updates)*
+                ::std::result::Result::Ok(())
+            }
+        }
+    })
+}
@@ -0,0 +1,125 @@
+//! `animation` contains the primitives used to compute transitions over time, e.g. a theme fading
+//! into another one or the border of the focused widget pulsing.
+//!
+//! An animation is driven by its progress `t`, going from `0.0` at its start to `1.0` at its end,
+//! which the application computes from the time elapsed between two frames. An [`Easing`] shapes
+//! this progress so the transition starts or ends smoothly, and [`pulse`] turns a running time
+//! into a progress going back and forth. The eased progress is then passed to an interpolation,
+//! e.g. [`Color::lerp`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::time::Duration;
+//!
+//! use ratatui::{animation::*, prelude::*};
+//!
+//! // fades the border of the focused widget from gray to yellow and back every second
+//! fn focus_color(elapsed: Duration) -> Color {
+//!     let t = Easing::EaseInOut.apply(pulse(elapsed, Duration::from_secs(1)));
+//!     Color::lerp(Color::Gray, Color::Yellow, t)
+//! }
+//!
+//! assert_eq!(focus_color(Duration::ZERO), Color::Gray);
+//! assert_eq!(focus_color(Duration::from_millis(500)), Color::Yellow);
+//! ```
+//!
+//! [`Color::lerp`]: crate::style::Color::lerp
+
+use std::time::Duration;
+
+use strum::{Display, EnumString};
+
+/// A function shaping the progress of an animation.
+///
+/// The eased progress starts at `0.0` and ends at `1.0` like the progress, but it can change
+/// slowly at the start of the animation and quickly at its end, or the other way around. The
+/// curves are cubic.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::animation::Easing;
+///
+/// assert_eq!(Easing::Linear.apply(0.5), 0.5);
+/// assert_eq!(Easing::EaseIn.apply(0.5), 0.125);
+/// assert_eq!(Easing::EaseOut.apply(0.5), 0.875);
+/// ```
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Easing {
+    /// The progress is not shaped.
+    #[default]
+    Linear,
+    /// Starts slowly and accelerates until the end.
+    EaseIn,
+    /// Starts quickly and decelerates until the end.
+    EaseOut,
+    /// Starts slowly, accelerates until the middle and decelerates until the end.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Returns the eased progress of an animation, `t` being clamped between `0.0` and `1.0`.
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut if t < 0.5 => 4.0 * t * t * t,
+            Easing::EaseInOut => 1.0 - (2.0 - 2.0 * t).powi(3) / 2.0,
+        }
+    }
+}
+
+/// Returns the progress of a pulse after the given time: it goes from `0.0` to `1.0` in the first
+/// half of each period, then back to `0.0` in the second half.
+///
+/// Returns `0.0` if the period is zero.
+pub fn pulse(elapsed: Duration, period: Duration) -> f64 {
+    if period.is_zero() {
+        return 0.0;
+    }
+    let phase = (elapsed.as_secs_f64() / period.as_secs_f64()).fract();
+    1.0 - (2.0 * phase - 1.0).abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easing_bounds() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert_eq!(easing.apply(0.0), 0.0, "{easing}");
+            assert_eq!(easing.apply(1.0), 1.0, "{easing}");
+            assert_eq!(easing.apply(-1.0), 0.0, "{easing}");
+            assert_eq!(easing.apply(2.0), 1.0, "{easing}");
+        }
+    }
+
+    #[test]
+    fn easing_curves() {
+        assert_eq!(Easing::EaseIn.apply(0.5), 0.125);
+        assert_eq!(Easing::EaseOut.apply(0.5), 0.875);
+        assert_eq!(Easing::EaseInOut.apply(0.25), 0.0625);
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+        assert_eq!(Easing::EaseInOut.apply(0.75), 0.9375);
+    }
+
+    #[test]
+    fn pulse_goes_back_and_forth() {
+        let period = Duration::from_secs(2);
+        assert_eq!(pulse(Duration::ZERO, period), 0.0);
+        assert_eq!(pulse(Duration::from_millis(500), period), 0.5);
+        assert_eq!(pulse(Duration::from_secs(1), period), 1.0);
+        assert_eq!(pulse(Duration::from_millis(1500), period), 0.5);
+        assert_eq!(pulse(Duration::from_secs(2), period), 0.0);
+        assert_eq!(pulse(Duration::from_secs(1), Duration::ZERO), 0.0);
+    }
+}
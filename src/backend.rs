@@ -100,11 +100,16 @@
 //! [Backend Comparison]:
 //!     https://ratatui-org.github.io/ratatui-book/concepts/backends/comparison.html
 //! [Ratatui Website]: https://ratatui-org.github.io/ratatui-book
-use std::io;
+use std::{io, ops::Range, time::Duration};
 
 use strum::{Display, EnumString};
 
-use crate::{buffer::Cell, layout::Size, prelude::Rect};
+use crate::{
+    buffer::Cell,
+    layout::{Position, Size},
+    prelude::Rect,
+    style::Color,
+};
 
 #[cfg(feature = "termion")]
 mod termion;
@@ -124,6 +129,9 @@ pub use self::termwiz::TermwizBackend;
 mod test;
 pub use self::test::TestBackend;
 
+mod capabilities;
+pub use self::capabilities::Capabilities;
+
 /// Enum representing the different types of clearing operations that can be performed
 /// on the terminal screen.
 #[derive(Debug, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
@@ -153,6 +161,54 @@ pub struct WindowSize {
     pub pixels: Size,
 }
 
+/// One of the default colors of the terminal, as queried by [`Backend::query_color`].
+#[derive(Debug, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TerminalColor {
+    /// The color of the text, queried with `OSC 10`.
+    Foreground,
+    /// The color of the background, queried with `OSC 11`.
+    Background,
+}
+
+impl TerminalColor {
+    /// Returns the `OSC` sequence asking the terminal for the color.
+    #[cfg_attr(not(feature = "termion"), allow(dead_code))]
+    pub(crate) fn query(self) -> &'static str {
+        match self {
+            TerminalColor::Foreground => "\x1b]10;?\x1b\\",
+            TerminalColor::Background => "\x1b]11;?\x1b\\",
+        }
+    }
+}
+
+/// Parses the answer of the terminal to a [`TerminalColor::query`], e.g.
+/// `ESC ] 11 ; rgb:ffff/ffff/dddd ESC \`, into an RGB color.
+///
+/// The answer may be terminated by `ST` or `BEL`, and each component has one to four hexadecimal
+/// digits.
+#[cfg_attr(not(feature = "termion"), allow(dead_code))]
+pub(crate) fn parse_color_answer(answer: &[u8]) -> Option<Color> {
+    let answer = std::str::from_utf8(answer).ok()?;
+    let start = answer.find("rgb:")? + 4;
+    let end = answer[start..]
+        .find(['\x1b', '\x07'])
+        .map_or(answer.len(), |end| start + end);
+    let mut components = answer[start..end].split('/').map(|component| {
+        let digits = component.get(..4).unwrap_or(component);
+        let value = u32::from_str_radix(digits, 16).ok()?;
+        let max = (1u32 << (4 * digits.len()))
+            .checked_sub(1)
+            .filter(|&max| max > 0)?;
+        Some((value * 255 / max) as u8)
+    });
+    let (r, g, b) = (
+        components.next()??,
+        components.next()??,
+        components.next()??,
+    );
+    components.next().is_none().then_some(Color::Rgb(r, g, b))
+}
+
 /// The `Backend` trait provides an abstraction over different terminal libraries. It defines the
 /// methods required to draw content, manipulate the cursor, and clear the terminal screen.
 ///
@@ -229,6 +285,36 @@ pub trait Backend {
     /// [`get_cursor`]: Backend::get_cursor
     fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()>;
 
+    /// Get the current cursor position on the terminal screen as a [`Position`].
+    ///
+    /// This is the same as [`get_cursor`], without the ambiguity of a bare tuple.
+    ///
+    /// [`get_cursor`]: Backend::get_cursor
+    fn get_cursor_position(&mut self) -> io::Result<Position> {
+        self.get_cursor().map(Position::from)
+    }
+
+    /// Set the cursor position on the terminal screen to the given [`Position`].
+    ///
+    /// This is the same as [`set_cursor`], also accepting a `(x, y)` tuple.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::backend::{Backend, TestBackend};
+    /// use ratatui::layout::Position;
+    /// # let mut backend = TestBackend::new(80, 25);
+    /// backend.set_cursor_position(Position::new(10, 20))?;
+    /// assert_eq!(backend.get_cursor_position()?, Position::new(10, 20));
+    /// # std::io::Result::Ok(())
+    /// ```
+    ///
+    /// [`set_cursor`]: Backend::set_cursor
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+        let Position { x, y } = position.into();
+        self.set_cursor(x, y)
+    }
+
     /// Clears the whole terminal screen
     ///
     /// # Example
@@ -275,6 +361,66 @@ pub trait Backend {
         }
     }
 
+    /// Scrolls the rows in `region` up by `line_count` lines.
+    ///
+    /// The region is given as a range of rows in terminal coordinates. Rows scrolled off the top
+    /// of the region are discarded and `line_count` blank rows are inserted at the bottom of the
+    /// region. Rows outside of the region are left untouched.
+    ///
+    /// This is used by [`Terminal`] to move large unchanged areas (e.g. the content of a log view)
+    /// with a single command instead of redrawing every cell.
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation returns an error of kind [`io::ErrorKind::Unsupported`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, backend::TestBackend};
+    /// let mut backend = TestBackend::new(3, 3);
+    /// let buffer = Buffer::with_lines(vec!["a  ", "b  ", "c  "]);
+    /// backend.draw(buffer.content.iter().enumerate().map(|(i, cell)| {
+    ///     let (x, y) = buffer.pos_of(i);
+    ///     (x, y, cell)
+    /// }))?;
+    /// backend.scroll_region_up(0..3, 1)?;
+    /// backend.assert_buffer(&Buffer::with_lines(vec!["b  ", "c  ", "   "]));
+    /// # std::io::Result::Ok(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the backend does not support scrolling regions or if
+    /// the command could not be written to the terminal.
+    ///
+    /// [`Terminal`]: crate::terminal::Terminal
+    fn scroll_region_up(&mut self, _region: Range<u16>, _line_count: u16) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "scrolling regions are not supported with this backend",
+        ))
+    }
+
+    /// Scrolls the rows in `region` down by `line_count` lines.
+    ///
+    /// Rows scrolled off the bottom of the region are discarded and `line_count` blank rows are
+    /// inserted at the top of the region. Rows outside of the region are left untouched.
+    ///
+    /// See [`scroll_region_up`] for more details.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the backend does not support scrolling regions or if
+    /// the command could not be written to the terminal.
+    ///
+    /// [`scroll_region_up`]: Backend::scroll_region_up
+    fn scroll_region_down(&mut self, _region: Range<u16>, _line_count: u16) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "scrolling regions are not supported with this backend",
+        ))
+    }
+
     /// Get the size of the terminal screen in columns/rows as a [`Rect`].
     ///
     /// The returned [`Rect`] contains the width and height of the terminal screen.
@@ -298,6 +444,34 @@ pub trait Backend {
 
     /// Flush any buffered content to the terminal screen.
     fn flush(&mut self) -> io::Result<()>;
+
+    /// Asks the terminal for one of its default colors with an `OSC 10` or `OSC 11` query,
+    /// waiting at most `timeout` for the answer.
+    ///
+    /// Returns `Ok(None)` if the terminal did not answer in time, or if the backend cannot read
+    /// the answers of the terminal. The terminal must be in [raw mode](crate::backend#raw-mode)
+    /// for the answer not to be echoed.
+    ///
+    /// The default implementation returns `Ok(None)`. See [`style::detect_background`] to pick
+    /// light or dark colors from the background of the terminal.
+    ///
+    /// [`style::detect_background`]: crate::style::detect_background
+    fn query_color(
+        &mut self,
+        _color: TerminalColor,
+        _timeout: Duration,
+    ) -> io::Result<Option<Color>> {
+        Ok(None)
+    }
+
+    /// Returns what the terminal can display, used by the widgets to fall back to simpler
+    /// symbols and styles. See [`Capabilities`].
+    ///
+    /// The default implementation looks up the terminal in the database of known quirks with
+    /// [`Capabilities::from_env`].
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::from_env()
+    }
 }
 
 #[cfg(test)]
@@ -336,4 +510,18 @@ mod tests {
         );
         assert_eq!("".parse::<ClearType>(), Err(ParseError::VariantNotFound));
     }
+
+    #[test]
+    fn color_answer() {
+        assert_eq!(
+            parse_color_answer(b"\x1b]11;rgb:ffff/8080/0000\x1b\\"),
+            Some(Color::Rgb(255, 128, 0))
+        );
+        assert_eq!(
+            parse_color_answer(b"\x1b]10;rgb:f/80/000\x07"),
+            Some(Color::Rgb(255, 128, 0))
+        );
+        assert_eq!(parse_color_answer(b"\x1b]11;rgb:ffff/ffff"), None);
+        assert_eq!(parse_color_answer(b"\x1b]11;?\x1b\\"), None);
+    }
 }
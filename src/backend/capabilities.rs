@@ -0,0 +1,253 @@
+//! Known quirks of terminals, used by the widgets to fall back to simpler symbols and styles.
+use std::{cell::Cell, env};
+
+use crate::symbols::border;
+
+/// What a terminal can display, as reported by [`Backend::capabilities`].
+///
+/// The default implementation of [`Backend::capabilities`] looks up the `$TERM` and
+/// `$TERM_PROGRAM` environment variables in a small database of known terminal quirks, see
+/// [`Capabilities::for_terminal`].
+///
+/// The [`Terminal`] makes the capabilities of its backend available to the widgets while they
+/// render in [`Terminal::draw`], through [`Capabilities::current`]. The widgets use them to fall
+/// back to simpler symbols without any code in the application: e.g. [`Gauge::use_unicode`] is
+/// ignored without [`block_fractions`](Capabilities::block_fractions), and the borders of a
+/// [`Block`] are drawn with ASCII characters without [`box_drawing`](Capabilities::box_drawing).
+/// The terminal also removes the italic modifier before drawing when
+/// [`italic`](Capabilities::italic) is `false`, and replaces the symbols that aren't ASCII with
+/// [`AsciiFallback`] when [`unicode`](Capabilities::unicode) is `false`.
+///
+/// [`Backend::capabilities`]: crate::backend::Backend::capabilities
+/// [`Terminal`]: crate::terminal::Terminal
+/// [`Terminal::draw`]: crate::terminal::Terminal::draw
+/// [`Gauge::use_unicode`]: crate::widgets::Gauge::use_unicode
+/// [`Block`]: crate::widgets::Block
+/// [`AsciiFallback`]: crate::terminal::AsciiFallback
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Capabilities {
+    /// Whether characters outside of ASCII are displayed, e.g. on terminals set to a legacy
+    /// encoding or when the output is captured in a log.
+    pub unicode: bool,
+    /// Whether italic text is displayed as such. Some terminals show it in reverse video or not
+    /// at all.
+    pub italic: bool,
+    /// Whether the characters of ambiguous East Asian width, e.g. `§` or `○`, are displayed two
+    /// columns wide. The widgets always measure them one column wide, applications can use this
+    /// to avoid them.
+    pub ambiguous_wide: bool,
+    /// Whether the block elements for eighths of a cell, e.g. `▏` or `▁`, are available.
+    pub block_fractions: bool,
+    /// Whether the box drawing characters, e.g. `─` or `┌`, are available.
+    pub box_drawing: bool,
+    /// Whether the rounded corners of the box drawing characters, e.g. `╭`, are available.
+    pub rounded_corners: bool,
+}
+
+/// The quirks of a family of terminals, identified by their `$TERM` or `$TERM_PROGRAM`.
+struct Quirks {
+    /// The `$TERM` values, also matching the variants like `screen-256color` or `screen.xterm`
+    terms: &'static [&'static str],
+    /// The `$TERM_PROGRAM` values
+    programs: &'static [&'static str],
+    capabilities: Capabilities,
+}
+
+/// Terminals without any Unicode support, only showing ASCII characters.
+const ASCII_ONLY: Capabilities = Capabilities {
+    unicode: false,
+    italic: false,
+    ambiguous_wide: false,
+    block_fractions: false,
+    box_drawing: false,
+    rounded_corners: false,
+};
+
+/// The known terminal quirks. The first matching entry is used.
+const QUIRKS: &[Quirks] = &[
+    Quirks {
+        terms: &["dumb", "ansi", "vt100", "vt102", "vt220", "xterm-mono"],
+        programs: &[],
+        capabilities: ASCII_ONLY,
+    },
+    // the consoles of Linux and BSDs, whose fonts lack most block elements and rounded corners
+    Quirks {
+        terms: &["linux", "cons25", "pcvt", "wsvt25"],
+        programs: &[],
+        capabilities: Capabilities {
+            italic: false,
+            block_fractions: false,
+            rounded_corners: false,
+            ..Capabilities::FULL
+        },
+    },
+    // Japanese terminals displaying the ambiguous characters as wide ones
+    Quirks {
+        terms: &["kterm", "kon", "jfbterm", "mlterm"],
+        programs: &[],
+        capabilities: Capabilities {
+            italic: false,
+            ambiguous_wide: true,
+            ..Capabilities::FULL
+        },
+    },
+    // GNU screen shows italic text in reverse video
+    Quirks {
+        terms: &["screen"],
+        programs: &[],
+        capabilities: Capabilities {
+            italic: false,
+            ..Capabilities::FULL
+        },
+    },
+    // the default fonts of Terminal.app lack some of the eighths
+    Quirks {
+        terms: &[],
+        programs: &["Apple_Terminal"],
+        capabilities: Capabilities {
+            block_fractions: false,
+            ..Capabilities::FULL
+        },
+    },
+];
+
+thread_local! {
+    static CURRENT: Cell<Capabilities> = const { Cell::new(Capabilities::FULL) };
+}
+
+/// Restores the previous [`Capabilities::current`] when dropped, see
+/// [`Capabilities::set_current`].
+#[must_use = "the previous capabilities are restored as soon as the guard is dropped"]
+pub(crate) struct CurrentGuard {
+    previous: Capabilities,
+}
+
+impl Drop for CurrentGuard {
+    fn drop(&mut self) {
+        CURRENT.with(|current| current.set(self.previous));
+    }
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Capabilities::FULL
+    }
+}
+
+impl Capabilities {
+    /// The capabilities of a modern terminal, supporting everything.
+    pub const FULL: Capabilities = Capabilities {
+        unicode: true,
+        italic: true,
+        ambiguous_wide: false,
+        block_fractions: true,
+        box_drawing: true,
+        rounded_corners: true,
+    };
+
+    /// Returns the capabilities of the terminal described by the `$TERM` and `$TERM_PROGRAM`
+    /// environment variables.
+    pub fn from_env() -> Capabilities {
+        let term = env::var("TERM").unwrap_or_default();
+        let program = env::var("TERM_PROGRAM").ok();
+        Capabilities::for_terminal(&term, program.as_deref())
+    }
+
+    /// Returns the capabilities of the terminal with the given `$TERM` and `$TERM_PROGRAM`, from
+    /// the database of known terminal quirks. Unknown terminals support everything.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::backend::Capabilities;
+    ///
+    /// assert!(!Capabilities::for_terminal("linux", None).block_fractions);
+    /// assert!(!Capabilities::for_terminal("screen-256color", None).italic);
+    /// assert_eq!(
+    ///     Capabilities::for_terminal("xterm-256color", Some("WezTerm")),
+    ///     Capabilities::FULL
+    /// );
+    /// ```
+    pub fn for_terminal(term: &str, program: Option<&str>) -> Capabilities {
+        let matches_term = |name: &&str| {
+            term.strip_prefix(name)
+                .is_some_and(|variant| variant.is_empty() || variant.starts_with(['-', '.']))
+        };
+        QUIRKS
+            .iter()
+            .find(|quirks| {
+                quirks.terms.iter().any(matches_term)
+                    || program.is_some_and(|program| quirks.programs.contains(&program))
+            })
+            .map_or(Capabilities::FULL, |quirks| quirks.capabilities)
+    }
+
+    /// Returns the capabilities of the terminal being drawn by [`Terminal::draw`] on this thread,
+    /// or [`Capabilities::FULL`] outside of it.
+    ///
+    /// [`Terminal::draw`]: crate::terminal::Terminal::draw
+    pub fn current() -> Capabilities {
+        CURRENT.with(Cell::get)
+    }
+
+    /// Sets the capabilities returned by [`Capabilities::current`] until the returned guard is
+    /// dropped, which restores the previous ones, even when unwinding from a panic.
+    pub(crate) fn set_current(capabilities: Capabilities) -> CurrentGuard {
+        CurrentGuard {
+            previous: CURRENT.with(|current| current.replace(capabilities)),
+        }
+    }
+
+    /// Returns the border set to draw instead of `set` on this terminal: rounded corners are
+    /// replaced by square ones, and without box drawing characters the whole set by
+    /// [`border::ASCII`].
+    pub fn border_set(&self, set: border::Set) -> border::Set {
+        if !self.box_drawing {
+            border::ASCII
+        } else if !self.rounded_corners && set == border::ROUNDED {
+            border::PLAIN
+        } else {
+            set
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn for_terminal() {
+        assert_eq!(Capabilities::for_terminal("", None), Capabilities::FULL);
+        assert_eq!(Capabilities::for_terminal("vt100", None), ASCII_ONLY);
+        assert!(!Capabilities::for_terminal("screen", None).italic);
+        assert!(!Capabilities::for_terminal("screen.xterm-256color", None).italic);
+        assert!(Capabilities::for_terminal("tmux-256color", None).italic);
+        assert!(Capabilities::for_terminal("mlterm", None).ambiguous_wide);
+        assert!(
+            !Capabilities::for_terminal("xterm-256color", Some("Apple_Terminal")).block_fractions
+        );
+    }
+
+    #[test]
+    fn border_set() {
+        let linux = Capabilities::for_terminal("linux", None);
+        assert_eq!(linux.border_set(border::ROUNDED), border::PLAIN);
+        assert_eq!(linux.border_set(border::DOUBLE), border::DOUBLE);
+        assert_eq!(ASCII_ONLY.border_set(border::THICK), border::ASCII);
+        assert_eq!(
+            Capabilities::FULL.border_set(border::ROUNDED),
+            border::ROUNDED
+        );
+    }
+
+    #[test]
+    fn current() {
+        assert_eq!(Capabilities::current(), Capabilities::FULL);
+        let guard = Capabilities::set_current(ASCII_ONLY);
+        assert_eq!(Capabilities::current(), ASCII_ONLY);
+        drop(guard);
+        assert_eq!(Capabilities::current(), Capabilities::FULL);
+    }
+}
@@ -2,7 +2,11 @@
 //! the [Crossterm] crate to interact with the terminal.
 //!
 //! [Crossterm]: https://crates.io/crates/crossterm
-use std::io::{self, Write};
+use std::{
+    fmt,
+    io::{self, Write},
+    ops::Range,
+};
 
 #[cfg(feature = "underline-color")]
 use crossterm::style::SetUnderlineColor;
@@ -14,6 +18,7 @@ use crossterm::{
         SetAttribute, SetBackgroundColor, SetForegroundColor,
     },
     terminal::{self, Clear},
+    Command,
 };
 
 use crate::{
@@ -223,6 +228,34 @@ where
         self.writer.flush()
     }
 
+    fn scroll_region_up(&mut self, region: Range<u16>, line_count: u16) -> io::Result<()> {
+        if region.is_empty() || line_count == 0 {
+            return Ok(());
+        }
+        queue!(
+            self.writer,
+            ScrollRegion {
+                region,
+                line_count,
+                direction: ScrollDirection::Up,
+            }
+        )
+    }
+
+    fn scroll_region_down(&mut self, region: Range<u16>, line_count: u16) -> io::Result<()> {
+        if region.is_empty() || line_count == 0 {
+            return Ok(());
+        }
+        queue!(
+            self.writer,
+            ScrollRegion {
+                region,
+                line_count,
+                direction: ScrollDirection::Down,
+            }
+        )
+    }
+
     fn size(&self) -> io::Result<Rect> {
         let (width, height) = terminal::size()?;
         Ok(Rect::new(0, 0, width, height))
@@ -301,6 +334,45 @@ impl From<CColor> for Color {
     }
 }
 
+/// The direction in which a [`ScrollRegion`] command moves the rows.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum ScrollDirection {
+    Up,
+    Down,
+}
+
+/// A crossterm [`Command`] that scrolls a range of rows using the terminal scrolling region
+/// escape sequences (DECSTBM followed by SU/SD) and then restores the full-screen region.
+///
+/// Crossterm does not provide a command for setting the scrolling region, so the sequences are
+/// written directly.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct ScrollRegion {
+    region: Range<u16>,
+    line_count: u16,
+    direction: ScrollDirection,
+}
+
+impl Command for ScrollRegion {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        // DECSTBM uses 1-based inclusive row numbers
+        write!(f, "\x1b[{};{}r", self.region.start + 1, self.region.end)?;
+        match self.direction {
+            ScrollDirection::Up => write!(f, "\x1b[{}S", self.line_count)?,
+            ScrollDirection::Down => write!(f, "\x1b[{}T", self.line_count)?,
+        }
+        write!(f, "\x1b[r")
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "scrolling regions are not supported with the legacy windows console",
+        ))
+    }
+}
+
 /// The `ModifierDiff` struct is used to calculate the difference between two `Modifier`
 /// values. This is useful when updating the terminal display, as it allows for more
 /// efficient updates by only sending the necessary changes.
@@ -463,6 +535,28 @@ impl From<ContentStyle> for Style {
 mod tests {
     use super::*;
 
+    #[test]
+    fn scroll_region_up() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.scroll_region_up(2..10, 3).unwrap();
+        assert_eq!(backend.writer, b"\x1b[3;10r\x1b[3S\x1b[r");
+    }
+
+    #[test]
+    fn scroll_region_down() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.scroll_region_down(0..5, 1).unwrap();
+        assert_eq!(backend.writer, b"\x1b[1;5r\x1b[1T\x1b[r");
+    }
+
+    #[test]
+    fn scroll_region_empty() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.scroll_region_up(3..3, 1).unwrap();
+        backend.scroll_region_down(0..5, 0).unwrap();
+        assert!(backend.writer.is_empty());
+    }
+
     #[test]
     fn from_crossterm_color() {
         assert_eq!(Color::from(CColor::Reset), Color::Reset);
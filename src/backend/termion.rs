@@ -6,13 +6,16 @@
 //! [Termion]: https://docs.rs/termion
 use std::{
     fmt,
-    io::{self, Write},
+    io::{self, Read, Write},
+    ops::Range,
+    thread,
+    time::{Duration, Instant},
 };
 
 use termion::{color as tcolor, style as tstyle};
 
 use crate::{
-    backend::{Backend, ClearType, WindowSize},
+    backend::{parse_color_answer, Backend, ClearType, TerminalColor, WindowSize},
     buffer::Cell,
     prelude::Rect,
     style::{Color, Modifier, Style},
@@ -126,6 +129,32 @@ where
         self.writer.flush()
     }
 
+    fn scroll_region_up(&mut self, region: Range<u16>, line_count: u16) -> io::Result<()> {
+        if region.is_empty() || line_count == 0 {
+            return Ok(());
+        }
+        write!(
+            self.writer,
+            "{}{}{}",
+            SetScrollRegion(region),
+            termion::scroll::Up(line_count),
+            ResetScrollRegion
+        )
+    }
+
+    fn scroll_region_down(&mut self, region: Range<u16>, line_count: u16) -> io::Result<()> {
+        if region.is_empty() || line_count == 0 {
+            return Ok(());
+        }
+        write!(
+            self.writer,
+            "{}{}{}",
+            SetScrollRegion(region),
+            termion::scroll::Down(line_count),
+            ResetScrollRegion
+        )
+    }
+
     fn hide_cursor(&mut self) -> io::Result<()> {
         write!(self.writer, "{}", termion::cursor::Hide)?;
         self.writer.flush()
@@ -208,6 +237,31 @@ where
     fn flush(&mut self) -> io::Result<()> {
         self.writer.flush()
     }
+
+    fn query_color(
+        &mut self,
+        color: TerminalColor,
+        timeout: Duration,
+    ) -> io::Result<Option<Color>> {
+        let mut stdin = termion::async_stdin();
+        write!(self.writer, "{}", color.query())?;
+        self.writer.flush()?;
+        let start = Instant::now();
+        let mut answer = vec![];
+        let mut byte = [0];
+        while start.elapsed() < timeout {
+            if stdin.read(&mut byte)? == 0 {
+                thread::sleep(Duration::from_millis(1));
+                continue;
+            }
+            answer.push(byte[0]);
+            // the answer ends with BEL or ST
+            if byte[0] == b'\x07' || answer.ends_with(b"\x1b\\") {
+                return Ok(parse_color_answer(&answer));
+            }
+        }
+        Ok(None)
+    }
 }
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
 struct Fg(Color);
@@ -215,6 +269,27 @@ struct Fg(Color);
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
 struct Bg(Color);
 
+/// Sets the terminal scrolling region (DECSTBM) to the given range of rows.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct SetScrollRegion(Range<u16>);
+
+/// Resets the terminal scrolling region to the full screen.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+struct ResetScrollRegion;
+
+impl fmt::Display for SetScrollRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // DECSTBM uses 1-based inclusive row numbers
+        write!(f, "\x1b[{};{}r", self.0.start + 1, self.0.end)
+    }
+}
+
+impl fmt::Display for ResetScrollRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\x1b[r")
+    }
+}
+
 /// The `ModifierDiff` struct is used to calculate the difference between two `Modifier`
 /// values. This is useful when updating the terminal display, as it allows for more
 /// efficient updates by only sending the necessary changes.
@@ -447,6 +522,20 @@ mod tests {
     use super::*;
     use crate::style::Stylize;
 
+    #[test]
+    fn scroll_region_up() {
+        let mut backend = TermionBackend::new(Vec::new());
+        backend.scroll_region_up(2..10, 3).unwrap();
+        assert_eq!(backend.writer, b"\x1b[3;10r\x1b[3S\x1b[r");
+    }
+
+    #[test]
+    fn scroll_region_down() {
+        let mut backend = TermionBackend::new(Vec::new());
+        backend.scroll_region_down(0..5, 1).unwrap();
+        assert_eq!(backend.writer, b"\x1b[1;5r\x1b[1T\x1b[r");
+    }
+
     #[test]
     fn from_termion_color() {
         assert_eq!(Color::from(tcolor::Reset), Color::Reset);
@@ -5,7 +5,7 @@
 //! [`TermwizBackend`]: crate::backend::TermionBackend
 //! [Termwiz]: https://crates.io/crates/termwiz
 
-use std::{error::Error, io};
+use std::{error::Error, io, ops::Range};
 
 use termwiz::{
     caps::Capabilities,
@@ -213,6 +213,24 @@ impl Backend for TermwizBackend {
         Ok(())
     }
 
+    fn scroll_region_up(&mut self, region: Range<u16>, line_count: u16) -> io::Result<()> {
+        self.buffered_terminal.add_change(Change::ScrollRegionUp {
+            first_row: region.start as usize,
+            region_size: region.len(),
+            scroll_count: line_count as usize,
+        });
+        Ok(())
+    }
+
+    fn scroll_region_down(&mut self, region: Range<u16>, line_count: u16) -> io::Result<()> {
+        self.buffered_terminal.add_change(Change::ScrollRegionDown {
+            first_row: region.start as usize,
+            region_size: region.len(),
+            scroll_count: line_count as usize,
+        });
+        Ok(())
+    }
+
     fn size(&self) -> Result<Rect, io::Error> {
         let (cols, rows) = self.buffered_terminal.dimensions();
         Ok(Rect::new(0, 0, u16_max(cols), u16_max(rows)))
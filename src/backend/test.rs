@@ -4,14 +4,17 @@
 use std::{
     fmt::{Display, Write},
     io,
+    ops::Range,
+    time::Duration,
 };
 
 use unicode_width::UnicodeWidthStr;
 
 use crate::{
-    backend::{Backend, ClearType, WindowSize},
+    backend::{Backend, Capabilities, ClearType, TerminalColor, WindowSize},
     buffer::{Buffer, Cell},
     layout::{Rect, Size},
+    style::Color,
 };
 
 /// A [`Backend`] implementation used for integration testing that that renders to an in memory
@@ -40,6 +43,12 @@ pub struct TestBackend {
     height: u16,
     cursor: bool,
     pos: (u16, u16),
+    #[cfg_attr(feature = "serde", serde(default))]
+    foreground: Option<Color>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    background: Option<Color>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    capabilities: Capabilities,
 }
 
 /// Returns a string representation of the given buffer for debugging purpose.
@@ -80,6 +89,24 @@ impl TestBackend {
             buffer: Buffer::empty(Rect::new(0, 0, width, height)),
             cursor: false,
             pos: (0, 0),
+            foreground: None,
+            background: None,
+            capabilities: Capabilities::FULL,
+        }
+    }
+
+    /// Sets the capabilities returned by [`Backend::capabilities`], to test how the widgets fall
+    /// back on limited terminals. Defaults to [`Capabilities::FULL`].
+    pub fn set_capabilities(&mut self, capabilities: Capabilities) {
+        self.capabilities = capabilities;
+    }
+
+    /// Sets the color returned by [`Backend::query_color`] for the given default color, or
+    /// `None` to simulate a terminal that does not answer.
+    pub fn set_terminal_color(&mut self, color: TerminalColor, value: Option<Color>) {
+        match color {
+            TerminalColor::Foreground => self.foreground = value,
+            TerminalColor::Background => self.background = value,
         }
     }
 
@@ -95,6 +122,14 @@ impl TestBackend {
         self.height = height;
     }
 
+    /// Returns the cells of the rows in `region`, clamped to the height of the backend.
+    fn region_content(&mut self, region: Range<u16>) -> &mut [Cell] {
+        let width = self.width as usize;
+        let end = region.end.min(self.height) as usize;
+        let start = (region.start as usize).min(end);
+        &mut self.buffer.content[start * width..end * width]
+    }
+
     /// Asserts that the TestBackend's buffer is equal to the expected buffer.
     /// If the buffers are not equal, a panic occurs with a detailed error message
     /// showing the differences between the expected and actual buffers.
@@ -244,6 +279,25 @@ impl Backend for TestBackend {
         Ok(())
     }
 
+    fn scroll_region_up(&mut self, region: Range<u16>, line_count: u16) -> io::Result<()> {
+        let width = self.width as usize;
+        let rows = self.region_content(region);
+        let shift = (line_count as usize * width).min(rows.len());
+        rows.rotate_left(shift);
+        let len = rows.len();
+        rows[len - shift..].fill(Cell::default());
+        Ok(())
+    }
+
+    fn scroll_region_down(&mut self, region: Range<u16>, line_count: u16) -> io::Result<()> {
+        let width = self.width as usize;
+        let rows = self.region_content(region);
+        let shift = (line_count as usize * width).min(rows.len());
+        rows.rotate_right(shift);
+        rows[..shift].fill(Cell::default());
+        Ok(())
+    }
+
     fn size(&self) -> Result<Rect, io::Error> {
         Ok(Rect::new(0, 0, self.width, self.height))
     }
@@ -263,6 +317,21 @@ impl Backend for TestBackend {
     fn flush(&mut self) -> Result<(), io::Error> {
         Ok(())
     }
+
+    fn query_color(
+        &mut self,
+        color: TerminalColor,
+        _timeout: Duration,
+    ) -> io::Result<Option<Color>> {
+        Ok(match color {
+            TerminalColor::Foreground => self.foreground,
+            TerminalColor::Background => self.background,
+        })
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
 }
 
 #[cfg(test)]
@@ -279,6 +348,9 @@ mod tests {
                 buffer: Buffer::with_lines(vec!["          "; 2]),
                 cursor: false,
                 pos: (0, 0),
+                foreground: None,
+                background: None,
+                capabilities: Capabilities::FULL,
             }
         );
     }
@@ -670,6 +742,64 @@ mod tests {
         ]));
     }
 
+    #[test]
+    fn scroll_region_up() {
+        let mut backend = TestBackend::new(10, 5);
+        backend.buffer = Buffer::with_lines(vec![
+            "aaaaaaaaaa",
+            "bbbbbbbbbb",
+            "cccccccccc",
+            "dddddddddd",
+            "eeeeeeeeee",
+        ]);
+
+        backend.scroll_region_up(1..4, 2).unwrap();
+
+        backend.assert_buffer(&Buffer::with_lines(vec![
+            "aaaaaaaaaa",
+            "dddddddddd",
+            "          ",
+            "          ",
+            "eeeeeeeeee",
+        ]));
+    }
+
+    #[test]
+    fn scroll_region_down() {
+        let mut backend = TestBackend::new(10, 5);
+        backend.buffer = Buffer::with_lines(vec![
+            "aaaaaaaaaa",
+            "bbbbbbbbbb",
+            "cccccccccc",
+            "dddddddddd",
+            "eeeeeeeeee",
+        ]);
+
+        backend.scroll_region_down(0..4, 1).unwrap();
+
+        backend.assert_buffer(&Buffer::with_lines(vec![
+            "          ",
+            "aaaaaaaaaa",
+            "bbbbbbbbbb",
+            "cccccccccc",
+            "eeeeeeeeee",
+        ]));
+    }
+
+    #[test]
+    fn scroll_region_more_lines_than_region() {
+        let mut backend = TestBackend::new(10, 3);
+        backend.buffer = Buffer::with_lines(vec!["aaaaaaaaaa", "bbbbbbbbbb", "cccccccccc"]);
+
+        backend.scroll_region_up(0..2, 5).unwrap();
+
+        backend.assert_buffer(&Buffer::with_lines(vec![
+            "          ",
+            "          ",
+            "cccccccccc",
+        ]));
+    }
+
     #[test]
     fn size() {
         let backend = TestBackend::new(10, 2);
@@ -681,4 +811,23 @@ mod tests {
         let mut backend = TestBackend::new(10, 2);
         backend.flush().unwrap();
     }
+
+    #[test]
+    fn query_color() {
+        let mut backend = TestBackend::new(10, 2);
+        let timeout = Duration::ZERO;
+        backend.set_terminal_color(TerminalColor::Foreground, Some(Color::White));
+        assert_eq!(
+            backend
+                .query_color(TerminalColor::Foreground, timeout)
+                .unwrap(),
+            Some(Color::White)
+        );
+        assert_eq!(
+            backend
+                .query_color(TerminalColor::Background, timeout)
+                .unwrap(),
+            None
+        );
+    }
 }
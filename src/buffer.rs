@@ -4,12 +4,11 @@ use std::{
 };
 
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
 
 use crate::{
     layout::Rect,
     style::{Color, Modifier, Style},
-    text::{Line, Span},
+    text::{grapheme_width, Line, Span},
 };
 
 /// A buffer cell
@@ -29,6 +28,9 @@ pub struct Cell {
     pub underline_color: Color,
     pub modifier: Modifier,
     pub skip: bool,
+    /// Whether the cell is kept by [`Buffer::clear_unprotected`], and so by the
+    /// [`Clear`](crate::widgets::Clear) widget.
+    pub protected: bool,
 }
 
 #[allow(deprecated)] // For Cell::symbol
@@ -101,6 +103,17 @@ impl Cell {
         self
     }
 
+    /// Sets the cell to be kept when clearing an area of the buffer with
+    /// [`Buffer::clear_unprotected`], e.g. by the [`Clear`](crate::widgets::Clear) widget.
+    ///
+    /// This is helpful to draw persistent chrome, such as a status bar or the borders drawn by a
+    /// parent widget, that must survive the popups of its children. Like [`Cell::set_skip`], the
+    /// flag is removed when the cell is reset, so it is set again on each frame.
+    pub fn set_protected(&mut self, protected: bool) -> &mut Cell {
+        self.protected = protected;
+        self
+    }
+
     pub fn reset(&mut self) {
         self.symbol.clear();
         self.symbol.push(' ');
@@ -112,6 +125,7 @@ impl Cell {
         }
         self.modifier = Modifier::empty();
         self.skip = false;
+        self.protected = false;
     }
 }
 
@@ -126,6 +140,7 @@ impl Default for Cell {
             underline_color: Color::Reset,
             modifier: Modifier::empty(),
             skip: false,
+            protected: false,
         }
     }
 }
@@ -304,6 +319,139 @@ impl Buffer {
         )
     }
 
+    /// Returns an iterator over the cells of the buffer with their (global) coordinates, row by
+    /// row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// let buffer = Buffer::with_lines(vec!["ab", "cd"]);
+    /// let cells: Vec<_> = buffer.cells().map(|(x, y, cell)| (x, y, cell.symbol())).collect();
+    /// assert_eq!(cells, [(0, 0, "a"), (1, 0, "b"), (0, 1, "c"), (1, 1, "d")]);
+    /// ```
+    pub fn cells(&self) -> impl Iterator<Item = (u16, u16, &Cell)> + '_ {
+        self.rows().flat_map(move |(y, row)| {
+            row.iter()
+                .zip(self.area.left()..)
+                .map(move |(cell, x)| (x, y, cell))
+        })
+    }
+
+    /// Returns an iterator over mutable references to the cells of the buffer with their
+    /// (global) coordinates, row by row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// let mut buffer = Buffer::with_lines(vec!["ab", "cd"]);
+    /// for (x, y, cell) in buffer.cells_mut() {
+    ///     if x == y {
+    ///         cell.set_char('x');
+    ///     }
+    /// }
+    /// assert_eq!(buffer, Buffer::with_lines(vec!["xb", "cx"]));
+    /// ```
+    pub fn cells_mut(&mut self) -> impl Iterator<Item = (u16, u16, &mut Cell)> + '_ {
+        let left = self.area.left();
+        self.rows_mut().flat_map(move |(y, row)| {
+            row.iter_mut()
+                .zip(left..)
+                .map(move |(cell, x)| (x, y, cell))
+        })
+    }
+
+    /// Returns an iterator over the cells of the given area with their (global) coordinates, row
+    /// by row.
+    ///
+    /// The part of the area outside of the buffer is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// let buffer = Buffer::with_lines(vec!["abc", "def"]);
+    /// let symbols: String = buffer
+    ///     .area_cells(Rect::new(1, 0, 5, 5))
+    ///     .map(|(_, _, cell)| cell.symbol())
+    ///     .collect();
+    /// assert_eq!(symbols, "bcef");
+    /// ```
+    pub fn area_cells(&self, area: Rect) -> impl Iterator<Item = (u16, u16, &Cell)> + '_ {
+        let area = area.intersection(self.area);
+        (area.top()..area.bottom()).flat_map(move |y| {
+            let start = self.index_of(area.left(), y);
+            let end = start + usize::from(area.width);
+            self.content[start..end]
+                .iter()
+                .zip(area.left()..)
+                .map(move |(cell, x)| (x, y, cell))
+        })
+    }
+
+    /// Returns an iterator over the rows of the buffer with their (global) `y` coordinate.
+    pub fn rows(&self) -> impl Iterator<Item = (u16, &[Cell])> + '_ {
+        // a buffer without columns has no content: any chunk size yields no rows
+        let width = usize::from(self.area.width).max(1);
+        self.content
+            .chunks(width)
+            .zip(self.area.top()..)
+            .map(|(row, y)| (y, row))
+    }
+
+    /// Returns an iterator over the mutable rows of the buffer with their (global) `y`
+    /// coordinate.
+    ///
+    /// The rows are disjoint slices, so they can be processed in parallel, e.g. with
+    /// [`std::thread::scope`] or by bridging the iterator to a thread pool.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = (u16, &mut [Cell])> + '_ {
+        let width = usize::from(self.area.width).max(1);
+        self.content
+            .chunks_mut(width)
+            .zip(self.area.top()..)
+            .map(|(row, y)| (y, row))
+    }
+
+    /// Returns an iterator over chunks of up to `rows` consecutive mutable rows of the buffer,
+    /// with the area each chunk covers.
+    ///
+    /// This splits the buffer in a few large disjoint slices to process in parallel, which
+    /// balances the work better than [`Buffer::rows_mut`] when each row is cheap to process.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+    /// std::thread::scope(|scope| {
+    ///     for (area, cells) in buffer.row_chunks_mut(2) {
+    ///         scope.spawn(move || {
+    ///             for cell in cells {
+    ///                 cell.set_char(char::from(b'0' + area.y as u8));
+    ///             }
+    ///         });
+    ///     }
+    /// });
+    /// assert_eq!(buffer.get(9, 3).symbol(), "2");
+    /// ```
+    pub fn row_chunks_mut(&mut self, rows: u16) -> impl Iterator<Item = (Rect, &mut [Cell])> + '_ {
+        assert!(rows > 0, "a chunk should have at least one row");
+        let area = self.area;
+        let width = usize::from(area.width).max(1);
+        self.content
+            .chunks_mut(width * usize::from(rows))
+            .zip((area.top()..area.bottom()).step_by(usize::from(rows)))
+            .map(move |(cells, y)| {
+                let height = (cells.len() / width) as u16;
+                (Rect::new(area.x, y, area.width, height), cells)
+            })
+    }
+
     /// Print a string, starting at the position (x, y)
     pub fn set_string<S>(&mut self, x: u16, y: u16, string: S, style: Style)
     where
@@ -330,7 +478,7 @@ impl Buffer {
         let graphemes = UnicodeSegmentation::graphemes(string.as_ref(), true);
         let max_offset = min(self.area.right() as usize, width.saturating_add(x as usize));
         for s in graphemes {
-            let width = s.width();
+            let width = grapheme_width(s);
             if width == 0 {
                 continue;
             }
@@ -385,6 +533,133 @@ impl Buffer {
         }
     }
 
+    /// Patches the styles of several areas, as if calling [`Buffer::set_style`] for each of them
+    /// in order, but touching each cell once.
+    ///
+    /// The buffer is split into the bands of rows and the spans of columns where the same areas
+    /// overlap, and the styles of the overlapping areas are merged with [`Style::patch`] before
+    /// being applied to the cells. This is much cheaper than setting the styles one after the
+    /// other when many areas overlap, e.g. for nested containers each styling their whole area.
+    /// The areas are clipped to the buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{buffer::Buffer, prelude::*};
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+    /// buffer.set_styles(&[
+    ///     (Rect::new(0, 0, 3, 2), Style::new().fg(Color::Red)),
+    ///     (Rect::new(1, 0, 1, 2), Style::new().bg(Color::Blue)),
+    /// ]);
+    /// assert_eq!(buffer.get(0, 0).bg, Color::Reset);
+    /// assert_eq!(buffer.get(1, 1).fg, Color::Red);
+    /// assert_eq!(buffer.get(1, 1).bg, Color::Blue);
+    /// ```
+    pub fn set_styles(&mut self, styles: &[(Rect, Style)]) {
+        let regions = styles
+            .iter()
+            .map(|&(area, style)| (area.intersection(self.area), style))
+            .filter(|(area, _)| !area.is_empty())
+            .collect::<Vec<_>>();
+        // the regions covering a span are tracked with the bits of a mask, so they are merged 64
+        // at a time
+        for regions in regions.chunks(64) {
+            self.set_region_styles(regions);
+        }
+    }
+
+    /// Patches the styles of up to 64 regions inside the buffer, see [`Buffer::set_styles`].
+    fn set_region_styles(&mut self, regions: &[(Rect, Style)]) {
+        let boundaries = |edges: fn(&Rect) -> [u16; 2]| {
+            let mut boundaries = regions
+                .iter()
+                .flat_map(|(area, _)| edges(area))
+                .collect::<Vec<_>>();
+            boundaries.sort_unstable();
+            boundaries.dedup();
+            boundaries
+        };
+        let rows = boundaries(|area| [area.top(), area.bottom()]);
+        let columns = boundaries(|area| [area.left(), area.right()]);
+        // bit `i` of the mask of a span of columns is set when region `i` covers it
+        let mut span_masks = vec![0u64; columns.len().saturating_sub(1)];
+        for (i, (area, _)) in regions.iter().enumerate() {
+            let start = columns.partition_point(|&x| x < area.left());
+            let end = columns.partition_point(|&x| x < area.right());
+            for mask in &mut span_masks[start..end] {
+                *mask |= 1 << i;
+            }
+        }
+        // the styles of the few distinct combinations of regions in a band are merged once
+        let mut merged = Vec::<(u64, Style)>::new();
+        for band in rows.windows(2) {
+            let (top, bottom) = (band[0], band[1]);
+            let band_mask = regions
+                .iter()
+                .enumerate()
+                .filter(|(_, (area, _))| area.top() <= top && area.bottom() >= bottom)
+                .fold(0u64, |mask, (i, _)| mask | 1 << i);
+            merged.clear();
+            for (i, &span_mask) in span_masks.iter().enumerate() {
+                let mask = band_mask & span_mask;
+                if mask == 0 {
+                    continue;
+                }
+                let style = match merged.iter().find(|(m, _)| *m == mask) {
+                    Some(&(_, style)) => style,
+                    None => {
+                        let mut style = Style::default();
+                        let mut bits = mask;
+                        while bits != 0 {
+                            style = style.patch(regions[bits.trailing_zeros() as usize].1);
+                            bits &= bits - 1;
+                        }
+                        merged.push((mask, style));
+                        style
+                    }
+                };
+                let (left, right) = (columns[i], columns[i + 1]);
+                for y in top..bottom {
+                    let start = self.index_of(left, y);
+                    let end = start + usize::from(right - left);
+                    for cell in &mut self.content[start..end] {
+                        cell.set_style(style);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Sets the symbol and patches the style of every cell in the area, clipped to the buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{buffer::Buffer, prelude::*};
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+    /// buffer.fill(Rect::new(1, 0, 5, 1), "#", Style::new().fg(Color::Red));
+    /// assert_eq!(buffer, {
+    ///     let mut expected = Buffer::with_lines(vec![" ###", "    "]);
+    ///     expected.set_style(Rect::new(1, 0, 3, 1), Style::new().fg(Color::Red));
+    ///     expected
+    /// });
+    /// ```
+    pub fn fill(&mut self, area: Rect, symbol: &str, style: Style) {
+        let area = area.intersection(self.area);
+        if area.is_empty() {
+            return;
+        }
+        for y in area.top()..area.bottom() {
+            let start = self.index_of(area.left(), y);
+            let end = start + usize::from(area.width);
+            for cell in &mut self.content[start..end] {
+                cell.set_symbol(symbol).set_style(style);
+            }
+        }
+    }
+
     /// Resize the buffer so that the mapped area matches the given area and that the buffer
     /// length is equal to area.width * area.height
     pub fn resize(&mut self, area: Rect) {
@@ -404,6 +679,48 @@ impl Buffer {
         }
     }
 
+    /// Marks the cells of an area as protected, so that they are kept by
+    /// [`Buffer::clear_unprotected`].
+    ///
+    /// The part of the area outside of the buffer is ignored. See [`Cell::set_protected`].
+    pub fn protect(&mut self, area: Rect) {
+        let area = area.intersection(self.area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                self.get_mut(x, y).set_protected(true);
+            }
+        }
+    }
+
+    /// Resets the cells of an area, except the protected ones.
+    ///
+    /// The part of the area outside of the buffer is ignored. See [`Cell::set_protected`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// let mut buf = Buffer::with_lines(vec!["status", "xxxxxx"]);
+    /// buf.protect(Rect::new(0, 0, 6, 1));
+    /// buf.clear_unprotected(buf.area);
+    /// assert_eq!(buf, {
+    ///     let mut expected = Buffer::with_lines(vec!["status", "      "]);
+    ///     expected.protect(Rect::new(0, 0, 6, 1));
+    ///     expected
+    /// });
+    /// ```
+    pub fn clear_unprotected(&mut self, area: Rect) {
+        let area = area.intersection(self.area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let cell = self.get_mut(x, y);
+                if !cell.protected {
+                    cell.reset();
+                }
+            }
+        }
+    }
+
     /// Merge an other buffer into this one
     pub fn merge(&mut self, other: &Buffer) {
         let area = self.area.union(other.area);
@@ -478,9 +795,12 @@ impl Buffer {
                 updates.push((x, y, &next_buffer[i]));
             }
 
-            to_skip = current.symbol().width().saturating_sub(1);
+            to_skip = grapheme_width(current.symbol()).saturating_sub(1);
 
-            let affected_width = std::cmp::max(current.symbol().width(), previous.symbol().width());
+            let affected_width = std::cmp::max(
+                grapheme_width(current.symbol()),
+                grapheme_width(previous.symbol()),
+            );
             invalidated = std::cmp::max(affected_width, invalidated).saturating_sub(1);
         }
         updates
@@ -566,7 +886,7 @@ impl Debug for Buffer {
                 } else {
                     overwritten.push((x, c.symbol()));
                 }
-                skip = std::cmp::max(skip, c.symbol().width()).saturating_sub(1);
+                skip = std::cmp::max(skip, grapheme_width(c.symbol())).saturating_sub(1);
                 #[cfg(feature = "underline-color")]
                 {
                     let style = (c.fg, c.bg, c.underline_color, c.modifier);
@@ -619,6 +939,44 @@ mod tests {
         cell
     }
 
+    #[test]
+    fn set_styles_matches_set_style_in_order() {
+        let area = Rect::new(2, 1, 8, 5);
+        let styles = [
+            (Rect::new(0, 0, 20, 20), Style::new().fg(Color::Red)),
+            (Rect::new(3, 2, 4, 2), Style::new().bg(Color::Blue)),
+            (
+                Rect::new(4, 1, 2, 4),
+                Style::new().add_modifier(Modifier::BOLD | Modifier::ITALIC),
+            ),
+            (
+                Rect::new(5, 3, 10, 1),
+                Style::new()
+                    .fg(Color::Green)
+                    .remove_modifier(Modifier::BOLD),
+            ),
+            (Rect::new(30, 30, 2, 2), Style::new().bg(Color::Yellow)),
+        ];
+        let mut expected = Buffer::empty(area);
+        for (rect, style) in styles {
+            expected.set_style(rect.intersection(area), style);
+        }
+        let mut buffer = Buffer::empty(area);
+        buffer.set_styles(&styles);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn fill() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        let style = Style::new().add_modifier(Modifier::BOLD);
+        buffer.fill(Rect::new(2, 1, 4, 4), "x", style);
+        buffer.fill(Rect::new(8, 8, 2, 2), "y", style);
+        let mut expected = Buffer::with_lines(vec!["    ", "  xx", "  xx"]);
+        expected.set_style(Rect::new(2, 1, 2, 2), style);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn it_implements_debug() {
         let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
@@ -708,6 +1066,37 @@ mod tests {
         assert_eq!(buf.index_of(249, 179), buf.content.len() - 1);
     }
 
+    #[test]
+    fn iterates_cells_with_positions() {
+        let mut buf = Buffer::empty(Rect::new(10, 20, 3, 5));
+        let positions: Vec<_> = buf.cells().map(|(x, y, _)| (x, y)).take(4).collect();
+        assert_eq!(positions, [(10, 20), (11, 20), (12, 20), (10, 21)]);
+        assert_eq!(buf.cells_mut().count(), 15);
+
+        let clipped: Vec<_> = buf
+            .area_cells(Rect::new(12, 23, 10, 10))
+            .map(|(x, y, _)| (x, y))
+            .collect();
+        assert_eq!(clipped, [(12, 23), (12, 24)]);
+        assert_eq!(buf.area_cells(Rect::new(0, 0, 5, 5)).count(), 0);
+
+        let rows: Vec<_> = buf.rows().map(|(y, row)| (y, row.len())).collect();
+        assert_eq!(rows, [(20, 3), (21, 3), (22, 3), (23, 3), (24, 3)]);
+        let chunks: Vec<_> = buf
+            .row_chunks_mut(2)
+            .map(|(area, cells)| (area, cells.len()))
+            .collect();
+        assert_eq!(
+            chunks,
+            [
+                (Rect::new(10, 20, 3, 2), 6),
+                (Rect::new(10, 22, 3, 2), 6),
+                (Rect::new(10, 24, 3, 1), 3),
+            ]
+        );
+        assert_eq!(Buffer::empty(Rect::new(0, 0, 0, 3)).rows().count(), 0);
+    }
+
     #[test]
     #[should_panic(expected = "outside the buffer")]
     fn pos_of_panics_on_out_of_bounds() {
@@ -1051,6 +1440,22 @@ mod tests {
         assert_eq!(buf, Buffer::with_lines(vec!["foo".red(), "bar".blue()]));
     }
 
+    #[test]
+    fn clear_unprotected() {
+        let mut buf = Buffer::with_lines(vec!["abc", "def", "ghi"]);
+        buf.protect(Rect::new(1, 0, 5, 5));
+        assert!(buf.get(1, 2).protected);
+        assert!(!buf.get(0, 2).protected);
+
+        // the part of the area outside of the buffer is ignored
+        buf.clear_unprotected(Rect::new(0, 1, 10, 10));
+        let symbols: Vec<&str> = buf.content().iter().map(Cell::symbol).collect();
+        assert_eq!(symbols, vec!["a", "b", "c", " ", "e", "f", " ", "h", "i"]);
+
+        buf.reset();
+        assert!(buf.content().iter().all(|cell| !cell.protected));
+    }
+
     #[test]
     fn cell_symbol_field() {
         let mut cell = Cell::default();
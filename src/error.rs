@@ -0,0 +1,109 @@
+#![deny(missing_docs)]
+//! Provides the [`Error`] type returned by the fallible operations of this crate.
+//!
+//! The operations of the [`Terminal`] fail when the backend fails to read from or write to the
+//! terminal, and the `try_*` builders of the widgets (e.g. [`Gauge::try_percent`]) fail when
+//! given a value out of their range instead of panicking. Both return an [`Error`], so that an
+//! application that must keep running (e.g. a daemon showing a dashboard) can handle every
+//! failure in one place.
+//!
+//! The [`Error`] converts into an [`io::Error`], so that `?` keeps working in functions returning
+//! an [`io::Result`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::{widgets::Gauge, Error};
+//!
+//! fn gauge(done: u16) -> ratatui::Result<Gauge<'static>> {
+//!     Gauge::default().try_percent(done)
+//! }
+//!
+//! assert!(gauge(42).is_ok());
+//! assert!(matches!(gauge(142), Err(Error::OutOfRange { .. })));
+//! ```
+//!
+//! [`Terminal`]: crate::Terminal
+//! [`Gauge::try_percent`]: crate::widgets::Gauge::try_percent
+use std::{fmt, io};
+
+/// A specialized [`Result`](std::result::Result) type for the fallible operations of this crate.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// The error returned by the fallible operations of this crate.
+///
+/// See the [module documentation](self) for the operations that can fail.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The backend failed to read from or write to the terminal.
+    Io(io::Error),
+    /// A value given to a widget is out of the range it accepts, e.g. a percentage above 100.
+    OutOfRange {
+        /// The name of the value, e.g. `percent`.
+        name: &'static str,
+        /// The range of the accepted values, e.g. `0..=100`.
+        range: &'static str,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "terminal I/O failed: {err}"),
+            Error::OutOfRange { name, range } => {
+                write!(f, "{name} should be in the range {range}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::OutOfRange { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<Error> for io::Error {
+    /// Returns the underlying I/O error, or an error of kind [`io::ErrorKind::InvalidInput`] for
+    /// a value out of range.
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Io(err) => err,
+            err @ Error::OutOfRange { .. } => io::Error::new(io::ErrorKind::InvalidInput, err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+
+    use super::*;
+
+    #[test]
+    fn converts_to_and_from_io_errors() {
+        let err = Error::from(io::Error::new(io::ErrorKind::BrokenPipe, "closed"));
+        assert_eq!(err.to_string(), "terminal I/O failed: closed");
+        assert!(err.source().is_some());
+        assert_eq!(io::Error::from(err).kind(), io::ErrorKind::BrokenPipe);
+
+        let err = Error::OutOfRange {
+            name: "percent",
+            range: "0..=100",
+        };
+        assert_eq!(err.to_string(), "percent should be in the range 0..=100");
+        let err = io::Error::from(err);
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(err.to_string(), "percent should be in the range 0..=100");
+    }
+}
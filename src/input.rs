@@ -0,0 +1,536 @@
+#![warn(missing_docs)]
+//! This module provides backend agnostic input events.
+//!
+//! Every terminal library has its own representation of key presses, mouse actions and other
+//! input. Code that handles input (widgets, keymaps, applications) should not have to depend on a
+//! specific backend, so this module defines an [`Event`] type owned by Ratatui along with
+//! conversions from the event types of each supported terminal library:
+//! - [Crossterm]: enable the `crossterm` feature (enabled by default) to convert from
+//!   `crossterm::event::Event` and its key and mouse types.
+//! - [Termion]: enable the `termion` feature to convert from `termion::event::Event` and its key
+//!   and mouse types.
+//! - [Termwiz]: enable the `termwiz` feature to convert from `termwiz::input::InputEvent` and its
+//!   key and mouse types.
+//!
+//! When the `crossterm` feature is enabled, the [`read`] and [`poll`] functions can be used to
+//! read events directly from the terminal as [`Event`]s.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::input::{Event, Key, KeyEvent, KeyModifiers};
+//!
+//! fn handle_event(event: Event) -> bool {
+//!     match event {
+//!         Event::Key(KeyEvent {
+//!             key: Key::Char('q'),
+//!             ..
+//!         }) => true,
+//!         Event::Key(key) if key.key == Key::Char('c') && key.modifiers == KeyModifiers::CONTROL => {
+//!             true
+//!         }
+//!         _ => false,
+//!     }
+//! }
+//!
+//! assert!(handle_event(Event::Key(KeyEvent::from(Key::Char('q')))));
+//! ```
+//!
+//! [Crossterm]: https://crates.io/crates/crossterm
+//! [Termion]: https://crates.io/crates/termion
+//! [Termwiz]: https://crates.io/crates/termwiz
+use std::fmt;
+
+use bitflags::bitflags;
+use strum::Display;
+
+use crate::layout::{Position, Size};
+
+mod focus;
+mod gesture;
+mod keymap;
+mod kill_ring;
+mod processor;
+mod scroll;
+mod throttle;
+mod validator;
+#[cfg(feature = "regex")]
+pub use self::validator::Matches;
+pub use self::{
+    focus::{FocusDirection, FocusManager, Focusable},
+    gesture::{Gesture, GestureDetector, GestureKind},
+    keymap::{EditCommand, Keymap},
+    kill_ring::KillRing,
+    processor::{EventProcessor, ProcessedEvent},
+    scroll::{Scroll, ScrollProcessor},
+    throttle::{EventThrottle, ThrottledEvent},
+    validator::{Float, Integer, MaxLength, NonEmpty, Validator},
+};
+
+#[cfg(feature = "crossterm")]
+mod crossterm;
+#[cfg(feature = "crossterm")]
+pub use self::crossterm::{poll, read};
+
+#[cfg(feature = "termion")]
+mod termion;
+
+#[cfg(feature = "termwiz")]
+mod termwiz;
+
+/// An input event received from the terminal.
+///
+/// Events are usually obtained by converting the events of the terminal library used by the
+/// application with [`From`] / [`Into`], or by calling [`read`] when the `crossterm` feature is
+/// enabled.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Event {
+    /// A key was pressed, repeated or released.
+    Key(KeyEvent),
+    /// A mouse button was pressed / released, the mouse moved or the wheel was scrolled.
+    Mouse(MouseEvent),
+    /// The terminal was resized to the given size, in columns and rows.
+    Resize(Size),
+    /// Text was pasted into the terminal (requires bracketed paste to be enabled).
+    ///
+    /// Without bracketed paste, the terminal replays the pasted text as key events: a line
+    /// break then submits a single line input, and each character is a separate edit. With
+    /// crossterm, bracketed paste is enabled by executing `crossterm::event::EnableBracketedPaste`.
+    /// Termion does not report pastes.
+    Paste(String),
+    /// The terminal gained focus.
+    FocusGained,
+    /// The terminal lost focus.
+    FocusLost,
+    /// An event that has no representation in this crate.
+    ///
+    /// This is produced when converting events that are specific to a terminal library (e.g.
+    /// unsupported escape sequences reported by Termion).
+    Unknown,
+}
+
+/// A key event.
+///
+/// Key events are made of the [`Key`] that was pressed, the [`KeyModifiers`] that were held down
+/// at the time and the [`KeyEventKind`] of the event.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::input::{Key, KeyEvent, KeyEventKind, KeyModifiers};
+///
+/// let event = KeyEvent::new(Key::Char('s'), KeyModifiers::CONTROL);
+/// assert_eq!(event.kind, KeyEventKind::Press);
+/// assert_eq!(KeyEvent::from(Key::Enter).modifiers, KeyModifiers::NONE);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyEvent {
+    /// The key that was pressed.
+    pub key: Key,
+    /// The modifiers that were held down when the key was pressed.
+    pub modifiers: KeyModifiers,
+    /// Whether the key was pressed, repeated or released.
+    pub kind: KeyEventKind,
+}
+
+impl KeyEvent {
+    /// Creates a new key press event with the given key and modifiers.
+    pub const fn new(key: Key, modifiers: KeyModifiers) -> Self {
+        Self {
+            key,
+            modifiers,
+            kind: KeyEventKind::Press,
+        }
+    }
+
+    /// Sets the kind of the key event.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn kind(mut self, kind: KeyEventKind) -> Self {
+        self.kind = kind;
+        self
+    }
+}
+
+/// Formats the key event as a human readable key chord, e.g. `Ctrl+Shift+P`.
+///
+/// The modifiers come first, in the `Ctrl`, `Alt`, `Shift`, `Super` order, and the kind of the
+/// event is ignored. Letters are shown in uppercase, so an uppercase letter reported without the
+/// [`KeyModifiers::SHIFT`] modifier is shown with `Shift`, and [`Key::BackTab`] is shown as
+/// `Shift+Tab`.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::input::{Key, KeyEvent, KeyModifiers};
+///
+/// let event = KeyEvent::new(Key::Char('p'), KeyModifiers::CONTROL | KeyModifiers::SHIFT);
+/// assert_eq!(event.to_string(), "Ctrl+Shift+P");
+/// assert_eq!(KeyEvent::from(Key::Char('A')).to_string(), "Shift+A");
+/// assert_eq!(KeyEvent::from(Key::F(5)).to_string(), "F5");
+/// ```
+impl fmt::Display for KeyEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut modifiers = self.modifiers;
+        let key = match self.key {
+            Key::Char(c) if c.is_uppercase() => {
+                modifiers |= KeyModifiers::SHIFT;
+                Key::Char(c)
+            }
+            Key::Char(c) if c.is_lowercase() => Key::Char(c.to_uppercase().next().unwrap_or(c)),
+            Key::BackTab => {
+                modifiers |= KeyModifiers::SHIFT;
+                Key::Tab
+            }
+            key => key,
+        };
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "Ctrl"),
+            (KeyModifiers::ALT, "Alt"),
+            (KeyModifiers::SHIFT, "Shift"),
+            (KeyModifiers::SUPER, "Super"),
+        ] {
+            if modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+        write!(f, "{key}")
+    }
+}
+
+impl From<Key> for KeyEvent {
+    fn from(key: Key) -> Self {
+        Self::new(key, KeyModifiers::NONE)
+    }
+}
+
+/// A key on the keyboard.
+///
+/// Characters are reported as typed, so `Shift+a` is usually reported as `Key::Char('A')` (with
+/// or without the [`KeyModifiers::SHIFT`] modifier depending on the terminal library).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Key {
+    /// A character key.
+    Char(char),
+    /// Backspace key.
+    Backspace,
+    /// Enter key.
+    Enter,
+    /// Left arrow key.
+    Left,
+    /// Right arrow key.
+    Right,
+    /// Up arrow key.
+    Up,
+    /// Down arrow key.
+    Down,
+    /// Home key.
+    Home,
+    /// End key.
+    End,
+    /// Page up key.
+    PageUp,
+    /// Page down key.
+    PageDown,
+    /// Tab key.
+    Tab,
+    /// Shift + Tab key.
+    BackTab,
+    /// Delete key.
+    Delete,
+    /// Insert key.
+    Insert,
+    /// Function key, e.g. `F(1)` for F1.
+    F(u8),
+    /// Escape key.
+    Esc,
+    /// The null key, or a key that has no representation in this crate (e.g. media keys).
+    Null,
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Key::Char(' ') => write!(f, "Space"),
+            Key::Char(c) => write!(f, "{c}"),
+            Key::F(n) => write!(f, "F{n}"),
+            key => fmt::Debug::fmt(key, f),
+        }
+    }
+}
+
+/// The kind of a [`KeyEvent`].
+///
+/// Most terminals only report key presses. Release and repeat events are only reported by
+/// terminals that support an enhanced keyboard protocol (e.g. the kitty keyboard protocol with
+/// Crossterm).
+#[derive(Debug, Default, Display, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyEventKind {
+    /// The key was pressed.
+    #[default]
+    Press,
+    /// The key is held down and the press was repeated.
+    Repeat,
+    /// The key was released.
+    Release,
+}
+
+bitflags! {
+    /// The modifier keys held down during a key or mouse event.
+    ///
+    /// They are bitflags so they can easily be composed.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// use ratatui::input::KeyModifiers;
+    ///
+    /// let m = KeyModifiers::CONTROL | KeyModifiers::SHIFT;
+    /// ```
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Default, Clone, Copy, Eq, PartialEq, Hash)]
+    pub struct KeyModifiers: u8 {
+        /// No modifier
+        const NONE    = 0b0000;
+        /// The shift key
+        const SHIFT   = 0b0001;
+        /// The control key
+        const CONTROL = 0b0010;
+        /// The alt (option) key
+        const ALT     = 0b0100;
+        /// The super (windows / command) key
+        const SUPER   = 0b1000;
+    }
+}
+
+/// Implement the `Debug` trait for `KeyModifiers` manually.
+///
+/// This will avoid printing the empty modifiers as 'KeyModifiers(0x0)' and instead print it as
+/// 'NONE'.
+impl fmt::Debug for KeyModifiers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "NONE");
+        }
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+/// A mouse event.
+///
+/// The position of the event is given in zero based terminal cells, with the origin (0, 0) at the
+/// top left corner of the screen.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MouseEvent {
+    /// What happened.
+    pub kind: MouseEventKind,
+    /// The column of the mouse cursor.
+    pub column: u16,
+    /// The row of the mouse cursor.
+    pub row: u16,
+    /// The modifiers that were held down during the event.
+    pub modifiers: KeyModifiers,
+}
+
+impl MouseEvent {
+    /// Creates a new mouse event of the given kind at the given position without modifiers.
+    pub const fn new(kind: MouseEventKind, column: u16, row: u16) -> Self {
+        Self {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    /// Returns the position of the mouse cursor, e.g. to hit test it with [`Rect::contains`].
+    ///
+    /// [`Rect::contains`]: crate::layout::Rect::contains
+    pub const fn position(&self) -> Position {
+        Position::new(self.column, self.row)
+    }
+}
+
+/// The kind of a [`MouseEvent`].
+#[derive(Debug, Display, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MouseEventKind {
+    /// A mouse button was pressed.
+    Down(MouseButton),
+    /// A mouse button was released.
+    Up(MouseButton),
+    /// The mouse moved while a button was held down.
+    Drag(MouseButton),
+    /// The mouse moved without any button held down.
+    Moved,
+    /// The mouse wheel was scrolled down (towards the user).
+    ScrollDown,
+    /// The mouse wheel was scrolled up (away from the user).
+    ScrollUp,
+    /// The mouse wheel was scrolled left.
+    ScrollLeft,
+    /// The mouse wheel was scrolled right.
+    ScrollRight,
+}
+
+/// A mouse button.
+#[derive(Debug, Display, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MouseButton {
+    /// The left mouse button.
+    Left,
+    /// The right mouse button.
+    Right,
+    /// The middle mouse button.
+    Middle,
+}
+
+/// A state that can be edited with input events, like the state of a text input.
+///
+/// Widgets implement this trait on their state so that applications can forward the events of
+/// any backend to them, after converting them to this module's [`Event`] type. The states of the
+/// widgets of this crate request a redraw with [`RedrawRequest`] when they handle an event.
+///
+/// [`RedrawRequest`]: crate::terminal::RedrawRequest
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     input::{Input, Key, KeyEvent},
+///     widgets::TextInputState,
+/// };
+///
+/// let mut state = TextInputState::default();
+/// assert!(state.handle_key(KeyEvent::from(Key::Char('a'))));
+/// assert!(state.handle_key(KeyEvent::from(Key::Left)));
+/// assert!(!state.handle_key(KeyEvent::from(Key::F(1))));
+/// assert_eq!(state.value(), "a");
+/// ```
+pub trait Input {
+    /// Handles a key event. Returns `true` if the event was handled, or `false` if the
+    /// application should handle it (e.g. `Enter` in a single line input).
+    ///
+    /// Key release events are ignored.
+    fn handle_key(&mut self, key: KeyEvent) -> bool;
+
+    /// Handles pasted text. Returns `true` if the text was handled.
+    ///
+    /// The default implementation ignores the text.
+    fn handle_paste(&mut self, text: &str) -> bool {
+        let _ = text;
+        false
+    }
+
+    /// Handles the text being composed with an input method (IME), or `None` when the
+    /// composition ends. Returns `true` if the text is displayed.
+    ///
+    /// Terminals display the composed text themselves, at the position of the terminal cursor,
+    /// so this is only needed when the application receives the composition from elsewhere, e.g.
+    /// an embedding GUI. The committed text is then delivered as key or paste events.
+    ///
+    /// The default implementation ignores the text.
+    fn handle_preedit(&mut self, text: Option<&str>) -> bool {
+        let _ = text;
+        false
+    }
+
+    /// Handles a mouse event. Returns `true` if the event was handled.
+    ///
+    /// The position of the event is compared to the area of the widget during its last render,
+    /// e.g. to move the cursor of a text input to the clicked cell.
+    ///
+    /// The default implementation ignores the event.
+    fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        let _ = mouse;
+        false
+    }
+
+    /// Handles an event, dispatching key, mouse and paste events to
+    /// [`handle_key`](Self::handle_key), [`handle_mouse`](Self::handle_mouse) and
+    /// [`handle_paste`](Self::handle_paste). Returns `true` if the event was handled.
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Key(key) => self.handle_key(*key),
+            Event::Mouse(mouse) => self.handle_mouse(*mouse),
+            Event::Paste(text) => self.handle_paste(text),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_event_new() {
+        let event = KeyEvent::new(Key::Char('a'), KeyModifiers::ALT);
+        assert_eq!(
+            event,
+            KeyEvent {
+                key: Key::Char('a'),
+                modifiers: KeyModifiers::ALT,
+                kind: KeyEventKind::Press,
+            }
+        );
+        assert_eq!(
+            event.kind(KeyEventKind::Release).kind,
+            KeyEventKind::Release
+        );
+    }
+
+    #[test]
+    fn key_event_from_key() {
+        assert_eq!(
+            KeyEvent::from(Key::Esc),
+            KeyEvent::new(Key::Esc, KeyModifiers::NONE)
+        );
+    }
+
+    #[test]
+    fn key_event_to_string() {
+        const CTRL: KeyModifiers = KeyModifiers::CONTROL;
+        const SHIFT: KeyModifiers = KeyModifiers::SHIFT;
+        let chord = |key, modifiers| KeyEvent::new(key, modifiers).to_string();
+        assert_eq!(chord(Key::Char('a'), KeyModifiers::NONE), "A");
+        assert_eq!(chord(Key::Char('A'), SHIFT), "Shift+A");
+        assert_eq!(chord(Key::Char('P'), CTRL), "Ctrl+Shift+P");
+        assert_eq!(chord(Key::Char('1'), KeyModifiers::ALT), "Alt+1");
+        assert_eq!(chord(Key::Char(' '), CTRL), "Ctrl+Space");
+        assert_eq!(chord(Key::BackTab, SHIFT), "Shift+Tab");
+        assert_eq!(
+            chord(Key::Left, KeyModifiers::all().difference(SHIFT)),
+            "Ctrl+Alt+Super+Left"
+        );
+        assert_eq!(
+            KeyEvent::from(Key::Enter)
+                .kind(KeyEventKind::Release)
+                .to_string(),
+            "Enter"
+        );
+    }
+
+    #[test]
+    fn key_to_string() {
+        assert_eq!(Key::Char('x').to_string(), "x");
+        assert_eq!(Key::Char(' ').to_string(), "Space");
+        assert_eq!(Key::F(5).to_string(), "F5");
+        assert_eq!(Key::PageDown.to_string(), "PageDown");
+    }
+
+    #[test]
+    fn key_modifiers_debug() {
+        assert_eq!(format!("{:?}", KeyModifiers::NONE), "NONE");
+        assert_eq!(
+            format!("{:?}", KeyModifiers::CONTROL | KeyModifiers::SHIFT),
+            "SHIFT | CONTROL"
+        );
+    }
+}
@@ -0,0 +1,224 @@
+//! Conversions from [Crossterm] events to the events of the [`input`] module, and functions to
+//! read events from the terminal.
+//!
+//! [Crossterm]: https://crates.io/crates/crossterm
+//! [`input`]: crate::input
+use std::{io, time::Duration};
+
+use crossterm::event::{
+    self as cevent, Event as CEvent, KeyCode as CKeyCode, KeyEvent as CKeyEvent,
+    KeyEventKind as CKeyEventKind, KeyModifiers as CKeyModifiers, MouseButton as CMouseButton,
+    MouseEvent as CMouseEvent, MouseEventKind as CMouseEventKind,
+};
+
+use crate::{
+    input::{
+        Event, Key, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+    },
+    layout::Size,
+};
+
+/// Reads the next [`Event`] from the terminal, blocking until one is available.
+///
+/// This is a thin wrapper around [`crossterm::event::read`] that converts the event into an
+/// [`Event`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use ratatui::input::{self, Event, Key};
+///
+/// if let Event::Key(key) = input::read()? {
+///     if key.key == Key::Char('q') {
+///         // quit
+///     }
+/// }
+/// # std::io::Result::Ok(())
+/// ```
+pub fn read() -> io::Result<Event> {
+    cevent::read().map(Event::from)
+}
+
+/// Checks whether an [`Event`] is available within the given timeout.
+///
+/// This is a thin wrapper around [`crossterm::event::poll`]. When it returns `true`, the next
+/// call to [`read`] is guaranteed not to block.
+pub fn poll(timeout: Duration) -> io::Result<bool> {
+    cevent::poll(timeout)
+}
+
+impl From<CEvent> for Event {
+    fn from(event: CEvent) -> Self {
+        match event {
+            CEvent::Key(key) => Event::Key(key.into()),
+            CEvent::Mouse(mouse) => Event::Mouse(mouse.into()),
+            CEvent::Resize(columns, rows) => Event::Resize(Size::new(columns, rows)),
+            CEvent::Paste(text) => Event::Paste(text),
+            CEvent::FocusGained => Event::FocusGained,
+            CEvent::FocusLost => Event::FocusLost,
+        }
+    }
+}
+
+impl From<CKeyEvent> for KeyEvent {
+    fn from(event: CKeyEvent) -> Self {
+        KeyEvent::new(event.code.into(), event.modifiers.into()).kind(event.kind.into())
+    }
+}
+
+impl From<CKeyCode> for Key {
+    fn from(code: CKeyCode) -> Self {
+        match code {
+            CKeyCode::Char(c) => Key::Char(c),
+            CKeyCode::Backspace => Key::Backspace,
+            CKeyCode::Enter => Key::Enter,
+            CKeyCode::Left => Key::Left,
+            CKeyCode::Right => Key::Right,
+            CKeyCode::Up => Key::Up,
+            CKeyCode::Down => Key::Down,
+            CKeyCode::Home => Key::Home,
+            CKeyCode::End => Key::End,
+            CKeyCode::PageUp => Key::PageUp,
+            CKeyCode::PageDown => Key::PageDown,
+            CKeyCode::Tab => Key::Tab,
+            CKeyCode::BackTab => Key::BackTab,
+            CKeyCode::Delete => Key::Delete,
+            CKeyCode::Insert => Key::Insert,
+            CKeyCode::F(n) => Key::F(n),
+            CKeyCode::Esc => Key::Esc,
+            _ => Key::Null,
+        }
+    }
+}
+
+impl From<CKeyEventKind> for KeyEventKind {
+    fn from(kind: CKeyEventKind) -> Self {
+        match kind {
+            CKeyEventKind::Press => KeyEventKind::Press,
+            CKeyEventKind::Repeat => KeyEventKind::Repeat,
+            CKeyEventKind::Release => KeyEventKind::Release,
+        }
+    }
+}
+
+impl From<CKeyModifiers> for KeyModifiers {
+    fn from(modifiers: CKeyModifiers) -> Self {
+        let mut res = KeyModifiers::NONE;
+        if modifiers.contains(CKeyModifiers::SHIFT) {
+            res |= KeyModifiers::SHIFT;
+        }
+        if modifiers.contains(CKeyModifiers::CONTROL) {
+            res |= KeyModifiers::CONTROL;
+        }
+        if modifiers.contains(CKeyModifiers::ALT) {
+            res |= KeyModifiers::ALT;
+        }
+        if modifiers.contains(CKeyModifiers::SUPER) {
+            res |= KeyModifiers::SUPER;
+        }
+        res
+    }
+}
+
+impl From<CMouseEvent> for MouseEvent {
+    fn from(event: CMouseEvent) -> Self {
+        MouseEvent {
+            kind: event.kind.into(),
+            column: event.column,
+            row: event.row,
+            modifiers: event.modifiers.into(),
+        }
+    }
+}
+
+impl From<CMouseEventKind> for MouseEventKind {
+    fn from(kind: CMouseEventKind) -> Self {
+        match kind {
+            CMouseEventKind::Down(button) => MouseEventKind::Down(button.into()),
+            CMouseEventKind::Up(button) => MouseEventKind::Up(button.into()),
+            CMouseEventKind::Drag(button) => MouseEventKind::Drag(button.into()),
+            CMouseEventKind::Moved => MouseEventKind::Moved,
+            CMouseEventKind::ScrollDown => MouseEventKind::ScrollDown,
+            CMouseEventKind::ScrollUp => MouseEventKind::ScrollUp,
+            CMouseEventKind::ScrollLeft => MouseEventKind::ScrollLeft,
+            CMouseEventKind::ScrollRight => MouseEventKind::ScrollRight,
+        }
+    }
+}
+
+impl From<CMouseButton> for MouseButton {
+    fn from(button: CMouseButton) -> Self {
+        match button {
+            CMouseButton::Left => MouseButton::Left,
+            CMouseButton::Right => MouseButton::Right,
+            CMouseButton::Middle => MouseButton::Middle,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_crossterm_key_event() {
+        let event = CEvent::Key(CKeyEvent::new(
+            CKeyCode::Char('c'),
+            CKeyModifiers::CONTROL | CKeyModifiers::SHIFT,
+        ));
+        assert_eq!(
+            Event::from(event),
+            Event::Key(KeyEvent::new(
+                Key::Char('c'),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT
+            ))
+        );
+        let event =
+            CKeyEvent::new_with_kind(CKeyCode::F(3), CKeyModifiers::NONE, CKeyEventKind::Release);
+        assert_eq!(
+            KeyEvent::from(event),
+            KeyEvent::new(Key::F(3), KeyModifiers::NONE).kind(KeyEventKind::Release)
+        );
+    }
+
+    #[test]
+    fn from_crossterm_key_code() {
+        assert_eq!(Key::from(CKeyCode::Enter), Key::Enter);
+        assert_eq!(Key::from(CKeyCode::BackTab), Key::BackTab);
+        assert_eq!(Key::from(CKeyCode::Esc), Key::Esc);
+        assert_eq!(Key::from(CKeyCode::CapsLock), Key::Null);
+    }
+
+    #[test]
+    fn from_crossterm_mouse_event() {
+        let event = CEvent::Mouse(CMouseEvent {
+            kind: CMouseEventKind::Drag(CMouseButton::Right),
+            column: 3,
+            row: 7,
+            modifiers: CKeyModifiers::ALT,
+        });
+        assert_eq!(
+            Event::from(event),
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Drag(MouseButton::Right),
+                column: 3,
+                row: 7,
+                modifiers: KeyModifiers::ALT,
+            })
+        );
+    }
+
+    #[test]
+    fn from_crossterm_other_events() {
+        assert_eq!(
+            Event::from(CEvent::Resize(80, 24)),
+            Event::Resize(Size::new(80, 24))
+        );
+        assert_eq!(
+            Event::from(CEvent::Paste("text".into())),
+            Event::Paste("text".into())
+        );
+        assert_eq!(Event::from(CEvent::FocusGained), Event::FocusGained);
+        assert_eq!(Event::from(CEvent::FocusLost), Event::FocusLost);
+    }
+}
@@ -0,0 +1,487 @@
+use crate::{
+    input::{Key, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
+    layout::{Position, Rect},
+    terminal::RedrawRequest,
+};
+
+/// A widget state that can be told whether its widget has the focus, e.g. to show its cursor.
+///
+/// It is implemented by the states of the input widgets, e.g.
+/// [`TextInputState`](crate::widgets::TextInputState) and [`FormState`](crate::widgets::FormState),
+/// so that a [`FocusManager`] can [`apply`](FocusManager::apply) its focus to them.
+pub trait Focusable {
+    /// Sets whether the widget has the focus.
+    fn set_focused(&mut self, focused: bool);
+
+    /// Returns `true` if the widget is disabled, e.g. because it was rendered
+    /// [disabled](crate::widgets::TextInput::disabled), so that a [`FocusManager`] skips it when
+    /// it is [registered with its state](FocusManager::register_state). Defaults to `false`.
+    ///
+    /// The input widgets record their read-only and disabled flags in their state when they are
+    /// rendered, so a state only reflects the flags of the last frame.
+    fn is_disabled(&self) -> bool {
+        false
+    }
+}
+
+/// The direction in which [`FocusManager::focus_towards`] looks for the next widget to focus.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum FocusDirection {
+    /// Towards the top of the screen.
+    Up,
+    /// Towards the bottom of the screen.
+    Down,
+    /// Towards the left of the screen.
+    Left,
+    /// Towards the right of the screen.
+    Right,
+}
+
+/// Tracks which widget of the application has the focus, across frames.
+///
+/// The focusable widgets are identified by IDs chosen by the application, e.g. an enum or a
+/// string. While rendering a frame, the application [`register`](Self::register)s the ID and the
+/// area of each widget that can get the focus, in the order `Tab` goes through them, then calls
+/// [`end_frame`](Self::end_frame). The manager then moves the focus between these widgets:
+/// - `Tab` and `BackTab` move the focus to the next and previous widgets, wrapping around.
+/// - The arrow keys move the focus to the nearest widget in their direction, see
+///   [`focus_towards`](Self::focus_towards).
+/// - A click with the left mouse button gives the focus to the widget under the mouse, see
+///   [`hit_test`](Self::hit_test).
+///
+/// The focused ID is kept as long as a widget with that ID is registered. When the focused widget
+/// is not registered anymore, e.g. because it was removed or disabled, the focus moves to the
+/// widget that took its place in the `Tab` order. Widgets registered with
+/// [`register_state`](Self::register_state) are skipped while their state is disabled.
+///
+/// The input widgets keep handling their own keys: the application should give a key to the
+/// focused widget first, and to the manager only if the widget didn't handle it. The states of
+/// the widgets implement [`Focusable`], so that [`apply`](Self::apply) shows them focused or not.
+/// A [`Form`](struct@crate::widgets::Form) is registered as a single widget, and keeps moving the focus
+/// between its own fields.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     input::{FocusManager, Key},
+///     prelude::*,
+///     widgets::{Button, ButtonState, TextInput, TextInputState},
+/// };
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Id {
+///     Name,
+///     Ok,
+/// }
+///
+/// struct App {
+///     focus: FocusManager<Id>,
+///     name: TextInputState,
+///     ok: ButtonState,
+/// }
+///
+/// fn ui(frame: &mut Frame, app: &mut App) {
+///     let [name, ok] = [Rect::new(0, 0, 20, 1), Rect::new(0, 1, 6, 1)];
+///     app.focus.apply(&Id::Name, &mut app.name);
+///     app.focus.apply(&Id::Ok, &mut app.ok);
+///     frame.render_stateful_widget(TextInput::new(), name, &mut app.name);
+///     frame.render_stateful_widget(Button::new("Ok"), ok, &mut app.ok);
+///     app.focus.register_state(Id::Name, name, &app.name);
+///     app.focus.register_state(Id::Ok, ok, &app.ok);
+///     app.focus.end_frame();
+/// }
+///
+/// # let mut terminal = Terminal::new(backend::TestBackend::new(20, 2))?;
+/// let mut app = App {
+///     focus: FocusManager::new(),
+///     name: TextInputState::default(),
+///     ok: ButtonState::default(),
+/// };
+/// terminal.draw(|frame| ui(frame, &mut app))?;
+/// assert_eq!(app.focus.focused(), Some(&Id::Name));
+/// assert!(app.focus.handle_key(Key::Tab.into()));
+/// terminal.draw(|frame| ui(frame, &mut app))?;
+/// assert_eq!(app.focus.focused(), Some(&Id::Ok));
+/// assert!(!app.name.is_focused());
+/// # std::io::Result::Ok(())
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct FocusManager<Id> {
+    focused: Option<Id>,
+    /// IDs and areas of the widgets registered during the last frame, in `Tab` order
+    targets: Vec<(Id, Rect)>,
+    /// IDs and areas of the widgets registered during the current frame
+    pending: Vec<(Id, Rect)>,
+}
+
+impl<Id> Default for FocusManager<Id> {
+    fn default() -> Self {
+        Self {
+            focused: None,
+            targets: Vec::new(),
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl<Id: Clone + PartialEq> FocusManager<Id> {
+    /// Creates a new manager, giving the focus to the first widget registered.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gives the focus to the widget with the given ID initially.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_focused(mut self, id: Id) -> Self {
+        self.focused = Some(id);
+        self
+    }
+
+    /// Returns the ID of the focused widget, or `None` if no widget was registered.
+    pub fn focused(&self) -> Option<&Id> {
+        self.focused.as_ref()
+    }
+
+    /// Returns `true` if the widget with the given ID has the focus.
+    pub fn is_focused(&self, id: &Id) -> bool {
+        self.focused.as_ref() == Some(id)
+    }
+
+    /// Tells the state of the widget with the given ID whether it has the focus.
+    pub fn apply<F: Focusable + ?Sized>(&self, id: &Id, state: &mut F) {
+        state.set_focused(self.is_focused(id));
+    }
+
+    /// Gives the focus to the widget with the given ID.
+    ///
+    /// The ID doesn't have to be registered yet, e.g. to focus a widget shown on the next frame,
+    /// but the focus moves to another widget if it isn't registered on that frame.
+    pub fn focus(&mut self, id: Id) {
+        RedrawRequest::request_if(self.focused.as_ref() != Some(&id));
+        self.focused = Some(id);
+    }
+
+    /// Returns the IDs and the areas of the widgets registered during the last frame, in `Tab`
+    /// order.
+    pub fn targets(&self) -> impl Iterator<Item = (&Id, Rect)> {
+        self.targets.iter().map(|(id, area)| (id, *area))
+    }
+
+    /// Returns the area of the widget with the given ID during the last frame.
+    pub fn area(&self, id: &Id) -> Option<Rect> {
+        self.targets
+            .iter()
+            .find(|(target, _)| target == id)
+            .map(|(_, area)| *area)
+    }
+
+    /// Registers a widget that can get the focus during the current frame, with the area it was
+    /// rendered in. The widgets are registered in the order `Tab` goes through them.
+    ///
+    /// Disabled widgets should not be registered, so that the focus skips them, see
+    /// [`register_state`](Self::register_state).
+    pub fn register(&mut self, id: Id, area: Rect) {
+        self.pending.push((id, area));
+    }
+
+    /// Registers a widget like [`register`](Self::register), unless its state is
+    /// [disabled](Focusable::is_disabled): the focus then skips the widget, and moves away from
+    /// it if it had the focus.
+    ///
+    /// The states of the widgets with a `disabled` option record it when they are rendered, so
+    /// the widget is registered after being rendered.
+    pub fn register_state<F: Focusable + ?Sized>(&mut self, id: Id, area: Rect, state: &F) {
+        if !state.is_disabled() {
+            self.register(id, area);
+        }
+    }
+
+    /// Ends the current frame, making the widgets registered during the frame the ones the focus
+    /// moves between.
+    ///
+    /// When the focused widget was not registered, the focus moves to the widget registered at
+    /// its place in the `Tab` order, or the last one.
+    pub fn end_frame(&mut self) {
+        let previous = std::mem::replace(&mut self.targets, std::mem::take(&mut self.pending));
+        let Some(focused) = &self.focused else {
+            self.focused = self.targets.first().map(|(id, _)| id.clone());
+            return;
+        };
+        if self.area(focused).is_some() {
+            return;
+        }
+        let index = previous
+            .iter()
+            .position(|(id, _)| id == focused)
+            .unwrap_or_default()
+            .min(self.targets.len().saturating_sub(1));
+        if let Some((id, _)) = self.targets.get(index) {
+            self.focus(id.clone());
+        }
+    }
+
+    /// Returns the ID of the widget at the given position during the last frame.
+    ///
+    /// When widgets overlap, e.g. a popup rendered over a form, the one registered last wins.
+    pub fn hit_test(&self, position: Position) -> Option<&Id> {
+        self.targets
+            .iter()
+            .rev()
+            .find(|(_, area)| area.contains(position))
+            .map(|(id, _)| id)
+    }
+
+    /// Moves the focus to the next widget, wrapping around after the last one.
+    pub fn focus_next(&mut self) {
+        self.focus_by(1);
+    }
+
+    /// Moves the focus to the previous widget, wrapping around before the first one.
+    pub fn focus_previous(&mut self) {
+        self.focus_by(self.targets.len().saturating_sub(1));
+    }
+
+    /// Moves the focus forwards by the given number of widgets in `Tab` order.
+    fn focus_by(&mut self, distance: usize) {
+        let len = self.targets.len();
+        if len == 0 {
+            return;
+        }
+        let next = match self.index() {
+            Some(index) => (index + distance) % len,
+            None => 0,
+        };
+        self.focus(self.targets[next].0.clone());
+    }
+
+    /// Returns the index in `Tab` order of the focused widget.
+    fn index(&self) -> Option<usize> {
+        let focused = self.focused.as_ref()?;
+        self.targets.iter().position(|(id, _)| id == focused)
+    }
+
+    /// Moves the focus to the nearest widget in the given direction from the focused one.
+    /// Returns `false` if there is no widget in that direction.
+    ///
+    /// The widgets in a direction are the ones entirely past the edge of the focused widget on
+    /// that side. The nearest one is the closest in that direction, favoring the widgets that are
+    /// aligned with the focused one: e.g. `Down` in a grid of buttons moves to the button right
+    /// below rather than to a button on the next row further aside.
+    pub fn focus_towards(&mut self, direction: FocusDirection) -> bool {
+        let Some(from) = self.index().map(|index| self.targets[index].1) else {
+            return false;
+        };
+        let nearest = self
+            .targets
+            .iter()
+            .filter_map(|(id, area)| Some((id, distance(from, *area, direction)?)))
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(id, _)| id.clone());
+        match nearest {
+            Some(id) => {
+                self.focus(id);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Handles a key event and returns `true` if it was handled.
+    ///
+    /// See [`FocusManager`] for the keys handled by the manager.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release || self.targets.is_empty() {
+            return false;
+        }
+        let direction = match (key.key, key.modifiers) {
+            (Key::Tab, KeyModifiers::NONE) => {
+                self.focus_next();
+                return true;
+            }
+            (Key::BackTab, _) => {
+                self.focus_previous();
+                return true;
+            }
+            (Key::Up, KeyModifiers::NONE) => FocusDirection::Up,
+            (Key::Down, KeyModifiers::NONE) => FocusDirection::Down,
+            (Key::Left, KeyModifiers::NONE) => FocusDirection::Left,
+            (Key::Right, KeyModifiers::NONE) => FocusDirection::Right,
+            _ => return false,
+        };
+        self.focus_towards(direction)
+    }
+
+    /// Handles a mouse event and returns `true` if it gave the focus to a widget.
+    ///
+    /// A click with the left mouse button focuses the widget under the mouse. The event should
+    /// still be given to that widget, e.g. to move its cursor.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return false;
+        }
+        match self.hit_test(mouse.position()).cloned() {
+            Some(id) => {
+                self.focus(id);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Returns how far `to` is from `from` in the given direction, or `None` if it isn't entirely in
+/// that direction. Being aside counts twice as much as being further in the direction.
+fn distance(from: Rect, to: Rect, direction: FocusDirection) -> Option<u32> {
+    let (ahead, aside) = match direction {
+        FocusDirection::Up => (
+            from.top().checked_sub(to.bottom())?,
+            gap(from.left()..from.right(), to.left()..to.right()),
+        ),
+        FocusDirection::Down => (
+            to.top().checked_sub(from.bottom())?,
+            gap(from.left()..from.right(), to.left()..to.right()),
+        ),
+        FocusDirection::Left => (
+            from.left().checked_sub(to.right())?,
+            gap(from.top()..from.bottom(), to.top()..to.bottom()),
+        ),
+        FocusDirection::Right => (
+            to.left().checked_sub(from.right())?,
+            gap(from.top()..from.bottom(), to.top()..to.bottom()),
+        ),
+    };
+    Some(u32::from(ahead) + 2 * u32::from(aside))
+}
+
+/// Returns how many columns or rows one range is off the other, 0 if they overlap.
+fn gap(a: std::ops::Range<u16>, b: std::ops::Range<u16>) -> u16 {
+    if a.end <= b.start {
+        b.start - a.end + 1
+    } else {
+        (a.start + 1).saturating_sub(b.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Registers a grid of 3 by 2 buttons, 4 columns wide with a 1 column gap
+    fn register_grid(focus: &mut FocusManager<&'static str>) {
+        for (i, id) in ["a", "b", "c", "d", "e", "f"].into_iter().enumerate() {
+            let (x, y) = (i as u16 % 3 * 5, i as u16 / 3);
+            focus.register(id, Rect::new(x, y, 4, 1));
+        }
+    }
+
+    fn grid() -> FocusManager<&'static str> {
+        let mut focus = FocusManager::new();
+        register_grid(&mut focus);
+        focus.end_frame();
+        focus
+    }
+
+    #[test]
+    fn tab_order() {
+        let mut focus = grid();
+        assert_eq!(focus.focused(), Some(&"a"));
+        focus.handle_key(Key::BackTab.into());
+        assert_eq!(focus.focused(), Some(&"f"));
+        focus.handle_key(Key::Tab.into());
+        focus.handle_key(Key::Tab.into());
+        assert_eq!(focus.focused(), Some(&"b"));
+        assert!(!focus.handle_key(KeyEvent::new(Key::Tab, KeyModifiers::CONTROL)));
+        assert!(!FocusManager::<&str>::new().handle_key(Key::Tab.into()));
+    }
+
+    #[test]
+    fn spatial_navigation() {
+        let mut focus = grid();
+        assert!(focus.handle_key(Key::Right.into()));
+        assert_eq!(focus.focused(), Some(&"b"));
+        assert!(focus.handle_key(Key::Down.into()));
+        assert_eq!(focus.focused(), Some(&"e"));
+        assert!(!focus.handle_key(Key::Down.into()));
+        assert!(focus.focus_towards(FocusDirection::Left));
+        assert_eq!(focus.focused(), Some(&"d"));
+        assert!(!focus.focus_towards(FocusDirection::Left));
+        // the aligned widget wins over a nearer one aside
+        let mut focus = FocusManager::new().with_focused("top");
+        focus.register("top", Rect::new(10, 0, 4, 1));
+        focus.register("aside", Rect::new(0, 1, 4, 1));
+        focus.register("below", Rect::new(10, 3, 4, 1));
+        focus.end_frame();
+        focus.focus_towards(FocusDirection::Down);
+        assert_eq!(focus.focused(), Some(&"below"));
+    }
+
+    #[test]
+    fn focus_kept_across_frames() {
+        let mut focus = grid();
+        focus.focus("e");
+        // "e" is disabled: the focus moves to the widget that took its place
+        for (id, x) in [("d", 0), ("f", 10)] {
+            focus.register(id, Rect::new(x, 1, 4, 1));
+        }
+        focus.end_frame();
+        assert_eq!(focus.focused(), Some(&"f"));
+        // and stays there when the widgets move
+        focus.register("f", Rect::new(0, 5, 4, 1));
+        focus.end_frame();
+        assert_eq!(focus.focused(), Some(&"f"));
+        assert_eq!(focus.area(&"f"), Some(Rect::new(0, 5, 4, 1)));
+        focus.end_frame();
+        assert_eq!(focus.focused(), Some(&"f"));
+        assert_eq!(focus.targets().count(), 0);
+    }
+
+    #[test]
+    fn disabled_states_are_skipped() {
+        use crate::widgets::ButtonState;
+
+        let mut focus = FocusManager::new().with_focused("b");
+        let states = [
+            ("a", ButtonState::default()),
+            ("b", ButtonState::default().with_disabled(true)),
+            ("c", ButtonState::default()),
+        ];
+        for (i, (id, _)) in states.iter().enumerate() {
+            focus.register(*id, Rect::new(0, i as u16, 4, 1));
+        }
+        focus.end_frame();
+        // "b" is disabled in the next frame, the focus moves to the widget at its place
+        for (i, (id, state)) in states.iter().enumerate() {
+            focus.register_state(*id, Rect::new(0, i as u16, 4, 1), state);
+        }
+        focus.end_frame();
+        assert_eq!(focus.focused(), Some(&"c"));
+        focus.focus_next();
+        assert_eq!(focus.focused(), Some(&"a"));
+        assert!(!focus.focus_towards(FocusDirection::Up));
+        assert!(focus.focus_towards(FocusDirection::Down));
+        assert_eq!(focus.focused(), Some(&"c"));
+        assert_eq!(focus.hit_test(Position::new(0, 1)), None);
+    }
+
+    #[test]
+    fn click_focuses() {
+        let mut focus = grid();
+        register_grid(&mut focus);
+        focus.register("popup", Rect::new(0, 0, 10, 1));
+        focus.end_frame();
+        assert_eq!(focus.hit_test(Position::new(7, 1)), Some(&"e"));
+        assert_eq!(focus.hit_test(Position::new(4, 1)), None);
+        // the popup registered last is over "a" and "b"
+        assert_eq!(focus.hit_test(Position::new(6, 0)), Some(&"popup"));
+        let click =
+            |column, row| MouseEvent::new(MouseEventKind::Down(MouseButton::Left), column, row);
+        assert!(focus.handle_mouse(click(12, 0)));
+        assert_eq!(focus.focused(), Some(&"c"));
+        assert!(!focus.handle_mouse(click(14, 0)));
+        assert!(!focus.handle_mouse(MouseEvent::new(MouseEventKind::Moved, 0, 0)));
+        assert_eq!(focus.focused(), Some(&"c"));
+    }
+}
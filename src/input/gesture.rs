@@ -0,0 +1,335 @@
+use std::time::{Duration, Instant};
+
+use crate::input::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+/// A mouse gesture recognized by a [`GestureDetector`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Gesture {
+    /// The kind of gesture.
+    pub kind: GestureKind,
+    /// The button used for the gesture.
+    pub button: MouseButton,
+    /// The column where the gesture happened.
+    pub column: u16,
+    /// The row where the gesture happened.
+    pub row: u16,
+    /// The modifiers that were held down when the gesture happened.
+    pub modifiers: KeyModifiers,
+}
+
+/// The kind of a [`Gesture`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum GestureKind {
+    /// A button was pressed and released without moving the mouse.
+    Click,
+    /// A second click at the same position as the previous one, within the double click
+    /// interval.
+    DoubleClick,
+    /// The mouse started moving while a button was held down. The position is where the button
+    /// was pressed.
+    DragStart,
+    /// The mouse moved during a drag. The deltas are relative to the previous position of the
+    /// drag.
+    DragMove {
+        /// Number of columns moved since the previous position.
+        delta_column: i32,
+        /// Number of rows moved since the previous position.
+        delta_row: i32,
+    },
+    /// The button was released, ending a drag. The deltas are relative to the position where
+    /// the drag started.
+    DragEnd {
+        /// Number of columns moved since the drag started.
+        delta_column: i32,
+        /// Number of rows moved since the drag started.
+        delta_row: i32,
+    },
+}
+
+/// Synthesizes [`Gesture`]s (clicks, double clicks and drags) from raw [`MouseEvent`]s.
+///
+/// Terminals only report button presses, releases and mouse movements. The detector keeps track
+/// of these events to tell clicks apart from drags, and to recognize double clicks.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::{Duration, Instant};
+///
+/// use ratatui::input::{GestureDetector, GestureKind, MouseButton, MouseEvent, MouseEventKind};
+///
+/// let mut detector = GestureDetector::new();
+/// let now = Instant::now();
+/// let down = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 3, 4);
+/// let up = MouseEvent::new(MouseEventKind::Up(MouseButton::Left), 3, 4);
+/// detector.process_at(down, now);
+/// assert_eq!(detector.process_at(up, now)[0].kind, GestureKind::Click);
+/// detector.process_at(down, now + Duration::from_millis(100));
+/// let gestures = detector.process_at(up, now + Duration::from_millis(150));
+/// assert_eq!(gestures[0].kind, GestureKind::DoubleClick);
+/// ```
+#[derive(Debug, Clone)]
+pub struct GestureDetector {
+    double_click_interval: Duration,
+    /// The button currently held down, where it was pressed and the last position of the drag
+    press: Option<Press>,
+    /// The last click, used to detect double clicks
+    last_click: Option<(MouseButton, u16, u16, Instant)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Press {
+    button: MouseButton,
+    column: u16,
+    row: u16,
+    last_column: u16,
+    last_row: u16,
+    dragging: bool,
+}
+
+impl Default for GestureDetector {
+    fn default() -> Self {
+        Self {
+            double_click_interval: Duration::from_millis(500),
+            press: None,
+            last_click: None,
+        }
+    }
+}
+
+impl GestureDetector {
+    /// Creates a new `GestureDetector` with a double click interval of 500 milliseconds.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum interval between two clicks for them to be reported as a double click.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn double_click_interval(mut self, interval: Duration) -> Self {
+        self.double_click_interval = interval;
+        self
+    }
+
+    /// Returns `true` if a drag is in progress.
+    pub fn is_dragging(&self) -> bool {
+        self.press.is_some_and(|press| press.dragging)
+    }
+
+    /// Processes a mouse event received now. See [`process_at`](Self::process_at).
+    pub fn process(&mut self, event: MouseEvent) -> Vec<Gesture> {
+        self.process_at(event, Instant::now())
+    }
+
+    /// Processes a mouse event received at the given instant and returns the recognized
+    /// gestures.
+    pub fn process_at(&mut self, event: MouseEvent, now: Instant) -> Vec<Gesture> {
+        let gesture = |kind, button| Gesture {
+            kind,
+            button,
+            column: event.column,
+            row: event.row,
+            modifiers: event.modifiers,
+        };
+        match event.kind {
+            MouseEventKind::Down(button) => {
+                self.press = Some(Press {
+                    button,
+                    column: event.column,
+                    row: event.row,
+                    last_column: event.column,
+                    last_row: event.row,
+                    dragging: false,
+                });
+                vec![]
+            }
+            MouseEventKind::Drag(button) => {
+                let Some(press) = self.press.as_mut().filter(|press| press.button == button) else {
+                    return vec![];
+                };
+                if (press.last_column, press.last_row) == (event.column, event.row) {
+                    return vec![];
+                }
+                let mut gestures = vec![];
+                if !press.dragging {
+                    press.dragging = true;
+                    gestures.push(Gesture {
+                        column: press.column,
+                        row: press.row,
+                        ..gesture(GestureKind::DragStart, button)
+                    });
+                }
+                let kind = GestureKind::DragMove {
+                    delta_column: delta(press.last_column, event.column),
+                    delta_row: delta(press.last_row, event.row),
+                };
+                press.last_column = event.column;
+                press.last_row = event.row;
+                gestures.push(gesture(kind, button));
+                gestures
+            }
+            MouseEventKind::Up(button) => {
+                let Some(press) = self.press.take().filter(|press| press.button == button) else {
+                    return vec![];
+                };
+                if press.dragging {
+                    self.last_click = None;
+                    let kind = GestureKind::DragEnd {
+                        delta_column: delta(press.column, event.column),
+                        delta_row: delta(press.row, event.row),
+                    };
+                    return vec![gesture(kind, button)];
+                }
+                let double_click = self.last_click.is_some_and(|(b, column, row, at)| {
+                    b == button
+                        && (column, row) == (event.column, event.row)
+                        && now.duration_since(at) <= self.double_click_interval
+                });
+                if double_click {
+                    self.last_click = None;
+                    vec![gesture(GestureKind::DoubleClick, button)]
+                } else {
+                    self.last_click = Some((button, event.column, event.row, now));
+                    vec![gesture(GestureKind::Click, button)]
+                }
+            }
+            _ => vec![],
+        }
+    }
+}
+
+fn delta(from: u16, to: u16) -> i32 {
+    i32::from(to) - i32::from(from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    fn event(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent::new(kind, column, row)
+    }
+
+    fn kinds(gestures: Vec<Gesture>) -> Vec<GestureKind> {
+        gestures.into_iter().map(|g| g.kind).collect()
+    }
+
+    const LEFT: MouseButton = MouseButton::Left;
+
+    #[test]
+    fn click() {
+        let mut detector = GestureDetector::new();
+        let now = Instant::now();
+        assert_eq!(
+            detector.process_at(event(MouseEventKind::Down(LEFT), 1, 2), now),
+            vec![]
+        );
+        assert_eq!(
+            detector.process_at(event(MouseEventKind::Up(LEFT), 1, 2), now),
+            vec![Gesture {
+                kind: GestureKind::Click,
+                button: LEFT,
+                column: 1,
+                row: 2,
+                modifiers: KeyModifiers::NONE,
+            }]
+        );
+    }
+
+    #[test]
+    fn double_click() {
+        let mut detector = GestureDetector::new().double_click_interval(ms(300));
+        let now = Instant::now();
+        let mut click = |at| {
+            detector.process_at(event(MouseEventKind::Down(LEFT), 1, 2), at);
+            kinds(detector.process_at(event(MouseEventKind::Up(LEFT), 1, 2), at))
+        };
+        assert_eq!(click(now), vec![GestureKind::Click]);
+        assert_eq!(click(now + ms(200)), vec![GestureKind::DoubleClick]);
+        assert_eq!(click(now + ms(300)), vec![GestureKind::Click]);
+        assert_eq!(click(now + ms(700)), vec![GestureKind::Click]);
+    }
+
+    #[test]
+    fn clicks_at_different_positions_are_not_double_clicks() {
+        let mut detector = GestureDetector::new();
+        let now = Instant::now();
+        detector.process_at(event(MouseEventKind::Down(LEFT), 1, 2), now);
+        detector.process_at(event(MouseEventKind::Up(LEFT), 1, 2), now);
+        detector.process_at(event(MouseEventKind::Down(LEFT), 5, 2), now);
+        assert_eq!(
+            kinds(detector.process_at(event(MouseEventKind::Up(LEFT), 5, 2), now)),
+            vec![GestureKind::Click]
+        );
+    }
+
+    #[test]
+    fn drag() {
+        let mut detector = GestureDetector::new();
+        let now = Instant::now();
+        detector.process_at(event(MouseEventKind::Down(LEFT), 10, 5), now);
+        let gestures = detector.process_at(event(MouseEventKind::Drag(LEFT), 12, 4), now);
+        assert_eq!(
+            (gestures[0].kind, gestures[0].column, gestures[0].row),
+            (GestureKind::DragStart, 10, 5)
+        );
+        assert_eq!(
+            gestures[1].kind,
+            GestureKind::DragMove {
+                delta_column: 2,
+                delta_row: -1
+            }
+        );
+        assert!(detector.is_dragging());
+        assert_eq!(
+            kinds(detector.process_at(event(MouseEventKind::Drag(LEFT), 11, 4), now)),
+            vec![GestureKind::DragMove {
+                delta_column: -1,
+                delta_row: 0
+            }]
+        );
+        assert_eq!(
+            kinds(detector.process_at(event(MouseEventKind::Up(LEFT), 11, 4), now)),
+            vec![GestureKind::DragEnd {
+                delta_column: 1,
+                delta_row: -1
+            }]
+        );
+        assert!(!detector.is_dragging());
+    }
+
+    #[test]
+    fn drag_without_movement_is_a_click() {
+        let mut detector = GestureDetector::new();
+        let now = Instant::now();
+        detector.process_at(event(MouseEventKind::Down(LEFT), 10, 5), now);
+        assert_eq!(
+            detector.process_at(event(MouseEventKind::Drag(LEFT), 10, 5), now),
+            vec![]
+        );
+        assert_eq!(
+            kinds(detector.process_at(event(MouseEventKind::Up(LEFT), 10, 5), now)),
+            vec![GestureKind::Click]
+        );
+    }
+
+    #[test]
+    fn events_of_other_buttons_are_ignored() {
+        let mut detector = GestureDetector::new();
+        let now = Instant::now();
+        detector.process_at(event(MouseEventKind::Down(LEFT), 10, 5), now);
+        assert_eq!(
+            detector.process_at(event(MouseEventKind::Drag(MouseButton::Right), 11, 5), now),
+            vec![]
+        );
+        assert_eq!(
+            detector.process_at(event(MouseEventKind::Up(MouseButton::Right), 11, 5), now),
+            vec![]
+        );
+    }
+}
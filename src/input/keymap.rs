@@ -0,0 +1,388 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use strum::{Display, EnumString};
+
+use crate::input::{Key, KeyEvent, KeyModifiers};
+
+/// A command of the text editing widgets, bound to keys by a [`Keymap`].
+#[derive(Debug, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum EditCommand {
+    /// Moves the cursor one grapheme to the left.
+    MoveLeft,
+    /// Moves the cursor one grapheme to the right.
+    MoveRight,
+    /// Moves the cursor to the previous line.
+    MoveUp,
+    /// Moves the cursor to the next line.
+    MoveDown,
+    /// Moves the cursor to the start of the line.
+    MoveHome,
+    /// Moves the cursor to the end of the line.
+    MoveEnd,
+    /// Moves the cursor to the start of the current or previous word.
+    MoveWordLeft,
+    /// Moves the cursor to the end of the current or next word.
+    MoveWordRight,
+    /// Extends the selection one grapheme to the left.
+    SelectLeft,
+    /// Extends the selection one grapheme to the right.
+    SelectRight,
+    /// Extends the selection to the previous line.
+    SelectUp,
+    /// Extends the selection to the next line.
+    SelectDown,
+    /// Extends the selection to the start of the line.
+    SelectHome,
+    /// Extends the selection to the end of the line.
+    SelectEnd,
+    /// Extends the selection to the start of the current or previous word.
+    SelectWordLeft,
+    /// Extends the selection to the end of the current or next word.
+    SelectWordRight,
+    /// Deletes the grapheme before the cursor.
+    DeleteBefore,
+    /// Deletes the grapheme after the cursor.
+    DeleteAfter,
+    /// Deletes from the start of the current or previous word to the cursor.
+    DeleteWordBefore,
+    /// Deletes from the cursor to the end of the current or next word.
+    DeleteWordAfter,
+    /// Deletes from the start of the line to the cursor.
+    DeleteToStart,
+    /// Deletes from the cursor to the end of the line.
+    DeleteToEnd,
+    /// Deletes from the start of the current or previous word to the cursor, and adds the deleted
+    /// text to the [`KillRing`](crate::input::KillRing).
+    KillWordBefore,
+    /// Deletes from the cursor to the end of the current or next word, and adds the deleted text
+    /// to the [`KillRing`](crate::input::KillRing).
+    KillWordAfter,
+    /// Deletes from the start of the line to the cursor, and adds the deleted text to the
+    /// [`KillRing`](crate::input::KillRing).
+    KillToStart,
+    /// Deletes from the cursor to the end of the line, and adds the deleted text to the
+    /// [`KillRing`](crate::input::KillRing).
+    KillToEnd,
+    /// Inserts the last text of the [`KillRing`](crate::input::KillRing) at the cursor.
+    Yank,
+    /// Moves the cursor to the next diagnostic of a text area, see
+    /// [`TextAreaState::next_diagnostic`](crate::widgets::TextAreaState::next_diagnostic).
+    NextDiagnostic,
+    /// Moves the cursor to the previous diagnostic of a text area, see
+    /// [`TextAreaState::prev_diagnostic`](crate::widgets::TextAreaState::prev_diagnostic).
+    PrevDiagnostic,
+    /// Adds a cursor on the line before the first cursor of a text area, see
+    /// [`TextAreaState::add_cursor_above`](crate::widgets::TextAreaState::add_cursor_above).
+    AddCursorAbove,
+    /// Adds a cursor on the line after the last cursor of a text area, see
+    /// [`TextAreaState::add_cursor_below`](crate::widgets::TextAreaState::add_cursor_below).
+    AddCursorBelow,
+    /// Selects the next occurrence of the selection of a text area with a new cursor, see
+    /// [`TextAreaState::select_next_occurrence`].
+    ///
+    /// [`TextAreaState::select_next_occurrence`]:
+    ///     crate::widgets::TextAreaState::select_next_occurrence
+    SelectNextOccurrence,
+    /// Splits the line at the cursor.
+    InsertNewline,
+    /// Reverts the last edit.
+    Undo,
+    /// Restores the last undone edit.
+    Redo,
+}
+
+impl EditCommand {
+    /// Returns `true` if the command changes the text, i.e. the delete and kill commands,
+    /// [`Yank`](Self::Yank), [`InsertNewline`](Self::InsertNewline), [`Undo`](Self::Undo) and
+    /// [`Redo`](Self::Redo).
+    ///
+    /// These commands are rejected by read-only inputs, which still move the cursor and select
+    /// text.
+    pub fn is_edit(self) -> bool {
+        matches!(
+            self,
+            Self::DeleteBefore
+                | Self::DeleteAfter
+                | Self::DeleteWordBefore
+                | Self::DeleteWordAfter
+                | Self::DeleteToStart
+                | Self::DeleteToEnd
+                | Self::KillWordBefore
+                | Self::KillWordAfter
+                | Self::KillToStart
+                | Self::KillToEnd
+                | Self::Yank
+                | Self::InsertNewline
+                | Self::Undo
+                | Self::Redo
+        )
+    }
+
+    /// Returns `true` if the command adds the deleted text to the
+    /// [`KillRing`](crate::input::KillRing).
+    pub fn is_kill(self) -> bool {
+        matches!(
+            self,
+            Self::KillWordBefore | Self::KillWordAfter | Self::KillToStart | Self::KillToEnd
+        )
+    }
+}
+
+/// Maps key presses to [`EditCommand`]s.
+///
+/// The default keymap binds the usual editing keys (arrows, `Home`, `End`, `Backspace`,
+/// `Delete`, `Enter`), the arrows with `Shift` to select text, and the Emacs / readline
+/// shortcuts:
+///
+/// | Keys                      | Command                                  |
+/// |---------------------------|------------------------------------------|
+/// | `Shift-Left` / `Shift-Right` | [`SelectLeft`] / [`SelectRight`]      |
+/// | `Shift-Up` / `Shift-Down` | [`SelectUp`] / [`SelectDown`]            |
+/// | `Shift-Home` / `Shift-End`| [`SelectHome`] / [`SelectEnd`]           |
+/// | `Ctrl-Shift-Left` / `Ctrl-Shift-Right` | [`SelectWordLeft`] / [`SelectWordRight`] |
+/// | `Ctrl-B` / `Ctrl-F`       | [`MoveLeft`] / [`MoveRight`]             |
+/// | `Ctrl-P` / `Ctrl-N`       | [`MoveUp`] / [`MoveDown`]                |
+/// | `Ctrl-A` / `Ctrl-E`       | [`MoveHome`] / [`MoveEnd`]               |
+/// | `Alt-B` / `Alt-F`         | [`MoveWordLeft`] / [`MoveWordRight`]     |
+/// | `Ctrl-Left` / `Ctrl-Right`| [`MoveWordLeft`] / [`MoveWordRight`]     |
+/// | `Ctrl-H` / `Ctrl-D`       | [`DeleteBefore`] / [`DeleteAfter`]       |
+/// | `Ctrl-W` / `Alt-D`        | [`KillWordBefore`] / [`KillWordAfter`]   |
+/// | `Alt-Backspace`           | [`DeleteWordBefore`]                     |
+/// | `Ctrl-U` / `Ctrl-K`       | [`KillToStart`] / [`KillToEnd`]          |
+/// | `Ctrl-Y`                  | [`Yank`]                                 |
+/// | `Ctrl-Z` / `Ctrl-Shift-Z` | [`Undo`] / [`Redo`]                      |
+/// | `F8` / `Shift-F8`         | [`NextDiagnostic`] / [`PrevDiagnostic`]  |
+/// | `Ctrl-Alt-Up` / `Ctrl-Alt-Down` | [`AddCursorAbove`] / [`AddCursorBelow`] |
+/// | `Alt-N`                   | [`SelectNextOccurrence`]                 |
+///
+/// Use [`Keymap::new`] to start from an empty keymap instead, and [`bind`](Keymap::bind) /
+/// [`unbind`](Keymap::unbind) to customize a keymap.
+///
+/// [`MoveLeft`]: EditCommand::MoveLeft
+/// [`MoveRight`]: EditCommand::MoveRight
+/// [`MoveUp`]: EditCommand::MoveUp
+/// [`MoveDown`]: EditCommand::MoveDown
+/// [`MoveHome`]: EditCommand::MoveHome
+/// [`MoveEnd`]: EditCommand::MoveEnd
+/// [`MoveWordLeft`]: EditCommand::MoveWordLeft
+/// [`MoveWordRight`]: EditCommand::MoveWordRight
+/// [`SelectLeft`]: EditCommand::SelectLeft
+/// [`SelectRight`]: EditCommand::SelectRight
+/// [`SelectUp`]: EditCommand::SelectUp
+/// [`SelectDown`]: EditCommand::SelectDown
+/// [`SelectHome`]: EditCommand::SelectHome
+/// [`SelectEnd`]: EditCommand::SelectEnd
+/// [`SelectWordLeft`]: EditCommand::SelectWordLeft
+/// [`SelectWordRight`]: EditCommand::SelectWordRight
+/// [`DeleteBefore`]: EditCommand::DeleteBefore
+/// [`DeleteAfter`]: EditCommand::DeleteAfter
+/// [`DeleteWordBefore`]: EditCommand::DeleteWordBefore
+/// [`KillWordBefore`]: EditCommand::KillWordBefore
+/// [`KillWordAfter`]: EditCommand::KillWordAfter
+/// [`KillToStart`]: EditCommand::KillToStart
+/// [`KillToEnd`]: EditCommand::KillToEnd
+/// [`Yank`]: EditCommand::Yank
+/// [`NextDiagnostic`]: EditCommand::NextDiagnostic
+/// [`PrevDiagnostic`]: EditCommand::PrevDiagnostic
+/// [`AddCursorAbove`]: EditCommand::AddCursorAbove
+/// [`AddCursorBelow`]: EditCommand::AddCursorBelow
+/// [`SelectNextOccurrence`]: EditCommand::SelectNextOccurrence
+/// [`Undo`]: EditCommand::Undo
+/// [`Redo`]: EditCommand::Redo
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     input::{EditCommand, Key, KeyEvent, KeyModifiers, Keymap},
+///     widgets::TextInputState,
+/// };
+///
+/// let keymap = Keymap::default()
+///     .unbind(KeyEvent::new(Key::Char('d'), KeyModifiers::CONTROL))
+///     .bind(Key::F(2), EditCommand::DeleteToStart);
+/// let mut state = TextInputState::new("hello");
+/// assert!(state.handle_key_with(&keymap, Key::F(2).into()));
+/// assert_eq!(state.value(), "");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Keymap {
+    bindings: HashMap<(Key, KeyModifiers), EditCommand>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        use EditCommand::*;
+        const NONE: KeyModifiers = KeyModifiers::NONE;
+        const CTRL: KeyModifiers = KeyModifiers::CONTROL;
+        const ALT: KeyModifiers = KeyModifiers::ALT;
+        const SHIFT: KeyModifiers = KeyModifiers::SHIFT;
+        const CTRL_SHIFT: KeyModifiers = CTRL.union(SHIFT);
+        const CTRL_ALT: KeyModifiers = CTRL.union(ALT);
+        let bindings = [
+            (Key::Left, NONE, MoveLeft),
+            (Key::Char('b'), CTRL, MoveLeft),
+            (Key::Right, NONE, MoveRight),
+            (Key::Char('f'), CTRL, MoveRight),
+            (Key::Up, NONE, MoveUp),
+            (Key::Char('p'), CTRL, MoveUp),
+            (Key::Down, NONE, MoveDown),
+            (Key::Char('n'), CTRL, MoveDown),
+            (Key::Home, NONE, MoveHome),
+            (Key::Char('a'), CTRL, MoveHome),
+            (Key::End, NONE, MoveEnd),
+            (Key::Char('e'), CTRL, MoveEnd),
+            (Key::Char('b'), ALT, MoveWordLeft),
+            (Key::Left, CTRL, MoveWordLeft),
+            (Key::Char('f'), ALT, MoveWordRight),
+            (Key::Right, CTRL, MoveWordRight),
+            (Key::Left, SHIFT, SelectLeft),
+            (Key::Right, SHIFT, SelectRight),
+            (Key::Up, SHIFT, SelectUp),
+            (Key::Down, SHIFT, SelectDown),
+            (Key::Home, SHIFT, SelectHome),
+            (Key::End, SHIFT, SelectEnd),
+            (Key::Left, CTRL_SHIFT, SelectWordLeft),
+            (Key::Right, CTRL_SHIFT, SelectWordRight),
+            (Key::Backspace, NONE, DeleteBefore),
+            (Key::Char('h'), CTRL, DeleteBefore),
+            (Key::Delete, NONE, DeleteAfter),
+            (Key::Char('d'), CTRL, DeleteAfter),
+            (Key::Char('w'), CTRL, KillWordBefore),
+            (Key::Backspace, ALT, DeleteWordBefore),
+            (Key::Char('d'), ALT, KillWordAfter),
+            (Key::Char('u'), CTRL, KillToStart),
+            (Key::Char('k'), CTRL, KillToEnd),
+            (Key::Char('y'), CTRL, Yank),
+            (Key::Enter, NONE, InsertNewline),
+            (Key::Char('z'), CTRL, Undo),
+            (Key::Char('Z'), CTRL_SHIFT, Redo),
+            (Key::F(8), NONE, NextDiagnostic),
+            (Key::F(8), SHIFT, PrevDiagnostic),
+            (Key::Up, CTRL_ALT, AddCursorAbove),
+            (Key::Down, CTRL_ALT, AddCursorBelow),
+            (Key::Char('n'), ALT, SelectNextOccurrence),
+        ];
+        Self {
+            bindings: bindings
+                .into_iter()
+                .map(|(key, modifiers, command)| ((key, modifiers), command))
+                .collect(),
+        }
+    }
+}
+
+impl Keymap {
+    /// Creates an empty keymap.
+    pub fn new() -> Self {
+        Self {
+            bindings: HashMap::new(),
+        }
+    }
+
+    /// Binds the given key (and modifiers) to a command, replacing its previous binding.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn bind<K>(mut self, key: K, command: EditCommand) -> Self
+    where
+        K: Into<KeyEvent>,
+    {
+        let key = key.into();
+        self.bindings.insert((key.key, key.modifiers), command);
+        self
+    }
+
+    /// Removes the binding of the given key (and modifiers).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn unbind<K>(mut self, key: K) -> Self
+    where
+        K: Into<KeyEvent>,
+    {
+        let key = key.into();
+        self.bindings.remove(&(key.key, key.modifiers));
+        self
+    }
+
+    /// Returns the command bound to the given key event, if any. The kind of the event is
+    /// ignored.
+    pub fn command(&self, key: &KeyEvent) -> Option<EditCommand> {
+        self.bindings.get(&(key.key, key.modifiers)).copied()
+    }
+
+    /// Returns the shared default keymap, used by [`Input::handle_key`].
+    ///
+    /// [`Input::handle_key`]: crate::input::Input::handle_key
+    pub(crate) fn default_ref() -> &'static Keymap {
+        static DEFAULT: OnceLock<Keymap> = OnceLock::new();
+        DEFAULT.get_or_init(Keymap::default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_bindings() {
+        let keymap = Keymap::default();
+        let ctrl = |c| KeyEvent::new(Key::Char(c), KeyModifiers::CONTROL);
+        assert_eq!(keymap.command(&ctrl('a')), Some(EditCommand::MoveHome));
+        assert_eq!(
+            keymap.command(&ctrl('w')),
+            Some(EditCommand::KillWordBefore)
+        );
+        assert_eq!(keymap.command(&ctrl('y')), Some(EditCommand::Yank));
+        assert_eq!(
+            keymap.command(&KeyEvent::new(
+                Key::Char('Z'),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT
+            )),
+            Some(EditCommand::Redo)
+        );
+        assert_eq!(
+            keymap.command(&KeyEvent::new(Key::Char('f'), KeyModifiers::ALT)),
+            Some(EditCommand::MoveWordRight)
+        );
+        assert_eq!(
+            keymap.command(&KeyEvent::new(
+                Key::Left,
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT
+            )),
+            Some(EditCommand::SelectWordLeft)
+        );
+        assert_eq!(keymap.command(&Key::Char('a').into()), None);
+    }
+
+    #[test]
+    fn is_edit() {
+        assert!(EditCommand::DeleteWordBefore.is_edit());
+        assert!(EditCommand::InsertNewline.is_edit());
+        assert!(EditCommand::Undo.is_edit());
+        assert!(EditCommand::KillToEnd.is_edit());
+        assert!(EditCommand::Yank.is_edit());
+        assert!(!EditCommand::MoveUp.is_edit());
+        assert!(!EditCommand::SelectWordLeft.is_edit());
+    }
+
+    #[test]
+    fn is_kill() {
+        assert!(EditCommand::KillWordBefore.is_kill());
+        assert!(EditCommand::KillToStart.is_kill());
+        assert!(!EditCommand::DeleteWordBefore.is_kill());
+        assert!(!EditCommand::Yank.is_kill());
+    }
+
+    #[test]
+    fn bind_and_unbind() {
+        let keymap = Keymap::new()
+            .bind(Key::F(1), EditCommand::Undo)
+            .bind(Key::F(2), EditCommand::Redo)
+            .unbind(Key::F(2));
+        assert_eq!(keymap.command(&Key::F(1).into()), Some(EditCommand::Undo));
+        assert_eq!(keymap.command(&Key::F(2).into()), None);
+        assert_eq!(keymap.command(&Key::Left.into()), None);
+    }
+}
@@ -0,0 +1,140 @@
+use std::{cell::RefCell, collections::VecDeque};
+
+thread_local! {
+    static RING: RefCell<VecDeque<String>> = const { RefCell::new(VecDeque::new()) };
+}
+
+/// The text killed by the Emacs-style editing commands, shared by all the text inputs.
+///
+/// The kill commands ([`KillWordBefore`], [`KillToEnd`], ...) delete text like their delete
+/// counterparts and push it to the ring, and [`Yank`] inserts the last killed text at the cursor,
+/// in the same input or in another one. Consecutive kills in the same input are joined into one
+/// entry, so that e.g. `Ctrl-W` pressed three times yanks back the three words at once.
+///
+/// The ring is kept for the current thread and holds the last [`KillRing::CAPACITY`] entries.
+/// It is separate from the system clipboard.
+///
+/// [`KillWordBefore`]: crate::input::EditCommand::KillWordBefore
+/// [`KillToEnd`]: crate::input::EditCommand::KillToEnd
+/// [`Yank`]: crate::input::EditCommand::Yank
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     input::{EditCommand, KillRing},
+///     widgets::TextInputState,
+/// };
+///
+/// let mut source = TextInputState::new("hello world");
+/// source.execute(EditCommand::KillWordBefore);
+/// assert_eq!(KillRing::yank().as_deref(), Some("world"));
+///
+/// let mut target = TextInputState::new("hello ");
+/// target.execute(EditCommand::Yank);
+/// assert_eq!(target.value(), "hello world");
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct KillRing;
+
+impl KillRing {
+    /// The number of entries kept in the ring, the oldest ones being dropped first.
+    pub const CAPACITY: usize = 32;
+
+    /// Pushes a new entry to the ring on this thread. Empty text is ignored.
+    pub fn push<T>(text: T)
+    where
+        T: Into<String>,
+    {
+        let text = text.into();
+        if text.is_empty() {
+            return;
+        }
+        RING.with(|ring| {
+            let mut ring = ring.borrow_mut();
+            if ring.len() == Self::CAPACITY {
+                ring.pop_back();
+            }
+            ring.push_front(text);
+        });
+    }
+
+    /// Returns the last killed text on this thread, if any.
+    pub fn yank() -> Option<String> {
+        RING.with(|ring| ring.borrow().front().cloned())
+    }
+
+    /// Returns the number of entries in the ring on this thread.
+    pub fn len() -> usize {
+        RING.with(|ring| ring.borrow().len())
+    }
+
+    /// Returns `true` if nothing was killed on this thread.
+    pub fn is_empty() -> bool {
+        Self::len() == 0
+    }
+
+    /// Removes all the entries of the ring on this thread.
+    pub fn clear() {
+        RING.with(|ring| ring.borrow_mut().clear());
+    }
+
+    /// Records killed text: pushes it as a new entry, or joins it to the last entry when the
+    /// previous command also killed text, before it if the text was killed backwards.
+    pub(crate) fn kill(text: &str, join: bool, backwards: bool) {
+        if !join || Self::is_empty() {
+            Self::push(text);
+            return;
+        }
+        RING.with(|ring| {
+            if let Some(last) = ring.borrow_mut().front_mut() {
+                if backwards {
+                    last.insert_str(0, text);
+                } else {
+                    last.push_str(text);
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_and_yank() {
+        KillRing::clear();
+        assert!(KillRing::is_empty());
+        assert_eq!(KillRing::yank(), None);
+        KillRing::push("foo");
+        KillRing::push("");
+        KillRing::push("bar");
+        assert_eq!(KillRing::len(), 2);
+        assert_eq!(KillRing::yank().as_deref(), Some("bar"));
+        KillRing::clear();
+        assert_eq!(KillRing::yank(), None);
+    }
+
+    #[test]
+    fn capacity() {
+        KillRing::clear();
+        for i in 0..KillRing::CAPACITY + 5 {
+            KillRing::push(i.to_string());
+        }
+        assert_eq!(KillRing::len(), KillRing::CAPACITY);
+        assert_eq!(KillRing::yank(), Some((KillRing::CAPACITY + 4).to_string()));
+    }
+
+    #[test]
+    fn consecutive_kills_are_joined() {
+        KillRing::clear();
+        KillRing::kill("bar", true, false);
+        KillRing::kill(" baz", true, false);
+        KillRing::kill("foo ", true, true);
+        assert_eq!(KillRing::yank().as_deref(), Some("foo bar baz"));
+        KillRing::kill("qux", false, true);
+        assert_eq!(KillRing::len(), 2);
+        assert_eq!(KillRing::yank().as_deref(), Some("qux"));
+    }
+}
@@ -0,0 +1,493 @@
+use std::time::{Duration, Instant};
+
+use crate::input::{Event, KeyEvent, KeyEventKind};
+
+/// The output of an [`EventProcessor`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ProcessedEvent<A> {
+    /// A bound key sequence was completed.
+    Action(A),
+    /// A key event that is not part of any bound key sequence.
+    ///
+    /// Presses of a key that is held down are reported with [`KeyEventKind::Repeat`], even when
+    /// the terminal only reports presses.
+    Key(KeyEvent),
+    /// Any other event, passed through unchanged.
+    Event(Event),
+}
+
+/// Resolves raw [`Event`]s into actions, handling key repeats and multi-key sequences.
+///
+/// The processor is configured with key sequences bound to actions (e.g. `g g` to go to the top
+/// of a list). Each event fed to [`process`] returns the [`ProcessedEvent`]s that could be
+/// resolved so far:
+/// - Keys that start a bound sequence are held back until the sequence is completed, the next key
+///   doesn't continue the sequence or the [`sequence_timeout`] elapses. Call [`tick`] regularly
+///   (e.g. when polling for events times out, see [`deadline`]) to resolve sequences that timed
+///   out.
+/// - Presses of the same key arriving quicker than the [`hold_threshold`] are reported as
+///   [`KeyEventKind::Repeat`] presses, so that the application can tell a held key apart from
+///   distinct presses on terminals that don't report repeats.
+/// - Repeats arriving quicker than the [`repeat_debounce`] interval are dropped to avoid flooding
+///   the application on fast key repeat rates.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::{Duration, Instant};
+///
+/// use ratatui::input::{Event, EventProcessor, Key, KeyEvent, KeyModifiers, ProcessedEvent};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Action {
+///     Top,
+///     Save,
+/// }
+///
+/// let mut processor = EventProcessor::new()
+///     .sequence_timeout(Duration::from_millis(500))
+///     .bind([Key::Char('g'), Key::Char('g')], Action::Top)
+///     .bind([KeyEvent::new(Key::Char('s'), KeyModifiers::CONTROL)], Action::Save);
+///
+/// let now = Instant::now();
+/// let g = Event::Key(Key::Char('g').into());
+/// assert_eq!(processor.process_at(g.clone(), now), vec![]);
+/// assert_eq!(
+///     processor.process_at(g, now + Duration::from_millis(100)),
+///     vec![ProcessedEvent::Action(Action::Top)]
+/// );
+/// ```
+///
+/// [`process`]: EventProcessor::process
+/// [`tick`]: EventProcessor::tick
+/// [`deadline`]: EventProcessor::deadline
+/// [`sequence_timeout`]: EventProcessor::sequence_timeout
+/// [`hold_threshold`]: EventProcessor::hold_threshold
+/// [`repeat_debounce`]: EventProcessor::repeat_debounce
+#[derive(Debug, Clone)]
+pub struct EventProcessor<A> {
+    bindings: Vec<(Vec<KeyEvent>, A)>,
+    sequence_timeout: Duration,
+    hold_threshold: Duration,
+    repeat_debounce: Duration,
+    /// Keys of a sequence that is not resolved yet
+    pending: Vec<KeyEvent>,
+    /// When the last key was added to the pending sequence
+    pending_since: Option<Instant>,
+    /// The last key press and when it was received, used to detect held keys
+    last_press: Option<(KeyEvent, Instant)>,
+    /// When the last repeat was emitted, used to debounce repeats
+    last_repeat: Option<Instant>,
+}
+
+impl<A> Default for EventProcessor<A> {
+    fn default() -> Self {
+        Self {
+            bindings: vec![],
+            sequence_timeout: Duration::from_millis(1000),
+            hold_threshold: Duration::from_millis(50),
+            repeat_debounce: Duration::ZERO,
+            pending: vec![],
+            pending_since: None,
+            last_press: None,
+            last_repeat: None,
+        }
+    }
+}
+
+impl<A> EventProcessor<A>
+where
+    A: Clone,
+{
+    /// Creates a new `EventProcessor` without any bindings.
+    ///
+    /// The sequence timeout defaults to 1 second, the hold threshold to 50 milliseconds and
+    /// repeats are not debounced.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds a sequence of keys to an action.
+    ///
+    /// The keys are matched by key and modifiers, the kind of the key events is ignored. Binding
+    /// an empty sequence has no effect.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn bind<I>(mut self, keys: I, action: A) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<KeyEvent>,
+    {
+        let keys = keys
+            .into_iter()
+            .map(|key| {
+                let key = key.into();
+                KeyEvent::new(key.key, key.modifiers)
+            })
+            .collect::<Vec<_>>();
+        if !keys.is_empty() {
+            self.bindings.push((keys, action));
+        }
+        self
+    }
+
+    /// Sets how long to wait for the next key of a sequence before resolving the keys received
+    /// so far.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn sequence_timeout(mut self, timeout: Duration) -> Self {
+        self.sequence_timeout = timeout;
+        self
+    }
+
+    /// Sets the maximum interval between two presses of the same key for the second one to be
+    /// considered a repeat of a held key.
+    ///
+    /// Set this to [`Duration::ZERO`] to only rely on repeats reported by the terminal.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hold_threshold(mut self, threshold: Duration) -> Self {
+        self.hold_threshold = threshold;
+        self
+    }
+
+    /// Sets the minimum interval between two repeats of a held key. Repeats arriving quicker are
+    /// dropped.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn repeat_debounce(mut self, interval: Duration) -> Self {
+        self.repeat_debounce = interval;
+        self
+    }
+
+    /// Returns the key that is currently held down, if any.
+    ///
+    /// A key is considered held from its first repeat until it is released or another key is
+    /// pressed.
+    pub fn held_key(&self) -> Option<KeyEvent> {
+        self.last_repeat
+            .and(self.last_press)
+            .map(|(key, _)| KeyEvent::new(key.key, key.modifiers))
+    }
+
+    /// Returns the keys of the sequence that is waiting to be completed.
+    pub fn pending_keys(&self) -> &[KeyEvent] {
+        &self.pending
+    }
+
+    /// Returns the instant at which the pending sequence times out, if there is one.
+    ///
+    /// Applications can use this to compute the timeout when polling for the next event, and
+    /// call [`tick`](Self::tick) once it has elapsed.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.pending_since
+            .map(|since| since + self.sequence_timeout)
+    }
+
+    /// Processes an event received now. See [`process_at`](Self::process_at).
+    pub fn process(&mut self, event: Event) -> Vec<ProcessedEvent<A>> {
+        self.process_at(event, Instant::now())
+    }
+
+    /// Processes an event received at the given instant and returns the events that could be
+    /// resolved.
+    pub fn process_at(&mut self, event: Event, now: Instant) -> Vec<ProcessedEvent<A>> {
+        let mut output = self.tick(now);
+        let Event::Key(key) = event else {
+            output.push(ProcessedEvent::Event(event));
+            return output;
+        };
+        let Some(key) = self.detect_repeat(key, now) else {
+            return output;
+        };
+        if key.kind == KeyEventKind::Release {
+            output.push(ProcessedEvent::Key(key));
+            return output;
+        }
+        self.pending.push(key);
+        self.pending_since = Some(now);
+        self.resolve(&mut output, false);
+        output
+    }
+
+    /// Resolves the pending sequence if it timed out.
+    ///
+    /// This should be called regularly when no events are received so that sequences which are
+    /// not completed are not held back forever.
+    pub fn tick(&mut self, now: Instant) -> Vec<ProcessedEvent<A>> {
+        let mut output = vec![];
+        if self.deadline().is_some_and(|deadline| now >= deadline) {
+            self.resolve(&mut output, true);
+        }
+        output
+    }
+
+    /// Updates the held key state and returns the key to process, or `None` if it is a repeat
+    /// that must be dropped.
+    fn detect_repeat(&mut self, mut key: KeyEvent, now: Instant) -> Option<KeyEvent> {
+        match key.kind {
+            KeyEventKind::Release => {
+                self.last_press = None;
+                self.last_repeat = None;
+                return Some(key);
+            }
+            KeyEventKind::Press => {
+                let same_key = self.last_press.is_some_and(|(last, at)| {
+                    same_key(&last, &key) && now - at < self.hold_threshold
+                });
+                if same_key {
+                    key.kind = KeyEventKind::Repeat;
+                }
+            }
+            KeyEventKind::Repeat => {}
+        }
+        if key.kind == KeyEventKind::Repeat {
+            if self
+                .last_repeat
+                .is_some_and(|at| now - at < self.repeat_debounce)
+            {
+                return None;
+            }
+            self.last_repeat = Some(now);
+        } else {
+            self.last_repeat = None;
+        }
+        self.last_press = Some((key, now));
+        Some(key)
+    }
+
+    /// Resolves as much of the pending sequence as possible.
+    ///
+    /// When `timed_out` is true, the pending keys are resolved even if they could be continued
+    /// into a longer sequence.
+    fn resolve(&mut self, output: &mut Vec<ProcessedEvent<A>>, timed_out: bool) {
+        while !self.pending.is_empty() {
+            let exact = self.binding_for(&self.pending);
+            let continues = self.bindings.iter().any(|(keys, _)| {
+                keys.len() > self.pending.len() && starts_with(keys, &self.pending)
+            });
+            if continues && !timed_out {
+                return;
+            }
+            if let Some(action) = exact {
+                output.push(ProcessedEvent::Action(action));
+                self.pending.clear();
+                break;
+            }
+            // the pending keys don't form a sequence, so resolve the first key on its own and
+            // try again with the remaining keys
+            let first = self.pending.remove(0);
+            match self.binding_for(&[first]) {
+                Some(action) => output.push(ProcessedEvent::Action(action)),
+                None => output.push(ProcessedEvent::Key(first)),
+            }
+        }
+        self.pending_since = None;
+    }
+
+    fn binding_for(&self, keys: &[KeyEvent]) -> Option<A> {
+        self.bindings
+            .iter()
+            .find(|(binding, _)| binding.len() == keys.len() && starts_with(binding, keys))
+            .map(|(_, action)| action.clone())
+    }
+}
+
+fn same_key(a: &KeyEvent, b: &KeyEvent) -> bool {
+    a.key == b.key && a.modifiers == b.modifiers
+}
+
+fn starts_with(keys: &[KeyEvent], prefix: &[KeyEvent]) -> bool {
+    keys.len() >= prefix.len() && keys.iter().zip(prefix).all(|(a, b)| same_key(a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{Key, KeyModifiers};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Action {
+        Top,
+        Delete,
+        DeleteLine,
+        Quit,
+    }
+
+    fn processor() -> EventProcessor<Action> {
+        EventProcessor::new()
+            .sequence_timeout(Duration::from_millis(500))
+            .bind([Key::Char('g'), Key::Char('g')], Action::Top)
+            .bind([Key::Char('d')], Action::Delete)
+            .bind([Key::Char('d'), Key::Char('d')], Action::DeleteLine)
+            .bind(
+                [KeyEvent::new(Key::Char('q'), KeyModifiers::CONTROL)],
+                Action::Quit,
+            )
+    }
+
+    fn key(c: char) -> Event {
+        Event::Key(Key::Char(c).into())
+    }
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn single_key_binding() {
+        let mut processor = processor();
+        let now = Instant::now();
+        let event = Event::Key(KeyEvent::new(Key::Char('q'), KeyModifiers::CONTROL));
+        assert_eq!(
+            processor.process_at(event, now),
+            vec![ProcessedEvent::Action(Action::Quit)]
+        );
+    }
+
+    #[test]
+    fn unbound_keys_pass_through() {
+        let mut processor = processor();
+        let now = Instant::now();
+        assert_eq!(
+            processor.process_at(key('x'), now),
+            vec![ProcessedEvent::Key(Key::Char('x').into())]
+        );
+        assert_eq!(
+            processor.process_at(Event::FocusLost, now),
+            vec![ProcessedEvent::Event(Event::FocusLost)]
+        );
+    }
+
+    #[test]
+    fn sequence_completed() {
+        let mut processor = processor();
+        let now = Instant::now();
+        assert_eq!(processor.process_at(key('g'), now), vec![]);
+        assert_eq!(processor.pending_keys(), &[Key::Char('g').into()]);
+        assert_eq!(processor.deadline(), Some(now + ms(500)));
+        assert_eq!(
+            processor.process_at(key('g'), now + ms(200)),
+            vec![ProcessedEvent::Action(Action::Top)]
+        );
+        assert_eq!(processor.pending_keys(), &[]);
+        assert_eq!(processor.deadline(), None);
+    }
+
+    #[test]
+    fn sequence_broken() {
+        let mut processor = processor();
+        let now = Instant::now();
+        processor.process_at(key('g'), now);
+        assert_eq!(
+            processor.process_at(key('x'), now + ms(100)),
+            vec![
+                ProcessedEvent::Key(Key::Char('g').into()),
+                ProcessedEvent::Key(Key::Char('x').into())
+            ]
+        );
+    }
+
+    #[test]
+    fn sequence_broken_by_prefix_of_other_sequence() {
+        let mut processor = processor();
+        let now = Instant::now();
+        processor.process_at(key('g'), now);
+        assert_eq!(
+            processor.process_at(key('d'), now + ms(100)),
+            vec![ProcessedEvent::Key(Key::Char('g').into())]
+        );
+        assert_eq!(processor.pending_keys(), &[Key::Char('d').into()]);
+    }
+
+    #[test]
+    fn sequence_timeout() {
+        let mut processor = processor();
+        let now = Instant::now();
+        processor.process_at(key('g'), now);
+        assert_eq!(processor.tick(now + ms(499)), vec![]);
+        assert_eq!(
+            processor.tick(now + ms(500)),
+            vec![ProcessedEvent::Key(Key::Char('g').into())]
+        );
+    }
+
+    #[test]
+    fn ambiguous_sequence_resolves_on_timeout() {
+        let mut processor = processor();
+        let now = Instant::now();
+        assert_eq!(processor.process_at(key('d'), now), vec![]);
+        assert_eq!(
+            processor.tick(now + ms(600)),
+            vec![ProcessedEvent::Action(Action::Delete)]
+        );
+        processor.process_at(key('d'), now + ms(1000));
+        assert_eq!(
+            processor.process_at(key('d'), now + ms(1200)),
+            vec![ProcessedEvent::Action(Action::DeleteLine)]
+        );
+    }
+
+    #[test]
+    fn timed_out_sequence_resolved_before_next_event() {
+        let mut processor = processor();
+        let now = Instant::now();
+        processor.process_at(key('d'), now);
+        assert_eq!(
+            processor.process_at(key('x'), now + ms(800)),
+            vec![
+                ProcessedEvent::Action(Action::Delete),
+                ProcessedEvent::Key(Key::Char('x').into())
+            ]
+        );
+    }
+
+    #[test]
+    fn held_key_is_reported_as_repeat() {
+        let mut processor = EventProcessor::<Action>::new().hold_threshold(ms(50));
+        let now = Instant::now();
+        processor.process_at(key('j'), now);
+        assert_eq!(processor.held_key(), None);
+        assert_eq!(
+            processor.process_at(key('j'), now + ms(30)),
+            vec![ProcessedEvent::Key(
+                KeyEvent::from(Key::Char('j')).kind(KeyEventKind::Repeat)
+            )]
+        );
+        assert_eq!(processor.held_key(), Some(Key::Char('j').into()));
+        let release = KeyEvent::from(Key::Char('j')).kind(KeyEventKind::Release);
+        processor.process_at(Event::Key(release), now + ms(40));
+        assert_eq!(processor.held_key(), None);
+    }
+
+    #[test]
+    fn slow_presses_are_not_repeats() {
+        let mut processor = EventProcessor::<Action>::new().hold_threshold(ms(50));
+        let now = Instant::now();
+        processor.process_at(key('j'), now);
+        assert_eq!(
+            processor.process_at(key('j'), now + ms(100)),
+            vec![ProcessedEvent::Key(Key::Char('j').into())]
+        );
+    }
+
+    #[test]
+    fn repeats_are_debounced() {
+        let mut processor = EventProcessor::<Action>::new()
+            .hold_threshold(ms(50))
+            .repeat_debounce(ms(20));
+        let now = Instant::now();
+        let repeat = ProcessedEvent::Key(KeyEvent::from(Key::Char('j')).kind(KeyEventKind::Repeat));
+        processor.process_at(key('j'), now);
+        assert_eq!(
+            processor.process_at(key('j'), now + ms(10)),
+            vec![repeat.clone()]
+        );
+        assert_eq!(processor.process_at(key('j'), now + ms(20)), vec![]);
+        assert_eq!(processor.process_at(key('j'), now + ms(30)), vec![repeat]);
+    }
+}
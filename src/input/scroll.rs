@@ -0,0 +1,474 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+    input::{KeyModifiers, MouseEvent, MouseEventKind},
+    layout::Position,
+};
+
+/// Interval without wheel events after which a burst of events is considered finished
+const BURST_GAP: Duration = Duration::from_millis(50);
+/// Minimum number of events in a burst to glide after it, so that a single notch of a mouse wheel
+/// never glides
+const MIN_MOMENTUM_EVENTS: u32 = 4;
+/// Interval between two scrolls synthesized while gliding
+const MOMENTUM_STEP: Duration = Duration::from_millis(16);
+/// Velocity, in cells per step, under which gliding stops
+const MIN_MOMENTUM_VELOCITY: f64 = 0.25;
+
+/// A scroll of the mouse wheel or of a trackpad, produced by a [`ScrollProcessor`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Scroll {
+    /// Number of columns to scroll, positive to the right.
+    pub delta_column: i32,
+    /// Number of rows to scroll, positive downwards.
+    pub delta_row: i32,
+    /// The column of the mouse cursor.
+    pub column: u16,
+    /// The row of the mouse cursor.
+    pub row: u16,
+    /// The modifiers that were held down during the scroll.
+    pub modifiers: KeyModifiers,
+}
+
+impl Scroll {
+    /// Returns the position of the mouse cursor, e.g. to hit test it with [`Rect::contains`].
+    ///
+    /// [`Rect::contains`]: crate::layout::Rect::contains
+    pub const fn position(&self) -> Position {
+        Position::new(self.column, self.row)
+    }
+}
+
+/// Normalizes the wheel events of the mouse and of trackpads into [`Scroll`] deltas.
+///
+/// Terminals report one [`MouseEvent`] per notch of the wheel, and trackpads report a flood of
+/// them. The processor turns them into scrolls of a number of columns and rows:
+/// - Vertical wheel events with `Shift` held down scroll horizontally, as most terminals don't
+///   report the horizontal wheel. See [`shift_scrolls_horizontally`].
+/// - Events arriving quicker than the [`coalesce_interval`] are coalesced into a single scroll,
+///   so that the application redraws once per interval instead of once per event.
+/// - When [`momentum`] is enabled, a quick burst of events is followed by scrolls gliding with a
+///   decreasing speed, as with the kinetic scrolling of trackpads.
+///
+/// The coalesced events and the gliding scrolls are emitted by [`tick`], which should be called
+/// regularly (e.g. when polling for events times out, see [`deadline`]).
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::{Duration, Instant};
+///
+/// use ratatui::input::{KeyModifiers, MouseEvent, MouseEventKind, ScrollProcessor};
+///
+/// let mut processor = ScrollProcessor::new();
+/// let now = Instant::now();
+/// let down = MouseEvent::new(MouseEventKind::ScrollDown, 3, 4);
+/// assert_eq!(processor.process_at(down, now).unwrap().delta_row, 1);
+/// // the next events are coalesced until the interval elapses
+/// assert_eq!(processor.process_at(down, now + Duration::from_millis(5)), None);
+/// assert_eq!(processor.process_at(down, now + Duration::from_millis(10)), None);
+/// let scroll = processor.tick(now + Duration::from_millis(20)).unwrap();
+/// assert_eq!(scroll.delta_row, 2);
+///
+/// let mut left = MouseEvent::new(MouseEventKind::ScrollUp, 3, 4);
+/// left.modifiers = KeyModifiers::SHIFT;
+/// let scroll = processor.process_at(left, now + Duration::from_secs(1)).unwrap();
+/// assert_eq!((scroll.delta_column, scroll.delta_row), (-1, 0));
+/// ```
+///
+/// [`shift_scrolls_horizontally`]: ScrollProcessor::shift_scrolls_horizontally
+/// [`coalesce_interval`]: ScrollProcessor::coalesce_interval
+/// [`momentum`]: ScrollProcessor::momentum
+/// [`tick`]: ScrollProcessor::tick
+/// [`deadline`]: ScrollProcessor::deadline
+#[derive(Debug, Clone)]
+pub struct ScrollProcessor {
+    shift_scrolls_horizontally: bool,
+    coalesce_interval: Duration,
+    /// The fraction of the speed kept at each step of a glide, if momentum is enabled
+    friction: Option<f64>,
+    /// The coalesced scroll that is not emitted yet
+    pending: Option<Scroll>,
+    /// When the last scroll was emitted, used to coalesce the next events
+    last_emitted: Option<Instant>,
+    /// The events received in quick succession, used to compute the speed of a glide
+    burst: Option<Burst>,
+    glide: Option<Glide>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Burst {
+    start: Instant,
+    last: Instant,
+    events: u32,
+    delta_column: i32,
+    delta_row: i32,
+    last_scroll: Scroll,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Glide {
+    /// Speed, in cells per step
+    velocity: (f64, f64),
+    /// Fractions of cells scrolled but not emitted yet
+    remainder: (f64, f64),
+    next: Instant,
+    scroll: Scroll,
+}
+
+impl Default for ScrollProcessor {
+    fn default() -> Self {
+        Self {
+            shift_scrolls_horizontally: true,
+            coalesce_interval: Duration::from_millis(16),
+            friction: None,
+            pending: None,
+            last_emitted: None,
+            burst: None,
+            glide: None,
+        }
+    }
+}
+
+impl ScrollProcessor {
+    /// Creates a new `ScrollProcessor`.
+    ///
+    /// `Shift` with the vertical wheel scrolls horizontally, events are coalesced over 16
+    /// milliseconds and momentum is disabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether vertical wheel events with `Shift` held down scroll horizontally: up to the
+    /// left and down to the right. Defaults to `true`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn shift_scrolls_horizontally(mut self, enabled: bool) -> Self {
+        self.shift_scrolls_horizontally = enabled;
+        self
+    }
+
+    /// Sets the interval over which events are coalesced into a single scroll.
+    ///
+    /// The first event after the interval is emitted right away, and the next ones are coalesced
+    /// until the interval elapses again. Set this to [`Duration::ZERO`] to emit every event.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn coalesce_interval(mut self, interval: Duration) -> Self {
+        self.coalesce_interval = interval;
+        self
+    }
+
+    /// Enables momentum: after a quick burst of events, the scroll glides with the speed of the
+    /// burst, keeping the given fraction of its speed every 16 milliseconds (e.g. `0.9`).
+    ///
+    /// The friction is clamped between `0` and `0.99` so that gliding always stops.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn momentum(mut self, friction: f64) -> Self {
+        self.friction = Some(friction.clamp(0.0, 0.99));
+        self
+    }
+
+    /// Returns `true` while the scroll glides after a burst of events.
+    pub fn is_gliding(&self) -> bool {
+        self.glide.is_some()
+    }
+
+    /// Stops gliding, e.g. when the content is scrolled with the keyboard. The scroll that is
+    /// being coalesced is still emitted.
+    pub fn stop(&mut self) {
+        self.burst = None;
+        self.glide = None;
+    }
+
+    /// Returns the instant at which the next call to [`tick`](Self::tick) may emit a scroll, if
+    /// any.
+    ///
+    /// Applications can use this to compute the timeout when polling for the next event.
+    pub fn deadline(&self) -> Option<Instant> {
+        let pending = self
+            .pending
+            .and(self.last_emitted)
+            .map(|at| at + self.coalesce_interval);
+        let burst_end = self
+            .burst
+            .filter(|burst| self.friction.is_some() && burst.events >= MIN_MOMENTUM_EVENTS)
+            .map(|burst| burst.last + BURST_GAP);
+        let glide = self.glide.map(|glide| glide.next);
+        [pending, burst_end, glide].into_iter().flatten().min()
+    }
+
+    /// Processes a mouse event received now. See [`process_at`](Self::process_at).
+    pub fn process(&mut self, mouse: MouseEvent) -> Option<Scroll> {
+        self.process_at(mouse, Instant::now())
+    }
+
+    /// Processes a mouse event received at the given instant and returns the scroll to apply, if
+    /// any.
+    ///
+    /// Events other than the wheel events stop gliding.
+    pub fn process_at(&mut self, mouse: MouseEvent, now: Instant) -> Option<Scroll> {
+        let Some((delta_column, delta_row)) = self.delta(mouse) else {
+            self.stop();
+            return None;
+        };
+        let scroll = Scroll {
+            delta_column,
+            delta_row,
+            column: mouse.column,
+            row: mouse.row,
+            modifiers: mouse.modifiers,
+        };
+        self.glide = None;
+        self.record_burst(scroll, now);
+        let scroll = match self.pending.take() {
+            Some(pending) => Scroll {
+                delta_column: pending.delta_column + scroll.delta_column,
+                delta_row: pending.delta_row + scroll.delta_row,
+                ..scroll
+            },
+            None => scroll,
+        };
+        if self
+            .last_emitted
+            .is_some_and(|at| now.saturating_duration_since(at) < self.coalesce_interval)
+        {
+            self.pending = Some(scroll);
+            return None;
+        }
+        self.emit(scroll, now)
+    }
+
+    /// Emits the coalesced scroll once the interval elapsed, and the gliding scrolls.
+    ///
+    /// This should be called regularly when no events are received, so that the coalesced events
+    /// are not held back and the scroll glides smoothly.
+    pub fn tick(&mut self, now: Instant) -> Option<Scroll> {
+        if let (Some(pending), Some(at)) = (self.pending, self.last_emitted) {
+            if now.saturating_duration_since(at) >= self.coalesce_interval {
+                self.pending = None;
+                if let Some(scroll) = self.emit(pending, now) {
+                    return Some(scroll);
+                }
+            }
+        }
+        if let Some(burst) = self.burst {
+            if now.saturating_duration_since(burst.last) >= BURST_GAP {
+                self.burst = None;
+                self.start_glide(burst, now);
+            }
+        }
+        self.step_glide(now)
+    }
+
+    /// Returns the columns and rows scrolled by a wheel event.
+    fn delta(&self, mouse: MouseEvent) -> Option<(i32, i32)> {
+        let horizontal =
+            self.shift_scrolls_horizontally && mouse.modifiers.contains(KeyModifiers::SHIFT);
+        match mouse.kind {
+            MouseEventKind::ScrollUp if horizontal => Some((-1, 0)),
+            MouseEventKind::ScrollDown if horizontal => Some((1, 0)),
+            MouseEventKind::ScrollUp => Some((0, -1)),
+            MouseEventKind::ScrollDown => Some((0, 1)),
+            MouseEventKind::ScrollLeft => Some((-1, 0)),
+            MouseEventKind::ScrollRight => Some((1, 0)),
+            _ => None,
+        }
+    }
+
+    fn emit(&mut self, scroll: Scroll, now: Instant) -> Option<Scroll> {
+        // opposite events coalesced together cancel each other
+        if scroll.delta_column == 0 && scroll.delta_row == 0 {
+            return None;
+        }
+        self.last_emitted = Some(now);
+        Some(scroll)
+    }
+
+    fn record_burst(&mut self, scroll: Scroll, now: Instant) {
+        match &mut self.burst {
+            Some(burst) if now.saturating_duration_since(burst.last) < BURST_GAP => {
+                burst.last = now;
+                burst.events += 1;
+                burst.delta_column += scroll.delta_column;
+                burst.delta_row += scroll.delta_row;
+                burst.last_scroll = scroll;
+            }
+            _ => {
+                self.burst = Some(Burst {
+                    start: now,
+                    last: now,
+                    events: 1,
+                    delta_column: scroll.delta_column,
+                    delta_row: scroll.delta_row,
+                    last_scroll: scroll,
+                });
+            }
+        }
+    }
+
+    fn start_glide(&mut self, burst: Burst, now: Instant) {
+        if self.friction.is_none() || burst.events < MIN_MOMENTUM_EVENTS {
+            return;
+        }
+        let duration = (burst.last - burst.start).max(MOMENTUM_STEP);
+        let steps = duration.as_secs_f64() / MOMENTUM_STEP.as_secs_f64();
+        self.glide = Some(Glide {
+            velocity: (
+                f64::from(burst.delta_column) / steps,
+                f64::from(burst.delta_row) / steps,
+            ),
+            remainder: (0.0, 0.0),
+            next: now,
+            scroll: burst.last_scroll,
+        });
+    }
+
+    fn step_glide(&mut self, now: Instant) -> Option<Scroll> {
+        let friction = self.friction?;
+        let glide = self.glide.as_mut()?;
+        if now < glide.next {
+            return None;
+        }
+        glide.remainder.0 += glide.velocity.0;
+        glide.remainder.1 += glide.velocity.1;
+        let delta_column = glide.remainder.0.trunc();
+        let delta_row = glide.remainder.1.trunc();
+        glide.remainder.0 -= delta_column;
+        glide.remainder.1 -= delta_row;
+        glide.velocity.0 *= friction;
+        glide.velocity.1 *= friction;
+        glide.next = now + MOMENTUM_STEP;
+        let scroll = Scroll {
+            delta_column: delta_column as i32,
+            delta_row: delta_row as i32,
+            ..glide.scroll
+        };
+        if glide.velocity.0.abs() < MIN_MOMENTUM_VELOCITY
+            && glide.velocity.1.abs() < MIN_MOMENTUM_VELOCITY
+        {
+            self.glide = None;
+        }
+        (scroll.delta_column != 0 || scroll.delta_row != 0).then_some(scroll)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::MouseButton;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    fn wheel(kind: MouseEventKind) -> MouseEvent {
+        MouseEvent::new(kind, 2, 3)
+    }
+
+    #[test]
+    fn normalizes_wheel_events() {
+        let mut processor = ScrollProcessor::new().coalesce_interval(Duration::ZERO);
+        let now = Instant::now();
+        let mut delta = |mouse| {
+            processor
+                .process_at(mouse, now)
+                .map(|scroll| (scroll.delta_column, scroll.delta_row))
+        };
+        assert_eq!(delta(wheel(MouseEventKind::ScrollUp)), Some((0, -1)));
+        assert_eq!(delta(wheel(MouseEventKind::ScrollRight)), Some((1, 0)));
+        let mut shifted = wheel(MouseEventKind::ScrollDown);
+        shifted.modifiers = KeyModifiers::SHIFT;
+        assert_eq!(delta(shifted), Some((1, 0)));
+        assert_eq!(delta(wheel(MouseEventKind::Moved)), None);
+
+        let mut processor = ScrollProcessor::new().shift_scrolls_horizontally(false);
+        let scroll = processor.process_at(shifted, now).unwrap();
+        assert_eq!((scroll.delta_column, scroll.delta_row), (0, 1));
+        assert_eq!(scroll.position(), Position::new(2, 3));
+        assert_eq!(scroll.modifiers, KeyModifiers::SHIFT);
+    }
+
+    #[test]
+    fn coalesces_events() {
+        let mut processor = ScrollProcessor::new();
+        let now = Instant::now();
+        let down = wheel(MouseEventKind::ScrollDown);
+        assert!(processor.process_at(down, now).is_some());
+        assert_eq!(processor.deadline(), None);
+        assert_eq!(processor.process_at(down, now + ms(4)), None);
+        assert_eq!(processor.process_at(down, now + ms(8)), None);
+        assert_eq!(processor.deadline(), Some(now + ms(16)));
+        assert_eq!(processor.tick(now + ms(10)), None);
+        assert_eq!(processor.tick(now + ms(16)).unwrap().delta_row, 2);
+        assert_eq!(processor.deadline(), None);
+
+        // the next event after the interval is coalesced with the pending ones
+        assert_eq!(processor.process_at(down, now + ms(20)), None);
+        let up = wheel(MouseEventKind::ScrollUp);
+        assert_eq!(processor.process_at(up, now + ms(24)), None);
+        // opposite events cancel each other
+        assert_eq!(processor.tick(now + ms(40)), None);
+        assert_eq!(
+            processor.process_at(up, now + ms(100)).unwrap().delta_row,
+            -1
+        );
+    }
+
+    #[test]
+    fn glides_after_a_burst() {
+        let mut processor = ScrollProcessor::new()
+            .coalesce_interval(Duration::ZERO)
+            .momentum(0.5);
+        let now = Instant::now();
+        let down = wheel(MouseEventKind::ScrollDown);
+        // 5 rows in 32 milliseconds, i.e. 2.5 rows per step
+        for i in 0..5 {
+            processor.process_at(down, now + ms(i * 8));
+        }
+        assert!(!processor.is_gliding());
+        assert_eq!(processor.deadline(), Some(now + ms(32) + BURST_GAP));
+
+        let mut t = now + ms(32) + BURST_GAP;
+        let mut rows = vec![];
+        while let Some(deadline) = processor.deadline() {
+            t = t.max(deadline);
+            if let Some(scroll) = processor.tick(t) {
+                rows.push(scroll.delta_row);
+            }
+        }
+        assert_eq!(rows, vec![2, 1, 1]);
+        assert!(!processor.is_gliding());
+    }
+
+    #[test]
+    fn single_notch_does_not_glide() {
+        let mut processor = ScrollProcessor::new().momentum(0.9);
+        let now = Instant::now();
+        processor.process_at(wheel(MouseEventKind::ScrollDown), now);
+        assert_eq!(processor.tick(now + ms(100)), None);
+        assert!(!processor.is_gliding());
+        assert_eq!(processor.deadline(), None);
+    }
+
+    #[test]
+    fn click_stops_gliding() {
+        let mut processor = ScrollProcessor::new()
+            .coalesce_interval(Duration::ZERO)
+            .momentum(0.9);
+        let now = Instant::now();
+        for i in 0..4 {
+            processor.process_at(wheel(MouseEventKind::ScrollUp), now + ms(i * 2));
+        }
+        assert!(processor.tick(now + ms(100)).is_some());
+        assert!(processor.is_gliding());
+        let click = wheel(MouseEventKind::Down(MouseButton::Left));
+        assert_eq!(processor.process_at(click, now + ms(110)), None);
+        assert!(!processor.is_gliding());
+        assert_eq!(processor.deadline(), None);
+    }
+}
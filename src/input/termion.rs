@@ -0,0 +1,121 @@
+//! Conversions from [Termion] events to the events of the [`input`] module.
+//!
+//! Termion reports positions starting at 1 and does not report which button was released, so the
+//! conversions make positions zero based and report releases and drags as coming from the left
+//! button.
+//!
+//! [Termion]: https://crates.io/crates/termion
+//! [`input`]: crate::input
+use termion::event::{
+    Event as TEvent, Key as TKey, MouseButton as TMouseButton, MouseEvent as TMouseEvent,
+};
+
+use crate::input::{Event, Key, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+impl From<TEvent> for Event {
+    fn from(event: TEvent) -> Self {
+        match event {
+            TEvent::Key(key) => Event::Key(key.into()),
+            TEvent::Mouse(mouse) => Event::Mouse(mouse.into()),
+            TEvent::Unsupported(_) => Event::Unknown,
+        }
+    }
+}
+
+impl From<TKey> for KeyEvent {
+    fn from(key: TKey) -> Self {
+        let (key, modifiers) = match key {
+            TKey::Char('\n') => (Key::Enter, KeyModifiers::NONE),
+            TKey::Char('\t') => (Key::Tab, KeyModifiers::NONE),
+            TKey::Char(c) => (Key::Char(c), KeyModifiers::NONE),
+            TKey::Alt(c) => (Key::Char(c), KeyModifiers::ALT),
+            TKey::Ctrl(c) => (Key::Char(c), KeyModifiers::CONTROL),
+            TKey::Backspace => (Key::Backspace, KeyModifiers::NONE),
+            TKey::Left => (Key::Left, KeyModifiers::NONE),
+            TKey::Right => (Key::Right, KeyModifiers::NONE),
+            TKey::Up => (Key::Up, KeyModifiers::NONE),
+            TKey::Down => (Key::Down, KeyModifiers::NONE),
+            TKey::Home => (Key::Home, KeyModifiers::NONE),
+            TKey::End => (Key::End, KeyModifiers::NONE),
+            TKey::PageUp => (Key::PageUp, KeyModifiers::NONE),
+            TKey::PageDown => (Key::PageDown, KeyModifiers::NONE),
+            TKey::BackTab => (Key::BackTab, KeyModifiers::SHIFT),
+            TKey::Delete => (Key::Delete, KeyModifiers::NONE),
+            TKey::Insert => (Key::Insert, KeyModifiers::NONE),
+            TKey::F(n) => (Key::F(n), KeyModifiers::NONE),
+            TKey::Esc => (Key::Esc, KeyModifiers::NONE),
+            _ => (Key::Null, KeyModifiers::NONE),
+        };
+        KeyEvent::new(key, modifiers)
+    }
+}
+
+impl From<TMouseEvent> for MouseEvent {
+    fn from(event: TMouseEvent) -> Self {
+        let (kind, x, y) = match event {
+            TMouseEvent::Press(TMouseButton::WheelUp, x, y) => (MouseEventKind::ScrollUp, x, y),
+            TMouseEvent::Press(TMouseButton::WheelDown, x, y) => (MouseEventKind::ScrollDown, x, y),
+            TMouseEvent::Press(TMouseButton::Left, x, y) => {
+                (MouseEventKind::Down(MouseButton::Left), x, y)
+            }
+            TMouseEvent::Press(TMouseButton::Right, x, y) => {
+                (MouseEventKind::Down(MouseButton::Right), x, y)
+            }
+            TMouseEvent::Press(TMouseButton::Middle, x, y) => {
+                (MouseEventKind::Down(MouseButton::Middle), x, y)
+            }
+            TMouseEvent::Release(x, y) => (MouseEventKind::Up(MouseButton::Left), x, y),
+            TMouseEvent::Hold(x, y) => (MouseEventKind::Drag(MouseButton::Left), x, y),
+        };
+        MouseEvent::new(kind, x.saturating_sub(1), y.saturating_sub(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_termion_key() {
+        assert_eq!(
+            KeyEvent::from(TKey::Char('a')),
+            KeyEvent::new(Key::Char('a'), KeyModifiers::NONE)
+        );
+        assert_eq!(
+            KeyEvent::from(TKey::Ctrl('w')),
+            KeyEvent::new(Key::Char('w'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            KeyEvent::from(TKey::Alt('b')),
+            KeyEvent::new(Key::Char('b'), KeyModifiers::ALT)
+        );
+        assert_eq!(KeyEvent::from(TKey::Char('\n')).key, Key::Enter);
+        assert_eq!(KeyEvent::from(TKey::Char('\t')).key, Key::Tab);
+        assert_eq!(KeyEvent::from(TKey::F(12)).key, Key::F(12));
+    }
+
+    #[test]
+    fn from_termion_mouse() {
+        assert_eq!(
+            MouseEvent::from(TMouseEvent::Press(TMouseButton::Left, 1, 1)),
+            MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 0, 0)
+        );
+        assert_eq!(
+            MouseEvent::from(TMouseEvent::Press(TMouseButton::WheelDown, 5, 3)),
+            MouseEvent::new(MouseEventKind::ScrollDown, 4, 2)
+        );
+        assert_eq!(
+            MouseEvent::from(TMouseEvent::Release(10, 2)),
+            MouseEvent::new(MouseEventKind::Up(MouseButton::Left), 9, 1)
+        );
+    }
+
+    #[test]
+    fn from_termion_event() {
+        assert_eq!(
+            Event::from(TEvent::Key(TKey::Esc)),
+            Event::Key(Key::Esc.into())
+        );
+        assert_eq!(Event::from(TEvent::Unsupported(vec![0x1b])), Event::Unknown);
+    }
+}
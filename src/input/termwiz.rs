@@ -0,0 +1,176 @@
+//! Conversions from [Termwiz] events to the events of the [`input`] module.
+//!
+//! Termwiz reports mouse events as the state of the mouse buttons rather than as presses and
+//! releases, so mouse events with a button held down are converted to
+//! [`MouseEventKind::Down`] and events without any button to [`MouseEventKind::Moved`].
+//!
+//! [Termwiz]: https://crates.io/crates/termwiz
+//! [`input`]: crate::input
+use termwiz::input::{
+    InputEvent as TInputEvent, KeyCode as TKeyCode, KeyEvent as TKeyEvent, Modifiers as TModifiers,
+    MouseButtons as TMouseButtons, MouseEvent as TMouseEvent,
+};
+
+use crate::{
+    input::{Event, Key, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
+    layout::Size,
+};
+
+impl From<TInputEvent> for Event {
+    fn from(event: TInputEvent) -> Self {
+        match event {
+            TInputEvent::Key(key) => Event::Key(key.into()),
+            TInputEvent::Mouse(mouse) => Event::Mouse(mouse.into()),
+            TInputEvent::Resized { cols, rows } => {
+                Event::Resize(Size::new(u16_max(cols), u16_max(rows)))
+            }
+            TInputEvent::Paste(text) => Event::Paste(text),
+            _ => Event::Unknown,
+        }
+    }
+}
+
+impl From<TKeyEvent> for KeyEvent {
+    fn from(event: TKeyEvent) -> Self {
+        let key = match event.key {
+            // termwiz reports Shift-Tab as a tab with the shift modifier
+            TKeyCode::Tab if event.modifiers.contains(TModifiers::SHIFT) => Key::BackTab,
+            code => code.into(),
+        };
+        KeyEvent::new(key, event.modifiers.into())
+    }
+}
+
+impl From<TKeyCode> for Key {
+    fn from(code: TKeyCode) -> Self {
+        match code {
+            TKeyCode::Char(c) => Key::Char(c),
+            TKeyCode::Backspace => Key::Backspace,
+            TKeyCode::Enter => Key::Enter,
+            TKeyCode::LeftArrow | TKeyCode::ApplicationLeftArrow => Key::Left,
+            TKeyCode::RightArrow | TKeyCode::ApplicationRightArrow => Key::Right,
+            TKeyCode::UpArrow | TKeyCode::ApplicationUpArrow => Key::Up,
+            TKeyCode::DownArrow | TKeyCode::ApplicationDownArrow => Key::Down,
+            TKeyCode::Home => Key::Home,
+            TKeyCode::End => Key::End,
+            TKeyCode::PageUp => Key::PageUp,
+            TKeyCode::PageDown => Key::PageDown,
+            TKeyCode::Tab => Key::Tab,
+            TKeyCode::Delete => Key::Delete,
+            TKeyCode::Insert => Key::Insert,
+            TKeyCode::Function(n) => Key::F(n),
+            TKeyCode::Escape => Key::Esc,
+            _ => Key::Null,
+        }
+    }
+}
+
+impl From<TModifiers> for KeyModifiers {
+    fn from(modifiers: TModifiers) -> Self {
+        let mut res = KeyModifiers::NONE;
+        if modifiers.contains(TModifiers::SHIFT) {
+            res |= KeyModifiers::SHIFT;
+        }
+        if modifiers.contains(TModifiers::CTRL) {
+            res |= KeyModifiers::CONTROL;
+        }
+        if modifiers.contains(TModifiers::ALT) {
+            res |= KeyModifiers::ALT;
+        }
+        if modifiers.contains(TModifiers::SUPER) {
+            res |= KeyModifiers::SUPER;
+        }
+        res
+    }
+}
+
+impl From<TMouseEvent> for MouseEvent {
+    fn from(event: TMouseEvent) -> Self {
+        let buttons = event.mouse_buttons;
+        let positive = buttons.contains(TMouseButtons::WHEEL_POSITIVE);
+        let kind = if buttons.contains(TMouseButtons::VERT_WHEEL) {
+            if positive {
+                MouseEventKind::ScrollUp
+            } else {
+                MouseEventKind::ScrollDown
+            }
+        } else if buttons.contains(TMouseButtons::HORZ_WHEEL) {
+            if positive {
+                MouseEventKind::ScrollLeft
+            } else {
+                MouseEventKind::ScrollRight
+            }
+        } else if buttons.contains(TMouseButtons::LEFT) {
+            MouseEventKind::Down(MouseButton::Left)
+        } else if buttons.contains(TMouseButtons::RIGHT) {
+            MouseEventKind::Down(MouseButton::Right)
+        } else if buttons.contains(TMouseButtons::MIDDLE) {
+            MouseEventKind::Down(MouseButton::Middle)
+        } else {
+            MouseEventKind::Moved
+        };
+        MouseEvent {
+            kind,
+            // termwiz positions start at 1
+            column: event.x.saturating_sub(1),
+            row: event.y.saturating_sub(1),
+            modifiers: event.modifiers.into(),
+        }
+    }
+}
+
+fn u16_max(i: usize) -> u16 {
+    u16::try_from(i).unwrap_or(u16::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_termwiz_key_event() {
+        let event = TInputEvent::Key(TKeyEvent {
+            key: TKeyCode::Char('c'),
+            modifiers: TModifiers::CTRL | TModifiers::ALT,
+        });
+        assert_eq!(
+            Event::from(event),
+            Event::Key(KeyEvent::new(
+                Key::Char('c'),
+                KeyModifiers::CONTROL | KeyModifiers::ALT
+            ))
+        );
+        let event = TKeyEvent {
+            key: TKeyCode::Tab,
+            modifiers: TModifiers::SHIFT,
+        };
+        assert_eq!(
+            KeyEvent::from(event),
+            KeyEvent::new(Key::BackTab, KeyModifiers::SHIFT)
+        );
+    }
+
+    #[test]
+    fn from_termwiz_key_code() {
+        assert_eq!(Key::from(TKeyCode::ApplicationUpArrow), Key::Up);
+        assert_eq!(Key::from(TKeyCode::PageDown), Key::PageDown);
+        assert_eq!(Key::from(TKeyCode::Function(5)), Key::F(5));
+        assert_eq!(Key::from(TKeyCode::CapsLock), Key::Null);
+    }
+
+    #[test]
+    fn from_termwiz_mouse_event() {
+        let event = TMouseEvent {
+            x: 4,
+            y: 8,
+            mouse_buttons: TMouseButtons::VERT_WHEEL,
+            modifiers: TModifiers::NONE,
+        };
+        assert_eq!(
+            MouseEvent::from(event),
+            MouseEvent::new(MouseEventKind::ScrollDown, 3, 7)
+        );
+        let event = TInputEvent::Resized { cols: 80, rows: 24 };
+        assert_eq!(Event::from(event), Event::Resize(Size::new(80, 24)));
+    }
+}
@@ -0,0 +1,243 @@
+use crate::input::{Event, KeyEventKind, MouseEventKind};
+
+/// An event coalesced by an [`EventThrottle`], with the number of times it was received.
+///
+/// Handling the event `count` times has the same effect as handling each event that was
+/// coalesced, e.g. moving the selection of a list down by `count` items.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ThrottledEvent {
+    /// The event. For scroll events, this is the last one received, at the latest position of the
+    /// mouse cursor.
+    pub event: Event,
+    /// The number of times the event was received since the last frame, at least 1.
+    pub count: u32,
+}
+
+/// Coalesces the key repeats and the scroll events received between two frames.
+///
+/// On slow terminals, or when a key is held down, events arrive quicker than the application can
+/// render them. Handling and rendering each of them makes the application lag behind the input.
+/// Instead, the application pushes all the events available with [`push`], then takes them with
+/// [`drain`] once per frame, handles them and renders once:
+/// - Consecutive presses and repeats of the same key with the same modifiers are coalesced into a
+///   single [`ThrottledEvent`] counting them, so that a widget advances by that many steps in a
+///   single render.
+/// - Consecutive scroll events of the same direction with the same modifiers are coalesced the
+///   same way, at the position of the last one.
+/// - The other events are kept as they are, in order, with a count of 1.
+///
+/// The number of steps of a coalesced event can be capped with [`max_steps`], so that a widget
+/// doesn't overshoot when repeats piled up while the application was busy.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::input::{Event, EventThrottle, Key, KeyEvent, KeyEventKind, KeyModifiers};
+///
+/// let mut throttle = EventThrottle::new();
+/// let down = KeyEvent::from(Key::Down);
+/// throttle.push(Event::Key(down));
+/// for _ in 0..3 {
+///     let repeat = KeyEvent {
+///         kind: KeyEventKind::Repeat,
+///         ..down
+///     };
+///     throttle.push(Event::Key(repeat));
+/// }
+/// throttle.push(Event::Key(Key::Enter.into()));
+///
+/// let events: Vec<_> = throttle.drain().map(|e| (e.event, e.count)).collect();
+/// assert_eq!(
+///     events,
+///     vec![(Event::Key(down), 4), (Event::Key(Key::Enter.into()), 1)]
+/// );
+/// assert!(throttle.is_empty());
+/// ```
+///
+/// With crossterm, the events available at the start of a frame are read without blocking:
+///
+/// ```rust,no_run
+/// # #[cfg(feature = "crossterm")]
+/// # fn run() -> std::io::Result<()> {
+/// use std::time::Duration;
+///
+/// use ratatui::input::{self, EventThrottle};
+///
+/// let mut throttle = EventThrottle::new().max_steps(10);
+/// loop {
+///     throttle.push(input::read()?);
+///     while input::poll(Duration::ZERO)? {
+///         throttle.push(input::read()?);
+///     }
+///     for throttled in throttle.drain() {
+///         for _ in 0..throttled.count {
+///             // handle throttled.event
+///         }
+///     }
+///     // render once
+/// }
+/// # }
+/// ```
+///
+/// [`push`]: EventThrottle::push
+/// [`drain`]: EventThrottle::drain
+/// [`max_steps`]: EventThrottle::max_steps
+#[derive(Debug, Default, Clone)]
+pub struct EventThrottle {
+    max_steps: Option<u32>,
+    /// The events received since the last frame
+    pending: Vec<ThrottledEvent>,
+}
+
+impl EventThrottle {
+    /// Creates a new `EventThrottle`, counting any number of steps.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of times a coalesced event is counted. The events received beyond
+    /// that are dropped.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn max_steps(mut self, max_steps: u32) -> Self {
+        self.max_steps = Some(max_steps.max(1));
+        self
+    }
+
+    /// Returns `true` if no event was received since the last frame.
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Returns the number of events to handle in the next frame, after coalescing.
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Adds an event received from the terminal, coalescing it with the previous one if it
+    /// repeats it.
+    pub fn push(&mut self, event: Event) {
+        if let Some(last) = self.pending.last_mut() {
+            if repeats(&last.event, &event) {
+                if self.max_steps.map_or(true, |max| last.count < max) {
+                    last.count += 1;
+                }
+                // a scroll is reported at the latest position of the mouse
+                if matches!(event, Event::Mouse(_)) {
+                    last.event = event;
+                }
+                return;
+            }
+        }
+        self.pending.push(ThrottledEvent { event, count: 1 });
+    }
+
+    /// Takes the events received since the last frame, in the order they were received.
+    pub fn drain(&mut self) -> std::vec::Drain<'_, ThrottledEvent> {
+        self.pending.drain(..)
+    }
+}
+
+/// Returns `true` if `event` repeats `last`: a press or a repeat of the same key, or a scroll in
+/// the same direction, with the same modifiers.
+fn repeats(last: &Event, event: &Event) -> bool {
+    match (last, event) {
+        (Event::Key(last), Event::Key(key)) => {
+            last.kind != KeyEventKind::Release
+                && key.kind != KeyEventKind::Release
+                && last.key == key.key
+                && last.modifiers == key.modifiers
+        }
+        (Event::Mouse(last), Event::Mouse(mouse)) => {
+            let is_scroll = matches!(
+                mouse.kind,
+                MouseEventKind::ScrollUp
+                    | MouseEventKind::ScrollDown
+                    | MouseEventKind::ScrollLeft
+                    | MouseEventKind::ScrollRight
+            );
+            is_scroll && last.kind == mouse.kind && last.modifiers == mouse.modifiers
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::{Key, KeyEvent, KeyModifiers, MouseButton, MouseEvent};
+
+    fn counts(throttle: &mut EventThrottle) -> Vec<(Event, u32)> {
+        throttle.drain().map(|e| (e.event, e.count)).collect()
+    }
+
+    #[test]
+    fn coalesces_key_repeats() {
+        let mut throttle = EventThrottle::new();
+        let repeat = KeyEvent {
+            kind: KeyEventKind::Repeat,
+            ..KeyEvent::from(Key::Down)
+        };
+        throttle.push(Event::Key(Key::Down.into()));
+        throttle.push(Event::Key(repeat));
+        throttle.push(Event::Key(Key::Down.into()));
+        // other modifiers, then a release, are not coalesced
+        let shifted = KeyEvent::new(Key::Down, KeyModifiers::SHIFT);
+        throttle.push(Event::Key(shifted));
+        let release = KeyEvent {
+            kind: KeyEventKind::Release,
+            ..shifted
+        };
+        throttle.push(Event::Key(release));
+        throttle.push(Event::Key(release));
+        assert_eq!(throttle.len(), 4);
+        assert_eq!(
+            counts(&mut throttle),
+            vec![
+                (Event::Key(Key::Down.into()), 3),
+                (Event::Key(shifted), 1),
+                (Event::Key(release), 1),
+                (Event::Key(release), 1),
+            ]
+        );
+        assert!(throttle.is_empty());
+    }
+
+    #[test]
+    fn coalesces_scrolls() {
+        let mut throttle = EventThrottle::new();
+        let scroll = |kind, column| Event::Mouse(MouseEvent::new(kind, column, 0));
+        throttle.push(scroll(MouseEventKind::ScrollDown, 1));
+        throttle.push(scroll(MouseEventKind::ScrollDown, 2));
+        throttle.push(scroll(MouseEventKind::ScrollUp, 3));
+        let click = MouseEventKind::Down(MouseButton::Left);
+        throttle.push(scroll(click, 3));
+        throttle.push(scroll(click, 3));
+        assert_eq!(
+            counts(&mut throttle),
+            vec![
+                (scroll(MouseEventKind::ScrollDown, 2), 2),
+                (scroll(MouseEventKind::ScrollUp, 3), 1),
+                (scroll(click, 3), 1),
+                (scroll(click, 3), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn caps_the_steps() {
+        let mut throttle = EventThrottle::new().max_steps(2);
+        for _ in 0..5 {
+            throttle.push(Event::Key(Key::Char('j').into()));
+        }
+        throttle.push(Event::FocusLost);
+        assert_eq!(
+            counts(&mut throttle),
+            vec![
+                (Event::Key(Key::Char('j').into()), 2),
+                (Event::FocusLost, 1)
+            ]
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Checks the value of a text field.
+///
+/// A validator returns an error message describing the problem when the value is invalid.
+/// Validators can be configured on a [`TextInput`], which validates its value when rendered, or
+/// used directly e.g. when a form is submitted.
+///
+/// Besides the built-in validators ([`NonEmpty`], [`Integer`], [`Float`], [`MaxLength`] and, with
+/// the `regex` feature, [`Matches`]), any closure taking a `&str` and returning a
+/// `Result<(), String>` is a validator.
+///
+/// [`TextInput`]: crate::widgets::TextInput
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::input::{Integer, Validator};
+///
+/// assert_eq!(Integer.validate("42"), Ok(()));
+/// assert!(Integer.validate("4.2").is_err());
+///
+/// let even = |value: &str| match value.parse::<i64>() {
+///     Ok(n) if n % 2 == 0 => Ok(()),
+///     _ => Err("must be an even number".to_string()),
+/// };
+/// assert!(even.validate("3").is_err());
+/// ```
+pub trait Validator {
+    /// Returns `Ok(())` if the value is valid, or an error message otherwise.
+    fn validate(&self, value: &str) -> Result<(), String>;
+}
+
+impl<F> Validator for F
+where
+    F: Fn(&str) -> Result<(), String>,
+{
+    fn validate(&self, value: &str) -> Result<(), String> {
+        self(value)
+    }
+}
+
+/// Rejects empty values and values made only of whitespace.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct NonEmpty;
+
+impl Validator for NonEmpty {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        if value.trim().is_empty() {
+            Err("must not be empty".to_string())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Accepts integers, with an optional sign.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Integer;
+
+impl Validator for Integer {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        value
+            .trim()
+            .parse::<i128>()
+            .map(|_| ())
+            .map_err(|_| "must be an integer".to_string())
+    }
+}
+
+/// Accepts decimal numbers, e.g. `-1.5` or `2e3`.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Float;
+
+impl Validator for Float {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        match value.trim().parse::<f64>() {
+            Ok(n) if n.is_finite() => Ok(()),
+            _ => Err("must be a number".to_string()),
+        }
+    }
+}
+
+/// Rejects values longer than the given number of graphemes.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct MaxLength(pub usize);
+
+impl Validator for MaxLength {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        if value.graphemes(true).count() > self.0 {
+            Err(format!("must be at most {} characters long", self.0))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Accepts values matching a regular expression.
+///
+/// The regular expression must match the whole value, so `^` and `$` are implied.
+#[cfg(feature = "regex")]
+#[derive(Debug, Clone)]
+pub struct Matches {
+    regex: regex::Regex,
+    message: String,
+}
+
+#[cfg(feature = "regex")]
+impl Matches {
+    /// Creates a validator accepting the values matching the given regular expression, and
+    /// reporting the given message otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the regular expression is invalid.
+    pub fn new<T>(pattern: &str, message: T) -> Result<Self, regex::Error>
+    where
+        T: Into<String>,
+    {
+        Ok(Self {
+            regex: regex::Regex::new(&format!("^(?:{pattern})$"))?,
+            message: message.into(),
+        })
+    }
+}
+
+#[cfg(feature = "regex")]
+impl Validator for Matches {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        if self.regex.is_match(value) {
+            Ok(())
+        } else {
+            Err(self.message.clone())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_empty() {
+        assert_eq!(NonEmpty.validate("a"), Ok(()));
+        assert_eq!(NonEmpty.validate(" "), Err("must not be empty".to_string()));
+    }
+
+    #[test]
+    fn integer() {
+        assert_eq!(Integer.validate("-12"), Ok(()));
+        assert!(Integer.validate("1.5").is_err());
+        assert!(Integer.validate("").is_err());
+    }
+
+    #[test]
+    fn float() {
+        assert_eq!(Float.validate("1.5"), Ok(()));
+        assert_eq!(Float.validate("2e3"), Ok(()));
+        assert!(Float.validate("inf").is_err());
+        assert!(Float.validate("abc").is_err());
+    }
+
+    #[test]
+    fn max_length() {
+        assert_eq!(MaxLength(3).validate("e\u{301}ab"), Ok(()));
+        assert_eq!(
+            MaxLength(3).validate("abcd"),
+            Err("must be at most 3 characters long".to_string())
+        );
+    }
+
+    #[test]
+    fn closure() {
+        let validator = |value: &str| {
+            if value == "ok" {
+                Ok(())
+            } else {
+                Err("not ok".to_string())
+            }
+        };
+        assert_eq!(validator.validate("ok"), Ok(()));
+        assert_eq!(validator.validate("ko"), Err("not ok".to_string()));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn matches() {
+        let validator = Matches::new("[a-z]+@[a-z]+", "must be an email address").unwrap();
+        assert_eq!(validator.validate("me@host"), Ok(()));
+        assert_eq!(
+            validator.validate("me@host!"),
+            Err("must be an email address".to_string())
+        );
+        assert!(Matches::new("(", "").is_err());
+    }
+}
@@ -16,8 +16,15 @@ use itertools::Itertools;
 use lru::LruCache;
 use strum::{Display, EnumString};
 
+mod position;
 mod rect;
+mod size;
+mod viewport;
+pub use position::Position;
 pub use rect::*;
+pub use size::Size;
+pub(crate) use viewport::first_visible_item;
+pub use viewport::Viewport;
 
 type Cache = LruCache<(Rect, Layout), Rc<[Rect]>>;
 
@@ -88,13 +95,6 @@ pub struct Margin {
     pub vertical: u16,
 }
 
-/// A simple size struct
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
-pub struct Size {
-    pub width: u16,
-    pub height: u16,
-}
-
 #[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum Alignment {
     #[default]
@@ -598,7 +598,7 @@ impl Layout {
             .map(|element| {
                 let start = changes.get(&element.start).unwrap_or(&0.0).round() as u16;
                 let end = changes.get(&element.end).unwrap_or(&0.0).round() as u16;
-                let size = end - start;
+                let size = end.saturating_sub(start);
                 match layout.direction {
                     Direction::Horizontal => Rect {
                         x: start,
@@ -771,12 +771,6 @@ impl Display for Constraint {
     }
 }
 
-impl From<(u16, u16)> for Size {
-    fn from((width, height): (u16, u16)) -> Self {
-        Size { width, height }
-    }
-}
-
 impl Element {
     fn new() -> Element {
         Element {
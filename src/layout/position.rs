@@ -0,0 +1,81 @@
+#![warn(missing_docs)]
+use std::fmt;
+
+use crate::layout::Rect;
+
+/// The position of a cell in the terminal, e.g. of the cursor or of a mouse event.
+///
+/// The origin `(0, 0)` is the top left corner of the terminal. Positions can be converted from
+/// and into `(x, y)` tuples, and from the top left corner of a [`Rect`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::layout::{Position, Rect};
+///
+/// let position = Position::new(2, 1);
+/// assert_eq!(Position::from((2, 1)), position);
+/// assert_eq!(Rect::new(2, 1, 10, 5).as_position(), position);
+/// let (x, y) = position.into();
+/// assert_eq!((x, y), (2, 1));
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Position {
+    /// The column, from the left of the terminal.
+    pub x: u16,
+    /// The row, from the top of the terminal.
+    pub y: u16,
+}
+
+impl Position {
+    /// The top left corner of the terminal.
+    pub const ORIGIN: Position = Position::new(0, 0);
+
+    /// Creates a new position.
+    pub const fn new(x: u16, y: u16) -> Position {
+        Position { x, y }
+    }
+}
+
+impl From<(u16, u16)> for Position {
+    fn from((x, y): (u16, u16)) -> Self {
+        Position { x, y }
+    }
+}
+
+impl From<Position> for (u16, u16) {
+    fn from(position: Position) -> Self {
+        (position.x, position.y)
+    }
+}
+
+impl From<Rect> for Position {
+    fn from(rect: Rect) -> Self {
+        rect.as_position()
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        let position = Position::new(1, 2);
+        assert_eq!(Position::from((1, 2)), position);
+        assert_eq!(<(u16, u16)>::from(position), (1, 2));
+        assert_eq!(Position::from(Rect::new(1, 2, 3, 4)), position);
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(Position::new(1, 2).to_string(), "(1, 2)");
+    }
+}
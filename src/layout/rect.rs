@@ -1,10 +1,10 @@
 #![warn(missing_docs)]
-use std::{
-    cmp::{max, min},
-    fmt,
-};
+use std::fmt;
 
-use crate::prelude::*;
+use crate::{
+    layout::{Position, Size},
+    prelude::*,
+};
 
 mod offset;
 
@@ -25,6 +25,12 @@ pub struct Rect {
     pub height: u16,
 }
 
+impl From<(Position, Size)> for Rect {
+    fn from((position, size): (Position, Size)) -> Self {
+        Rect::new(position.x, position.y, size.width, size.height)
+    }
+}
+
 impl fmt::Display for Rect {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}x{}+{}+{}", self.width, self.height, self.x, self.y)
@@ -34,23 +40,33 @@ impl fmt::Display for Rect {
 impl Rect {
     /// Creates a new rect, with width and height limited to keep the area under max u16. If
     /// clipped, aspect ratio will be preserved.
-    pub fn new(x: u16, y: u16, width: u16, height: u16) -> Rect {
-        let max_area = u16::max_value();
-        let (clipped_width, clipped_height) =
-            if u32::from(width) * u32::from(height) > u32::from(max_area) {
-                let aspect_ratio = f64::from(width) / f64::from(height);
-                let max_area_f = f64::from(max_area);
-                let height_f = (max_area_f / aspect_ratio).sqrt();
-                let width_f = height_f * aspect_ratio;
-                (width_f as u16, height_f as u16)
-            } else {
-                (width, height)
-            };
+    ///
+    /// This is a `const fn`, so fixed areas can be declared as constants:
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// const POPUP: Rect = Rect::new(10, 5, 40, 10);
+    /// const POPUP_CONTENT: Rect = POPUP.inner(&Margin::new(1, 1));
+    /// assert_eq!(POPUP_CONTENT, Rect::new(11, 6, 38, 8));
+    /// ```
+    pub const fn new(x: u16, y: u16, width: u16, height: u16) -> Rect {
+        let max_area = u16::MAX as u64;
+        let (width, height) = (width as u64, height as u64);
+        let (clipped_width, clipped_height) = if width * height > max_area {
+            // the largest size with the same aspect ratio: `w * h = max_area` and `w / h` is the
+            // ratio, i.e. `w = sqrt(max_area * ratio)` and `h = sqrt(max_area / ratio)`
+            (
+                isqrt(max_area * width / height),
+                isqrt(max_area * height / width),
+            )
+        } else {
+            (width, height)
+        };
         Rect {
             x,
             y,
-            width: clipped_width,
-            height: clipped_height,
+            width: clipped_width as u16,
+            height: clipped_height as u16,
         }
     }
 
@@ -94,12 +110,12 @@ impl Rect {
     /// Returns a new rect inside the current one, with the given margin on each side.
     ///
     /// If the margin is larger than the rect, the returned rect will have no area.
-    pub fn inner(self, margin: &Margin) -> Rect {
+    pub const fn inner(self, margin: &Margin) -> Rect {
         let doubled_margin_horizontal = margin.horizontal.saturating_mul(2);
         let doubled_margin_vertical = margin.vertical.saturating_mul(2);
 
         if self.width < doubled_margin_horizontal || self.height < doubled_margin_vertical {
-            Rect::default()
+            Rect::new(0, 0, 0, 0)
         } else {
             Rect {
                 x: self.x.saturating_add(margin.horizontal),
@@ -131,7 +147,7 @@ impl Rect {
     }
 
     /// Returns a new rect that contains both the current one and the given one.
-    pub fn union(self, other: Rect) -> Rect {
+    pub const fn union(self, other: Rect) -> Rect {
         let x1 = min(self.x, other.x);
         let y1 = min(self.y, other.y);
         let x2 = max(self.right(), other.right());
@@ -147,7 +163,7 @@ impl Rect {
     /// Returns a new rect that is the intersection of the current one and the given one.
     ///
     /// If the two rects do not intersect, the returned rect will have no area.
-    pub fn intersection(self, other: Rect) -> Rect {
+    pub const fn intersection(self, other: Rect) -> Rect {
         let x1 = max(self.x, other.x);
         let y1 = max(self.y, other.y);
         let x2 = min(self.right(), other.right());
@@ -167,6 +183,115 @@ impl Rect {
             && self.y < other.bottom()
             && self.bottom() > other.y
     }
+
+    /// Returns true if the given position is inside the rect.
+    ///
+    /// The position is inside if it is on or after the top left corner, and before the
+    /// [`right`](Rect::right) and [`bottom`](Rect::bottom) coordinates.
+    pub const fn contains(self, position: Position) -> bool {
+        position.x >= self.x
+            && position.x < self.right()
+            && position.y >= self.y
+            && position.y < self.bottom()
+    }
+
+    /// Returns the position of the top left corner of the rect.
+    pub const fn as_position(self) -> Position {
+        Position::new(self.x, self.y)
+    }
+
+    /// Returns the size of the rect.
+    pub const fn as_size(self) -> Size {
+        Size::new(self.width, self.height)
+    }
+
+    /// Splits the rect in two at the given height from its top, returning the top and bottom
+    /// parts, e.g. to put a header above the content.
+    ///
+    /// The height is limited to the height of the rect, the bottom part is then empty.
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// const SCREEN: Rect = Rect::new(0, 0, 80, 24);
+    /// const HEADER: Rect = SCREEN.split_top(2).0;
+    /// const STATUS_BAR: Rect = SCREEN.split_bottom(1).1;
+    /// assert_eq!(HEADER, Rect::new(0, 0, 80, 2));
+    /// assert_eq!(STATUS_BAR, Rect::new(0, 23, 80, 1));
+    /// ```
+    pub const fn split_top(self, height: u16) -> (Rect, Rect) {
+        let height = min(height, self.height);
+        (
+            Rect { height, ..self },
+            Rect {
+                y: self.y.saturating_add(height),
+                height: self.height - height,
+                ..self
+            },
+        )
+    }
+
+    /// Splits the rect in two at the given height from its bottom, returning the top and bottom
+    /// parts, e.g. to put a status bar below the content.
+    ///
+    /// The height is limited to the height of the rect, the top part is then empty.
+    pub const fn split_bottom(self, height: u16) -> (Rect, Rect) {
+        self.split_top(self.height.saturating_sub(height))
+    }
+
+    /// Splits the rect in two at the given width from its left, returning the left and right
+    /// parts, e.g. to put a sidebar next to the content.
+    ///
+    /// The width is limited to the width of the rect, the right part is then empty.
+    pub const fn split_left(self, width: u16) -> (Rect, Rect) {
+        let width = min(width, self.width);
+        (
+            Rect { width, ..self },
+            Rect {
+                x: self.x.saturating_add(width),
+                width: self.width - width,
+                ..self
+            },
+        )
+    }
+
+    /// Splits the rect in two at the given width from its right, returning the left and right
+    /// parts.
+    ///
+    /// The width is limited to the width of the rect, the left part is then empty.
+    pub const fn split_right(self, width: u16) -> (Rect, Rect) {
+        self.split_left(self.width.saturating_sub(width))
+    }
+}
+
+const fn min(a: u16, b: u16) -> u16 {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+
+const fn max(a: u16, b: u16) -> u16 {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Returns the integer square root of `n`, i.e. the square root rounded down.
+const fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method, decreasing from a first guess above the root
+    let mut x = n;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
 }
 
 #[cfg(test)]
@@ -288,6 +413,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn contains() {
+        let rect = Rect::new(1, 2, 3, 4);
+        assert!(rect.contains(Position::new(1, 2)));
+        assert!(rect.contains(Position::new(3, 5)));
+        assert!(!rect.contains(Position::new(4, 2)));
+        assert!(!rect.contains(Position::new(1, 6)));
+        assert!(!rect.contains(Position::new(0, 3)));
+    }
+
+    #[test]
+    fn position_and_size() {
+        let rect = Rect::new(1, 2, 3, 4);
+        assert_eq!(rect.as_position(), Position::new(1, 2));
+        assert_eq!(rect.as_size(), Size::new(3, 4));
+        assert_eq!(Rect::from((rect.as_position(), rect.as_size())), rect);
+    }
+
     #[test]
     fn intersects() {
         assert!(Rect::new(1, 2, 3, 4).intersects(Rect::new(2, 3, 4, 5)));
@@ -351,6 +494,65 @@ mod tests {
         const _RIGHT: u16 = RECT.right();
         const _TOP: u16 = RECT.top();
         const _BOTTOM: u16 = RECT.bottom();
+        const NEW: Rect = Rect::new(0, 0, 1000, 1000);
+        const INNER: Rect = NEW.inner(&Margin::new(1, 1));
+        const _UNION: Rect = RECT.union(INNER);
+        const _INTERSECTION: Rect = RECT.intersection(INNER);
+        const _SPLIT: (Rect, Rect) = RECT.split_top(1);
         assert!(RECT.intersects(RECT));
+        assert_eq!(NEW, Rect::new(0, 0, 255, 255));
+        assert_eq!(INNER, Rect::new(1, 1, 253, 253));
+    }
+
+    #[test]
+    fn split_top_and_bottom() {
+        let rect = Rect::new(1, 2, 3, 4);
+        assert_eq!(
+            rect.split_top(1),
+            (Rect::new(1, 2, 3, 1), Rect::new(1, 3, 3, 3))
+        );
+        assert_eq!(
+            rect.split_bottom(1),
+            (Rect::new(1, 2, 3, 3), Rect::new(1, 5, 3, 1))
+        );
+        // splitting more than the height saturates
+        assert_eq!(
+            rect.split_top(10),
+            (Rect::new(1, 2, 3, 4), Rect::new(1, 6, 3, 0))
+        );
+        assert_eq!(
+            rect.split_bottom(10),
+            (Rect::new(1, 2, 3, 0), Rect::new(1, 2, 3, 4))
+        );
+    }
+
+    #[test]
+    fn split_left_and_right() {
+        let rect = Rect::new(1, 2, 3, 4);
+        assert_eq!(
+            rect.split_left(1),
+            (Rect::new(1, 2, 1, 4), Rect::new(2, 2, 2, 4))
+        );
+        assert_eq!(
+            rect.split_right(1),
+            (Rect::new(1, 2, 2, 4), Rect::new(3, 2, 1, 4))
+        );
+        assert_eq!(
+            rect.split_left(10),
+            (Rect::new(1, 2, 3, 4), Rect::new(4, 2, 0, 4))
+        );
+        assert_eq!(
+            rect.split_right(10),
+            (Rect::new(1, 2, 0, 4), Rect::new(1, 2, 3, 4))
+        );
+    }
+
+    #[test]
+    fn isqrt() {
+        for n in 0..10_000u64 {
+            let root = super::isqrt(n);
+            assert!(root * root <= n && (root + 1) * (root + 1) > n, "{n}");
+        }
+        assert_eq!(super::isqrt(u64::from(u32::MAX) * 65535), 16_777_087);
     }
 }
@@ -0,0 +1,77 @@
+#![warn(missing_docs)]
+use std::fmt;
+
+use crate::layout::Rect;
+
+/// The size of an area of the terminal, e.g. of the terminal itself.
+///
+/// Sizes can be converted from and into `(width, height)` tuples, and from the size of a
+/// [`Rect`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::layout::{Position, Rect, Size};
+///
+/// let size = Size::new(80, 24);
+/// assert_eq!(Size::from((80, 24)), size);
+/// assert_eq!(Rect::new(2, 1, 80, 24).as_size(), size);
+/// assert_eq!(Rect::from((Position::ORIGIN, size)), Rect::new(0, 0, 80, 24));
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Size {
+    /// The number of columns.
+    pub width: u16,
+    /// The number of rows.
+    pub height: u16,
+}
+
+impl Size {
+    /// Creates a new size.
+    pub const fn new(width: u16, height: u16) -> Size {
+        Size { width, height }
+    }
+}
+
+impl From<(u16, u16)> for Size {
+    fn from((width, height): (u16, u16)) -> Self {
+        Size { width, height }
+    }
+}
+
+impl From<Size> for (u16, u16) {
+    fn from(size: Size) -> Self {
+        (size.width, size.height)
+    }
+}
+
+impl From<Rect> for Size {
+    fn from(rect: Rect) -> Self {
+        rect.as_size()
+    }
+}
+
+impl fmt::Display for Size {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        let size = Size::new(3, 4);
+        assert_eq!(Size::from((3, 4)), size);
+        assert_eq!(<(u16, u16)>::from(size), (3, 4));
+        assert_eq!(Size::from(Rect::new(1, 2, 3, 4)), size);
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(Size::new(3, 4).to_string(), "3x4");
+    }
+}
@@ -0,0 +1,177 @@
+#![warn(missing_docs)]
+use std::ops::Range;
+
+use crate::layout::Rect;
+
+/// The part of a scrollable content shown in an area of the terminal, e.g. the rows of a list.
+///
+/// The viewport is a rectangle in the coordinates of the content: `x` and `y` are the offsets of
+/// the first column and row shown, and `width` and `height` the size of the area showing them.
+/// [`ensure_visible`](Self::ensure_visible) scrolls it as little as possible to show a part of
+/// the content, e.g. the cursor of an input or the selected item of a list, which is how the
+/// [`TextArea`], [`List`] and [`Table`] widgets keep their cursor or selection on screen.
+///
+/// [`TextArea`]: crate::widgets::TextArea
+/// [`List`]: crate::widgets::List
+/// [`Table`]: crate::widgets::Table
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::layout::{Rect, Viewport};
+///
+/// let mut viewport = Viewport::new(0, 0, 20, 5);
+/// // the rows 0 to 4 are shown, showing the row 7 scrolls down to the rows 3 to 7
+/// assert!(viewport.ensure_visible(Rect::new(4, 7, 1, 1)));
+/// assert_eq!(viewport.rows(), 3..8);
+/// // the row 5 is already shown
+/// assert!(!viewport.ensure_visible(Rect::new(4, 5, 1, 1)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Viewport {
+    /// The first column of the content shown.
+    pub x: usize,
+    /// The first row of the content shown.
+    pub y: usize,
+    /// The number of columns shown.
+    pub width: usize,
+    /// The number of rows shown.
+    pub height: usize,
+}
+
+impl Viewport {
+    /// Creates a new viewport showing `width` columns and `height` rows from the given offsets.
+    pub const fn new(x: usize, y: usize, width: usize, height: usize) -> Viewport {
+        Viewport {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the columns of the content shown.
+    pub const fn columns(&self) -> Range<usize> {
+        self.x..self.x.saturating_add(self.width)
+    }
+
+    /// Returns the rows of the content shown.
+    pub const fn rows(&self) -> Range<usize> {
+        self.y..self.y.saturating_add(self.height)
+    }
+
+    /// Scrolls as little as possible to show the given area of the content. Returns `true` if
+    /// the viewport scrolled.
+    ///
+    /// An area larger than the viewport is aligned with its top left corner.
+    pub fn ensure_visible(&mut self, area: Rect) -> bool {
+        let columns = usize::from(area.left())..usize::from(area.right());
+        let rows = usize::from(area.top())..usize::from(area.bottom());
+        // both axes are scrolled, even if the first one changed
+        let scrolled = self.ensure_columns_visible(columns);
+        self.ensure_rows_visible(rows) || scrolled
+    }
+
+    /// Scrolls horizontally as little as possible to show the given columns, see
+    /// [`ensure_visible`](Self::ensure_visible). Returns `true` if the viewport scrolled.
+    pub fn ensure_columns_visible(&mut self, columns: Range<usize>) -> bool {
+        scroll_into_view(&mut self.x, self.width, columns)
+    }
+
+    /// Scrolls vertically as little as possible to show the given rows, see
+    /// [`ensure_visible`](Self::ensure_visible). Returns `true` if the viewport scrolled.
+    pub fn ensure_rows_visible(&mut self, rows: Range<usize>) -> bool {
+        scroll_into_view(&mut self.y, self.height, rows)
+    }
+
+    /// Scrolls up so that the viewport doesn't show past the end of a content of `len` rows,
+    /// unless the content is shorter than the viewport.
+    pub fn clamp_rows(&mut self, len: usize) {
+        self.y = self.y.min(len.saturating_sub(self.height));
+    }
+
+    /// Scrolls left so that the viewport doesn't show past the end of a content of `len`
+    /// columns, unless the content is narrower than the viewport.
+    pub fn clamp_columns(&mut self, len: usize) {
+        self.x = self.x.min(len.saturating_sub(self.width));
+    }
+}
+
+/// Moves the `offset` of a view of `size` cells as little as possible to show `range`, aligning
+/// it with the start of a range larger than the view. Returns `true` if the offset changed.
+fn scroll_into_view(offset: &mut usize, size: usize, range: Range<usize>) -> bool {
+    let previous = *offset;
+    if range.start < *offset || range.len() >= size {
+        *offset = range.start;
+    } else if range.end > *offset + size {
+        *offset = range.end - size;
+    }
+    *offset != previous
+}
+
+/// Returns the first item shown by a list scrolled from the item `offset` to show the item
+/// `selected`, where `height` gives the number of rows of each item and the viewport shows
+/// `size` rows.
+///
+/// The list is scrolled by whole items: when scrolling down, the first item shown is the first
+/// one that is entirely in the [`Viewport`].
+pub(crate) fn first_visible_item(
+    offset: usize,
+    selected: usize,
+    size: usize,
+    height: impl Fn(usize) -> usize,
+) -> usize {
+    if selected < offset {
+        return selected;
+    }
+    // rows from the top of the offset item, the items before it don't need to be measured
+    let top: usize = (offset..selected).map(&height).sum();
+    let mut viewport = Viewport::new(0, 0, 0, size);
+    viewport.ensure_rows_visible(top..top + height(selected));
+    let mut start = offset;
+    let mut y = 0;
+    while y < viewport.y {
+        y += height(start);
+        start += 1;
+    }
+    start
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ensure_visible() {
+        let mut viewport = Viewport::new(10, 10, 5, 3);
+        assert!(!viewport.ensure_visible(Rect::new(12, 11, 2, 2)));
+        assert!(viewport.ensure_visible(Rect::new(14, 12, 2, 2)));
+        assert_eq!((viewport.columns(), viewport.rows()), (11..16, 11..14));
+        assert!(viewport.ensure_visible(Rect::new(2, 1, 1, 1)));
+        assert_eq!((viewport.x, viewport.y), (2, 1));
+        // a larger area is aligned with its start
+        assert!(viewport.ensure_visible(Rect::new(20, 20, 10, 10)));
+        assert_eq!((viewport.x, viewport.y), (20, 20));
+    }
+
+    #[test]
+    fn clamp() {
+        let mut viewport = Viewport::new(8, 8, 4, 4);
+        viewport.clamp_rows(10);
+        viewport.clamp_columns(3);
+        assert_eq!((viewport.x, viewport.y), (0, 6));
+    }
+
+    #[test]
+    fn first_visible_item_scrolls_by_items() {
+        let heights = [1, 3, 1, 2, 1];
+        let height = |i: usize| heights[i];
+        assert_eq!(first_visible_item(3, 1, 4, height), 1);
+        assert_eq!(first_visible_item(0, 2, 5, height), 0);
+        // showing the rows 5 and 6 of the item 3 hides the item 1, which starts at the row 1
+        assert_eq!(first_visible_item(0, 3, 5, height), 2);
+        // an item taller than the viewport is shown from its top
+        assert_eq!(first_visible_item(0, 1, 2, height), 1);
+    }
+}
@@ -286,6 +286,48 @@
     feature = "document-features",
     doc = "[`calendar`]: widgets::calendar::Monthly"
 )]
+#![cfg_attr(
+    feature = "document-features",
+    doc = "[`json_view`]: widgets::json_view::JsonView"
+)]
+#![cfg_attr(
+    feature = "document-features",
+    doc = "[`Table::copy_selected`]: widgets::Table::copy_selected"
+)]
+#![cfg_attr(feature = "document-features", doc = "[`Matches`]: input::Matches")]
+#![cfg_attr(feature = "document-features", doc = "[`TextArea`]: widgets::TextArea")]
+#![cfg_attr(
+    feature = "document-features",
+    doc = "[`derive(Form)`]: derive@widgets::Form"
+)]
+#![cfg_attr(feature = "document-features", doc = "[`Form`]: struct@widgets::Form")]
+#![cfg_attr(
+    feature = "document-features",
+    doc = "[`PathInput`]: widgets::PathInput"
+)]
+#![cfg_attr(feature = "document-features", doc = "[`streams`]: streams")]
+#![cfg_attr(feature = "document-features", doc = "[`Theme`]: style::Theme")]
+#![cfg_attr(
+    feature = "document-features",
+    doc = "[`ThemeWatcher`]: style::ThemeWatcher"
+)]
+#![cfg_attr(feature = "document-features", doc = "[`Buffer`]: buffer::Buffer")]
+#![cfg_attr(
+    feature = "document-features",
+    doc = "[`grapheme_width`]: text::grapheme_width"
+)]
+#![cfg_attr(
+    feature = "document-features",
+    doc = "[`TextAreaState::set_vim`]: widgets::TextAreaState::set_vim"
+)]
+#![cfg_attr(
+    feature = "document-features",
+    doc = "[`TextAreaState::search_regex`]: widgets::TextAreaState::search_regex"
+)]
+#![cfg_attr(
+    feature = "document-features",
+    doc = "[`SyntectHighlighter`]: widgets::SyntectHighlighter"
+)]
 //!
 //! [Ratatui Website]: https://ratatui.rs/
 //! [Installation]: https://ratatui.rs/installation/
@@ -348,9 +390,14 @@
     html_favicon_url = "https://raw.githubusercontent.com/ratatui-org/ratatui/main/assets/favicon.ico"
 )]
 
+pub mod animation;
 pub mod backend;
 pub mod buffer;
+pub mod error;
+pub mod input;
 pub mod layout;
+#[cfg(feature = "tokio")]
+pub mod streams;
 pub mod style;
 pub mod symbols;
 pub mod terminal;
@@ -358,6 +405,10 @@ pub mod text;
 pub mod widgets;
 
 #[doc(inline)]
-pub use self::terminal::{CompletedFrame, Frame, Terminal, TerminalOptions, Viewport};
+pub use self::error::{Error, Result};
+#[doc(inline)]
+pub use self::terminal::{
+    CompletedFrame, Frame, ResizeBehavior, Terminal, TerminalOptions, Viewport,
+};
 
 pub mod prelude;
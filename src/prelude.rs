@@ -27,8 +27,8 @@ pub use crate::{
     backend::{self, Backend},
     buffer::{self, Buffer},
     layout::{self, Alignment, Constraint, Corner, Direction, Layout, Margin, Rect},
-    style::{self, Color, Modifier, Style, Styled, Stylize},
+    style::{self, Color, Modifier, Style, StyleAll, Styled, Stylize},
     symbols::{self, Marker},
-    terminal::{CompletedFrame, Frame, Terminal, TerminalOptions, Viewport},
+    terminal::{CompletedFrame, Frame, ResizeBehavior, Terminal, TerminalOptions, Viewport},
     text::{self, Line, Masked, Span, Text},
 };
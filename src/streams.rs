@@ -0,0 +1,474 @@
+#![deny(missing_docs)]
+//! Pipes asynchronous [`Stream`]s into bounded buffers that widgets render from.
+//!
+//! Data arriving from the network, a child process or a background task is produced on another
+//! task than the one drawing the terminal. [`pipe`] splits the connection in two halves:
+//!
+//! - the [`Pump`] is a future polling the stream, spawned on the async runtime. It forwards the
+//!   items through a bounded channel and stops polling the stream while the channel is full, so
+//!   that a fast producer is slowed down instead of growing the memory without limit.
+//! - the [`StreamSource`] stays with the UI. [`StreamSource::drain_into`] moves the items received
+//!   since the last call into a [`StreamBuffer`] and coalesces them into a single
+//!   [`RedrawRequest`], however many items arrived.
+//!
+//! The buffers keep the most recent items up to their capacity: a [`LogBuffer`] of lines for a
+//! [`List`] or a [`Paragraph`], a [`DataBuffer`] of points for a [`Chart`] and a [`RowStore`] of
+//! rows for a [`Table`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use ratatui::{
+//!     prelude::*,
+//!     streams::{self, LogBuffer},
+//!     terminal::RedrawScheduler,
+//!     widgets::*,
+//! };
+//!
+//! # fn lines() -> impl futures_core::Stream<Item = String> + Send + 'static {
+//! #     struct Empty;
+//! #     impl futures_core::Stream for Empty {
+//! #         type Item = String;
+//! #         fn poll_next(
+//! #             self: std::pin::Pin<&mut Self>,
+//! #             _: &mut std::task::Context<'_>,
+//! #         ) -> std::task::Poll<Option<String>> {
+//! #             std::task::Poll::Ready(None)
+//! #         }
+//! #     }
+//! #     Empty
+//! # }
+//! # async fn run(mut terminal: Terminal<CrosstermBackend<std::io::Stdout>>) -> std::io::Result<()> {
+//! let (pump, mut source) = streams::pipe(lines(), 64);
+//! tokio::spawn(pump.run());
+//!
+//! let mut log = LogBuffer::new(1000);
+//! let mut scheduler = RedrawScheduler::new().tick_rate(Duration::from_millis(100));
+//! loop {
+//!     // requests a redraw if new lines arrived
+//!     source.drain_into(&mut log);
+//!     scheduler.draw(&mut terminal, |frame| {
+//!         let list = List::new(log.lines().cloned().collect::<Vec<_>>());
+//!         frame.render_widget(list, frame.size());
+//!     })?;
+//!     tokio::time::sleep(Duration::from_millis(10)).await;
+//! }
+//! # }
+//! ```
+//!
+//! [`Chart`]: crate::widgets::Chart
+//! [`List`]: crate::widgets::List
+//! [`Paragraph`]: crate::widgets::Paragraph
+//! [`Table`]: crate::widgets::Table
+use std::{collections::VecDeque, future::poll_fn, pin::pin};
+
+use futures_core::Stream;
+use tokio::sync::mpsc::{self, error::TryRecvError};
+
+use crate::{terminal::RedrawRequest, text::Line};
+
+/// Connects a stream to a [`StreamSource`] through a channel holding at most `capacity` items.
+///
+/// The returned [`Pump`] must be spawned on the async runtime to poll the stream.
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero.
+pub fn pipe<S>(stream: S, capacity: usize) -> (Pump<S>, StreamSource<S::Item>)
+where
+    S: Stream,
+{
+    let (sender, receiver) = mpsc::channel(capacity);
+    let pump = Pump { stream, sender };
+    let source = StreamSource {
+        receiver,
+        batch_size: usize::MAX,
+        closed: false,
+    };
+    (pump, source)
+}
+
+/// The half of a [`pipe`] polling the stream.
+#[derive(Debug)]
+pub struct Pump<S: Stream> {
+    stream: S,
+    sender: mpsc::Sender<S::Item>,
+}
+
+impl<S: Stream> Pump<S> {
+    /// Forwards the items of the stream to the [`StreamSource`].
+    ///
+    /// The stream is not polled while the channel is full. This completes when the stream ends or
+    /// the [`StreamSource`] is dropped.
+    pub async fn run(self) {
+        let Pump { stream, sender } = self;
+        let mut stream = pin!(stream);
+        while let Some(item) = poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+            if sender.send(item).await.is_err() {
+                break;
+            }
+        }
+    }
+}
+
+/// The half of a [`pipe`] receiving the items on the UI side.
+#[derive(Debug)]
+pub struct StreamSource<T> {
+    receiver: mpsc::Receiver<T>,
+    batch_size: usize,
+    closed: bool,
+}
+
+impl<T> StreamSource<T> {
+    /// Sets the maximum number of items moved by a single call to
+    /// [`drain_into`](Self::drain_into).
+    ///
+    /// By default all the received items are moved. A smaller batch keeps the frames responsive
+    /// when a burst of items arrives, the remaining ones are moved by the next calls.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Moves the items received since the last call into the buffer and returns how many were
+    /// moved.
+    ///
+    /// A single redraw is requested if any item was moved.
+    pub fn drain_into<B>(&mut self, buffer: &mut B) -> usize
+    where
+        B: StreamBuffer<T>,
+    {
+        let mut count = 0;
+        while count < self.batch_size {
+            match self.receiver.try_recv() {
+                Ok(item) => {
+                    buffer.push(item);
+                    count += 1;
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.closed = true;
+                    break;
+                }
+            }
+        }
+        RedrawRequest::request_if(count > 0);
+        count
+    }
+
+    /// Returns `true` once the stream ended and all its items were drained.
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+}
+
+/// A buffer receiving the items of a [`StreamSource`].
+pub trait StreamBuffer<T> {
+    /// Appends an item to the buffer.
+    fn push(&mut self, item: T);
+}
+
+/// A ring buffer of the most recent log lines.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct LogBuffer {
+    lines: VecDeque<Line<'static>>,
+    capacity: usize,
+}
+
+impl LogBuffer {
+    /// Creates an empty buffer keeping at most `capacity` lines.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            lines: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Returns the maximum number of lines kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of lines in the buffer.
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns `true` if the buffer holds no line.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Returns the lines from the oldest to the most recent.
+    pub fn lines(&self) -> impl DoubleEndedIterator<Item = &Line<'static>> + ExactSizeIterator {
+        self.lines.iter()
+    }
+
+    /// Removes all the lines.
+    pub fn clear(&mut self) {
+        self.lines.clear();
+    }
+}
+
+impl<T> StreamBuffer<T> for LogBuffer
+where
+    T: Into<Line<'static>>,
+{
+    fn push(&mut self, item: T) {
+        push_bounded(&mut self.lines, self.capacity, item.into());
+    }
+}
+
+/// A ring buffer of the most recent points of a [`Chart`](crate::widgets::Chart) dataset.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DataBuffer {
+    points: VecDeque<(f64, f64)>,
+    capacity: usize,
+}
+
+impl DataBuffer {
+    /// Creates an empty buffer keeping at most `capacity` points.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            points: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Returns the maximum number of points kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of points in the buffer.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns `true` if the buffer holds no point.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Returns the points from the oldest to the most recent, as expected by
+    /// [`Dataset::data`](crate::widgets::Dataset::data).
+    pub fn points(&mut self) -> &[(f64, f64)] {
+        self.points.make_contiguous()
+    }
+
+    /// Returns the smallest and largest `x` of the points, as expected by
+    /// [`Axis::bounds`](crate::widgets::Axis::bounds).
+    pub fn x_bounds(&self) -> Option<[f64; 2]> {
+        bounds(self.points.iter().map(|&(x, _)| x))
+    }
+
+    /// Returns the smallest and largest `y` of the points, as expected by
+    /// [`Axis::bounds`](crate::widgets::Axis::bounds).
+    pub fn y_bounds(&self) -> Option<[f64; 2]> {
+        bounds(self.points.iter().map(|&(_, y)| y))
+    }
+
+    /// Removes all the points.
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+}
+
+impl StreamBuffer<(f64, f64)> for DataBuffer {
+    fn push(&mut self, point: (f64, f64)) {
+        push_bounded(&mut self.points, self.capacity, point);
+    }
+}
+
+/// A store of the most recent rows of a [`Table`](crate::widgets::Table).
+///
+/// The rows are kept as received and converted to [`Row`](crate::widgets::Row)s when rendering.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RowStore<T> {
+    rows: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<T> RowStore<T> {
+    /// Creates an empty store keeping at most `capacity` rows.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            rows: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Returns the maximum number of rows kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of rows in the store.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns `true` if the store holds no row.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns the row at the given index, counted from the oldest.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.rows.get(index)
+    }
+
+    /// Returns the rows from the oldest to the most recent.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.rows.iter()
+    }
+
+    /// Removes all the rows.
+    pub fn clear(&mut self) {
+        self.rows.clear();
+    }
+}
+
+impl<T> Default for RowStore<T> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<T> StreamBuffer<T> for RowStore<T> {
+    fn push(&mut self, row: T) {
+        push_bounded(&mut self.rows, self.capacity, row);
+    }
+}
+
+/// Appends an item, dropping the oldest ones beyond the capacity.
+fn push_bounded<T>(items: &mut VecDeque<T>, capacity: usize, item: T) {
+    if capacity == 0 {
+        return;
+    }
+    while items.len() >= capacity {
+        items.pop_front();
+    }
+    items.push_back(item);
+}
+
+fn bounds(values: impl Iterator<Item = f64>) -> Option<[f64; 2]> {
+    values.fold(None, |bounds, value| match bounds {
+        None => Some([value, value]),
+        Some([min, max]) => Some([min.min(value), max.max(value)]),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    use tokio::runtime::Builder;
+
+    use super::*;
+
+    struct Iter<I>(I);
+
+    impl<I: Iterator + Unpin> Stream for Iter<I> {
+        type Item = I::Item;
+
+        fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<I::Item>> {
+            Poll::Ready(self.0.next())
+        }
+    }
+
+    #[test]
+    fn pipe_into_log_buffer() {
+        let (pump, mut source) = pipe(Iter(["a", "b", "c"].into_iter()), 8);
+        Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(pump.run());
+        let mut log = LogBuffer::new(2);
+        RedrawRequest::clear();
+        assert_eq!(source.drain_into(&mut log), 3);
+        assert!(RedrawRequest::is_pending());
+        assert!(source.is_closed());
+        assert_eq!(
+            log.lines().collect::<Vec<_>>(),
+            [&Line::from("b"), &Line::from("c")]
+        );
+    }
+
+    #[test]
+    fn backpressure() {
+        let (pump, mut source) = pipe(Iter(0..5), 2);
+        let mut rows = RowStore::new(10);
+        Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let task = tokio::spawn(pump.run());
+                tokio::task::yield_now().await;
+                // the pump waits for room in the channel
+                assert_eq!(source.drain_into(&mut rows), 2);
+                assert!(!source.is_closed());
+                while !source.is_closed() {
+                    source.drain_into(&mut rows);
+                    tokio::task::yield_now().await;
+                }
+                task.await.unwrap();
+            });
+        assert_eq!(rows.rows().copied().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn batch_size() {
+        let (pump, source) = pipe(Iter(0..5), 8);
+        let mut source = source.batch_size(2);
+        Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(pump.run());
+        let mut rows = RowStore::new(10);
+        assert_eq!(source.drain_into(&mut rows), 2);
+        assert_eq!(source.drain_into(&mut rows), 2);
+        assert_eq!(source.drain_into(&mut rows), 1);
+        RedrawRequest::clear();
+        assert_eq!(source.drain_into(&mut rows), 0);
+        assert!(!RedrawRequest::is_pending());
+        assert!(source.is_closed());
+    }
+
+    #[test]
+    fn dropped_source_stops_pump() {
+        let (pump, source) = pipe(Iter(0..), 1);
+        drop(source);
+        Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(pump.run());
+    }
+
+    #[test]
+    fn data_buffer() {
+        let mut data = DataBuffer::new(3);
+        assert_eq!(data.x_bounds(), None);
+        for point in [(0.0, 5.0), (1.0, -2.0), (2.0, 3.0), (3.0, 1.0)] {
+            data.push(point);
+        }
+        assert_eq!(data.points(), [(1.0, -2.0), (2.0, 3.0), (3.0, 1.0)]);
+        assert_eq!(data.x_bounds(), Some([1.0, 3.0]));
+        assert_eq!(data.y_bounds(), Some([-2.0, 3.0]));
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut rows = RowStore::new(0);
+        rows.push(1);
+        assert!(rows.is_empty());
+    }
+}
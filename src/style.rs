@@ -35,8 +35,15 @@
 //! - [`Span`]s can be styled again, which will merge the styles.
 //! - Many widget types can be styled directly rather than calling their style() method.
 //!
-//! See the [`Stylize`] and [`Styled`] traits for more information. These traits are re-exported in
-//! the [`prelude`] module for convenience.
+//! The [`StyleAll`] trait provides the same shorthands with an `_all` suffix to restyle every span
+//! of lines, text, vectors and iterators at once, e.g. `lines.fg_all(Color::Gray)`.
+//!
+//! For accessible interfaces, the built-in [`Palette`]s are distinguishable with the common forms
+//! of color blindness, and [`contrast_ratio`] tells whether text is readable on its background.
+//! [`ContrastCheck`] checks every cell of the drawn frames in debug builds.
+//!
+//! See the [`Stylize`], [`Styled`] and [`StyleAll`] traits for more information. These traits are
+//! re-exported in the [`prelude`] module for convenience.
 //!
 //! ## Example
 //!
@@ -73,9 +80,17 @@ use std::fmt::{self, Debug};
 use bitflags::bitflags;
 
 mod stylize;
-pub use stylize::{Styled, Stylize};
+pub use stylize::{StyleAll, StyleAllIter, Styled, Stylize};
 mod color;
 pub use color::Color;
+mod background;
+pub use background::{detect_background, Background, ColorScheme};
+mod contrast;
+pub use contrast::{check_contrast, contrast_ratio, ContrastCheck, ContrastWarning, Palette};
+mod theme;
+#[cfg(feature = "fs-watch")]
+pub use theme::ThemeWatcher;
+pub use theme::{ParseThemeError, Theme};
 
 bitflags! {
     /// Modifier changes the way a piece of text is displayed.
@@ -0,0 +1,129 @@
+//! Detection of the background color of the terminal, to pick light or dark colors.
+use std::{io, time::Duration};
+
+use crate::{
+    backend::{Backend, TerminalColor},
+    style::Color,
+};
+
+/// Whether the background of the terminal is light or dark.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ColorScheme {
+    /// A light background, to use with dark text.
+    Light,
+    /// A dark background, to use with light text.
+    Dark,
+}
+
+impl ColorScheme {
+    /// Returns `light` for a [`ColorScheme::Light`] scheme and `dark` for a
+    /// [`ColorScheme::Dark`] scheme, e.g. to choose between two palettes.
+    pub fn pick<T>(self, light: T, dark: T) -> T {
+        match self {
+            ColorScheme::Light => light,
+            ColorScheme::Dark => dark,
+        }
+    }
+}
+
+/// The background color of the terminal, as returned by [`detect_background`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Background {
+    /// The red component of the color.
+    pub red: u8,
+    /// The green component of the color.
+    pub green: u8,
+    /// The blue component of the color.
+    pub blue: u8,
+}
+
+impl Background {
+    /// Returns the background as a [`Color::Rgb`].
+    pub fn color(&self) -> Color {
+        Color::Rgb(self.red, self.green, self.blue)
+    }
+
+    /// Returns the perceived brightness of the background, from `0.0` for black to `1.0` for
+    /// white, with the Rec. 709 weights of the components.
+    pub fn luminance(&self) -> f64 {
+        (0.2126 * f64::from(self.red)
+            + 0.7152 * f64::from(self.green)
+            + 0.0722 * f64::from(self.blue))
+            / 255.0
+    }
+
+    /// Returns [`ColorScheme::Light`] if the [`luminance`](Background::luminance) is above one
+    /// half, and [`ColorScheme::Dark`] otherwise.
+    pub fn scheme(&self) -> ColorScheme {
+        if self.luminance() > 0.5 {
+            ColorScheme::Light
+        } else {
+            ColorScheme::Dark
+        }
+    }
+}
+
+/// Asks the terminal for its background color with an `OSC 11` query through the backend,
+/// waiting at most `timeout` for the answer.
+///
+/// Returns `Ok(None)` if the terminal did not answer in time or the backend cannot read the
+/// answer, see [`Backend::query_color`]. The query should be done once at startup, in raw mode and
+/// before reading any input, as the answer is read from the input of the terminal.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::{
+///     backend::{TerminalColor, TestBackend},
+///     prelude::*,
+///     style::{detect_background, ColorScheme},
+/// };
+///
+/// let mut backend = TestBackend::new(10, 2);
+/// backend.set_terminal_color(TerminalColor::Background, Some(Color::Rgb(250, 250, 240)));
+/// let scheme = detect_background(&mut backend, Duration::from_millis(100))?
+///     .map_or(ColorScheme::Dark, |background| background.scheme());
+/// let text = scheme.pick(Color::Black, Color::White);
+/// assert_eq!(text, Color::Black);
+/// # std::io::Result::Ok(())
+/// ```
+pub fn detect_background<B>(backend: &mut B, timeout: Duration) -> io::Result<Option<Background>>
+where
+    B: Backend,
+{
+    let color = backend.query_color(TerminalColor::Background, timeout)?;
+    Ok(match color {
+        Some(Color::Rgb(red, green, blue)) => Some(Background { red, green, blue }),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::TestBackend;
+
+    #[test]
+    fn scheme() {
+        let background = |red, green, blue| Background { red, green, blue };
+        assert_eq!(background(0, 0, 0).scheme(), ColorScheme::Dark);
+        assert_eq!(background(255, 255, 255).scheme(), ColorScheme::Light);
+        assert_eq!(background(0x28, 0x2c, 0x34).scheme(), ColorScheme::Dark);
+        assert_eq!(background(0xfd, 0xf6, 0xe3).scheme(), ColorScheme::Light);
+        // bright blue is dark, bright green is light
+        assert_eq!(background(0, 0, 255).scheme(), ColorScheme::Dark);
+        assert_eq!(background(0, 255, 0).scheme(), ColorScheme::Light);
+    }
+
+    #[test]
+    fn detect() {
+        let timeout = Duration::from_millis(10);
+        let mut backend = TestBackend::new(1, 1);
+        assert_eq!(detect_background(&mut backend, timeout).unwrap(), None);
+        backend.set_terminal_color(TerminalColor::Background, Some(Color::Rgb(1, 2, 3)));
+        let background = detect_background(&mut backend, timeout).unwrap().unwrap();
+        assert_eq!(background.color(), Color::Rgb(1, 2, 3));
+    }
+}
@@ -124,6 +124,111 @@ pub enum Color {
     Indexed(u8),
 }
 
+impl Color {
+    /// Returns the red, green and blue components of the color, or `None` for [`Color::Reset`].
+    ///
+    /// The named and indexed colors are converted with the default palette of xterm, which most
+    /// terminals approximate: the actual colors depend on the theme of the terminal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::prelude::*;
+    ///
+    /// assert_eq!(Color::Indexed(196).to_rgb(), Some((255, 0, 0)));
+    /// assert_eq!(Color::Reset.to_rgb(), None);
+    /// ```
+    pub fn to_rgb(self) -> Option<(u8, u8, u8)> {
+        const ANSI: [(u8, u8, u8); 16] = [
+            (0, 0, 0),
+            (205, 0, 0),
+            (0, 205, 0),
+            (205, 205, 0),
+            (0, 0, 238),
+            (205, 0, 205),
+            (0, 205, 205),
+            (229, 229, 229),
+            (127, 127, 127),
+            (255, 0, 0),
+            (0, 255, 0),
+            (255, 255, 0),
+            (92, 92, 255),
+            (255, 0, 255),
+            (0, 255, 255),
+            (255, 255, 255),
+        ];
+        let index = match self {
+            Color::Reset => return None,
+            Color::Rgb(red, green, blue) => return Some((red, green, blue)),
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::Gray => 7,
+            Color::DarkGray => 8,
+            Color::LightRed => 9,
+            Color::LightGreen => 10,
+            Color::LightYellow => 11,
+            Color::LightBlue => 12,
+            Color::LightMagenta => 13,
+            Color::LightCyan => 14,
+            Color::White => 15,
+            Color::Indexed(index) => index,
+        };
+        Some(match index {
+            0..=15 => ANSI[usize::from(index)],
+            16..=231 => {
+                let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+                let index = index - 16;
+                (level(index / 36), level(index / 6 % 6), level(index % 6))
+            }
+            _ => {
+                let gray = 8 + (index - 232) * 10;
+                (gray, gray, gray)
+            }
+        })
+    }
+
+    /// Interpolates linearly between two colors, returning `a` for `t = 0.0` and `b` for
+    /// `t = 1.0`. `t` is clamped between these bounds.
+    ///
+    /// The colors are interpolated in RGB, the named and indexed colors being converted with
+    /// [`to_rgb`](Self::to_rgb), so the result is a [`Color::Rgb`] unless `t` is a bound. As
+    /// [`Color::Reset`] has no RGB value, the interpolation jumps from `a` to `b` halfway when
+    /// one of them is reset. Pass `t` through an [`Easing`] for non linear transitions.
+    ///
+    /// [`Easing`]: crate::animation::Easing
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::prelude::*;
+    ///
+    /// let color = Color::lerp(Color::Rgb(0, 0, 0), Color::Rgb(200, 100, 50), 0.5);
+    /// assert_eq!(color, Color::Rgb(100, 50, 25));
+    /// assert_eq!(Color::lerp(Color::Black, Color::White, 0.0), Color::Black);
+    /// ```
+    pub fn lerp(a: Color, b: Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        if t == 0.0 {
+            return a;
+        }
+        if t == 1.0 {
+            return b;
+        }
+        let (Some(from), Some(to)) = (a.to_rgb(), b.to_rgb()) else {
+            return if t < 0.5 { a } else { b };
+        };
+        let mix = |from: u8, to: u8| {
+            (f64::from(from) + (f64::from(to) - f64::from(from)) * t).round() as u8
+        };
+        Color::Rgb(mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2))
+    }
+}
+
 /// Error type indicating a failure to parse a color string.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub struct ParseColorError;
@@ -259,6 +364,32 @@ mod tests {
         assert_eq!(color, Color::Rgb(255, 0, 0));
     }
 
+    #[test]
+    fn to_rgb() {
+        assert_eq!(Color::Indexed(1).to_rgb(), Color::Red.to_rgb());
+        assert_eq!(Color::Indexed(16).to_rgb(), Some((0, 0, 0)));
+        assert_eq!(Color::Indexed(196).to_rgb(), Some((255, 0, 0)));
+        assert_eq!(Color::Indexed(110).to_rgb(), Some((135, 175, 215)));
+        assert_eq!(Color::Indexed(232).to_rgb(), Some((8, 8, 8)));
+        assert_eq!(Color::Indexed(255).to_rgb(), Some((238, 238, 238)));
+        assert_eq!(Color::Reset.to_rgb(), None);
+    }
+
+    #[test]
+    fn lerp() {
+        let (black, white) = (Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255));
+        assert_eq!(Color::lerp(black, white, 0.25), Color::Rgb(64, 64, 64));
+        assert_eq!(Color::lerp(black, white, 2.0), white);
+        assert_eq!(Color::lerp(black, white, -1.0), black);
+        // indexed colors are interpolated through their RGB value
+        assert_eq!(
+            Color::lerp(Color::Indexed(16), Color::Red, 0.5),
+            Color::Rgb(103, 0, 0)
+        );
+        assert_eq!(Color::lerp(Color::Reset, white, 0.4), Color::Reset);
+        assert_eq!(Color::lerp(Color::Reset, white, 0.6), white);
+    }
+
     #[test]
     fn from_indexed_color() {
         let color: Color = Color::from_str("10").unwrap();
@@ -0,0 +1,364 @@
+//! Color-blind safe palettes and the contrast of the colors, to build accessible interfaces.
+use std::{cell::RefCell, fmt};
+
+use crate::{
+    buffer::Buffer,
+    layout::Position,
+    style::{Color, Modifier},
+};
+
+thread_local! {
+    static CHECK: RefCell<Option<(f64, Vec<ContrastWarning>)>> = const { RefCell::new(None) };
+}
+
+/// A set of colors that remain distinguishable with the common forms of color blindness, e.g. to
+/// tell apart the series of a chart or the states of a list.
+///
+/// The colors are given as [`Color::Rgb`], so they require a terminal supporting true colors.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, style::Palette, widgets::*};
+///
+/// let palette = Palette::OKABE_ITO;
+/// let datasets = ["cpu", "memory", "disk"]
+///     .iter()
+///     .enumerate()
+///     .map(|(i, name)| Dataset::default().name(*name).fg(palette.color(i)))
+///     .collect::<Vec<_>>();
+/// assert_eq!(palette.color(1), Color::Rgb(0xe6, 0x9f, 0x00));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Palette {
+    name: &'static str,
+    colors: &'static [Color],
+}
+
+impl Palette {
+    /// The palette of Masataka Okabe and Kei Ito: black, orange, sky blue, bluish green, yellow,
+    /// blue, vermillion and reddish purple.
+    pub const OKABE_ITO: Palette = Palette {
+        name: "Okabe-Ito",
+        colors: &[
+            Color::Rgb(0x00, 0x00, 0x00),
+            Color::Rgb(0xe6, 0x9f, 0x00),
+            Color::Rgb(0x56, 0xb4, 0xe9),
+            Color::Rgb(0x00, 0x9e, 0x73),
+            Color::Rgb(0xf0, 0xe4, 0x42),
+            Color::Rgb(0x00, 0x72, 0xb2),
+            Color::Rgb(0xd5, 0x5e, 0x00),
+            Color::Rgb(0xcc, 0x79, 0xa7),
+        ],
+    };
+
+    /// The bright qualitative palette of Paul Tol: blue, cyan, green, yellow, red, purple and
+    /// grey.
+    pub const TOL_BRIGHT: Palette = Palette {
+        name: "Tol bright",
+        colors: &[
+            Color::Rgb(0x44, 0x77, 0xaa),
+            Color::Rgb(0x66, 0xcc, 0xee),
+            Color::Rgb(0x22, 0x88, 0x33),
+            Color::Rgb(0xcc, 0xbb, 0x44),
+            Color::Rgb(0xee, 0x66, 0x77),
+            Color::Rgb(0xaa, 0x33, 0x77),
+            Color::Rgb(0xbb, 0xbb, 0xbb),
+        ],
+    };
+
+    /// The vibrant qualitative palette of Paul Tol: blue, cyan, teal, orange, red, magenta and
+    /// grey.
+    pub const TOL_VIBRANT: Palette = Palette {
+        name: "Tol vibrant",
+        colors: &[
+            Color::Rgb(0x00, 0x77, 0xbb),
+            Color::Rgb(0x33, 0xbb, 0xee),
+            Color::Rgb(0x00, 0x99, 0x88),
+            Color::Rgb(0xee, 0x77, 0x33),
+            Color::Rgb(0xcc, 0x33, 0x11),
+            Color::Rgb(0xee, 0x33, 0x77),
+            Color::Rgb(0xbb, 0xbb, 0xbb),
+        ],
+    };
+
+    /// The palette of the IBM Design Library: ultramarine, indigo, magenta, orange and gold.
+    pub const IBM: Palette = Palette {
+        name: "IBM",
+        colors: &[
+            Color::Rgb(0x64, 0x8f, 0xff),
+            Color::Rgb(0x78, 0x5e, 0xf0),
+            Color::Rgb(0xdc, 0x26, 0x7f),
+            Color::Rgb(0xfe, 0x61, 0x00),
+            Color::Rgb(0xff, 0xb0, 0x00),
+        ],
+    };
+
+    /// All the built-in palettes.
+    pub const ALL: [Palette; 4] = [
+        Palette::OKABE_ITO,
+        Palette::TOL_BRIGHT,
+        Palette::TOL_VIBRANT,
+        Palette::IBM,
+    ];
+
+    /// Returns the name of the palette.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the colors of the palette.
+    pub fn colors(&self) -> &'static [Color] {
+        self.colors
+    }
+
+    /// Returns the color at the given index, cycling through the palette when the index is past
+    /// its end.
+    pub fn color(&self, index: usize) -> Color {
+        self.colors[index % self.colors.len()]
+    }
+}
+
+/// Returns the contrast ratio between two colors, from `1.0` for identical colors to `21.0` for
+/// black on white, as defined by the Web Content Accessibility Guidelines.
+///
+/// The guidelines recommend a ratio of at least `4.5` for text (`7.0` for the enhanced level),
+/// and `3.0` for large text and graphics.
+///
+/// The named and indexed colors are converted with the default palette of xterm, as the actual
+/// colors depend on the terminal. Returns `None` if either color is [`Color::Reset`], whose value
+/// is unknown.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, style::contrast_ratio};
+///
+/// assert_eq!(contrast_ratio(Color::Black, Color::White), Some(21.0));
+/// assert!(contrast_ratio(Color::Blue, Color::Black).unwrap() < 4.5);
+/// assert_eq!(contrast_ratio(Color::Reset, Color::White), None);
+/// ```
+pub fn contrast_ratio(fg: Color, bg: Color) -> Option<f64> {
+    let fg = relative_luminance(fg)?;
+    let bg = relative_luminance(bg)?;
+    Some((fg.max(bg) + 0.05) / (fg.min(bg) + 0.05))
+}
+
+/// A cell of a [`Buffer`] whose colors don't contrast enough, found by [`check_contrast`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastWarning {
+    /// The position of the cell.
+    pub position: Position,
+    /// The color of the text, after applying [`Modifier::REVERSED`].
+    pub fg: Color,
+    /// The color of the background, after applying [`Modifier::REVERSED`].
+    pub bg: Color,
+    /// The contrast ratio between the two colors.
+    pub ratio: f64,
+}
+
+impl fmt::Display for ContrastWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "low contrast {:.2}:1 of {} on {} at ({}, {})",
+            self.ratio, self.fg, self.bg, self.position.x, self.position.y
+        )
+    }
+}
+
+/// Returns the cells of the buffer whose text has a [contrast ratio](contrast_ratio) with its
+/// background below `min_ratio`.
+///
+/// Blank cells are ignored, as well as the cells with a [`Color::Reset`] color, whose contrast
+/// depends on the terminal.
+pub fn check_contrast(buffer: &Buffer, min_ratio: f64) -> Vec<ContrastWarning> {
+    let mut warnings = vec![];
+    for (i, cell) in buffer.content.iter().enumerate() {
+        if cell.symbol().trim().is_empty() || cell.modifier.contains(Modifier::HIDDEN) {
+            continue;
+        }
+        let (fg, bg) = if cell.modifier.contains(Modifier::REVERSED) {
+            (cell.bg, cell.fg)
+        } else {
+            (cell.fg, cell.bg)
+        };
+        match contrast_ratio(fg, bg) {
+            Some(ratio) if ratio < min_ratio => {
+                let (x, y) = buffer.pos_of(i);
+                warnings.push(ContrastWarning {
+                    position: Position::new(x, y),
+                    fg,
+                    bg,
+                    ratio,
+                });
+            }
+            _ => {}
+        }
+    }
+    warnings
+}
+
+/// Checks the contrast of the frames drawn by the [`Terminal`] in debug builds.
+///
+/// Once enabled with a minimum [contrast ratio](contrast_ratio), every frame drawn on the
+/// current thread is checked with [`check_contrast`] and the cells below the threshold are
+/// available with [`warnings`](Self::warnings) until the next frame, e.g. to log them while
+/// developing the interface. The check is skipped in release builds, where it is never enabled.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{backend::TestBackend, prelude::*, style::ContrastCheck, widgets::Paragraph};
+///
+/// ContrastCheck::enable(4.5);
+/// let mut terminal = Terminal::new(TestBackend::new(10, 1))?;
+/// terminal.draw(|frame| {
+///     frame.render_widget(Paragraph::new("hello").blue().on_black(), frame.size());
+/// })?;
+/// # #[cfg(debug_assertions)]
+/// for warning in ContrastCheck::warnings() {
+///     eprintln!("{warning}");
+/// }
+/// # #[cfg(debug_assertions)]
+/// assert_eq!(ContrastCheck::warnings().len(), 5);
+/// # std::io::Result::Ok(())
+/// ```
+///
+/// [`Terminal`]: crate::Terminal
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ContrastCheck;
+
+impl ContrastCheck {
+    /// Checks the frames drawn on this thread against the given minimum contrast ratio. Does
+    /// nothing in release builds.
+    pub fn enable(min_ratio: f64) {
+        if cfg!(debug_assertions) {
+            CHECK.with(|check| *check.borrow_mut() = Some((min_ratio, vec![])));
+        }
+    }
+
+    /// Stops checking the frames drawn on this thread.
+    pub fn disable() {
+        CHECK.with(|check| *check.borrow_mut() = None);
+    }
+
+    /// Returns the minimum contrast ratio checked on this thread, if the check is enabled.
+    pub fn min_ratio() -> Option<f64> {
+        CHECK.with(|check| check.borrow().as_ref().map(|(min_ratio, _)| *min_ratio))
+    }
+
+    /// Returns the cells of the last frame drawn on this thread whose contrast is below the
+    /// minimum ratio.
+    pub fn warnings() -> Vec<ContrastWarning> {
+        CHECK.with(|check| {
+            check
+                .borrow()
+                .as_ref()
+                .map(|(_, warnings)| warnings.clone())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Checks a frame drawn by the terminal, if the check is enabled.
+    pub(crate) fn check(buffer: &Buffer) {
+        CHECK.with(|check| {
+            if let Some((min_ratio, warnings)) = check.borrow_mut().as_mut() {
+                *warnings = check_contrast(buffer, *min_ratio);
+            }
+        });
+    }
+}
+
+/// Returns the relative luminance of a color, from `0.0` for black to `1.0` for white.
+fn relative_luminance(color: Color) -> Option<f64> {
+    let (red, green, blue) = color.to_rgb()?;
+    let linear = |component: u8| {
+        let c = f64::from(component) / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    Some(0.2126 * linear(red) + 0.7152 * linear(green) + 0.0722 * linear(blue))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{backend::TestBackend, layout::Rect, style::Style, Terminal};
+
+    #[test]
+    fn ratio() {
+        assert_eq!(contrast_ratio(Color::White, Color::Black), Some(21.0));
+        assert_eq!(contrast_ratio(Color::Red, Color::Red), Some(1.0));
+        let ratio = contrast_ratio(Color::Rgb(0x77, 0x77, 0x77), Color::White).unwrap();
+        assert!((ratio - 4.48).abs() < 0.01, "{ratio}");
+        assert_eq!(contrast_ratio(Color::Black, Color::Reset), None);
+    }
+
+    #[test]
+    fn palettes() {
+        for palette in Palette::ALL {
+            assert!(!palette.colors().is_empty(), "{}", palette.name());
+        }
+        assert_eq!(Palette::IBM.color(5), Palette::IBM.color(0));
+    }
+
+    #[test]
+    fn check_buffer() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        buffer.set_string(0, 0, "ab", Style::new().fg(Color::Blue).bg(Color::Black));
+        buffer.set_string(0, 1, "c", Style::new().fg(Color::White).bg(Color::Black));
+        buffer.set_string(2, 1, "d", Style::new().fg(Color::Reset).bg(Color::Black));
+        // blank cells don't have text to read
+        buffer.set_style(Rect::new(3, 0, 1, 1), Style::new().bg(Color::Black));
+        let warnings = check_contrast(&buffer, 4.5);
+        let positions = warnings.iter().map(|w| w.position).collect::<Vec<_>>();
+        assert_eq!(positions, [Position::new(0, 0), Position::new(1, 0)]);
+        assert_eq!(
+            warnings[0].to_string(),
+            "low contrast 2.23:1 of Blue on Black at (0, 0)"
+        );
+
+        // reversed colors swap the text and the background
+        buffer.set_style(
+            Rect::new(0, 0, 2, 1),
+            Style::new().fg(Color::Black).bg(Color::Yellow),
+        );
+        buffer.set_style(
+            Rect::new(0, 0, 1, 1),
+            Style::new().add_modifier(Modifier::REVERSED),
+        );
+        let warnings = check_contrast(&buffer, 4.5);
+        assert!(warnings.is_empty(), "{warnings:?}");
+    }
+
+    #[test]
+    fn check_drawn_frames() {
+        let mut terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+        let draw = |terminal: &mut Terminal<TestBackend>| {
+            terminal
+                .draw(|frame| {
+                    let style = Style::new().fg(Color::DarkGray).bg(Color::Black);
+                    frame.buffer_mut().set_string(0, 0, "ab", style);
+                })
+                .unwrap();
+        };
+        draw(&mut terminal);
+        assert_eq!(ContrastCheck::min_ratio(), None);
+        assert!(ContrastCheck::warnings().is_empty());
+
+        // dark gray on black has a ratio of 5.24
+        ContrastCheck::enable(6.0);
+        assert_eq!(ContrastCheck::min_ratio(), Some(6.0));
+        draw(&mut terminal);
+        assert_eq!(ContrastCheck::warnings().len(), 2);
+        ContrastCheck::enable(4.5);
+        draw(&mut terminal);
+        assert!(ContrastCheck::warnings().is_empty());
+        ContrastCheck::disable();
+        assert_eq!(ContrastCheck::min_ratio(), None);
+    }
+}
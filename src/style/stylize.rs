@@ -2,7 +2,7 @@ use paste::paste;
 
 use crate::{
     style::{Color, Modifier, Style},
-    text::Span,
+    text::{Line, Span, StyledGrapheme, Text},
 };
 
 /// A trait for objects that have a `Style`.
@@ -226,6 +226,209 @@ impl Styled for String {
     }
 }
 
+/// Generates the `_all` variants of the color methods of [`Stylize`] for [`StyleAll`], e.g.
+/// `red_all()` and `on_red_all()`.
+macro_rules! color_all {
+    ( $color:ident ) => {
+        paste! {
+            #[doc = "Sets the foreground color of every span to [`" $color "`](Color::" $color:camel ")."]
+            #[must_use = concat!("`", stringify!($color), "_all` returns the modified value without modifying the original")]
+            fn [<$color _all>](self) -> T {
+                self.fg_all(Color::[<$color:camel>])
+            }
+
+            #[doc = "Sets the background color of every span to [`" $color "`](Color::" $color:camel ")."]
+            #[must_use = concat!("`on_", stringify!($color), "_all` returns the modified value without modifying the original")]
+            fn [<on_ $color _all>](self) -> T {
+                self.bg_all(Color::[<$color:camel>])
+            }
+        }
+    };
+}
+
+/// Generates the `_all` variants of the modifier methods of [`Stylize`] for [`StyleAll`], e.g.
+/// `bold_all()` and `not_bold_all()`.
+macro_rules! modifier_all {
+    ( $modifier:ident ) => {
+        paste! {
+            #[doc = "Adds the [`" $modifier:upper "`](Modifier::" $modifier:upper ") modifier to every span."]
+            #[must_use = concat!("`", stringify!($modifier), "_all` returns the modified value without modifying the original")]
+            fn [<$modifier _all>](self) -> T {
+                self.add_modifier_all(Modifier::[<$modifier:upper>])
+            }
+
+            #[doc = "Removes the [`" $modifier:upper "`](Modifier::" $modifier:upper ") modifier from every span."]
+            #[must_use = concat!("`not_", stringify!($modifier), "_all` returns the modified value without modifying the original")]
+            fn [<not_ $modifier _all>](self) -> T {
+                self.remove_modifier_all(Modifier::[<$modifier:upper>])
+            }
+        }
+    };
+}
+
+/// An extension trait for styling every span of composed text at once.
+///
+/// Where [`Stylize`] styles a single value, `StyleAll` patches a style on top of the style of each
+/// span of a [`Line`] or a [`Text`], of each element of a [`Vec`], and of each item of an
+/// iterator. It provides the methods of [`Stylize`] with an `_all` suffix, e.g. `dim_all()` or
+/// `fg_all(Color::Gray)`.
+///
+/// Vectors are restyled eagerly, while iterators are wrapped in a [`StyleAllIter`] that restyles
+/// the items as they are yielded.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::prelude::*;
+///
+/// let spans = vec!["hello".red(), " ".into(), "world".bold()].dim_all();
+/// assert_eq!(spans[0], "hello".red().dim());
+///
+/// let lines = vec![Line::from("one"), Line::from("two")].fg_all(Color::Gray);
+/// assert_eq!(lines[1], Line::from("two".gray()));
+///
+/// let text = Text::from("footer").italic_all();
+/// assert_eq!(text, Text::from(Line::from("footer".italic())));
+///
+/// let items: Vec<Span> = ["a", "b"].into_iter().map(Span::raw).on_blue_all().collect();
+/// assert_eq!(items, vec!["a".on_blue(), "b".on_blue()]);
+/// ```
+pub trait StyleAll<T>: Sized {
+    /// Patches the given style on top of the style of every span.
+    #[must_use = "`patch_all` returns the modified value without modifying the original"]
+    fn patch_all(self, style: Style) -> T;
+
+    /// Sets the background color of every span.
+    #[must_use = "`bg_all` returns the modified value without modifying the original"]
+    fn bg_all(self, color: Color) -> T {
+        self.patch_all(Style::new().bg(color))
+    }
+
+    /// Sets the foreground color of every span.
+    #[must_use = "`fg_all` returns the modified value without modifying the original"]
+    fn fg_all<S: Into<Color>>(self, color: S) -> T {
+        self.patch_all(Style::new().fg(color.into()))
+    }
+
+    /// Resets the style of every span.
+    #[must_use = "`reset_all` returns the modified value without modifying the original"]
+    fn reset_all(self) -> T {
+        self.patch_all(Style::reset())
+    }
+
+    /// Adds the modifier to every span.
+    #[must_use = "`add_modifier_all` returns the modified value without modifying the original"]
+    fn add_modifier_all(self, modifier: Modifier) -> T {
+        self.patch_all(Style::new().add_modifier(modifier))
+    }
+
+    /// Removes the modifier from every span.
+    #[must_use = "`remove_modifier_all` returns the modified value without modifying the original"]
+    fn remove_modifier_all(self, modifier: Modifier) -> T {
+        self.patch_all(Style::new().remove_modifier(modifier))
+    }
+
+    color_all!(black);
+    color_all!(red);
+    color_all!(green);
+    color_all!(yellow);
+    color_all!(blue);
+    color_all!(magenta);
+    color_all!(cyan);
+    color_all!(gray);
+    color_all!(dark_gray);
+    color_all!(light_red);
+    color_all!(light_green);
+    color_all!(light_yellow);
+    color_all!(light_blue);
+    color_all!(light_magenta);
+    color_all!(light_cyan);
+    color_all!(white);
+
+    modifier_all!(bold);
+    modifier_all!(dim);
+    modifier_all!(italic);
+    modifier_all!(underlined);
+    modifier_all!(slow_blink);
+    modifier_all!(rapid_blink);
+    modifier_all!(reversed);
+    modifier_all!(hidden);
+    modifier_all!(crossed_out);
+}
+
+impl<'a> StyleAll<Span<'a>> for Span<'a> {
+    fn patch_all(mut self, style: Style) -> Span<'a> {
+        self.patch_style(style);
+        self
+    }
+}
+
+impl<'a> StyleAll<StyledGrapheme<'a>> for StyledGrapheme<'a> {
+    fn patch_all(mut self, style: Style) -> StyledGrapheme<'a> {
+        self.style = self.style.patch(style);
+        self
+    }
+}
+
+impl<'a> StyleAll<Line<'a>> for Line<'a> {
+    fn patch_all(mut self, style: Style) -> Line<'a> {
+        self.patch_style(style);
+        self
+    }
+}
+
+impl<'a> StyleAll<Text<'a>> for Text<'a> {
+    fn patch_all(mut self, style: Style) -> Text<'a> {
+        self.patch_style(style);
+        self
+    }
+}
+
+impl<T, U> StyleAll<Vec<U>> for Vec<T>
+where
+    T: StyleAll<U>,
+{
+    fn patch_all(self, style: Style) -> Vec<U> {
+        self.into_iter().map(|item| item.patch_all(style)).collect()
+    }
+}
+
+impl<I> StyleAll<StyleAllIter<I>> for I
+where
+    I: Iterator,
+    I::Item: StyleAll<I::Item>,
+{
+    fn patch_all(self, style: Style) -> StyleAllIter<I> {
+        StyleAllIter { iter: self, style }
+    }
+}
+
+/// An iterator that patches a style on top of the style of each item of another iterator.
+///
+/// This is created by the methods of [`StyleAll`] on iterators.
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct StyleAllIter<I> {
+    iter: I,
+    style: Style,
+}
+
+impl<I> Iterator for StyleAllIter<I>
+where
+    I: Iterator,
+    I::Item: StyleAll<I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|item| item.patch_all(self.style))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use itertools::Itertools;
@@ -421,4 +624,41 @@ mod tests {
             Span::styled("hello", all_modifier_black)
         );
     }
+
+    #[test]
+    fn style_all_spans() {
+        let spans = vec!["a".red(), Span::raw("b")].dim_all().on_blue_all();
+        assert_eq!(spans, vec!["a".red().dim().on_blue(), "b".dim().on_blue()]);
+        assert_eq!(spans.not_dim_all()[1], "b".on_blue().not_dim());
+    }
+
+    #[test]
+    fn style_all_lines_and_text() {
+        let line = Line::from(vec!["a".bold(), "b".into()]).fg_all(Color::Gray);
+        assert_eq!(line, Line::from(vec!["a".bold().gray(), "b".gray()]));
+
+        let text = Text::from(vec![Line::from("a"), Line::from("b".red())]).reset_all();
+        assert_eq!(
+            text,
+            Text::from(vec![Line::from("a".reset()), Line::from("b".reset())])
+        );
+
+        let lines = vec![Line::from("a"), Line::from("b")].underlined_all();
+        assert_eq!(lines[1], Line::from("b".underlined()));
+    }
+
+    #[test]
+    fn style_all_iterator() {
+        let iter = "ab".chars().map(|c| Span::raw(c.to_string())).bold_all();
+        assert_eq!(iter.size_hint().1, Some(2));
+        let spans = iter.red_all().collect_vec();
+        assert_eq!(spans, vec!["a".bold().red(), "b".bold().red()]);
+
+        let span = Span::raw("ab");
+        let graphemes = span
+            .styled_graphemes(Style::new())
+            .italic_all()
+            .collect_vec();
+        assert_eq!(graphemes[0].style, Style::new().italic());
+    }
 }
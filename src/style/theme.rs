@@ -0,0 +1,405 @@
+use std::{collections::HashMap, fmt, fs, io, path::Path, str::FromStr};
+
+use crate::style::{Color, Modifier, Style};
+
+/// A set of named styles, e.g. the styles of the titles, borders and errors of an application.
+///
+/// Applications read their styles from a theme instead of hard-coding them, so that a theme can be
+/// loaded from a stylesheet file and, with the `fs-watch` feature, reloaded with a
+/// `ThemeWatcher` while the application runs.
+///
+/// # Stylesheet format
+///
+/// A stylesheet has a style per line, written as a name, `=` and the attributes of the style
+/// separated by spaces:
+/// - a color (see [`Color::from_str`]) sets the foreground color,
+/// - `on` followed by a color sets the background color,
+/// - a modifier name, e.g. `bold` or `crossed_out`, adds the modifier.
+///
+/// Empty lines and lines starting with `#` are ignored.
+///
+/// ```text
+/// # colors of the log viewer
+/// title = bold yellow
+/// error = white on red
+/// border = dark_gray
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, style::Theme};
+///
+/// let theme: Theme = "title = bold yellow\nerror = white on red".parse()?;
+/// assert_eq!(theme.style("title"), Style::new().yellow().bold());
+/// assert_eq!(theme.style("missing"), Style::new());
+/// # Ok::<(), ratatui::style::ParseThemeError>(())
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Theme {
+    styles: HashMap<String, Style>,
+}
+
+impl Theme {
+    /// Creates an empty theme.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a theme from a stylesheet file.
+    ///
+    /// A stylesheet that can't be parsed is reported as an error of kind
+    /// [`io::ErrorKind::InvalidData`].
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        fs::read_to_string(path)?
+            .parse()
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    /// Adds or replaces the style with the given name.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_style<S: Into<String>>(mut self, name: S, style: Style) -> Self {
+        self.insert(name, style);
+        self
+    }
+
+    /// Adds or replaces the style with the given name.
+    pub fn insert<S: Into<String>>(&mut self, name: S, style: Style) {
+        self.styles.insert(name.into(), style);
+    }
+
+    /// Returns the style with the given name, if the theme has one.
+    pub fn get(&self, name: &str) -> Option<Style> {
+        self.styles.get(name).copied()
+    }
+
+    /// Returns the style with the given name, or the default style if the theme has none.
+    pub fn style(&self, name: &str) -> Style {
+        self.get(name).unwrap_or_default()
+    }
+
+    /// Returns the number of styles of the theme.
+    pub fn len(&self) -> usize {
+        self.styles.len()
+    }
+
+    /// Returns `true` if the theme has no style.
+    pub fn is_empty(&self) -> bool {
+        self.styles.is_empty()
+    }
+}
+
+/// An error returned when a stylesheet can't be parsed into a [`Theme`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ParseThemeError {
+    line: usize,
+    message: String,
+}
+
+impl ParseThemeError {
+    /// Returns the number of the invalid line, starting at 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl fmt::Display for ParseThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseThemeError {}
+
+impl FromStr for Theme {
+    type Err = ParseThemeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut theme = Theme::new();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: String| ParseThemeError {
+                line: i + 1,
+                message,
+            };
+            let (name, attributes) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected `name = style`".into()))?;
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(error("missing style name".into()));
+            }
+            let style = parse_style(attributes).map_err(error)?;
+            theme.insert(name, style);
+        }
+        Ok(theme)
+    }
+}
+
+/// Parses the attributes of a style, e.g. `bold white on red`.
+fn parse_style(attributes: &str) -> Result<Style, String> {
+    let mut style = Style::new();
+    let mut words = attributes.split_whitespace();
+    while let Some(word) = words.next() {
+        if word == "on" {
+            let color = words
+                .next()
+                .ok_or_else(|| "expected a color after `on`".to_string())?;
+            style = style.bg(parse_color(color)?);
+        } else if let Some(modifier) = parse_modifier(word) {
+            style = style.add_modifier(modifier);
+        } else {
+            style = style.fg(parse_color(word)?);
+        }
+    }
+    Ok(style)
+}
+
+fn parse_color(word: &str) -> Result<Color, String> {
+    Color::from_str(word).map_err(|_| format!("unknown color or modifier `{word}`"))
+}
+
+fn parse_modifier(word: &str) -> Option<Modifier> {
+    let modifier = match word.to_lowercase().replace('-', "_").as_str() {
+        "bold" => Modifier::BOLD,
+        "dim" => Modifier::DIM,
+        "italic" => Modifier::ITALIC,
+        "underlined" => Modifier::UNDERLINED,
+        "slow_blink" => Modifier::SLOW_BLINK,
+        "rapid_blink" => Modifier::RAPID_BLINK,
+        "reversed" => Modifier::REVERSED,
+        "hidden" => Modifier::HIDDEN,
+        "crossed_out" => Modifier::CROSSED_OUT,
+        _ => return None,
+    };
+    Some(modifier)
+}
+
+#[cfg(feature = "fs-watch")]
+pub use watcher::ThemeWatcher;
+
+#[cfg(feature = "fs-watch")]
+mod watcher {
+    use std::{
+        ffi::OsString,
+        io,
+        path::{Path, PathBuf},
+        sync::mpsc::{self, Receiver},
+    };
+
+    use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+    use super::Theme;
+    use crate::terminal::RedrawRequest;
+
+    /// Reloads a [`Theme`] when its stylesheet file changes. `(feature: fs-watch)`
+    ///
+    /// The file is watched in the background, and the changes are applied on the thread of the
+    /// application by [`poll`](Self::poll), which also requests a redraw with [`RedrawRequest`]
+    /// so that a [`RedrawScheduler`] draws the new styles. Polling once per iteration of the event
+    /// loop lets theme authors see their changes instantly, provided that the loop wakes up
+    /// regularly, e.g. with a [`tick_rate`](crate::terminal::RedrawScheduler::tick_rate).
+    ///
+    /// A stylesheet that can't be loaded, e.g. while it is being edited, keeps the previous theme
+    /// and its error is available with [`error`](Self::error).
+    ///
+    /// [`RedrawScheduler`]: crate::terminal::RedrawScheduler
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ratatui::{prelude::*, style::ThemeWatcher, widgets::*};
+    ///
+    /// # fn ui(frame: &mut Frame, theme: &ratatui::style::Theme) {}
+    /// # let mut terminal = Terminal::new(CrosstermBackend::new(std::io::stdout()))?;
+    /// let mut theme = ThemeWatcher::new("theme.txt")?;
+    /// loop {
+    ///     theme.poll();
+    ///     terminal.draw(|frame| ui(frame, theme.theme()))?;
+    ///     # break;
+    /// }
+    /// # std::io::Result::Ok(())
+    /// ```
+    #[derive(Debug)]
+    pub struct ThemeWatcher {
+        path: PathBuf,
+        file_name: Option<OsString>,
+        theme: Theme,
+        error: Option<io::Error>,
+        events: Receiver<notify::Result<notify::Event>>,
+        // kept alive to keep watching the file
+        _watcher: RecommendedWatcher,
+    }
+
+    impl ThemeWatcher {
+        /// Loads the theme from the given stylesheet file and starts watching the file.
+        ///
+        /// The directory of the file is watched rather than the file itself, so that the file is
+        /// still watched when an editor saves it by replacing it.
+        pub fn new<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+            let path = path.as_ref().to_path_buf();
+            let theme = Theme::load(&path)?;
+            let (sender, events) = mpsc::channel();
+            let mut watcher = notify::recommended_watcher(sender).map_err(to_io_error)?;
+            let directory = match path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent,
+                _ => Path::new("."),
+            };
+            watcher
+                .watch(directory, RecursiveMode::NonRecursive)
+                .map_err(to_io_error)?;
+            Ok(Self {
+                file_name: path.file_name().map(OsString::from),
+                path,
+                theme,
+                error: None,
+                events,
+                _watcher: watcher,
+            })
+        }
+
+        /// Returns the current theme.
+        pub fn theme(&self) -> &Theme {
+            &self.theme
+        }
+
+        /// Returns the path of the stylesheet file.
+        pub fn path(&self) -> &Path {
+            &self.path
+        }
+
+        /// Returns the error of the last reload, if it failed.
+        pub fn error(&self) -> Option<&io::Error> {
+            self.error.as_ref()
+        }
+
+        /// Applies the changes of the stylesheet file since the last poll, without blocking.
+        ///
+        /// Returns `true` and requests a redraw if the theme was reloaded.
+        pub fn poll(&mut self) -> bool {
+            let mut changed = false;
+            for event in self.events.try_iter() {
+                match event {
+                    Ok(event) => {
+                        changed |= matches!(
+                            event.kind,
+                            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Any
+                        ) && event
+                            .paths
+                            .iter()
+                            .any(|path| path.file_name() == self.file_name.as_deref());
+                    }
+                    Err(err) => self.error = Some(to_io_error(err)),
+                }
+            }
+            changed && self.reload()
+        }
+
+        /// Reloads the theme from the stylesheet file. Returns `true` and requests a redraw if
+        /// the theme changed.
+        pub fn reload(&mut self) -> bool {
+            match Theme::load(&self.path) {
+                Ok(theme) => {
+                    self.error = None;
+                    let changed = theme != self.theme;
+                    self.theme = theme;
+                    RedrawRequest::request_if(changed)
+                }
+                Err(err) => {
+                    self.error = Some(err);
+                    false
+                }
+            }
+        }
+    }
+
+    fn to_io_error(err: notify::Error) -> io::Error {
+        match err.kind {
+            notify::ErrorKind::Io(err) => err,
+            _ => io::Error::new(io::ErrorKind::Other, err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_stylesheet() {
+        let theme: Theme = indoc::indoc! {"
+            # comment
+            title = bold yellow
+
+            error =  white on #ff0000 crossed-out
+            plain =
+        "}
+        .parse()
+        .unwrap();
+        assert_eq!(theme.len(), 3);
+        assert_eq!(
+            theme.get("title"),
+            Some(Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+        );
+        assert_eq!(
+            theme.style("error"),
+            Style::new()
+                .fg(Color::White)
+                .bg(Color::Rgb(255, 0, 0))
+                .add_modifier(Modifier::CROSSED_OUT)
+        );
+        assert_eq!(theme.get("plain"), Some(Style::new()));
+        assert_eq!(theme.get("missing"), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = "a = red\nb red".parse::<Theme>().unwrap_err();
+        assert_eq!(error.line(), 2);
+        assert_eq!(error.to_string(), "line 2: expected `name = style`");
+        let error = "a = bold shiny".parse::<Theme>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1: unknown color or modifier `shiny`"
+        );
+        assert!("a = red on".parse::<Theme>().is_err());
+        assert!(" = red".parse::<Theme>().is_err());
+    }
+
+    #[cfg(feature = "fs-watch")]
+    #[test]
+    fn watcher_reloads_changed_file() {
+        use std::time::{Duration, Instant};
+
+        use crate::terminal::RedrawRequest;
+
+        let directory = std::env::temp_dir().join(format!("ratatui-theme-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("theme.txt");
+        fs::write(&path, "title = red").unwrap();
+
+        let mut watcher = ThemeWatcher::new(&path).unwrap();
+        assert_eq!(watcher.theme().style("title"), Style::new().fg(Color::Red));
+        assert!(!watcher.poll());
+
+        fs::write(&path, "title = blue").unwrap();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !watcher.poll() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(watcher.theme().style("title"), Style::new().fg(Color::Blue));
+        assert!(RedrawRequest::is_pending());
+
+        // an invalid stylesheet keeps the previous theme
+        fs::write(&path, "title blue").unwrap();
+        assert!(!watcher.reload());
+        assert_eq!(watcher.theme().style("title"), Style::new().fg(Color::Blue));
+        assert!(watcher.error().is_some());
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
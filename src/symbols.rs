@@ -114,6 +114,14 @@ pub mod bar {
     };
 }
 
+pub mod shade {
+    pub const EMPTY: &str = " ";
+    pub const LIGHT: &str = "░";
+    pub const MEDIUM: &str = "▒";
+    pub const DARK: &str = "▓";
+    pub const FULL: &str = "█";
+}
+
 pub mod line {
     pub const VERTICAL: &str = "│";
     pub const DOUBLE_VERTICAL: &str = "║";
@@ -310,6 +318,24 @@ pub mod border {
         horizontal_bottom: line::DOUBLE.horizontal,
     };
 
+    /// Border Set with ASCII characters, for terminals without box drawing characters
+    ///
+    /// ```text
+    /// +-----+
+    /// |xxxxx|
+    /// |xxxxx|
+    /// +-----+
+    pub const ASCII: Set = Set {
+        top_left: "+",
+        top_right: "+",
+        bottom_left: "+",
+        bottom_right: "+",
+        vertical_left: "|",
+        vertical_right: "|",
+        horizontal_top: "-",
+        horizontal_bottom: "-",
+    };
+
     /// Border Set with a thick line width
     ///
     /// ```text
@@ -395,6 +421,52 @@ pub mod braille {
     ];
 }
 
+pub mod checkbox {
+    /// The symbols of a checked and an unchecked box.
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+    pub struct Set {
+        pub checked: &'static str,
+        pub unchecked: &'static str,
+    }
+
+    impl Default for Set {
+        fn default() -> Self {
+            BRACKETS
+        }
+    }
+
+    /// Checkbox drawn with ASCII characters: `[x]` and `[ ]`.
+    pub const BRACKETS: Set = Set {
+        checked: "[x]",
+        unchecked: "[ ]",
+    };
+
+    /// Checkbox drawn with the ballot box characters: `☑` and `☐`.
+    pub const BALLOT_BOX: Set = Set {
+        checked: "☑",
+        unchecked: "☐",
+    };
+
+    /// Radio button drawn with ASCII characters: `(*)` and `( )`.
+    pub const PARENTHESES: Set = Set {
+        checked: "(*)",
+        unchecked: "( )",
+    };
+
+    /// Radio button drawn with circles: `◉` and `○`.
+    pub const CIRCLES: Set = Set {
+        checked: "◉",
+        unchecked: "○",
+    };
+}
+
+pub mod spinner {
+    /// The frames of a spinner made of a dot turning in a Braille pattern.
+    pub const BRAILLE: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+    /// The frames of a spinner made of a line turning, with ASCII characters only.
+    pub const LINE: &[&str] = &["|", "/", "-", "\\"];
+}
+
 /// Marker to use when plotting data points
 #[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum Marker {
@@ -30,15 +30,33 @@
 //! [`backend`]: crate::backend
 //! [`Backend`]: crate::backend::Backend
 //! [`Buffer`]: crate::buffer::Buffer
-use std::{fmt, io};
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt,
+    hash::{Hash, Hasher},
+    io,
+    ops::Range,
+};
+
+use unicode_width::UnicodeWidthStr;
 
 use crate::{
-    backend::{Backend, ClearType},
-    buffer::Buffer,
-    layout::Rect,
-    widgets::{StatefulWidget, Widget},
+    backend::{Backend, Capabilities, ClearType},
+    buffer::{Buffer, Cell},
+    error::Result,
+    layout::{Position, Rect, Size},
+    style::{ContrastCheck, Modifier},
+    widgets::{CursorProvider, StatefulWidget, Widget},
 };
 
+mod filter;
+mod layout_debug;
+mod redraw;
+use filter::Filters;
+pub use filter::{AsciiFallback, Dim, Filter, Grayscale, Scanlines};
+pub use layout_debug::LayoutDebug;
+pub use redraw::{RedrawRequest, RedrawScheduler};
+
 /// Represents the viewport of the terminal. The viewport is the area of the terminal that is
 /// currently visible to the user. It can be either fullscreen, inline or fixed.
 ///
@@ -75,6 +93,29 @@ impl fmt::Display for Viewport {
     }
 }
 
+/// What the terminal does with the previous frame when it is resized.
+///
+/// See [`Terminal::set_resize_behavior`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ResizeBehavior {
+    /// Clears the screen and redraws the whole frame on the next draw call.
+    ///
+    /// This is always correct, but the screen may flicker while it is blank.
+    #[default]
+    Clear,
+    /// Keeps the cells of the previous frame that are still inside the viewport, and only redraws
+    /// the cells that change on the next draw call.
+    ///
+    /// This assumes that the terminal keeps its content in place when resized, which is the case
+    /// of most terminals in the alternate screen. Terminals rewrapping long lines on resize may
+    /// show stale cells until they change.
+    Reflow,
+    /// Like [`Reflow`](Self::Reflow), and immediately redraws the previous frame stretched or
+    /// shrunk to the new size, so that the screen is not left half drawn until the next draw
+    /// call. Wide characters are blanked in the scaled frame.
+    Scale,
+}
+
 /// Options to pass to [`Terminal::with_options`]
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct TerminalOptions {
@@ -104,6 +145,12 @@ pub struct TerminalOptions {
 /// Applications should detect terminal resizes and call [`Terminal::draw`] to redraw the
 /// application with the new size. This will automatically resize the internal buffers to match the
 /// new size for inline and fullscreen viewports. Fixed viewports are not resized automatically.
+/// The screen is cleared when the terminal is resized, see [`Terminal::set_resize_behavior`] to
+/// keep the previous frame instead.
+///
+/// The operations talking to the terminal return an [`Error`] when the backend fails, which
+/// converts into an [`io::Error`] for applications returning an [`io::Result`]. The [`Backend`]
+/// implementations themselves keep returning [`io::Result`].
 ///
 /// # Examples
 ///
@@ -128,6 +175,7 @@ pub struct TerminalOptions {
 /// [`backend`]: crate::backend
 /// [`Backend`]: crate::backend::Backend
 /// [`Buffer`]: crate::buffer::Buffer
+/// [`Error`]: crate::Error
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct Terminal<B>
 where
@@ -150,7 +198,16 @@ where
     last_known_size: Rect,
     /// Last known position of the cursor. Used to find the new area when the viewport is inlined
     /// and the terminal resized.
-    last_known_cursor_pos: (u16, u16),
+    last_known_cursor_pos: Position,
+    /// Whether the backend reported that it does not support scrolling regions. Used to avoid
+    /// looking for shifted areas in every frame when they can't be scrolled anyway.
+    scroll_regions_unsupported: bool,
+    /// What the terminal can display, made available to the widgets while drawing
+    capabilities: Capabilities,
+    /// What to do with the previous frame when the terminal is resized
+    resize_behavior: ResizeBehavior,
+    /// The overlay showing the gaps and the overlaps of the layout, if enabled
+    layout_debug: Option<LayoutDebug>,
 }
 
 impl<B> Drop for Terminal<B>
@@ -173,6 +230,10 @@ where
 {
     /// Creates a new [`Terminal`] with the given [`Backend`] with a full screen viewport.
     ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to query the size of the terminal.
+    ///
     /// # Example
     ///
     /// ```rust,no_run
@@ -182,7 +243,7 @@ where
     /// let terminal = Terminal::new(backend)?;
     /// # std::io::Result::Ok(())
     /// ```
-    pub fn new(backend: B) -> io::Result<Terminal<B>> {
+    pub fn new(backend: B) -> Result<Terminal<B>> {
         Terminal::with_options(
             backend,
             TerminalOptions {
@@ -193,6 +254,11 @@ where
 
     /// Creates a new [`Terminal`] with the given [`Backend`] and [`TerminalOptions`].
     ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to query the size of the terminal, or the position of
+    /// the cursor for an inline viewport.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -203,16 +269,17 @@ where
     /// let terminal = Terminal::with_options(backend, TerminalOptions { viewport })?;
     /// # std::io::Result::Ok(())
     /// ```
-    pub fn with_options(mut backend: B, options: TerminalOptions) -> io::Result<Terminal<B>> {
+    pub fn with_options(mut backend: B, options: TerminalOptions) -> Result<Terminal<B>> {
         let size = match options.viewport {
             Viewport::Fullscreen | Viewport::Inline(_) => backend.size()?,
             Viewport::Fixed(area) => area,
         };
         let (viewport_area, cursor_pos) = match options.viewport {
-            Viewport::Fullscreen => (size, (0, 0)),
+            Viewport::Fullscreen => (size, Position::ORIGIN),
             Viewport::Inline(height) => compute_inline_size(&mut backend, height, size, 0)?,
-            Viewport::Fixed(area) => (area, (area.left(), area.top())),
+            Viewport::Fixed(area) => (area, area.as_position()),
         };
+        let capabilities = backend.capabilities();
         Ok(Terminal {
             backend,
             buffers: [Buffer::empty(viewport_area), Buffer::empty(viewport_area)],
@@ -222,6 +289,10 @@ where
             viewport_area,
             last_known_size: size,
             last_known_cursor_pos: cursor_pos,
+            scroll_regions_unsupported: false,
+            capabilities,
+            resize_behavior: ResizeBehavior::default(),
+            layout_debug: None,
         })
     }
 
@@ -230,7 +301,9 @@ where
         Frame {
             cursor_position: None,
             viewport_area: self.viewport_area,
+            rendered_areas: self.layout_debug.map(|_| Vec::new()),
             buffer: self.current_buffer_mut(),
+            filters: Filters::default(),
         }
     }
 
@@ -249,41 +322,166 @@ where
         &mut self.backend
     }
 
+    /// Returns what the terminal can display. Defaults to the [`Backend::capabilities`] of the
+    /// backend.
+    pub fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+
+    /// Overrides what the terminal can display, e.g. from a setting of the application when the
+    /// terminal is misdetected.
+    pub fn set_capabilities(&mut self, capabilities: Capabilities) {
+        self.capabilities = capabilities;
+    }
+
+    /// Returns what the terminal does with the previous frame when it is resized.
+    pub fn resize_behavior(&self) -> ResizeBehavior {
+        self.resize_behavior
+    }
+
+    /// Sets what the terminal does with the previous frame when it is resized. Defaults to
+    /// [`ResizeBehavior::Clear`].
+    pub fn set_resize_behavior(&mut self, behavior: ResizeBehavior) {
+        self.resize_behavior = behavior;
+    }
+
+    /// Returns the overlay showing the gaps and the overlaps of the layout, if it is enabled.
+    pub fn layout_debug(&self) -> Option<LayoutDebug> {
+        self.layout_debug
+    }
+
+    /// Enables or disables the overlay showing the gaps and the overlaps of the layout of the
+    /// next frames, see [`LayoutDebug`].
+    pub fn set_layout_debug(&mut self, layout_debug: Option<LayoutDebug>) {
+        self.layout_debug = layout_debug;
+    }
+
     /// Obtains a difference between the previous and the current buffer and passes it to the
     /// current backend for drawing.
-    pub fn flush(&mut self) -> io::Result<()> {
+    ///
+    /// If a large area of the previous buffer was shifted vertically (e.g. new lines appended to
+    /// a log view) and the backend supports scrolling regions, the area is scrolled on the
+    /// terminal first so that only the rows that actually changed have to be redrawn.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to write the changes to the terminal.
+    pub fn flush(&mut self) -> Result<()> {
+        if !self.capabilities.italic {
+            for cell in &mut self.buffers[self.current].content {
+                cell.modifier.remove(Modifier::ITALIC);
+            }
+        }
+        if !self.capabilities.unicode {
+            for (x, y, cell) in self.buffers[self.current].cells_mut() {
+                AsciiFallback.apply(Position::new(x, y), cell);
+            }
+        }
+        self.scroll_shifted_region()?;
         let previous_buffer = &self.buffers[1 - self.current];
         let current_buffer = &self.buffers[self.current];
         let updates = previous_buffer.diff(current_buffer);
         if let Some((col, row, _)) = updates.last() {
-            self.last_known_cursor_pos = (*col, *row);
+            self.last_known_cursor_pos = Position::new(*col, *row);
+        }
+        self.backend.draw(updates.into_iter())?;
+        Ok(())
+    }
+
+    /// Scrolls the terminal if the current buffer contains a vertically shifted copy of a large
+    /// area of the previous buffer, and updates the previous buffer to match the terminal.
+    ///
+    /// Scrolling regions always span the full width of the terminal, so this is only done when
+    /// the viewport does too.
+    fn scroll_shifted_region(&mut self) -> io::Result<()> {
+        if self.scroll_regions_unsupported
+            || self.viewport_area.x != 0
+            || self.viewport_area.width != self.last_known_size.width
+        {
+            return Ok(());
+        }
+        let previous_buffer = &self.buffers[1 - self.current];
+        let current_buffer = &self.buffers[self.current];
+        let Some(shift) = VerticalShift::detect(previous_buffer, current_buffer) else {
+            return Ok(());
+        };
+        let top = self.viewport_area.top();
+        let region = top + shift.region.start..top + shift.region.end;
+        let result = match shift.direction {
+            ShiftDirection::Up => self.backend.scroll_region_up(region, shift.lines),
+            ShiftDirection::Down => self.backend.scroll_region_down(region, shift.lines),
+        };
+        match result {
+            Ok(()) => {
+                shift.apply(&mut self.buffers[1 - self.current]);
+                Ok(())
+            }
+            Err(err) if err.kind() == io::ErrorKind::Unsupported => {
+                self.scroll_regions_unsupported = true;
+                Ok(())
+            }
+            Err(err) => Err(err),
         }
-        self.backend.draw(updates.into_iter())
     }
 
     /// Updates the Terminal so that internal buffers match the requested size.
     ///
-    /// Requested size will be saved so the size can remain consistent when rendering. This leads
-    /// to a full clear of the screen.
-    pub fn resize(&mut self, size: Rect) -> io::Result<()> {
+    /// Requested size will be saved so the size can remain consistent when rendering. What
+    /// happens to the previous frame depends on the [`ResizeBehavior`]: by default this leads to
+    /// a full clear of the screen.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to clear, redraw or query the cursor of the terminal.
+    pub fn resize(&mut self, size: Rect) -> Result<()> {
         let next_area = match self.viewport {
             Viewport::Fullscreen => size,
             Viewport::Inline(height) => {
                 let offset_in_previous_viewport = self
                     .last_known_cursor_pos
-                    .1
+                    .y
                     .saturating_sub(self.viewport_area.top());
                 compute_inline_size(&mut self.backend, height, size, offset_in_previous_viewport)?.0
             }
             Viewport::Fixed(area) => area,
         };
-        self.set_viewport_area(next_area);
-        self.clear()?;
+        match self.resize_behavior {
+            ResizeBehavior::Clear => {
+                self.set_viewport_area(next_area);
+                self.clear()?;
+            }
+            ResizeBehavior::Reflow => self.reflow_viewport_area(next_area),
+            ResizeBehavior::Scale => {
+                let previous_frame = self.buffers[1 - self.current].clone();
+                self.reflow_viewport_area(next_area);
+                let scaled = scale_buffer(&previous_frame, next_area);
+                let updates = self.buffers[1 - self.current].diff(&scaled);
+                self.backend.draw(updates.into_iter())?;
+                self.backend.flush()?;
+                self.buffers[1 - self.current] = scaled;
+            }
+        }
 
         self.last_known_size = size;
         Ok(())
     }
 
+    /// Resizes the buffers to the area, keeping the cells of the previous frame that are still
+    /// inside it.
+    fn reflow_viewport_area(&mut self, area: Rect) {
+        let previous = &self.buffers[1 - self.current];
+        let mut reflowed = Buffer::empty(area);
+        let kept = previous.area.intersection(area);
+        for y in kept.top()..kept.bottom() {
+            for x in kept.left()..kept.right() {
+                *reflowed.get_mut(x, y) = previous.get(x, y).clone();
+            }
+        }
+        self.buffers[1 - self.current] = reflowed;
+        self.buffers[self.current] = Buffer::empty(area);
+        self.viewport_area = area;
+    }
+
     fn set_viewport_area(&mut self, area: Rect) {
         self.buffers[self.current].resize(area);
         self.buffers[1 - self.current].resize(area);
@@ -291,10 +489,15 @@ where
     }
 
     /// Queries the backend for size and resizes if it doesn't match the previous size.
-    pub fn autoresize(&mut self) -> io::Result<()> {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to query the size of the terminal or to resize it, see
+    /// [`Terminal::resize`].
+    pub fn autoresize(&mut self) -> Result<()> {
         // fixed viewports do not get autoresized
         if matches!(self.viewport, Viewport::Fullscreen | Viewport::Inline(_)) {
-            let size = self.size()?;
+            let size = self.backend.size()?;
             if size != self.last_known_size {
                 self.resize(size)?;
             }
@@ -305,7 +508,13 @@ where
     /// Synchronizes terminal size, calls the rendering closure, flushes the current internal state
     /// and prepares for the next draw call.
     ///
-    /// This is the main entry point for drawing to the terminal.
+    /// This is the main entry point for drawing to the terminal. The redraws requested with
+    /// [`RedrawRequest`] are cleared once the closure returns.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to query the size of the terminal, to write the
+    /// frame or to move the cursor. The closure is not called if the size can't be queried.
     ///
     /// # Examples
     ///
@@ -321,7 +530,7 @@ where
     /// })?;
     /// # std::io::Result::Ok(())
     /// ```
-    pub fn draw<F>(&mut self, f: F) -> io::Result<CompletedFrame>
+    pub fn draw<F>(&mut self, f: F) -> Result<CompletedFrame>
     where
         F: FnOnce(&mut Frame),
     {
@@ -329,21 +538,34 @@ where
         // and the terminal (if growing), which may OOB.
         self.autoresize()?;
 
+        // restores the previous capabilities even if `f` panics and the panic is caught
+        let capabilities = Capabilities::set_current(self.capabilities);
+        let layout_debug = self.layout_debug;
         let mut frame = self.get_frame();
         f(&mut frame);
+        frame.filters.apply(frame.buffer);
+        if let (Some(layout_debug), Some(areas)) = (layout_debug, &frame.rendered_areas) {
+            layout_debug.apply(areas, frame.buffer);
+        }
+        drop(capabilities);
+        RedrawRequest::clear();
+        if cfg!(debug_assertions) {
+            ContrastCheck::check(frame.buffer);
+        }
         // We can't change the cursor position right away because we have to flush the frame to
         // stdout first. But we also can't keep the frame around, since it holds a &mut to
         // Buffer. Thus, we're taking the important data out of the Frame and dropping it.
         let cursor_position = frame.cursor_position;
+        drop(frame);
 
         // Draw to stdout
         self.flush()?;
 
         match cursor_position {
             None => self.hide_cursor()?,
-            Some((x, y)) => {
+            Some(position) => {
                 self.show_cursor()?;
-                self.set_cursor(x, y)?;
+                self.set_cursor_position(position)?;
             }
         }
 
@@ -359,14 +581,22 @@ where
     }
 
     /// Hides the cursor.
-    pub fn hide_cursor(&mut self) -> io::Result<()> {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to hide the cursor.
+    pub fn hide_cursor(&mut self) -> Result<()> {
         self.backend.hide_cursor()?;
         self.hidden_cursor = true;
         Ok(())
     }
 
     /// Shows the cursor.
-    pub fn show_cursor(&mut self) -> io::Result<()> {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to show the cursor.
+    pub fn show_cursor(&mut self) -> Result<()> {
         self.backend.show_cursor()?;
         self.hidden_cursor = false;
         Ok(())
@@ -376,19 +606,52 @@ where
     ///
     /// This is the position of the cursor after the last draw call and is returned as a tuple of
     /// `(x, y)` coordinates.
-    pub fn get_cursor(&mut self) -> io::Result<(u16, u16)> {
-        self.backend.get_cursor()
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to query the position of the cursor.
+    pub fn get_cursor(&mut self) -> Result<(u16, u16)> {
+        Ok(self.backend.get_cursor()?)
     }
 
     /// Sets the cursor position.
-    pub fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
-        self.backend.set_cursor(x, y)?;
-        self.last_known_cursor_pos = (x, y);
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to move the cursor.
+    pub fn set_cursor(&mut self, x: u16, y: u16) -> Result<()> {
+        self.set_cursor_position(Position::new(x, y))
+    }
+
+    /// Gets the current cursor position as a [`Position`].
+    ///
+    /// This is the same as [`Terminal::get_cursor`], without the ambiguity of a bare tuple.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to query the position of the cursor.
+    pub fn get_cursor_position(&mut self) -> Result<Position> {
+        Ok(self.backend.get_cursor_position()?)
+    }
+
+    /// Sets the cursor position, from a [`Position`] or a `(x, y)` tuple.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to move the cursor.
+    pub fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> Result<()> {
+        let position = position.into();
+        self.backend.set_cursor_position(position)?;
+        self.last_known_cursor_pos = position;
         Ok(())
     }
 
     /// Clear the terminal and force a full redraw on the next draw call.
-    pub fn clear(&mut self) -> io::Result<()> {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to clear the terminal.
+    pub fn clear(&mut self) -> Result<()> {
         match self.viewport {
             Viewport::Fullscreen => self.backend.clear_region(ClearType::All)?,
             Viewport::Inline(_) => {
@@ -414,9 +677,16 @@ where
         self.current = 1 - self.current;
     }
 
-    /// Queries the real size of the backend.
-    pub fn size(&self) -> io::Result<Rect> {
-        self.backend.size()
+    /// Queries the real size of the backend, in columns and rows.
+    ///
+    /// Use [`Terminal::get_frame`] and [`Frame::size`] for the area to render in, which also
+    /// accounts for the viewport.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to query the size of the terminal.
+    pub fn size(&self) -> Result<Size> {
+        Ok(self.backend.size().map(Size::from)?)
     }
 
     /// Insert some content before the current inline viewport. This has no effect when the
@@ -446,6 +716,10 @@ where
     /// +-------------------+
     /// ```
     ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to clear the viewport or to write the inserted lines.
+    ///
     /// # Examples
     ///
     /// ## Insert a single line before the current viewport
@@ -463,7 +737,7 @@ where
     ///     .render(buf.area, buf);
     /// });
     /// ```
-    pub fn insert_before<F>(&mut self, height: u16, draw_fn: F) -> io::Result<()>
+    pub fn insert_before<F>(&mut self, height: u16, draw_fn: F) -> Result<()>
     where
         F: FnOnce(&mut Buffer),
     {
@@ -520,14 +794,151 @@ where
     }
 }
 
+/// The direction in which the rows of a [`VerticalShift`] moved.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum ShiftDirection {
+    Up,
+    Down,
+}
+
+/// A range of rows that moved vertically by a number of lines between two buffers.
+///
+/// The region is given in rows relative to the top of the buffers and includes both the moved
+/// rows and the rows that were vacated by the move.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct VerticalShift {
+    region: Range<u16>,
+    lines: u16,
+    direction: ShiftDirection,
+}
+
+impl VerticalShift {
+    /// The minimum number of rows that must be saved from redrawing for a shift to be used.
+    const MIN_SAVED_ROWS: usize = 2;
+
+    /// Finds the vertical shift between `previous` and `next` that saves the most rows from being
+    /// redrawn, if any.
+    ///
+    /// A row is saved when it differs from the row at the same position in `previous` but equals
+    /// the row `lines` rows below (for a shift up) or above (for a shift down). Each vacated row
+    /// has to be redrawn, so it counts against the shift.
+    fn detect(previous: &Buffer, next: &Buffer) -> Option<VerticalShift> {
+        let width = previous.area.width as usize;
+        let height = previous.area.height as usize;
+        if previous.area != next.area || width == 0 || height <= Self::MIN_SAVED_ROWS {
+            return None;
+        }
+        let previous_rows = previous.content.chunks(width).collect::<Vec<_>>();
+        let next_rows = next.content.chunks(width).collect::<Vec<_>>();
+        let previous_hashes = previous_rows
+            .iter()
+            .map(|row| row_hash(row))
+            .collect::<Vec<_>>();
+        let next_hashes = next_rows
+            .iter()
+            .map(|row| row_hash(row))
+            .collect::<Vec<_>>();
+        let rows_equal = |next_y: usize, previous_y: usize| {
+            next_hashes[next_y] == previous_hashes[previous_y]
+                && next_rows[next_y] == previous_rows[previous_y]
+        };
+
+        let mut best: Option<(usize, VerticalShift)> = None;
+        for lines in 1..height - Self::MIN_SAVED_ROWS {
+            for direction in [ShiftDirection::Up, ShiftDirection::Down] {
+                // `start` and `saved` describe the current run of rows that match when shifted
+                let mut start = None;
+                let mut saved = 0;
+                for y in 0..=height - lines {
+                    let (next_y, previous_y) = match direction {
+                        ShiftDirection::Up => (y, y + lines),
+                        ShiftDirection::Down => (y + lines, y),
+                    };
+                    if y < height - lines && rows_equal(next_y, previous_y) {
+                        start.get_or_insert(y);
+                        if !rows_equal(next_y, next_y) {
+                            saved += 1;
+                        }
+                        continue;
+                    }
+                    if let Some(run_start) = start.take() {
+                        let benefit = saved - saved.min(lines);
+                        if benefit >= Self::MIN_SAVED_ROWS
+                            && best.as_ref().map_or(true, |(b, _)| benefit > *b)
+                        {
+                            let shift = VerticalShift {
+                                region: run_start as u16..(y + lines) as u16,
+                                lines: lines as u16,
+                                direction,
+                            };
+                            best = Some((benefit, shift));
+                        }
+                    }
+                    saved = 0;
+                }
+            }
+        }
+        best.map(|(_, shift)| shift)
+    }
+
+    /// Applies the shift to the rows of the buffer the same way the terminal scrolls them.
+    fn apply(&self, buffer: &mut Buffer) {
+        let width = buffer.area.width as usize;
+        let rows = &mut buffer.content
+            [self.region.start as usize * width..self.region.end as usize * width];
+        let shift = self.lines as usize * width;
+        match self.direction {
+            ShiftDirection::Up => {
+                rows.rotate_left(shift);
+                let len = rows.len();
+                rows[len - shift..].fill(Cell::default());
+            }
+            ShiftDirection::Down => {
+                rows.rotate_right(shift);
+                rows[..shift].fill(Cell::default());
+            }
+        }
+    }
+}
+
+/// Returns a copy of the buffer stretched or shrunk to the area, picking the nearest cell of the
+/// buffer for each cell of the area. Wide and zero-width symbols are replaced by a space as their
+/// neighbours may be dropped or repeated.
+fn scale_buffer(buffer: &Buffer, area: Rect) -> Buffer {
+    let mut scaled = Buffer::empty(area);
+    if buffer.area.is_empty() {
+        return scaled;
+    }
+    for y in 0..area.height {
+        let source_y = buffer.area.y
+            + (u32::from(y) * u32::from(buffer.area.height) / u32::from(area.height)) as u16;
+        for x in 0..area.width {
+            let source_x = buffer.area.x
+                + (u32::from(x) * u32::from(buffer.area.width) / u32::from(area.width)) as u16;
+            let mut cell = buffer.get(source_x, source_y).clone();
+            if cell.symbol().width() != 1 {
+                cell.set_symbol(" ");
+            }
+            *scaled.get_mut(area.x + x, area.y + y) = cell;
+        }
+    }
+    scaled
+}
+
+fn row_hash(row: &[Cell]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    row.hash(&mut hasher);
+    hasher.finish()
+}
+
 fn compute_inline_size<B: Backend>(
     backend: &mut B,
     height: u16,
     size: Rect,
     offset_in_previous_viewport: u16,
-) -> io::Result<(Rect, (u16, u16))> {
-    let pos = backend.get_cursor()?;
-    let mut row = pos.1;
+) -> io::Result<(Rect, Position)> {
+    let pos = backend.get_cursor_position()?;
+    let mut row = pos.y;
 
     let max_height = size.height.min(height);
 
@@ -569,17 +980,24 @@ fn compute_inline_size<B: Backend>(
 pub struct Frame<'a> {
     /// Where should the cursor be after drawing this frame?
     ///
-    /// If `None`, the cursor is hidden and its position is controlled by the backend. If `Some((x,
-    /// y))`, the cursor is shown and placed at `(x, y)` after the call to `Terminal::draw()`.
-    cursor_position: Option<(u16, u16)>,
+    /// If `None`, the cursor is hidden and its position is controlled by the backend. If
+    /// `Some(position)`, the cursor is shown and placed at `position` after the call to
+    /// `Terminal::draw()`.
+    cursor_position: Option<Position>,
     /// The area of the viewport
     viewport_area: Rect,
 
     /// The buffer that is used to draw the current frame
     buffer: &'a mut Buffer,
+
+    /// The filters post-processing the buffer once the frame is rendered
+    filters: Filters<'a>,
+
+    /// The areas the widgets were rendered in, recorded for the [`LayoutDebug`] overlay
+    rendered_areas: Option<Vec<Rect>>,
 }
 
-impl Frame<'_> {
+impl<'a> Frame<'a> {
     /// The size of the current frame
     ///
     /// This is guaranteed not to change during rendering, so may be called multiple times.
@@ -613,6 +1031,7 @@ impl Frame<'_> {
     where
         W: Widget,
     {
+        self.record_area(area);
         widget.render(area, self.buffer);
     }
 
@@ -642,9 +1061,16 @@ impl Frame<'_> {
     where
         W: StatefulWidget,
     {
+        self.record_area(area);
         widget.render(area, self.buffer, state);
     }
 
+    fn record_area(&mut self, area: Rect) {
+        if let Some(areas) = &mut self.rendered_areas {
+            areas.push(area);
+        }
+    }
+
     /// After drawing this frame, make the cursor visible and put it at the specified (x, y)
     /// coordinates. If this method is not called, the cursor will be hidden.
     ///
@@ -652,13 +1078,66 @@ impl Frame<'_> {
     /// `Terminal::show_cursor()`, and `Terminal::set_cursor()`. Pick one of the APIs and stick
     /// with it.
     pub fn set_cursor(&mut self, x: u16, y: u16) {
-        self.cursor_position = Some((x, y));
+        self.set_cursor_position(Position::new(x, y));
+    }
+
+    /// After drawing this frame, make the cursor visible and put it at the given [`Position`] or
+    /// `(x, y)` tuple. If this method is not called, the cursor will be hidden.
+    ///
+    /// This is the same as [`Frame::set_cursor`], e.g. for the position returned by
+    /// [`TextInputState::cursor_screen_position`].
+    ///
+    /// [`TextInputState::cursor_screen_position`]:
+    ///     crate::widgets::TextInputState::cursor_screen_position
+    pub fn set_cursor_position<P: Into<Position>>(&mut self, position: P) {
+        self.cursor_position = Some(position.into());
+    }
+
+    /// After drawing this frame, make the cursor visible at the position provided by the given
+    /// widget state, e.g. a focused [`TextInputState`].
+    ///
+    /// Nothing changes if the state doesn't provide a position, i.e. if its widget doesn't have
+    /// the focus, so this can be called for every input of a screen and the cursor ends up in the
+    /// focused one. If no state provides a position, the cursor is hidden.
+    ///
+    /// [`TextInputState`]: crate::widgets::TextInputState
+    pub fn set_cursor_for<C: CursorProvider + ?Sized>(&mut self, provider: &C) {
+        if let Some(position) = provider.cursor_position() {
+            self.set_cursor_position(position);
+        }
     }
 
     /// Gets the buffer that this `Frame` draws into as a mutable reference.
     pub fn buffer_mut(&mut self) -> &mut Buffer {
         self.buffer
     }
+
+    /// Post-processes the given area of the frame with a [`Filter`] once all the widgets are
+    /// rendered, e.g. to dim the screen behind a modal.
+    ///
+    /// The filters are applied by [`Terminal::draw`] after the closure returns, in the order they
+    /// were registered, and before the frame is compared to the previous one. Each filter only
+    /// visits the cells of its area inside the frame, so effects limited to a part of the screen
+    /// stay cheap.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{backend::TestBackend, prelude::*, terminal::Dim, widgets::*};
+    /// # let backend = TestBackend::new(10, 5);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// terminal.draw(|frame| {
+    ///     frame.render_widget(Paragraph::new("Background"), frame.size());
+    ///     frame.post_process(frame.size(), Dim);
+    /// })?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn post_process<F>(&mut self, area: Rect, filter: F)
+    where
+        F: Filter + 'a,
+    {
+        self.filters.push(area, Box::new(filter));
+    }
 }
 
 /// `CompletedFrame` represents the state of the terminal after all changes performed in the last
@@ -674,7 +1153,88 @@ pub struct CompletedFrame<'a> {
 
 #[cfg(test)]
 mod tests {
+    use std::panic::{self, AssertUnwindSafe};
+
     use super::*;
+    use crate::backend::TestBackend;
+
+    #[test]
+    fn draw_restores_capabilities_after_panic() {
+        let mut terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+        let ascii = Capabilities {
+            unicode: false,
+            ..Capabilities::FULL
+        };
+        terminal.set_capabilities(ascii);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            terminal
+                .draw(|_| {
+                    assert_eq!(Capabilities::current(), ascii);
+                    panic!("render failed");
+                })
+                .ok();
+        }));
+        assert!(result.is_err());
+        assert_eq!(Capabilities::current(), Capabilities::FULL);
+    }
+
+    #[test]
+    fn vertical_shift_detect_up() {
+        let previous = Buffer::with_lines(vec!["a", "b", "c", "d", "e", "f"]);
+        let next = Buffer::with_lines(vec!["c", "d", "e", "f", "g", "h"]);
+        assert_eq!(
+            VerticalShift::detect(&previous, &next),
+            Some(VerticalShift {
+                region: 0..6,
+                lines: 2,
+                direction: ShiftDirection::Up,
+            })
+        );
+    }
+
+    #[test]
+    fn vertical_shift_detect_down_within_region() {
+        let previous = Buffer::with_lines(vec!["#", "a", "b", "c", "d", "e", "#"]);
+        let next = Buffer::with_lines(vec!["#", "z", "a", "b", "c", "d", "#"]);
+        assert_eq!(
+            VerticalShift::detect(&previous, &next),
+            Some(VerticalShift {
+                region: 1..6,
+                lines: 1,
+                direction: ShiftDirection::Down,
+            })
+        );
+    }
+
+    #[test]
+    fn vertical_shift_detect_none() {
+        let previous = Buffer::with_lines(vec!["a", "b", "c", "d"]);
+        assert_eq!(VerticalShift::detect(&previous, &previous), None);
+        let next = Buffer::with_lines(vec!["w", "x", "y", "z"]);
+        assert_eq!(VerticalShift::detect(&previous, &next), None);
+        // only a single row would be saved, which is not worth a scroll
+        let next = Buffer::with_lines(vec!["b", "x", "c", "d"]);
+        assert_eq!(VerticalShift::detect(&previous, &next), None);
+    }
+
+    #[test]
+    fn vertical_shift_apply() {
+        let mut buffer = Buffer::with_lines(vec!["a", "b", "c", "d", "e"]);
+        VerticalShift {
+            region: 1..4,
+            lines: 1,
+            direction: ShiftDirection::Up,
+        }
+        .apply(&mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(vec!["a", "c", "d", " ", "e"]));
+        VerticalShift {
+            region: 0..5,
+            lines: 2,
+            direction: ShiftDirection::Down,
+        }
+        .apply(&mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(vec![" ", " ", "a", "c", "d"]));
+    }
 
     #[test]
     fn viewport_to_string() {
@@ -685,4 +1245,109 @@ mod tests {
             "Fixed(5x5+0+0)"
         );
     }
+
+    fn resized_previous_frame(behavior: ResizeBehavior) -> Buffer {
+        let mut terminal = Terminal::new(crate::backend::TestBackend::new(4, 2)).unwrap();
+        terminal.set_resize_behavior(behavior);
+        terminal
+            .draw(|frame| {
+                let buffer = frame.buffer_mut();
+                buffer.set_string(0, 0, "abcd", crate::style::Style::default());
+                buffer.set_string(0, 1, "efgh", crate::style::Style::default());
+            })
+            .unwrap();
+        terminal.backend_mut().resize(6, 3);
+        terminal.autoresize().unwrap();
+        terminal.buffers[1 - terminal.current].clone()
+    }
+
+    #[test]
+    fn resize_clear() {
+        assert_eq!(
+            resized_previous_frame(ResizeBehavior::Clear),
+            Buffer::empty(Rect::new(0, 0, 6, 3))
+        );
+    }
+
+    #[test]
+    fn resize_reflow() {
+        assert_eq!(
+            resized_previous_frame(ResizeBehavior::Reflow),
+            Buffer::with_lines(vec!["abcd  ", "efgh  ", "      "])
+        );
+    }
+
+    #[test]
+    fn resize_scale() {
+        assert_eq!(
+            resized_previous_frame(ResizeBehavior::Scale),
+            Buffer::with_lines(vec!["aabccd", "aabccd", "eefggh"])
+        );
+    }
+
+    #[test]
+    fn scale_buffer_shrinks_and_blanks_wide_symbols() {
+        let buffer = Buffer::with_lines(vec!["ab界", "cdef"]);
+        assert_eq!(
+            scale_buffer(&buffer, Rect::new(0, 0, 3, 1)),
+            Buffer::with_lines(vec!["ab "])
+        );
+    }
+
+    #[test]
+    fn set_cursor_for_focused_input() {
+        use crate::{
+            backend::TestBackend,
+            widgets::{TextInput, TextInputState},
+        };
+
+        let mut terminal = Terminal::new(TestBackend::new(10, 2)).unwrap();
+        let mut focused = TextInputState::default();
+        focused.insert_str("abc");
+        let mut unfocused = TextInputState::default();
+        unfocused.set_focused(false);
+        terminal
+            .draw(|frame| {
+                frame.render_stateful_widget(
+                    TextInput::new(),
+                    Rect::new(0, 1, 10, 1),
+                    &mut focused,
+                );
+                frame.render_stateful_widget(
+                    TextInput::new(),
+                    Rect::new(0, 0, 10, 1),
+                    &mut unfocused,
+                );
+                frame.set_cursor_for(&focused);
+                frame.set_cursor_for(&unfocused);
+            })
+            .unwrap();
+        assert!(!terminal.hidden_cursor);
+        assert_eq!(terminal.get_cursor().unwrap(), (3, 1));
+
+        terminal
+            .draw(|frame| frame.set_cursor_for(&unfocused))
+            .unwrap();
+        assert!(terminal.hidden_cursor);
+    }
+
+    #[test]
+    fn post_process_before_flushing() {
+        use crate::{
+            backend::TestBackend,
+            style::{Style, Stylize},
+            widgets::Paragraph,
+        };
+
+        let mut terminal = Terminal::new(TestBackend::new(4, 2)).unwrap();
+        terminal
+            .draw(|frame| {
+                frame.post_process(Rect::new(0, 1, 2, 5), Dim);
+                frame.render_widget(Paragraph::new("ab\ncd"), frame.size());
+            })
+            .unwrap();
+        let mut expected = Buffer::with_lines(vec!["ab  ", "cd  "]);
+        expected.set_style(Rect::new(0, 1, 2, 1), Style::new().dim());
+        terminal.backend().assert_buffer(&expected);
+    }
 }
@@ -0,0 +1,281 @@
+use std::fmt;
+
+use crate::{
+    buffer::{Buffer, Cell},
+    layout::{Position, Rect},
+    style::{Color, Modifier},
+};
+
+/// Post-processes the cells of a frame once all its widgets are rendered, e.g. to dim the whole
+/// screen behind a modal or to turn it gray while the terminal doesn't have the focus.
+///
+/// A filter is registered for an area of the frame with [`Frame::post_process`] and is applied
+/// by [`Terminal::draw`] to each cell of that area before the frame is compared to the previous
+/// one, so that only the cells it changes are written to the terminal. Closures taking the
+/// position and the cell are filters, and [`Dim`], [`Grayscale`], [`Scanlines`] and
+/// [`AsciiFallback`] are provided.
+///
+/// [`Frame::post_process`]: crate::Frame::post_process
+/// [`Terminal::draw`]: crate::Terminal::draw
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{buffer::Cell, prelude::*, terminal::Grayscale};
+///
+/// # fn ui(frame: &mut Frame, focused: bool) {
+/// if !focused {
+///     frame.post_process(frame.size(), Grayscale);
+/// }
+/// // underlines the first row
+/// frame.post_process(Rect::new(0, 0, frame.size().width, 1), |_, cell: &mut Cell| {
+///     cell.modifier.insert(Modifier::UNDERLINED);
+/// });
+/// # }
+/// ```
+pub trait Filter {
+    /// Changes a cell of the frame, at the given position in the terminal.
+    fn apply(&self, position: Position, cell: &mut Cell);
+}
+
+impl<F> Filter for F
+where
+    F: Fn(Position, &mut Cell),
+{
+    fn apply(&self, position: Position, cell: &mut Cell) {
+        self(position, cell);
+    }
+}
+
+/// A [`Filter`] dimming the cells, e.g. behind a modal.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Dim;
+
+impl Filter for Dim {
+    fn apply(&self, _position: Position, cell: &mut Cell) {
+        cell.modifier.insert(Modifier::DIM);
+    }
+}
+
+/// A [`Filter`] turning the colors of the cells to shades of gray of the same luminance, e.g.
+/// while the terminal doesn't have the focus.
+///
+/// The [`Color::Reset`] colors, whose value is chosen by the terminal, are kept.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Grayscale;
+
+impl Grayscale {
+    fn gray(color: Color) -> Color {
+        match color.to_rgb() {
+            Some((r, g, b)) => {
+                let luminance = 0.299 * f64::from(r) + 0.587 * f64::from(g) + 0.114 * f64::from(b);
+                let level = luminance.round() as u8;
+                Color::Rgb(level, level, level)
+            }
+            None => color,
+        }
+    }
+}
+
+impl Filter for Grayscale {
+    fn apply(&self, _position: Position, cell: &mut Cell) {
+        cell.fg = Self::gray(cell.fg);
+        cell.bg = Self::gray(cell.bg);
+        #[cfg(feature = "underline-color")]
+        {
+            cell.underline_color = Self::gray(cell.underline_color);
+        }
+    }
+}
+
+/// A [`Filter`] dimming every other row of the terminal, from the second one, like the scanlines
+/// of an old screen.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Scanlines;
+
+impl Filter for Scanlines {
+    fn apply(&self, position: Position, cell: &mut Cell) {
+        if position.y % 2 == 1 {
+            cell.modifier.insert(Modifier::DIM);
+        }
+    }
+}
+
+/// A [`Filter`] replacing the symbols that aren't ASCII with ASCII ones, for terminals that can't
+/// display anything else, e.g. `TERM=dumb` or a CI log.
+///
+/// [`Terminal::flush`] applies it to the whole frame when the [`Capabilities`] of the terminal
+/// don't report [`unicode`], so that the widgets don't need an ASCII mode of their own. The box
+/// drawing characters become `-`, `=`, `|` and `+`, the block elements `#`, the braille patterns
+/// `.` and `:`, the arrows and triangles `<`, `>`, `^` and `v`, and the bullets and check marks
+/// `*`, `o` and `x`. The other symbols become `?`.
+///
+/// [`Terminal::flush`]: crate::Terminal::flush
+/// [`Capabilities`]: crate::backend::Capabilities
+/// [`unicode`]: crate::backend::Capabilities::unicode
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::terminal::AsciiFallback;
+///
+/// assert_eq!(AsciiFallback::symbol("┌"), "+");
+/// assert_eq!(AsciiFallback::symbol("⣿"), ":");
+/// assert_eq!(AsciiFallback::symbol("a"), "a");
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct AsciiFallback;
+
+impl AsciiFallback {
+    /// Returns the ASCII symbol displayed instead of the given one, or the symbol itself if it is
+    /// ASCII already.
+    ///
+    /// The replacement is always one column wide, the cells following a wide symbol being
+    /// blank already.
+    pub fn symbol(symbol: &str) -> &str {
+        let Some(ch) = symbol.chars().find(|ch| !ch.is_ascii()) else {
+            return symbol;
+        };
+        match ch {
+            '─' | '━' | '┄' | '┅' | '┈' | '┉' | '╌' | '╍' | '╴' | '╶' | '╸' | '╺' | '╼' | '╾' => {
+                "-"
+            }
+            '═' => "=",
+            '│' | '┃' | '┆' | '┇' | '┊' | '┋' | '╎' | '╏' | '║' | '╵' | '╷' | '╹' | '╻' | '╽'
+            | '╿' => "|",
+            '╱' => "/",
+            '╲' => "\\",
+            '╳' => "X",
+            '\u{2500}'..='\u{257F}' => "+",
+            '\u{2580}'..='\u{259F}' => "#",
+            // the blank pattern, then patterns of up to 3 dots and denser ones
+            '\u{2800}' => " ",
+            '\u{2801}'..='\u{28FF}' if (ch as u32 - 0x2800).count_ones() <= 3 => ".",
+            '\u{2801}'..='\u{28FF}' => ":",
+            '←' | '◄' | '◀' | '◂' | '◅' => "<",
+            '→' | '►' | '▶' | '▸' | '▻' => ">",
+            '↑' | '▲' | '▴' | '△' => "^",
+            '↓' | '▼' | '▾' | '▽' => "v",
+            '•' | '●' | '◉' | '■' | '◆' => "*",
+            '○' | '◯' | '◦' | '□' | '◇' | '☐' => "o",
+            '✓' | '✔' | '☑' | '☒' | '✕' | '✖' | '✗' | '✘' => "x",
+            '…' => ".",
+            _ => "?",
+        }
+    }
+}
+
+impl Filter for AsciiFallback {
+    fn apply(&self, _position: Position, cell: &mut Cell) {
+        let symbol = Self::symbol(cell.symbol());
+        if symbol != cell.symbol() {
+            let symbol = symbol.to_string();
+            cell.set_symbol(&symbol);
+        }
+    }
+}
+
+/// The filters registered for a frame, with the areas they are limited to.
+#[derive(Default)]
+pub(super) struct Filters<'a>(Vec<(Rect, Box<dyn Filter + 'a>)>);
+
+impl<'a> Filters<'a> {
+    pub(super) fn push(&mut self, area: Rect, filter: Box<dyn Filter + 'a>) {
+        self.0.push((area, filter));
+    }
+
+    /// Applies the filters in the order they were registered, each one only visiting the cells
+    /// of its area inside the buffer.
+    pub(super) fn apply(&self, buf: &mut Buffer) {
+        for (area, filter) in &self.0 {
+            let area = area.intersection(buf.area);
+            if area.is_empty() {
+                continue;
+            }
+            for y in area.top()..area.bottom() {
+                let start = buf.index_of(area.x, y);
+                let row = &mut buf.content[start..start + usize::from(area.width)];
+                for (x, cell) in (area.left()..).zip(row) {
+                    filter.apply(Position::new(x, y), cell);
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Filters<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|(area, _)| (area, "..")))
+            .finish()
+    }
+}
+
+impl std::hash::Hash for Filters<'_> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for (area, _) in &self.0 {
+            area.hash(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{Style, Stylize};
+
+    #[test]
+    fn filters_are_limited_to_their_area() {
+        let mut buf = Buffer::with_lines(vec!["abc", "def"]);
+        let mut filters = Filters::default();
+        filters.push(Rect::new(1, 1, 5, 5), Box::new(Dim));
+        filters.push(
+            Rect::new(0, 0, 1, 1),
+            Box::new(|position: Position, cell: &mut Cell| {
+                cell.set_symbol(&position.x.to_string());
+            }),
+        );
+        filters.push(Rect::new(10, 10, 2, 2), Box::new(Dim));
+        filters.apply(&mut buf);
+        let mut expected = Buffer::with_lines(vec!["0bc", "def"]);
+        expected.set_style(Rect::new(1, 1, 2, 1), Style::new().dim());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn grayscale() {
+        let mut cell = Cell::default();
+        cell.set_style(Style::new().fg(Color::Rgb(255, 0, 0)).bg(Color::White));
+        Grayscale.apply(Position::new(0, 0), &mut cell);
+        assert_eq!(cell.fg, Color::Rgb(76, 76, 76));
+        assert_eq!(cell.bg, Color::Rgb(255, 255, 255));
+        let mut cell = Cell::default();
+        Grayscale.apply(Position::new(0, 0), &mut cell);
+        assert_eq!((cell.fg, cell.bg), (Color::Reset, Color::Reset));
+    }
+
+    #[test]
+    fn scanlines() {
+        let mut cell = Cell::default();
+        Scanlines.apply(Position::new(3, 0), &mut cell);
+        assert_eq!(cell.modifier, Modifier::empty());
+        Scanlines.apply(Position::new(3, 1), &mut cell);
+        assert_eq!(cell.modifier, Modifier::DIM);
+    }
+
+    #[test]
+    fn ascii_fallback() {
+        let symbols: String = [
+            "╔", "═", "║", "╭", "┤", "▀", "▕", "⠀", "⠃", "⣷", "◀", "▲", "•", "☐", "✔", "é",
+        ]
+        .into_iter()
+        .map(AsciiFallback::symbol)
+        .collect();
+        assert_eq!(symbols, "+=|++## .:<^*ox?");
+        let mut cell = Cell::default();
+        cell.set_symbol("日").set_style(Style::new().red());
+        AsciiFallback.apply(Position::new(0, 0), &mut cell);
+        assert_eq!(cell.symbol(), "?");
+        assert_eq!(cell.fg, Color::Red);
+    }
+}
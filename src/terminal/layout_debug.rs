@@ -0,0 +1,109 @@
+use crate::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+};
+
+/// A debug overlay showing the gaps and the overlaps of the layout of each frame.
+///
+/// Once enabled with [`Terminal::set_layout_debug`], the areas given to
+/// [`Frame::render_widget`] and [`Frame::render_stateful_widget`] are recorded during each
+/// frame. After the frame is rendered, the cells outside of all these areas, which no widget was
+/// asked to draw, are patched with the [`gap_style`](Self::gap_style), and the cells inside
+/// several of them, which several widgets draw over, with the
+/// [`overlap_style`](Self::overlap_style). The widgets rendered by other widgets don't count, as
+/// their parent decides where they go.
+///
+/// A popup rendered over the rest of the screen is an overlap too, so the overlay is meant to be
+/// turned on while looking for a layout bug, e.g. from a debug key binding.
+///
+/// [`Terminal::set_layout_debug`]: crate::Terminal::set_layout_debug
+/// [`Frame::render_widget`]: crate::Frame::render_widget
+/// [`Frame::render_stateful_widget`]: crate::Frame::render_stateful_widget
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{backend::TestBackend, prelude::*, terminal::LayoutDebug, widgets::*};
+///
+/// let mut terminal = Terminal::new(TestBackend::new(6, 1))?;
+/// terminal.set_layout_debug(Some(LayoutDebug::default()));
+/// terminal.draw(|frame| {
+///     frame.render_widget(Paragraph::new("ab"), Rect::new(0, 0, 2, 1));
+///     frame.render_widget(Paragraph::new("cd"), Rect::new(1, 0, 2, 1));
+/// })?;
+/// let buffer = terminal.backend().buffer();
+/// assert_eq!(buffer.get(1, 0).bg, Color::Red);
+/// assert_eq!(buffer.get(4, 0).bg, Color::Magenta);
+/// # std::io::Result::Ok(())
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct LayoutDebug {
+    gap_style: Style,
+    overlap_style: Style,
+}
+
+impl Default for LayoutDebug {
+    fn default() -> Self {
+        Self {
+            gap_style: Style::new().bg(Color::Magenta),
+            overlap_style: Style::new().bg(Color::Red),
+        }
+    }
+}
+
+impl LayoutDebug {
+    /// Sets the style patched over the cells outside of all the rendered areas. Defaults to a
+    /// magenta background.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn gap_style(mut self, style: Style) -> Self {
+        self.gap_style = style;
+        self
+    }
+
+    /// Sets the style patched over the cells inside several rendered areas. Defaults to a red
+    /// background.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn overlap_style(mut self, style: Style) -> Self {
+        self.overlap_style = style;
+        self
+    }
+
+    /// Patches the gaps and the overlaps of the given rendered areas in the buffer.
+    pub(super) fn apply(&self, areas: &[Rect], buf: &mut Buffer) {
+        let mut counts = vec![0u8; buf.content.len()];
+        for area in areas {
+            let area = area.intersection(buf.area);
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    let count = &mut counts[buf.index_of(x, y)];
+                    *count = count.saturating_add(1);
+                }
+            }
+        }
+        for (cell, count) in buf.content.iter_mut().zip(counts) {
+            match count {
+                0 => cell.set_style(self.gap_style),
+                1 => cell,
+                _ => cell.set_style(self.overlap_style),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marks_gaps_and_overlaps() {
+        let mut buf = Buffer::with_lines(vec!["abcd", "efgh"]);
+        let debug = LayoutDebug::default().overlap_style(Style::new().bg(Color::Yellow));
+        // the area outside of the buffer is ignored
+        debug.apply(&[Rect::new(0, 0, 3, 1), Rect::new(2, 0, 4, 4)], &mut buf);
+        let mut expected = Buffer::with_lines(vec!["abcd", "efgh"]);
+        expected.set_style(Rect::new(0, 1, 2, 1), Style::new().bg(Color::Magenta));
+        expected.set_style(Rect::new(2, 0, 1, 1), Style::new().bg(Color::Yellow));
+        assert_eq!(buf, expected);
+    }
+}
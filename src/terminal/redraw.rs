@@ -0,0 +1,233 @@
+use std::{
+    cell::Cell,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    backend::Backend,
+    terminal::{Frame, Terminal},
+    Result,
+};
+
+thread_local! {
+    static PENDING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Requests to redraw the terminal because the state of the application changed.
+///
+/// The widget states request a redraw when they are changed, e.g. [`ListState::select`] selecting
+/// another item or a [`TextInputState`] handling a key. Changes that the widgets can't see, e.g.
+/// in the data they display or in the size of the terminal, are requested with
+/// [`RedrawRequest::request`].
+///
+/// The accessors returning a mutable reference, e.g. [`ListState::selected_mut`], can't see
+/// whether the value is changed through it, so they request a redraw on every call. Reading the
+/// state through them in the event loop redraws on every iteration: use the getters and the
+/// setters, which only request a redraw on a change, instead.
+///
+/// The requests are recorded for the current thread and cleared by [`Terminal::draw`] once the
+/// frame is rendered, so that the changes made by the widgets while rendering don't request
+/// another redraw. A [`RedrawScheduler`] reads them to only draw when something changed.
+///
+/// [`ListState::select`]: crate::widgets::ListState::select
+/// [`ListState::selected_mut`]: crate::widgets::ListState::selected_mut
+/// [`TextInputState`]: crate::widgets::TextInputState
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct RedrawRequest;
+
+impl RedrawRequest {
+    /// Requests to redraw the terminal on this thread.
+    pub fn request() {
+        PENDING.with(|pending| pending.set(true));
+    }
+
+    /// Returns `true` if a redraw was requested on this thread since the last draw.
+    pub fn is_pending() -> bool {
+        PENDING.with(Cell::get)
+    }
+
+    /// Requests a redraw if `changed` is `true`, and returns `changed`.
+    pub(crate) fn request_if(changed: bool) -> bool {
+        if changed {
+            RedrawRequest::request();
+        }
+        changed
+    }
+
+    /// Clears the requests made on this thread.
+    pub(crate) fn clear() {
+        PENDING.with(|pending| pending.set(false));
+    }
+}
+
+/// Draws the terminal only when a redraw was requested or a tick elapsed.
+///
+/// Applications redrawing on every iteration of their event loop use CPU time even when nothing
+/// changes. With a scheduler, the loop waits for the next event until the
+/// [`deadline`](Self::deadline) and [`draw`](Self::draw) skips the frames where nothing was
+/// requested with [`RedrawRequest`]. The [`tick_rate`](Self::tick_rate) still redraws regularly,
+/// e.g. for clocks or animations; without it the application is idle between two events.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::{Duration, Instant};
+///
+/// use ratatui::{
+///     input::{self, Event},
+///     prelude::*,
+///     terminal::{RedrawRequest, RedrawScheduler},
+///     widgets::*,
+/// };
+///
+/// # fn ui(frame: &mut Frame, state: &mut ListState) {}
+/// # let mut terminal = Terminal::new(CrosstermBackend::new(std::io::stdout()))?;
+/// let mut state = ListState::default();
+/// let mut scheduler = RedrawScheduler::new().tick_rate(Duration::from_secs(1));
+/// loop {
+///     scheduler.draw(&mut terminal, |frame| ui(frame, &mut state))?;
+///     let event_ready = match scheduler.deadline() {
+///         Some(deadline) => input::poll(deadline.saturating_duration_since(Instant::now()))?,
+///         None => true,
+///     };
+///     if event_ready {
+///         match input::read()? {
+///             // selecting an item requests a redraw
+///             Event::Key(_) => state.select(Some(0)),
+///             Event::Resize(_) => RedrawRequest::request(),
+///             _ => {}
+///         }
+///     }
+/// }
+/// # std::io::Result::Ok(())
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct RedrawScheduler {
+    tick_rate: Option<Duration>,
+    last_draw: Option<Instant>,
+}
+
+impl RedrawScheduler {
+    /// Creates a new scheduler drawing only when a redraw is requested.
+    pub const fn new() -> Self {
+        Self {
+            tick_rate: None,
+            last_draw: None,
+        }
+    }
+
+    /// Also redraws once the given duration elapsed since the last draw.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn tick_rate(mut self, tick_rate: Duration) -> Self {
+        self.tick_rate = Some(tick_rate);
+        self
+    }
+
+    /// Returns the instant of the next tick, if the scheduler has a tick rate.
+    ///
+    /// This is the instant until which the application can wait for events. It is now when the
+    /// terminal was never drawn or a redraw is pending.
+    pub fn deadline(&self) -> Option<Instant> {
+        if RedrawRequest::is_pending() {
+            return Some(Instant::now());
+        }
+        match (self.last_draw, self.tick_rate) {
+            (None, _) => Some(Instant::now()),
+            (Some(last_draw), Some(tick_rate)) => Some(last_draw + tick_rate),
+            (Some(_), None) => None,
+        }
+    }
+
+    /// Returns `true` if the terminal should be drawn at the given instant: it was never drawn, a
+    /// redraw was requested or a tick elapsed.
+    pub fn should_draw(&self, now: Instant) -> bool {
+        let tick_elapsed = match (self.last_draw, self.tick_rate) {
+            (None, _) => true,
+            (Some(last_draw), Some(tick_rate)) => now >= last_draw + tick_rate,
+            (Some(_), None) => false,
+        };
+        tick_elapsed || RedrawRequest::is_pending()
+    }
+
+    /// Draws the terminal with [`Terminal::draw`] if it should be drawn now. Returns `true` if it
+    /// was drawn.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`Terminal::draw`] if the terminal fails to be drawn.
+    pub fn draw<B, F>(&mut self, terminal: &mut Terminal<B>, f: F) -> Result<bool>
+    where
+        B: Backend,
+        F: FnOnce(&mut Frame),
+    {
+        self.draw_at(terminal, Instant::now(), f)
+    }
+
+    /// Draws the terminal with [`Terminal::draw`] if it should be drawn at the given instant.
+    /// Returns `true` if it was drawn.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`Terminal::draw`] if the terminal fails to be drawn.
+    pub fn draw_at<B, F>(&mut self, terminal: &mut Terminal<B>, now: Instant, f: F) -> Result<bool>
+    where
+        B: Backend,
+        F: FnOnce(&mut Frame),
+    {
+        if !self.should_draw(now) {
+            return Ok(false);
+        }
+        terminal.draw(f)?;
+        self.last_draw = Some(now);
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{backend::TestBackend, widgets::ListState};
+
+    #[test]
+    fn request() {
+        let mut terminal = Terminal::new(TestBackend::new(1, 1)).unwrap();
+        RedrawRequest::request();
+        assert!(RedrawRequest::is_pending());
+        terminal.draw(|_| {}).unwrap();
+        assert!(!RedrawRequest::is_pending());
+        // requests made while rendering are cleared too
+        terminal.draw(|_| RedrawRequest::request()).unwrap();
+        assert!(!RedrawRequest::is_pending());
+    }
+
+    #[test]
+    fn schedule_requests() {
+        let mut terminal = Terminal::new(TestBackend::new(1, 1)).unwrap();
+        let mut scheduler = RedrawScheduler::new();
+        let now = Instant::now();
+        assert!(scheduler.draw_at(&mut terminal, now, |_| {}).unwrap());
+        assert_eq!(scheduler.deadline(), None);
+        let later = now + Duration::from_secs(60);
+        assert!(!scheduler.draw_at(&mut terminal, later, |_| {}).unwrap());
+
+        let mut state = ListState::default();
+        state.select(None);
+        assert!(!scheduler.should_draw(later));
+        state.select(Some(1));
+        assert!(scheduler.draw_at(&mut terminal, later, |_| {}).unwrap());
+        assert!(!scheduler.should_draw(later));
+    }
+
+    #[test]
+    fn schedule_ticks() {
+        let mut terminal = Terminal::new(TestBackend::new(1, 1)).unwrap();
+        let tick_rate = Duration::from_millis(100);
+        let mut scheduler = RedrawScheduler::new().tick_rate(tick_rate);
+        let now = Instant::now();
+        assert!(scheduler.should_draw(now));
+        assert!(scheduler.draw_at(&mut terminal, now, |_| {}).unwrap());
+        assert_eq!(scheduler.deadline(), Some(now + tick_rate));
+        assert!(!scheduler.should_draw(now + tick_rate / 2));
+        assert!(scheduler.should_draw(now + tick_rate));
+    }
+}
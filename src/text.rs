@@ -47,8 +47,11 @@
 
 use crate::style::Style;
 
+mod element;
+pub use element::{Badge, InlineGauge, InlineSparkline, LineElement};
+
 mod grapheme;
-pub use grapheme::StyledGrapheme;
+pub use grapheme::{grapheme_width, str_width, StyledGrapheme};
 
 mod line;
 pub use line::Line;
@@ -56,6 +59,10 @@ pub use line::Line;
 mod masked;
 pub use masked::Masked;
 
+mod mnemonic;
+pub(crate) use mnemonic::is_accelerator_key;
+pub use mnemonic::Mnemonic;
+
 mod span;
 pub use span::Span;
 
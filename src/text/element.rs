@@ -0,0 +1,316 @@
+#![deny(missing_docs)]
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::{grapheme_width, Span};
+use crate::{style::Style, symbols};
+
+/// A no-break space, which doesn't let the text be wrapped inside an element.
+const NBSP: char = '\u{00a0}';
+
+/// A fixed-width element drawn inline in a [`Line`], e.g. a tiny gauge or a sparkline.
+///
+/// An element is added to a line with [`Line::element`], which converts it into spans exactly
+/// [`width`](Self::width) columns wide, so that status lines can be composed declaratively:
+///
+/// ```rust
+/// use ratatui::{prelude::*, text::InlineGauge};
+///
+/// let line = Line::from("CPU ").element(&InlineGauge::new(0.62, 5));
+/// assert_eq!(String::from(line), "CPU ▓▓▓░░");
+/// ```
+///
+/// The spaces of an element are replaced by no-break spaces, so that a wrapped paragraph moves
+/// an element to the next line as a whole instead of splitting it, like a word.
+///
+/// [`Line`]: super::Line
+/// [`Line::element`]: super::Line::element
+pub trait LineElement {
+    /// Returns the number of columns of the element.
+    fn width(&self) -> u16;
+
+    /// Returns the spans drawing the element. They are truncated or padded with spaces to the
+    /// [`width`](Self::width) of the element when it is added to a line.
+    fn spans(&self) -> Vec<Span<'static>>;
+}
+
+/// Converts an element into spans of exactly its width, with no-break spaces.
+pub(super) fn element_spans<E: LineElement + ?Sized>(element: &E) -> Vec<Span<'static>> {
+    let mut remaining = usize::from(element.width());
+    let mut spans = vec![];
+    let mut truncated = false;
+    for span in element.spans() {
+        let mut content = String::new();
+        for grapheme in span.content.graphemes(true) {
+            let width = grapheme_width(grapheme);
+            truncated |= width > remaining;
+            if truncated {
+                break;
+            }
+            remaining -= width;
+            content.extend(grapheme.chars().map(|c| if c == ' ' { NBSP } else { c }));
+        }
+        if !content.is_empty() {
+            spans.push(Span::styled(content, span.style));
+        }
+    }
+    if remaining > 0 {
+        spans.push(Span::raw(NBSP.to_string().repeat(remaining)));
+    }
+    spans
+}
+
+/// A tiny gauge made of filled and empty cells, e.g. `▓▓▓░░` for 62% on 5 cells.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, text::InlineGauge};
+///
+/// let gauge = InlineGauge::new(0.5, 4)
+///     .symbols("#", "-")
+///     .filled_style(Style::new().green());
+/// assert_eq!(String::from(Line::default().element(&gauge)), "##--");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InlineGauge {
+    /// Between 0 and 1, never NaN
+    ratio: f64,
+    width: u16,
+    filled: &'static str,
+    empty: &'static str,
+    filled_style: Style,
+    empty_style: Style,
+}
+
+impl InlineGauge {
+    /// Creates a gauge of `width` cells filled with the given ratio, clamped between 0 and 1. A
+    /// NaN ratio shows an empty gauge.
+    pub fn new(ratio: f64, width: u16) -> Self {
+        Self {
+            ratio: if ratio.is_nan() {
+                0.0
+            } else {
+                ratio.clamp(0.0, 1.0)
+            },
+            width,
+            filled: symbols::shade::DARK,
+            empty: symbols::shade::LIGHT,
+            filled_style: Style::new(),
+            empty_style: Style::new(),
+        }
+    }
+
+    /// Sets the symbols of the filled and empty cells, one column wide. Defaults to `▓` and `░`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn symbols(mut self, filled: &'static str, empty: &'static str) -> Self {
+        self.filled = filled;
+        self.empty = empty;
+        self
+    }
+
+    /// Sets the style of the filled cells.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn filled_style(mut self, style: Style) -> Self {
+        self.filled_style = style;
+        self
+    }
+
+    /// Sets the style of the empty cells.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn empty_style(mut self, style: Style) -> Self {
+        self.empty_style = style;
+        self
+    }
+}
+
+impl LineElement for InlineGauge {
+    fn width(&self) -> u16 {
+        self.width
+    }
+
+    fn spans(&self) -> Vec<Span<'static>> {
+        let filled = (f64::from(self.width) * self.ratio).round() as usize;
+        let empty = usize::from(self.width) - filled;
+        vec![
+            Span::styled(self.filled.repeat(filled), self.filled_style),
+            Span::styled(self.empty.repeat(empty), self.empty_style),
+        ]
+    }
+}
+
+/// A sparkline of one bar per cell, e.g. `▂▄▆` for a short history of measures.
+///
+/// The bars are scaled to the maximum of the data, or to the [`max`](Self::max) when it is set.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, text::InlineSparkline};
+///
+/// let sparkline = InlineSparkline::new([1, 2, 3]).max(4).width(4);
+/// assert_eq!(String::from(Line::default().element(&sparkline)), "\u{a0}▂▄▆");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct InlineSparkline {
+    data: Vec<u64>,
+    max: Option<u64>,
+    width: Option<u16>,
+    style: Style,
+}
+
+impl InlineSparkline {
+    /// Creates a sparkline of the given data, one cell wide per value.
+    pub fn new(data: impl Into<Vec<u64>>) -> Self {
+        Self {
+            data: data.into(),
+            max: None,
+            width: None,
+            style: Style::new(),
+        }
+    }
+
+    /// Sets the value of a full bar. Defaults to the maximum of the data.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn max(mut self, max: u64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the number of cells of the sparkline, which shows the last values of the data,
+    /// aligned to the right. Defaults to the number of values.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets the style of the bars.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl LineElement for InlineSparkline {
+    fn width(&self) -> u16 {
+        self.width
+            .unwrap_or_else(|| u16::try_from(self.data.len()).unwrap_or(u16::MAX))
+    }
+
+    fn spans(&self) -> Vec<Span<'static>> {
+        let width = usize::from(LineElement::width(self));
+        let shown = &self.data[self.data.len().saturating_sub(width)..];
+        let max = self
+            .max
+            .unwrap_or_else(|| shown.iter().copied().max().unwrap_or(0))
+            .max(1);
+        let set = symbols::bar::NINE_LEVELS;
+        let bars: String = shown
+            .iter()
+            .map(|&value| {
+                let eighths = u128::from(value.min(max)) * 8 / u128::from(max);
+                match eighths {
+                    0 => set.empty,
+                    1 => set.one_eighth,
+                    2 => set.one_quarter,
+                    3 => set.three_eighths,
+                    4 => set.half,
+                    5 => set.five_eighths,
+                    6 => set.three_quarters,
+                    7 => set.seven_eighths,
+                    _ => set.full,
+                }
+            })
+            .collect();
+        vec![
+            Span::raw(" ".repeat(width - shown.len())),
+            Span::styled(bars, self.style),
+        ]
+    }
+}
+
+/// A short label padded with a space on each side, e.g. ` OK ` drawn on a green background.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, text::Badge};
+///
+/// let badge = Badge::new("OK").style(Style::new().black().on_green());
+/// let line = Line::from("Build ").element(&badge);
+/// assert_eq!(line.width(), 10);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Badge {
+    text: String,
+    style: Style,
+}
+
+impl Badge {
+    /// Creates a badge showing the given text.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            style: Style::new(),
+        }
+    }
+
+    /// Sets the style of the badge, including its padding.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl LineElement for Badge {
+    fn width(&self) -> u16 {
+        u16::try_from(super::str_width(&self.text) + 2).unwrap_or(u16::MAX)
+    }
+
+    fn spans(&self) -> Vec<Span<'static>> {
+        vec![Span::styled(format!(" {} ", self.text), self.style)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        buffer::Buffer,
+        layout::Rect,
+        style::Stylize,
+        text::Line,
+        widgets::{Paragraph, Widget, Wrap},
+    };
+
+    #[test]
+    fn elements_have_fixed_widths() {
+        let gauge = InlineGauge::new(f64::NAN, 3).symbols("#", "全");
+        // the spans are truncated to the width, and padded when a wide symbol doesn't fit
+        assert_eq!(String::from(Line::default().element(&gauge)), "全\u{a0}");
+        let sparkline = InlineSparkline::new([0, 4, 8, 2]).width(3);
+        assert_eq!(String::from(Line::default().element(&sparkline)), "▄█▂");
+        let line = Line::from("CPU ")
+            .element(&InlineSparkline::new([2, 4, 6]).max(8))
+            .element(&Badge::new("62%").style(Style::new().red()));
+        assert_eq!(String::from(line.clone()), "CPU ▂▄▆\u{a0}62%\u{a0}");
+        assert_eq!(line.spans[2].style, Style::new().red());
+    }
+
+    #[test]
+    fn elements_are_wrapped_as_words() {
+        let line = Line::from("Build ")
+            .element(&Badge::new("OK"))
+            .element(&InlineGauge::new(1.0, 3));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+        Paragraph::new(line)
+            .wrap(Wrap { trim: true })
+            .render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(vec!["Build   ", "\u{a0}OK\u{a0}▓▓▓ "])
+        );
+    }
+}
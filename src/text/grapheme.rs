@@ -1,5 +1,71 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
 use crate::style::{Style, Styled};
 
+/// Returns the number of columns taken on screen by a grapheme.
+///
+/// This is the width used by the [`Buffer`] and the input widgets, so that the cursor of a
+/// [`TextInput`] or a [`TextArea`] stays aligned with the text they render. Wide characters take
+/// two columns and combining marks none.
+///
+/// With the `emoji` feature, emoji sequences take two columns whatever the version of the
+/// Unicode width tables: sequences joined with a zero width joiner (e.g. `👩‍💻`), emoji with a
+/// skin tone modifier, flags, keycaps and characters followed by the emoji presentation selector
+/// `U+FE0F` (e.g. `❤️`). A character followed by the text presentation selector `U+FE0E` takes a
+/// single column.
+///
+/// [`Buffer`]: crate::buffer::Buffer
+/// [`TextInput`]: crate::widgets::TextInput
+/// [`TextArea`]: crate::widgets::TextArea
+pub fn grapheme_width(grapheme: &str) -> usize {
+    #[cfg(feature = "emoji")]
+    if let Some(width) = emoji_width(grapheme) {
+        return width;
+    }
+    grapheme.width()
+}
+
+/// Returns the number of columns taken on screen by a string, the sum of the
+/// [`grapheme_width`] of its graphemes.
+pub fn str_width(s: &str) -> usize {
+    s.graphemes(true).map(grapheme_width).sum()
+}
+
+/// Returns the width of an emoji presentation sequence, or `None` if the grapheme is not one.
+#[cfg(feature = "emoji")]
+fn emoji_width(grapheme: &str) -> Option<usize> {
+    const ZWJ: char = '\u{200D}';
+    const TEXT_PRESENTATION: char = '\u{FE0E}';
+    const EMOJI_PRESENTATION: char = '\u{FE0F}';
+    const KEYCAP: char = '\u{20E3}';
+
+    let mut chars = grapheme.chars();
+    let first = chars.next()?;
+    chars.next()?;
+    if grapheme.contains(TEXT_PRESENTATION) {
+        return Some(1);
+    }
+    let regional_indicator = |c| ('\u{1F1E6}'..='\u{1F1FF}').contains(&c);
+    let skin_tone = |c| ('\u{1F3FB}'..='\u{1F3FF}').contains(&c);
+    let emoji_sequence = grapheme.contains(EMOJI_PRESENTATION)
+        || grapheme.contains(KEYCAP)
+        || regional_indicator(first)
+        // zero width joiners also shape the letters of some scripts, only join pictographs
+        || (is_pictograph(first) && (grapheme.contains(ZWJ) || grapheme.chars().any(skin_tone)));
+    emoji_sequence.then_some(2)
+}
+
+/// Returns `true` for the characters of the blocks holding most emoji.
+#[cfg(feature = "emoji")]
+fn is_pictograph(c: char) -> bool {
+    matches!(c,
+        '\u{2300}'..='\u{23FF}'
+        | '\u{2600}'..='\u{27BF}'
+        | '\u{2B00}'..='\u{2BFF}'
+        | '\u{1F000}'..='\u{1FAFF}')
+}
+
 /// A grapheme associated to a style.
 /// Note that, although `StyledGrapheme` is the smallest divisible unit of text,
 /// it actually is not a member of the text type hierarchy (`Text` -> `Line` -> `Span`).
@@ -35,6 +101,28 @@ mod tests {
     use super::*;
     use crate::prelude::*;
 
+    #[test]
+    fn width() {
+        assert_eq!(grapheme_width("a"), 1);
+        assert_eq!(grapheme_width("界"), 2);
+        assert_eq!(grapheme_width("e\u{301}"), 1);
+        assert_eq!(str_width("a界e\u{301}"), 4);
+    }
+
+    #[cfg(feature = "emoji")]
+    #[test]
+    fn emoji_width() {
+        assert_eq!(grapheme_width("👨\u{200D}👩\u{200D}👧"), 2);
+        assert_eq!(grapheme_width("👍🏽"), 2);
+        assert_eq!(grapheme_width("🇫🇷"), 2);
+        assert_eq!(grapheme_width("1\u{FE0F}\u{20E3}"), 2);
+        assert_eq!(grapheme_width("❤\u{FE0F}"), 2);
+        assert_eq!(grapheme_width("✔\u{FE0E}"), 1);
+        // joiners between letters are not emoji sequences
+        assert_eq!(grapheme_width("\u{915}\u{94D}\u{200D}"), 1);
+        assert_eq!(str_width("a👩\u{200D}💻b"), 4);
+    }
+
     #[test]
     fn new() {
         let style = Style::new().yellow();
@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use super::{Span, Style, StyledGrapheme};
+use super::{element::element_spans, LineElement, Span, Style, StyledGrapheme};
 use crate::layout::Alignment;
 
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
@@ -170,6 +170,28 @@ impl<'a> Line<'a> {
             ..self
         }
     }
+
+    /// Appends an inline element, e.g. a tiny gauge, to the line.
+    ///
+    /// See [`LineElement`] for more information.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, text::InlineSparkline};
+    /// let line = Line::from("Load ").element(&InlineSparkline::new([1, 3, 5, 7]));
+    /// assert_eq!(9, line.width());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn element<E: LineElement + ?Sized>(mut self, element: &E) -> Self {
+        self.push_element(element);
+        self
+    }
+
+    /// Appends an inline element to an existing line, see [`Line::element`].
+    pub fn push_element<E: LineElement + ?Sized>(&mut self, element: &E) {
+        self.spans.extend(element_spans(element));
+    }
 }
 
 impl<'a> From<String> for Line<'a> {
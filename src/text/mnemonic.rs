@@ -0,0 +1,198 @@
+use std::{borrow::Cow, ops::Range};
+
+use crate::{
+    input::{Key, KeyEvent, KeyModifiers},
+    style::Style,
+    text::{Line, Span},
+};
+
+/// A label marking its keyboard accelerator with an ampersand, e.g. `&File` or `Save &As`.
+///
+/// The character following the first `&` is the accelerator of the label: it is rendered with an
+/// accelerator style (e.g. underlined) and activates the labeled widget when typed with `Alt`.
+/// `&&` stands for a literal ampersand. The other `&` are kept as is: the ones which aren't
+/// followed by a letter or a digit (e.g. in `Save & Quit`) and the ones after the accelerator.
+///
+/// [`Button`] labels are parsed as mnemonics.
+///
+/// [`Button`]: crate::widgets::Button
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     input::{Key, KeyEvent, KeyModifiers},
+///     prelude::*,
+///     text::Mnemonic,
+/// };
+///
+/// let mnemonic = Mnemonic::parse("Save &As");
+/// assert_eq!(mnemonic.label(), "Save As");
+/// assert_eq!(mnemonic.accelerator(), Some('a'));
+/// assert!(mnemonic.matches(KeyEvent::new(Key::Char('a'), KeyModifiers::ALT)));
+///
+/// let line = mnemonic.to_line(Style::new(), Style::new().underlined());
+/// assert_eq!(line.spans[1], Span::styled("A", Style::new().underlined()));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Mnemonic<'a> {
+    label: Cow<'a, str>,
+    /// The byte range of the accelerator in the label
+    accelerator: Option<Range<usize>>,
+}
+
+impl<'a> Mnemonic<'a> {
+    /// Parses a label marking its accelerator with `&`.
+    pub fn parse<T>(label: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let label = label.into();
+        if !label.contains('&') {
+            return Self {
+                label,
+                accelerator: None,
+            };
+        }
+        let mut parsed = String::with_capacity(label.len());
+        let mut accelerator = None;
+        let mut chars = label.chars().peekable();
+        while let Some(c) = chars.next() {
+            match (c, chars.peek()) {
+                ('&', Some('&')) => {
+                    chars.next();
+                    parsed.push('&');
+                }
+                ('&', Some(&next)) if next.is_alphanumeric() && accelerator.is_none() => {
+                    chars.next();
+                    accelerator = Some(parsed.len()..parsed.len() + next.len_utf8());
+                    parsed.push(next);
+                }
+                _ => parsed.push(c),
+            }
+        }
+        Self {
+            label: Cow::Owned(parsed),
+            accelerator,
+        }
+    }
+
+    /// Returns the label without the `&` markers.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the accelerator of the label in lowercase, if it has one.
+    pub fn accelerator(&self) -> Option<char> {
+        let range = self.accelerator.clone()?;
+        self.label[range]
+            .chars()
+            .flat_map(char::to_lowercase)
+            .next()
+    }
+
+    /// Returns the key event activating the label: its accelerator typed with `Alt`.
+    pub fn key_event(&self) -> Option<KeyEvent> {
+        let accelerator = self.accelerator()?;
+        Some(KeyEvent::new(Key::Char(accelerator), KeyModifiers::ALT))
+    }
+
+    /// Returns `true` if the key event is the accelerator of the label typed with `Alt`, in any
+    /// case.
+    pub fn matches(&self, key: KeyEvent) -> bool {
+        self.accelerator()
+            .is_some_and(|accelerator| is_accelerator_key(accelerator, key))
+    }
+
+    /// Returns the label as a line, with the accelerator style patched over the style of the
+    /// accelerator.
+    pub fn to_line(&self, style: Style, accelerator_style: Style) -> Line<'a> {
+        let Some(range) = self.accelerator.clone() else {
+            return Line::from(Span::styled(self.label.clone(), style));
+        };
+        let spans = [
+            (0..range.start, style),
+            (range.clone(), style.patch(accelerator_style)),
+            (range.end..self.label.len(), style),
+        ];
+        Line::from(
+            spans
+                .into_iter()
+                .filter(|(range, _)| !range.is_empty())
+                .map(|(range, style)| Span::styled(self.slice(range), style))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn slice(&self, range: Range<usize>) -> Cow<'a, str> {
+        match &self.label {
+            Cow::Borrowed(label) => Cow::Borrowed(&label[range]),
+            Cow::Owned(label) => Cow::Owned(label[range].to_string()),
+        }
+    }
+}
+
+/// Returns `true` if the key event is the given lowercase accelerator typed with `Alt`.
+pub(crate) fn is_accelerator_key(accelerator: char, key: KeyEvent) -> bool {
+    match key.key {
+        Key::Char(c) if key.modifiers.contains(KeyModifiers::ALT) => {
+            c.to_lowercase().eq(accelerator.to_lowercase())
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Stylize;
+
+    #[test]
+    fn parse() {
+        let mnemonic = Mnemonic::parse("&File");
+        assert_eq!(
+            (mnemonic.label(), mnemonic.accelerator()),
+            ("File", Some('f'))
+        );
+        let mnemonic = Mnemonic::parse("Save && E&xit &Now");
+        assert_eq!(
+            (mnemonic.label(), mnemonic.accelerator()),
+            ("Save & Exit &Now", Some('x'))
+        );
+        let mnemonic = Mnemonic::parse("Save & Quit&");
+        assert_eq!(
+            (mnemonic.label(), mnemonic.accelerator()),
+            ("Save & Quit&", None)
+        );
+        assert_eq!(Mnemonic::parse("Open").label, Cow::Borrowed("Open"));
+    }
+
+    #[test]
+    fn matches() {
+        let mnemonic = Mnemonic::parse("&Open");
+        assert_eq!(
+            mnemonic.key_event(),
+            Some(KeyEvent::new(Key::Char('o'), KeyModifiers::ALT))
+        );
+        assert!(mnemonic.matches(KeyEvent::new(Key::Char('o'), KeyModifiers::ALT)));
+        let shift_alt = KeyModifiers::ALT | KeyModifiers::SHIFT;
+        assert!(mnemonic.matches(KeyEvent::new(Key::Char('O'), shift_alt)));
+        assert!(!mnemonic.matches(KeyEvent::from(Key::Char('o'))));
+        assert!(!Mnemonic::parse("Open").matches(KeyEvent::new(Key::Char('o'), KeyModifiers::ALT)));
+    }
+
+    #[test]
+    fn to_line() {
+        let line = Mnemonic::parse("E&xit").to_line(Style::new().red(), Style::new().underlined());
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::styled("E", Style::new().red()),
+                Span::styled("x", Style::new().red().underlined()),
+                Span::styled("it", Style::new().red()),
+            ])
+        );
+        let line = Mnemonic::parse("Exit").to_line(Style::new(), Style::new().underlined());
+        assert_eq!(line, Line::from("Exit"));
+    }
+}
@@ -1,9 +1,26 @@
 //! This module holds the [`Title`] element and its related configuration types.
 //! A title is a piece of [`Block`](crate::widgets::Block) configuration.
+//!
+//! Besides text, a title can show a small widget implementing [`TitleContent`] on the border
+//! row, e.g. a [`TitleGauge`], a [`Spinner`] or a [`Badge`].
+
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
 
 use strum::{Display, EnumString};
+use unicode_width::UnicodeWidthStr;
 
-use crate::{layout::Alignment, text::Line};
+use crate::{
+    backend::Capabilities,
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    symbols::{line, spinner},
+    text::Line,
+};
 
 /// A [`Block`](crate::widgets::Block) title.
 ///
@@ -43,7 +60,14 @@ use crate::{layout::Alignment, text::Line};
 ///     .position(Position::Top)
 ///     .alignment(Alignment::Right);
 /// ```
-#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+///
+/// Title followed by a progress bar (see [`TitleContent`]).
+/// ```
+/// use ratatui::widgets::block::*;
+///
+/// Title::from("Download").widget(TitleGauge::new(0.4).width(10));
+/// ```
+#[derive(Default, Clone)]
 pub struct Title<'a> {
     /// Title content
     pub content: Line<'a>,
@@ -60,6 +84,226 @@ pub struct Title<'a> {
     /// [`Block::title_position`](crate::widgets::Block::title_position) in the associated
     /// [`Block`](crate::widgets::Block).
     pub position: Option<Position>,
+
+    /// Widget rendered after the title content, separated by a space if there is content
+    pub widget: Option<Rc<dyn TitleContent + 'a>>,
+}
+
+/// A small widget rendered in a [`Title`], on the border row of a
+/// [`Block`](crate::widgets::Block).
+///
+/// The block reserves [`width`](TitleContent::width) columns for the widget, and renders it in
+/// an area of one row that may be narrower if the block is too small.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{buffer::Buffer, layout::Rect, prelude::*, widgets::block::*};
+///
+/// /// Shows a dot for each unread message.
+/// struct Unread(u16);
+///
+/// impl TitleContent for Unread {
+///     fn width(&self) -> u16 {
+///         self.0
+///     }
+///
+///     fn render(&self, area: Rect, buf: &mut Buffer) {
+///         buf.set_string(area.x, area.y, "•".repeat(area.width.into()), Style::new().red());
+///     }
+/// }
+///
+/// let title = Title::from("Inbox").widget(Unread(3));
+/// ```
+pub trait TitleContent {
+    /// Returns the number of columns taken by the widget.
+    fn width(&self) -> u16;
+
+    /// Draws the widget in the given area of one row.
+    fn render(&self, area: Rect, buf: &mut Buffer);
+}
+
+/// A [`TitleContent`] showing a progress bar on the border, followed by the percentage.
+///
+/// The bar is drawn with thick horizontal lines for the progress and thin ones for the rest, so
+/// that it blends with the border. It falls back to `#` and `-` when the terminal lacks box
+/// drawing characters (see [`Capabilities`]).
+///
+/// ```text
+/// ┌Download ━━━━────── 40%───┐
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TitleGauge {
+    ratio: f64,
+    width: u16,
+    filled_style: Style,
+    unfilled_style: Style,
+    show_percent: bool,
+}
+
+impl TitleGauge {
+    /// Creates a progress bar for the given ratio, clamped between 0 and 1, ten columns wide.
+    pub fn new(ratio: f64) -> TitleGauge {
+        TitleGauge {
+            ratio: ratio.clamp(0.0, 1.0),
+            width: 10,
+            filled_style: Style::new(),
+            unfilled_style: Style::new(),
+            show_percent: true,
+        }
+    }
+
+    /// Sets the width of the bar, without the percentage.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn width(mut self, width: u16) -> TitleGauge {
+        self.width = width;
+        self
+    }
+
+    /// Sets the style of the part of the bar showing the progress.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn filled_style(mut self, style: Style) -> TitleGauge {
+        self.filled_style = style;
+        self
+    }
+
+    /// Sets the style of the rest of the bar.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn unfilled_style(mut self, style: Style) -> TitleGauge {
+        self.unfilled_style = style;
+        self
+    }
+
+    /// Sets whether the percentage is shown after the bar. Defaults to `true`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn show_percent(mut self, show: bool) -> TitleGauge {
+        self.show_percent = show;
+        self
+    }
+}
+
+impl TitleContent for TitleGauge {
+    fn width(&self) -> u16 {
+        // the percentage is padded to keep the width stable as the progress changes
+        self.width + if self.show_percent { 5 } else { 0 }
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        let (filled, unfilled) = if Capabilities::current().box_drawing {
+            (line::THICK_HORIZONTAL, line::HORIZONTAL)
+        } else {
+            ("#", "-")
+        };
+        let bar_width = self.width.min(area.width);
+        let filled_width = (f64::from(self.width) * self.ratio).round() as u16;
+        for x in 0..bar_width {
+            let (symbol, style) = if x < filled_width {
+                (filled, self.filled_style)
+            } else {
+                (unfilled, self.unfilled_style)
+            };
+            buf.get_mut(area.x + x, area.y)
+                .set_symbol(symbol)
+                .set_style(style);
+        }
+        if self.show_percent && area.width > bar_width {
+            let percent = format!(" {:>3}%", (self.ratio * 100.0).round());
+            let width = usize::from(area.width - bar_width);
+            buf.set_stringn(area.x + bar_width, area.y, percent, width, Style::new());
+        }
+    }
+}
+
+/// A [`TitleContent`] showing one frame of a spinner animation, e.g. while something loads.
+///
+/// The animation is driven by the application, which passes an ever increasing tick, e.g. the
+/// number of frames drawn so far.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Spinner {
+    frames: &'static [&'static str],
+    tick: usize,
+    style: Style,
+}
+
+impl Spinner {
+    /// Creates a spinner showing the frame for the given tick, with the
+    /// [`BRAILLE`](spinner::BRAILLE) frames.
+    pub fn new(tick: usize) -> Spinner {
+        Spinner {
+            frames: spinner::BRAILLE,
+            tick,
+            style: Style::new(),
+        }
+    }
+
+    /// Sets the frames of the animation, see [`symbols::spinner`](crate::symbols::spinner).
+    ///
+    /// The frames should all be one column wide.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn frames(mut self, frames: &'static [&'static str]) -> Spinner {
+        self.frames = frames;
+        self
+    }
+
+    /// Sets the style of the spinner.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Spinner {
+        self.style = style;
+        self
+    }
+}
+
+impl TitleContent for Spinner {
+    fn width(&self) -> u16 {
+        1
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        if self.frames.is_empty() || area.is_empty() {
+            return;
+        }
+        let frame = self.frames[self.tick % self.frames.len()];
+        buf.set_stringn(area.x, area.y, frame, usize::from(area.width), self.style);
+    }
+}
+
+/// A [`TitleContent`] showing a short text padded with a space on each side, e.g. a count or a
+/// status. Defaults to reversed colors so that it stands out from the border.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Badge {
+    text: String,
+    style: Style,
+}
+
+impl Badge {
+    /// Creates a badge with the given text.
+    pub fn new<T>(text: T) -> Badge
+    where
+        T: Into<String>,
+    {
+        Badge {
+            text: text.into(),
+            style: Style::new().add_modifier(Modifier::REVERSED),
+        }
+    }
+
+    /// Sets the style of the badge.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Badge {
+        self.style = style;
+        self
+    }
+}
+
+impl TitleContent for Badge {
+    fn width(&self) -> u16 {
+        self.text.width() as u16 + 2
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        let text = format!(" {} ", self.text);
+        buf.set_stringn(area.x, area.y, text, usize::from(area.width), self.style);
+    }
 }
 
 /// Defines the [title](crate::widgets::block::Title) position.
@@ -108,6 +352,64 @@ impl<'a> Title<'a> {
         self.position = Some(position);
         self
     }
+
+    /// Set the widget rendered after the title content, see [`TitleContent`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn widget<W>(mut self, widget: W) -> Title<'a>
+    where
+        W: TitleContent + 'a,
+    {
+        self.widget = Some(Rc::new(widget));
+        self
+    }
+
+    /// Returns the number of columns taken by the content and the widget.
+    pub(crate) fn width(&self) -> u16 {
+        let content_width = self.content.width() as u16;
+        match &self.widget {
+            Some(widget) if content_width > 0 => content_width + 1 + widget.width(),
+            Some(widget) => widget.width(),
+            None => content_width,
+        }
+    }
+}
+
+impl<'a> fmt::Debug for Title<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Title")
+            .field("content", &self.content)
+            .field("alignment", &self.alignment)
+            .field("position", &self.position)
+            .field("widget", &self.widget.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+/// Titles with widgets are equal if they share the same widget.
+impl<'a> PartialEq for Title<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.content == other.content
+            && self.alignment == other.alignment
+            && self.position == other.position
+            && match (&self.widget, &other.widget) {
+                (Some(a), Some(b)) => Rc::as_ptr(a).cast::<()>() == Rc::as_ptr(b).cast::<()>(),
+                (a, b) => a.is_none() && b.is_none(),
+            }
+    }
+}
+
+impl<'a> Eq for Title<'a> {}
+
+impl<'a> Hash for Title<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.content.hash(state);
+        self.alignment.hash(state);
+        self.position.hash(state);
+        self.widget
+            .as_ref()
+            .map(|widget| Rc::as_ptr(widget).cast::<()>())
+            .hash(state);
+    }
 }
 
 impl<'a, T> From<T> for Title<'a>
@@ -137,4 +439,29 @@ mod tests {
         assert_eq!("Bottom".parse::<Position>(), Ok(Position::Bottom));
         assert_eq!("".parse::<Position>(), Err(ParseError::VariantNotFound));
     }
+
+    #[test]
+    fn width() {
+        assert_eq!(Title::from("abc").width(), 3);
+        assert_eq!(Title::from("abc").widget(Badge::new("12")).width(), 8);
+        assert_eq!(Title::default().widget(Spinner::new(0)).width(), 1);
+    }
+
+    #[test]
+    fn title_gauge() {
+        let gauge = TitleGauge::new(0.25).width(4);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 1));
+        gauge.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["━───  25%"]));
+
+        // without box drawing characters, and clipped
+        let capabilities = Capabilities::set_current(Capabilities {
+            box_drawing: false,
+            ..Capabilities::FULL
+        });
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        gauge.show_percent(false).render(buf.area, &mut buf);
+        drop(capabilities);
+        assert_eq!(buf, Buffer::with_lines(vec!["#---  "]));
+    }
 }
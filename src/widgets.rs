@@ -4,56 +4,160 @@
 //! They are not meant to be stored but used as *commands* to draw common figures in the UI.
 //!
 //! The available widgets are:
+//! - [`Autocomplete`]: a dropdown of fuzzy matched completions for a [`TextInput`].
 //! - [`Block`]: a basic widget that draws a block with optional borders, titles and styles.
 //! - [`BarChart`]: displays multiple datasets as bars with optional grouping.
+//! - [`Button`]: a clickable button, and [`ButtonRow`] to lay out several of them.
 //! - [`calendar::Monthly`]: displays a single month.
 //! - [`Canvas`]: draws arbitrary shapes using drawing characters.
+//! - [`Checkbox`]: a checkbox with a label, and [`RadioGroup`] to select one of several options.
 //! - [`Chart`]: displays multiple datasets as a lines or scatter graph.
+//! - [`Clock`]: displays an elapsed or remaining duration as `HH:MM:SS`.
+//! - [`ColorPicker`]: an input choosing an ANSI, indexed or RGB color, with a preview.
+//! - [`DatePicker`]: an input for a date edited segment by segment, and [`TimePicker`] for a
+//!   time of the day.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
+//! - [`decorator`]: wrappers adding borders, a title, scrolling or a style to any widget, see
+//!   [`Decorate`].
+//! - [`dialogs::confirm`] and [`dialogs::prompt`]: modal dialogs asking a question or a line of
+//!   text, and [`ConfirmDialog`] to choose between `Yes`, `No` and `Cancel`.
+//! - [`Form`](struct@Form): labeled text inputs with focus traversal, submitted together.
 //! - [`Gauge`]: displays progress percentage using block characters.
+//! - [`GraphView`]: displays a directed graph of labeled nodes connected by arrows.
+//! - [`json_view::JsonView`]: displays a JSON value as a tree of expandable nodes.
+//! - [`KeybindInput`]: an input recording a key chord, shown as e.g. `Ctrl+Shift+P`.
 //! - [`LineGauge`]: display progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
+//! - [`MaskedInput`]: an input typing into a pattern of segments, e.g. an IP address.
+//! - [`NumberInput`]: an input for integer and floating point numbers with a range and a step.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
+//! - [`PathInput`]: an input for a file path, completed from the filesystem (feature `fs`).
+//! - [`Prompt`]: a label, a [`TextInput`], its validation error and a hint, on a few rows.
+//! - [`PropertyList`]: displays key-value pairs in two aligned columns.
 //! - [`Scrollbar`]: displays a scrollbar.
+//! - [`SelectionRect`]: a rectangular selection of cells, highlighted with a [`SelectionOverlay`]
+//!   and copied from the buffer.
+//! - [`Select`]: a dropdown showing the selected option, with a searchable list of options.
+//! - [`Slider`]: an input adjusting a bounded value, drawn as a gauge with a thumb.
 //! - [`Sparkline`]: display a single data set as a sparkline.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`TagInput`]: an input turning the typed text into tags, drawn as chips wrapping onto rows.
+//! - [`TaskList`]: displays the status and progress of a list of tasks.
+//! - [`TextArea`]: a multi-line text editor with soft word wrap.
+//! - [`TextInput`]: a single line text input.
+//! - [`TreeMap`]: displays weighted hierarchical data as nested rectangles.
+//! - [`WeekView`]: displays the events of a week in a grid of days and time slots.
 //!
 //! [`Canvas`]: crate::widgets::canvas::Canvas
+mod autocomplete;
 mod barchart;
+mod binding;
 pub mod block;
+mod button;
 #[cfg(feature = "widget-calendar")]
 pub mod calendar;
 pub mod canvas;
 mod chart;
+mod checkbox;
 mod clear;
+mod clock;
+mod color_picker;
+#[cfg(feature = "widget-calendar")]
+mod date_picker;
+pub mod decorator;
+pub mod dialogs;
+mod form;
 mod gauge;
+mod graph_view;
+mod history;
+#[cfg(feature = "widget-json")]
+pub mod json_view;
+mod keybind_input;
 mod list;
+mod masked_input;
+mod number_input;
 mod paragraph;
+#[cfg(feature = "fs")]
+mod path_input;
+mod prompt;
+mod property_list;
 mod reflow;
 mod scrollbar;
+mod select;
+mod selection_rect;
+mod slider;
 mod sparkline;
 mod table;
 mod tabs;
+mod tag_input;
+mod task_list;
+mod text_area;
+mod text_input;
+mod tree_map;
+mod week_view;
+mod words;
 
 use std::fmt::{self, Debug};
 
 use bitflags::bitflags;
 
+#[cfg(feature = "widget-calendar")]
+pub use self::date_picker::{DatePicker, DatePickerState, TimePicker, TimePickerState};
+#[cfg(feature = "fs")]
+pub use self::path_input::{PathInput, PathInputState, PathKind, PathValidator};
+#[cfg(feature = "syntect")]
+pub use self::text_area::SyntectHighlighter;
+#[cfg(feature = "vim")]
+pub use self::text_area::VimMode;
 pub use self::{
+    autocomplete::{Autocomplete, AutocompleteState},
     barchart::{Bar, BarChart, BarGroup},
+    binding::{Bindable, Binding, BindingChange},
     block::{Block, BorderType, Padding},
+    button::{Button, ButtonRow, ButtonRowState, ButtonState},
     chart::{Axis, Chart, Dataset, GraphType, LegendPosition},
+    checkbox::{Checkbox, CheckboxState, RadioGroup, RadioGroupState},
     clear::Clear,
-    gauge::{Gauge, LineGauge},
-    list::{List, ListDirection, ListItem, ListState},
-    paragraph::{Paragraph, Wrap},
+    clock::Clock,
+    color_picker::{ColorPicker, ColorPickerMode, ColorPickerState},
+    decorator::{Decorate, Decorator},
+    dialogs::{ConfirmAction, ConfirmDialog, ConfirmDialogState},
+    form::{FieldError, Form, FormData, FormField, FormState},
+    gauge::{Gauge, GaugeDirection, LineGauge},
+    graph_view::{GraphNode, GraphView, GraphViewState},
+    keybind_input::{KeybindInput, KeybindInputState},
+    list::{List, ListDirection, ListItem, ListState, ScrollPosition, Truncated},
+    masked_input::{MaskedInput, MaskedInputState},
+    number_input::{Number, NumberInput, NumberInputState},
+    paragraph::{Paragraph, Wrap, WrapIndent},
+    prompt::Prompt,
+    property_list::{Property, PropertyList, ValueKind},
     scrollbar::{ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    select::{Select, SelectOption, SelectState},
+    selection_rect::{SelectionOverlay, SelectionRect},
+    slider::{Slider, SliderState},
     sparkline::{RenderDirection, Sparkline},
-    table::{Cell, HighlightSpacing, Row, Table, TableState},
+    table::{
+        aggregate, Cell, ExportFormat, HighlightSpacing, Row, Table, TableState, VerticalAlignment,
+    },
     tabs::Tabs,
+    tag_input::{TagInput, TagInputState},
+    task_list::{Task, TaskList, TaskListState, TaskStatus},
+    text_area::{
+        Diagnostic, Highlighter, LineNumbers, Severity, TextArea, TextAreaState, Whitespace,
+        WrapNavigation,
+    },
+    text_input::{TextInput, TextInputEvent, TextInputState},
+    tree_map::{TreeMap, TreeMapNode, TreeMapState},
+    week_view::{WeekEvent, WeekView},
+};
+use crate::{
+    buffer::Buffer,
+    layout::{Position, Rect},
 };
-use crate::{buffer::Buffer, layout::Rect};
+#[cfg(feature = "derive")]
+pub use ratatui_derive::Form;
 
 bitflags! {
     /// Bitflags that can be composed to set the visible borders essentially on the block widget.
@@ -227,6 +331,34 @@ pub trait StatefulWidget {
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State);
 }
 
+/// A widget state that knows where the terminal cursor belongs on screen.
+///
+/// The states of the input widgets, e.g. [`TextInputState`] and [`TextAreaState`], implement this
+/// trait so that [`Frame::set_cursor_for`] shows the terminal cursor at the position of the
+/// logical cursor of the focused input, instead of apps computing it manually.
+///
+/// [`Frame::set_cursor_for`]: crate::Frame::set_cursor_for
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// fn ui(frame: &mut Frame, name: &mut TextInputState, bio: &mut TextAreaState) {
+///     let [top, bottom] = [Rect::new(0, 0, 20, 1), Rect::new(0, 1, 20, 3)];
+///     frame.render_stateful_widget(TextInput::new(), top, name);
+///     frame.render_stateful_widget(TextArea::new(), bottom, bio);
+///     // only the focused input provides a cursor
+///     frame.set_cursor_for(name);
+///     frame.set_cursor_for(bio);
+/// }
+/// ```
+pub trait CursorProvider {
+    /// Returns the position of the cursor on screen during the last render, or `None` if the
+    /// widget doesn't have the focus or its cursor was not visible.
+    fn cursor_position(&self) -> Option<Position>;
+}
+
 /// Macro that constructs and returns a [`Borders`] object from TOP, BOTTOM, LEFT, RIGHT, NONE, and
 /// ALL. Internally it creates an empty `Borders` object and then inserts each bit flag specified
 /// into it using `Borders::insert()`.
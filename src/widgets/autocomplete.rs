@@ -0,0 +1,539 @@
+#![deny(missing_docs)]
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    buffer::Buffer,
+    input::{Input, Key, KeyEvent, KeyEventKind, KeyModifiers},
+    layout::Rect,
+    style::{Modifier, Style, Styled},
+    widgets::{Block, Borders, Clear, StatefulWidget, TextInputState, Widget},
+};
+
+const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_MATCH_STYLE: Style = Style::new().add_modifier(Modifier::BOLD);
+
+/// A dropdown of completions for a [`TextInput`], filtered with fuzzy matching as the user
+/// types.
+///
+/// The dropdown is rendered below the input, or above it when there is not enough room below.
+/// It is given the area of the input with [`new`](Autocomplete::new), and the area it can be
+/// drawn in (e.g. the whole frame) when rendered. The characters matching the typed text are
+/// shown with the [`match_style`](Autocomplete::match_style).
+///
+/// The candidates, the matches and the selected completion are stored in an
+/// [`AutocompleteState`], which handles the keyboard input of both the dropdown and the input
+/// with [`AutocompleteState::handle_key`].
+///
+/// [`TextInput`]: crate::widgets::TextInput
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, input: &mut TextInputState, completions: &mut AutocompleteState) {
+/// let input_area = Rect::new(0, 0, 20, 1);
+/// frame.render_stateful_widget(TextInput::new(), input_area, input);
+/// frame.render_stateful_widget(Autocomplete::new(input_area), frame.size(), completions);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Autocomplete<'a> {
+    input_area: Rect,
+    block: Option<Block<'a>>,
+    style: Style,
+    highlight_style: Style,
+    match_style: Style,
+    max_height: u16,
+}
+
+impl<'a> Autocomplete<'a> {
+    /// Creates a new dropdown for the input rendered in the given area.
+    pub fn new(input_area: Rect) -> Self {
+        Self {
+            input_area,
+            block: Some(Block::default().borders(Borders::ALL)),
+            style: Style::default(),
+            highlight_style: DEFAULT_HIGHLIGHT_STYLE,
+            match_style: DEFAULT_MATCH_STYLE,
+            max_height: 5,
+        }
+    }
+
+    /// Surrounds the dropdown with a [`Block`]. Defaults to a block with all borders.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the dropdown.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the selected completion. Defaults to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// Sets the style of the characters matching the typed text. Defaults to bold text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn match_style(mut self, style: Style) -> Self {
+        self.match_style = style;
+        self
+    }
+
+    /// Sets the maximum number of completions shown at once. Defaults to 5.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn max_height(mut self, height: u16) -> Self {
+        self.max_height = height.max(1);
+        self
+    }
+}
+
+impl<'a> Styled for Autocomplete<'a> {
+    type Item = Autocomplete<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// A candidate matching the typed text.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Match {
+    /// Index of the candidate
+    index: usize,
+    score: i64,
+    /// Byte offsets of the matching characters in the candidate
+    positions: Vec<usize>,
+}
+
+/// The state of an [`Autocomplete`] dropdown.
+///
+/// The state keeps the candidates, filters them with the text of the input and keeps track of
+/// the selected completion. The dropdown is open while the typed text matches candidates, or
+/// after `Tab` was pressed.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     input::{Input, Key},
+///     widgets::*,
+/// };
+///
+/// let mut completions = AutocompleteState::new(["checkout", "cherry-pick", "commit"]);
+/// let mut input = TextInputState::default();
+/// completions.handle_key(&mut input, Key::Char('c').into());
+/// completions.handle_key(&mut input, Key::Char('h').into());
+/// assert_eq!(completions.matches().collect::<Vec<_>>(), ["checkout", "cherry-pick"]);
+/// completions.handle_key(&mut input, Key::Down.into());
+/// completions.handle_key(&mut input, Key::Tab.into());
+/// assert_eq!(input.value(), "cherry-pick");
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct AutocompleteState {
+    candidates: Vec<String>,
+    matches: Vec<Match>,
+    /// Index of the selected match
+    selected: Option<usize>,
+    /// Index of the first visible match
+    offset: usize,
+    open: bool,
+}
+
+impl AutocompleteState {
+    /// Creates a new state with the given candidates. The dropdown is initially closed.
+    pub fn new<I>(candidates: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            candidates: candidates.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the candidates.
+    pub fn candidates(&self) -> &[String] {
+        &self.candidates
+    }
+
+    /// Replaces the candidates and closes the dropdown.
+    pub fn set_candidates<I>(&mut self, candidates: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.candidates = candidates.into_iter().map(Into::into).collect();
+        self.close();
+    }
+
+    /// Filters the candidates with the given text, best matches first, and selects the first
+    /// match. With an empty text, all the candidates match in their given order. The dropdown is opened if the text is not empty and some candidates match.
+    pub fn update(&mut self, text: &str) {
+        self.filter(text);
+        self.open = !text.is_empty() && !self.matches.is_empty();
+    }
+
+    fn filter(&mut self, text: &str) {
+        self.matches = self
+            .candidates
+            .iter()
+            .enumerate()
+            .filter_map(|(index, candidate)| {
+                fuzzy_match(text, candidate).map(|(score, positions)| Match {
+                    index,
+                    score,
+                    positions,
+                })
+            })
+            .collect();
+        // with nothing typed, every candidate matches equally: keep them in their given order.
+        // Otherwise the sort is stable: equal scores keep the order of the candidates
+        if !text.is_empty() {
+            self.matches.sort_by_key(|m| -m.score);
+        }
+        self.selected = (!self.matches.is_empty()).then_some(0);
+        self.offset = 0;
+    }
+
+    /// Returns the candidates matching the text, best matches first.
+    pub fn matches(&self) -> impl Iterator<Item = &str> {
+        self.matches
+            .iter()
+            .map(|m| self.candidates[m.index].as_str())
+    }
+
+    /// Returns the selected completion.
+    pub fn selected(&self) -> Option<&str> {
+        self.selected
+            .and_then(|i| self.matches.get(i))
+            .map(|m| self.candidates[m.index].as_str())
+    }
+
+    /// Selects the next match, wrapping around at the end.
+    pub fn select_next(&mut self) {
+        if !self.matches.is_empty() {
+            self.selected = Some(self.selected.map_or(0, |i| (i + 1) % self.matches.len()));
+        }
+    }
+
+    /// Selects the previous match, wrapping around at the start.
+    pub fn select_previous(&mut self) {
+        let len = self.matches.len();
+        if len > 0 {
+            self.selected = Some(self.selected.map_or(len - 1, |i| (i + len - 1) % len));
+        }
+    }
+
+    /// Filters the candidates with the given text and opens the dropdown, even if the text is
+    /// empty. Returns `true` if some candidates match.
+    pub(crate) fn open(&mut self, text: &str) -> bool {
+        self.filter(text);
+        self.open = !self.matches.is_empty();
+        self.open
+    }
+
+    /// Returns `true` if the dropdown is open.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Closes the dropdown.
+    pub fn close(&mut self) {
+        self.open = false;
+        self.matches.clear();
+        self.selected = None;
+        self.offset = 0;
+    }
+
+    /// Replaces the value of the input with the selected completion and closes the dropdown.
+    /// Returns `true` if a completion was selected. This can be undone in the input.
+    pub fn accept(&mut self, input: &mut TextInputState) -> bool {
+        let Some(selected) = self.selected() else {
+            return false;
+        };
+        input.replace_value(selected);
+        self.close();
+        true
+    }
+
+    /// Handles a key event for the dropdown and its input. Returns `true` if the event was
+    /// handled.
+    ///
+    /// While the dropdown is open, `Down` / `Ctrl-N` and `Up` / `Ctrl-P` move the selection,
+    /// `Tab` and `Enter` accept the selected completion and `Esc` closes the dropdown. `Tab`
+    /// opens the dropdown with all the matching candidates when it is closed. The other keys are
+    /// passed to the input, and the candidates are filtered again when its value changes.
+    pub fn handle_key(&mut self, input: &mut TextInputState, key: KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release {
+            return false;
+        }
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        if self.open {
+            match key.key {
+                Key::Down => return self.navigate(Self::select_next),
+                Key::Char('n') if ctrl => return self.navigate(Self::select_next),
+                Key::Up => return self.navigate(Self::select_previous),
+                Key::Char('p') if ctrl => return self.navigate(Self::select_previous),
+                Key::Tab | Key::Enter => return self.accept(input),
+                Key::Esc => {
+                    self.close();
+                    return true;
+                }
+                _ => {}
+            }
+        } else if key.key == Key::Tab {
+            return self.open(input.value());
+        }
+        let value = input.value().to_string();
+        let handled = input.handle_key(key);
+        if input.value() != value {
+            self.update(input.value());
+        }
+        handled
+    }
+
+    fn navigate(&mut self, select: fn(&mut Self)) -> bool {
+        select(self);
+        true
+    }
+}
+
+/// Matches the text against the candidate, ignoring case. The characters of the text must
+/// appear in the candidate in the same order, but not necessarily next to each other.
+///
+/// Returns the score of the match, higher being better, and the byte offsets of the matching
+/// characters. Consecutive characters and characters at the start of words score higher.
+fn fuzzy_match(text: &str, candidate: &str) -> Option<(i64, Vec<usize>)> {
+    let mut positions = Vec::with_capacity(text.len());
+    let mut score = 0;
+    let mut chars = candidate.char_indices().peekable();
+    let mut previous: Option<(usize, char)> = None;
+    let mut last_match = None;
+    for wanted in text.chars() {
+        loop {
+            let (i, c) = chars.next()?;
+            let at_word_start = previous.map_or(true, |(_, p)| !p.is_alphanumeric());
+            previous = Some((i, c));
+            if !c.to_lowercase().eq(wanted.to_lowercase()) {
+                continue;
+            }
+            score += 1;
+            if at_word_start {
+                score += 8;
+            }
+            if last_match.is_some_and(|last| previous_char_index(candidate, i) == Some(last)) {
+                score += 5;
+            }
+            positions.push(i);
+            last_match = Some(i);
+            break;
+        }
+    }
+    // prefer shorter candidates
+    score -= candidate.width() as i64 / 4;
+    Some((score, positions))
+}
+
+fn previous_char_index(s: &str, i: usize) -> Option<usize> {
+    s[..i].char_indices().next_back().map(|(i, _)| i)
+}
+
+impl<'a> StatefulWidget for Autocomplete<'a> {
+    type State = AutocompleteState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if !state.open || state.matches.is_empty() {
+            return;
+        }
+        // measure the space taken by the borders and titles of the block
+        let probe = Rect::new(0, 0, 100, 100);
+        let inner_probe = self.block.as_ref().map_or(probe, |b| b.inner(probe));
+        let frame_width = probe.width - inner_probe.width;
+        let frame_height = probe.height - inner_probe.height;
+
+        let rows = (state.matches.len() as u16).min(self.max_height);
+        let content_width = state
+            .matches()
+            .map(|candidate| candidate.width() as u16)
+            .max()
+            .unwrap_or(0);
+        let width = (content_width + frame_width)
+            .max(self.input_area.width)
+            .min(area.width);
+        let wanted_height = rows + frame_height;
+        let below = area.bottom().saturating_sub(self.input_area.bottom());
+        let above = self.input_area.top().saturating_sub(area.top());
+        let (y, height) = if below >= wanted_height || below >= above {
+            (self.input_area.bottom(), wanted_height.min(below))
+        } else {
+            let height = wanted_height.min(above);
+            (self.input_area.top() - height, height)
+        };
+        let x = self
+            .input_area
+            .x
+            .clamp(area.x, area.right().saturating_sub(width));
+        let dropdown = Rect::new(x, y, width, height);
+        if dropdown.is_empty() {
+            return;
+        }
+
+        Clear.render(dropdown, buf);
+        buf.set_style(dropdown, self.style);
+        let inner = match self.block {
+            Some(block) => {
+                let inner = block.inner(dropdown);
+                block.render(dropdown, buf);
+                inner
+            }
+            None => dropdown,
+        };
+        if inner.is_empty() {
+            return;
+        }
+        let visible = usize::from(inner.height);
+        if let Some(selected) = state.selected {
+            state.offset = state
+                .offset
+                .min(selected)
+                .max((selected + 1).saturating_sub(visible));
+        }
+        for (row, (i, m)) in state
+            .matches
+            .iter()
+            .enumerate()
+            .skip(state.offset)
+            .take(visible)
+            .enumerate()
+        {
+            let y = inner.y + row as u16;
+            let line = Rect::new(inner.x, y, inner.width, 1);
+            if Some(i) == state.selected {
+                buf.set_style(line, self.highlight_style);
+            }
+            let candidate = &state.candidates[m.index];
+            let mut x = inner.x;
+            for (offset, c) in candidate.char_indices() {
+                let char_width = c.to_string().width() as u16;
+                if x + char_width > inner.right() {
+                    break;
+                }
+                let style = if m.positions.contains(&offset) {
+                    self.match_style
+                } else {
+                    Style::default()
+                };
+                buf.set_stringn(x, y, c.to_string(), char_width.into(), style);
+                x += char_width;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    fn typed(state: &mut AutocompleteState, input: &mut TextInputState, text: &str) {
+        for c in text.chars() {
+            state.handle_key(input, Key::Char(c).into());
+        }
+    }
+
+    #[test]
+    fn fuzzy_matching() {
+        assert_eq!(fuzzy_match("ck", "checkout").map(|m| m.1), Some(vec![0, 4]));
+        assert_eq!(fuzzy_match("CO", "checkout").map(|m| m.1), Some(vec![0, 5]));
+        assert_eq!(fuzzy_match("x", "checkout"), None);
+        assert_eq!(fuzzy_match("", "checkout").map(|m| m.1), Some(vec![]));
+        // word starts and consecutive characters score higher
+        let score = |text, candidate| fuzzy_match(text, candidate).unwrap().0;
+        assert!(score("cp", "cherry-pick") > score("cp", "compare"));
+        assert!(score("che", "check") > score("che", "cache"));
+    }
+
+    #[test]
+    fn filter_and_accept() {
+        let mut state = AutocompleteState::new(["compare", "cherry-pick", "commit"]);
+        let mut input = TextInputState::default();
+        typed(&mut state, &mut input, "cp");
+        assert!(state.is_open());
+        assert_eq!(
+            state.matches().collect::<Vec<_>>(),
+            ["cherry-pick", "compare"]
+        );
+        assert_eq!(state.selected(), Some("cherry-pick"));
+        assert!(state.handle_key(&mut input, Key::Up.into()));
+        assert_eq!(state.selected(), Some("compare"));
+        assert!(state.handle_key(&mut input, Key::Enter.into()));
+        assert_eq!(input.value(), "compare");
+        assert!(!state.is_open());
+        assert!(input.undo());
+        assert_eq!(input.value(), "cp");
+    }
+
+    #[test]
+    fn tab_opens_dropdown() {
+        let mut state = AutocompleteState::new(["a", "b"]);
+        let mut input = TextInputState::default();
+        assert!(state.handle_key(&mut input, Key::Tab.into()));
+        assert!(state.is_open());
+        assert_eq!(state.matches().count(), 2);
+        assert!(state.handle_key(&mut input, Key::Esc.into()));
+        assert!(!state.is_open());
+        typed(&mut state, &mut input, "z");
+        assert!(!state.is_open());
+    }
+
+    #[test]
+    fn render_below_input() {
+        let mut state = AutocompleteState::new(["apple", "apricot", "banana"]);
+        state.update("ap");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 6));
+        let dropdown = Autocomplete::new(Rect::new(1, 0, 6, 1))
+            .highlight_style(Style::default())
+            .match_style(Style::default());
+        StatefulWidget::render(dropdown, buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(
+            buf,
+            Buffer::with_lines(vec![
+                "          ",
+                " ┌───────┐",
+                " │apple  │",
+                " │apricot│",
+                " └───────┘",
+                "          ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_above_input_without_room_below() {
+        let mut state = AutocompleteState::new(["apple", "apricot", "banana"]);
+        state.update("ap");
+        state.select_next();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 3));
+        let dropdown = Autocomplete::new(Rect::new(0, 2, 9, 1))
+            .block(Block::default())
+            .match_style(Style::default());
+        StatefulWidget::render(dropdown, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["apple    ", "apricot  ", "         "]);
+        expected.set_style(Rect::new(0, 1, 9, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+}
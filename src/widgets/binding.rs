@@ -0,0 +1,217 @@
+use crate::widgets::{ListState, TableState};
+
+/// A widget state holding a value that can be bound to the application state with a
+/// [`Binding`].
+pub trait Bindable {
+    /// The type of the value held by the widget state.
+    type Value: Clone + PartialEq;
+
+    /// Returns the current value of the widget state.
+    fn value(&self) -> Self::Value;
+
+    /// Replaces the value of the widget state.
+    fn set_value(&mut self, value: Self::Value);
+}
+
+impl Bindable for ListState {
+    type Value = Option<usize>;
+
+    fn value(&self) -> Self::Value {
+        self.selected()
+    }
+
+    fn set_value(&mut self, value: Self::Value) {
+        self.select(value);
+    }
+}
+
+impl Bindable for TableState {
+    type Value = Option<usize>;
+
+    fn value(&self) -> Self::Value {
+        self.selected()
+    }
+
+    fn set_value(&mut self, value: Self::Value) {
+        self.select(value);
+    }
+}
+
+/// The direction in which a value was copied by [`Binding::sync`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum BindingChange {
+    /// Neither side changed since the last sync.
+    #[default]
+    None,
+    /// The application value changed and was copied into the widget state.
+    ToWidget,
+    /// The widget value changed (e.g. the user selected another item) and was copied into the
+    /// application value.
+    ToModel,
+}
+
+/// Keeps a value of the application state and a widget state in sync.
+///
+/// A binding remembers the last value both sides agreed on. Each call to [`sync`](Self::sync)
+/// compares both sides against it and copies the value from the side that changed to the other
+/// one. If both sides changed since the last sync, the application value wins.
+///
+/// The binding is marked dirty whenever the widget changes the application value, which lets
+/// applications know that there are user edits to save. Call [`clear_dirty`](Self::clear_dirty)
+/// once they were handled.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::{Binding, BindingChange, ListState};
+///
+/// struct App {
+///     selected_file: Option<usize>,
+/// }
+///
+/// let mut app = App {
+///     selected_file: Some(2),
+/// };
+/// let mut state = ListState::default();
+/// let mut binding = Binding::new(None);
+///
+/// // the application value is copied into the widget state
+/// assert_eq!(
+///     binding.sync(&mut app.selected_file, &mut state),
+///     BindingChange::ToWidget
+/// );
+/// assert_eq!(state.selected(), Some(2));
+///
+/// // the user selects another item
+/// state.select(Some(3));
+/// assert_eq!(
+///     binding.sync(&mut app.selected_file, &mut state),
+///     BindingChange::ToModel
+/// );
+/// assert_eq!(app.selected_file, Some(3));
+/// assert!(binding.is_dirty());
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Binding<T> {
+    synced: T,
+    dirty: bool,
+}
+
+impl<T> Binding<T>
+where
+    T: Clone + PartialEq,
+{
+    /// Creates a new binding with the value both sides are assumed to hold.
+    ///
+    /// Pass a value that differs from the application value (e.g. the widget's default) to copy
+    /// the application value into the widget on the first sync.
+    pub fn new(value: T) -> Self {
+        Self {
+            synced: value,
+            dirty: false,
+        }
+    }
+
+    /// Returns the last value both sides agreed on.
+    pub fn value(&self) -> &T {
+        &self.synced
+    }
+
+    /// Returns `true` if the widget changed the application value since the binding was created
+    /// or [`clear_dirty`](Self::clear_dirty) was called.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Clears the dirty flag, e.g. after the user edits were saved.
+    pub fn clear_dirty(&mut self) {
+        self.dirty = false;
+    }
+
+    /// Synchronizes the application value and the widget state.
+    ///
+    /// This is usually called once per frame, before rendering the widget and after handling
+    /// input events.
+    pub fn sync<S>(&mut self, model: &mut T, state: &mut S) -> BindingChange
+    where
+        S: Bindable<Value = T>,
+    {
+        let widget = state.value();
+        if *model != self.synced {
+            self.synced = model.clone();
+            if widget == *model {
+                return BindingChange::None;
+            }
+            state.set_value(model.clone());
+            BindingChange::ToWidget
+        } else if widget != self.synced {
+            *model = widget.clone();
+            self.synced = widget;
+            self.dirty = true;
+            BindingChange::ToModel
+        } else {
+            BindingChange::None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sync_unchanged() {
+        let mut binding = Binding::new(Some(1));
+        let mut model = Some(1);
+        let mut state = ListState::default().with_selected(Some(1));
+        assert_eq!(binding.sync(&mut model, &mut state), BindingChange::None);
+        assert!(!binding.is_dirty());
+    }
+
+    #[test]
+    fn sync_to_widget() {
+        let mut binding = Binding::new(None);
+        let mut model = Some(4);
+        let mut state = TableState::default();
+        assert_eq!(
+            binding.sync(&mut model, &mut state),
+            BindingChange::ToWidget
+        );
+        assert_eq!(state.selected(), Some(4));
+        assert_eq!(binding.value(), &Some(4));
+        assert!(!binding.is_dirty());
+    }
+
+    #[test]
+    fn sync_to_model() {
+        let mut binding = Binding::new(None);
+        let mut model = None;
+        let mut state = ListState::default().with_selected(Some(2));
+        assert_eq!(binding.sync(&mut model, &mut state), BindingChange::ToModel);
+        assert_eq!(model, Some(2));
+        assert!(binding.is_dirty());
+        binding.clear_dirty();
+        assert!(!binding.is_dirty());
+    }
+
+    #[test]
+    fn model_wins_when_both_changed() {
+        let mut binding = Binding::new(Some(0));
+        let mut model = Some(1);
+        let mut state = ListState::default().with_selected(Some(2));
+        assert_eq!(
+            binding.sync(&mut model, &mut state),
+            BindingChange::ToWidget
+        );
+        assert_eq!(state.selected(), Some(1));
+    }
+
+    #[test]
+    fn both_changed_to_same_value() {
+        let mut binding = Binding::new(Some(0));
+        let mut model = Some(1);
+        let mut state = ListState::default().with_selected(Some(1));
+        assert_eq!(binding.sync(&mut model, &mut state), BindingChange::None);
+        assert_eq!(binding.value(), &Some(1));
+    }
+}
@@ -11,8 +11,9 @@ pub mod title;
 
 use strum::{Display, EnumString};
 
-pub use self::title::{Position, Title};
+pub use self::title::{Badge, Position, Spinner, Title, TitleContent, TitleGauge};
 use crate::{
+    backend::Capabilities,
     buffer::Buffer,
     layout::{Alignment, Rect},
     style::{Style, Styled},
@@ -484,7 +485,8 @@ impl<'a> Block<'a> {
 
     /// Sets the symbols used to display the border as a [`crate::symbols::border::Set`].
     ///
-    /// Setting this overwrites any [`border_type`](Block::border_type) that was set.
+    /// Setting this overwrites any [`border_type`](Block::border_type) that was set. Terminals
+    /// lacking some of the symbols get simpler ones, see [`Capabilities::border_set`].
     ///
     /// # Examples
     ///
@@ -600,7 +602,7 @@ impl<'a> Block<'a> {
 
     fn render_borders(&self, area: Rect, buf: &mut Buffer) {
         buf.set_style(area, self.style);
-        let symbols = self.border_set;
+        let symbols = Capabilities::current().border_set(self.border_set);
 
         // Sides
         if self.borders.intersects(Borders::LEFT) {
@@ -691,19 +693,14 @@ impl<'a> Block<'a> {
             .filter(|title| self.title_filter(title, Alignment::Left, position))
             .for_each(|title| {
                 let title_x = current_offset;
-                current_offset += title.content.width() as u16 + 1;
+                current_offset += title.width() + 1;
 
-                // Clone the title's content, applying block title style then the title style
-                let mut content = title.content.clone();
-                for span in content.spans.iter_mut() {
-                    span.style = self.titles_style.patch(span.style);
-                }
-
-                buf.set_line(
+                self.render_title(
+                    title,
                     title_x + area.left(),
                     self.get_title_y(position, area),
-                    &content,
                     title_area_width,
+                    buf,
                 );
             });
     }
@@ -716,26 +713,19 @@ impl<'a> Block<'a> {
             .iter()
             .filter(|title| self.title_filter(title, Alignment::Center, position));
 
-        let titles_sum = titles
-            .clone()
-            .fold(-1, |acc, f| acc + f.content.width() as i16 + 1); // First element isn't spaced
+        let titles_sum = titles.clone().fold(-1, |acc, f| acc + f.width() as i16 + 1); // First element isn't spaced
 
         let mut current_offset = area.width.saturating_sub(titles_sum as u16) / 2;
         titles.for_each(|title| {
             let title_x = current_offset;
-            current_offset += title.content.width() as u16 + 1;
-
-            // Clone the title's content, applying block title style then the title style
-            let mut content = title.content.clone();
-            for span in content.spans.iter_mut() {
-                span.style = self.titles_style.patch(span.style);
-            }
+            current_offset += title.width() + 1;
 
-            buf.set_line(
+            self.render_title(
+                title,
                 title_x + area.left(),
                 self.get_title_y(position, area),
-                &content,
                 title_area_width,
+                buf,
             );
         });
     }
@@ -749,24 +739,42 @@ impl<'a> Block<'a> {
             .filter(|title| self.title_filter(title, Alignment::Right, position))
             .rev() // so that the titles appear in the order they have been set
             .for_each(|title| {
-                current_offset += title.content.width() as u16 + 1;
+                current_offset += title.width() + 1;
                 let title_x = current_offset - 1; // First element isn't spaced
 
-                // Clone the title's content, applying block title style then the title style
-                let mut content = title.content.clone();
-                for span in content.spans.iter_mut() {
-                    span.style = self.titles_style.patch(span.style);
-                }
-
-                buf.set_line(
+                self.render_title(
+                    title,
                     area.width.saturating_sub(title_x) + area.left(),
                     self.get_title_y(position, area),
-                    &content,
                     title_area_width,
+                    buf,
                 );
             });
     }
 
+    /// Renders the content of the title then its widget, at most `width` columns wide.
+    fn render_title(&self, title: &Title, x: u16, y: u16, width: u16, buf: &mut Buffer) {
+        // Clone the title's content, applying block title style then the title style
+        let mut content = title.content.clone();
+        for span in content.spans.iter_mut() {
+            span.style = self.titles_style.patch(span.style);
+        }
+        let (mut widget_x, _) = buf.set_line(x, y, &content, width);
+
+        if let Some(widget) = &title.widget {
+            if content.width() > 0 {
+                widget_x = widget_x.saturating_add(1);
+            }
+            let remaining = x.saturating_add(width).saturating_sub(widget_x);
+            let widget_area =
+                Rect::new(widget_x, y, widget.width().min(remaining), 1).intersection(buf.area);
+            if !widget_area.is_empty() {
+                buf.set_style(widget_area, self.titles_style);
+                widget.render(widget_area, buf);
+            }
+        }
+    }
+
     fn render_title_position(&self, position: Position, area: Rect, buf: &mut Buffer) {
         // Note: the order in which these functions are called define the overlapping behavior
         self.render_right_titles(position, area, buf);
@@ -1247,6 +1255,34 @@ mod tests {
         assert_buffer_eq!(buffer, expected_buffer);
     }
 
+    #[test]
+    fn title_widgets() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 3));
+        Block::default()
+            .title(Title::from("Build").widget(TitleGauge::new(0.5).width(4)))
+            .title(
+                Title::default()
+                    .widget(Badge::new("3"))
+                    .alignment(Alignment::Right),
+            )
+            .borders(Borders::ALL)
+            .render(buffer.area, &mut buffer);
+
+        let mut expected_buffer = Buffer::with_lines(vec![
+            "┌Build─━━──  50%────────── 3 ┐",
+            "│                            │",
+            "└────────────────────────────┘",
+        ]);
+        expected_buffer.set_style(Rect::new(26, 0, 3, 1), Style::new().reversed());
+        assert_buffer_eq!(buffer, expected_buffer);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        Block::default()
+            .title(Title::from("Load").widget(Spinner::new(1)))
+            .render(buffer.area, &mut buffer);
+        assert_buffer_eq!(buffer, Buffer::with_lines(vec!["Load ⠙"]));
+    }
+
     #[test]
     fn border_type_to_string() {
         assert_eq!(format!("{}", BorderType::Plain), "Plain");
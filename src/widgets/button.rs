@@ -0,0 +1,733 @@
+#![deny(missing_docs)]
+use crate::{
+    buffer::Buffer,
+    input::{Focusable, Key, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind},
+    layout::{Alignment, Rect},
+    style::{Modifier, Style, Styled},
+    text::{is_accelerator_key, Line, Mnemonic, Span},
+    widgets::{StatefulWidget, Widget},
+};
+
+const DEFAULT_HOVER_STYLE: Style = Style::new().add_modifier(Modifier::BOLD);
+const DEFAULT_FOCUSED_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_PRESSED_STYLE: Style = Style::new()
+    .add_modifier(Modifier::REVERSED)
+    .add_modifier(Modifier::BOLD);
+const DEFAULT_DISABLED_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+const DEFAULT_ACCELERATOR_STYLE: Style = Style::new().add_modifier(Modifier::UNDERLINED);
+
+/// A clickable button with an optional icon.
+///
+/// The button is rendered as its label surrounded by brackets, e.g. `[ OK ]`, centered in the
+/// render area. Its appearance depends on its [`ButtonState`]: the [`style`] is always applied,
+/// then the [`focused_style`], [`hover_style`] and [`pressed_style`] are patched on top of it
+/// when the button is in the corresponding state. Disabled buttons use the [`disabled_style`]
+/// instead.
+///
+/// The label is parsed as a [`Mnemonic`]: the character following an `&` (e.g. in `&Save`) is
+/// the accelerator of the button, drawn with the [`accelerator_style`], and `Alt` with that
+/// character activates the button even when it doesn't have the focus. Use `&&` for a literal
+/// ampersand.
+///
+/// Use [`ButtonState::handle_key`] and [`ButtonState::handle_mouse`] to activate the button from
+/// input events, and [`ButtonRow`] to lay out several buttons next to each other.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut ButtonState) {
+/// let button = Button::new("&Save").icon("💾").style(Style::new().green());
+/// frame.render_stateful_widget(button, Rect::new(0, 0, 12, 1), state);
+/// # }
+/// ```
+///
+/// [`style`]: Button::style
+/// [`focused_style`]: Button::focused_style
+/// [`hover_style`]: Button::hover_style
+/// [`pressed_style`]: Button::pressed_style
+/// [`disabled_style`]: Button::disabled_style
+/// [`accelerator_style`]: Button::accelerator_style
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Button<'a> {
+    label: Mnemonic<'a>,
+    label_style: Style,
+    icon: Option<Span<'a>>,
+    style: Style,
+    focused_style: Style,
+    hover_style: Style,
+    pressed_style: Style,
+    disabled_style: Style,
+    accelerator_style: Style,
+}
+
+impl<'a> Button<'a> {
+    /// Creates a new button with the given label, marking its accelerator with `&`.
+    pub fn new<T>(label: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        let label = label.into();
+        Self {
+            label: Mnemonic::parse(label.content),
+            label_style: label.style,
+            icon: None,
+            style: Style::default(),
+            focused_style: DEFAULT_FOCUSED_STYLE,
+            hover_style: DEFAULT_HOVER_STYLE,
+            pressed_style: DEFAULT_PRESSED_STYLE,
+            disabled_style: DEFAULT_DISABLED_STYLE,
+            accelerator_style: DEFAULT_ACCELERATOR_STYLE,
+        }
+    }
+
+    /// Sets an icon displayed before the label.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn icon<T>(mut self, icon: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the base style of the button.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style patched on top of the base style when the button has the focus. Defaults
+    /// to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_style(mut self, style: Style) -> Self {
+        self.focused_style = style;
+        self
+    }
+
+    /// Sets the style patched on top of the base style when the mouse is over the button.
+    /// Defaults to bold text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hover_style(mut self, style: Style) -> Self {
+        self.hover_style = style;
+        self
+    }
+
+    /// Sets the style patched on top of the base style while the button is pressed. Defaults to
+    /// reversed colors and bold text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn pressed_style(mut self, style: Style) -> Self {
+        self.pressed_style = style;
+        self
+    }
+
+    /// Sets the style patched on top of the base style when the button is disabled. Defaults to
+    /// dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = style;
+        self
+    }
+
+    /// Sets the style patched on top of the style of the accelerator of the label. Defaults to
+    /// underlined text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn accelerator_style(mut self, style: Style) -> Self {
+        self.accelerator_style = style;
+        self
+    }
+
+    /// Returns the accelerator of the label in lowercase, activating the button with `Alt`.
+    pub fn accelerator(&self) -> Option<char> {
+        self.label.accelerator()
+    }
+
+    /// Returns the width of the button content, including the brackets.
+    pub fn width(&self) -> usize {
+        self.content().width()
+    }
+
+    fn content(&self) -> Line<'a> {
+        let mut spans = vec![Span::raw("[ ")];
+        if let Some(icon) = &self.icon {
+            spans.push(icon.clone());
+            spans.push(Span::raw(" "));
+        }
+        spans.extend(
+            self.label
+                .to_line(self.label_style, self.accelerator_style)
+                .spans,
+        );
+        spans.push(Span::raw(" ]"));
+        Line::from(spans)
+    }
+
+    fn current_style(&self, state: &ButtonState) -> Style {
+        if state.disabled {
+            return self.style.patch(self.disabled_style);
+        }
+        let mut style = self.style;
+        if state.focused {
+            style = style.patch(self.focused_style);
+        }
+        if state.hovered {
+            style = style.patch(self.hover_style);
+        }
+        if state.pressed {
+            style = style.patch(self.pressed_style);
+        }
+        style
+    }
+}
+
+impl<'a> Styled for Button<'a> {
+    type Item = Button<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// The state of a [`Button`].
+///
+/// The state tracks whether the button has the focus, is hovered by the mouse, is pressed or is
+/// disabled. It also remembers where the button was last rendered, which is used to hit test
+/// mouse events, and the accelerator of its label, which is used to activate it from the keyboard.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ButtonState {
+    focused: bool,
+    hovered: bool,
+    pressed: bool,
+    disabled: bool,
+    area: Rect,
+    accelerator: Option<char>,
+}
+
+impl ButtonState {
+    /// Sets whether the button has the focus.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Sets whether the button is disabled.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.set_disabled(disabled);
+        self
+    }
+
+    /// Returns `true` if the button has the focus.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Sets whether the button has the focus.
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Returns `true` if the mouse is over the button.
+    pub fn is_hovered(&self) -> bool {
+        self.hovered
+    }
+
+    /// Returns `true` if the button is pressed.
+    pub fn is_pressed(&self) -> bool {
+        self.pressed
+    }
+
+    /// Returns `true` if the button is disabled.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Sets whether the button is disabled. Disabled buttons ignore input events.
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+        if disabled {
+            self.hovered = false;
+            self.pressed = false;
+        }
+    }
+
+    /// Handles a key event and returns `true` if the button was activated.
+    ///
+    /// A focused button is activated by pressing `Enter` or `Space`. Any button is activated by
+    /// its [accelerator](Button::accelerator) pressed with `Alt`, once it has been rendered.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if self.disabled || key.kind == KeyEventKind::Release {
+            return false;
+        }
+        match key.key {
+            Key::Enter | Key::Char(' ') if self.focused => true,
+            _ => self
+                .accelerator
+                .is_some_and(|accelerator| is_accelerator_key(accelerator, key)),
+        }
+    }
+
+    /// Handles a mouse event and returns `true` if the button was activated.
+    ///
+    /// The button is activated when the left mouse button is pressed and released over it. The
+    /// event position is compared to the area where the button was last rendered.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        if self.disabled {
+            return false;
+        }
+        let inside = self.area.contains(mouse.position());
+        match mouse.kind {
+            MouseEventKind::Moved | MouseEventKind::Drag(_) => {
+                self.hovered = inside;
+                false
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.hovered = inside;
+                self.pressed = inside;
+                false
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                let activated = self.pressed && inside;
+                self.pressed = false;
+                activated
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Focusable for ButtonState {
+    fn set_focused(&mut self, focused: bool) {
+        ButtonState::set_focused(self, focused);
+    }
+
+    fn is_disabled(&self) -> bool {
+        ButtonState::is_disabled(self)
+    }
+}
+
+impl<'a> StatefulWidget for Button<'a> {
+    type State = ButtonState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.area = area;
+        state.accelerator = self.accelerator();
+        if area.is_empty() {
+            return;
+        }
+        buf.set_style(area, self.current_style(state));
+        let content = self.content();
+        let width = (content.width() as u16).min(area.width);
+        let x = area.x + (area.width - width) / 2;
+        let y = area.y + area.height.saturating_sub(1) / 2;
+        buf.set_line(x, y, &content, width);
+    }
+}
+
+impl<'a> Widget for Button<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = ButtonState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+/// A row of [`Button`]s laid out next to each other.
+///
+/// The buttons are separated by [`spacing`](ButtonRow::spacing) columns and aligned in the render
+/// area with [`alignment`](ButtonRow::alignment). One of the buttons can be marked as the
+/// [default button](ButtonRow::default_button): it is highlighted with the
+/// [`default_style`](ButtonRow::default_style) and activated by `Enter` when no button has the
+/// focus.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut ButtonRowState) {
+/// let buttons = ButtonRow::new(vec![Button::new("Cancel"), Button::new("OK")])
+///     .default_button(1)
+///     .alignment(Alignment::Right);
+/// frame.render_stateful_widget(buttons, Rect::new(0, 0, 30, 1), state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ButtonRow<'a> {
+    buttons: Vec<Button<'a>>,
+    spacing: u16,
+    alignment: Alignment,
+    default_button: Option<usize>,
+    default_style: Style,
+}
+
+impl<'a> ButtonRow<'a> {
+    /// Creates a new row of buttons.
+    pub fn new(buttons: Vec<Button<'a>>) -> Self {
+        Self {
+            buttons,
+            spacing: 2,
+            alignment: Alignment::Center,
+            default_button: None,
+            default_style: Style::new().add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// Sets the number of columns between two buttons. Defaults to 2.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the horizontal alignment of the buttons. Defaults to [`Alignment::Center`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets the index of the default button.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn default_button(mut self, index: usize) -> Self {
+        self.default_button = Some(index);
+        self
+    }
+
+    /// Sets the style patched on top of the style of the default button. Defaults to bold text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn default_style(mut self, style: Style) -> Self {
+        self.default_style = style;
+        self
+    }
+}
+
+/// The state of a [`ButtonRow`].
+///
+/// It holds the [`ButtonState`] of every button and which button has the focus.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct ButtonRowState {
+    buttons: Vec<ButtonState>,
+    focused: Option<usize>,
+    default_button: Option<usize>,
+}
+
+impl ButtonRowState {
+    /// Returns the index of the focused button.
+    pub fn focused(&self) -> Option<usize> {
+        self.focused
+    }
+
+    /// Gives the focus to the button at the given index, or removes the focus with `None`.
+    pub fn focus(&mut self, index: Option<usize>) {
+        self.focused = index;
+        for (i, button) in self.buttons.iter_mut().enumerate() {
+            button.focused = Some(i) == index;
+        }
+    }
+
+    /// Returns the state of the button at the given index.
+    ///
+    /// The states are created when the row is first rendered.
+    pub fn button(&self, index: usize) -> Option<&ButtonState> {
+        self.buttons.get(index)
+    }
+
+    /// Returns a mutable reference to the state of the button at the given index, e.g. to
+    /// disable it.
+    ///
+    /// The states are created when the row is first rendered.
+    pub fn button_mut(&mut self, index: usize) -> Option<&mut ButtonState> {
+        self.buttons.get_mut(index)
+    }
+
+    /// Moves the focus to the next enabled button, wrapping around at the end of the row.
+    pub fn focus_next(&mut self) {
+        let len = self.buttons.len();
+        let start = self.focused.map_or(0, |i| i + 1);
+        let next = (0..len)
+            .map(|i| (start + i) % len)
+            .find(|&i| !self.buttons[i].disabled);
+        if next.is_some() {
+            self.focus(next);
+        }
+    }
+
+    /// Moves the focus to the previous enabled button, wrapping around at the start of the row.
+    pub fn focus_previous(&mut self) {
+        let len = self.buttons.len();
+        let start = self.focused.unwrap_or(0) + len;
+        let previous = (1..=len)
+            .map(|i| (start - i) % len)
+            .find(|&i| !self.buttons[i].disabled);
+        if previous.is_some() {
+            self.focus(previous);
+        }
+    }
+
+    /// Handles a key event and returns the index of the activated button, if any.
+    ///
+    /// `Left`, `Right`, `Tab` and `BackTab` move the focus between the buttons. `Enter` and
+    /// `Space` activate the focused button. `Enter` activates the default button when no button
+    /// has the focus, and `Alt` with the [accelerator](Button::accelerator) of a button activates
+    /// it.
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<usize> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+        match key.key {
+            Key::Right | Key::Tab => {
+                self.focus_next();
+                None
+            }
+            Key::Left | Key::BackTab => {
+                self.focus_previous();
+                None
+            }
+            Key::Enter if self.focused.is_none() => self
+                .default_button
+                .filter(|&i| self.buttons.get(i).is_some_and(|b| !b.disabled)),
+            _ => self
+                .buttons
+                .iter_mut()
+                .position(|button| button.handle_key(key)),
+        }
+    }
+
+    /// Handles a mouse event and returns the index of the activated button, if any.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> Option<usize> {
+        let mut activated = None;
+        for (i, button) in self.buttons.iter_mut().enumerate() {
+            if button.handle_mouse(mouse) {
+                activated = Some(i);
+            }
+        }
+        activated
+    }
+}
+
+impl<'a> StatefulWidget for ButtonRow<'a> {
+    type State = ButtonRowState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state
+            .buttons
+            .resize(self.buttons.len(), ButtonState::default());
+        state.default_button = self.default_button;
+        if state.focused.is_some_and(|i| i >= self.buttons.len()) {
+            state.focus(None);
+        }
+        let widths = self
+            .buttons
+            .iter()
+            .map(|button| button.width() as u16)
+            .collect::<Vec<_>>();
+        let spacing = self
+            .spacing
+            .saturating_mul(widths.len().saturating_sub(1) as u16);
+        let total_width = widths.iter().sum::<u16>().saturating_add(spacing);
+        let free = area.width.saturating_sub(total_width);
+        let mut x = area.x
+            + match self.alignment {
+                Alignment::Left => 0,
+                Alignment::Center => free / 2,
+                Alignment::Right => free,
+            };
+        for (i, (mut button, width)) in self.buttons.into_iter().zip(widths).enumerate() {
+            if Some(i) == self.default_button {
+                button.style = button.style.patch(self.default_style);
+            }
+            let width = width.min(area.right().saturating_sub(x));
+            let button_area = Rect::new(x, area.y, width, area.height);
+            StatefulWidget::render(button, button_area, buf, &mut state.buttons[i]);
+            x = x.saturating_add(width).saturating_add(self.spacing);
+            x = x.min(area.right());
+        }
+    }
+}
+
+impl<'a> Widget for ButtonRow<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = ButtonRowState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_buffer_eq,
+        input::KeyModifiers,
+        style::{Color, Stylize},
+    };
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+        Widget::render(Button::new("OK"), buf.area, &mut buf);
+        assert_buffer_eq!(
+            buf,
+            Buffer::with_lines(vec!["          ", "  [ OK ]  ", "          "])
+        );
+    }
+
+    #[test]
+    fn render_icon() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        Widget::render(Button::new("OK").icon("+"), buf.area, &mut buf);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec![" [ + OK ] "]));
+    }
+
+    #[test]
+    fn render_styles() {
+        let button = Button::new("OK").style(Style::new().red());
+        let area = Rect::new(0, 0, 6, 1);
+        let render = |state: &mut ButtonState| {
+            let mut buf = Buffer::empty(area);
+            StatefulWidget::render(button.clone(), area, &mut buf, state);
+            let cell = buf.get(0, 0);
+            (cell.fg, cell.modifier)
+        };
+        let mut state = ButtonState::default();
+        assert_eq!(render(&mut state), (Color::Red, Modifier::empty()));
+        let mut state = ButtonState::default().with_focused(true);
+        assert_eq!(render(&mut state), (Color::Red, Modifier::REVERSED));
+        let mut state = ButtonState::default()
+            .with_focused(true)
+            .with_disabled(true);
+        assert_eq!(render(&mut state), (Color::Red, Modifier::DIM));
+    }
+
+    #[test]
+    fn handle_key() {
+        let mut state = ButtonState::default();
+        assert!(!state.handle_key(Key::Enter.into()));
+        state.set_focused(true);
+        assert!(state.handle_key(Key::Enter.into()));
+        assert!(state.handle_key(Key::Char(' ').into()));
+        assert!(!state.handle_key(Key::Char('x').into()));
+        state.set_disabled(true);
+        assert!(!state.handle_key(Key::Enter.into()));
+    }
+
+    #[test]
+    fn render_accelerator() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        Widget::render(Button::new("&Save"), buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(vec![" [ Save ] "]);
+        expected.set_style(Rect::new(3, 0, 1, 1), Style::new().underlined());
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn handle_accelerator_key() {
+        let alt_s = KeyEvent::new(Key::Char('s'), KeyModifiers::ALT);
+        let mut state = ButtonState::default();
+        assert!(!state.handle_key(alt_s));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        StatefulWidget::render(Button::new("&Save"), buf.area, &mut buf, &mut state);
+        assert!(state.handle_key(alt_s));
+        assert!(!state.handle_key(Key::Char('s').into()));
+        state.set_disabled(true);
+        assert!(!state.handle_key(alt_s));
+    }
+
+    #[test]
+    fn handle_mouse() {
+        let mut state = ButtonState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        StatefulWidget::render(
+            Button::new("OK"),
+            Rect::new(2, 0, 6, 1),
+            &mut buf,
+            &mut state,
+        );
+        let left = MouseButton::Left;
+        assert!(!state.handle_mouse(MouseEvent::new(MouseEventKind::Moved, 3, 0)));
+        assert!(state.is_hovered());
+        assert!(!state.handle_mouse(MouseEvent::new(MouseEventKind::Down(left), 3, 0)));
+        assert!(state.is_pressed());
+        assert!(state.handle_mouse(MouseEvent::new(MouseEventKind::Up(left), 4, 0)));
+        assert!(!state.is_pressed());
+        // released outside of the button
+        state.handle_mouse(MouseEvent::new(MouseEventKind::Down(left), 3, 0));
+        assert!(!state.handle_mouse(MouseEvent::new(MouseEventKind::Up(left), 9, 0)));
+    }
+
+    #[test]
+    fn row_render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+        let row = ButtonRow::new(vec![Button::new("No"), Button::new("Yes")]);
+        Widget::render(row, buf.area, &mut buf);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["  [ No ]  [ Yes ]   "]));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+        let row = ButtonRow::new(vec![Button::new("No"), Button::new("Yes")])
+            .spacing(1)
+            .alignment(Alignment::Right)
+            .default_button(1);
+        Widget::render(row, buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(vec!["      [ No ] [ Yes ]"]);
+        expected.set_style(Rect::new(13, 0, 7, 1), Style::new().bold());
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn row_handle_key() {
+        let mut state = ButtonRowState::default();
+        let row = ButtonRow::new(vec![Button::new("A"), Button::new("B"), Button::new("C")])
+            .default_button(2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+        StatefulWidget::render(row, buf.area, &mut buf, &mut state);
+        assert_eq!(state.handle_key(Key::Enter.into()), Some(2));
+        state.button_mut(1).unwrap().set_disabled(true);
+        state.handle_key(Key::Right.into());
+        assert_eq!(state.focused(), Some(0));
+        state.handle_key(Key::Right.into());
+        assert_eq!(state.focused(), Some(2));
+        state.handle_key(Key::Right.into());
+        assert_eq!(state.focused(), Some(0));
+        state.handle_key(Key::Left.into());
+        assert_eq!(state.focused(), Some(2));
+        assert_eq!(state.handle_key(Key::Char(' ').into()), Some(2));
+    }
+
+    #[test]
+    fn row_handle_accelerator_key() {
+        let mut state = ButtonRowState::default();
+        let row = ButtonRow::new(vec![Button::new("&Yes"), Button::new("&No")]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+        StatefulWidget::render(row, buf.area, &mut buf, &mut state);
+        let alt = |c| KeyEvent::new(Key::Char(c), KeyModifiers::ALT);
+        assert_eq!(state.handle_key(alt('n')), Some(1));
+        assert_eq!(state.handle_key(alt('Y')), Some(0));
+        assert_eq!(state.handle_key(alt('x')), None);
+    }
+
+    #[test]
+    fn row_handle_mouse() {
+        let mut state = ButtonRowState::default();
+        let row = ButtonRow::new(vec![Button::new("A"), Button::new("B")])
+            .alignment(Alignment::Left)
+            .spacing(1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+        StatefulWidget::render(row, buf.area, &mut buf, &mut state);
+        let left = MouseButton::Left;
+        state.handle_mouse(MouseEvent::new(MouseEventKind::Down(left), 7, 0));
+        assert_eq!(
+            state.handle_mouse(MouseEvent::new(MouseEventKind::Up(left), 7, 0)),
+            Some(1)
+        );
+    }
+}
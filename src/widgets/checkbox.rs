@@ -0,0 +1,705 @@
+#![deny(missing_docs)]
+use crate::{
+    buffer::Buffer,
+    input::{Focusable, Key, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind},
+    layout::{Direction, Rect},
+    style::{Modifier, Style, Styled},
+    symbols::checkbox,
+    terminal::RedrawRequest,
+    text::{Line, Span},
+    widgets::{StatefulWidget, Widget},
+};
+
+const DEFAULT_FOCUSED_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_DISABLED_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+
+/// A checkbox with a label, e.g. `[x] Remember me`.
+///
+/// Whether the box is checked is stored in a [`CheckboxState`], which also toggles it from the
+/// keyboard and the mouse: `Space` and `Enter` toggle a focused checkbox, and a click with the
+/// left button toggles it whether it has the focus or not. The [`focused_style`] is patched on
+/// top of the [`style`] while the checkbox has the focus.
+///
+/// A [`read_only`](Checkbox::read_only) checkbox gets the focus but isn't toggled by the keys and
+/// the clicks. A [`disabled`](Checkbox::disabled) checkbox also isn't drawn focused, and is
+/// restyled with the [`disabled_style`](Checkbox::disabled_style).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{input::Key, prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut CheckboxState) {
+/// let checkbox = Checkbox::new("Remember me").symbols(symbols::checkbox::BALLOT_BOX);
+/// frame.render_stateful_widget(checkbox, Rect::new(0, 0, 20, 1), state);
+/// # }
+/// let mut state = CheckboxState::default().with_focused(true);
+/// assert!(state.handle_key(Key::Char(' ').into()));
+/// assert!(state.is_checked());
+/// ```
+///
+/// [`style`]: Checkbox::style
+/// [`focused_style`]: Checkbox::focused_style
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Checkbox<'a> {
+    label: Line<'a>,
+    symbols: checkbox::Set,
+    style: Style,
+    focused_style: Style,
+    read_only: bool,
+    disabled: bool,
+    disabled_style: Style,
+}
+
+impl<'a> Checkbox<'a> {
+    /// Creates a new checkbox with the given label.
+    pub fn new<T>(label: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        Self {
+            label: label.into(),
+            symbols: checkbox::BRACKETS,
+            style: Style::default(),
+            focused_style: DEFAULT_FOCUSED_STYLE,
+            read_only: false,
+            disabled: false,
+            disabled_style: DEFAULT_DISABLED_STYLE,
+        }
+    }
+
+    /// Sets the symbols of the checked and unchecked box. Defaults to
+    /// [`checkbox::BRACKETS`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn symbols(mut self, symbols: checkbox::Set) -> Self {
+        self.symbols = symbols;
+        self
+    }
+
+    /// Sets the style of the checkbox.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style patched on top of the style while the checkbox has the focus. Defaults to
+    /// reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_style(mut self, style: Style) -> Self {
+        self.focused_style = style;
+        self
+    }
+
+    /// Makes the checkbox read-only: it still gets the focus, but the keys and the clicks don't
+    /// toggle it.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Disables the checkbox: it ignores the keys and the clicks, isn't drawn focused and is
+    /// restyled with the [`disabled_style`](Self::disabled_style).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the style patched on top of the style while the checkbox is
+    /// [`disabled`](Self::disabled). Defaults to dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = style;
+        self
+    }
+}
+
+impl<'a> Styled for Checkbox<'a> {
+    type Item = Checkbox<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// The state of a [`Checkbox`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct CheckboxState {
+    checked: bool,
+    focused: bool,
+    /// Whether the keys and the clicks don't toggle the checkbox, set when rendering
+    read_only: bool,
+    /// Whether the checkbox ignores the focus too, set when rendering
+    disabled: bool,
+    /// Area of the last render, where a click toggles the checkbox
+    area: Rect,
+}
+
+impl CheckboxState {
+    /// Sets whether the checkbox is checked.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    /// Sets whether the checkbox has the focus.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Returns `true` if the checkbox is checked.
+    pub fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    /// Sets whether the checkbox is checked.
+    pub fn set_checked(&mut self, checked: bool) {
+        RedrawRequest::request_if(self.checked != checked);
+        self.checked = checked;
+    }
+
+    /// Checks the checkbox if it is unchecked, and unchecks it otherwise.
+    pub fn toggle(&mut self) {
+        self.set_checked(!self.checked);
+    }
+
+    /// Returns `true` if the checkbox has the focus.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Sets whether the checkbox has the focus.
+    pub fn set_focused(&mut self, focused: bool) {
+        RedrawRequest::request_if(self.focused != focused);
+        self.focused = focused;
+    }
+
+    /// Returns `true` if the checkbox was [read-only](Checkbox::read_only) during the last render.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns `true` if the checkbox was [disabled](Checkbox::disabled) during the last render.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Returns `true` if the checkbox can be toggled by the input events.
+    fn is_editable(&self) -> bool {
+        !self.read_only && !self.disabled
+    }
+
+    /// Handles a key event and returns `true` if the checkbox was toggled.
+    ///
+    /// A focused checkbox is toggled by pressing `Space` or `Enter`, unless it is read-only or
+    /// disabled.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let toggled = self.focused
+            && self.is_editable()
+            && key.kind != KeyEventKind::Release
+            && matches!(key.key, Key::Enter | Key::Char(' '));
+        if toggled {
+            self.toggle();
+        }
+        toggled
+    }
+
+    /// Handles a mouse event and returns `true` if the checkbox was toggled.
+    ///
+    /// The checkbox is toggled when the left button is pressed over the area of its last render,
+    /// i.e. over the box or its label, unless it is read-only or disabled.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        let toggled = self.is_editable()
+            && mouse.kind == MouseEventKind::Down(MouseButton::Left)
+            && self.area.contains(mouse.position());
+        if toggled {
+            self.toggle();
+        }
+        toggled
+    }
+}
+
+impl Focusable for CheckboxState {
+    fn set_focused(&mut self, focused: bool) {
+        CheckboxState::set_focused(self, focused);
+    }
+
+    fn is_disabled(&self) -> bool {
+        CheckboxState::is_disabled(self)
+    }
+}
+
+impl<'a> StatefulWidget for Checkbox<'a> {
+    type State = CheckboxState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.area = area;
+        state.read_only = self.read_only;
+        state.disabled = self.disabled;
+        if area.is_empty() {
+            return;
+        }
+        let style = if self.disabled {
+            self.style.patch(self.disabled_style)
+        } else if state.focused {
+            self.style.patch(self.focused_style)
+        } else {
+            self.style
+        };
+        buf.set_style(area, style);
+        let line = option_line(self.symbols, state.checked, self.label);
+        buf.set_line(area.x, area.y, &line, area.width);
+    }
+}
+
+impl<'a> Widget for Checkbox<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = CheckboxState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+/// A group of options of which a single one can be selected, e.g. `(*) Small ( ) Large`.
+///
+/// The options are laid out in the given [`direction`](RadioGroup::direction), one per row or
+/// separated by [`spacing`](RadioGroup::spacing) columns. The selected option and the
+/// highlighted option are stored in a [`RadioGroupState`], which handles the keyboard input of a
+/// focused group:
+/// - `Up` and `Left` highlight the previous option, `Down` and `Right` the next one.
+/// - `Space` and `Enter` select the highlighted option.
+///
+/// The [`focused_style`](RadioGroup::focused_style) is patched on top of the
+/// [`style`](RadioGroup::style) of the highlighted option while the group has the focus.
+///
+/// In a [`read_only`](RadioGroup::read_only) group, the keys move the highlight but don't change
+/// the selection. A [`disabled`](RadioGroup::disabled) group ignores all the keys, isn't drawn
+/// focused and is restyled with the [`disabled_style`](RadioGroup::disabled_style).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{input::Key, prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut RadioGroupState) {
+/// let sizes = RadioGroup::new(vec!["Small", "Medium", "Large"]).direction(Direction::Horizontal);
+/// frame.render_stateful_widget(sizes, Rect::new(0, 0, 40, 1), state);
+/// # }
+/// let mut state = RadioGroupState::default().with_selected(Some(0));
+/// state.set_focused(true);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RadioGroup<'a> {
+    options: Vec<Line<'a>>,
+    direction: Direction,
+    spacing: u16,
+    symbols: checkbox::Set,
+    style: Style,
+    focused_style: Style,
+    read_only: bool,
+    disabled: bool,
+    disabled_style: Style,
+}
+
+impl<'a> RadioGroup<'a> {
+    /// Creates a new group with the given options.
+    pub fn new<T>(options: Vec<T>) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        Self {
+            options: options.into_iter().map(Into::into).collect(),
+            direction: Direction::Vertical,
+            spacing: 2,
+            symbols: checkbox::PARENTHESES,
+            style: Style::default(),
+            focused_style: DEFAULT_FOCUSED_STYLE,
+            read_only: false,
+            disabled: false,
+            disabled_style: DEFAULT_DISABLED_STYLE,
+        }
+    }
+
+    /// Sets whether the options are laid out in a column or in a row. Defaults to
+    /// [`Direction::Vertical`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the number of columns between two options laid out in a row. Defaults to 2.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the symbols of the selected and unselected options. Defaults to
+    /// [`checkbox::PARENTHESES`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn symbols(mut self, symbols: checkbox::Set) -> Self {
+        self.symbols = symbols;
+        self
+    }
+
+    /// Sets the style of the group.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style patched on top of the style of the highlighted option while the group has
+    /// the focus. Defaults to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_style(mut self, style: Style) -> Self {
+        self.focused_style = style;
+        self
+    }
+
+    /// Makes the group read-only: the keys still move the highlight, but don't change the
+    /// selected option.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Disables the group: it ignores the keys, isn't drawn focused and is restyled with the
+    /// [`disabled_style`](Self::disabled_style).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the style patched on top of the style of the group while it is
+    /// [`disabled`](Self::disabled). Defaults to dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = style;
+        self
+    }
+}
+
+impl<'a> Styled for RadioGroup<'a> {
+    type Item = RadioGroup<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// The state of a [`RadioGroup`].
+///
+/// It holds the index of the selected option, the index of the highlighted option that is
+/// selected by `Space` and `Enter`, and whether the group has the focus. The number of options is
+/// known once the group is first rendered.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct RadioGroupState {
+    selected: Option<usize>,
+    highlighted: usize,
+    focused: bool,
+    /// Whether the keys don't change the selection, set when rendering
+    read_only: bool,
+    /// Whether all the keys are ignored, set when rendering
+    disabled: bool,
+    len: usize,
+}
+
+impl RadioGroupState {
+    /// Selects the option at the given index, also highlighting it.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_selected(mut self, selected: Option<usize>) -> Self {
+        self.select(selected);
+        self
+    }
+
+    /// Returns the index of the selected option.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Selects the option at the given index, or no option with `None`. The selected option is
+    /// also highlighted.
+    pub fn select(&mut self, index: Option<usize>) {
+        RedrawRequest::request_if(self.selected != index);
+        self.selected = index;
+        if let Some(index) = index {
+            self.highlighted = index;
+        }
+    }
+
+    /// Returns the index of the highlighted option.
+    pub fn highlighted(&self) -> usize {
+        self.highlighted
+    }
+
+    /// Returns `true` if the group has the focus.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Sets whether the group has the focus.
+    pub fn set_focused(&mut self, focused: bool) {
+        RedrawRequest::request_if(self.focused != focused);
+        self.focused = focused;
+    }
+
+    /// Returns `true` if the group was [read-only](RadioGroup::read_only) during the last render.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns `true` if the group was [disabled](RadioGroup::disabled) during the last render.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Handles a key event and returns `true` if it was handled.
+    ///
+    /// See [`RadioGroup`] for the keys handled by a focused group.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if !self.focused || self.disabled || key.kind == KeyEventKind::Release || self.len == 0 {
+            return false;
+        }
+        let highlighted = match key.key {
+            Key::Up | Key::Left => self.highlighted.saturating_sub(1),
+            Key::Down | Key::Right => (self.highlighted + 1).min(self.len - 1),
+            Key::Enter | Key::Char(' ') if !self.read_only => {
+                self.select(Some(self.highlighted));
+                return true;
+            }
+            _ => return false,
+        };
+        RedrawRequest::request_if(self.highlighted != highlighted);
+        self.highlighted = highlighted;
+        true
+    }
+}
+
+impl Focusable for RadioGroupState {
+    fn set_focused(&mut self, focused: bool) {
+        RadioGroupState::set_focused(self, focused);
+    }
+
+    fn is_disabled(&self) -> bool {
+        RadioGroupState::is_disabled(self)
+    }
+}
+
+impl<'a> StatefulWidget for RadioGroup<'a> {
+    type State = RadioGroupState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.len = self.options.len();
+        state.highlighted = state.highlighted.min(state.len.saturating_sub(1));
+        state.read_only = self.read_only;
+        state.disabled = self.disabled;
+        if self.disabled {
+            buf.set_style(area, self.style.patch(self.disabled_style));
+        } else {
+            buf.set_style(area, self.style);
+        }
+        let (mut x, mut y) = (area.x, area.y);
+        for (i, option) in self.options.into_iter().enumerate() {
+            if x >= area.right() || y >= area.bottom() {
+                break;
+            }
+            let line = option_line(self.symbols, state.selected == Some(i), option);
+            let width = (line.width() as u16).min(area.right() - x);
+            if state.focused && !state.disabled && i == state.highlighted {
+                buf.set_style(Rect::new(x, y, width, 1), self.focused_style);
+            }
+            buf.set_line(x, y, &line, width);
+            match self.direction {
+                Direction::Horizontal => x = x.saturating_add(width).saturating_add(self.spacing),
+                Direction::Vertical => y += 1,
+            }
+        }
+    }
+}
+
+impl<'a> Widget for RadioGroup<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = RadioGroupState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+/// Returns the line of an option: its symbol followed by its label.
+fn option_line(symbols: checkbox::Set, checked: bool, label: Line) -> Line {
+    let symbol = if checked {
+        symbols.checked
+    } else {
+        symbols.unchecked
+    };
+    let mut spans = vec![Span::raw(symbol), Span::raw(" ")];
+    spans.extend(label.spans);
+    Line::from(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    #[test]
+    fn checkbox_render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+        let mut state = CheckboxState::default().with_checked(true);
+        StatefulWidget::render(Checkbox::new("Save"), buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["[x] Save    "]));
+
+        state.set_checked(false);
+        state.set_focused(true);
+        let mut buf = Buffer::empty(buf.area);
+        let checkbox = Checkbox::new("Save").symbols(checkbox::BALLOT_BOX);
+        StatefulWidget::render(checkbox, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["☐ Save      "]);
+        expected.set_style(buf.area, DEFAULT_FOCUSED_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn checkbox_handle_key() {
+        let mut state = CheckboxState::default();
+        assert!(!state.handle_key(Key::Char(' ').into()));
+        assert!(!state.is_checked());
+        state.set_focused(true);
+        assert!(state.handle_key(Key::Char(' ').into()));
+        assert!(state.is_checked());
+        assert!(state.handle_key(Key::Enter.into()));
+        assert!(!state.is_checked());
+        assert!(!state.handle_key(Key::Char('x').into()));
+    }
+
+    #[test]
+    fn checkbox_handle_mouse() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
+        let mut state = CheckboxState::default();
+        let area = Rect::new(0, 1, 12, 1);
+        StatefulWidget::render(Checkbox::new("Save"), area, &mut buf, &mut state);
+        let click =
+            |column, row| MouseEvent::new(MouseEventKind::Down(MouseButton::Left), column, row);
+        assert!(state.handle_mouse(click(6, 1)));
+        assert!(state.is_checked());
+        assert!(!state.handle_mouse(click(6, 0)));
+        let release = MouseEvent::new(MouseEventKind::Up(MouseButton::Left), 6, 1);
+        assert!(!state.handle_mouse(release));
+        assert!(state.handle_mouse(click(0, 1)));
+        assert!(!state.is_checked());
+    }
+
+    #[test]
+    fn checkbox_read_only_and_disabled() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+        let mut state = CheckboxState::default().with_focused(true);
+        let checkbox = Checkbox::new("Save").read_only(true);
+        StatefulWidget::render(checkbox, buf.area, &mut buf, &mut state);
+        assert!(state.is_read_only() && !state.is_disabled());
+        let click = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 0, 0);
+        assert!(!state.handle_key(Key::Char(' ').into()));
+        assert!(!state.handle_mouse(click));
+        assert!(!state.is_checked());
+
+        let mut buf = Buffer::empty(buf.area);
+        let checkbox = Checkbox::new("Save").disabled(true);
+        StatefulWidget::render(checkbox, buf.area, &mut buf, &mut state);
+        assert!(state.is_disabled() && Focusable::is_disabled(&state));
+        assert!(!state.handle_key(Key::Enter.into()));
+        assert!(!state.handle_mouse(click));
+        let mut expected = Buffer::with_lines(vec!["[ ] Save    "]);
+        expected.set_style(buf.area, DEFAULT_DISABLED_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn radio_group_render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+        let mut state = RadioGroupState::default().with_selected(Some(1));
+        let group = RadioGroup::new(vec!["Small", "Medium", "Large"]);
+        StatefulWidget::render(group, buf.area, &mut buf, &mut state);
+        let expected = Buffer::with_lines(vec!["( ) Small   ", "(*) Medium  ", "( ) Large   "]);
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn radio_group_render_horizontal() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 1));
+        let mut state = RadioGroupState::default();
+        state.set_focused(true);
+        let group = RadioGroup::new(vec!["On", "Off", "Auto"]).direction(Direction::Horizontal);
+        StatefulWidget::render(group, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["( ) On  ( ) Off "]);
+        expected.set_style(Rect::new(0, 0, 6, 1), DEFAULT_FOCUSED_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn radio_group_handle_key() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+        let mut state = RadioGroupState::default();
+        let group = RadioGroup::new(vec!["Small", "Medium", "Large"]);
+        StatefulWidget::render(group, buf.area, &mut buf, &mut state);
+        assert!(!state.handle_key(Key::Down.into()));
+        state.set_focused(true);
+        assert!(state.handle_key(Key::Down.into()));
+        assert!(state.handle_key(Key::Down.into()));
+        assert!(state.handle_key(Key::Down.into()));
+        assert_eq!(state.highlighted(), 2);
+        assert_eq!(state.selected(), None);
+        assert!(state.handle_key(Key::Char(' ').into()));
+        assert_eq!(state.selected(), Some(2));
+        assert!(state.handle_key(Key::Up.into()));
+        assert!(state.handle_key(Key::Enter.into()));
+        assert_eq!(state.selected(), Some(1));
+        assert!(!state.handle_key(Key::Tab.into()));
+    }
+
+    #[test]
+    fn radio_group_read_only_and_disabled() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+        let mut state = RadioGroupState::default().with_selected(Some(0));
+        state.set_focused(true);
+        let group = RadioGroup::new(vec!["Small", "Medium", "Large"]);
+        StatefulWidget::render(
+            group.clone().read_only(true),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        assert!(state.is_read_only());
+        assert!(state.handle_key(Key::Down.into()));
+        assert!(!state.handle_key(Key::Enter.into()));
+        assert_eq!((state.highlighted(), state.selected()), (1, Some(0)));
+
+        let mut buf = Buffer::empty(buf.area);
+        StatefulWidget::render(group.disabled(true), buf.area, &mut buf, &mut state);
+        assert!(state.is_disabled() && !state.is_read_only());
+        assert!(!state.handle_key(Key::Up.into()));
+        assert_eq!(state.highlighted(), 1);
+        let mut expected = Buffer::with_lines(vec!["(*) Small   ", "( ) Medium  ", "( ) Large   "]);
+        expected.set_style(buf.area, DEFAULT_DISABLED_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+}
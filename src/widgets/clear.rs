@@ -2,6 +2,9 @@ use crate::{buffer::Buffer, layout::Rect, widgets::Widget};
 
 /// A widget to clear/reset a certain area to allow overdrawing (e.g. for popups).
 ///
+/// The [protected](crate::buffer::Cell::set_protected) cells are kept, so that persistent chrome
+/// drawn by a parent widget, such as a status bar, survives the popups of its children.
+///
 /// This widget **cannot be used to clear the terminal on the first render** as `ratatui` assumes
 /// the render area is empty. Use [`crate::Terminal::clear`] instead.
 ///
@@ -26,11 +29,7 @@ pub struct Clear;
 
 impl Widget for Clear {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        for x in area.left()..area.right() {
-            for y in area.top()..area.bottom() {
-                buf.get_mut(x, y).reset();
-            }
-        }
+        buf.clear_unprotected(area);
     }
 }
 
@@ -57,4 +56,14 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn render_keeps_protected_cells() {
+        let mut buf = Buffer::with_lines(vec!["xxxxx"; 3]);
+        buf.protect(Rect::new(0, 1, 5, 1));
+        Clear.render(Rect::new(1, 0, 3, 3), &mut buf);
+        let mut expected = Buffer::with_lines(vec!["x   x", "xxxxx", "x   x"]);
+        expected.protect(Rect::new(0, 1, 5, 1));
+        assert_buffer_eq!(buf, expected);
+    }
 }
@@ -0,0 +1,270 @@
+#![deny(missing_docs)]
+use std::time::Duration;
+
+use crate::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Style, Styled},
+    widgets::{Block, Widget},
+};
+
+/// The height of the digits in big mode.
+const BIG_HEIGHT: u16 = 5;
+
+/// Returns the rows of the glyph of the given character in big mode.
+fn big_glyph(c: char) -> [&'static str; BIG_HEIGHT as usize] {
+    match c {
+        '0' => ["███", "█ █", "█ █", "█ █", "███"],
+        '1' => ["  █", "  █", "  █", "  █", "  █"],
+        '2' => ["███", "  █", "███", "█  ", "███"],
+        '3' => ["███", "  █", "███", "  █", "███"],
+        '4' => ["█ █", "█ █", "███", "  █", "  █"],
+        '5' => ["███", "█  ", "███", "  █", "███"],
+        '6' => ["███", "█  ", "███", "█ █", "███"],
+        '7' => ["███", "  █", "  █", "  █", "  █"],
+        '8' => ["███", "█ █", "███", "█ █", "███"],
+        '9' => ["███", "█ █", "███", "  █", "███"],
+        ':' => [" ", "█", " ", "█", " "],
+        _ => [" "; BIG_HEIGHT as usize],
+    }
+}
+
+/// A widget displaying a duration as `HH:MM:SS`, e.g. for a stopwatch or a countdown.
+///
+/// The clock doesn't keep track of time itself: pass the elapsed [`Duration`] on every frame.
+/// In [`countdown`](Clock::countdown) mode the clock shows the time remaining until the given
+/// total instead. The digits are drawn either as normal text or, with [`big`](Clock::big), with
+/// a 5 rows high block font. All digits have the same width, so the clock doesn't jitter when
+/// the time changes.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, elapsed: Duration) {
+/// let clock = Clock::new(elapsed)
+///     .countdown(Duration::from_secs(25 * 60))
+///     .big(true)
+///     .blink_separator(true)
+///     .alignment(Alignment::Center);
+/// frame.render_widget(clock, frame.size());
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Clock<'a> {
+    elapsed: Duration,
+    countdown: Option<Duration>,
+    block: Option<Block<'a>>,
+    style: Style,
+    alignment: Alignment,
+    big: bool,
+    blink_separator: bool,
+}
+
+impl<'a> Clock<'a> {
+    /// Creates a new clock showing the given elapsed time.
+    pub fn new(elapsed: Duration) -> Self {
+        Self {
+            elapsed,
+            ..Self::default()
+        }
+    }
+
+    /// Shows the time remaining until `total` instead of the elapsed time. The clock stops at
+    /// zero.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn countdown(mut self, total: Duration) -> Self {
+        self.countdown = Some(total);
+        self
+    }
+
+    /// Surrounds the clock with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the clock.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the horizontal alignment of the clock in its area. Defaults to the left.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Draws the digits with a 5 rows high block font instead of normal text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn big(mut self, big: bool) -> Self {
+        self.big = big;
+        self
+    }
+
+    /// Hides the `:` separators during the second half of every second, so that they blink when
+    /// the clock is rendered regularly.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn blink_separator(mut self, blink: bool) -> Self {
+        self.blink_separator = blink;
+        self
+    }
+
+    /// Returns the displayed duration, i.e. the elapsed or remaining time.
+    pub fn displayed(&self) -> Duration {
+        match self.countdown {
+            Some(total) => total.saturating_sub(self.elapsed),
+            None => self.elapsed,
+        }
+    }
+
+    /// Returns the text shown by the clock, e.g. `01:02:03`.
+    pub fn text(&self) -> String {
+        let displayed = self.displayed();
+        // round a countdown up, so that it shows 00:00:00 only when it is over
+        let secs = if self.countdown.is_some() && displayed.subsec_nanos() > 0 {
+            displayed.as_secs() + 1
+        } else {
+            displayed.as_secs()
+        };
+        let separator = if self.blink_separator && self.elapsed.subsec_millis() >= 500 {
+            ' '
+        } else {
+            ':'
+        };
+        format!(
+            "{:02}{separator}{:02}{separator}{:02}",
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60
+        )
+    }
+}
+
+impl<'a> Styled for Clock<'a> {
+    type Item = Clock<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl<'a> Widget for Clock<'a> {
+    fn render(mut self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        if area.is_empty() {
+            return;
+        }
+
+        let text = self.text();
+        let lines = if self.big {
+            // glyphs are separated by one column
+            let glyphs = text.chars().map(big_glyph).collect::<Vec<_>>();
+            (0..usize::from(BIG_HEIGHT))
+                .map(|row| {
+                    glyphs
+                        .iter()
+                        .map(|glyph| glyph[row])
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .collect()
+        } else {
+            vec![text]
+        };
+        for (line, y) in lines.iter().zip(area.top()..area.bottom()) {
+            let width = line.chars().count() as u16;
+            let x = match self.alignment {
+                Alignment::Left => 0,
+                Alignment::Center => area.width.saturating_sub(width) / 2,
+                Alignment::Right => area.width.saturating_sub(width),
+            };
+            buf.set_stringn(
+                area.x + x,
+                y,
+                line,
+                usize::from(area.width - x),
+                Style::default(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    fn render(clock: Clock, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        clock.render(buf.area, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(Clock::new(Duration::from_secs(3723)).text(), "01:02:03");
+        assert_eq!(Clock::new(Duration::from_secs(360_000)).text(), "100:00:00");
+    }
+
+    #[test]
+    fn countdown() {
+        let clock = Clock::new(Duration::from_millis(1500)).countdown(Duration::from_secs(60));
+        assert_eq!(clock.displayed(), Duration::from_millis(58500));
+        assert_eq!(clock.text(), "00:00:59");
+        let clock = Clock::new(Duration::from_secs(90)).countdown(Duration::from_secs(60));
+        assert_eq!(clock.text(), "00:00:00");
+    }
+
+    #[test]
+    fn blink_separator() {
+        let clock = |millis| Clock::new(Duration::from_millis(millis)).blink_separator(true);
+        assert_eq!(clock(2_400).text(), "00:00:02");
+        assert_eq!(clock(2_600).text(), "00 00 02");
+    }
+
+    #[test]
+    fn render_alignment() {
+        let clock = Clock::new(Duration::from_secs(61)).alignment(Alignment::Center);
+        assert_buffer_eq!(
+            render(clock, 12, 1),
+            Buffer::with_lines(vec!["  00:01:01  "])
+        );
+        let clock = Clock::new(Duration::from_secs(61)).alignment(Alignment::Right);
+        assert_buffer_eq!(render(clock, 10, 1), Buffer::with_lines(vec!["  00:01:01"]));
+    }
+
+    #[test]
+    fn render_big() {
+        let clock = Clock::new(Duration::from_secs(4 * 3600 + 7 * 60 + 29)).big(true);
+        assert_buffer_eq!(
+            render(clock, 27, 5),
+            Buffer::with_lines(vec![
+                "███ █ █   ███ ███   ███ ███",
+                "█ █ █ █ █ █ █   █ █   █ █ █",
+                "█ █ ███   █ █   █   ███ ███",
+                "█ █   █ █ █ █   █ █ █     █",
+                "███   █   ███   █   ███ ███",
+            ])
+        );
+    }
+}
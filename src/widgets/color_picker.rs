@@ -0,0 +1,830 @@
+#![deny(missing_docs)]
+use crate::{
+    buffer::Buffer,
+    input::{
+        Focusable, Input, Key, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent,
+        MouseEventKind,
+    },
+    layout::{Position, Rect},
+    style::{Color, Modifier, Style, Styled},
+    terminal::RedrawRequest,
+    widgets::{Block, CursorProvider, StatefulWidget, TextInput, TextInputState, Widget},
+};
+
+const DEFAULT_SELECTED_TAB_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_SELECTED_SWATCH_STYLE: Style = Style::new().add_modifier(Modifier::BOLD);
+const DEFAULT_DISABLED_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+
+/// The 16 ANSI colors, in the order of their indices in the 256 colors palette.
+const ANSI_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+/// Width of the filled block previewing the color, right of the palette.
+const PREVIEW_WIDTH: u16 = 6;
+
+/// The palettes of a [`ColorPicker`], shown in its tabs.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ColorPickerMode {
+    /// The 16 ANSI colors, whose actual colors depend on the theme of the terminal.
+    #[default]
+    Ansi,
+    /// The 256 colors palette of `xterm`, whose first 16 colors are the ANSI colors.
+    Indexed,
+    /// A 24-bit color entered as an hexadecimal string, e.g. `#FF8000`.
+    Rgb,
+}
+
+impl ColorPickerMode {
+    const ALL: [Self; 3] = [Self::Ansi, Self::Indexed, Self::Rgb];
+
+    /// Returns the label of the tab of the mode.
+    fn label(self) -> &'static str {
+        match self {
+            Self::Ansi => " ANSI ",
+            Self::Indexed => " 256 ",
+            Self::Rgb => " RGB ",
+        }
+    }
+
+    /// Returns the color of the swatch at the given index of the palette of the mode.
+    fn swatch(self, index: usize) -> Color {
+        match self {
+            Self::Indexed => Color::Indexed(index as u8),
+            _ => ANSI_COLORS[index],
+        }
+    }
+
+    /// Returns the number of columns, the width of each swatch and the number of colors of the
+    /// palette, or `None` for the hexadecimal input.
+    fn grid(self) -> Option<(usize, u16, usize)> {
+        match self {
+            Self::Ansi => Some((8, 3, 16)),
+            Self::Indexed => Some((16, 2, 256)),
+            Self::Rgb => None,
+        }
+    }
+}
+
+/// An input choosing a color among the 16 ANSI colors, the 256 colors palette, or entered as an
+/// hexadecimal RGB string.
+///
+/// The first row shows a tab for each [`ColorPickerMode`], followed by the palette of the
+/// selected mode, or the hexadecimal input in [`Rgb`](ColorPickerMode::Rgb) mode. The chosen
+/// color is previewed as a filled block right of the palette, and returned by
+/// [`ColorPickerState::color`]. The keyboard and the mouse are handled by the state:
+/// - `Ctrl+Left` and `Ctrl+Right` switch to the previous and next modes, as well as clicking
+///   their tabs.
+/// - The arrows move the selection in the palettes, `Home` and `End` select the first and last
+///   colors, and clicking a swatch selects it.
+/// - In [`Rgb`](ColorPickerMode::Rgb) mode, the keys edit the hexadecimal string, which only
+///   accepts hexadecimal digits and `#`.
+///
+/// The palette of 256 colors is 16 rows tall, and scrolls to keep the selected color visible in
+/// a smaller area.
+///
+/// A [`read_only`](ColorPicker::read_only) picker keeps its mode and color, only moving the
+/// cursor of the hexadecimal input. A [`disabled`](ColorPicker::disabled) picker ignores all the
+/// events and is restyled with the [`disabled_style`](ColorPicker::disabled_style).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     input::{Input, Key, KeyEvent, KeyModifiers},
+///     prelude::*,
+///     widgets::*,
+/// };
+///
+/// # fn ui(frame: &mut Frame, state: &mut ColorPickerState) {
+/// let picker = ColorPicker::new().block(Block::default().borders(Borders::ALL).title("Accent"));
+/// frame.render_stateful_widget(picker, Rect::new(0, 0, 34, 5), state);
+/// # }
+/// let mut state = ColorPickerState::default().with_focused(true);
+/// state.handle_key(Key::Right.into());
+/// assert_eq!(state.color(), Some(Color::Red));
+///
+/// state.handle_key(KeyEvent::new(Key::Right, KeyModifiers::CONTROL));
+/// state.handle_key(KeyEvent::new(Key::Right, KeyModifiers::CONTROL));
+/// state.handle_paste("#ff8000");
+/// assert_eq!(state.color(), Some(Color::Rgb(255, 128, 0)));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ColorPicker<'a> {
+    block: Option<Block<'a>>,
+    style: Style,
+    selected_tab_style: Style,
+    selected_swatch_style: Style,
+    read_only: bool,
+    disabled: bool,
+    disabled_style: Style,
+}
+
+impl<'a> Default for ColorPicker<'a> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            style: Style::default(),
+            selected_tab_style: DEFAULT_SELECTED_TAB_STYLE,
+            selected_swatch_style: DEFAULT_SELECTED_SWATCH_STYLE,
+            read_only: false,
+            disabled: false,
+            disabled_style: DEFAULT_DISABLED_STYLE,
+        }
+    }
+}
+
+impl<'a> ColorPicker<'a> {
+    /// Creates a new color picker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Surrounds the picker with a block.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the picker.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the tab of the current mode. Defaults to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selected_tab_style(mut self, style: Style) -> Self {
+        self.selected_tab_style = style;
+        self
+    }
+
+    /// Sets the style patched on the `[]` marking the selected swatch, drawn in black or white
+    /// depending on the brightness of the swatch. Defaults to bold text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selected_swatch_style(mut self, style: Style) -> Self {
+        self.selected_swatch_style = style;
+        self
+    }
+
+    /// Makes the picker read-only: the keys and the mouse change neither the mode nor the
+    /// color, and the hexadecimal input is [read-only](TextInput::read_only).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Disables the picker: it ignores all the events and is restyled with the
+    /// [`disabled_style`](Self::disabled_style).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the style patched over the whole picker while it is [`disabled`](Self::disabled).
+    /// Defaults to dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = style;
+        self
+    }
+}
+
+impl<'a> Styled for ColorPicker<'a> {
+    type Item = ColorPicker<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// The state of a [`ColorPicker`].
+///
+/// It holds the current mode and the color selected in each of them, so that switching modes
+/// back and forth keeps the selections.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ColorPickerState {
+    mode: ColorPickerMode,
+    /// Index of the selected ANSI color
+    ansi: usize,
+    indexed: u8,
+    hex: TextInputState,
+    focused: bool,
+    /// Whether the mode and the color can't be changed, set when rendering
+    read_only: bool,
+    /// Whether the picker ignores all the events, set when rendering
+    disabled: bool,
+    /// First row of the 256 colors palette shown during the last render
+    indexed_offset: usize,
+    /// Area inside the block during the last render, where the mouse selects colors
+    area: Rect,
+}
+
+impl Default for ColorPickerState {
+    fn default() -> Self {
+        let mut hex = TextInputState::default()
+            .with_max_length(7)
+            .with_filter(|c| c == '#' || c.is_ascii_hexdigit());
+        hex.set_focused(false);
+        Self {
+            mode: ColorPickerMode::default(),
+            ansi: 0,
+            indexed: 0,
+            hex,
+            focused: false,
+            read_only: false,
+            disabled: false,
+            indexed_offset: 0,
+            area: Rect::default(),
+        }
+    }
+}
+
+impl ColorPickerState {
+    /// Selects the given color, see [`set_color`](Self::set_color).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.set_color(color);
+        self
+    }
+
+    /// Sets whether the picker has the focus.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_focused(mut self, focused: bool) -> Self {
+        self.set_focused(focused);
+        self
+    }
+
+    /// Returns the color chosen in the current mode, or `None` if the hexadecimal string of the
+    /// [`Rgb`](ColorPickerMode::Rgb) mode is not a valid color.
+    pub fn color(&self) -> Option<Color> {
+        match self.mode {
+            ColorPickerMode::Ansi => Some(ANSI_COLORS[self.ansi]),
+            ColorPickerMode::Indexed => Some(Color::Indexed(self.indexed)),
+            ColorPickerMode::Rgb => {
+                let hex = self
+                    .hex
+                    .value()
+                    .strip_prefix('#')
+                    .unwrap_or(self.hex.value());
+                if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return None;
+                }
+                let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap_or(0);
+                Some(Color::Rgb(channel(0), channel(2), channel(4)))
+            }
+        }
+    }
+
+    /// Selects the given color, switching to the mode where it is found. [`Color::Reset`] selects
+    /// the first ANSI color.
+    pub fn set_color(&mut self, color: Color) {
+        match color {
+            Color::Indexed(index) => {
+                self.set_mode(ColorPickerMode::Indexed);
+                self.select(usize::from(index));
+            }
+            Color::Rgb(..) => {
+                self.set_mode(ColorPickerMode::Rgb);
+                self.hex.set_value(color.to_string());
+            }
+            _ => {
+                self.set_mode(ColorPickerMode::Ansi);
+                let index = ANSI_COLORS.iter().position(|&c| c == color);
+                self.select(index.unwrap_or_default());
+            }
+        }
+    }
+
+    /// Returns the current mode.
+    pub fn mode(&self) -> ColorPickerMode {
+        self.mode
+    }
+
+    /// Switches to the given mode, keeping the color selected in each mode.
+    pub fn set_mode(&mut self, mode: ColorPickerMode) {
+        RedrawRequest::request_if(self.mode != mode);
+        self.mode = mode;
+        self.hex
+            .set_focused(self.focused && mode == ColorPickerMode::Rgb);
+    }
+
+    /// Returns the state of the hexadecimal string of the [`Rgb`](ColorPickerMode::Rgb) mode.
+    pub fn hex_input(&self) -> &TextInputState {
+        &self.hex
+    }
+
+    /// Returns `true` if the picker has the focus.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Sets whether the picker has the focus.
+    pub fn set_focused(&mut self, focused: bool) {
+        RedrawRequest::request_if(self.focused != focused);
+        self.focused = focused;
+        self.hex
+            .set_focused(focused && self.mode == ColorPickerMode::Rgb);
+    }
+
+    /// Returns `true` if the picker was [read-only](ColorPicker::read_only) during the last
+    /// render.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns `true` if the picker was [disabled](ColorPicker::disabled) during the last render.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Selects the color at the given index in the palette of the current mode, clamped to the
+    /// last color. Returns `true` if the selection changed.
+    fn select(&mut self, index: usize) -> bool {
+        let changed = match self.mode {
+            ColorPickerMode::Ansi => {
+                let index = index.min(ANSI_COLORS.len() - 1);
+                std::mem::replace(&mut self.ansi, index) != index
+            }
+            ColorPickerMode::Indexed => {
+                let index = index.min(usize::from(u8::MAX)) as u8;
+                std::mem::replace(&mut self.indexed, index) != index
+            }
+            ColorPickerMode::Rgb => false,
+        };
+        RedrawRequest::request_if(changed)
+    }
+
+    /// Returns the index of the color selected in the palette of the current mode.
+    fn selected(&self) -> usize {
+        match self.mode {
+            ColorPickerMode::Ansi => self.ansi,
+            ColorPickerMode::Indexed => usize::from(self.indexed),
+            ColorPickerMode::Rgb => 0,
+        }
+    }
+
+    /// Switches to the next or the previous mode, wrapping around.
+    fn cycle_mode(&mut self, forward: bool) {
+        let index = ColorPickerMode::ALL
+            .iter()
+            .position(|&mode| mode == self.mode)
+            .unwrap_or_default();
+        let count = ColorPickerMode::ALL.len();
+        let index = if forward {
+            (index + 1) % count
+        } else {
+            (index + count - 1) % count
+        };
+        self.set_mode(ColorPickerMode::ALL[index]);
+    }
+
+    /// Returns the mode whose tab is at the given position during the last render.
+    fn tab_at(&self, position: Position) -> Option<ColorPickerMode> {
+        if position.y != self.area.y || !self.area.contains(position) {
+            return None;
+        }
+        let mut x = self.area.x;
+        ColorPickerMode::ALL.into_iter().find(|mode| {
+            x += mode.label().len() as u16;
+            position.x < x
+        })
+    }
+
+    /// Returns the index of the swatch at the given position during the last render.
+    fn swatch_at(&self, position: Position) -> Option<usize> {
+        let (columns, swatch_width, count) = self.mode.grid()?;
+        if !self.area.contains(position) || position.y == self.area.y {
+            return None;
+        }
+        let column = usize::from((position.x - self.area.x) / swatch_width);
+        let mut row = usize::from(position.y - self.area.y - 1);
+        if self.mode == ColorPickerMode::Indexed {
+            row += self.indexed_offset;
+        }
+        let index = row * columns + column;
+        (column < columns && index < count).then_some(index)
+    }
+}
+
+impl Focusable for ColorPickerState {
+    fn set_focused(&mut self, focused: bool) {
+        ColorPickerState::set_focused(self, focused);
+    }
+
+    fn is_disabled(&self) -> bool {
+        ColorPickerState::is_disabled(self)
+    }
+}
+
+impl Input for ColorPickerState {
+    /// Handles the keys of a focused picker, see [`ColorPicker`].
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if !self.focused || self.disabled || key.kind == KeyEventKind::Release {
+            return false;
+        }
+        if key.modifiers == KeyModifiers::CONTROL && !self.read_only {
+            match key.key {
+                Key::Left => {
+                    self.cycle_mode(false);
+                    return true;
+                }
+                Key::Right => {
+                    self.cycle_mode(true);
+                    return true;
+                }
+                _ => {}
+            }
+        }
+        let Some((columns, _, count)) = self.mode.grid() else {
+            return self.hex.handle_key(key);
+        };
+        if self.read_only {
+            return false;
+        }
+        let selected = self.selected();
+        match key.key {
+            Key::Left if selected % columns > 0 => self.select(selected - 1),
+            Key::Right if selected % columns < columns - 1 => self.select(selected + 1),
+            Key::Up if selected >= columns => self.select(selected - columns),
+            Key::Down if selected + columns < count => self.select(selected + columns),
+            Key::Home => self.select(0),
+            Key::End => self.select(count - 1),
+            _ => false,
+        }
+    }
+
+    /// Inserts the pasted text in the hexadecimal string, in [`Rgb`](ColorPickerMode::Rgb) mode.
+    fn handle_paste(&mut self, text: &str) -> bool {
+        self.focused
+            && !self.read_only
+            && !self.disabled
+            && self.mode == ColorPickerMode::Rgb
+            && self.hex.handle_paste(text)
+    }
+
+    /// Switches the mode when its tab is clicked with the left button, and selects the clicked
+    /// swatch.
+    fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        if self.disabled {
+            return false;
+        }
+        if mouse.kind == MouseEventKind::Down(MouseButton::Left) && !self.read_only {
+            if let Some(mode) = self.tab_at(mouse.position()) {
+                self.set_mode(mode);
+                return true;
+            }
+            if let Some(index) = self.swatch_at(mouse.position()) {
+                self.select(index);
+                return true;
+            }
+        }
+        self.mode == ColorPickerMode::Rgb && self.hex.handle_mouse(mouse)
+    }
+}
+
+impl CursorProvider for ColorPickerState {
+    fn cursor_position(&self) -> Option<Position> {
+        self.hex.cursor_position()
+    }
+}
+
+/// Returns `true` if the color is bright enough to draw black text on it, using the colors of the
+/// default palette of `xterm` for the indexed colors.
+fn is_light(color: Color) -> bool {
+    let (r, g, b) = match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(index @ 16..=231) => {
+            let level = |i: u8| if i == 0 { 0 } else { 55 + i * 40 };
+            let index = index - 16;
+            (level(index / 36), level(index / 6 % 6), level(index % 6))
+        }
+        Color::Indexed(index @ 232..) => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+        Color::Indexed(index) => return is_light(ANSI_COLORS[usize::from(index)]),
+        Color::Gray
+        | Color::Green
+        | Color::Yellow
+        | Color::Cyan
+        | Color::LightRed
+        | Color::LightGreen
+        | Color::LightYellow
+        | Color::LightBlue
+        | Color::LightMagenta
+        | Color::LightCyan
+        | Color::White => return true,
+        _ => return false,
+    };
+    u32::from(r) * 299 + u32::from(g) * 587 + u32::from(b) * 114 > 140_000
+}
+
+impl<'a> StatefulWidget for ColorPicker<'a> {
+    type State = ColorPickerState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.read_only = self.read_only;
+        state.disabled = self.disabled;
+        buf.set_style(area, self.style);
+        let inner = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        state.area = inner;
+        if !inner.is_empty() {
+            self.render_picker(inner, buf, state);
+        }
+        if self.disabled {
+            buf.set_style(area, self.disabled_style);
+        }
+    }
+}
+
+impl<'a> ColorPicker<'a> {
+    fn render_picker(&self, area: Rect, buf: &mut Buffer, state: &mut ColorPickerState) {
+        let mut x = area.x;
+        for mode in ColorPickerMode::ALL {
+            if x >= area.right() {
+                break;
+            }
+            let style = if mode == state.mode {
+                self.selected_tab_style
+            } else {
+                Style::default()
+            };
+            (x, _) = buf.set_stringn(
+                x,
+                area.y,
+                mode.label(),
+                usize::from(area.right() - x),
+                style,
+            );
+        }
+
+        let body = Rect {
+            y: area.y + 1,
+            height: area.height - 1,
+            ..area
+        };
+        if body.is_empty() {
+            return;
+        }
+        let palette_width = match state.mode.grid() {
+            Some((columns, swatch_width, count)) => {
+                let rows = count / columns;
+                // scroll the palette so that the selected row is visible
+                let selected_row = state.selected() / columns;
+                let visible = usize::from(body.height);
+                let offset = state.indexed_offset.min(rows.saturating_sub(visible));
+                state.indexed_offset = offset
+                    .min(selected_row)
+                    .max((selected_row + 1).saturating_sub(visible));
+                let offset = if state.mode == ColorPickerMode::Indexed {
+                    state.indexed_offset
+                } else {
+                    0
+                };
+                for index in offset * columns..count {
+                    let (row, column) = (index / columns - offset, index % columns);
+                    let x = body.x + column as u16 * swatch_width;
+                    if row >= visible || x + swatch_width > body.right() {
+                        continue;
+                    }
+                    let color = state.mode.swatch(index);
+                    let y = body.y + row as u16;
+                    let swatch = Rect::new(x, y, swatch_width, 1);
+                    buf.set_style(swatch, Style::new().bg(color));
+                    if index == state.selected() {
+                        let marker = if swatch_width > 2 { "[ ]" } else { "[]" };
+                        let fg = if is_light(color) {
+                            Color::Black
+                        } else {
+                            Color::White
+                        };
+                        let style = Style::new().fg(fg).patch(self.selected_swatch_style);
+                        buf.set_string(x, y, marker, style);
+                    }
+                }
+                columns as u16 * swatch_width
+            }
+            None => {
+                let input = TextInput::new()
+                    .placeholder("#RRGGBB")
+                    .read_only(self.read_only)
+                    .disabled(self.disabled)
+                    .disabled_style(Style::default());
+                let input_area = Rect {
+                    width: body.width.min(8),
+                    height: 1,
+                    ..body
+                };
+                StatefulWidget::render(input, input_area, buf, &mut state.hex);
+                input_area.width
+            }
+        };
+
+        let preview_x = body.x.saturating_add(palette_width + 1);
+        if let Some(color) = state.color().filter(|_| preview_x < body.right()) {
+            let preview = Rect {
+                x: preview_x,
+                width: PREVIEW_WIDTH.min(body.right() - preview_x),
+                ..body
+            };
+            buf.set_style(preview, Style::new().bg(color));
+        }
+    }
+}
+
+impl<'a> Widget for ColorPicker<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = ColorPickerState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_buffer_eq, buffer::Cell};
+
+    fn ctrl(key: Key) -> KeyEvent {
+        KeyEvent::new(key, KeyModifiers::CONTROL)
+    }
+
+    #[test]
+    fn selects_colors_with_the_keys() {
+        let mut state = ColorPickerState::default();
+        assert!(!state.handle_key(Key::Right.into()));
+        state.set_focused(true);
+        assert!(state.handle_key(Key::Down.into()));
+        assert!(state.handle_key(Key::Right.into()));
+        assert_eq!(state.color(), Some(Color::LightRed));
+        assert!(!state.handle_key(Key::Down.into()));
+        assert!(state.handle_key(Key::End.into()));
+        assert_eq!(state.color(), Some(Color::White));
+
+        assert!(state.handle_key(ctrl(Key::Right)));
+        assert_eq!(state.mode(), ColorPickerMode::Indexed);
+        assert!(state.handle_key(Key::End.into()));
+        assert!(state.handle_key(Key::Up.into()));
+        assert_eq!(state.color(), Some(Color::Indexed(239)));
+
+        // the selection of each mode is kept
+        assert!(state.handle_key(ctrl(Key::Left)));
+        assert_eq!(state.color(), Some(Color::White));
+    }
+
+    #[test]
+    fn enters_an_hexadecimal_color() {
+        let mut state = ColorPickerState::default()
+            .with_color(Color::Rgb(0, 128, 255))
+            .with_focused(true);
+        assert_eq!(state.mode(), ColorPickerMode::Rgb);
+        assert_eq!(state.hex_input().value(), "#0080FF");
+        assert!(state.hex_input().is_focused());
+
+        assert!(state.handle_key(Key::Backspace.into()));
+        assert_eq!(state.color(), None);
+        // the characters that are not hexadecimal digits are rejected
+        state.handle_paste("xa");
+        assert_eq!(state.color(), Some(Color::Rgb(0, 128, 250)));
+
+        state.set_color(Color::Indexed(42));
+        assert_eq!(state.mode(), ColorPickerMode::Indexed);
+        assert!(!state.hex_input().is_focused());
+        state.set_color(Color::Blue);
+        assert_eq!(state.color(), Some(Color::Blue));
+    }
+
+    #[test]
+    fn light_colors() {
+        assert!(is_light(Color::Yellow));
+        assert!(!is_light(Color::Indexed(4)));
+        assert!(is_light(Color::Indexed(231)));
+        assert!(!is_light(Color::Indexed(232)));
+        assert!(is_light(Color::Rgb(255, 128, 0)));
+    }
+
+    #[test]
+    fn render_ansi() {
+        let mut state = ColorPickerState::default().with_color(Color::Green);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 34, 3));
+        StatefulWidget::render(ColorPicker::new(), buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec![
+            " ANSI  256  RGB                   ",
+            "      [ ]                         ",
+            "                                  ",
+        ]);
+        expected.set_style(Rect::new(0, 0, 6, 1), DEFAULT_SELECTED_TAB_STYLE);
+        for (index, color) in ANSI_COLORS.into_iter().enumerate() {
+            let area = Rect::new(index as u16 % 8 * 3, 1 + index as u16 / 8, 3, 1);
+            expected.set_style(area, Style::new().bg(color));
+        }
+        expected.set_style(
+            Rect::new(6, 1, 3, 1),
+            Style::new().fg(Color::Black).add_modifier(Modifier::BOLD),
+        );
+        expected.set_style(Rect::new(25, 1, 6, 2), Style::new().bg(Color::Green));
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_narrow() {
+        for color in [Color::Green, Color::Indexed(200), Color::Rgb(0, 128, 255)] {
+            for width in 1..=16 {
+                let mut state = ColorPickerState::default().with_color(color);
+                let mut buf = Buffer::empty(Rect::new(0, 0, width, 4));
+                StatefulWidget::render(ColorPicker::new(), buf.area, &mut buf, &mut state);
+                let tabs = format!("{:width$.width$}", " ANSI  256  RGB ", width = width.into());
+                let row = buf.content[..usize::from(width)]
+                    .iter()
+                    .map(Cell::symbol)
+                    .collect::<String>();
+                assert_eq!(row, tabs);
+            }
+        }
+    }
+
+    #[test]
+    fn indexed_palette_scrolls_to_the_selection() {
+        let mut state = ColorPickerState::default().with_color(Color::Indexed(200));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 32, 3));
+        StatefulWidget::render(ColorPicker::new(), buf.area, &mut buf, &mut state);
+        // rows 11 and 12 are shown
+        assert_eq!(buf.get(0, 1).bg, Color::Indexed(176));
+        assert_eq!(buf.get(16, 2).bg, Color::Indexed(200));
+        assert_eq!(buf.get(16, 2).symbol(), "[");
+
+        let click = |x, y| MouseEvent::new(MouseEventKind::Down(MouseButton::Left), x, y);
+        assert!(state.handle_mouse(click(3, 1)));
+        assert_eq!(state.color(), Some(Color::Indexed(177)));
+        assert!(state.handle_mouse(click(12, 0)));
+        assert_eq!(state.mode(), ColorPickerMode::Rgb);
+    }
+
+    #[test]
+    fn read_only_and_disabled() {
+        let mut state = ColorPickerState::default()
+            .with_color(Color::Rgb(0, 128, 255))
+            .with_focused(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 32, 2));
+        let picker = ColorPicker::new().read_only(true);
+        StatefulWidget::render(picker, buf.area, &mut buf, &mut state);
+        assert!(state.is_read_only() && state.hex_input().is_read_only());
+        // the hexadecimal input only moves its cursor
+        state.handle_key(ctrl(Key::Left));
+        assert!(!state.handle_key(Key::Char('0').into()));
+        assert!(!state.handle_paste("#FFFFFF"));
+        let click = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 1, 0);
+        assert!(!state.handle_mouse(click));
+        assert_eq!(state.mode(), ColorPickerMode::Rgb);
+        assert_eq!(state.color(), Some(Color::Rgb(0, 128, 255)));
+
+        let mut buf = Buffer::empty(buf.area);
+        StatefulWidget::render(
+            ColorPicker::new().disabled(true),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        assert!(state.is_disabled() && Focusable::is_disabled(&state));
+        assert!(!state.handle_key(Key::Home.into()));
+        assert_eq!(state.cursor_position(), None);
+        assert!(buf
+            .content
+            .iter()
+            .all(|cell| cell.modifier.contains(Modifier::DIM)));
+    }
+}
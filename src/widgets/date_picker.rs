@@ -0,0 +1,1099 @@
+#![deny(missing_docs)]
+use std::ops::RangeInclusive;
+
+use time::{util::is_leap_year, Date, Month, OffsetDateTime, Time};
+
+use crate::{
+    buffer::Buffer,
+    input::{Focusable, Key, KeyEvent, KeyEventKind},
+    layout::Rect,
+    style::{Color, Modifier, Style, Styled},
+    terminal::RedrawRequest,
+    widgets::{
+        calendar::{CalendarEventStore, Monthly},
+        StatefulWidget, Widget,
+    },
+};
+
+const DEFAULT_FOCUSED_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_INVALID_STYLE: Style = Style::new().fg(Color::Red);
+const DEFAULT_SELECTED_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_DISABLED_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+
+/// The numeric segments of a date or a time field, edited one at a time.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct Segments<const N: usize> {
+    values: [i32; N],
+    /// Index of the segment edited from the keyboard
+    focused: usize,
+    /// Number of digits typed in the focused segment, 0 when not typing
+    typed: usize,
+}
+
+impl<const N: usize> Segments<N> {
+    const fn new(values: [i32; N]) -> Self {
+        Self {
+            values,
+            focused: 0,
+            typed: 0,
+        }
+    }
+
+    fn focus(&mut self, segment: usize) -> bool {
+        self.typed = 0;
+        let changed = self.focused != segment;
+        self.focused = segment;
+        changed
+    }
+
+    /// Adds `delta` to the focused segment, wrapping around the range.
+    fn step(&mut self, delta: i32, range: RangeInclusive<i32>) {
+        self.typed = 0;
+        let (min, max) = (*range.start(), *range.end());
+        let value = self.values[self.focused].clamp(min, max) - min + delta;
+        self.values[self.focused] = min + value.rem_euclid(max - min + 1);
+    }
+
+    /// Types a digit in the focused segment, which has at most `digits` digits and a value of at
+    /// most `max`. The next segment is focused once the segment is full, i.e. it has all its
+    /// digits or another digit would exceed `max`.
+    fn type_digit(&mut self, digit: i32, (digits, max): (usize, i32)) {
+        let value = &mut self.values[self.focused];
+        if self.typed == 0 || *value * 10 + digit > max {
+            *value = digit;
+            self.typed = 1;
+        } else {
+            *value = *value * 10 + digit;
+            self.typed += 1;
+        }
+        if self.typed == digits || *value * 10 > max {
+            self.focus((self.focused + 1).min(N - 1));
+        }
+    }
+
+    /// Handles the keys common to the fields and returns `true` if the key was handled.
+    ///
+    /// `range` gives the range of a segment stepped with `Up` and `Down`, and `limits` the
+    /// number of digits and the maximum value typed in a segment. Only the keys moving between
+    /// the segments are handled when `read_only` is set.
+    fn handle_key(
+        &mut self,
+        key: KeyEvent,
+        read_only: bool,
+        separator: char,
+        range: impl Fn(&Self, usize) -> RangeInclusive<i32>,
+        limits: impl Fn(usize) -> (usize, i32),
+    ) -> bool {
+        let segment = self.focused;
+        match key.key {
+            Key::Left => {
+                self.focus(segment.saturating_sub(1));
+            }
+            Key::Right => {
+                self.focus((segment + 1).min(N - 1));
+            }
+            Key::Char(c) if c == separator => {
+                self.focus((segment + 1).min(N - 1));
+            }
+            _ if read_only => return false,
+            Key::Up => self.step(1, range(self, segment)),
+            Key::Down => self.step(-1, range(self, segment)),
+            Key::Char(c) if c.is_ascii_digit() => {
+                let digit = c.to_digit(10).unwrap_or_default() as i32;
+                self.type_digit(digit, limits(segment));
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Renders the segments separated by `separator`. The focused segment is patched with
+    /// `focused_style`.
+    fn render(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        separator: char,
+        digits: impl Fn(usize) -> usize,
+        style: Style,
+        focused_style: Option<Style>,
+    ) {
+        let mut x = area.x;
+        for (i, value) in self.values.iter().enumerate() {
+            if i > 0 {
+                if x >= area.right() {
+                    return;
+                }
+                buf.set_string(x, area.y, separator.to_string(), style);
+                x += 1;
+            }
+            if x >= area.right() {
+                return;
+            }
+            let text = format!("{:0width$}", value, width = digits(i));
+            let segment_style = match focused_style {
+                Some(focused_style) if i == self.focused => style.patch(focused_style),
+                _ => style,
+            };
+            let width = area.right() - x;
+            x = buf
+                .set_stringn(x, area.y, text, width.into(), segment_style)
+                .0;
+        }
+    }
+}
+
+/// An input for a date, edited segment by segment, e.g. `2024-02-29`.
+///
+/// The first row of the area shows the year, the month and the day of a [`DatePickerState`],
+/// which handles the keyboard input of a focused picker:
+/// - `Left` and `Right` move to the previous and next segment, as does typing `-`.
+/// - `Up` and `Down` increase and decrease the segment, wrapping around its range.
+/// - Digits are typed in the segment, moving to the next one once it is full.
+///
+/// The typed segments may not form a real date, e.g. `2023-02-29`: the field is then shown with
+/// the [`invalid_style`](DatePicker::invalid_style) and [`DatePickerState::value`] is `None`. With
+/// [`calendar`](DatePicker::calendar), the month of the date is shown below the field as a
+/// [`Monthly`] calendar.
+///
+/// A [`read_only`](DatePicker::read_only) picker still moves between the segments, but their
+/// values don't change. A [`disabled`](DatePicker::disabled) picker ignores all the keys, isn't
+/// drawn focused, and is restyled with the [`disabled_style`](DatePicker::disabled_style).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{input::Key, prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut DatePickerState) {
+/// frame.render_stateful_widget(DatePicker::new().calendar(true), frame.size(), state);
+/// # }
+/// let mut state = DatePickerState::default().with_focused(true);
+/// for c in "20230229".chars() {
+///     state.handle_key(Key::Char(c).into());
+/// }
+/// assert!(state.value().is_none());
+/// // back to the year, which is increased to a leap year
+/// state.handle_key(Key::Left.into());
+/// state.handle_key(Key::Left.into());
+/// state.handle_key(Key::Up.into());
+/// assert_eq!(state.iso().as_deref(), Some("2024-02-29"));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct DatePicker {
+    style: Style,
+    focused_style: Style,
+    invalid_style: Style,
+    selected_style: Style,
+    calendar: bool,
+    read_only: bool,
+    disabled: bool,
+    disabled_style: Style,
+}
+
+impl Default for DatePicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DatePicker {
+    /// Creates a new date picker.
+    pub const fn new() -> Self {
+        Self {
+            style: Style::new(),
+            focused_style: DEFAULT_FOCUSED_STYLE,
+            invalid_style: DEFAULT_INVALID_STYLE,
+            selected_style: DEFAULT_SELECTED_STYLE,
+            calendar: false,
+            read_only: false,
+            disabled: false,
+            disabled_style: DEFAULT_DISABLED_STYLE,
+        }
+    }
+
+    /// Sets the style of the field.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style patched on top of the edited segment while the picker has the focus.
+    /// Defaults to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn focused_style(mut self, style: Style) -> Self {
+        self.focused_style = style;
+        self
+    }
+
+    /// Sets the style patched on top of the field when the segments don't form a real date.
+    /// Defaults to a red foreground.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn invalid_style(mut self, style: Style) -> Self {
+        self.invalid_style = style;
+        self
+    }
+
+    /// Sets the style of the date in the calendar. Defaults to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn selected_style(mut self, style: Style) -> Self {
+        self.selected_style = style;
+        self
+    }
+
+    /// Sets whether the month of the date is shown below the field. Defaults to `false`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn calendar(mut self, calendar: bool) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    /// Makes the picker read-only: the keys still move between the segments, but their values
+    /// don't change.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Disables the picker: it ignores all the keys, isn't drawn focused and is restyled with
+    /// the [`disabled_style`](Self::disabled_style).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the style patched over the whole picker while it is [`disabled`](Self::disabled).
+    /// Defaults to dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = style;
+        self
+    }
+}
+
+impl Styled for DatePicker {
+    type Item = DatePicker;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// The state of a [`DatePicker`].
+///
+/// It holds the year, month and day segments, which may not form a real date while they are
+/// edited, the edited segment and whether the picker has the focus.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct DatePickerState {
+    segments: Segments<3>,
+    focused: bool,
+    /// Whether the values can't be changed, set when rendering
+    read_only: bool,
+    /// Whether the picker ignores all the keys, set when rendering
+    disabled: bool,
+}
+
+impl Default for DatePickerState {
+    /// A picker set to the Unix epoch, 1970-01-01.
+    fn default() -> Self {
+        Self::new(OffsetDateTime::UNIX_EPOCH.date())
+    }
+}
+
+impl DatePickerState {
+    /// Creates a new state set to the given date.
+    pub fn new(date: Date) -> Self {
+        Self {
+            segments: Segments::new(date_segments(date)),
+            focused: false,
+            read_only: false,
+            disabled: false,
+        }
+    }
+
+    /// Sets whether the picker has the focus.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Returns the date, or `None` if the segments don't form a real date.
+    pub fn value(&self) -> Option<Date> {
+        let [year, month, day] = self.segments.values;
+        let month = Month::try_from(u8::try_from(month).ok()?).ok()?;
+        Date::from_calendar_date(year, month, u8::try_from(day).ok()?).ok()
+    }
+
+    /// Sets the date.
+    pub fn set_value(&mut self, date: Date) {
+        let values = date_segments(date);
+        RedrawRequest::request_if(self.segments.values != values);
+        self.segments.values = values;
+        self.segments.typed = 0;
+    }
+
+    /// Returns `true` if the segments form a real date.
+    pub fn is_valid(&self) -> bool {
+        self.value().is_some()
+    }
+
+    /// Returns the date in the ISO 8601 format, e.g. `2024-02-29`, or `None` if the segments
+    /// don't form a real date.
+    pub fn iso(&self) -> Option<String> {
+        self.value().map(|date| {
+            format!(
+                "{:04}-{:02}-{:02}",
+                date.year(),
+                u8::from(date.month()),
+                date.day()
+            )
+        })
+    }
+
+    /// Sets the date from the ISO 8601 format, e.g. `2024-02-29`. Returns `false` and keeps the
+    /// date if the text is not a real date in this format.
+    pub fn set_iso(&mut self, text: &str) -> bool {
+        let Some(date) = parse_iso_date(text) else {
+            return false;
+        };
+        self.set_value(date);
+        true
+    }
+
+    /// Returns the index of the edited segment: 0 for the year, 1 for the month and 2 for the
+    /// day.
+    pub fn focused_segment(&self) -> usize {
+        self.segments.focused
+    }
+
+    /// Returns `true` if the picker has the focus.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Sets whether the picker has the focus.
+    pub fn set_focused(&mut self, focused: bool) {
+        RedrawRequest::request_if(self.focused != focused);
+        self.focused = focused;
+        self.segments.typed = 0;
+    }
+
+    /// Returns `true` if the picker was [read-only](DatePicker::read_only) during the last render.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns `true` if the picker was [disabled](DatePicker::disabled) during the last render.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Handles a key event and returns `true` if it was handled.
+    ///
+    /// See [`DatePicker`] for the keys handled by a focused picker. Changing the year or the
+    /// month with `Up` and `Down` keeps the day in the month.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if !self.focused || self.disabled || key.kind == KeyEventKind::Release {
+            return false;
+        }
+        let stepped = matches!(key.key, Key::Up | Key::Down) && self.segments.focused < 2;
+        let handled = self.segments.handle_key(
+            key,
+            self.read_only,
+            '-',
+            date_segment_range,
+            date_segment_limits,
+        );
+        if stepped && handled {
+            let [year, month, day] = self.segments.values;
+            let days = days_in_month(year, month);
+            self.segments.values[2] = day.min(days);
+        }
+        RedrawRequest::request_if(handled)
+    }
+}
+
+impl Focusable for DatePickerState {
+    fn set_focused(&mut self, focused: bool) {
+        DatePickerState::set_focused(self, focused);
+    }
+
+    fn is_disabled(&self) -> bool {
+        DatePickerState::is_disabled(self)
+    }
+}
+
+fn date_segments(date: Date) -> [i32; 3] {
+    [
+        date.year(),
+        i32::from(u8::from(date.month())),
+        i32::from(date.day()),
+    ]
+}
+
+fn date_segment_range(segments: &Segments<3>, segment: usize) -> RangeInclusive<i32> {
+    let [year, month, _] = segments.values;
+    match segment {
+        0 => 1..=9999,
+        1 => 1..=12,
+        _ => 1..=days_in_month(year, month),
+    }
+}
+
+fn date_segment_digits(segment: usize) -> usize {
+    date_segment_limits(segment).0
+}
+
+/// Any day up to 31 can be typed, even if it is not in the month: the date is then invalid.
+fn date_segment_limits(segment: usize) -> (usize, i32) {
+    match segment {
+        0 => (4, 9999),
+        1 => (2, 12),
+        _ => (2, 31),
+    }
+}
+
+/// Returns the number of days in the month, or 31 if the month is not valid.
+fn days_in_month(year: i32, month: i32) -> i32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn parse_iso_date(text: &str) -> Option<Date> {
+    let mut parts = text.split('-');
+    let year = parse_digits(parts.next()?, 4)?;
+    let month = parse_digits(parts.next()?, 2)?;
+    let day = parse_digits(parts.next()?, 2)?;
+    if parts.next().is_some() {
+        return None;
+    }
+    let month = Month::try_from(u8::try_from(month).ok()?).ok()?;
+    Date::from_calendar_date(year, month, u8::try_from(day).ok()?).ok()
+}
+
+/// Parses a number of exactly `digits` ASCII digits.
+fn parse_digits(text: &str, digits: usize) -> Option<i32> {
+    if text.len() != digits || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    text.parse().ok()
+}
+
+impl StatefulWidget for DatePicker {
+    type State = DatePickerState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.read_only = self.read_only;
+        state.disabled = self.disabled;
+        if area.is_empty() {
+            return;
+        }
+        let disabled_style = self.disabled.then_some(self.disabled_style);
+        self.render_date(area, buf, state);
+        if let Some(style) = disabled_style {
+            buf.set_style(area, style);
+        }
+    }
+}
+
+impl DatePicker {
+    fn render_date(self, area: Rect, buf: &mut Buffer, state: &DatePickerState) {
+        let valid = state.value();
+        let style = if valid.is_some() {
+            self.style
+        } else {
+            self.style.patch(self.invalid_style)
+        };
+        let field = Rect { height: 1, ..area };
+        state.segments.render(
+            field,
+            buf,
+            '-',
+            date_segment_digits,
+            style,
+            (state.focused && !self.disabled).then_some(self.focused_style),
+        );
+
+        let Some(date) = valid.filter(|_| self.calendar && area.height > 1) else {
+            return;
+        };
+        let mut events = CalendarEventStore::default();
+        events.add(date, self.selected_style);
+        let calendar = Rect {
+            y: area.y + 1,
+            height: area.height - 1,
+            ..area
+        };
+        Monthly::new(date, events)
+            .show_month_header(self.style)
+            .show_weekdays_header(self.style)
+            .default_style(self.style)
+            .render(calendar, buf);
+    }
+}
+
+impl Widget for DatePicker {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = DatePickerState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+/// An input for a time of the day, edited segment by segment, e.g. `13:45:00`.
+///
+/// The first row of the area shows the hour, the minute and, unless disabled with
+/// [`TimePickerState::with_seconds`], the second of a [`TimePickerState`]. It handles the
+/// keyboard input of a focused picker:
+/// - `Left` and `Right` move to the previous and next segment, as does typing `:`.
+/// - `Up` and `Down` increase and decrease the segment, wrapping around its range.
+/// - Digits are typed in the segment, moving to the next one once it is full.
+///
+/// A [`read_only`](TimePicker::read_only) picker still moves between the segments, but their
+/// values don't change. A [`disabled`](TimePicker::disabled) picker ignores all the keys, isn't
+/// drawn focused, and is restyled with the [`disabled_style`](TimePicker::disabled_style).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{input::Key, widgets::*};
+///
+/// let mut state = TimePickerState::default()
+///     .with_seconds(false)
+///     .with_focused(true);
+/// for c in "930".chars() {
+///     state.handle_key(Key::Char(c).into());
+/// }
+/// assert_eq!(state.iso(), "09:30");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TimePicker {
+    style: Style,
+    focused_style: Style,
+    read_only: bool,
+    disabled: bool,
+    disabled_style: Style,
+}
+
+impl Default for TimePicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimePicker {
+    /// Creates a new time picker.
+    pub const fn new() -> Self {
+        Self {
+            style: Style::new(),
+            focused_style: DEFAULT_FOCUSED_STYLE,
+            read_only: false,
+            disabled: false,
+            disabled_style: DEFAULT_DISABLED_STYLE,
+        }
+    }
+
+    /// Sets the style of the field.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style patched on top of the edited segment while the picker has the focus.
+    /// Defaults to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn focused_style(mut self, style: Style) -> Self {
+        self.focused_style = style;
+        self
+    }
+
+    /// Makes the picker read-only: the keys still move between the segments, but their values
+    /// don't change.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Disables the picker: it ignores all the keys, isn't drawn focused and is restyled with
+    /// the [`disabled_style`](Self::disabled_style).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the style patched over the whole picker while it is [`disabled`](Self::disabled).
+    /// Defaults to dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = style;
+        self
+    }
+}
+
+impl Styled for TimePicker {
+    type Item = TimePicker;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// The state of a [`TimePicker`].
+///
+/// It holds the hour, minute and second segments, the edited segment and whether the picker has
+/// the focus. The segments always form a valid time.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct TimePickerState {
+    segments: Segments<3>,
+    seconds: bool,
+    focused: bool,
+    /// Whether the values can't be changed, set when rendering
+    read_only: bool,
+    /// Whether the picker ignores all the keys, set when rendering
+    disabled: bool,
+}
+
+impl Default for TimePickerState {
+    /// A picker set to midnight, showing the seconds.
+    fn default() -> Self {
+        Self::new(Time::MIDNIGHT)
+    }
+}
+
+impl TimePickerState {
+    /// Creates a new state set to the given time, showing the seconds.
+    pub fn new(time: Time) -> Self {
+        Self {
+            segments: Segments::new(time_segments(time)),
+            seconds: true,
+            focused: false,
+            read_only: false,
+            disabled: false,
+        }
+    }
+
+    /// Sets whether the seconds are shown and edited. Hiding them sets them to zero.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_seconds(mut self, seconds: bool) -> Self {
+        self.seconds = seconds;
+        if !seconds {
+            self.segments.values[2] = 0;
+            self.segments.focused = self.segments.focused.min(1);
+        }
+        self
+    }
+
+    /// Sets whether the picker has the focus.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Returns the time.
+    pub fn value(&self) -> Time {
+        let [hour, minute, second] = self.segments.values.map(|v| v as u8);
+        Time::from_hms(hour, minute, second).unwrap_or(Time::MIDNIGHT)
+    }
+
+    /// Sets the time. The seconds are ignored when they are not shown.
+    pub fn set_value(&mut self, time: Time) {
+        let mut values = time_segments(time);
+        if !self.seconds {
+            values[2] = 0;
+        }
+        RedrawRequest::request_if(self.segments.values != values);
+        self.segments.values = values;
+        self.segments.typed = 0;
+    }
+
+    /// Returns the time in the ISO 8601 format, e.g. `13:45:00`, or `13:45` when the seconds are
+    /// not shown.
+    pub fn iso(&self) -> String {
+        let [hour, minute, second] = self.segments.values;
+        if self.seconds {
+            format!("{hour:02}:{minute:02}:{second:02}")
+        } else {
+            format!("{hour:02}:{minute:02}")
+        }
+    }
+
+    /// Sets the time from the ISO 8601 format, `HH:MM` or `HH:MM:SS`. Returns `false` and keeps
+    /// the time if the text is not a valid time in this format.
+    pub fn set_iso(&mut self, text: &str) -> bool {
+        let Some(time) = parse_iso_time(text) else {
+            return false;
+        };
+        self.set_value(time);
+        true
+    }
+
+    /// Returns the index of the edited segment: 0 for the hour, 1 for the minute and 2 for the
+    /// second.
+    pub fn focused_segment(&self) -> usize {
+        self.segments.focused
+    }
+
+    /// Returns `true` if the picker has the focus.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Sets whether the picker has the focus.
+    pub fn set_focused(&mut self, focused: bool) {
+        RedrawRequest::request_if(self.focused != focused);
+        self.focused = focused;
+        self.segments.typed = 0;
+    }
+
+    /// Returns `true` if the picker was [read-only](TimePicker::read_only) during the last render.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns `true` if the picker was [disabled](TimePicker::disabled) during the last render.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Handles a key event and returns `true` if it was handled.
+    ///
+    /// See [`TimePicker`] for the keys handled by a focused picker.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if !self.focused || self.disabled || key.kind == KeyEventKind::Release {
+            return false;
+        }
+        let handled = self.segments.handle_key(
+            key,
+            self.read_only,
+            ':',
+            |_, segment| time_segment_range(segment),
+            |segment| (2, *time_segment_range(segment).end()),
+        );
+        if !self.seconds && self.segments.focused == 2 {
+            self.segments.focus(1);
+        }
+        RedrawRequest::request_if(handled)
+    }
+}
+
+impl Focusable for TimePickerState {
+    fn set_focused(&mut self, focused: bool) {
+        TimePickerState::set_focused(self, focused);
+    }
+
+    fn is_disabled(&self) -> bool {
+        TimePickerState::is_disabled(self)
+    }
+}
+
+fn time_segments(time: Time) -> [i32; 3] {
+    [time.hour(), time.minute(), time.second()].map(i32::from)
+}
+
+fn time_segment_range(segment: usize) -> RangeInclusive<i32> {
+    if segment == 0 {
+        0..=23
+    } else {
+        0..=59
+    }
+}
+
+fn parse_iso_time(text: &str) -> Option<Time> {
+    let mut parts = text.split(':');
+    let hour = parse_digits(parts.next()?, 2)?;
+    let minute = parse_digits(parts.next()?, 2)?;
+    let second = parts.next().map_or(Some(0), |part| parse_digits(part, 2))?;
+    if parts.next().is_some() {
+        return None;
+    }
+    let [hour, minute, second] = [hour, minute, second].map(|v| v as u8);
+    Time::from_hms(hour, minute, second).ok()
+}
+
+impl StatefulWidget for TimePicker {
+    type State = TimePickerState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.read_only = self.read_only;
+        state.disabled = self.disabled;
+        if area.is_empty() {
+            return;
+        }
+        let field = Rect { height: 1, ..area };
+        let focused_style = (state.focused && !self.disabled).then_some(self.focused_style);
+        if state.seconds {
+            state
+                .segments
+                .render(field, buf, ':', |_| 2, self.style, focused_style);
+        } else {
+            let [hour, minute, _] = state.segments.values;
+            let segments = Segments {
+                values: [hour, minute],
+                focused: state.segments.focused,
+                typed: state.segments.typed,
+            };
+            segments.render(field, buf, ':', |_| 2, self.style, focused_style);
+        }
+        if self.disabled {
+            buf.set_style(area, self.disabled_style);
+        }
+    }
+}
+
+impl Widget for TimePicker {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = TimePickerState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    fn date(year: i32, month: u8, day: u8) -> Date {
+        Date::from_calendar_date(year, Month::try_from(month).unwrap(), day).unwrap()
+    }
+
+    fn time(hour: u8, minute: u8, second: u8) -> Time {
+        Time::from_hms(hour, minute, second).unwrap()
+    }
+
+    fn typed(state: &mut DatePickerState, text: &str) {
+        for c in text.chars() {
+            state.handle_key(Key::Char(c).into());
+        }
+    }
+
+    #[test]
+    fn date_typing_moves_between_segments() {
+        let mut state = DatePickerState::default().with_focused(true);
+        typed(&mut state, "2024");
+        assert_eq!(state.focused_segment(), 1);
+        // no month starts with 3, so the day is next
+        typed(&mut state, "3");
+        assert_eq!(state.focused_segment(), 2);
+        typed(&mut state, "15");
+        assert_eq!(state.value(), Some(date(2024, 3, 15)));
+        assert_eq!(state.iso().as_deref(), Some("2024-03-15"));
+    }
+
+    #[test]
+    fn date_validation() {
+        let mut state = DatePickerState::new(date(2023, 2, 1)).with_focused(true);
+        state.handle_key(Key::Right.into());
+        state.handle_key(Key::Char('-').into());
+        typed(&mut state, "29");
+        assert!(!state.is_valid());
+        assert_eq!(state.iso(), None);
+        // changing the year to a leap year makes the date valid
+        state.handle_key(Key::Left.into());
+        state.handle_key(Key::Left.into());
+        state.handle_key(Key::Up.into());
+        assert_eq!(state.value(), Some(date(2024, 2, 29)));
+        // and back keeps the day in the month
+        state.handle_key(Key::Down.into());
+        assert_eq!(state.value(), Some(date(2023, 2, 28)));
+    }
+
+    #[test]
+    fn date_step_wraps() {
+        let mut state = DatePickerState::new(date(2024, 12, 31)).with_focused(true);
+        state.handle_key(Key::Right.into());
+        state.handle_key(Key::Up.into());
+        assert_eq!(state.value(), Some(date(2024, 1, 31)));
+        state.handle_key(Key::Right.into());
+        state.handle_key(Key::Up.into());
+        assert_eq!(state.value(), Some(date(2024, 1, 1)));
+        state.handle_key(Key::Down.into());
+        assert_eq!(state.value(), Some(date(2024, 1, 31)));
+    }
+
+    #[test]
+    fn date_iso() {
+        let mut state = DatePickerState::default();
+        assert_eq!(state.iso().as_deref(), Some("1970-01-01"));
+        assert!(state.set_iso("2000-02-29"));
+        assert_eq!(state.value(), Some(date(2000, 2, 29)));
+        assert!(!state.set_iso("2001-02-29"));
+        assert!(!state.set_iso("2001-2-3"));
+        assert!(!state.set_iso("2001-02-03T00:00"));
+        assert_eq!(state.value(), Some(date(2000, 2, 29)));
+    }
+
+    #[test]
+    fn date_render() {
+        let mut state = DatePickerState::new(date(2024, 3, 15)).with_focused(true);
+        state.handle_key(Key::Right.into());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+        StatefulWidget::render(DatePicker::new(), buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["2024-03-15  "]);
+        expected.set_style(Rect::new(5, 0, 2, 1), DEFAULT_FOCUSED_STYLE);
+        assert_buffer_eq!(buf, expected);
+
+        let mut state = DatePickerState::new(date(2023, 4, 30));
+        state.segments.values[2] = 31;
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        StatefulWidget::render(DatePicker::new(), buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["2023-04-31"]);
+        expected.set_style(expected.area, DEFAULT_INVALID_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn date_render_narrow() {
+        let text = "2024-03-15";
+        for width in 1..=12 {
+            let mut state = DatePickerState::new(date(2024, 3, 15));
+            let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+            StatefulWidget::render(DatePicker::new(), buf.area, &mut buf, &mut state);
+            let line = format!("{text:width$.width$}", width = width.into());
+            assert_buffer_eq!(buf, Buffer::with_lines(vec![line]));
+        }
+    }
+
+    #[test]
+    fn date_render_calendar() {
+        let mut state = DatePickerState::new(date(2024, 2, 29));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 21, 8));
+        StatefulWidget::render(
+            DatePicker::new()
+                .calendar(true)
+                .selected_style(Style::default()),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        assert_buffer_eq!(
+            buf,
+            Buffer::with_lines(vec![
+                "2024-02-29           ",
+                "     February 2024   ",
+                " Su Mo Tu We Th Fr Sa",
+                "              1  2  3",
+                "  4  5  6  7  8  9 10",
+                " 11 12 13 14 15 16 17",
+                " 18 19 20 21 22 23 24",
+                " 25 26 27 28 29      ",
+            ])
+        );
+    }
+
+    #[test]
+    fn time_editing() {
+        let mut state = TimePickerState::new(time(13, 45, 10)).with_focused(true);
+        assert_eq!(state.iso(), "13:45:10");
+        state.handle_key(Key::Down.into());
+        assert_eq!(state.value(), time(12, 45, 10));
+        state.handle_key(Key::Char(':').into());
+        typed_time(&mut state, "7");
+        // 7 can't start a minute above 59, so the second is next
+        assert_eq!(state.focused_segment(), 2);
+        state.handle_key(Key::Down.into());
+        assert_eq!(state.value(), time(12, 7, 9));
+        state.handle_key(Key::Right.into());
+        assert_eq!(state.focused_segment(), 2);
+        state.handle_key(Key::Left.into());
+        state.handle_key(Key::Left.into());
+        state.handle_key(Key::Down.into());
+        state.handle_key(Key::Down.into());
+        assert_eq!(state.iso(), "10:07:09");
+    }
+
+    fn typed_time(state: &mut TimePickerState, text: &str) {
+        for c in text.chars() {
+            state.handle_key(Key::Char(c).into());
+        }
+    }
+
+    #[test]
+    fn time_without_seconds() {
+        let mut state = TimePickerState::new(time(23, 59, 59))
+            .with_seconds(false)
+            .with_focused(true);
+        assert_eq!(state.iso(), "23:59");
+        state.handle_key(Key::Right.into());
+        state.handle_key(Key::Right.into());
+        assert_eq!(state.focused_segment(), 1);
+        state.handle_key(Key::Up.into());
+        assert_eq!(state.value(), time(23, 0, 0));
+        assert!(state.set_iso("07:30:15"));
+        assert_eq!(state.iso(), "07:30");
+        assert!(!state.set_iso("24:00"));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        StatefulWidget::render(
+            TimePicker::new().focused_style(Style::default()),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["07:30 "]));
+    }
+
+    #[test]
+    fn time_render_narrow() {
+        for (seconds, text) in [(true, "13:45:10"), (false, "13:45")] {
+            for width in 1..=10 {
+                let mut state = TimePickerState::new(time(13, 45, 10)).with_seconds(seconds);
+                let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+                StatefulWidget::render(TimePicker::new(), buf.area, &mut buf, &mut state);
+                let line = format!("{text:width$.width$}", width = width.into());
+                assert_buffer_eq!(buf, Buffer::with_lines(vec![line]));
+            }
+        }
+    }
+
+    #[test]
+    fn read_only_and_disabled() {
+        let mut state = DatePickerState::new(date(2024, 3, 15)).with_focused(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let picker = DatePicker::new().read_only(true);
+        StatefulWidget::render(picker, buf.area, &mut buf, &mut state);
+        assert!(state.is_read_only() && !state.is_disabled());
+        assert!(state.handle_key(Key::Right.into()));
+        assert!(!state.handle_key(Key::Up.into()));
+        assert!(!state.handle_key(Key::Char('1').into()));
+        assert_eq!(state.iso().as_deref(), Some("2024-03-15"));
+
+        let mut state = TimePickerState::new(time(13, 45, 0)).with_focused(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        StatefulWidget::render(
+            TimePicker::new().disabled(true),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        assert!(state.is_disabled() && Focusable::is_disabled(&state));
+        assert!(!state.handle_key(Key::Right.into()));
+        let mut expected = Buffer::with_lines(vec!["13:45:00"]);
+        expected.set_style(expected.area, DEFAULT_DISABLED_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+}
@@ -0,0 +1,1295 @@
+//! Decorators that wrap any widget to add borders, a title, scrolling or a style.
+//!
+//! - [`Bordered`] draws a [`Block`] around the widget.
+//! - [`Titled`] draws a title line above the widget.
+//! - [`Scrollable`] renders the widget taller or wider than its area and shows the part selected
+//!   by a [`ScrollableState`], with scrollbars.
+//! - [`Styled`] sets a base style on the area of the widget.
+//! - [`Tooltip`] shows a text in a [`TooltipPopup`] after the mouse rests on the widget.
+//!
+//! Decorators are widgets themselves, so they compose: the methods of the [`Decorate`] extension
+//! trait, implemented for every [`Widget`], wrap the widget they are called on. Decorations are
+//! applied from the inside out, e.g. `.titled("Logs").bordered()` draws the title inside the
+//! border. Titling a [`Bordered`] widget puts the title in its top border instead, as the `block()`
+//! builders of the built-in widgets do.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::{
+//!     prelude::*,
+//!     widgets::{decorator::ScrollableState, Decorate, Paragraph},
+//! };
+//!
+//! # fn ui(frame: &mut Frame, state: &mut ScrollableState) {
+//! let logs = Paragraph::new("first\nsecond\nthird\nfourth");
+//! let widget = logs.bordered().titled("Logs").scrollable(state);
+//! frame.render_widget(widget, frame.size());
+//! # }
+//! let mut state = ScrollableState::new(4);
+//! state.scroll_down(1);
+//! assert_eq!(state.offset(), 1);
+//! ```
+use std::time::{Duration, Instant};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    buffer::Buffer,
+    input::{Key, KeyEvent, KeyEventKind, MouseEvent, MouseEventKind, Scroll},
+    layout::{Position, Rect},
+    style::Style,
+    terminal::RedrawRequest,
+    text::Line,
+    widgets::{
+        Block, BorderType, Borders, Clear, Scrollbar, ScrollbarOrientation, ScrollbarState,
+        StatefulWidget, Widget,
+    },
+};
+
+/// A widget that wraps another widget and decorates it.
+///
+/// This is implemented by the decorators of this module, and gives access to the decorated
+/// widget.
+pub trait Decorator<W: Widget>: Widget {
+    /// Returns a reference to the decorated widget.
+    fn inner(&self) -> &W;
+
+    /// Returns the decorated widget, dropping the decoration.
+    fn into_inner(self) -> W;
+}
+
+/// An extension trait wrapping any [`Widget`] in the decorators of the [`decorator`] module.
+///
+/// [`decorator`]: self
+pub trait Decorate: Widget + Sized {
+    /// Draws a block with all the borders around the widget.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    fn bordered<'a>(self) -> Bordered<'a, Self> {
+        Bordered::new(self)
+    }
+
+    /// Draws a title line above the widget.
+    ///
+    /// On a [`Bordered`] widget, [`Bordered::titled`] puts the title in the top border instead.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    fn titled<'a, T>(self, title: T) -> Titled<'a, Self>
+    where
+        T: Into<Line<'a>>,
+    {
+        Titled::new(self, title)
+    }
+
+    /// Renders the widget with the size of the content of the state and shows the part selected
+    /// by its offsets.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    fn scrollable(self, state: &mut ScrollableState) -> Scrollable<'_, Self> {
+        Scrollable::new(self, state)
+    }
+
+    /// Sets the base style of the area of the widget.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    fn styled(self, style: Style) -> Styled<Self> {
+        Styled::new(self, style)
+    }
+
+    /// Shows the given text in the [`TooltipPopup`] of the state while the mouse rests on the
+    /// widget.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    fn tooltip<T>(self, text: T, state: &mut TooltipState) -> Tooltip<'_, Self>
+    where
+        T: Into<String>,
+    {
+        Tooltip::new(self, text, state)
+    }
+}
+
+impl<W: Widget> Decorate for W {}
+
+/// A widget drawn inside a [`Block`], which has all the borders by default.
+///
+/// This is created by [`Decorate::bordered`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Bordered<'a, W> {
+    widget: W,
+    block: Block<'a>,
+}
+
+impl<'a, W: Widget> Bordered<'a, W> {
+    /// Wraps the widget in a block with all the borders.
+    pub fn new(widget: W) -> Self {
+        Self {
+            widget,
+            block: Block::default().borders(Borders::ALL),
+        }
+    }
+
+    /// Replaces the block drawn around the widget.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Sets the type of the borders. Defaults to [`BorderType::Plain`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn border_type(mut self, border_type: BorderType) -> Self {
+        self.block = self.block.border_type(border_type);
+        self
+    }
+
+    /// Sets the style of the borders.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn border_style(mut self, style: Style) -> Self {
+        self.block = self.block.border_style(style);
+        self
+    }
+
+    /// Adds a title in the top border. This can be called several times to add several titles.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn titled<T>(mut self, title: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        self.block = self.block.title(title.into());
+        self
+    }
+}
+
+impl<'a, W: Widget> Widget for Bordered<'a, W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner = self.block.inner(area);
+        self.block.render(area, buf);
+        self.widget.render(inner, buf);
+    }
+}
+
+impl<'a, W: Widget> Decorator<W> for Bordered<'a, W> {
+    fn inner(&self) -> &W {
+        &self.widget
+    }
+
+    fn into_inner(self) -> W {
+        self.widget
+    }
+}
+
+/// A widget with a title line above it.
+///
+/// This is created by [`Decorate::titled`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Titled<'a, W> {
+    widget: W,
+    title: Line<'a>,
+    style: Style,
+}
+
+impl<'a, W: Widget> Titled<'a, W> {
+    /// Wraps the widget with the given title above it.
+    pub fn new<T>(widget: W, title: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        Self {
+            widget,
+            title: title.into(),
+            style: Style::default(),
+        }
+    }
+
+    /// Sets the style of the title line.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn title_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl<'a, W: Widget> Widget for Titled<'a, W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        let title = Rect { height: 1, ..area };
+        buf.set_style(title, self.style);
+        buf.set_line(area.x, area.y, &self.title, area.width);
+        let inner = Rect {
+            y: area.y + 1,
+            height: area.height - 1,
+            ..area
+        };
+        self.widget.render(inner, buf);
+    }
+}
+
+impl<'a, W: Widget> Decorator<W> for Titled<'a, W> {
+    fn inner(&self) -> &W {
+        &self.widget
+    }
+
+    fn into_inner(self) -> W {
+        self.widget
+    }
+}
+
+/// A widget with a base style set on its area before it is rendered.
+///
+/// This is created by [`Decorate::styled`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Styled<W> {
+    widget: W,
+    style: Style,
+}
+
+impl<W: Widget> Styled<W> {
+    /// Wraps the widget with the given base style.
+    pub fn new(widget: W, style: Style) -> Self {
+        Self { widget, style }
+    }
+}
+
+impl<W: Widget> Widget for Styled<W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.widget.render(area, buf);
+    }
+}
+
+impl<W: Widget> Decorator<W> for Styled<W> {
+    fn inner(&self) -> &W {
+        &self.widget
+    }
+
+    fn into_inner(self) -> W {
+        self.widget
+    }
+}
+
+/// A widget rendered with the size of its content, of which the rows and columns selected by a
+/// [`ScrollableState`] are shown.
+///
+/// The widget is rendered in an area with the [`content_height`](ScrollableState::content_height)
+/// of the state, and the width of the viewport or the
+/// [`content_width`](ScrollableState::content_width) of the state if it is wider. While the
+/// content is taller than the viewport, the last column of the area shows a scrollbar and the
+/// widget is one column narrower. While it is wider, the last row shows a horizontal scrollbar.
+///
+/// This is created by [`Decorate::scrollable`].
+#[derive(Debug)]
+pub struct Scrollable<'s, W> {
+    widget: W,
+    state: &'s mut ScrollableState,
+    scrollbar: bool,
+}
+
+impl<'s, W: Widget> Scrollable<'s, W> {
+    /// Wraps the widget, scrolled with the given state.
+    pub fn new(widget: W, state: &'s mut ScrollableState) -> Self {
+        Self {
+            widget,
+            state,
+            scrollbar: true,
+        }
+    }
+
+    /// Sets whether the scrollbars are shown while the content is larger than the viewport.
+    /// Defaults to `true`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn scrollbar(mut self, scrollbar: bool) -> Self {
+        self.scrollbar = scrollbar;
+        self
+    }
+}
+
+impl<'s, W: Widget> Widget for Scrollable<'s, W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let state = self.state;
+        state.area = area;
+        if area.is_empty() {
+            return;
+        }
+        // each scrollbar takes space from the viewport, which can make the content overflow in the
+        // other direction
+        let (mut tall, mut wide) = (false, false);
+        for _ in 0..2 {
+            wide =
+                self.scrollbar && state.content_width > usize::from(area.width - u16::from(tall));
+            tall =
+                self.scrollbar && state.content_height > usize::from(area.height - u16::from(wide));
+        }
+        let viewport = usize::from(area.height - u16::from(wide));
+        let viewport_width = usize::from(area.width - u16::from(tall));
+        // the content can't extend past the bottom right corner of the coordinate space
+        let max_height = usize::from(u16::MAX - area.y);
+        let max_width = usize::from(u16::MAX - area.x);
+        let height = state.content_height.clamp(viewport, max_height);
+        let width = state.content_width.clamp(viewport_width, max_width);
+        state.viewport = viewport;
+        state.viewport_width = viewport_width;
+        state.offset = state.offset.min(height - viewport);
+        state.column_offset = state.column_offset.min(width - viewport_width);
+
+        let mut content = Buffer::empty(Rect::new(area.x, area.y, width as u16, height as u16));
+        self.widget.render(content.area, &mut content);
+        for y in 0..viewport as u16 {
+            let source_y = area.y + state.offset as u16 + y;
+            for x in 0..viewport_width as u16 {
+                let source_x = area.x + state.column_offset as u16 + x;
+                *buf.get_mut(area.x + x, area.y + y) = content.get(source_x, source_y).clone();
+            }
+        }
+
+        if tall {
+            let mut scrollbar_state = ScrollbarState::new(height - viewport + 1)
+                .position(state.offset)
+                .viewport_content_length(viewport);
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .render(
+                    Rect {
+                        height: viewport as u16,
+                        ..area
+                    },
+                    buf,
+                    &mut scrollbar_state,
+                );
+        }
+        if wide {
+            let mut scrollbar_state = ScrollbarState::new(width - viewport_width + 1)
+                .position(state.column_offset)
+                .viewport_content_length(viewport_width);
+            Scrollbar::new(ScrollbarOrientation::HorizontalBottom)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .render(
+                    Rect {
+                        width: viewport_width as u16,
+                        ..area
+                    },
+                    buf,
+                    &mut scrollbar_state,
+                );
+        }
+    }
+}
+
+impl<'s, W: Widget> Decorator<W> for Scrollable<'s, W> {
+    fn inner(&self) -> &W {
+        &self.widget
+    }
+
+    fn into_inner(self) -> W {
+        self.widget
+    }
+}
+
+/// The state of a [`Scrollable`] widget.
+///
+/// It holds the size of the content and the offsets of the first row and column shown. The
+/// offsets are clamped so that the viewport stays within the content when the widget is rendered.
+/// The content has the width of the viewport unless a wider
+/// [`content_width`](Self::with_content_width) is set.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ScrollableState {
+    content_height: usize,
+    content_width: usize,
+    offset: usize,
+    column_offset: usize,
+    /// Height of the viewport during the last render
+    viewport: usize,
+    /// Width of the viewport during the last render
+    viewport_width: usize,
+    /// Area of the viewport during the last render, used to handle the mouse wheel
+    area: Rect,
+}
+
+impl ScrollableState {
+    /// Creates a new state for content of the given height.
+    pub fn new(content_height: usize) -> Self {
+        Self {
+            content_height,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the offset of the first row shown.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the width of the content, to scroll horizontally when it is wider than the viewport.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_content_width(mut self, content_width: usize) -> Self {
+        self.content_width = content_width;
+        self
+    }
+
+    /// Sets the offset of the first column shown.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_column_offset(mut self, column_offset: usize) -> Self {
+        self.column_offset = column_offset;
+        self
+    }
+
+    /// Returns the height of the content.
+    pub fn content_height(&self) -> usize {
+        self.content_height
+    }
+
+    /// Sets the height of the content, e.g. when lines are added to a log.
+    pub fn set_content_height(&mut self, content_height: usize) {
+        RedrawRequest::request_if(self.content_height != content_height);
+        self.content_height = content_height;
+    }
+
+    /// Returns the width of the content, `0` if it has the width of the viewport.
+    pub fn content_width(&self) -> usize {
+        self.content_width
+    }
+
+    /// Sets the width of the content, e.g. when the longest line of a log changes.
+    pub fn set_content_width(&mut self, content_width: usize) {
+        RedrawRequest::request_if(self.content_width != content_width);
+        self.content_width = content_width;
+    }
+
+    /// Returns the offset of the first row shown.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Sets the offset of the first row shown, clamped to the last offset of the content. Returns
+    /// `true` if the offset changed.
+    pub fn set_offset(&mut self, offset: usize) -> bool {
+        let offset = offset.min(self.max_offset());
+        let changed = RedrawRequest::request_if(self.offset != offset);
+        self.offset = offset;
+        changed
+    }
+
+    /// Scrolls down by the given number of rows. Returns `true` if the offset changed.
+    pub fn scroll_down(&mut self, rows: usize) -> bool {
+        self.set_offset(self.offset.saturating_add(rows))
+    }
+
+    /// Scrolls up by the given number of rows. Returns `true` if the offset changed.
+    pub fn scroll_up(&mut self, rows: usize) -> bool {
+        self.set_offset(self.offset.saturating_sub(rows))
+    }
+
+    /// Returns the offset of the first column shown.
+    pub fn column_offset(&self) -> usize {
+        self.column_offset
+    }
+
+    /// Sets the offset of the first column shown, clamped to the last offset of the content.
+    /// Returns `true` if the offset changed.
+    pub fn set_column_offset(&mut self, column_offset: usize) -> bool {
+        let column_offset = column_offset.min(self.max_column_offset());
+        let changed = RedrawRequest::request_if(self.column_offset != column_offset);
+        self.column_offset = column_offset;
+        changed
+    }
+
+    /// Scrolls right by the given number of columns. Returns `true` if the offset changed.
+    pub fn scroll_right(&mut self, columns: usize) -> bool {
+        self.set_column_offset(self.column_offset.saturating_add(columns))
+    }
+
+    /// Scrolls left by the given number of columns. Returns `true` if the offset changed.
+    pub fn scroll_left(&mut self, columns: usize) -> bool {
+        self.set_column_offset(self.column_offset.saturating_sub(columns))
+    }
+
+    /// Handles a key event and returns `true` if an offset changed.
+    ///
+    /// `Up` and `Down` scroll by one row, `PageUp` and `PageDown` by the height of the viewport,
+    /// and `Home` and `End` to the top and the bottom of the content. `Left` and `Right` scroll by
+    /// one column.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release {
+            return false;
+        }
+        let page = self.viewport.max(1);
+        match key.key {
+            Key::Up => self.scroll_up(1),
+            Key::Down => self.scroll_down(1),
+            Key::PageUp => self.scroll_up(page),
+            Key::PageDown => self.scroll_down(page),
+            Key::Home => self.set_offset(0),
+            Key::End => self.set_offset(usize::MAX),
+            Key::Left => self.scroll_left(1),
+            Key::Right => self.scroll_right(1),
+            _ => false,
+        }
+    }
+
+    /// Handles a mouse event and returns `true` if an offset changed.
+    ///
+    /// The mouse wheel scrolls by one row or column while the pointer is over the area of the last
+    /// render. Use [`handle_scroll`](Self::handle_scroll) with a
+    /// [`ScrollProcessor`](crate::input::ScrollProcessor) to coalesce the wheel events and glide
+    /// after the bursts of a trackpad.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        if !self.area.contains(mouse.position()) {
+            return false;
+        }
+        match mouse.kind {
+            MouseEventKind::ScrollUp => self.scroll_up(1),
+            MouseEventKind::ScrollDown => self.scroll_down(1),
+            MouseEventKind::ScrollLeft => self.scroll_left(1),
+            MouseEventKind::ScrollRight => self.scroll_right(1),
+            _ => false,
+        }
+    }
+
+    /// Handles a scroll and returns `true` if an offset changed.
+    ///
+    /// The content is scrolled by the deltas of the scroll while the pointer is over the area of
+    /// the last render.
+    pub fn handle_scroll(&mut self, scroll: Scroll) -> bool {
+        if !self.area.contains(scroll.position()) {
+            return false;
+        }
+        let rows = scroll.delta_row.unsigned_abs() as usize;
+        let columns = scroll.delta_column.unsigned_abs() as usize;
+        let vertical = if scroll.delta_row < 0 {
+            self.scroll_up(rows)
+        } else {
+            self.scroll_down(rows)
+        };
+        let horizontal = if scroll.delta_column < 0 {
+            self.scroll_left(columns)
+        } else {
+            self.scroll_right(columns)
+        };
+        vertical || horizontal
+    }
+
+    /// Returns the last offset showing the bottom of the content, once the viewport is known.
+    fn max_offset(&self) -> usize {
+        self.content_height.saturating_sub(self.viewport.max(1))
+    }
+
+    /// Returns the last column offset showing the right of the content, once the viewport is known.
+    fn max_column_offset(&self) -> usize {
+        self.content_width
+            .saturating_sub(self.viewport_width.max(1))
+    }
+}
+
+/// A widget with a text shown in a [`TooltipPopup`] while the mouse rests on it.
+///
+/// Rendering the decorator registers the area of the widget and its text in the
+/// [`TooltipState`] when the mouse is over it, the last widget rendered under the mouse winning.
+/// The popup itself is drawn by a [`TooltipPopup`] rendered with the same state after all the
+/// other widgets, so that it stays on top of them.
+///
+/// This is created by [`Decorate::tooltip`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     prelude::*,
+///     widgets::{
+///         decorator::{TooltipPopup, TooltipState},
+///         Decorate, Paragraph,
+///     },
+/// };
+///
+/// # fn ui(frame: &mut Frame, state: &mut TooltipState) {
+/// let save = Paragraph::new("💾").tooltip("Save the file (Ctrl+S)", state);
+/// frame.render_widget(save, Rect::new(0, 0, 2, 1));
+/// // after all the other widgets
+/// frame.render_stateful_widget(TooltipPopup::new(), frame.size(), state);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Tooltip<'s, W> {
+    widget: W,
+    text: String,
+    state: &'s mut TooltipState,
+}
+
+impl<'s, W: Widget> Tooltip<'s, W> {
+    /// Wraps the widget with the given tooltip text, registered in the given state.
+    pub fn new<T>(widget: W, text: T, state: &'s mut TooltipState) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            widget,
+            text: text.into(),
+            state,
+        }
+    }
+}
+
+impl<'s, W: Widget> Widget for Tooltip<'s, W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.widget.render(area, buf);
+        self.state.register(area, self.text);
+    }
+}
+
+impl<'s, W: Widget> Decorator<W> for Tooltip<'s, W> {
+    fn inner(&self) -> &W {
+        &self.widget
+    }
+
+    fn into_inner(self) -> W {
+        self.widget
+    }
+}
+
+/// The state of the tooltips of a frame, shown by a [`TooltipPopup`].
+///
+/// The state follows the mouse with [`handle_mouse`](Self::handle_mouse), and learns about the
+/// widgets under it from the [`Tooltip`] decorators, or [`register`](Self::register) for the
+/// parts of a widget such as truncated table cells. The tooltip of the hovered area is shown once
+/// the mouse has rested for the [delay](Self::with_delay), as checked by [`tick`](Self::tick),
+/// and hidden when the mouse leaves the area, a button is pressed or the wheel is scrolled.
+///
+/// The event loop should wait for input until the [`deadline`](Self::deadline), then call
+/// [`tick`](Self::tick) and redraw.
+///
+/// ```rust
+/// use std::time::{Duration, Instant};
+///
+/// use ratatui::{
+///     input::{MouseEvent, MouseEventKind},
+///     layout::Rect,
+///     widgets::decorator::TooltipState,
+/// };
+///
+/// let mut state = TooltipState::default();
+/// let now = Instant::now();
+/// state.handle_mouse_at(MouseEvent::new(MouseEventKind::Moved, 1, 0), now);
+/// // rendering a `Tooltip` and the `TooltipPopup`
+/// state.register(Rect::new(0, 0, 2, 1), "Save");
+/// state.end_frame();
+/// assert_eq!(state.deadline(), Some(now + Duration::from_millis(500)));
+/// assert!(state.tick(now + Duration::from_millis(500)));
+/// assert!(state.is_visible());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TooltipState {
+    delay: Duration,
+    /// Position of the mouse and when it moved there
+    mouse: Option<(Position, Instant)>,
+    /// Whether a click or a scroll hid the tooltip until the mouse moves again
+    dismissed: bool,
+    visible: bool,
+    /// Whether the tooltip was shown with `show`, and stays until the mouse moves
+    pinned: bool,
+    /// Area and text of the tooltip under the mouse during the last frame
+    hovered: Option<(Rect, String)>,
+    /// Area and text of the tooltip under the mouse registered during the current frame
+    pending: Option<(Rect, String)>,
+}
+
+impl Default for TooltipState {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_millis(500),
+            mouse: None,
+            dismissed: false,
+            visible: false,
+            pinned: false,
+            hovered: None,
+            pending: None,
+        }
+    }
+}
+
+impl TooltipState {
+    /// Creates a new state showing the tooltips after the mouse rests for 500 milliseconds.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how long the mouse must rest on a widget before its tooltip is shown.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Returns `true` if the tooltip of the hovered area is shown.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Returns the area and the text of the tooltip under the mouse during the last frame.
+    pub fn hovered(&self) -> Option<(Rect, &str)> {
+        self.hovered
+            .as_ref()
+            .map(|(area, text)| (*area, text.as_str()))
+    }
+
+    /// Hides the tooltip until the mouse moves again, e.g. when a key is pressed.
+    pub fn hide(&mut self) {
+        RedrawRequest::request_if(self.visible);
+        self.visible = false;
+        self.dismissed = true;
+        self.pinned = false;
+    }
+
+    /// Shows the given text next to the area right away, e.g. when a key is pressed on the
+    /// selected row of a table. The tooltip stays until the mouse moves or it is
+    /// [hidden](Self::hide).
+    pub fn show<T>(&mut self, area: Rect, text: T)
+    where
+        T: Into<String>,
+    {
+        self.hovered = Some((area, text.into()));
+        self.visible = true;
+        self.pinned = true;
+        self.dismissed = false;
+        RedrawRequest::request();
+    }
+
+    /// Handles a mouse event received now. See [`handle_mouse_at`](Self::handle_mouse_at).
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        self.handle_mouse_at(mouse, Instant::now())
+    }
+
+    /// Handles a mouse event received at the given instant. Returns `true` if the tooltip was
+    /// hidden.
+    ///
+    /// Moving the mouse restarts the delay, and hides the tooltip once the mouse leaves its area.
+    /// Pressing a button or scrolling hides it until the mouse moves again.
+    pub fn handle_mouse_at(&mut self, mouse: MouseEvent, now: Instant) -> bool {
+        let visible = self.visible;
+        let position = mouse.position();
+        if mouse.kind == MouseEventKind::Moved {
+            if self.mouse.map(|(p, _)| p) != Some(position) {
+                self.mouse = Some((position, now));
+                self.dismissed = false;
+                if self.pinned {
+                    self.pinned = false;
+                    self.visible = false;
+                }
+            }
+            let hovered = self.hovered.as_ref().map(|(area, _)| *area);
+            if !self.pinned && !hovered.is_some_and(|area| area.contains(position)) {
+                self.visible = false;
+            }
+        } else {
+            self.mouse = Some((position, now));
+            self.hide();
+        }
+        RedrawRequest::request_if(visible && !self.visible)
+    }
+
+    /// Registers the tooltip of the given area for the current frame, shown if the mouse is in
+    /// the area. This is called by the [`Tooltip`] decorator, and can be called for the parts of
+    /// a widget, e.g. the cells of a table.
+    pub fn register<T>(&mut self, area: Rect, text: T)
+    where
+        T: Into<String>,
+    {
+        if self
+            .mouse
+            .is_some_and(|(position, _)| area.contains(position))
+        {
+            self.pending = Some((area, text.into()));
+        }
+    }
+
+    /// Ends the current frame, making the tooltip registered under the mouse the hovered one.
+    /// This is called by the [`TooltipPopup`].
+    ///
+    /// The tooltip is hidden if the hovered area changed, e.g. when the widgets moved under the
+    /// mouse, unless it was [shown](Self::show) explicitly.
+    pub fn end_frame(&mut self) {
+        let pending = self.pending.take();
+        if self.pinned {
+            return;
+        }
+        if pending != self.hovered {
+            self.visible = false;
+            self.hovered = pending;
+        }
+    }
+
+    /// Returns when the tooltip of the hovered area will be shown, or `None` if there is none or
+    /// it is already shown.
+    pub fn deadline(&self) -> Option<Instant> {
+        if self.visible || self.dismissed || self.hovered.is_none() {
+            return None;
+        }
+        self.mouse.map(|(_, since)| since + self.delay)
+    }
+
+    /// Shows the tooltip of the hovered area once the mouse has rested for the delay. Returns
+    /// `true` if the tooltip was shown.
+    pub fn tick(&mut self, now: Instant) -> bool {
+        let show = self.deadline().is_some_and(|deadline| now >= deadline);
+        self.visible |= show;
+        RedrawRequest::request_if(show)
+    }
+}
+
+/// Where a [`TooltipPopup`] is placed relative to the area of its widget.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TooltipPlacement {
+    /// Below the widget, aligned on its left edge.
+    #[default]
+    Below,
+    /// Above the widget, aligned on its left edge.
+    Above,
+    /// Right of the widget, aligned on its top edge.
+    Right,
+    /// Left of the widget, aligned on its top edge.
+    Left,
+}
+
+impl TooltipPlacement {
+    /// Returns the placement on the other side of the widget.
+    fn flipped(self) -> Self {
+        match self {
+            Self::Below => Self::Above,
+            Self::Above => Self::Below,
+            Self::Right => Self::Left,
+            Self::Left => Self::Right,
+        }
+    }
+
+    /// Returns the room between the widget and the bounds on the side of the placement.
+    fn room(self, anchor: Rect, bounds: Rect) -> u16 {
+        match self {
+            Self::Below => bounds.bottom().saturating_sub(anchor.bottom()),
+            Self::Above => anchor.top().saturating_sub(bounds.top()),
+            Self::Right => bounds.right().saturating_sub(anchor.right()),
+            Self::Left => anchor.left().saturating_sub(bounds.left()),
+        }
+    }
+}
+
+/// The popup showing the text of the hovered [`Tooltip`] of a [`TooltipState`].
+///
+/// The popup is rendered after all the other widgets, in the area where it may be placed, usually
+/// the whole frame. The text is wrapped to the [`max_width`](Self::max_width) in a bordered block,
+/// and the popup is placed next to the widget on the side of its
+/// [`placement`](Self::placement), or on the opposite side when there is not enough room there.
+/// It is then moved along the widget to stay in the area.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TooltipPopup<'a> {
+    block: Block<'a>,
+    style: Style,
+    max_width: u16,
+    placement: TooltipPlacement,
+}
+
+impl<'a> Default for TooltipPopup<'a> {
+    fn default() -> Self {
+        Self {
+            block: Block::default().borders(Borders::ALL),
+            style: Style::default(),
+            max_width: 40,
+            placement: TooltipPlacement::default(),
+        }
+    }
+}
+
+impl<'a> TooltipPopup<'a> {
+    /// Creates a new popup with borders, below the widgets.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the block drawn around the text. Defaults to all the borders.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Sets the style of the popup.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the maximum width of the popup, including its block. Defaults to 40.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn max_width(mut self, max_width: u16) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the preferred side of the popup. Defaults to [`TooltipPlacement::Below`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placement(mut self, placement: TooltipPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Returns the area of a popup of the given size next to the anchor, inside the bounds.
+    fn place(&self, anchor: Rect, width: u16, height: u16, bounds: Rect) -> Rect {
+        let size = |placement| match placement {
+            TooltipPlacement::Below | TooltipPlacement::Above => height,
+            TooltipPlacement::Right | TooltipPlacement::Left => width,
+        };
+        let mut placement = self.placement;
+        let flipped = placement.flipped();
+        if placement.room(anchor, bounds) < size(placement)
+            && flipped.room(anchor, bounds) > placement.room(anchor, bounds)
+        {
+            placement = flipped;
+        }
+        let (x, y) = match placement {
+            TooltipPlacement::Below => (anchor.x, anchor.bottom()),
+            TooltipPlacement::Above => (anchor.x, anchor.y.saturating_sub(height)),
+            TooltipPlacement::Right => (anchor.right(), anchor.y),
+            TooltipPlacement::Left => (anchor.x.saturating_sub(width), anchor.y),
+        };
+        let clamp = |position: u16, size: u16, start: u16, end: u16| {
+            position.min(end.saturating_sub(size)).max(start)
+        };
+        let x = clamp(x, width, bounds.left(), bounds.right());
+        let y = clamp(y, height, bounds.top(), bounds.bottom());
+        Rect::new(x, y, width, height).intersection(bounds)
+    }
+}
+
+/// Wraps the text on the spaces between its words so that its lines fit in the given width.
+/// Longer words are kept on their own lines.
+fn wrap_words(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            if !line.is_empty() && line.width() + 1 + word.width() > width {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+impl<'a> StatefulWidget for TooltipPopup<'a> {
+    type State = TooltipState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.end_frame();
+        let Some((anchor, text)) = state.hovered.as_ref().filter(|_| state.visible) else {
+            return;
+        };
+        // the space taken by the block around the text
+        let probe = Rect::new(0, 0, u16::MAX / 2, u16::MAX / 2);
+        let inner = self.block.inner(probe);
+        let (margin_x, margin_y) = (probe.width - inner.width, probe.height - inner.height);
+
+        let max_width = self.max_width.min(area.width).saturating_sub(margin_x);
+        let lines = wrap_words(text, usize::from(max_width.max(1)));
+        let text_width = lines.iter().map(|line| line.width()).max().unwrap_or(0);
+        let width = (text_width as u16).min(max_width) + margin_x;
+        let height = (lines.len() as u16).saturating_add(margin_y);
+        let popup = self.place(*anchor, width, height, area);
+        if popup.is_empty() {
+            return;
+        }
+
+        Clear.render(popup, buf);
+        buf.set_style(popup, self.style);
+        let inner = self.block.inner(popup);
+        self.block.render(popup, buf);
+        for (y, line) in (inner.top()..inner.bottom()).zip(&lines) {
+            buf.set_stringn(inner.x, y, line, usize::from(inner.width), Style::default());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_buffer_eq,
+        input::{KeyModifiers, MouseButton},
+        style::Modifier,
+        widgets::Paragraph,
+    };
+
+    #[test]
+    fn bordered_and_titled() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+        Paragraph::new("hi")
+            .bordered()
+            .titled("Logs")
+            .render(buf.area, &mut buf);
+        assert_buffer_eq!(
+            buf,
+            Buffer::with_lines(vec!["┌Logs──┐", "│hi    │", "└──────┘"])
+        );
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 4));
+        Paragraph::new("hi")
+            .titled("Logs")
+            .bordered()
+            .render(buf.area, &mut buf);
+        assert_buffer_eq!(
+            buf,
+            Buffer::with_lines(vec!["┌──────┐", "│Logs  │", "│hi    │", "└──────┘"])
+        );
+    }
+
+    #[test]
+    fn styled_sets_base_style() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        let style = Style::new().add_modifier(Modifier::BOLD);
+        Paragraph::new("hi")
+            .styled(style)
+            .render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(vec!["hi  "]);
+        expected.set_style(expected.area, style);
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn decorator_gives_the_inner_widget() {
+        let bordered = Paragraph::new("hi").bordered();
+        assert_eq!(bordered.inner(), &Paragraph::new("hi"));
+        assert_eq!(bordered.into_inner(), Paragraph::new("hi"));
+    }
+
+    #[test]
+    fn scrollable_shows_the_offset_rows() {
+        let text = "one\ntwo\nthree\nfour\nfive";
+        let mut state = ScrollableState::new(5).with_offset(1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+        Paragraph::new(text)
+            .scrollable(&mut state)
+            .render(buf.area, &mut buf);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["two  █", "three║"]));
+
+        // the offset is clamped to the last rows
+        state.set_offset(10);
+        assert_eq!(state.offset(), 3);
+        let mut buf = Buffer::empty(buf.area);
+        Paragraph::new(text)
+            .scrollable(&mut state)
+            .scrollbar(false)
+            .render(buf.area, &mut buf);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["four  ", "five  "]));
+    }
+
+    #[test]
+    fn scrollable_without_overflow() {
+        let mut state = ScrollableState::new(1).with_offset(3);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        Paragraph::new("a\nb")
+            .bordered()
+            .scrollable(&mut state)
+            .render(buf.area, &mut buf);
+        assert_eq!(state.offset(), 0);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["┌──┐", "└──┘"]));
+    }
+
+    #[test]
+    fn scrollable_state_handles_input() {
+        let mut state = ScrollableState::new(10);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+        Paragraph::new("")
+            .scrollable(&mut state)
+            .render(buf.area, &mut buf);
+        assert!(state.handle_key(Key::Down.into()));
+        assert!(state.handle_key(Key::PageDown.into()));
+        assert_eq!(state.offset(), 5);
+        assert!(state.handle_key(Key::End.into()));
+        assert_eq!(state.offset(), 6);
+        assert!(!state.handle_key(Key::Down.into()));
+        assert!(state.handle_key(Key::Home.into()));
+        assert_eq!(state.offset(), 0);
+
+        let scroll_down = MouseEventKind::ScrollDown;
+        assert!(state.handle_mouse(MouseEvent::new(scroll_down, 1, 1)));
+        assert!(!state.handle_mouse(MouseEvent::new(scroll_down, 4, 1)));
+        assert_eq!(state.offset(), 1);
+    }
+
+    #[test]
+    fn scrollable_scrolls_horizontally() {
+        let text = "abcdefgh
+ijklmnop
+qrstuvwx";
+        let mut state = ScrollableState::new(3)
+            .with_content_width(8)
+            .with_column_offset(2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        Paragraph::new(text)
+            .scrollable(&mut state)
+            .render(buf.area, &mut buf);
+        // the horizontal scrollbar makes the content taller than the viewport
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["cdef█", "klmn█", "███═ "]));
+        assert_eq!(state.column_offset(), 2);
+
+        let mut buf = Buffer::empty(buf.area);
+        state.set_column_offset(10);
+        assert_eq!(state.column_offset(), 4);
+        Paragraph::new(text)
+            .scrollable(&mut state)
+            .scrollbar(false)
+            .render(buf.area, &mut buf);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["defgh", "lmnop", "tuvwx"]));
+        assert_eq!(state.column_offset(), 3);
+    }
+
+    #[test]
+    fn scrollable_state_handles_scrolls() {
+        let mut state = ScrollableState::new(10).with_content_width(10);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+        Paragraph::new("")
+            .scrollable(&mut state)
+            .scrollbar(false)
+            .render(buf.area, &mut buf);
+        assert!(state.handle_key(Key::Right.into()));
+        let scroll_right = MouseEventKind::ScrollRight;
+        assert!(state.handle_mouse(MouseEvent::new(scroll_right, 1, 1)));
+        assert_eq!(state.column_offset(), 2);
+
+        let scroll = |delta_column, delta_row| Scroll {
+            delta_column,
+            delta_row,
+            column: 0,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        };
+        assert!(state.handle_scroll(scroll(3, 2)));
+        assert_eq!((state.column_offset(), state.offset()), (5, 2));
+        assert!(state.handle_scroll(scroll(-10, 0)));
+        assert_eq!((state.column_offset(), state.offset()), (0, 2));
+        assert!(state.handle_scroll(scroll(0, -5)));
+        assert_eq!(state.offset(), 0);
+        assert!(!state.handle_scroll(Scroll {
+            column: 4,
+            ..scroll(1, 1)
+        }));
+    }
+
+    fn render_frame(state: &mut TooltipState, buf: &mut Buffer, widget_area: Rect) {
+        Paragraph::new("ab")
+            .tooltip("Save the file", state)
+            .render(widget_area, buf);
+        TooltipPopup::new().render(buf.area, buf, state);
+    }
+
+    #[test]
+    fn tooltip_shows_after_the_delay() {
+        let mut state = TooltipState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 5));
+        let widget_area = Rect::new(1, 1, 2, 1);
+        let now = Instant::now();
+        let moved = |x, y| MouseEvent::new(MouseEventKind::Moved, x, y);
+        state.handle_mouse_at(moved(1, 1), now);
+        render_frame(&mut state, &mut buf, widget_area);
+        assert_eq!(state.hovered(), Some((widget_area, "Save the file")));
+        assert_eq!(state.deadline(), Some(now + Duration::from_millis(500)));
+        assert!(!state.tick(now + Duration::from_millis(499)));
+        assert!(state.tick(now + Duration::from_millis(500)));
+
+        // moving in the area keeps the tooltip, leaving it hides the tooltip
+        assert!(!state.handle_mouse_at(moved(2, 1), now + Duration::from_millis(600)));
+        assert!(state.is_visible());
+        render_frame(&mut state, &mut buf, widget_area);
+        assert_buffer_eq!(
+            buf,
+            Buffer::with_lines(vec![
+                "                ",
+                " ab             ",
+                " ┌─────────────┐",
+                " │Save the file│",
+                " └─────────────┘",
+            ])
+        );
+        assert!(state.handle_mouse_at(moved(3, 1), now + Duration::from_millis(700)));
+        render_frame(&mut state, &mut buf, widget_area);
+        assert_eq!(state.hovered(), None);
+        assert_eq!(state.deadline(), None);
+    }
+
+    #[test]
+    fn tooltip_is_dismissed_by_a_click() {
+        let mut state = TooltipState::default().with_delay(Duration::ZERO);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 5));
+        let now = Instant::now();
+        state.handle_mouse_at(MouseEvent::new(MouseEventKind::Moved, 1, 1), now);
+        render_frame(&mut state, &mut buf, Rect::new(1, 1, 2, 1));
+        assert!(state.tick(now));
+        let click = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 1, 1);
+        assert!(state.handle_mouse_at(click, now));
+        assert_eq!(state.deadline(), None);
+        assert!(!state.tick(now));
+    }
+
+    #[test]
+    fn tooltip_shown_explicitly() {
+        let mut state = TooltipState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 5));
+        let now = Instant::now();
+        state.handle_mouse_at(MouseEvent::new(MouseEventKind::Moved, 9, 0), now);
+        state.show(Rect::new(1, 1, 2, 1), "Save the file");
+        // kept while the frames don't register it, until the mouse moves
+        render_frame(&mut state, &mut buf, Rect::new(1, 1, 2, 1));
+        assert!(state.is_visible());
+        assert_eq!(state.deadline(), None);
+        state.handle_mouse_at(MouseEvent::new(MouseEventKind::Moved, 9, 0), now);
+        assert!(state.is_visible());
+        assert!(state.handle_mouse_at(MouseEvent::new(MouseEventKind::Moved, 8, 0), now));
+        assert!(!state.is_visible());
+    }
+
+    #[test]
+    fn tooltip_popup_flips_and_stays_in_the_area() {
+        let bounds = Rect::new(0, 0, 20, 10);
+        let popup = TooltipPopup::new();
+        // no room below
+        assert_eq!(
+            popup.place(Rect::new(2, 8, 4, 2), 10, 3, bounds),
+            Rect::new(2, 5, 10, 3)
+        );
+        // no room above either, more room below
+        assert_eq!(
+            popup.place(Rect::new(2, 1, 4, 2), 10, 9, bounds),
+            Rect::new(2, 1, 10, 9)
+        );
+        // moved left to stay in the area
+        assert_eq!(
+            popup.place(Rect::new(15, 0, 4, 2), 10, 3, bounds),
+            Rect::new(10, 2, 10, 3)
+        );
+        let popup = popup.placement(TooltipPlacement::Right);
+        assert_eq!(
+            popup.place(Rect::new(12, 4, 4, 1), 6, 3, bounds),
+            Rect::new(6, 4, 6, 3)
+        );
+    }
+
+    #[test]
+    fn wraps_words() {
+        assert_eq!(
+            wrap_words("Save the file\nto disk", 8),
+            ["Save the", "file", "to disk"]
+        );
+        assert_eq!(wrap_words("a verylongword", 4), ["a", "verylongword"]);
+    }
+}
@@ -0,0 +1,857 @@
+//! Ready-made modal dialogs for common interactions.
+//!
+//! - [`confirm`] asks the user a yes / no question.
+//! - [`ConfirmDialog`] asks the user to choose between configurable `Yes`, `No` and `Cancel`
+//!   buttons.
+//! - [`prompt`] asks the user to enter a line of text.
+//!
+//! Dialogs are [`StatefulWidget`]s that render centered over the area they are given, clearing
+//! what is below them. Their state handles the keyboard input of the dialog and returns a typed
+//! [`DialogOutcome`] once the user submitted or cancelled it.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::{
+//!     input::{Key, KeyEvent},
+//!     prelude::*,
+//!     widgets::dialogs::{self, ConfirmState, DialogOutcome},
+//! };
+//!
+//! # fn ui(frame: &mut Frame, state: &mut ConfirmState) {
+//! frame.render_stateful_widget(dialogs::confirm("Delete file?"), frame.size(), state);
+//! # }
+//! let mut state = ConfirmState::default();
+//! assert_eq!(state.handle_key(KeyEvent::from(Key::Left)), None);
+//! assert_eq!(
+//!     state.handle_key(KeyEvent::from(Key::Enter)),
+//!     Some(DialogOutcome::Submitted(true))
+//! );
+//! ```
+use unicode_width::UnicodeWidthChar;
+
+use crate::{
+    buffer::Buffer,
+    input::{Key, KeyEvent, KeyEventKind, KeyModifiers},
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Clear, Paragraph, StatefulWidget, Widget},
+};
+
+const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+
+/// The outcome of a dialog once it is closed.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum DialogOutcome<T> {
+    /// The user submitted the dialog with the given value.
+    Submitted(T),
+    /// The user cancelled the dialog (e.g. by pressing `Esc`).
+    Cancelled,
+}
+
+/// Creates a [`Confirm`] dialog asking the given question.
+pub fn confirm<'a, T>(message: T) -> Confirm<'a>
+where
+    T: Into<Text<'a>>,
+{
+    Confirm::new(message)
+}
+
+/// Creates a [`Prompt`] dialog asking for a line of text.
+pub fn prompt<'a, T>(message: T) -> Prompt<'a>
+where
+    T: Into<Text<'a>>,
+{
+    Prompt::new(message)
+}
+
+/// A modal dialog asking a yes / no question.
+///
+/// The dialog shows the message above two buttons. The state of the dialog is stored in a
+/// [`ConfirmState`] which also handles the keyboard input:
+/// - `Left`, `Right`, `Tab` and `BackTab` move the selection between the buttons.
+/// - `Enter` submits the selected button.
+/// - `y` and `n` submit the corresponding button directly.
+/// - `Esc` cancels the dialog.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Confirm<'a> {
+    block: Block<'a>,
+    message: Text<'a>,
+    yes_label: Span<'a>,
+    no_label: Span<'a>,
+    style: Style,
+    highlight_style: Style,
+}
+
+impl<'a> Confirm<'a> {
+    /// Creates a new confirmation dialog with the given message.
+    pub fn new<T>(message: T) -> Self
+    where
+        T: Into<Text<'a>>,
+    {
+        Self {
+            block: Block::default().borders(Borders::ALL),
+            message: message.into(),
+            yes_label: Span::raw("Yes"),
+            no_label: Span::raw("No"),
+            style: Style::default(),
+            highlight_style: DEFAULT_HIGHLIGHT_STYLE,
+        }
+    }
+
+    /// Sets the block surrounding the dialog. Defaults to a block with all borders.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Sets the labels of the yes and no buttons.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn labels<Y, N>(mut self, yes: Y, no: N) -> Self
+    where
+        Y: Into<Span<'a>>,
+        N: Into<Span<'a>>,
+    {
+        self.yes_label = yes.into();
+        self.no_label = no.into();
+        self
+    }
+
+    /// Sets the style of the whole dialog.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the selected button. Defaults to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+}
+
+/// The state of a [`Confirm`] dialog.
+///
+/// The `No` button is selected by default so that pressing `Enter` right away does not confirm
+/// a destructive action by accident.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ConfirmState {
+    yes_selected: bool,
+}
+
+impl ConfirmState {
+    /// Returns `true` if the `Yes` button is selected.
+    pub fn is_yes_selected(&self) -> bool {
+        self.yes_selected
+    }
+
+    /// Selects the `Yes` button if `yes` is `true`, the `No` button otherwise.
+    pub fn select(&mut self, yes: bool) {
+        self.yes_selected = yes;
+    }
+
+    /// Handles a key event and returns the outcome of the dialog if it was closed.
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<DialogOutcome<bool>> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+        match key.key {
+            Key::Left | Key::Right | Key::Tab | Key::BackTab => {
+                self.yes_selected = !self.yes_selected;
+                None
+            }
+            Key::Enter => Some(DialogOutcome::Submitted(self.yes_selected)),
+            Key::Char('y' | 'Y') => Some(DialogOutcome::Submitted(true)),
+            Key::Char('n' | 'N') => Some(DialogOutcome::Submitted(false)),
+            Key::Esc => Some(DialogOutcome::Cancelled),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> StatefulWidget for Confirm<'a> {
+    type State = ConfirmState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let buttons = buttons_line(
+            vec![
+                (self.yes_label, state.yes_selected),
+                (self.no_label, !state.yes_selected),
+            ],
+            self.highlight_style,
+        );
+        render_question(self.block, self.style, self.message, buttons, area, buf);
+    }
+}
+
+/// An action chosen in a [`ConfirmDialog`], one per button of the dialog.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ConfirmAction {
+    /// The `Yes` button, also chosen with `y`.
+    Yes,
+    /// The `No` button, also chosen with `n`.
+    No,
+    /// The `Cancel` button, also chosen with `Esc`.
+    Cancel,
+}
+
+/// A modal dialog asking the user to choose between `Yes`, `No` and `Cancel`.
+///
+/// The dialog shows the message above its buttons, centered over the area it is rendered in and
+/// clearing what is below it. The buttons, their labels and the button selected when the dialog
+/// opens are configurable. The state of the dialog is stored in a [`ConfirmDialogState`] which
+/// also handles the keyboard input:
+/// - `Left`, `Right`, `Tab` and `BackTab` move the selection between the buttons.
+/// - `Enter` chooses the selected button.
+/// - `y` and `n` choose the `Yes` and `No` buttons directly.
+/// - `Esc` chooses the `Cancel` button, or the `No` button when there is no `Cancel` button.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     input::{Key, KeyEvent},
+///     prelude::*,
+///     widgets::*,
+/// };
+///
+/// # fn ui(frame: &mut Frame, state: &mut ConfirmDialogState) {
+/// let dialog = ConfirmDialog::new("Save the changes before closing?")
+///     .label(ConfirmAction::No, "Don't save")
+///     .default_action(ConfirmAction::Yes);
+/// frame.render_stateful_widget(dialog, frame.size(), state);
+/// # }
+/// let mut state = ConfirmDialogState::default();
+/// assert_eq!(state.handle_key(KeyEvent::from(Key::Esc)), Some(ConfirmAction::Cancel));
+/// assert_eq!(state.action(), Some(ConfirmAction::Cancel));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ConfirmDialog<'a> {
+    block: Block<'a>,
+    message: Text<'a>,
+    buttons: Vec<ConfirmAction>,
+    default_action: ConfirmAction,
+    yes_label: Span<'a>,
+    no_label: Span<'a>,
+    cancel_label: Span<'a>,
+    style: Style,
+    highlight_style: Style,
+}
+
+impl<'a> ConfirmDialog<'a> {
+    /// Creates a new dialog with the given message, showing the `Yes`, `No` and `Cancel` buttons.
+    pub fn new<T>(message: T) -> Self
+    where
+        T: Into<Text<'a>>,
+    {
+        Self {
+            block: Block::default().borders(Borders::ALL),
+            message: message.into(),
+            buttons: vec![ConfirmAction::Yes, ConfirmAction::No, ConfirmAction::Cancel],
+            default_action: ConfirmAction::No,
+            yes_label: Span::raw("Yes"),
+            no_label: Span::raw("No"),
+            cancel_label: Span::raw("Cancel"),
+            style: Style::default(),
+            highlight_style: DEFAULT_HIGHLIGHT_STYLE,
+        }
+    }
+
+    /// Sets the block surrounding the dialog. Defaults to a block with all borders.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Sets the buttons of the dialog, in the order they are shown.
+    ///
+    /// Defaults to the `Yes`, `No` and `Cancel` buttons. The keyboard shortcuts of the actions
+    /// without a button are ignored.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn buttons<I>(mut self, buttons: I) -> Self
+    where
+        I: IntoIterator<Item = ConfirmAction>,
+    {
+        self.buttons = buttons.into_iter().collect();
+        self
+    }
+
+    /// Sets the button selected when the dialog opens.
+    ///
+    /// Defaults to `No`, so that pressing `Enter` right away does not confirm a destructive action
+    /// by accident. The first button is selected if the dialog has no button for this action.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn default_action(mut self, action: ConfirmAction) -> Self {
+        self.default_action = action;
+        self
+    }
+
+    /// Sets the label of the button of an action.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label<T>(mut self, action: ConfirmAction, label: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        let label = label.into();
+        match action {
+            ConfirmAction::Yes => self.yes_label = label,
+            ConfirmAction::No => self.no_label = label,
+            ConfirmAction::Cancel => self.cancel_label = label,
+        }
+        self
+    }
+
+    /// Sets the style of the whole dialog.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the selected button. Defaults to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+}
+
+/// The state of a [`ConfirmDialog`].
+///
+/// The state remembers the buttons of the dialog when it is rendered, so that the keyboard
+/// shortcuts only choose the actions that have a button. Once an action is chosen, it is
+/// returned by [`ConfirmDialogState::handle_key`] and kept in [`ConfirmDialogState::action`]
+/// until the state is [reset](ConfirmDialogState::reset).
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct ConfirmDialogState {
+    buttons: Vec<ConfirmAction>,
+    selected: Option<ConfirmAction>,
+    action: Option<ConfirmAction>,
+}
+
+impl ConfirmDialogState {
+    /// Returns the action of the selected button, if the dialog was rendered or a button was
+    /// selected.
+    pub fn selected(&self) -> Option<ConfirmAction> {
+        self.selected
+    }
+
+    /// Selects the button of the given action.
+    pub fn select(&mut self, action: ConfirmAction) {
+        self.selected = Some(action);
+    }
+
+    /// Returns the action chosen by the user, if any.
+    pub fn action(&self) -> Option<ConfirmAction> {
+        self.action
+    }
+
+    /// Forgets the chosen action and the selected button, so that the dialog can be opened again
+    /// with its default button selected.
+    pub fn reset(&mut self) {
+        self.selected = None;
+        self.action = None;
+    }
+
+    /// Handles a key event and returns the chosen action if the dialog was closed.
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<ConfirmAction> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+        let action = match key.key {
+            Key::Left | Key::BackTab => {
+                self.select_offset(self.buttons.len().saturating_sub(1));
+                None
+            }
+            Key::Right | Key::Tab => {
+                self.select_offset(1);
+                None
+            }
+            Key::Enter => self.selected,
+            Key::Char('y' | 'Y') => self.button(ConfirmAction::Yes),
+            Key::Char('n' | 'N') => self.button(ConfirmAction::No),
+            Key::Esc => self
+                .button(ConfirmAction::Cancel)
+                .or_else(|| self.button(ConfirmAction::No)),
+            _ => None,
+        };
+        if action.is_some() {
+            self.action = action;
+        }
+        action
+    }
+
+    /// Returns the action if the dialog has a button for it. Every action is accepted before the
+    /// dialog is rendered for the first time.
+    fn button(&self, action: ConfirmAction) -> Option<ConfirmAction> {
+        (self.buttons.is_empty() || self.buttons.contains(&action)).then_some(action)
+    }
+
+    /// Selects the button `offset` positions after the selected one, wrapping around.
+    fn select_offset(&mut self, offset: usize) {
+        let count = self.buttons.len();
+        if count == 0 {
+            return;
+        }
+        let index = self
+            .selected
+            .and_then(|selected| self.buttons.iter().position(|&action| action == selected))
+            .map_or(0, |index| (index + offset) % count);
+        self.selected = Some(self.buttons[index]);
+    }
+}
+
+impl<'a> StatefulWidget for ConfirmDialog<'a> {
+    type State = ConfirmDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.buttons.clone_from(&self.buttons);
+        if !state
+            .selected
+            .is_some_and(|selected| self.buttons.contains(&selected))
+        {
+            state.selected = if self.buttons.contains(&self.default_action) {
+                Some(self.default_action)
+            } else {
+                self.buttons.first().copied()
+            };
+        }
+        let buttons = self
+            .buttons
+            .iter()
+            .map(|&action| {
+                let label = match action {
+                    ConfirmAction::Yes => self.yes_label.clone(),
+                    ConfirmAction::No => self.no_label.clone(),
+                    ConfirmAction::Cancel => self.cancel_label.clone(),
+                };
+                (label, state.selected == Some(action))
+            })
+            .collect();
+        let buttons = buttons_line(buttons, self.highlight_style);
+        render_question(self.block, self.style, self.message, buttons, area, buf);
+    }
+}
+
+/// A modal dialog asking for a line of text.
+///
+/// The dialog shows the message above an input line. The state of the dialog is stored in a
+/// [`PromptState`] which also handles the keyboard input:
+/// - Characters are appended to the value and `Backspace` removes the last one.
+/// - `Enter` submits the value.
+/// - `Esc` cancels the dialog.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Prompt<'a> {
+    block: Block<'a>,
+    message: Text<'a>,
+    style: Style,
+    input_style: Style,
+    cursor_style: Style,
+}
+
+impl<'a> Prompt<'a> {
+    /// Creates a new prompt dialog with the given message.
+    pub fn new<T>(message: T) -> Self
+    where
+        T: Into<Text<'a>>,
+    {
+        Self {
+            block: Block::default().borders(Borders::ALL),
+            message: message.into(),
+            style: Style::default(),
+            input_style: Style::default(),
+            cursor_style: DEFAULT_HIGHLIGHT_STYLE,
+        }
+    }
+
+    /// Sets the block surrounding the dialog. Defaults to a block with all borders.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Sets the style of the whole dialog.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the input line.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn input_style(mut self, style: Style) -> Self {
+        self.input_style = style;
+        self
+    }
+
+    /// Sets the style of the cursor shown at the end of the input. Defaults to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn cursor_style(mut self, style: Style) -> Self {
+        self.cursor_style = style;
+        self
+    }
+}
+
+/// The state of a [`Prompt`] dialog.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct PromptState {
+    value: String,
+}
+
+impl PromptState {
+    /// Creates a new state with the given initial value.
+    pub fn new<T>(value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            value: value.into(),
+        }
+    }
+
+    /// Returns the current value of the input.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Handles a key event and returns the outcome of the dialog if it was closed.
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<DialogOutcome<String>> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+        match key.key {
+            Key::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.value.push(c);
+                None
+            }
+            Key::Backspace => {
+                self.value.pop();
+                None
+            }
+            Key::Enter => Some(DialogOutcome::Submitted(self.value.clone())),
+            Key::Esc => Some(DialogOutcome::Cancelled),
+            _ => None,
+        }
+    }
+}
+
+impl StatefulWidget for Prompt<'_> {
+    type State = PromptState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        const MIN_INPUT_WIDTH: u16 = 20;
+        let content_width = (self.message.width() as u16).max(MIN_INPUT_WIDTH);
+        let message_height = self.message.height() as u16;
+        let dialog_area = render_frame(
+            self.block,
+            self.style,
+            area,
+            content_width,
+            message_height + 1,
+            buf,
+        );
+        let message_area = Rect {
+            height: message_height.min(dialog_area.height),
+            ..dialog_area
+        };
+        Paragraph::new(self.message).render(message_area, buf);
+        if dialog_area.height <= message_height || dialog_area.width == 0 {
+            return;
+        }
+        // show the end of the value, keeping one cell for the cursor
+        let y = dialog_area.bottom() - 1;
+        let available = usize::from(dialog_area.width - 1);
+        let mut width = 0;
+        let start = state
+            .value
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| {
+                width += c.width().unwrap_or(0);
+                width <= available
+            })
+            .last()
+            .map_or(state.value.len(), |(i, _)| i);
+        let input_line = Rect {
+            y,
+            height: 1,
+            ..dialog_area
+        };
+        buf.set_style(input_line, self.input_style);
+        let (x, _) = buf.set_stringn(
+            dialog_area.x,
+            y,
+            &state.value[start..],
+            available,
+            self.input_style,
+        );
+        buf.set_style(Rect::new(x, y, 1, 1), self.cursor_style);
+    }
+}
+
+/// Returns the line of the buttons of a dialog, highlighting the selected ones.
+fn buttons_line<'a>(buttons: Vec<(Span<'a>, bool)>, highlight_style: Style) -> Line<'a> {
+    let mut spans = Vec::with_capacity(buttons.len() * 2);
+    for (i, (label, selected)) in buttons.into_iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::raw("  "));
+        }
+        let style = if selected {
+            highlight_style
+        } else {
+            Style::default()
+        };
+        spans.push(Span::styled(
+            format!("[ {} ]", label.content),
+            label.style.patch(style),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Renders a dialog showing a centered message above a line of buttons.
+fn render_question(
+    block: Block,
+    style: Style,
+    message: Text,
+    buttons: Line,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let content_width = message.width().max(buttons.width()) as u16;
+    let message_height = message.height() as u16;
+    let dialog_area = render_frame(block, style, area, content_width, message_height + 2, buf);
+    let message_area = Rect {
+        height: message_height.min(dialog_area.height),
+        ..dialog_area
+    };
+    Paragraph::new(message)
+        .alignment(Alignment::Center)
+        .render(message_area, buf);
+    if dialog_area.height > message_height {
+        let x = dialog_area.x + dialog_area.width.saturating_sub(buttons.width() as u16) / 2;
+        buf.set_line(x, dialog_area.bottom() - 1, &buttons, dialog_area.width);
+    }
+}
+
+/// Clears and renders the frame of a dialog centered in `area`, returning the area inside the
+/// frame.
+fn render_frame(
+    block: Block,
+    style: Style,
+    area: Rect,
+    content_width: u16,
+    content_height: u16,
+    buf: &mut Buffer,
+) -> Rect {
+    // measure the space taken by the borders and titles of the block
+    let probe = Rect::new(0, 0, 100, 100);
+    let inner = block.inner(probe);
+    // plus one column of padding on each side
+    let width = (probe.width - inner.width)
+        .saturating_add(content_width)
+        .saturating_add(2);
+    let height = (probe.height - inner.height).saturating_add(content_height);
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    let dialog_area = Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    );
+    Clear.render(dialog_area, buf);
+    buf.set_style(dialog_area, style);
+    let inner = block.inner(dialog_area);
+    block.render(dialog_area, buf);
+    Rect {
+        x: inner.x.saturating_add(1).min(inner.right()),
+        width: inner.width.saturating_sub(2),
+        ..inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    #[test]
+    fn confirm_render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 24, 7));
+        let mut state = ConfirmState::default();
+        confirm("Delete file?").render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec![
+            "                        ",
+            "  ┌─────────────────┐   ",
+            "  │  Delete file?   │   ",
+            "  │                 │   ",
+            "  │ [ Yes ]  [ No ] │   ",
+            "  └─────────────────┘   ",
+            "                        ",
+        ]);
+        expected.set_style(Rect::new(13, 4, 6, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn confirm_handle_key() {
+        let mut state = ConfirmState::default();
+        assert!(!state.is_yes_selected());
+        assert_eq!(state.handle_key(Key::Tab.into()), None);
+        assert!(state.is_yes_selected());
+        assert_eq!(
+            state.handle_key(Key::Enter.into()),
+            Some(DialogOutcome::Submitted(true))
+        );
+        assert_eq!(
+            state.handle_key(Key::Char('n').into()),
+            Some(DialogOutcome::Submitted(false))
+        );
+        assert_eq!(
+            state.handle_key(Key::Esc.into()),
+            Some(DialogOutcome::Cancelled)
+        );
+    }
+
+    #[test]
+    fn confirm_dialog_render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 31, 5));
+        let mut state = ConfirmDialogState::default();
+        ConfirmDialog::new("Save changes?").render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec![
+            "┌─────────────────────────────┐",
+            "│        Save changes?        │",
+            "│                             │",
+            "│ [ Yes ]  [ No ]  [ Cancel ] │",
+            "└─────────────────────────────┘",
+        ]);
+        expected.set_style(Rect::new(11, 3, 6, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_buffer_eq!(buf, expected);
+        assert_eq!(state.selected(), Some(ConfirmAction::No));
+    }
+
+    #[test]
+    fn confirm_dialog_render_buttons() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 24, 5));
+        let mut state = ConfirmDialogState::default();
+        ConfirmDialog::new("Overwrite?")
+            .buttons([ConfirmAction::Yes, ConfirmAction::Cancel])
+            .label(ConfirmAction::Yes, "Sure")
+            .render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec![
+            "┌──────────────────────┐",
+            "│      Overwrite?      │",
+            "│                      │",
+            "│ [ Sure ]  [ Cancel ] │",
+            "└──────────────────────┘",
+        ]);
+        // the default action has no button, so the first button is selected
+        expected.set_style(Rect::new(2, 3, 8, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn confirm_dialog_handle_key() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 5));
+        let mut state = ConfirmDialogState::default();
+        let dialog = ConfirmDialog::new("Quit?").default_action(ConfirmAction::Yes);
+        dialog.clone().render(buf.area, &mut buf, &mut state);
+        assert_eq!(state.selected(), Some(ConfirmAction::Yes));
+        assert_eq!(state.handle_key(Key::Left.into()), None);
+        assert_eq!(state.selected(), Some(ConfirmAction::Cancel));
+        assert_eq!(state.handle_key(Key::Tab.into()), None);
+        assert_eq!(state.selected(), Some(ConfirmAction::Yes));
+        assert_eq!(state.action(), None);
+        assert_eq!(
+            state.handle_key(Key::Enter.into()),
+            Some(ConfirmAction::Yes)
+        );
+        assert_eq!(state.action(), Some(ConfirmAction::Yes));
+        assert_eq!(
+            state.handle_key(Key::Char('n').into()),
+            Some(ConfirmAction::No)
+        );
+
+        state.reset();
+        assert_eq!(state.action(), None);
+        dialog
+            .buttons([ConfirmAction::Yes, ConfirmAction::No])
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(state.selected(), Some(ConfirmAction::Yes));
+        // without a cancel button, escape chooses no
+        assert_eq!(state.handle_key(Key::Esc.into()), Some(ConfirmAction::No));
+    }
+
+    #[test]
+    fn confirm_dialog_ignores_shortcuts_without_button() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 5));
+        let mut state = ConfirmDialogState::default();
+        ConfirmDialog::new("Done")
+            .buttons([ConfirmAction::Yes])
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(state.handle_key(Key::Char('n').into()), None);
+        assert_eq!(state.handle_key(Key::Esc.into()), None);
+        assert_eq!(
+            state.handle_key(Key::Char('y').into()),
+            Some(ConfirmAction::Yes)
+        );
+    }
+
+    #[test]
+    fn prompt_render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 26, 6));
+        let mut state = PromptState::new("notes.txt");
+        prompt("New name:").render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec![
+            "                          ",
+            " ┌──────────────────────┐ ",
+            " │ New name:            │ ",
+            " │ notes.txt            │ ",
+            " └──────────────────────┘ ",
+            "                          ",
+        ]);
+        expected.set_style(Rect::new(12, 3, 1, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn prompt_render_scrolls_long_value() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 4));
+        let mut state = PromptState::new("0123456789");
+        prompt("Name").render(buf.area, &mut buf, &mut state);
+        let mut expected =
+            Buffer::with_lines(vec!["┌────────┐", "│ Name   │", "│ 56789  │", "└────────┘"]);
+        expected.set_style(Rect::new(7, 2, 1, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn prompt_handle_key() {
+        let mut state = PromptState::default();
+        for c in "abc".chars() {
+            assert_eq!(state.handle_key(Key::Char(c).into()), None);
+        }
+        state.handle_key(KeyEvent::new(Key::Char('w'), KeyModifiers::CONTROL));
+        state.handle_key(Key::Backspace.into());
+        assert_eq!(state.value(), "ab");
+        assert_eq!(
+            state.handle_key(Key::Enter.into()),
+            Some(DialogOutcome::Submitted("ab".to_string()))
+        );
+        assert_eq!(
+            state.handle_key(Key::Esc.into()),
+            Some(DialogOutcome::Cancelled)
+        );
+    }
+}
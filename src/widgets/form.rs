@@ -0,0 +1,793 @@
+#![deny(missing_docs)]
+use std::{borrow::Cow, collections::BTreeMap, fmt};
+
+use crate::{
+    buffer::Buffer,
+    input::{
+        Focusable, Input, Key, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind,
+    },
+    layout::{Position, Rect},
+    style::{Modifier, Style, Styled},
+    terminal::RedrawRequest,
+    text::Line,
+    widgets::{Block, CursorProvider, StatefulWidget, TextInput, TextInputState, Widget},
+};
+
+const DEFAULT_FOCUSED_STYLE: Style = Style::new().add_modifier(Modifier::BOLD);
+
+/// A group of labeled [`TextInput`]s edited and submitted together.
+///
+/// Each [`FormField`] is rendered on its own row: the labels are aligned in a column as wide as
+/// the widest label, followed by the inputs. Fields that don't fit in the render area are not
+/// rendered. The field having the focus is styled with the
+/// [`focused_style`](Form::focused_style), patched on top of its label and input.
+///
+/// The values of the fields are stored in a [`FormState`], keyed by the names of the fields. The
+/// state also handles the keyboard input:
+/// - `Tab` and `BackTab` move the focus to the next and previous fields, wrapping around.
+/// - `Enter` moves the focus to the next field, and submits the form from the last one.
+/// - The other keys are handled by the focused input.
+/// - A click with the left mouse button on the row of a field gives it the focus, and moves the
+///   cursor when it is on the input. The other mouse events are handled by the focused input,
+///   e.g. to select text by dragging.
+///
+/// The fields whose input is [disabled](TextInput::disabled) are skipped when moving the focus
+/// and are not validated on submit, while read-only fields get the focus as usual. A form whose
+/// fields are all disabled is itself skipped by a [`FocusManager`] it is
+/// [registered with](crate::input::FocusManager::register_state).
+///
+/// A struct can describe its own form by implementing [`FormData`], usually with
+/// `#[derive(Form)]` (enabled by the `derive` feature), which also writes the submitted values
+/// back into the struct.
+///
+/// [`FocusManager`]: crate::input::FocusManager
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{input::Key, prelude::*, widgets::*};
+///
+/// fn ui(frame: &mut Frame, state: &mut FormState) {
+///     let form = Form::new(vec![
+///         FormField::new("user", "User"),
+///         FormField::new("password", "Password").input(TextInput::new().mask_char('*')),
+///     ]);
+///     frame.render_stateful_widget(form, frame.size(), state);
+///     frame.set_cursor_for(state);
+/// }
+///
+/// # let mut terminal = Terminal::new(backend::TestBackend::new(30, 2))?;
+/// let mut state = FormState::default().with_value("user", "admin");
+/// terminal.draw(|frame| ui(frame, &mut state))?;
+/// state.handle_key(Key::Tab.into());
+/// state.handle_paste("hunter2");
+/// let values = state.handle_key(Key::Enter.into()).unwrap();
+/// assert_eq!(values["user"], "admin");
+/// assert_eq!(values["password"], "hunter2");
+/// # std::io::Result::Ok(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Form<'a> {
+    fields: Vec<FormField<'a>>,
+    block: Option<Block<'a>>,
+    style: Style,
+    label_style: Style,
+    focused_style: Style,
+    spacing: u16,
+}
+
+impl<'a> Form<'a> {
+    /// Creates a new form with the given fields, from top to bottom.
+    pub fn new(fields: Vec<FormField<'a>>) -> Self {
+        Self {
+            fields,
+            block: None,
+            style: Style::default(),
+            label_style: Style::default(),
+            focused_style: DEFAULT_FOCUSED_STYLE,
+            spacing: 1,
+        }
+    }
+
+    /// Surrounds the form with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the whole form.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the labels.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label_style(mut self, style: Style) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    /// Sets the style patched on top of the label and the input of the focused field. Defaults
+    /// to bold text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_style(mut self, style: Style) -> Self {
+        self.focused_style = style;
+        self
+    }
+
+    /// Sets the number of columns between the labels and the inputs. Defaults to 1.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+}
+
+impl<'a> Styled for Form<'a> {
+    type Item = Form<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// A labeled field of a [`Form`].
+///
+/// The name identifies the value of the field in the [`FormState`], the label is displayed next
+/// to the input.
+#[derive(Debug, Clone)]
+pub struct FormField<'a> {
+    name: Cow<'a, str>,
+    label: Line<'a>,
+    input: TextInput<'a>,
+}
+
+impl<'a> FormField<'a> {
+    /// Creates a new field with the given name and label.
+    pub fn new<N, L>(name: N, label: L) -> Self
+    where
+        N: Into<Cow<'a, str>>,
+        L: Into<Line<'a>>,
+    {
+        Self {
+            name: name.into(),
+            label: label.into(),
+            input: TextInput::new(),
+        }
+    }
+
+    /// Sets the input used to render the value of the field, e.g. to mask or validate it.
+    ///
+    /// Defaults to a [`TextInput`] without any block or validator.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn input(mut self, input: TextInput<'a>) -> Self {
+        self.input = input;
+        self
+    }
+}
+
+/// The state of a [`Form`].
+///
+/// It holds the [`TextInputState`] of every field, keyed by the name of the field, and which
+/// field has the focus. The states are created when the form is first rendered, or beforehand
+/// with [`with_value`](Self::with_value), and the states of the fields removed from the form are
+/// dropped on the next render.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct FormState {
+    fields: Vec<(String, TextInputState)>,
+    focused: usize,
+    /// Whether another widget has the focus, so that no field shows it
+    blurred: bool,
+    /// Area of the fields during the last render, one field per row
+    area: Rect,
+}
+
+impl FormState {
+    /// Sets the initial value of the field with the given name.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_value<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.set_value(name, value);
+        self
+    }
+
+    /// Returns the value of the field with the given name.
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.field(name).map(TextInputState::value)
+    }
+
+    /// Replaces the value of the field with the given name.
+    pub fn set_value<N, V>(&mut self, name: N, value: V)
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        let name = name.into();
+        match self.field_mut(&name) {
+            Some(field) => field.set_value(value),
+            None => self.fields.push((name, TextInputState::new(value))),
+        }
+    }
+
+    /// Returns the values of all the fields, keyed by their names.
+    pub fn values(&self) -> BTreeMap<String, String> {
+        self.fields
+            .iter()
+            .map(|(name, field)| (name.clone(), field.value().to_string()))
+            .collect()
+    }
+
+    /// Returns the state of the input of the field with the given name.
+    pub fn field(&self, name: &str) -> Option<&TextInputState> {
+        self.fields
+            .iter()
+            .find(|(field_name, _)| field_name == name)
+            .map(|(_, field)| field)
+    }
+
+    /// Returns a mutable reference to the state of the input of the field with the given name.
+    pub fn field_mut(&mut self, name: &str) -> Option<&mut TextInputState> {
+        self.fields
+            .iter_mut()
+            .find(|(field_name, _)| field_name == name)
+            .map(|(_, field)| field)
+    }
+
+    /// Returns the index of the focused field.
+    pub fn focused(&self) -> usize {
+        self.focused
+    }
+
+    /// Returns the name of the focused field, if the form has any field.
+    pub fn focused_name(&self) -> Option<&str> {
+        self.fields.get(self.focused).map(|(name, _)| name.as_str())
+    }
+
+    /// Gives the focus to the field at the given index.
+    pub fn focus(&mut self, index: usize) {
+        RedrawRequest::request_if(self.focused != index);
+        self.focused = index;
+        for (i, (_, field)) in self.fields.iter_mut().enumerate() {
+            field.set_focused(i == index && !self.blurred);
+        }
+    }
+
+    /// Returns `true` if the form has the focus. Defaults to `true`.
+    pub fn is_focused(&self) -> bool {
+        !self.blurred
+    }
+
+    /// Sets whether the form has the focus, e.g. from a [`FocusManager`].
+    ///
+    /// A form without the focus doesn't style its focused field nor draw its cursor, and keeps
+    /// which field is focused for when it gets the focus back.
+    ///
+    /// [`FocusManager`]: crate::input::FocusManager
+    pub fn set_focused(&mut self, focused: bool) {
+        RedrawRequest::request_if(self.blurred == focused);
+        self.blurred = !focused;
+        self.focus(self.focused);
+    }
+
+    /// Returns the area of the fields during the last render, e.g. to register the form in a
+    /// [`FocusManager`](crate::input::FocusManager).
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Gives the focus to the field with the given name. Returns `false` if there is no such
+    /// field.
+    pub fn focus_field(&mut self, name: &str) -> bool {
+        match self
+            .fields
+            .iter()
+            .position(|(field_name, _)| field_name == name)
+        {
+            Some(index) => {
+                self.focus(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves the focus to the next field that isn't disabled, wrapping around after the last
+    /// one.
+    pub fn focus_next(&mut self) {
+        if let Some(next) = self.enabled_field(1..=self.fields.len(), true) {
+            self.focus(next);
+        }
+    }
+
+    /// Moves the focus to the previous field that isn't disabled, wrapping around before the
+    /// first one.
+    pub fn focus_previous(&mut self) {
+        if let Some(previous) = self.enabled_field(1..=self.fields.len(), false) {
+            self.focus(previous);
+        }
+    }
+
+    /// Returns the index of the first field that isn't disabled, at the given distances from
+    /// the focused field, forwards or backwards with wrapping.
+    fn enabled_field<I>(&self, distances: I, forwards: bool) -> Option<usize>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let len = self.fields.len();
+        distances
+            .into_iter()
+            .map(|distance| {
+                if forwards {
+                    (self.focused + distance) % len
+                } else {
+                    (self.focused + len - distance % len) % len
+                }
+            })
+            .find(|&i| !self.fields[i].1.is_disabled())
+    }
+
+    /// Returns `true` if no field after the focused one can get the focus.
+    fn is_last_enabled(&self) -> bool {
+        self.fields
+            .iter()
+            .skip(self.focused + 1)
+            .all(|(_, field)| field.is_disabled())
+    }
+
+    /// Returns the position of the cursor of the focused field on screen during the last render,
+    /// if it was visible.
+    pub fn cursor_screen_position(&self) -> Option<Position> {
+        self.fields
+            .get(self.focused)
+            .and_then(|(_, field)| field.cursor_screen_position())
+    }
+
+    /// Submits the form and returns the values of the fields.
+    ///
+    /// The form is not submitted while the value of a field is invalid, see
+    /// [`TextInput::validator`]: the focus is then moved to the first invalid field and `None`
+    /// is returned. The values are validated when the form is rendered, and the disabled fields
+    /// are not validated. The values of all the fields are returned, including the disabled
+    /// ones.
+    pub fn submit(&mut self) -> Option<BTreeMap<String, String>> {
+        match self
+            .fields
+            .iter()
+            .position(|(_, field)| !field.is_disabled() && !field.is_valid())
+        {
+            Some(invalid) => {
+                self.focus(invalid);
+                None
+            }
+            None => Some(self.values()),
+        }
+    }
+
+    /// Handles a key event and returns the values of the fields if the form was submitted.
+    ///
+    /// See [`Form`] for the keys handled by the form, and [`submit`](Self::submit) for the
+    /// validation of the values.
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<BTreeMap<String, String>> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+        match key.key {
+            Key::Tab => self.focus_next(),
+            Key::BackTab => self.focus_previous(),
+            Key::Enter if self.is_last_enabled() => return self.submit(),
+            Key::Enter => self.focus_next(),
+            _ => {
+                if let Some((_, field)) = self.fields.get_mut(self.focused) {
+                    field.handle_key(key);
+                }
+            }
+        }
+        None
+    }
+
+    /// Handles a mouse event and returns `true` if it was handled.
+    ///
+    /// See [`Form`] for the mouse events handled by the form.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return self
+                .fields
+                .get_mut(self.focused)
+                .is_some_and(|(_, field)| field.handle_mouse(mouse));
+        }
+        if !self.area.contains(mouse.position()) {
+            return false;
+        }
+        let index = usize::from(mouse.row - self.area.y);
+        match self.fields.get(index) {
+            Some((_, field)) if !field.is_disabled() => {
+                self.focus(index);
+                self.fields[index].1.handle_mouse(mouse);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Inserts pasted text in the focused field. Returns `true` if the form has any field.
+    pub fn handle_paste(&mut self, text: &str) -> bool {
+        self.fields
+            .get_mut(self.focused)
+            .is_some_and(|(_, field)| field.handle_paste(text))
+    }
+}
+
+impl Focusable for FormState {
+    fn set_focused(&mut self, focused: bool) {
+        FormState::set_focused(self, focused);
+    }
+
+    fn is_disabled(&self) -> bool {
+        !self.fields.is_empty() && self.fields.iter().all(|(_, field)| field.is_disabled())
+    }
+}
+
+impl CursorProvider for FormState {
+    /// Returns the position of the cursor of the focused field.
+    fn cursor_position(&self) -> Option<Position> {
+        self.fields
+            .get(self.focused)
+            .and_then(|(_, field)| field.cursor_position())
+    }
+}
+
+impl<'a> StatefulWidget for Form<'a> {
+    type State = FormState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        // keep the states of the fields that are still in the form, in the order of the form
+        let mut previous = std::mem::take(&mut state.fields);
+        state.fields = self
+            .fields
+            .iter()
+            .map(|field| {
+                let mut input = previous
+                    .iter()
+                    .position(|(name, _)| *name == field.name)
+                    .map(|i| previous.swap_remove(i).1)
+                    .unwrap_or_default();
+                field.input.apply_flags(&mut input);
+                (field.name.to_string(), input)
+            })
+            .collect();
+        state.focused = state.focused.min(self.fields.len().saturating_sub(1));
+        // move the focus away from a field that was just disabled
+        let focused = state.enabled_field(0..state.fields.len(), true);
+        state.focus(focused.unwrap_or(state.focused));
+
+        buf.set_style(area, self.style);
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        state.area = area;
+        let label_width = self
+            .fields
+            .iter()
+            .map(|field| field.label.width() as u16)
+            .max()
+            .unwrap_or_default()
+            .min(area.width);
+        let input_x = area
+            .x
+            .saturating_add(label_width)
+            .saturating_add(self.spacing)
+            .min(area.right());
+        for (i, (field, (_, input_state))) in
+            self.fields.into_iter().zip(&mut state.fields).enumerate()
+        {
+            let y = area.y.saturating_add(i as u16);
+            if y >= area.bottom() {
+                // still validate the hidden fields, for submit
+                StatefulWidget::render(field.input, Rect::default(), buf, input_state);
+                continue;
+            }
+            let focused = i == state.focused && !state.blurred && !input_state.is_disabled();
+            let mut label_style = self.label_style;
+            let mut input = field.input;
+            if focused {
+                label_style = label_style.patch(self.focused_style);
+                let input_style = Styled::style(&input).patch(self.focused_style);
+                input = input.style(input_style);
+            }
+            buf.set_style(Rect::new(area.x, y, label_width, 1), label_style);
+            buf.set_line(area.x, y, &field.label, label_width);
+            let input_area = Rect::new(input_x, y, area.right() - input_x, 1);
+            StatefulWidget::render(input, input_area, buf, input_state);
+        }
+    }
+}
+
+impl<'a> Widget for Form<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = FormState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+/// A struct edited with a [`Form`], having a field of the form per field of the struct.
+///
+/// The trait is usually implemented with `#[derive(Form)]`, enabled by the `derive` feature,
+/// which names the fields of the form after the fields of the struct and validates their values
+/// with the [`FromStr`](std::str::FromStr) implementation of their types. See the documentation
+/// of the derive macro for the attributes customizing the fields.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// use ratatui::widgets::{FieldError, Form, FormData, FormField, FormState, TextInput};
+///
+/// struct Settings {
+///     port: u16,
+/// }
+///
+/// impl FormData for Settings {
+///     fn form<'a>() -> Form<'a> {
+///         let port = TextInput::new().validator(|value: &str| {
+///             value.parse::<u16>().map(|_| ()).map_err(|e| e.to_string())
+///         });
+///         Form::new(vec![FormField::new("port", "Port").input(port)])
+///     }
+///
+///     fn form_state(&self) -> FormState {
+///         FormState::default().with_value("port", self.port.to_string())
+///     }
+///
+///     fn update(&mut self, values: &BTreeMap<String, String>) -> Result<(), FieldError> {
+///         if let Some(port) = values.get("port") {
+///             self.port = port.parse().map_err(|e| FieldError::new("port", e))?;
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let mut settings = Settings { port: 80 };
+/// let mut state = settings.form_state();
+/// state.set_value("port", "8080");
+/// settings.update(&state.values())?;
+/// assert_eq!(settings.port, 8080);
+/// # Ok::<(), FieldError>(())
+/// ```
+pub trait FormData {
+    /// Returns the form editing the fields of the struct.
+    fn form<'a>() -> Form<'a>;
+
+    /// Returns the state of the [`form`](Self::form) holding the values of the struct.
+    fn form_state(&self) -> FormState;
+
+    /// Writes the values submitted with the [`form`](Self::form) back into the struct.
+    ///
+    /// The fields missing from the values are left unchanged. If a value doesn't parse, its
+    /// error is returned and the struct is left unchanged.
+    fn update(&mut self, values: &BTreeMap<String, String>) -> Result<(), FieldError>;
+}
+
+/// An error returned by [`FormData::update`] when a value doesn't parse into its field.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct FieldError {
+    name: String,
+    message: String,
+}
+
+impl FieldError {
+    /// Creates an error for the field with the given name.
+    pub fn new<N, M>(name: N, message: M) -> Self
+    where
+        N: Into<String>,
+        M: ToString,
+    {
+        Self {
+            name: name.into(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Returns the name of the field whose value doesn't parse.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the error message of the parser.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.message)
+    }
+}
+
+impl std::error::Error for FieldError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_buffer_eq,
+        input::{FocusManager, NonEmpty},
+    };
+
+    fn form() -> Form<'static> {
+        Form::new(vec![
+            FormField::new("name", "Name"),
+            FormField::new("password", "Password").input(TextInput::new().mask_char('*')),
+        ])
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 3));
+        let mut state = FormState::default()
+            .with_value("name", "Ann")
+            .with_value("password", "abc");
+        StatefulWidget::render(form(), buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec![
+            "Name     Ann    ",
+            "Password ***    ",
+            "                ",
+        ]);
+        expected.set_style(Rect::new(0, 0, 8, 1), DEFAULT_FOCUSED_STYLE);
+        expected.set_style(Rect::new(9, 0, 7, 1), DEFAULT_FOCUSED_STYLE);
+        expected.set_style(
+            Rect::new(12, 0, 1, 1),
+            Style::new().add_modifier(Modifier::REVERSED),
+        );
+        assert_buffer_eq!(buf, expected);
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(12, 0)));
+    }
+
+    #[test]
+    fn focus_traversal() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 2));
+        let mut state = FormState::default();
+        StatefulWidget::render(form(), buf.area, &mut buf, &mut state);
+        assert_eq!(state.focused_name(), Some("name"));
+        assert!(state.field("name").unwrap().is_focused());
+        assert!(!state.field("password").unwrap().is_focused());
+
+        state.handle_key(Key::Tab.into());
+        assert_eq!(state.focused_name(), Some("password"));
+        assert!(state.field("password").unwrap().is_focused());
+        state.handle_key(Key::Tab.into());
+        assert_eq!(state.focused_name(), Some("name"));
+        state.handle_key(Key::BackTab.into());
+        assert_eq!(state.focused_name(), Some("password"));
+        assert!(state.focus_field("name"));
+        assert!(!state.focus_field("email"));
+        assert_eq!(state.focused(), 0);
+    }
+
+    #[test]
+    fn form_loses_focus() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 2));
+        let mut state = FormState::default();
+        let mut focus = FocusManager::new().with_focused("list");
+        focus.apply(&"form", &mut state);
+        StatefulWidget::render(form(), buf.area, &mut buf, &mut state);
+        assert_eq!(state.area(), buf.area);
+        assert!(!state.is_focused());
+        assert!(!state.field("name").unwrap().is_focused());
+        assert_eq!(state.cursor_position(), None);
+        assert!(!buf.get(0, 0).modifier.contains(Modifier::BOLD));
+
+        focus.focus("form");
+        focus.apply(&"form", &mut state);
+        assert!(state.field("name").unwrap().is_focused());
+        assert!(!state.field("password").unwrap().is_focused());
+    }
+
+    #[test]
+    fn disabled_fields_are_skipped() {
+        let form = Form::new(vec![
+            FormField::new("id", "Id").input(TextInput::new().disabled(true)),
+            FormField::new("name", "Name").input(TextInput::new().validator(NonEmpty)),
+            FormField::new("code", "Code")
+                .input(TextInput::new().disabled(true).validator(NonEmpty)),
+        ]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 3));
+        let mut state = FormState::default().with_value("name", "Ann");
+        StatefulWidget::render(form, buf.area, &mut buf, &mut state);
+        assert_eq!(state.focused_name(), Some("name"));
+        state.handle_key(Key::Tab.into());
+        assert_eq!(state.focused_name(), Some("name"));
+        state.handle_key(Key::BackTab.into());
+        assert_eq!(state.focused_name(), Some("name"));
+        assert!(state.handle_paste("x"));
+        assert!(!state.field_mut("id").unwrap().handle_paste("x"));
+        // the invalid value of a disabled field doesn't prevent the submission
+        let values = state.handle_key(Key::Enter.into()).unwrap();
+        assert_eq!(values["name"], "Annx");
+        assert_eq!(values["code"], "");
+    }
+
+    #[test]
+    fn handle_mouse() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 3));
+        let mut state = FormState::default().with_value("password", "abc");
+        StatefulWidget::render(form(), buf.area, &mut buf, &mut state);
+        let left = MouseButton::Left;
+        // a click on the label focuses the field, a click on the input also moves the cursor
+        assert!(state.handle_mouse(MouseEvent::new(MouseEventKind::Down(left), 2, 1)));
+        assert_eq!(state.focused_name(), Some("password"));
+        assert_eq!(state.field("password").unwrap().cursor(), 3);
+        assert!(state.handle_mouse(MouseEvent::new(MouseEventKind::Down(left), 10, 1)));
+        assert_eq!(state.field("password").unwrap().cursor(), 1);
+        assert!(state.handle_mouse(MouseEvent::new(MouseEventKind::Drag(left), 12, 1)));
+        assert_eq!(state.field("password").unwrap().selected_text(), Some("bc"));
+        assert!(!state.handle_mouse(MouseEvent::new(MouseEventKind::Down(left), 2, 2)));
+        assert_eq!(state.focused_name(), Some("password"));
+    }
+
+    #[test]
+    fn submit() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 2));
+        let mut state = FormState::default();
+        StatefulWidget::render(form(), buf.area, &mut buf, &mut state);
+        for c in "Bob".chars() {
+            assert_eq!(state.handle_key(Key::Char(c).into()), None);
+        }
+        // enter moves to the next field, then submits from the last one
+        assert_eq!(state.handle_key(Key::Enter.into()), None);
+        assert_eq!(state.focused_name(), Some("password"));
+        assert!(state.handle_paste("secret"));
+        let values = state.handle_key(Key::Enter.into()).unwrap();
+        assert_eq!(
+            values,
+            BTreeMap::from([
+                ("name".to_string(), "Bob".to_string()),
+                ("password".to_string(), "secret".to_string()),
+            ])
+        );
+        assert_eq!(state.values(), values);
+    }
+
+    #[test]
+    fn submit_invalid() {
+        let form = Form::new(vec![
+            FormField::new("name", "Name").input(TextInput::new().validator(NonEmpty)),
+            FormField::new("email", "Email"),
+        ]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 2));
+        let mut state = FormState::default().with_value("email", "a@b.c");
+        state.focus(1);
+        StatefulWidget::render(form, buf.area, &mut buf, &mut state);
+        assert_eq!(state.handle_key(Key::Enter.into()), None);
+        assert_eq!(state.focused_name(), Some("name"));
+    }
+
+    #[test]
+    fn render_syncs_fields() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 2));
+        let mut state = FormState::default()
+            .with_value("password", "abc")
+            .with_value("removed", "x");
+        StatefulWidget::render(form(), buf.area, &mut buf, &mut state);
+        assert_eq!(state.value("name"), Some(""));
+        assert_eq!(state.value("password"), Some("abc"));
+        assert_eq!(state.value("removed"), None);
+        assert_eq!(state.focused_name(), Some("name"));
+    }
+}
@@ -1,20 +1,155 @@
 #![deny(missing_docs)]
 use crate::{
+    backend::Capabilities,
     buffer::Buffer,
-    layout::Rect,
+    error::{Error, Result},
+    layout::{Alignment, Rect},
     style::{Color, Style, Styled},
     symbols,
-    text::{Line, Span},
-    widgets::{Block, Widget},
+    text::Line,
+    widgets::{Block, VerticalAlignment, Widget},
 };
 
+/// The options shared by [`Gauge`] and [`LineGauge`], so that both widgets handle their ratio,
+/// label, thresholds and styles the same way.
+#[derive(Debug, Default, Clone, PartialEq)]
+struct GaugeCore<'a> {
+    block: Option<Block<'a>>,
+    /// Between 0 and 1, never NaN
+    ratio: f64,
+    label: Option<Line<'a>>,
+    style: Style,
+    gauge_style: Style,
+    /// Styles patched on the bar from a ratio, sorted by ratio, which is never NaN
+    thresholds: Vec<(f64, Style)>,
+    /// The ratio above 1 given to a clamping setter, never NaN
+    overflow: Option<f64>,
+    overflow_style: Style,
+    direction: GaugeDirection,
+}
+
+impl<'a> GaugeCore<'a> {
+    fn set_percent(&mut self, percent: u16) {
+        assert!(
+            percent <= 100,
+            "Percentage should be between 0 and 100 inclusively."
+        );
+        self.ratio = f64::from(percent) / 100.0;
+        self.overflow = None;
+    }
+
+    fn check_percent(percent: u16) -> Result<()> {
+        if percent > 100 {
+            return Err(Error::OutOfRange {
+                name: "percent",
+                range: "0..=100",
+            });
+        }
+        Ok(())
+    }
+
+    fn set_ratio(&mut self, ratio: f64) {
+        assert!(
+            (0.0..=1.0).contains(&ratio),
+            "Ratio should be between 0 and 1 inclusively."
+        );
+        self.ratio = ratio;
+        self.overflow = None;
+    }
+
+    /// Returns an error if the ratio is not between 0 and 1, or is NaN.
+    fn check_ratio(ratio: f64) -> Result<()> {
+        if !(0.0..=1.0).contains(&ratio) {
+            return Err(Error::OutOfRange {
+                name: "ratio",
+                range: "0.0..=1.0",
+            });
+        }
+        Ok(())
+    }
+
+    fn set_ratio_clamped(&mut self, ratio: f64) {
+        self.ratio = if ratio.is_nan() {
+            0.0
+        } else {
+            ratio.clamp(0.0, 1.0)
+        };
+        self.overflow = Some(ratio).filter(|ratio| *ratio > 1.0);
+    }
+
+    fn add_threshold(&mut self, ratio: f64, style: Style) {
+        if ratio.is_nan() {
+            return;
+        }
+        // the sort is stable: the styles of the same ratio are patched in the order given
+        self.thresholds.push((ratio, style));
+        self.thresholds.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    }
+
+    /// Returns the ratio given to the gauge, which exceeds 1 when it overflows.
+    fn value(&self) -> f64 {
+        self.overflow.unwrap_or(self.ratio)
+    }
+
+    /// Returns the style of the bar, patched with the styles of the thresholds reached and the
+    /// overflow style.
+    fn bar_style(&self) -> Style {
+        let style = self
+            .thresholds
+            .iter()
+            .take_while(|(ratio, _)| self.value() >= *ratio)
+            .fold(self.gauge_style, |style, (_, patch)| style.patch(*patch));
+        match self.overflow {
+            Some(_) => style.patch(self.overflow_style),
+            None => style,
+        }
+    }
+
+    /// Returns the label, the rounded percentage given by default, e.g. `120%` when the gauge
+    /// overflows.
+    fn label(&mut self) -> Line<'a> {
+        let percent = (self.value() * 100.0).round();
+        self.label
+            .take()
+            .unwrap_or_else(|| Line::from(format!("{percent}%")))
+    }
+
+    /// Renders the style and the block, and returns the area inside the block.
+    fn render_block(&mut self, area: Rect, buf: &mut Buffer) -> Rect {
+        buf.set_style(area, self.style);
+        match self.block.take() {
+            Some(block) => {
+                let inner_area = block.inner(area);
+                block.render(area, buf);
+                inner_area
+            }
+            None => area,
+        }
+    }
+}
+
+/// The direction in which a [`Gauge`] or a [`LineGauge`] is filled.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum GaugeDirection {
+    /// Fill from the left edge, the default.
+    #[default]
+    LeftToRight,
+    /// Fill from the right edge, e.g. for right-to-left layouts.
+    RightToLeft,
+    /// Fill from the bottom edge, e.g. for a level meter.
+    BottomToTop,
+    /// Fill from the top edge.
+    TopToBottom,
+}
+
 /// A widget to display a progress bar.
 ///
 /// A `Gauge` renders a bar filled according to the value given to [`Gauge::percent`] or
 /// [`Gauge::ratio`]. The bar width and height are defined by the [`Rect`] it is
 /// [rendered](Widget::render) in.  
-/// The associated label is always centered horizontally and vertically. If not set with
-/// [`Gauge::label`], the label is the percentage of the bar filled.  
+/// The associated label is centered horizontally and vertically, unless placed with
+/// [`Gauge::label_alignment`] and [`Gauge::label_position`]. If not set with [`Gauge::label`],
+/// the label is the percentage of the bar filled.  
 /// You might want to have a higher precision bar using [`Gauge::use_unicode`].
 ///
 /// This can be useful to indicate the progression of a task, like a download.
@@ -37,30 +172,29 @@ use crate::{
 ///
 /// # See also
 ///
-/// - [`LineGauge`] for a thin progress bar
+/// - [`LineGauge`] for a thin progress bar, taking the same options
 #[derive(Debug, Clone, PartialEq)]
 pub struct Gauge<'a> {
-    block: Option<Block<'a>>,
-    ratio: f64,
-    label: Option<Span<'a>>,
+    core: GaugeCore<'a>,
     use_unicode: bool,
-    style: Style,
-    gauge_style: Style,
+    label_alignment: Alignment,
+    label_position: VerticalAlignment,
 }
 
-impl<'a> Default for Gauge<'a> {
-    fn default() -> Gauge<'a> {
-        Gauge {
-            block: None,
-            ratio: 0.0,
-            label: None,
+impl Default for Gauge<'_> {
+    fn default() -> Self {
+        Self {
+            core: GaugeCore::default(),
             use_unicode: false,
-            style: Style::default(),
-            gauge_style: Style::default(),
+            label_alignment: Alignment::Center,
+            label_position: VerticalAlignment::Middle,
         }
     }
 }
 
+/// The ratio and the thresholds are never NaN, so a gauge is always equal to itself.
+impl Eq for Gauge<'_> {}
+
 impl<'a> Gauge<'a> {
     /// Surrounds the `Gauge` with a [`Block`].
     ///
@@ -68,7 +202,7 @@ impl<'a> Gauge<'a> {
     /// is reserved. Styles set on the block do **not** affect the bar itself.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn block(mut self, block: Block<'a>) -> Gauge<'a> {
-        self.block = Some(block);
+        self.core.block = Some(block);
         self
     }
 
@@ -76,21 +210,28 @@ impl<'a> Gauge<'a> {
     ///
     /// # Panics
     ///
-    /// This method panics if `percent` is **not** between 0 and 100 inclusively.
+    /// This method panics if `percent` is **not** between 0 and 100 inclusively. See
+    /// [`Gauge::try_percent`] for a version returning an error instead.
     ///
     /// # See also
     ///
     /// See [`Gauge::ratio`] to set from a float.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn percent(mut self, percent: u16) -> Gauge<'a> {
-        assert!(
-            percent <= 100,
-            "Percentage should be between 0 and 100 inclusively."
-        );
-        self.ratio = f64::from(percent) / 100.0;
+        self.core.set_percent(percent);
         self
     }
 
+    /// Sets the bar progression from a percentage, like [`Gauge::percent`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfRange`] if `percent` is **not** between 0 and 100 inclusively.
+    pub fn try_percent(self, percent: u16) -> Result<Gauge<'a>> {
+        GaugeCore::check_percent(percent)?;
+        Ok(self.percent(percent))
+    }
+
     /// Sets the bar progression from a ratio (float).
     ///
     /// `ratio` is the ratio between filled bar over empty bar (i.e. `3/4` completion is `0.75`).
@@ -98,31 +239,88 @@ impl<'a> Gauge<'a> {
     ///
     /// # Panics
     ///
-    /// This method panics if `ratio` is **not** between 0 and 1 inclusively.
+    /// This method panics if `ratio` is **not** between 0 and 1 inclusively. See
+    /// [`Gauge::try_ratio`] for a version returning an error instead, and
+    /// [`Gauge::ratio_clamped`] for a version clamping the ratio.
     ///
     /// # See also
     ///
     /// See [`Gauge::percent`] to set from a percentage.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn ratio(mut self, ratio: f64) -> Gauge<'a> {
-        assert!(
-            (0.0..=1.0).contains(&ratio),
-            "Ratio should be between 0 and 1 inclusively."
-        );
-        self.ratio = ratio;
+        self.core.set_ratio(ratio);
         self
     }
 
-    /// Sets the label to display in the center of the bar.
+    /// Sets the bar progression from a ratio (float), like [`Gauge::ratio`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfRange`] if `ratio` is **not** between 0 and 1 inclusively, or is
+    /// NaN.
+    pub fn try_ratio(self, ratio: f64) -> Result<Gauge<'a>> {
+        GaugeCore::check_ratio(ratio)?;
+        Ok(self.ratio(ratio))
+    }
+
+    /// Sets the bar progression from a ratio (float), like [`Gauge::ratio`], clamping it between
+    /// 0 and 1 instead of panicking, e.g. for a sensor reading slightly out of its range.
     ///
-    /// For a left-aligned label, see [`LineGauge`].
+    /// A NaN ratio shows an empty bar. A ratio above 1 fills the bar, which is patched with the
+    /// [`overflow_style`](Gauge::overflow_style), and is shown by the default label.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn ratio_clamped(mut self, ratio: f64) -> Gauge<'a> {
+        self.core.set_ratio_clamped(ratio);
+        self
+    }
+
+    /// Sets the bar progression from a percentage, like [`Gauge::percent`], clamping it to 100
+    /// instead of panicking.
+    ///
+    /// A percentage above 100 fills the bar, which is patched with the
+    /// [`overflow_style`](Gauge::overflow_style), and is shown by the default label.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn percent_clamped(mut self, percent: u16) -> Gauge<'a> {
+        self.core.set_ratio_clamped(f64::from(percent) / 100.0);
+        self
+    }
+
+    /// Sets the style patched on the bar when the ratio or the percentage given to
+    /// [`ratio_clamped`](Gauge::ratio_clamped) or [`percent_clamped`](Gauge::percent_clamped)
+    /// exceeds the full bar, e.g. to turn it red above 100%.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn overflow_style(mut self, style: Style) -> Gauge<'a> {
+        self.core.overflow_style = style;
+        self
+    }
+
+    /// Sets the label to display in the center of the bar, or where it is placed with
+    /// [`Gauge::label_alignment`] and [`Gauge::label_position`].
+    ///
+    /// The label is a [`Line`], so its spans can be styled separately.
     /// If the label is not defined, it is the percentage filled.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn label<T>(mut self, label: T) -> Gauge<'a>
     where
-        T: Into<Span<'a>>,
+        T: Into<Line<'a>>,
     {
-        self.label = Some(label.into());
+        self.core.label = Some(label.into());
+        self
+    }
+
+    /// Sets the horizontal alignment of the label in the bar. Defaults to
+    /// [`Alignment::Center`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label_alignment(mut self, alignment: Alignment) -> Gauge<'a> {
+        self.label_alignment = alignment;
+        self
+    }
+
+    /// Sets the row of the bar where the label is displayed, for a bar taller than one row.
+    /// Defaults to [`VerticalAlignment::Middle`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label_position(mut self, position: VerticalAlignment) -> Gauge<'a> {
+        self.label_position = position;
         self
     }
 
@@ -132,14 +330,49 @@ impl<'a> Gauge<'a> {
     /// except the bar itself). [`Block`] style set with [`Gauge::block`] takes precedence.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn style(mut self, style: Style) -> Gauge<'a> {
-        self.style = style;
+        self.core.style = style;
         self
     }
 
     /// Sets the style of the bar.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn gauge_style(mut self, style: Style) -> Gauge<'a> {
-        self.gauge_style = style;
+        self.core.gauge_style = style;
+        self
+    }
+
+    /// Patches the style of the bar with the given style once the ratio reaches the given one,
+    /// e.g. to turn the bar red from 90%.
+    ///
+    /// The styles of all the thresholds reached are patched on top of the
+    /// [`gauge_style`](Gauge::gauge_style), from the lowest ratio to the highest. A NaN ratio is
+    /// ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, widgets::*};
+    ///
+    /// Gauge::default()
+    ///     .gauge_style(Style::new().green())
+    ///     .threshold(0.75, Style::new().yellow())
+    ///     .threshold(0.9, Style::new().red())
+    ///     .ratio(0.8);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn threshold(mut self, ratio: f64, style: Style) -> Gauge<'a> {
+        self.core.add_threshold(ratio, style);
+        self
+    }
+
+    /// Sets the direction in which the bar is filled. Defaults to
+    /// [`GaugeDirection::LeftToRight`].
+    ///
+    /// The [unicode](Gauge::use_unicode) fractional blocks are only available when filling from
+    /// the left or from the bottom: the other directions round the filled part to whole cells.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn direction(mut self, direction: GaugeDirection) -> Gauge<'a> {
+        self.core.direction = direction;
         self
     }
 
@@ -148,6 +381,8 @@ impl<'a> Gauge<'a> {
     /// This enables the use of
     /// [unicode block characters](https://en.wikipedia.org/wiki/Block_Elements).
     /// This is useful to display a higher precision bar (8 extra fractional parts per cell).
+    ///
+    /// This is ignored on terminals lacking these characters, see [`Capabilities`].
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn use_unicode(mut self, unicode: bool) -> Gauge<'a> {
         self.use_unicode = unicode;
@@ -157,61 +392,116 @@ impl<'a> Gauge<'a> {
 
 impl<'a> Widget for Gauge<'a> {
     fn render(mut self, area: Rect, buf: &mut Buffer) {
-        buf.set_style(area, self.style);
-        let gauge_area = match self.block.take() {
-            Some(b) => {
-                let inner_area = b.inner(area);
-                b.render(area, buf);
-                inner_area
-            }
-            None => area,
-        };
-        buf.set_style(gauge_area, self.gauge_style);
+        self.use_unicode &= Capabilities::current().block_fractions;
+        let gauge_area = self.core.render_block(area, buf);
+        let gauge_style = self.core.bar_style();
+        buf.set_style(gauge_area, gauge_style);
         if gauge_area.height < 1 {
             return;
         }
 
         // compute label value and its position
-        // label is put at the center of the gauge_area
-        let label = {
-            let pct = f64::round(self.ratio * 100.0);
-            self.label.unwrap_or_else(|| Span::from(format!("{pct}%")))
-        };
+        let label = self.core.label();
         let clamped_label_width = gauge_area.width.min(label.width() as u16);
-        let label_col = gauge_area.left() + (gauge_area.width - clamped_label_width) / 2;
-        let label_row = gauge_area.top() + gauge_area.height / 2;
+        let label_col = gauge_area.left()
+            + match self.label_alignment {
+                Alignment::Left => 0,
+                Alignment::Center => (gauge_area.width - clamped_label_width) / 2,
+                Alignment::Right => gauge_area.width - clamped_label_width,
+            };
+        let label_row = match self.label_position {
+            VerticalAlignment::Top => gauge_area.top(),
+            VerticalAlignment::Middle => gauge_area.top() + gauge_area.height / 2,
+            VerticalAlignment::Bottom => gauge_area.bottom() - 1,
+        };
 
         // the gauge will be filled proportionally to the ratio
-        let filled_width = f64::from(gauge_area.width) * self.ratio;
-        let end = if self.use_unicode {
-            gauge_area.left() + filled_width.floor() as u16
+        let ratio = self.core.ratio;
+        let direction = self.core.direction;
+        let length = match direction {
+            GaugeDirection::LeftToRight | GaugeDirection::RightToLeft => gauge_area.width,
+            GaugeDirection::BottomToTop | GaugeDirection::TopToBottom => gauge_area.height,
+        };
+        let filled_length = f64::from(length) * ratio;
+        // the fractional blocks are drawn from the left or from the bottom of a cell
+        let fractional = self.use_unicode
+            && matches!(
+                direction,
+                GaugeDirection::LeftToRight | GaugeDirection::BottomToTop
+            );
+        let filled = if fractional {
+            filled_length.floor() as u16
         } else {
-            gauge_area.left() + filled_width.round() as u16
+            filled_length.round() as u16
+        };
+        let filled_area = match direction {
+            GaugeDirection::LeftToRight => Rect {
+                width: filled,
+                ..gauge_area
+            },
+            GaugeDirection::RightToLeft => Rect {
+                x: gauge_area.right() - filled,
+                width: filled,
+                ..gauge_area
+            },
+            GaugeDirection::TopToBottom => Rect {
+                height: filled,
+                ..gauge_area
+            },
+            GaugeDirection::BottomToTop => Rect {
+                y: gauge_area.bottom() - filled,
+                height: filled,
+                ..gauge_area
+            },
         };
-        for y in gauge_area.top()..gauge_area.bottom() {
-            // render the filled area (left to end)
-            for x in gauge_area.left()..end {
+        for y in filled_area.top()..filled_area.bottom() {
+            for x in filled_area.left()..filled_area.right() {
                 let cell = buf.get_mut(x, y);
                 // Use full block for the filled part of the gauge and spaces for the part that is
                 // covered by the label. Note that the background and foreground colors are swapped
                 // for the label part, otherwise the gauge will be inverted
                 if x < label_col || x > label_col + clamped_label_width || y != label_row {
                     cell.set_symbol(symbols::block::FULL)
-                        .set_fg(self.gauge_style.fg.unwrap_or(Color::Reset))
-                        .set_bg(self.gauge_style.bg.unwrap_or(Color::Reset));
+                        .set_fg(gauge_style.fg.unwrap_or(Color::Reset))
+                        .set_bg(gauge_style.bg.unwrap_or(Color::Reset));
                 } else {
                     cell.set_symbol(" ")
-                        .set_fg(self.gauge_style.bg.unwrap_or(Color::Reset))
-                        .set_bg(self.gauge_style.fg.unwrap_or(Color::Reset));
+                        .set_fg(gauge_style.bg.unwrap_or(Color::Reset))
+                        .set_bg(gauge_style.fg.unwrap_or(Color::Reset));
                 }
             }
-            if self.use_unicode && self.ratio < 1.0 {
-                buf.get_mut(end, y)
-                    .set_symbol(get_unicode_block(filled_width % 1.0));
+        }
+        if fractional && ratio < 1.0 {
+            let fraction = filled_length % 1.0;
+            if direction == GaugeDirection::LeftToRight {
+                for y in gauge_area.top()..gauge_area.bottom() {
+                    buf.get_mut(filled_area.right(), y)
+                        .set_symbol(get_unicode_block(fraction));
+                }
+            } else {
+                for x in gauge_area.left()..gauge_area.right() {
+                    buf.get_mut(x, filled_area.top() - 1)
+                        .set_symbol(get_unicode_bar(fraction));
+                }
             }
         }
         // render the label
-        buf.set_span(label_col, label_row, &label, clamped_label_width);
+        buf.set_line(label_col, label_row, &label, clamped_label_width);
+    }
+}
+
+/// Returns the block filling the bottom of a cell with the given fraction.
+fn get_unicode_bar<'a>(frac: f64) -> &'a str {
+    match (frac * 8.0).round() as u16 {
+        1 => symbols::bar::ONE_EIGHTH,
+        2 => symbols::bar::ONE_QUARTER,
+        3 => symbols::bar::THREE_EIGHTHS,
+        4 => symbols::bar::HALF,
+        5 => symbols::bar::FIVE_EIGHTHS,
+        6 => symbols::bar::THREE_QUARTERS,
+        7 => symbols::bar::SEVEN_EIGHTHS,
+        8 => symbols::bar::FULL,
+        _ => " ",
     }
 }
 
@@ -236,7 +526,9 @@ fn get_unicode_block<'a>(frac: f64) -> &'a str {
 /// The height is always 1.  
 /// The associated label is always left-aligned. If not set with [`LineGauge::label`], the label
 /// is the percentage of the bar filled.  
-/// You can also set the symbols used to draw the bar with [`LineGauge::line_set`].
+/// You can also set the symbols used to draw the bar with [`LineGauge::line_set`], or those of
+/// its filled and unfilled parts independently with [`LineGauge::filled_symbol`] and
+/// [`LineGauge::unfilled_symbol`].
 ///
 /// This can be useful to indicate the progression of a task, like a download.
 ///
@@ -259,25 +551,48 @@ fn get_unicode_block<'a>(frac: f64) -> &'a str {
 ///
 /// # See also
 ///
-/// - [`Gauge`] for bigger, higher precision and more configurable progress bar
+/// - [`Gauge`] for bigger, higher precision progress bar, taking the same options
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct LineGauge<'a> {
-    block: Option<Block<'a>>,
-    ratio: f64,
-    label: Option<Line<'a>>,
+    core: GaugeCore<'a>,
     line_set: symbols::line::Set,
-    style: Style,
-    gauge_style: Style,
+    filled_symbol: Option<&'a str>,
+    unfilled_symbol: Option<&'a str>,
 }
 
+/// The ratio and the thresholds are never NaN, so a gauge is always equal to itself.
+impl Eq for LineGauge<'_> {}
+
 impl<'a> LineGauge<'a> {
     /// Surrounds the `LineGauge` with a [`Block`].
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn block(mut self, block: Block<'a>) -> Self {
-        self.block = Some(block);
+        self.core.block = Some(block);
+        self
+    }
+
+    /// Sets the bar progression from a percentage.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `percent` is **not** between 0 and 100 inclusively. See
+    /// [`LineGauge::try_percent`] for a version returning an error instead.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn percent(mut self, percent: u16) -> Self {
+        self.core.set_percent(percent);
         self
     }
 
+    /// Sets the bar progression from a percentage, like [`LineGauge::percent`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfRange`] if `percent` is **not** between 0 and 100 inclusively.
+    pub fn try_percent(self, percent: u16) -> Result<Self> {
+        GaugeCore::check_percent(percent)?;
+        Ok(self.percent(percent))
+    }
+
     /// Sets the bar progression from a ratio (float).
     ///
     /// `ratio` is the ratio between filled bar over empty bar (i.e. `3/4` completion is `0.75`).
@@ -285,14 +600,65 @@ impl<'a> LineGauge<'a> {
     ///
     /// # Panics
     ///
-    /// This method panics if `ratio` is **not** between 0 and 1 inclusively.
+    /// This method panics if `ratio` is **not** between 0 and 1 inclusively. See
+    /// [`LineGauge::try_ratio`] for a version returning an error instead, and
+    /// [`LineGauge::ratio_clamped`] for a version clamping the ratio.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn ratio(mut self, ratio: f64) -> Self {
-        assert!(
-            (0.0..=1.0).contains(&ratio),
-            "Ratio should be between 0 and 1 inclusively."
-        );
-        self.ratio = ratio;
+        self.core.set_ratio(ratio);
+        self
+    }
+
+    /// Sets the bar progression from a ratio (float), like [`LineGauge::ratio`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfRange`] if `ratio` is **not** between 0 and 1 inclusively, or is
+    /// NaN.
+    pub fn try_ratio(self, ratio: f64) -> Result<Self> {
+        GaugeCore::check_ratio(ratio)?;
+        Ok(self.ratio(ratio))
+    }
+
+    /// Sets the bar progression from a ratio (float), like [`LineGauge::ratio`], clamping it
+    /// between 0 and 1 instead of panicking, e.g. for a sensor reading slightly out of its range.
+    ///
+    /// A NaN ratio shows an empty bar. A ratio above 1 fills the bar, which is patched with the
+    /// [`overflow_style`](LineGauge::overflow_style), and is shown by the default label.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn ratio_clamped(mut self, ratio: f64) -> Self {
+        self.core.set_ratio_clamped(ratio);
+        self
+    }
+
+    /// Sets the bar progression from a percentage, like [`LineGauge::percent`], clamping it to 100
+    /// instead of panicking.
+    ///
+    /// A percentage above 100 fills the bar, which is patched with the
+    /// [`overflow_style`](LineGauge::overflow_style), and is shown by the default label.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn percent_clamped(mut self, percent: u16) -> Self {
+        self.core.set_ratio_clamped(f64::from(percent) / 100.0);
+        self
+    }
+
+    /// Sets the style patched on the bar when the ratio or the percentage given to
+    /// [`ratio_clamped`](LineGauge::ratio_clamped) or [`percent_clamped`](LineGauge::percent_clamped)
+    /// exceeds the full bar, e.g. to turn it red above 100%.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn overflow_style(mut self, style: Style) -> Self {
+        self.core.overflow_style = style;
+        self
+    }
+
+    /// Sets the direction in which the line is filled. Defaults to
+    /// [`GaugeDirection::LeftToRight`].
+    ///
+    /// The line is a single row after the label, so it is filled from its right end with
+    /// [`GaugeDirection::RightToLeft`], and from its left end with the other directions.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn direction(mut self, direction: GaugeDirection) -> Self {
+        self.core.direction = direction;
         self
     }
 
@@ -309,15 +675,43 @@ impl<'a> LineGauge<'a> {
         self
     }
 
+    /// Sets the symbol drawing the filled part of the line, e.g. a thick line over a thin track.
+    /// Defaults to the horizontal symbol of the [`line_set`](LineGauge::line_set).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{prelude::*, widgets::*};
+    ///
+    /// LineGauge::default()
+    ///     .filled_symbol(symbols::line::THICK_HORIZONTAL)
+    ///     .unfilled_symbol(symbols::line::HORIZONTAL)
+    ///     .ratio(0.4);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn filled_symbol(mut self, symbol: &'a str) -> Self {
+        self.filled_symbol = Some(symbol);
+        self
+    }
+
+    /// Sets the symbol drawing the unfilled part of the line, the track. Defaults to the
+    /// horizontal symbol of the [`line_set`](LineGauge::line_set).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn unfilled_symbol(mut self, symbol: &'a str) -> Self {
+        self.unfilled_symbol = Some(symbol);
+        self
+    }
+
     /// Sets the label to display.
     ///
     /// With `LineGauge`, labels are only on the left, see [`Gauge`] for a centered label.
     /// If the label is not defined, it is the percentage filled.
+    #[must_use = "method moves the value of self and returns the modified value"]
     pub fn label<T>(mut self, label: T) -> Self
     where
         T: Into<Line<'a>>,
     {
-        self.label = Some(label.into());
+        self.core.label = Some(label.into());
         self
     }
 
@@ -327,38 +721,39 @@ impl<'a> LineGauge<'a> {
     /// background.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn style(mut self, style: Style) -> Self {
-        self.style = style;
+        self.core.style = style;
         self
     }
 
     /// Sets the style of the bar.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn gauge_style(mut self, style: Style) -> Self {
-        self.gauge_style = style;
+        self.core.gauge_style = style;
+        self
+    }
+
+    /// Patches the style of the bar with the given style once the ratio reaches the given one,
+    /// e.g. to turn the bar red from 90%.
+    ///
+    /// The styles of all the thresholds reached are patched on top of the
+    /// [`gauge_style`](LineGauge::gauge_style), from the lowest ratio to the highest. A NaN ratio
+    /// is ignored.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn threshold(mut self, ratio: f64, style: Style) -> Self {
+        self.core.add_threshold(ratio, style);
         self
     }
 }
 
 impl<'a> Widget for LineGauge<'a> {
     fn render(mut self, area: Rect, buf: &mut Buffer) {
-        buf.set_style(area, self.style);
-        let gauge_area = match self.block.take() {
-            Some(b) => {
-                let inner_area = b.inner(area);
-                b.render(area, buf);
-                inner_area
-            }
-            None => area,
-        };
-
+        let gauge_area = self.core.render_block(area, buf);
         if gauge_area.height < 1 {
             return;
         }
 
-        let ratio = self.ratio;
-        let label = self
-            .label
-            .unwrap_or_else(move || Line::from(format!("{:.0}%", ratio * 100.0)));
+        let gauge_style = self.core.bar_style();
+        let label = self.core.label();
         let (col, row) = buf.set_line(
             gauge_area.left(),
             gauge_area.top(),
@@ -370,30 +765,38 @@ impl<'a> Widget for LineGauge<'a> {
             return;
         }
 
-        let end = start
-            + (f64::from(gauge_area.right().saturating_sub(start)) * self.ratio).floor() as u16;
-        for col in start..end {
+        let filled =
+            (f64::from(gauge_area.right().saturating_sub(start)) * self.core.ratio).floor() as u16;
+        let (filled, unfilled) = if self.core.direction == GaugeDirection::RightToLeft {
+            let end = gauge_area.right() - filled;
+            (end..gauge_area.right(), start..end)
+        } else {
+            (start..start + filled, start + filled..gauge_area.right())
+        };
+        let filled_symbol = self.filled_symbol.unwrap_or(self.line_set.horizontal);
+        let unfilled_symbol = self.unfilled_symbol.unwrap_or(self.line_set.horizontal);
+        for col in filled {
             buf.get_mut(col, row)
-                .set_symbol(self.line_set.horizontal)
+                .set_symbol(filled_symbol)
                 .set_style(Style {
-                    fg: self.gauge_style.fg,
+                    fg: gauge_style.fg,
                     bg: None,
                     #[cfg(feature = "underline-color")]
-                    underline_color: self.gauge_style.underline_color,
-                    add_modifier: self.gauge_style.add_modifier,
-                    sub_modifier: self.gauge_style.sub_modifier,
+                    underline_color: gauge_style.underline_color,
+                    add_modifier: gauge_style.add_modifier,
+                    sub_modifier: gauge_style.sub_modifier,
                 });
         }
-        for col in end..gauge_area.right() {
+        for col in unfilled {
             buf.get_mut(col, row)
-                .set_symbol(self.line_set.horizontal)
+                .set_symbol(unfilled_symbol)
                 .set_style(Style {
-                    fg: self.gauge_style.bg,
+                    fg: gauge_style.bg,
                     bg: None,
                     #[cfg(feature = "underline-color")]
-                    underline_color: self.gauge_style.underline_color,
-                    add_modifier: self.gauge_style.add_modifier,
-                    sub_modifier: self.gauge_style.sub_modifier,
+                    underline_color: gauge_style.underline_color,
+                    add_modifier: gauge_style.add_modifier,
+                    sub_modifier: gauge_style.sub_modifier,
                 });
         }
     }
@@ -403,7 +806,7 @@ impl<'a> Styled for Gauge<'a> {
     type Item = Gauge<'a>;
 
     fn style(&self) -> Style {
-        self.style
+        self.core.style
     }
 
     fn set_style(self, style: Style) -> Self::Item {
@@ -415,7 +818,7 @@ impl<'a> Styled for LineGauge<'a> {
     type Item = LineGauge<'a>;
 
     fn style(&self) -> Style {
-        self.style
+        self.core.style
     }
 
     fn set_style(self, style: Style) -> Self::Item {
@@ -446,10 +849,36 @@ mod tests {
         let _ = Gauge::default().ratio(-0.5);
     }
 
+    #[test]
+    fn gauge_try_invalid_values() {
+        assert_eq!(Gauge::default().try_percent(50).unwrap().core.ratio, 0.5);
+        assert!(matches!(
+            Gauge::default().try_percent(110),
+            Err(Error::OutOfRange {
+                name: "percent",
+                ..
+            })
+        ));
+        assert_eq!(Gauge::default().try_ratio(1.0).unwrap().core.ratio, 1.0);
+        assert!(Gauge::default().try_ratio(1.1).is_err());
+        assert!(Gauge::default().try_ratio(f64::NAN).is_err());
+        assert_eq!(
+            LineGauge::default().try_ratio(0.25).unwrap().core.ratio,
+            0.25
+        );
+        assert!(LineGauge::default().try_ratio(-0.5).is_err());
+    }
+
     #[test]
     fn gauge_can_be_stylized() {
         assert_eq!(
-            Gauge::default().black().on_white().bold().not_dim().style,
+            Gauge::default()
+                .black()
+                .on_white()
+                .bold()
+                .not_dim()
+                .core
+                .style,
             Style::default()
                 .fg(Color::Black)
                 .bg(Color::White)
@@ -466,6 +895,7 @@ mod tests {
                 .on_white()
                 .bold()
                 .not_dim()
+                .core
                 .style,
             Style::default()
                 .fg(Color::Black)
@@ -477,22 +907,187 @@ mod tests {
 
     #[test]
     fn line_gauge_default() {
-        // TODO: replace to `assert_eq!(LineGauge::default(), LineGauge::default())`
-        // when `Eq` or `PartialEq` is implemented for `LineGauge`.
         assert_eq!(
-            format!("{:?}", LineGauge::default()),
-            format!(
-                "{:?}",
-                LineGauge {
+            LineGauge::default(),
+            LineGauge {
+                core: GaugeCore {
                     block: None,
                     ratio: 0.0,
                     label: None,
                     style: Style::default(),
-                    line_set: symbols::line::NORMAL,
                     gauge_style: Style::default(),
-                }
-            ),
+                    thresholds: vec![],
+                    overflow: None,
+                    overflow_style: Style::default(),
+                    direction: GaugeDirection::LeftToRight,
+                },
+                line_set: symbols::line::NORMAL,
+                filled_symbol: None,
+                unfilled_symbol: None,
+            },
             "LineGauge::default() should have correct default values."
         );
     }
+
+    #[test]
+    fn line_gauge_symbols() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        LineGauge::default()
+            .label("ab")
+            .ratio(0.6)
+            .line_set(symbols::line::DOUBLE)
+            .filled_symbol(symbols::line::THICK_HORIZONTAL)
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(vec!["ab ━━━══"]));
+    }
+
+    #[test]
+    fn ratio_clamped() {
+        assert_eq!(Gauge::default().ratio_clamped(1.02).core.ratio, 1.0);
+        assert_eq!(Gauge::default().ratio_clamped(-0.1).core.ratio, 0.0);
+        assert_eq!(Gauge::default().ratio_clamped(0.3).core.ratio, 0.3);
+        assert_eq!(LineGauge::default().ratio_clamped(f64::NAN).core.ratio, 0.0);
+        assert_eq!(LineGauge::default().percent(30).core.ratio, 0.3);
+        assert!(LineGauge::default().try_percent(101).is_err());
+    }
+
+    #[test]
+    fn label_placement() {
+        let render = |gauge: Gauge| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+            gauge.render(buf.area, &mut buf);
+            buf
+        };
+        let label = Line::from(vec!["a".red(), "b".into()]);
+        let buf = render(
+            Gauge::default()
+                .label(label.clone())
+                .label_alignment(Alignment::Right)
+                .label_position(VerticalAlignment::Bottom),
+        );
+        let mut expected = Buffer::with_lines(vec!["        ", "        ", "      ab"]);
+        expected.set_style(Rect::new(6, 2, 1, 1), Style::new().red());
+        assert_eq!(buf, expected);
+        let buf = render(
+            Gauge::default()
+                .label(label)
+                .label_alignment(Alignment::Left)
+                .label_position(VerticalAlignment::Top),
+        );
+        let mut expected = Buffer::with_lines(vec!["ab      ", "        ", "        "]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red());
+        assert_eq!(buf, expected);
+        assert_eq!(
+            render(Gauge::default().percent(0)),
+            Buffer::with_lines(vec!["        ", "   0%   ", "        "])
+        );
+    }
+
+    #[test]
+    fn directions() {
+        let render = |gauge: Gauge| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+            gauge.render(buf.area, &mut buf);
+            buf
+        };
+        // the empty label is kept out of the filled part
+        let gauge = Gauge::default()
+            .percent(50)
+            .label("")
+            .label_alignment(Alignment::Left)
+            .label_position(VerticalAlignment::Top);
+        assert_eq!(
+            render(gauge.clone().direction(GaugeDirection::RightToLeft)),
+            Buffer::with_lines(vec!["  ██", "  ██", "  ██", "  ██"])
+        );
+        assert_eq!(
+            render(
+                gauge
+                    .clone()
+                    .label_position(VerticalAlignment::Bottom)
+                    .direction(GaugeDirection::TopToBottom)
+            ),
+            Buffer::with_lines(vec!["████", "████", "    ", "    "])
+        );
+        // the fractional blocks are drawn from the bottom
+        assert_eq!(
+            render(
+                gauge
+                    .ratio(0.6)
+                    .use_unicode(true)
+                    .direction(GaugeDirection::BottomToTop)
+            ),
+            Buffer::with_lines(vec!["    ", "▃▃▃▃", "████", "████"])
+        );
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 1));
+        LineGauge::default()
+            .label("ab")
+            .ratio(0.5)
+            .direction(GaugeDirection::RightToLeft)
+            .gauge_style(Style::new().red().on_blue())
+            .render(buf.area, &mut buf);
+        assert_eq!(buf.get(3, 0).fg, Color::Blue);
+        assert_eq!(buf.get(8, 0).fg, Color::Red);
+    }
+
+    #[test]
+    fn overflow() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        Gauge::default()
+            .gauge_style(Style::new().green())
+            .overflow_style(Style::new().red())
+            .percent_clamped(120)
+            .render(buf.area, &mut buf);
+        assert_eq!(buf.get(0, 0).fg, Color::Red);
+        let line = |buf: &Buffer| {
+            buf.content
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+        assert_eq!(line(&buf), "███120% ██");
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        LineGauge::default()
+            .overflow_style(Style::new().red())
+            .threshold(1.5, Style::new().bold())
+            .ratio_clamped(1.5)
+            .render(buf.area, &mut buf);
+        assert_eq!(line(&buf), "150% ─────");
+        assert_eq!(buf.get(5, 0).fg, Color::Red);
+        assert!(buf.get(5, 0).modifier.contains(Modifier::BOLD));
+        // the overflow is reset by the other setters
+        let gauge = Gauge::default().ratio_clamped(2.0).percent(50);
+        assert_eq!(gauge.core.overflow, None);
+        assert_eq!(Gauge::default().percent_clamped(99).core.overflow, None);
+    }
+
+    #[test]
+    fn thresholds() {
+        let gauge = |ratio| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+            Gauge::default()
+                .gauge_style(Style::new().green().on_black())
+                .threshold(0.9, Style::new().red())
+                .threshold(f64::NAN, Style::new().blue())
+                .threshold(0.75, Style::new().yellow().bold())
+                .ratio(ratio)
+                .render(buf.area, &mut buf);
+            let cell = buf.get(0, 0);
+            (cell.fg, cell.modifier)
+        };
+        assert_eq!(gauge(0.5), (Color::Green, Modifier::empty()));
+        assert_eq!(gauge(0.75), (Color::Yellow, Modifier::BOLD));
+        assert_eq!(gauge(0.95), (Color::Red, Modifier::BOLD));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        LineGauge::default()
+            .label("")
+            .threshold(0.5, Style::new().red())
+            .ratio(0.5)
+            .render(buf.area, &mut buf);
+        assert_eq!(buf.get(1, 0).fg, Color::Red);
+        assert_eq!(buf.get(9, 0).fg, Color::Reset);
+    }
 }
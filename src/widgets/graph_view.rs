@@ -0,0 +1,752 @@
+#![deny(missing_docs)]
+use std::collections::VecDeque;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    buffer::Buffer,
+    input::{Key, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind},
+    layout::Rect,
+    style::{Color, Modifier, Style, Styled},
+    symbols,
+    text::Line,
+    widgets::{
+        canvas::{Canvas, Line as CanvasLine},
+        Block, StatefulWidget, Widget,
+    },
+};
+
+/// The height of a node box: the label and the top and bottom borders.
+const NODE_HEIGHT: u16 = 3;
+
+/// A node of a [`GraphView`], drawn as a labeled box.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct GraphNode<'a> {
+    label: Line<'a>,
+    style: Style,
+}
+
+impl<'a> GraphNode<'a> {
+    /// Creates a new node with the given label.
+    pub fn new<T>(label: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        Self {
+            label: label.into(),
+            style: Style::default(),
+        }
+    }
+
+    /// Sets the style of the box of the node, patched on top of the
+    /// [`node_style`](GraphView::node_style) of the graph.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the size of the box of the node.
+    fn size(&self) -> (u16, u16) {
+        // borders and one column of padding on each side
+        (self.label.width() as u16 + 4, NODE_HEIGHT)
+    }
+}
+
+impl<'a, T> From<T> for GraphNode<'a>
+where
+    T: Into<Line<'a>>,
+{
+    fn from(label: T) -> Self {
+        Self::new(label)
+    }
+}
+
+/// A widget displaying a directed graph, e.g. dependencies or a network topology, as labeled
+/// boxes connected by arrows.
+///
+/// The nodes are laid out in layers from left to right: the nodes without incoming edges are in
+/// the first layer, and every node is placed to the right of its predecessors. Cycles are broken
+/// by ignoring the edges that go back to an earlier node. The nodes of each layer are ordered to
+/// reduce edge crossings. The edges are drawn on a [`Canvas`] with the configured
+/// [`marker`](GraphView::marker), below the boxes.
+///
+/// The [`GraphViewState`] keeps the selected node and the panning offset of the view, and
+/// handles the keyboard and mouse input.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut GraphViewState) {
+/// let graph = GraphView::new(
+///     vec!["app".into(), "http".into(), "json".into(), "io".into()],
+///     vec![(0, 1), (0, 2), (1, 3), (2, 3)],
+/// )
+/// .block(Block::default().title("Dependencies").borders(Borders::ALL))
+/// .edge_color(Color::DarkGray);
+/// frame.render_stateful_widget(graph, frame.size(), state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct GraphView<'a> {
+    nodes: Vec<GraphNode<'a>>,
+    edges: Vec<(usize, usize)>,
+    block: Option<Block<'a>>,
+    style: Style,
+    node_style: Style,
+    highlight_style: Style,
+    edge_color: Color,
+    marker: symbols::Marker,
+    layer_spacing: u16,
+    node_spacing: u16,
+}
+
+impl<'a> GraphView<'a> {
+    /// Creates a new graph with the given nodes and edges. An edge `(from, to)` goes from the
+    /// node at index `from` to the node at index `to`. Edges referring to missing nodes are
+    /// ignored.
+    pub fn new(nodes: Vec<GraphNode<'a>>, edges: Vec<(usize, usize)>) -> Self {
+        Self {
+            nodes,
+            edges,
+            block: None,
+            style: Style::default(),
+            node_style: Style::default(),
+            highlight_style: Style::new().add_modifier(Modifier::REVERSED),
+            edge_color: Color::Reset,
+            marker: symbols::Marker::Braille,
+            layer_spacing: 4,
+            node_spacing: 1,
+        }
+    }
+
+    /// Surrounds the graph with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the whole widget.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the node boxes.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn node_style(mut self, style: Style) -> Self {
+        self.node_style = style;
+        self
+    }
+
+    /// Sets the style patched on the box of the selected node. Defaults to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// Sets the color of the edges and arrowheads.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn edge_color(mut self, color: Color) -> Self {
+        self.edge_color = color;
+        self
+    }
+
+    /// Sets the marker used to draw the edges on the canvas. Defaults to braille patterns.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn marker(mut self, marker: symbols::Marker) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Sets the number of columns between the layers of nodes. Defaults to 4.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn layer_spacing(mut self, spacing: u16) -> Self {
+        self.layer_spacing = spacing;
+        self
+    }
+
+    /// Sets the number of rows between the nodes of a layer. Defaults to 1.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn node_spacing(mut self, spacing: u16) -> Self {
+        self.node_spacing = spacing;
+        self
+    }
+}
+
+impl<'a> Styled for GraphView<'a> {
+    type Item = GraphView<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// The box of a node in the layout, in cells from the top left corner of the graph.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+struct NodeBox {
+    x: i32,
+    y: i32,
+    width: u16,
+    height: u16,
+}
+
+impl NodeBox {
+    fn right(&self) -> i32 {
+        self.x + i32::from(self.width)
+    }
+
+    fn bottom(&self) -> i32 {
+        self.y + i32::from(self.height)
+    }
+}
+
+/// The state of a [`GraphView`]: the selected node, the panning offset and the positions of the
+/// nodes during the last render.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct GraphViewState {
+    selected: Option<usize>,
+    /// Position of the graph shown at the top left corner of the view
+    offset: (i32, i32),
+    /// Whether the next render should pan to show the selected node
+    follow_selected: bool,
+    /// Boxes of the nodes in the layout
+    boxes: Vec<NodeBox>,
+    /// Area where the graph was last rendered
+    viewport: Rect,
+    /// Position of the mouse while dragging the view
+    drag: Option<(u16, u16)>,
+}
+
+impl GraphViewState {
+    /// Returns the index of the selected node.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Selects the node at the given index, or removes the selection with `None`. The view pans
+    /// to show the selected node on the next render.
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+        self.follow_selected = index.is_some();
+    }
+
+    /// Selects the next node, wrapping around at the end. Does nothing before the first render.
+    pub fn select_next(&mut self) {
+        let len = self.boxes.len();
+        if len > 0 {
+            self.select(Some(self.selected.map_or(0, |i| (i + 1) % len)));
+        }
+    }
+
+    /// Selects the previous node, wrapping around at the start. Does nothing before the first
+    /// render.
+    pub fn select_previous(&mut self) {
+        let len = self.boxes.len();
+        if len > 0 {
+            self.select(Some(self.selected.map_or(len - 1, |i| (i + len - 1) % len)));
+        }
+    }
+
+    /// Returns the panning offset, i.e. the position of the graph shown at the top left corner
+    /// of the view.
+    pub fn offset(&self) -> (i32, i32) {
+        self.offset
+    }
+
+    /// Sets the panning offset.
+    pub fn set_offset(&mut self, offset: (i32, i32)) {
+        self.offset = offset;
+        self.follow_selected = false;
+    }
+
+    /// Pans the view by the given number of columns and rows.
+    pub fn pan(&mut self, dx: i32, dy: i32) {
+        self.set_offset((self.offset.0 + dx, self.offset.1 + dy));
+    }
+
+    /// Returns the index of the node rendered at the given position, if any.
+    pub fn node_at(&self, column: u16, row: u16) -> Option<usize> {
+        let (x, y) = self.to_graph(column, row);
+        let inside = (self.viewport.left()..self.viewport.right()).contains(&column)
+            && (self.viewport.top()..self.viewport.bottom()).contains(&row);
+        if !inside {
+            return None;
+        }
+        self.boxes
+            .iter()
+            .position(|b| (b.x..b.right()).contains(&x) && (b.y..b.bottom()).contains(&y))
+    }
+
+    /// Handles a key event and returns `true` if it changed the state.
+    ///
+    /// `Tab` and `BackTab` select the next and previous node, the arrow keys pan the view and
+    /// `Home` pans back to the top left corner of the graph.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release {
+            return false;
+        }
+        match key.key {
+            Key::Tab => self.select_next(),
+            Key::BackTab => self.select_previous(),
+            Key::Left => self.pan(-2, 0),
+            Key::Right => self.pan(2, 0),
+            Key::Up => self.pan(0, -1),
+            Key::Down => self.pan(0, 1),
+            Key::Home => self.set_offset((0, 0)),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Handles a mouse event and returns `true` if it changed the state.
+    ///
+    /// Clicking selects the node under the mouse, dragging pans the view and the mouse wheel
+    /// scrolls it.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.drag = Some((mouse.column, mouse.row));
+                match self.node_at(mouse.column, mouse.row) {
+                    Some(index) if self.selected != Some(index) => {
+                        // the node is visible: don't pan
+                        self.selected = Some(index);
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                let Some((column, row)) = self.drag else {
+                    return false;
+                };
+                self.drag = Some((mouse.column, mouse.row));
+                let dx = i32::from(column) - i32::from(mouse.column);
+                let dy = i32::from(row) - i32::from(mouse.row);
+                self.pan(dx, dy);
+                dx != 0 || dy != 0
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                self.drag = None;
+                false
+            }
+            MouseEventKind::ScrollUp => {
+                self.pan(0, -1);
+                true
+            }
+            MouseEventKind::ScrollDown => {
+                self.pan(0, 1);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Converts a position on screen to a position in the graph.
+    fn to_graph(&self, column: u16, row: u16) -> (i32, i32) {
+        (
+            i32::from(column) - i32::from(self.viewport.x) + self.offset.0,
+            i32::from(row) - i32::from(self.viewport.y) + self.offset.1,
+        )
+    }
+
+    /// Pans the view so that the selected node is visible, if it was selected since the last
+    /// render.
+    fn pan_to_selected(&mut self) {
+        if !std::mem::take(&mut self.follow_selected) {
+            return;
+        }
+        let Some(b) = self.selected.and_then(|i| self.boxes.get(i)) else {
+            return;
+        };
+        let (width, height) = (
+            i32::from(self.viewport.width),
+            i32::from(self.viewport.height),
+        );
+        self.offset.0 = self.offset.0.min(b.x).max(b.right() - width);
+        self.offset.1 = self.offset.1.min(b.y).max(b.bottom() - height);
+    }
+}
+
+/// Lays out the nodes with the given sizes in layers from left to right.
+fn layered_layout(
+    sizes: &[(u16, u16)],
+    edges: &[(usize, usize)],
+    layer_spacing: u16,
+    node_spacing: u16,
+) -> Vec<NodeBox> {
+    let n = sizes.len();
+    let mut successors = vec![vec![]; n];
+    let mut predecessors = vec![vec![]; n];
+    for &(from, to) in edges {
+        if from < n && to < n && from != to {
+            successors[from].push(to);
+            predecessors[to].push(from);
+        }
+    }
+
+    // topological order, breaking cycles at the first remaining node
+    let mut in_degree = predecessors.iter().map(Vec::len).collect::<Vec<_>>();
+    let mut queue = (0..n)
+        .filter(|&i| in_degree[i] == 0)
+        .collect::<VecDeque<_>>();
+    let mut rank = vec![None; n];
+    let mut order = Vec::with_capacity(n);
+    while order.len() < n {
+        let node = match queue.pop_front() {
+            Some(node) => node,
+            None => (0..n).find(|&i| rank[i].is_none()).unwrap_or_default(),
+        };
+        if rank[node].is_some() {
+            continue;
+        }
+        rank[node] = Some(order.len());
+        order.push(node);
+        for &next in &successors[node] {
+            in_degree[next] = in_degree[next].saturating_sub(1);
+            if in_degree[next] == 0 && rank[next].is_none() {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    // each node is in the layer after its furthest predecessor, ignoring the back edges
+    let mut layer_of = vec![0; n];
+    for &node in &order {
+        layer_of[node] = predecessors[node]
+            .iter()
+            .filter(|&&p| rank[p] < rank[node])
+            .map(|&p| layer_of[p] + 1)
+            .max()
+            .unwrap_or(0);
+    }
+    let layer_count = layer_of.iter().max().map_or(0, |max| max + 1);
+    let mut layers = vec![vec![]; layer_count];
+    for node in 0..n {
+        layers[layer_of[node]].push(node);
+    }
+
+    // order each layer by the mean position of the predecessors to reduce crossings
+    let mut position = vec![0.0; n];
+    for layer in &mut layers {
+        let keys = layer
+            .iter()
+            .enumerate()
+            .map(|(i, &node)| {
+                let placed = predecessors[node]
+                    .iter()
+                    .filter(|&&p| layer_of[p] < layer_of[node])
+                    .map(|&p| position[p])
+                    .collect::<Vec<f64>>();
+                if placed.is_empty() {
+                    i as f64
+                } else {
+                    placed.iter().sum::<f64>() / placed.len() as f64
+                }
+            })
+            .collect::<Vec<_>>();
+        let mut indices = (0..layer.len()).collect::<Vec<_>>();
+        indices.sort_by(|&a, &b| keys[a].total_cmp(&keys[b]));
+        *layer = indices.into_iter().map(|i| layer[i]).collect();
+        for (i, &node) in layer.iter().enumerate() {
+            position[node] = i as f64;
+        }
+    }
+
+    let column_height = |layer: &[usize]| {
+        let heights = layer
+            .iter()
+            .map(|&node| i32::from(sizes[node].1))
+            .sum::<i32>();
+        heights + i32::from(node_spacing) * (layer.len() as i32 - 1).max(0)
+    };
+    let height = layers.iter().map(|l| column_height(l)).max().unwrap_or(0);
+    let mut boxes = vec![NodeBox::default(); n];
+    let mut x = 0;
+    for layer in &layers {
+        let width = layer.iter().map(|&node| sizes[node].0).max().unwrap_or(0);
+        // center the layers vertically
+        let mut y = (height - column_height(layer)) / 2;
+        for &node in layer {
+            let (node_width, node_height) = sizes[node];
+            boxes[node] = NodeBox {
+                x,
+                y,
+                width: node_width,
+                height: node_height,
+            };
+            y += i32::from(node_height) + i32::from(node_spacing);
+        }
+        x += i32::from(width) + i32::from(layer_spacing);
+    }
+    boxes
+}
+
+/// A line segment between two points.
+type Segment = ((f64, f64), (f64, f64));
+
+/// An arrowhead symbol and its cell.
+type Arrow = ((i32, i32), &'static str);
+
+/// Clips the segment to the rectangle from `(0, 0)` to `max`, with the Liang-Barsky algorithm.
+fn clip_segment((x1, y1): (f64, f64), (x2, y2): (f64, f64), max: (f64, f64)) -> Option<Segment> {
+    let (dx, dy) = (x2 - x1, y2 - y1);
+    let mut t0: f64 = 0.0;
+    let mut t1: f64 = 1.0;
+    for (p, q) in [(-dx, x1), (dx, max.0 - x1), (-dy, y1), (dy, max.1 - y1)] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else {
+            let t = q / p;
+            if p < 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+        }
+    }
+    (t0 <= t1).then_some(((x1 + t0 * dx, y1 + t0 * dy), (x1 + t1 * dx, y1 + t1 * dy)))
+}
+
+/// Sets the symbol of the cell at the given position, if it is in the area.
+fn put(buf: &mut Buffer, area: Rect, (x, y): (i32, i32), symbol: &str, style: Style) {
+    let inside = (i32::from(area.left())..i32::from(area.right())).contains(&x)
+        && (i32::from(area.top())..i32::from(area.bottom())).contains(&y);
+    if inside {
+        buf.get_mut(x as u16, y as u16)
+            .set_symbol(symbol)
+            .set_style(style);
+    }
+}
+
+impl<'a> GraphView<'a> {
+    /// Returns the line segments and the arrowheads of the edges, in cells relative to the view.
+    fn edge_shapes(&self, boxes: &[NodeBox], offset: (i32, i32)) -> (Vec<Segment>, Vec<Arrow>) {
+        let mut segments = vec![];
+        let mut arrows = vec![];
+        for &(from, to) in &self.edges {
+            let (Some(a), Some(b)) = (boxes.get(from), boxes.get(to)) else {
+                continue;
+            };
+            if from == to {
+                continue;
+            }
+            let middle = |b: &NodeBox| b.y + i32::from(b.height) / 2;
+            let center = |b: &NodeBox| b.x + i32::from(b.width) / 2;
+            let (start, arrow, symbol) = if b.x > a.right() {
+                ((a.right(), middle(a)), (b.x - 1, middle(b)), "▶")
+            } else if a.x > b.right() {
+                ((a.x - 1, middle(a)), (b.right(), middle(b)), "◀")
+            } else if b.y >= a.y {
+                ((center(a), a.bottom()), (center(b), b.y - 1), "▼")
+            } else {
+                ((center(a), a.y - 1), (center(b), b.bottom()), "▲")
+            };
+            let relative = |(x, y): (i32, i32)| (x - offset.0, y - offset.1);
+            segments.push((relative(start), relative(arrow)));
+            arrows.push((relative(arrow), symbol));
+        }
+        let cell_center = |(x, y): (i32, i32)| (f64::from(x) + 0.5, f64::from(y) + 0.5);
+        let segments = segments
+            .into_iter()
+            .map(|(start, end)| (cell_center(start), cell_center(end)))
+            .collect();
+        (segments, arrows)
+    }
+
+    fn render_node(
+        &self,
+        node: &GraphNode,
+        b: NodeBox,
+        area: Rect,
+        buf: &mut Buffer,
+        selected: bool,
+    ) {
+        let mut style = self.node_style.patch(node.style);
+        if selected {
+            style = style.patch(self.highlight_style);
+        }
+        let set = symbols::line::NORMAL;
+        let (x, y) = (i32::from(area.x) + b.x, i32::from(area.y) + b.y);
+        let (right, bottom) = (x + i32::from(b.width) - 1, y + i32::from(b.height) - 1);
+        for cx in x..=right {
+            for cy in y..=bottom {
+                let symbol = match (cx == x, cx == right, cy == y, cy == bottom) {
+                    (true, _, true, _) => set.top_left,
+                    (_, true, true, _) => set.top_right,
+                    (true, _, _, true) => set.bottom_left,
+                    (_, true, _, true) => set.bottom_right,
+                    (true, _, _, _) | (_, true, _, _) => set.vertical,
+                    (_, _, true, _) | (_, _, _, true) => set.horizontal,
+                    _ => " ",
+                };
+                put(buf, area, (cx, cy), symbol, style);
+            }
+        }
+        let mut cx = x + 2;
+        let cy = y + i32::from(b.height) / 2;
+        for span in &node.label.spans {
+            for grapheme in span.content.graphemes(true) {
+                put(buf, area, (cx, cy), grapheme, style.patch(span.style));
+                cx += grapheme.width() as i32;
+            }
+        }
+    }
+}
+
+impl<'a> StatefulWidget for GraphView<'a> {
+    type State = GraphViewState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        let sizes = self.nodes.iter().map(GraphNode::size).collect::<Vec<_>>();
+        state.boxes = layered_layout(&sizes, &self.edges, self.layer_spacing, self.node_spacing);
+        state.viewport = area;
+        if state.selected.is_some_and(|i| i >= self.nodes.len()) {
+            state.selected = None;
+        }
+        if area.is_empty() {
+            return;
+        }
+        state.pan_to_selected();
+
+        let (segments, arrows) = self.edge_shapes(&state.boxes, state.offset);
+        let size = (f64::from(area.width), f64::from(area.height));
+        let color = self.edge_color;
+        Canvas::default()
+            .x_bounds([0.0, size.0])
+            .y_bounds([0.0, size.1])
+            .marker(self.marker)
+            .paint(|ctx| {
+                for &(start, end) in &segments {
+                    let Some((start, end)) = clip_segment(start, end, size) else {
+                        continue;
+                    };
+                    // the canvas has its origin at the bottom left corner
+                    ctx.draw(&CanvasLine::new(
+                        start.0,
+                        size.1 - start.1,
+                        end.0,
+                        size.1 - end.1,
+                        color,
+                    ));
+                }
+            })
+            .render(area, buf);
+
+        let edge_style = Style::new().fg(self.edge_color);
+        for ((x, y), symbol) in arrows {
+            let position = (i32::from(area.x) + x, i32::from(area.y) + y);
+            put(buf, area, position, symbol, edge_style);
+        }
+        for (i, (node, b)) in self.nodes.iter().zip(&state.boxes).enumerate() {
+            let b = NodeBox {
+                x: b.x - state.offset.0,
+                y: b.y - state.offset.1,
+                ..*b
+            };
+            self.render_node(node, b, area, buf, state.selected == Some(i));
+        }
+    }
+}
+
+impl<'a> Widget for GraphView<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = GraphViewState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    #[test]
+    fn layout_layers() {
+        let sizes = [(5, 3), (7, 3), (5, 3), (5, 3)];
+        let boxes = layered_layout(&sizes, &[(0, 1), (1, 2), (0, 2), (3, 2)], 2, 1);
+        let positions = boxes.iter().map(|b| (b.x, b.y)).collect::<Vec<_>>();
+        // 0 and 3 are sources, 2 comes after its furthest predecessor 1
+        assert_eq!(positions, [(0, 0), (7, 2), (16, 2), (0, 4)]);
+    }
+
+    #[test]
+    fn layout_breaks_cycles() {
+        let boxes = layered_layout(&[(5, 3); 3], &[(0, 1), (1, 2), (2, 0)], 1, 0);
+        let columns = boxes.iter().map(|b| b.x).collect::<Vec<_>>();
+        assert_eq!(columns, [0, 6, 12]);
+    }
+
+    #[test]
+    fn clip() {
+        let max = (10.0, 5.0);
+        assert_eq!(
+            clip_segment((-5.0, 1.0), (5.0, 1.0), max),
+            Some(((0.0, 1.0), (5.0, 1.0)))
+        );
+        assert_eq!(clip_segment((-5.0, 1.0), (-1.0, 1.0), max), None);
+    }
+
+    #[test]
+    fn render() {
+        let graph =
+            GraphView::new(vec!["a".into(), "b".into()], vec![(0, 1)]).marker(symbols::Marker::Dot);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 3));
+        Widget::render(graph, buf.area, &mut buf);
+        assert_buffer_eq!(
+            buf,
+            Buffer::with_lines(vec!["┌───┐    ┌───┐", "│ a │•••▶│ b │", "└───┘    └───┘",])
+        );
+    }
+
+    #[test]
+    fn selection_and_panning() {
+        let graph = || {
+            GraphView::new(vec!["a".into(), "b".into()], vec![(0, 1)])
+                .marker(symbols::Marker::Dot)
+                .highlight_style(Style::default())
+        };
+        let mut state = GraphViewState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+        StatefulWidget::render(graph(), buf.area, &mut buf, &mut state);
+        assert_eq!(state.node_at(1, 1), Some(0));
+        assert_eq!(state.node_at(6, 1), None);
+
+        // selecting a node outside of the view pans to it
+        assert!(state.handle_key(Key::BackTab.into()));
+        assert_eq!(state.selected(), Some(1));
+        let mut buf = Buffer::empty(buf.area);
+        StatefulWidget::render(graph(), buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), (6, 0));
+        assert_buffer_eq!(
+            buf,
+            Buffer::with_lines(vec!["   ┌───┐", "••▶│ b │", "   └───┘"])
+        );
+
+        let drag = |kind, column| MouseEvent::new(kind, column, 0);
+        state.handle_mouse(drag(MouseEventKind::Down(MouseButton::Left), 2));
+        assert!(state.handle_mouse(drag(MouseEventKind::Drag(MouseButton::Left), 6)));
+        assert_eq!(state.offset(), (2, 0));
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::VecDeque;
+
+/// The default maximum number of undo steps kept by the text editing widgets.
+pub(crate) const DEFAULT_HISTORY_DEPTH: usize = 100;
+
+/// The edit history of a text editing widget, made of snapshots of its content and cursor.
+///
+/// The widget records a snapshot of its state before each edit. Consecutive edits recorded with
+/// `coalesce` set (e.g. typing characters) are merged into a single undo step, until an edit
+/// that doesn't coalesce is recorded or [`break_coalescing`](Self::break_coalescing) is called
+/// (e.g. when the cursor moves).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub(crate) struct History<T> {
+    undo: VecDeque<T>,
+    redo: Vec<T>,
+    depth: usize,
+    coalescing: bool,
+}
+
+impl<T> Default for History<T> {
+    fn default() -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: vec![],
+            depth: DEFAULT_HISTORY_DEPTH,
+            coalescing: false,
+        }
+    }
+}
+
+impl<T> History<T> {
+    /// Sets the maximum number of undo steps, dropping the oldest ones if needed.
+    pub(crate) fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+        while self.undo.len() > depth {
+            self.undo.pop_front();
+        }
+    }
+
+    /// Records the state before an edit.
+    pub(crate) fn record(&mut self, snapshot: T, coalesce: bool) {
+        self.redo.clear();
+        if !(coalesce && self.coalescing) && self.depth > 0 {
+            if self.undo.len() == self.depth {
+                self.undo.pop_front();
+            }
+            self.undo.push_back(snapshot);
+        }
+        self.coalescing = coalesce;
+    }
+
+    /// Makes the next edit start a new undo step.
+    pub(crate) fn break_coalescing(&mut self) {
+        self.coalescing = false;
+    }
+
+    /// Returns the state to restore to undo the last edit, saving `current` to redo it.
+    pub(crate) fn undo(&mut self, current: T) -> Option<T> {
+        let snapshot = self.undo.pop_back()?;
+        self.redo.push(current);
+        self.coalescing = false;
+        Some(snapshot)
+    }
+
+    /// Returns the state to restore to redo the last undone edit, saving `current` to undo it
+    /// again.
+    pub(crate) fn redo(&mut self, current: T) -> Option<T> {
+        let snapshot = self.redo.pop()?;
+        self.undo.push_back(current);
+        self.coalescing = false;
+        Some(snapshot)
+    }
+
+    /// Forgets all the undo and redo steps.
+    pub(crate) fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.coalescing = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_redo() {
+        let mut history = History::default();
+        history.record(0, false);
+        history.record(1, false);
+        assert_eq!(history.undo(2), Some(1));
+        assert_eq!(history.undo(1), Some(0));
+        assert_eq!(history.undo(0), None);
+        assert_eq!(history.redo(0), Some(1));
+        assert_eq!(history.redo(1), Some(2));
+        assert_eq!(history.redo(2), None);
+    }
+
+    #[test]
+    fn coalescing() {
+        let mut history = History::default();
+        history.record(0, true);
+        history.record(1, true);
+        history.break_coalescing();
+        history.record(2, true);
+        history.record(3, false);
+        assert_eq!(history.undo(4), Some(3));
+        assert_eq!(history.undo(3), Some(2));
+        assert_eq!(history.undo(2), Some(0));
+    }
+
+    #[test]
+    fn record_clears_redo() {
+        let mut history = History::default();
+        history.record(0, false);
+        assert_eq!(history.undo(1), Some(0));
+        history.record(0, false);
+        assert_eq!(history.redo(5), None);
+    }
+
+    #[test]
+    fn depth() {
+        let mut history = History::default();
+        history.set_depth(2);
+        for i in 0..5 {
+            history.record(i, false);
+        }
+        assert_eq!(history.undo(5), Some(4));
+        assert_eq!(history.undo(4), Some(3));
+        assert_eq!(history.undo(3), None);
+        history.set_depth(0);
+        history.record(0, false);
+        assert_eq!(history.undo(1), None);
+    }
+}
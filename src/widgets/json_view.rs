@@ -0,0 +1,512 @@
+//! A widget to browse JSON documents.
+//!
+//! [`JsonView`] shows a [`serde_json::Value`] as a tree, where objects and arrays can be expanded
+//! and collapsed. The selection and the collapsed nodes are stored in a [`JsonViewState`].
+#![deny(missing_docs)]
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+use crate::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style, Styled},
+    text::{Line, Span},
+    widgets::{Block, StatefulWidget, Widget},
+};
+
+/// A widget displaying a JSON value as a tree of expandable nodes.
+///
+/// Each member of an object is shown with its key, and each element of an array with its index.
+/// Scalar values are styled by type, and collapsed objects and arrays show the number of items
+/// they contain.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::json_view::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut JsonViewState) {
+/// let value = serde_json::json!({ "name": "nginx", "ports": [80, 443] });
+/// frame.render_stateful_widget(JsonView::new(&value), frame.size(), state);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonView<'a> {
+    value: &'a Value,
+    block: Option<Block<'a>>,
+    style: Style,
+    highlight_style: Style,
+    key_style: Style,
+    index_style: Style,
+    string_style: Style,
+    number_style: Style,
+    bool_style: Style,
+    null_style: Style,
+    summary_style: Style,
+}
+
+impl<'a> JsonView<'a> {
+    /// Creates a new view of the given value.
+    pub fn new(value: &'a Value) -> Self {
+        Self {
+            value,
+            block: None,
+            style: Style::default(),
+            highlight_style: Style::new().add_modifier(Modifier::REVERSED),
+            key_style: Style::new().fg(Color::Blue),
+            index_style: Style::new().fg(Color::DarkGray),
+            string_style: Style::new().fg(Color::Green),
+            number_style: Style::new().fg(Color::Cyan),
+            bool_style: Style::new().fg(Color::Yellow),
+            null_style: Style::new().fg(Color::DarkGray),
+            summary_style: Style::new().add_modifier(Modifier::DIM),
+        }
+    }
+
+    /// Surrounds the view with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the whole widget.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the selected row. Defaults to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// Sets the style of object keys.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn key_style(mut self, style: Style) -> Self {
+        self.key_style = style;
+        self
+    }
+
+    /// Sets the style of array indices.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn index_style(mut self, style: Style) -> Self {
+        self.index_style = style;
+        self
+    }
+
+    /// Sets the style of string values.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn string_style(mut self, style: Style) -> Self {
+        self.string_style = style;
+        self
+    }
+
+    /// Sets the style of number values.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn number_style(mut self, style: Style) -> Self {
+        self.number_style = style;
+        self
+    }
+
+    /// Sets the style of boolean values.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn bool_style(mut self, style: Style) -> Self {
+        self.bool_style = style;
+        self
+    }
+
+    /// Sets the style of `null` values.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn null_style(mut self, style: Style) -> Self {
+        self.null_style = style;
+        self
+    }
+
+    /// Sets the style of the item count shown after objects and arrays.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn summary_style(mut self, style: Style) -> Self {
+        self.summary_style = style;
+        self
+    }
+
+    fn value_span(&self, value: &Value) -> Span<'static> {
+        match value {
+            Value::Null => Span::styled("null", self.null_style),
+            Value::Bool(b) => Span::styled(b.to_string(), self.bool_style),
+            Value::Number(n) => Span::styled(n.to_string(), self.number_style),
+            Value::String(s) => {
+                Span::styled(Value::from(s.as_str()).to_string(), self.string_style)
+            }
+            Value::Array(items) => Span::styled(format!("[{}]", items.len()), self.summary_style),
+            Value::Object(members) => {
+                Span::styled(format!("{{{}}}", members.len()), self.summary_style)
+            }
+        }
+    }
+}
+
+impl<'a> Styled for JsonView<'a> {
+    type Item = JsonView<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// The label of a node: the key of an object member or the index of an array element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Label<'a> {
+    Root,
+    Key(&'a str),
+    Index(usize),
+}
+
+/// A visible row of the tree.
+#[derive(Debug, Clone, PartialEq)]
+struct Node<'a> {
+    depth: usize,
+    label: Label<'a>,
+    path: String,
+    value: &'a Value,
+}
+
+/// Returns the visible nodes of the tree, skipping the children of collapsed nodes.
+fn visible_nodes<'a>(value: &'a Value, collapsed: &HashSet<String>) -> Vec<Node<'a>> {
+    fn visit<'a>(node: Node<'a>, collapsed: &HashSet<String>, nodes: &mut Vec<Node<'a>>) {
+        let expanded = !collapsed.contains(&node.path);
+        let (depth, path, value) = (node.depth + 1, node.path.clone(), node.value);
+        nodes.push(node);
+        if !expanded {
+            return;
+        }
+        match value {
+            Value::Array(items) => {
+                for (i, value) in items.iter().enumerate() {
+                    let node = Node {
+                        depth,
+                        label: Label::Index(i),
+                        path: format!("{path}[{i}]"),
+                        value,
+                    };
+                    visit(node, collapsed, nodes);
+                }
+            }
+            Value::Object(members) => {
+                for (key, value) in members {
+                    let node = Node {
+                        depth,
+                        label: Label::Key(key),
+                        path: member_path(&path, key),
+                        value,
+                    };
+                    visit(node, collapsed, nodes);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut nodes = vec![];
+    let root = Node {
+        depth: 0,
+        label: Label::Root,
+        path: "$".to_string(),
+        value,
+    };
+    visit(root, collapsed, &mut nodes);
+    nodes
+}
+
+/// Returns the path of a member of an object, using the dot notation when the key is a valid
+/// identifier and the bracket notation otherwise.
+fn member_path(parent: &str, key: &str) -> String {
+    let is_identifier = key
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_')
+        && key.chars().all(|c| c.is_alphanumeric() || c == '_');
+    if is_identifier {
+        format!("{parent}.{key}")
+    } else {
+        format!("{parent}[{}]", Value::from(key))
+    }
+}
+
+fn is_container(value: &Value) -> bool {
+    matches!(value, Value::Array(_) | Value::Object(_))
+}
+
+/// The state of a [`JsonView`]: the selected row, the collapsed nodes and the scroll offset.
+///
+/// Nodes are identified by their path, e.g. `$.ports[0]`, so the state stays valid when the
+/// value is updated, as long as its structure doesn't change.
+///
+/// As the rows depend on the displayed value, the navigation methods take the value as
+/// argument.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::json_view::*;
+///
+/// let value = serde_json::json!({ "name": "nginx", "ports": [80, 443] });
+/// let mut state = JsonViewState::default();
+/// state.select_next(&value);
+/// state.select_next(&value);
+/// assert_eq!(state.selected_path(&value).as_deref(), Some("$.ports"));
+/// state.toggle(&value);
+/// assert!(state.is_collapsed("$.ports"));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct JsonViewState {
+    selected: usize,
+    offset: usize,
+    collapsed: HashSet<String>,
+}
+
+impl JsonViewState {
+    /// Returns the index of the selected row.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Selects the row at the given index. The index is clamped when rendering.
+    pub fn select(&mut self, index: usize) {
+        self.selected = index;
+    }
+
+    /// Selects the next row.
+    pub fn select_next(&mut self, value: &Value) {
+        let len = visible_nodes(value, &self.collapsed).len();
+        self.selected = (self.selected + 1).min(len.saturating_sub(1));
+    }
+
+    /// Selects the previous row.
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Returns the path of the selected node, e.g. `$.ports[0]`.
+    pub fn selected_path(&self, value: &Value) -> Option<String> {
+        visible_nodes(value, &self.collapsed)
+            .into_iter()
+            .nth(self.selected)
+            .map(|node| node.path)
+    }
+
+    /// Returns `true` if the node at the given path is collapsed.
+    pub fn is_collapsed(&self, path: &str) -> bool {
+        self.collapsed.contains(path)
+    }
+
+    /// Expands the node at the given path.
+    pub fn expand(&mut self, path: &str) {
+        self.collapsed.remove(path);
+    }
+
+    /// Collapses the node at the given path.
+    pub fn collapse<T>(&mut self, path: T)
+    where
+        T: Into<String>,
+    {
+        self.collapsed.insert(path.into());
+    }
+
+    /// Expands or collapses the selected node. Returns `false` if the selected node is not an
+    /// object or an array.
+    pub fn toggle(&mut self, value: &Value) -> bool {
+        let nodes = visible_nodes(value, &self.collapsed);
+        let Some(node) = nodes.get(self.selected).filter(|n| is_container(n.value)) else {
+            return false;
+        };
+        if !self.collapsed.remove(&node.path) {
+            self.collapsed.insert(node.path.clone());
+        }
+        true
+    }
+
+    /// Copies the path of the selected node to the system clipboard.
+    ///
+    /// Returns `Ok(false)` if there is nothing to copy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the system clipboard can't be accessed.
+    #[cfg(feature = "clipboard")]
+    pub fn copy_selected_path(&self, value: &Value) -> std::io::Result<bool> {
+        let Some(path) = self.selected_path(value) else {
+            return Ok(false);
+        };
+        arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.set_text(path))
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))?;
+        Ok(true)
+    }
+}
+
+impl<'a> StatefulWidget for JsonView<'a> {
+    type State = JsonViewState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        if area.is_empty() {
+            return;
+        }
+
+        let nodes = visible_nodes(self.value, &state.collapsed);
+        state.selected = state.selected.min(nodes.len().saturating_sub(1));
+        let height = usize::from(area.height);
+        state.offset = state
+            .offset
+            .min(state.selected)
+            .max((state.selected + 1).saturating_sub(height));
+
+        let rows = nodes.iter().enumerate().skip(state.offset);
+        for ((i, node), y) in rows.zip(area.top()..area.bottom()) {
+            let marker = match (is_container(node.value), state.is_collapsed(&node.path)) {
+                (false, _) => "  ",
+                (true, false) => "▾ ",
+                (true, true) => "▸ ",
+            };
+            let mut spans = vec![Span::raw("  ".repeat(node.depth)), Span::raw(marker)];
+            match node.label {
+                Label::Root => {}
+                Label::Key(key) => {
+                    spans.push(Span::styled(key, self.key_style));
+                    spans.push(Span::raw(": "));
+                }
+                Label::Index(i) => {
+                    spans.push(Span::styled(format!("[{i}]"), self.index_style));
+                    spans.push(Span::raw(": "));
+                }
+            }
+            spans.push(self.value_span(node.value));
+            buf.set_line(area.x, y, &Line::from(spans), area.width);
+            if i == state.selected {
+                buf.set_style(Rect::new(area.x, y, area.width, 1), self.highlight_style);
+            }
+        }
+    }
+}
+
+impl<'a> Widget for JsonView<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = JsonViewState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    fn plain(value: &Value) -> JsonView<'_> {
+        let style = Style::new();
+        JsonView::new(value)
+            .highlight_style(style)
+            .key_style(style)
+            .index_style(style)
+            .string_style(style)
+            .number_style(style)
+            .bool_style(style)
+            .null_style(style)
+            .summary_style(style)
+    }
+
+    fn render(view: JsonView, state: &mut JsonViewState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        StatefulWidget::render(view, buf.area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn render_tree() {
+        let value = json!({ "name": "nginx", "ports": [80, true], "x": null });
+        let buf = render(plain(&value), &mut JsonViewState::default(), 20, 6);
+        assert_buffer_eq!(
+            buf,
+            Buffer::with_lines(vec![
+                "▾ {3}               ",
+                "    name: \"nginx\"   ",
+                "  ▾ ports: [2]      ",
+                "      [0]: 80       ",
+                "      [1]: true     ",
+                "    x: null         ",
+            ])
+        );
+    }
+
+    #[test]
+    fn toggle_collapses_nodes() {
+        let value = json!({ "a": { "b": 1 }, "c": 2 });
+        let mut state = JsonViewState::default();
+        state.select_next(&value);
+        assert!(state.toggle(&value));
+        assert!(state.is_collapsed("$.a"));
+        let buf = render(plain(&value), &mut state, 12, 4);
+        assert_buffer_eq!(
+            buf,
+            Buffer::with_lines(vec![
+                "▾ {2}       ",
+                "  ▸ a: {1}  ",
+                "    c: 2    ",
+                "            ",
+            ])
+        );
+        state.select_next(&value);
+        assert!(!state.toggle(&value));
+        state.select_previous();
+        assert!(state.toggle(&value));
+        assert!(!state.is_collapsed("$.a"));
+    }
+
+    #[test]
+    fn selected_path() {
+        let value = json!({ "list": [{ "my key": 1 }] });
+        let mut state = JsonViewState::default();
+        assert_eq!(state.selected_path(&value).as_deref(), Some("$"));
+        for _ in 0..5 {
+            state.select_next(&value);
+        }
+        assert_eq!(state.selected(), 3);
+        assert_eq!(
+            state.selected_path(&value).as_deref(),
+            Some("$.list[0][\"my key\"]")
+        );
+    }
+
+    #[test]
+    fn render_highlight_and_scroll() {
+        let value = json!([1, 2, 3, 4]);
+        let mut state = JsonViewState::default();
+        state.select(3);
+        let view = plain(&value).highlight_style(Style::new().add_modifier(Modifier::BOLD));
+        let buf = render(view, &mut state, 10, 2);
+        let mut expected = Buffer::with_lines(vec!["    [1]: 2", "    [2]: 3"]);
+        expected.set_style(
+            Rect::new(0, 1, 10, 1),
+            Style::new().add_modifier(Modifier::BOLD),
+        );
+        assert_buffer_eq!(buf, expected);
+    }
+}
@@ -0,0 +1,508 @@
+#![deny(missing_docs)]
+use crate::{
+    buffer::Buffer,
+    input::{
+        Focusable, Key, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent,
+        MouseEventKind,
+    },
+    layout::Rect,
+    style::{Modifier, Style, Styled},
+    terminal::RedrawRequest,
+    text::Span,
+    widgets::{Block, StatefulWidget, Widget},
+};
+
+const DEFAULT_FOCUSED_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_PLACEHOLDER_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+const DEFAULT_RECORDING_STYLE: Style = Style::new().add_modifier(Modifier::ITALIC);
+const DEFAULT_DISABLED_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+
+/// An input recording a key chord, e.g. to let the user configure the keymap of an application.
+///
+/// Instead of text, the input captures the next key pressed with its modifiers, and shows it in
+/// a human readable form such as `Ctrl+Shift+P` (see the [`Display`] implementation of
+/// [`KeyEvent`]). The chord is stored in a [`KeybindInputState`], which also handles the
+/// keyboard and the mouse:
+/// - `Enter` and `Space` start recording while the input has the focus, and `Backspace` and
+///   `Delete` clear the chord.
+/// - A click with the left button starts recording, whether the input has the focus or not.
+/// - While recording, the next key press is captured, including `Enter` and `Tab`, except `Esc`
+///   which stops recording and keeps the previous chord.
+///
+/// The [`placeholder`](KeybindInput::placeholder) is shown when there is no chord, and the
+/// [`recording_text`](KeybindInput::recording_text) while recording.
+///
+/// A [`read_only`](KeybindInput::read_only) input gets the focus but neither records nor clears
+/// the chord. A [`disabled`](KeybindInput::disabled) input also isn't drawn focused, and is
+/// restyled with the [`disabled_style`](KeybindInput::disabled_style).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     input::{Key, KeyEvent, KeyModifiers},
+///     prelude::*,
+///     widgets::*,
+/// };
+///
+/// # fn ui(frame: &mut Frame, state: &mut KeybindInputState) {
+/// let input = KeybindInput::new().block(Block::default().borders(Borders::ALL).title("Save"));
+/// frame.render_stateful_widget(input, Rect::new(0, 0, 20, 3), state);
+/// # }
+/// let mut state = KeybindInputState::default().with_focused(true);
+/// assert!(state.handle_key(Key::Enter.into()));
+/// assert!(state.is_recording());
+/// assert!(state.handle_key(KeyEvent::new(Key::Char('s'), KeyModifiers::CONTROL)));
+/// assert_eq!(state.keybind().unwrap().to_string(), "Ctrl+S");
+/// ```
+///
+/// [`Display`]: std::fmt::Display
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct KeybindInput<'a> {
+    block: Option<Block<'a>>,
+    placeholder: Span<'a>,
+    recording_text: Span<'a>,
+    style: Style,
+    focused_style: Style,
+    placeholder_style: Style,
+    recording_style: Style,
+    read_only: bool,
+    disabled: bool,
+    disabled_style: Style,
+}
+
+impl<'a> Default for KeybindInput<'a> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            placeholder: Span::raw("None"),
+            recording_text: Span::raw("Press a key…"),
+            style: Style::default(),
+            focused_style: DEFAULT_FOCUSED_STYLE,
+            placeholder_style: DEFAULT_PLACEHOLDER_STYLE,
+            recording_style: DEFAULT_RECORDING_STYLE,
+            read_only: false,
+            disabled: false,
+            disabled_style: DEFAULT_DISABLED_STYLE,
+        }
+    }
+}
+
+impl<'a> KeybindInput<'a> {
+    /// Creates a new keybind input.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Surrounds the input with a block.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the text shown when there is no chord. Defaults to `None`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder<T>(mut self, placeholder: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the text shown while recording. Defaults to `Press a key…`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn recording_text<T>(mut self, text: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.recording_text = text.into();
+        self
+    }
+
+    /// Sets the style of the input.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style patched on top of the style while the input has the focus. Defaults to
+    /// reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_style(mut self, style: Style) -> Self {
+        self.focused_style = style;
+        self
+    }
+
+    /// Sets the style of the placeholder. Defaults to dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder_style(mut self, style: Style) -> Self {
+        self.placeholder_style = style;
+        self
+    }
+
+    /// Sets the style of the text shown while recording. Defaults to italic text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn recording_style(mut self, style: Style) -> Self {
+        self.recording_style = style;
+        self
+    }
+
+    /// Makes the input read-only: it still gets the focus, but neither records nor clears the
+    /// chord.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Disables the input: it ignores the keys and the mouse, isn't drawn focused and is
+    /// restyled with the [`disabled_style`](Self::disabled_style).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the style patched over the whole input while it is [`disabled`](Self::disabled).
+    /// Defaults to dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = style;
+        self
+    }
+}
+
+impl<'a> Styled for KeybindInput<'a> {
+    type Item = KeybindInput<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// The state of a [`KeybindInput`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct KeybindInputState {
+    keybind: Option<KeyEvent>,
+    recording: bool,
+    focused: bool,
+    /// Whether the chord can't be recorded nor cleared, set when rendering
+    read_only: bool,
+    /// Whether the input ignores the focus too, set when rendering
+    disabled: bool,
+    /// Area of the last render, where a click starts recording
+    area: Rect,
+}
+
+impl KeybindInputState {
+    /// Sets the recorded chord.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_keybind<K>(mut self, keybind: K) -> Self
+    where
+        K: Into<KeyEvent>,
+    {
+        self.keybind = Some(keybind.into().kind(KeyEventKind::Press));
+        self
+    }
+
+    /// Sets whether the input has the focus.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Returns the recorded chord, if any.
+    pub fn keybind(&self) -> Option<KeyEvent> {
+        self.keybind
+    }
+
+    /// Sets or clears the recorded chord.
+    pub fn set_keybind(&mut self, keybind: Option<KeyEvent>) {
+        let keybind = keybind.map(|keybind| keybind.kind(KeyEventKind::Press));
+        RedrawRequest::request_if(self.keybind != keybind);
+        self.keybind = keybind;
+    }
+
+    /// Returns `true` if the next key press is captured as the chord.
+    pub fn is_recording(&self) -> bool {
+        self.recording
+    }
+
+    /// Captures the next key press as the chord.
+    pub fn start_recording(&mut self) {
+        RedrawRequest::request_if(!self.recording);
+        self.recording = true;
+    }
+
+    /// Stops recording, keeping the previous chord.
+    pub fn stop_recording(&mut self) {
+        RedrawRequest::request_if(self.recording);
+        self.recording = false;
+    }
+
+    /// Returns `true` if the input has the focus.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Returns `true` if the input was [read-only](KeybindInput::read_only) during the last
+    /// render.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns `true` if the input was [disabled](KeybindInput::disabled) during the last render.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Sets whether the input has the focus. Losing the focus stops recording.
+    pub fn set_focused(&mut self, focused: bool) {
+        RedrawRequest::request_if(self.focused != focused);
+        self.focused = focused;
+        if !focused {
+            self.stop_recording();
+        }
+    }
+
+    /// Handles a key event and returns `true` if the input consumed it.
+    ///
+    /// While recording, every key press is consumed: `Esc` without modifiers stops recording, and
+    /// any other key is recorded as the chord. Otherwise, a focused input starts recording on
+    /// `Enter` or `Space` and clears the chord on `Backspace` or `Delete`. The keys are ignored
+    /// while the input is read-only or disabled.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if self.read_only || self.disabled || key.kind == KeyEventKind::Release {
+            return false;
+        }
+        if self.recording {
+            match key.key {
+                Key::Esc if key.modifiers == KeyModifiers::NONE => {}
+                // a key that has no representation in this crate can't be shown or bound
+                Key::Null => return true,
+                _ => self.set_keybind(Some(key)),
+            }
+            self.stop_recording();
+            return true;
+        }
+        if !self.focused {
+            return false;
+        }
+        match key.key {
+            Key::Enter | Key::Char(' ') => self.start_recording(),
+            Key::Backspace | Key::Delete => self.set_keybind(None),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Handles a mouse event and returns `true` if the input started recording.
+    ///
+    /// The input starts recording when the left button is pressed over the area of its last
+    /// render, unless the input is read-only or disabled.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        let clicked = !self.read_only
+            && !self.disabled
+            && mouse.kind == MouseEventKind::Down(MouseButton::Left)
+            && self.area.contains(mouse.position());
+        if clicked {
+            self.start_recording();
+        }
+        clicked
+    }
+}
+
+impl Focusable for KeybindInputState {
+    fn set_focused(&mut self, focused: bool) {
+        KeybindInputState::set_focused(self, focused);
+    }
+
+    fn is_disabled(&self) -> bool {
+        KeybindInputState::is_disabled(self)
+    }
+}
+
+impl<'a> StatefulWidget for KeybindInput<'a> {
+    type State = KeybindInputState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.area = area;
+        state.read_only = self.read_only;
+        state.disabled = self.disabled;
+        if self.read_only || self.disabled {
+            state.stop_recording();
+        }
+        buf.set_style(area, self.style);
+        let inner = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        let disabled_style = self.disabled.then_some(self.disabled_style);
+        if !inner.is_empty() {
+            self.render_text(inner, buf, state);
+        }
+        if let Some(style) = disabled_style {
+            buf.set_style(area, style);
+        }
+    }
+}
+
+impl<'a> KeybindInput<'a> {
+    fn render_text(self, area: Rect, buf: &mut Buffer, state: &KeybindInputState) {
+        let text = if state.recording {
+            Span::styled(
+                self.recording_text.content,
+                self.recording_text.style.patch(self.recording_style),
+            )
+        } else if let Some(keybind) = state.keybind {
+            Span::raw(keybind.to_string())
+        } else {
+            Span::styled(
+                self.placeholder.content,
+                self.placeholder.style.patch(self.placeholder_style),
+            )
+        };
+        let line = Rect { height: 1, ..area };
+        if state.focused && !self.disabled {
+            buf.set_style(line, self.focused_style);
+        }
+        buf.set_span(area.x, area.y, &text, area.width);
+    }
+}
+
+impl<'a> Widget for KeybindInput<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = KeybindInputState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_buffer_eq, widgets::Borders};
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 3));
+        let mut state =
+            KeybindInputState::default().with_keybind(KeyEvent::new(Key::F(2), KeyModifiers::ALT));
+        let input = KeybindInput::new().block(Block::default().borders(Borders::ALL));
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(
+            buf,
+            Buffer::with_lines(vec![
+                "┌──────────────┐",
+                "│Alt+F2        │",
+                "└──────────────┘",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_placeholder_and_recording() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 1));
+        let mut state = KeybindInputState::default();
+        StatefulWidget::render(KeybindInput::new(), buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["None          "]);
+        expected.set_style(Rect::new(0, 0, 4, 1), DEFAULT_PLACEHOLDER_STYLE);
+        assert_buffer_eq!(buf, expected);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 1));
+        state.start_recording();
+        state.set_focused(true);
+        StatefulWidget::render(KeybindInput::new(), buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["Press a key…  "]);
+        expected.set_style(expected.area, DEFAULT_FOCUSED_STYLE);
+        expected.set_style(Rect::new(0, 0, 12, 1), DEFAULT_RECORDING_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn handle_key() {
+        let mut state = KeybindInputState::default();
+        assert!(!state.handle_key(Key::Enter.into()));
+
+        state.set_focused(true);
+        assert!(!state.handle_key(Key::Tab.into()));
+        assert!(state.handle_key(Key::Char(' ').into()));
+        assert!(state.is_recording());
+        // keys are recorded when pressed, not when released
+        let tab = KeyEvent::new(Key::Tab, KeyModifiers::CONTROL);
+        assert!(!state.handle_key(tab.kind(KeyEventKind::Release)));
+        assert!(state.handle_key(tab));
+        assert!(!state.is_recording());
+        assert_eq!(state.keybind(), Some(tab));
+
+        assert!(state.handle_key(Key::Enter.into()));
+        assert!(state.handle_key(Key::Esc.into()));
+        assert!(!state.is_recording());
+        assert_eq!(state.keybind(), Some(tab));
+
+        assert!(state.handle_key(Key::Backspace.into()));
+        assert_eq!(state.keybind(), None);
+    }
+
+    #[test]
+    fn handle_mouse() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = KeybindInputState::default();
+        let area = Rect::new(2, 0, 5, 1);
+        StatefulWidget::render(KeybindInput::new(), area, &mut buf, &mut state);
+        let click = |column| MouseEvent::new(MouseEventKind::Down(MouseButton::Left), column, 0);
+        assert!(!state.handle_mouse(click(0)));
+        assert!(state.handle_mouse(click(3)));
+        assert!(state.is_recording());
+    }
+
+    #[test]
+    fn losing_focus_stops_recording() {
+        let mut state = KeybindInputState::default().with_focused(true);
+        state.start_recording();
+        state.set_focused(false);
+        assert!(!state.is_recording());
+    }
+
+    #[test]
+    fn read_only_and_disabled() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let mut state = KeybindInputState::default().with_focused(true);
+        state.start_recording();
+        let input = KeybindInput::new();
+        StatefulWidget::render(
+            input.clone().read_only(true),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        assert!(state.is_read_only() && !state.is_recording());
+        let click = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 1, 0);
+        assert!(!state.handle_key(Key::Enter.into()));
+        assert!(!state.handle_mouse(click));
+
+        let mut buf = Buffer::empty(buf.area);
+        StatefulWidget::render(input.disabled(true), buf.area, &mut buf, &mut state);
+        assert!(state.is_disabled() && Focusable::is_disabled(&state));
+        assert!(!state.handle_key(Key::Backspace.into()));
+        let mut expected = Buffer::with_lines(vec!["None  "]);
+        expected.set_style(Rect::new(0, 0, 4, 1), DEFAULT_PLACEHOLDER_STYLE);
+        expected.set_style(expected.area, DEFAULT_DISABLED_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+}
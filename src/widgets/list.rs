@@ -4,10 +4,11 @@ use unicode_width::UnicodeWidthStr;
 
 use crate::{
     buffer::Buffer,
-    layout::{Alignment, Corner, Rect},
+    layout::{first_visible_item, Alignment, Corner, Rect},
     style::{Style, Styled},
-    text::Text,
-    widgets::{Block, HighlightSpacing, StatefulWidget, Widget},
+    terminal::RedrawRequest,
+    text::{Line, Text},
+    widgets::{decorator::TooltipState, Block, HighlightSpacing, StatefulWidget, Widget},
 };
 
 /// State of the [`List`] widget
@@ -21,6 +22,15 @@ use crate::{
 /// - [`offset`]: the index of the first item to be displayed
 /// - [`selected`]: the index of the selected item, which can be `None` if no item is selected
 ///
+/// The list also records the number of items and how many of them were visible when it was last
+/// rendered, so that keyboard paging ([`select_page_down`](ListState::select_page_down),
+/// [`select_last`](ListState::select_last), ...) matches what is on screen.
+///
+/// With [`follow`](ListState::set_follow) enabled, the list stays pinned to its last item as
+/// new items are appended, e.g. to tail a log. Scrolling or selecting away from the last item
+/// unpins it: the list then counts the [new items](ListState::new_items), shows them in an
+/// indicator such as `5 new ↓`, and [`jump_to_latest`](ListState::jump_to_latest) pins it again.
+///
 /// [`offset`]: ListState::offset()
 /// [`selected`]: ListState::selected()
 ///
@@ -51,6 +61,16 @@ use crate::{
 pub struct ListState {
     offset: usize,
     selected: Option<usize>,
+    /// Number of items during the last render
+    len: usize,
+    /// Number of items visible during the last render
+    page: usize,
+    /// Whether the list stays pinned to its last item as new items arrive
+    follow: bool,
+    /// Number of items appended while the list wasn't pinned to its last item
+    new_items: usize,
+    /// Items elided during the last render
+    truncated: Vec<Truncated>,
 }
 
 impl ListState {
@@ -101,6 +121,13 @@ impl ListState {
 
     /// Mutable reference to the index of the first item to be displayed
     ///
+    /// The state can't see the changes made through the reference, so a redraw is requested with
+    /// [`RedrawRequest`] on every call, even if the offset is only read or left unchanged. Read
+    /// it with [`offset`](Self::offset) instead, so that a [`RedrawScheduler`] doesn't keep
+    /// redrawing.
+    ///
+    /// [`RedrawScheduler`]: crate::terminal::RedrawScheduler
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -109,6 +136,7 @@ impl ListState {
     /// *state.offset_mut() = 1;
     /// ```
     pub fn offset_mut(&mut self) -> &mut usize {
+        RedrawRequest::request();
         &mut self.offset
     }
 
@@ -131,6 +159,14 @@ impl ListState {
     ///
     /// Returns `None` if no item is selected
     ///
+    /// The state can't see the changes made through the reference, so a redraw is requested with
+    /// [`RedrawRequest`] on every call, even if the selection is only read or left unchanged.
+    /// Read it with [`selected`](Self::selected) and change it with [`select`](Self::select)
+    /// instead, which only request a redraw when the selection changes, so that a
+    /// [`RedrawScheduler`] doesn't keep redrawing.
+    ///
+    /// [`RedrawScheduler`]: crate::terminal::RedrawScheduler
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -139,6 +175,7 @@ impl ListState {
     /// *state.selected_mut() = Some(1);
     /// ```
     pub fn selected_mut(&mut self) -> &mut Option<usize> {
+        RedrawRequest::request();
         &mut self.selected
     }
 
@@ -146,6 +183,8 @@ impl ListState {
     ///
     /// Set to `None` if no item is selected. This will also reset the offset to `0`.
     ///
+    /// A redraw is requested with [`RedrawRequest`] if the selection changed.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -154,11 +193,275 @@ impl ListState {
     /// state.select(Some(1));
     /// ```
     pub fn select(&mut self, index: Option<usize>) {
+        if self.selected != index {
+            RedrawRequest::request();
+        }
         self.selected = index;
         if index.is_none() {
             self.offset = 0;
         }
     }
+
+    /// Selects the first item.
+    ///
+    /// A redraw is requested with [`RedrawRequest`] if the selection changed.
+    pub fn select_first(&mut self) {
+        self.select(Some(0));
+    }
+
+    /// Selects the last item, as counted during the last render. Does nothing before the first
+    /// render or when the list is empty.
+    ///
+    /// A redraw is requested with [`RedrawRequest`] if the selection changed.
+    pub fn select_last(&mut self) {
+        if self.len > 0 {
+            self.select(Some(self.len - 1));
+        }
+    }
+
+    /// Selects the item one page below the selected one, and scrolls the list by a page.
+    ///
+    /// A page is the number of items visible during the last render, see
+    /// [`page_size`](Self::page_size). The selection stops at the last item. Selects the first
+    /// item when nothing is selected.
+    pub fn select_page_down(&mut self) {
+        let page = self.page_size();
+        let Some(selected) = self.selected else {
+            self.select_first();
+            return;
+        };
+        let last = self.len.saturating_sub(1).max(selected);
+        self.select(Some((selected + page).min(last)));
+        self.offset = (self.offset + page).min(self.len.saturating_sub(page));
+    }
+
+    /// Selects the item one page above the selected one, and scrolls the list by a page.
+    ///
+    /// A page is the number of items visible during the last render, see
+    /// [`page_size`](Self::page_size). The selection stops at the first item.
+    pub fn select_page_up(&mut self) {
+        let page = self.page_size();
+        let selected = self.selected.unwrap_or(0);
+        self.select(Some(selected.saturating_sub(page)));
+        self.offset = self.offset.saturating_sub(page);
+    }
+
+    /// Scrolls the list so that the selected item is at the given position of the viewport,
+    /// as sized during the last render.
+    ///
+    /// Does nothing when no item is selected. A redraw is requested with [`RedrawRequest`] if
+    /// the offset changed.
+    pub fn scroll_to_selected(&mut self, position: ScrollPosition) {
+        let Some(selected) = self.selected else {
+            return;
+        };
+        let page = self.page_size();
+        let offset = match position {
+            ScrollPosition::Top => selected,
+            ScrollPosition::Center => selected.saturating_sub((page - 1) / 2),
+            ScrollPosition::Bottom => (selected + 1).saturating_sub(page),
+        };
+        RedrawRequest::request_if(offset != self.offset);
+        self.offset = offset;
+    }
+
+    /// Returns the number of items visible during the last render, used as the size of a page
+    /// by [`select_page_down`](Self::select_page_down) and
+    /// [`select_page_up`](Self::select_page_up). It is at least 1, even before the first render.
+    pub fn page_size(&self) -> usize {
+        self.page.max(1)
+    }
+
+    /// Sets whether the list stays pinned to its last item as new items arrive.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = ListState::default().with_follow(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_follow(mut self, follow: bool) -> Self {
+        self.set_follow(follow);
+        self
+    }
+
+    /// Returns `true` if the list stays pinned to its last item as new items arrive. Defaults to
+    /// `false`.
+    pub fn follow(&self) -> bool {
+        self.follow
+    }
+
+    /// Sets whether the list stays pinned to its last item as new items arrive.
+    ///
+    /// While the last item is visible (or selected, when an item is selected), every render
+    /// scrolls the list to the items appended since the previous one, selecting the new last
+    /// item if there is a selection. Once the user scrolls or selects away from it, the list
+    /// stays where it is and counts the [new items](Self::new_items) instead.
+    pub fn set_follow(&mut self, follow: bool) {
+        self.follow = follow;
+        self.new_items = 0;
+    }
+
+    /// Returns the number of items appended while the list wasn't pinned to its last item, shown
+    /// by the list in an indicator such as `5 new ↓`. Always 0 without
+    /// [`follow`](Self::set_follow).
+    pub fn new_items(&self) -> usize {
+        self.new_items
+    }
+
+    /// Scrolls to the last item, selecting it if an item is selected, and pins the list to it
+    /// again with [`follow`](Self::set_follow).
+    ///
+    /// A redraw is requested with [`RedrawRequest`].
+    pub fn jump_to_latest(&mut self) {
+        let last = self.len.saturating_sub(1);
+        if self.selected.is_some() {
+            self.selected = Some(last);
+        }
+        self.offset = self.len.saturating_sub(self.page_size());
+        self.new_items = 0;
+        RedrawRequest::request();
+    }
+
+    /// Returns the items elided with an ellipsis during the last render, see [`List::ellipsis`].
+    pub fn truncated(&self) -> &[Truncated] {
+        &self.truncated
+    }
+
+    /// Registers the full content of the truncated items in the tooltips, shown in a
+    /// [`TooltipPopup`] when the mouse rests on them. Call this after rendering the list.
+    ///
+    /// [`TooltipPopup`]: crate::widgets::decorator::TooltipPopup
+    pub fn register_truncated(&self, tooltips: &mut TooltipState) {
+        for truncated in &self.truncated {
+            tooltips.register(truncated.area, truncated.text.as_str());
+        }
+    }
+
+    /// Shows the full content of the selected item in the tooltips right away, if it was
+    /// truncated during the last render, e.g. when a key is pressed. Returns `true` if it was
+    /// shown.
+    pub fn expand_selected(&self, tooltips: &mut TooltipState) -> bool {
+        let Some(truncated) = self
+            .truncated
+            .iter()
+            .find(|truncated| Some(truncated.index) == self.selected)
+        else {
+            return false;
+        };
+        tooltips.show(truncated.area, truncated.text.as_str());
+        true
+    }
+
+    /// Returns `true` if the last item, as counted during the last render, is selected or
+    /// visible when there is no selection.
+    fn is_at_latest(&self) -> bool {
+        match self.selected {
+            Some(selected) => selected + 1 >= self.len,
+            None => self.offset + self.page >= self.len,
+        }
+    }
+}
+
+/// Where to scroll the selected item of a [`List`] or row of a [`Table`] in the viewport.
+///
+/// See [`ListState::scroll_to_selected`] and [`TableState::scroll_to_selected`].
+///
+/// [`Table`]: crate::widgets::Table
+/// [`TableState::scroll_to_selected`]: crate::widgets::TableState::scroll_to_selected
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ScrollPosition {
+    /// The selection is the first visible item.
+    #[default]
+    Top,
+    /// The selection is in the middle of the viewport.
+    Center,
+    /// The selection is the last visible item.
+    Bottom,
+}
+
+/// An item of a [`List`] or a cell of a [`Table`] elided with an ellipsis during the last render.
+///
+/// See [`ListState::truncated`] and [`TableState::truncated`].
+///
+/// [`Table`]: crate::widgets::Table
+/// [`TableState::truncated`]: crate::widgets::TableState::truncated
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Truncated {
+    index: usize,
+    column: Option<usize>,
+    area: Rect,
+    text: String,
+}
+
+impl Truncated {
+    pub(crate) fn new(index: usize, column: Option<usize>, area: Rect, text: &Text) -> Self {
+        Self {
+            index,
+            column,
+            area,
+            text: plain_text(text),
+        }
+    }
+
+    /// Returns the index of the item, or of the row of the cell.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the index of the column of the cell, or `None` for an item of a list.
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    /// Returns the area where the item or the cell was rendered.
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Returns the full unstyled content, with its lines separated by line breaks.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Returns the unstyled content of a text, with its lines separated by line breaks.
+pub(crate) fn plain_text(text: &Text) -> String {
+    text.lines
+        .iter()
+        .map(|line| {
+            line.spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Draws the line in the given width like [`Buffer::set_line`], replacing its end with `…` if
+/// it doesn't fit. Returns `true` if the line was elided.
+pub(crate) fn set_line_elided(buf: &mut Buffer, x: u16, y: u16, line: &Line, width: u16) -> bool {
+    if width == 0 || line.width() <= usize::from(width) {
+        buf.set_line(x, y, line, width);
+        return false;
+    }
+    let (end, _) = buf.set_line(x, y, line, width - 1);
+    // the ellipsis takes the style of the span it cuts
+    let mut used = 0;
+    let style = line
+        .spans
+        .iter()
+        .find(|span| {
+            used += span.width();
+            used >= usize::from(width)
+        })
+        .map_or(Style::default(), |span| span.style);
+    buf.set_stringn(end, y, "…", 1, style);
+    true
 }
 
 /// A single item in a [`List`]
@@ -415,6 +718,10 @@ pub struct List<'a> {
     repeat_highlight_symbol: bool,
     /// Decides when to allocate spacing for the selection symbol
     highlight_spacing: HighlightSpacing,
+    /// Style of the indicator of the new items, see [`ListState::new_items`]
+    new_items_style: Style,
+    /// Whether the lines too wide for the list are elided with an ellipsis
+    ellipsis: bool,
 }
 
 /// Defines the direction in which the list will be rendered.
@@ -698,6 +1005,53 @@ impl<'a> List<'a> {
         }
     }
 
+    /// Sets the style of the indicator of the items appended while the list isn't pinned to its
+    /// last item, see [`ListState::set_follow`].
+    ///
+    /// The indicator (e.g. `5 new ↓`) is drawn over the items, on the side of the list where the
+    /// new items arrive. Defaults to the style of the list.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let items = vec!["Item 1"];
+    /// let list = List::new(items).new_items_style(Style::new().reversed());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn new_items_style(mut self, style: Style) -> List<'a> {
+        self.new_items_style = style;
+        self
+    }
+
+    /// Set whether the lines too wide for the list end with an ellipsis (`…`)
+    ///
+    /// The elided items are recorded in the [`ListState`], so that their full content can be
+    /// shown in a popup when the mouse rests on them with [`ListState::register_truncated`], or
+    /// when a key is pressed on the selected item with [`ListState::expand_selected`]. This is
+    /// `false` by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let list = List::new(["A long item"]).ellipsis(true);
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+    /// let mut state = ListState::default();
+    /// StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+    /// assert_eq!(buf, Buffer::with_lines(vec!["A lon…"]));
+    /// assert_eq!(state.truncated()[0].text(), "A long item");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn ellipsis(mut self, ellipsis: bool) -> List<'a> {
+        self.ellipsis = ellipsis;
+        self
+    }
+
     /// Returns the number of [`ListItem`]s in the list
     pub fn len(&self) -> usize {
         self.items.len()
@@ -715,35 +1069,68 @@ impl<'a> List<'a> {
         max_height: usize,
     ) -> (usize, usize) {
         let offset = offset.min(self.items.len().saturating_sub(1));
-        let mut start = offset;
-        let mut end = offset;
+        // without a selection, the list keeps its offset
+        let start = match selected {
+            Some(selected) => {
+                let selected = selected.min(self.items.len() - 1);
+                first_visible_item(offset, selected, max_height, |i| self.items[i].height())
+            }
+            None => offset,
+        };
+        let mut end = start;
         let mut height = 0;
-        for item in self.items.iter().skip(offset) {
+        for item in self.items.iter().skip(start) {
             if height + item.height() > max_height {
                 break;
             }
             height += item.height();
             end += 1;
         }
+        (start, end)
+    }
 
-        let selected = selected.unwrap_or(0).min(self.items.len() - 1);
-        while selected >= end {
-            height = height.saturating_add(self.items[end].height());
-            end += 1;
-            while height > max_height {
-                height = height.saturating_sub(self.items[start].height());
-                start += 1;
-            }
-        }
-        while selected < start {
+    /// Returns the offset showing as many items as fit at the end of the list.
+    fn last_page_offset(&self, max_height: usize) -> usize {
+        let mut start = self.items.len();
+        let mut height = 0;
+        while start > 0 && height + self.items[start - 1].height() <= max_height {
             start -= 1;
-            height = height.saturating_add(self.items[start].height());
-            while height > max_height {
-                end -= 1;
-                height = height.saturating_sub(self.items[end].height());
-            }
+            height += self.items[start].height();
         }
-        (start, end)
+        start.min(self.items.len().saturating_sub(1))
+    }
+
+    /// Pins the list to its last item if it was at its end during the previous render, or counts
+    /// the new items otherwise.
+    fn follow_latest(
+        &self,
+        state: &mut ListState,
+        previous_len: usize,
+        was_at_latest: bool,
+        height: usize,
+    ) {
+        if !was_at_latest {
+            state.new_items += self.items.len().saturating_sub(previous_len);
+            return;
+        }
+        state.new_items = 0;
+        if state.selected.is_some() {
+            state.selected = Some(self.items.len() - 1);
+        } else {
+            state.offset = self.last_page_offset(height);
+        }
+    }
+
+    /// Draws the indicator of the new items over the side of the list where they arrive.
+    fn render_new_items(&self, area: Rect, buf: &mut Buffer, new_items: usize) {
+        let (arrow, y) = match self.direction {
+            ListDirection::TopToBottom => ('↓', area.bottom() - 1),
+            ListDirection::BottomToTop => ('↑', area.top()),
+        };
+        let indicator = format!("{new_items} new {arrow}");
+        let width = (indicator.width() as u16).min(area.width);
+        let x = area.right() - width;
+        buf.set_stringn(x, y, indicator, width.into(), self.new_items_style);
     }
 }
 
@@ -752,6 +1139,10 @@ impl<'a> StatefulWidget for List<'a> {
 
     fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         buf.set_style(area, self.style);
+        let (previous_len, was_at_latest) = (state.len, state.is_at_latest());
+        state.len = self.items.len();
+        state.page = 0;
+        state.truncated.clear();
         let list_area = match self.block.take() {
             Some(b) => {
                 let inner_area = b.inner(area);
@@ -769,9 +1160,13 @@ impl<'a> StatefulWidget for List<'a> {
             return;
         }
         let list_height = list_area.height as usize;
+        if state.follow {
+            self.follow_latest(state, previous_len, was_at_latest, list_height);
+        }
 
         let (start, end) = self.get_items_bounds(state.selected, state.offset, list_height);
         state.offset = start;
+        state.page = end - start;
 
         let highlight_symbol = self.highlight_symbol.unwrap_or("");
         let blank_symbol = " ".repeat(highlight_symbol.width());
@@ -803,6 +1198,7 @@ impl<'a> StatefulWidget for List<'a> {
             buf.set_style(area, item_style);
 
             let is_selected = state.selected.map_or(false, |s| s == i);
+            let mut elided = false;
             for (j, line) in item.content.lines.iter().enumerate() {
                 // if the item is selected, we need to display the highlight symbol:
                 // - either for the first line of the item only,
@@ -831,12 +1227,26 @@ impl<'a> StatefulWidget for List<'a> {
                     Some(Alignment::Right) => area.width.saturating_sub(line.width() as u16),
                     _ => 0,
                 };
-                buf.set_line(elem_x + x_offset, y + j as u16, line, max_element_width);
+                let x = elem_x + x_offset;
+                let y = y + j as u16;
+                if self.ellipsis {
+                    elided |= set_line_elided(buf, x, y, line, max_element_width);
+                } else {
+                    buf.set_line(x, y, line, max_element_width);
+                }
+            }
+            if elided {
+                state
+                    .truncated
+                    .push(Truncated::new(i, None, area, &item.content));
             }
             if is_selected {
                 buf.set_style(area, self.highlight_style);
             }
         }
+        if state.new_items > 0 {
+            self.render_new_items(list_area, buf, state.new_items);
+        }
     }
 }
 
@@ -896,6 +1306,98 @@ mod tests {
         assert_eq!(state.selected(), None);
     }
 
+    #[test]
+    fn list_state_paging() {
+        let list = List::new((0..10).map(|i| i.to_string()).collect::<Vec<_>>());
+        let mut state = ListState::default().with_selected(Some(1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 3));
+        StatefulWidget::render(list.clone(), buf.area, &mut buf, &mut state);
+        assert_eq!(state.page_size(), 3);
+
+        state.select_page_down();
+        assert_eq!((state.selected, state.offset), (Some(4), 3));
+        state.select_last();
+        assert_eq!(state.selected, Some(9));
+        StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset, 7);
+        state.select_page_up();
+        state.select_page_up();
+        assert_eq!((state.selected, state.offset), (Some(3), 1));
+        state.select_page_up();
+        assert_eq!((state.selected, state.offset), (Some(0), 0));
+    }
+
+    #[test]
+    fn list_state_scroll_to_selected() {
+        let mut state = ListState::default().with_selected(Some(5));
+        let list = List::new((0..10).map(|i| i.to_string()).collect::<Vec<_>>());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 3));
+        StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset, 3);
+        state.scroll_to_selected(ScrollPosition::Top);
+        assert_eq!(state.offset, 5);
+        state.scroll_to_selected(ScrollPosition::Center);
+        assert_eq!(state.offset, 4);
+        state.scroll_to_selected(ScrollPosition::Bottom);
+        assert_eq!(state.offset, 3);
+        state.select(None);
+        state.scroll_to_selected(ScrollPosition::Top);
+        assert_eq!(state.offset, 0);
+    }
+
+    #[test]
+    fn list_state_follow() {
+        let items = |n: usize| List::new((0..n).map(|i| i.to_string()).collect::<Vec<_>>());
+        let mut state = ListState::default().with_follow(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+        StatefulWidget::render(items(2), buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset, 0);
+        StatefulWidget::render(items(5), buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(
+            buf,
+            Buffer::with_lines(vec!["2      ", "3      ", "4      "])
+        );
+
+        // scrolled away, the new items are counted
+        *state.offset_mut() = 1;
+        StatefulWidget::render(items(8), buf.area, &mut buf, &mut state);
+        StatefulWidget::render(items(10), buf.area, &mut buf, &mut state);
+        assert_eq!((state.offset, state.new_items()), (1, 5));
+        assert_buffer_eq!(
+            buf,
+            Buffer::with_lines(vec!["1      ", "2      ", "5 new ↓"])
+        );
+
+        state.jump_to_latest();
+        assert_eq!(state.new_items(), 0);
+        StatefulWidget::render(items(11), buf.area, &mut buf, &mut state);
+        assert_eq!((state.offset, state.new_items()), (8, 0));
+    }
+
+    #[test]
+    fn list_state_follow_selection() {
+        let items = |n: usize| List::new((0..n).map(|i| i.to_string()).collect::<Vec<_>>());
+        let mut state = ListState::default()
+            .with_follow(true)
+            .with_selected(Some(0));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+        StatefulWidget::render(items(1), buf.area, &mut buf, &mut state);
+        StatefulWidget::render(items(4), buf.area, &mut buf, &mut state);
+        assert_eq!((state.selected, state.offset), (Some(3), 1));
+
+        state.select(Some(2));
+        StatefulWidget::render(items(6), buf.area, &mut buf, &mut state);
+        assert_eq!((state.selected, state.new_items()), (Some(2), 2));
+        state.select_last();
+        StatefulWidget::render(items(7), buf.area, &mut buf, &mut state);
+        assert_eq!((state.selected, state.new_items()), (Some(6), 0));
+
+        state.set_follow(false);
+        state.select_first();
+        StatefulWidget::render(items(9), buf.area, &mut buf, &mut state);
+        assert_eq!((state.selected, state.new_items()), (Some(0), 0));
+    }
+
     #[test]
     fn test_list_state_select() {
         let mut state = ListState::default();
@@ -1627,6 +2129,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn list_ellipsis() {
+        let items = vec![
+            ListItem::new(vec![Line::from("Item 0"), Line::from("is long".red())]),
+            ListItem::new("Item 1"),
+            ListItem::new("Item 2 is long too"),
+        ];
+        let list = List::new(items).highlight_symbol(">").ellipsis(true);
+        let mut state = ListState::default().with_selected(Some(0));
+        let buffer = render_stateful_widget(list, &mut state, 7, 4);
+        let mut expected = Buffer::with_lines(vec![">Item 0", " is lo…", " Item 1", " Item …"]);
+        expected.set_style(Rect::new(1, 1, 6, 1), Style::new().red());
+        assert_buffer_eq!(buffer, expected);
+
+        let truncated = state.truncated();
+        assert_eq!(truncated.len(), 2);
+        assert_eq!(truncated[0].index(), 0);
+        assert_eq!(truncated[0].column(), None);
+        assert_eq!(truncated[0].area(), Rect::new(0, 0, 7, 2));
+        assert_eq!(truncated[0].text(), "Item 0\nis long");
+        assert_eq!(truncated[1].text(), "Item 2 is long too");
+
+        let mut tooltips = TooltipState::default();
+        assert!(state.expand_selected(&mut tooltips));
+        assert!(tooltips.is_visible());
+        assert_eq!(
+            tooltips.hovered(),
+            Some((Rect::new(0, 0, 7, 2), "Item 0\nis long"))
+        );
+        state.select(Some(1));
+        assert!(!state.expand_selected(&mut TooltipState::default()));
+    }
+
     #[test]
     fn test_list_selected_item_ensures_selected_item_is_visible_when_offset_is_before_visible_range(
     ) {
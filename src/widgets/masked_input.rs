@@ -0,0 +1,799 @@
+#![deny(missing_docs)]
+use std::ops::RangeInclusive;
+
+use crate::{
+    buffer::Buffer,
+    input::{
+        Focusable, Input, Key, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent,
+        MouseEventKind,
+    },
+    layout::{Position, Rect},
+    style::{Color, Modifier, Style, Styled},
+    terminal::RedrawRequest,
+    widgets::{CursorProvider, StatefulWidget, Widget},
+};
+
+const DEFAULT_FOCUSED_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_INVALID_STYLE: Style = Style::new().fg(Color::Red);
+const DEFAULT_DISABLED_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+
+/// The kind of characters accepted by a slot of a [`MaskedInputState`] pattern.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum Slot {
+    /// `#`: an ASCII digit
+    Digit,
+    /// `a`: an ASCII letter
+    Letter,
+    /// `*`: an ASCII digit or letter
+    Any,
+}
+
+impl Slot {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(Self::Digit),
+            'a' => Some(Self::Letter),
+            '*' => Some(Self::Any),
+            _ => None,
+        }
+    }
+
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_ascii_alphabetic(),
+            Self::Any => c.is_ascii_alphanumeric(),
+        }
+    }
+}
+
+/// A run of slots of the pattern, typed as a whole.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Segment {
+    slots: Vec<Slot>,
+    range: Option<RangeInclusive<u32>>,
+    text: String,
+}
+
+impl Segment {
+    fn is_full(&self) -> bool {
+        self.text.len() == self.slots.len()
+    }
+
+    /// A segment with a range is complete with any number of digits in the range, the others
+    /// once all their slots are typed.
+    fn is_valid(&self) -> bool {
+        match (&self.range, self.text.parse::<u32>()) {
+            (Some(range), Ok(value)) => range.contains(&value),
+            (Some(_), Err(_)) => false,
+            (None, _) => self.is_full(),
+        }
+    }
+
+    fn exceeds_range(&self) -> bool {
+        self.range.as_ref().is_some_and(|range| {
+            self.text
+                .parse::<u32>()
+                .map_or(true, |value| value > *range.end())
+        })
+    }
+
+    /// Returns `true` if another digit typed in the segment would exceed its range.
+    fn is_saturated(&self) -> bool {
+        match (&self.range, self.text.parse::<u32>()) {
+            (Some(range), Ok(value)) => value.saturating_mul(10) > *range.end(),
+            _ => false,
+        }
+    }
+}
+
+/// An input typing characters into a fixed pattern, e.g. an IP address or a credit card number.
+///
+/// The pattern, stored in a [`MaskedInputState`], is made of slots separated by literal
+/// characters: `#` accepts an ASCII digit, `a` an ASCII letter and `*` either of them, and any
+/// other character is a literal, which can be escaped with `\` to use one of `#`, `a`, `*` or
+/// `\` literally. The consecutive slots form the segments of the input, e.g. the four numbers of
+/// `###.###.###.###`. The state handles the keyboard input:
+/// - The characters accepted by the next slot of the edited segment are appended to it, and the
+///   next segment is edited once the segment is full. A segment with a
+///   [range](MaskedInputState::with_range) rejects the digits exceeding its maximum, and is full
+///   once another digit would exceed it.
+/// - Typing the literal following a segment moves to the next segment, e.g. `.` after `10` in an
+///   IP address, so the cursor never stops on the literals.
+/// - `Backspace` removes the last character of the segment, or of the previous segment when it
+///   is empty.
+/// - `Left`, `Right`, `Home` and `End` move between the segments. The next character typed in a
+///   segment reached this way replaces its text.
+///
+/// The empty slots are shown with the [`placeholder`](Self::placeholder), the edited segment is
+/// patched with the [`focused_style`](Self::focused_style) while the input has the focus, and
+/// the segments that are typed but not [valid](MaskedInputState::is_valid) with the
+/// [`invalid_style`](Self::invalid_style) once they are left.
+///
+/// A [`read_only`](Self::read_only) input still moves between the segments, but its text can't
+/// be typed nor pasted. A [`disabled`](Self::disabled) input ignores all the events, isn't drawn
+/// focused, and is restyled with the [`disabled_style`](Self::disabled_style).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{input::Input, prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut MaskedInputState) {
+/// let input = MaskedInput::new().placeholder('_');
+/// frame.render_stateful_widget(input, Rect::new(0, 0, 15, 1), state);
+/// # }
+/// let mut state = MaskedInputState::new("###.###.###.###").with_range(0..=255);
+/// state.handle_paste("192.168.1.1");
+/// assert_eq!(state.value(), "192.168.1.1");
+/// assert!(state.is_valid());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct MaskedInput {
+    style: Style,
+    focused_style: Style,
+    invalid_style: Style,
+    placeholder: char,
+    read_only: bool,
+    disabled: bool,
+    disabled_style: Style,
+}
+
+impl Default for MaskedInput {
+    fn default() -> Self {
+        Self {
+            style: Style::default(),
+            focused_style: DEFAULT_FOCUSED_STYLE,
+            invalid_style: DEFAULT_INVALID_STYLE,
+            placeholder: '_',
+            read_only: false,
+            disabled: false,
+            disabled_style: DEFAULT_DISABLED_STYLE,
+        }
+    }
+}
+
+impl MaskedInput {
+    /// Creates a new masked input.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the style of the input.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style patched over the edited segment while the input has the focus. Defaults
+    /// to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_style(mut self, style: Style) -> Self {
+        self.focused_style = style;
+        self
+    }
+
+    /// Sets the style patched over the invalid segments. Defaults to a red foreground.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn invalid_style(mut self, style: Style) -> Self {
+        self.invalid_style = style;
+        self
+    }
+
+    /// Sets the character shown in the empty slots. Defaults to `_`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder(mut self, placeholder: char) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    /// Makes the input read-only: the keys and the mouse still move between the segments, but
+    /// their text can't be typed nor pasted.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Disables the input: it ignores all the events, isn't drawn focused and is restyled with
+    /// the [`disabled_style`](Self::disabled_style).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the style patched over the whole input while it is [`disabled`](Self::disabled).
+    /// Defaults to dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = style;
+        self
+    }
+}
+
+impl Styled for MaskedInput {
+    type Item = MaskedInput;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// The state of a [`MaskedInput`]: its pattern and the text typed in each segment.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     input::{Input, Key},
+///     widgets::MaskedInputState,
+/// };
+///
+/// let mut state = MaskedInputState::new("#### #### #### ####");
+/// for c in "4111 1111 1111 1111".chars() {
+///     state.handle_key(Key::Char(c).into());
+/// }
+/// assert_eq!(state.raw_value(), "4111111111111111");
+/// assert!(state.is_valid());
+/// // the fifth segment doesn't exist
+/// assert!(!state.handle_key(Key::Char('1').into()));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct MaskedInputState {
+    segments: Vec<Segment>,
+    /// The literals before each segment, and the one after the last segment
+    literals: Vec<String>,
+    /// Index of the segment edited from the keyboard
+    edited: usize,
+    /// Whether the next character typed replaces the text of the edited segment
+    replace: bool,
+    focused: bool,
+    /// Whether the text can't be typed, set when rendering
+    read_only: bool,
+    /// Whether the input ignores all the events, set when rendering
+    disabled: bool,
+    segment_areas: Vec<Rect>,
+    screen_cursor: Option<Position>,
+}
+
+impl Default for MaskedInputState {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl MaskedInputState {
+    /// Creates a new empty state with the given pattern, see [`MaskedInput`] for its syntax.
+    pub fn new(pattern: &str) -> Self {
+        let mut segments = Vec::new();
+        let mut literals = vec![String::new()];
+        let mut slots = Vec::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            if let Some(slot) = Slot::from_char(c) {
+                slots.push(slot);
+                continue;
+            }
+            if !slots.is_empty() {
+                segments.push(Segment {
+                    slots: std::mem::take(&mut slots),
+                    range: None,
+                    text: String::new(),
+                });
+                literals.push(String::new());
+            }
+            let literal = if c == '\\' { chars.next() } else { Some(c) };
+            literals.last_mut().unwrap().extend(literal);
+        }
+        if !slots.is_empty() {
+            segments.push(Segment {
+                slots,
+                range: None,
+                text: String::new(),
+            });
+            literals.push(String::new());
+        }
+        Self {
+            segments,
+            literals,
+            edited: 0,
+            replace: false,
+            focused: true,
+            read_only: false,
+            disabled: false,
+            segment_areas: Vec::new(),
+            screen_cursor: None,
+        }
+    }
+
+    /// Sets the range of the numbers typed in all the segments, e.g. `0..=255` for an IP
+    /// address. The segments with a range accept any number of digits in the range.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_range(mut self, range: RangeInclusive<u32>) -> Self {
+        for segment in &mut self.segments {
+            segment.range = Some(range.clone());
+        }
+        self
+    }
+
+    /// Sets the range of the number typed in the given segment, e.g. `1..=12` for the month of
+    /// a card expiry date.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern doesn't have the segment.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_segment_range(mut self, segment: usize, range: RangeInclusive<u32>) -> Self {
+        self.segments[segment].range = Some(range);
+        self
+    }
+
+    /// Sets whether the input has the focus.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Returns the typed text with the literals of the pattern, up to the last segment that
+    /// isn't empty, e.g. `192.168` for an IP address being typed.
+    pub fn value(&self) -> String {
+        let Some(last) = self.segments.iter().rposition(|s| !s.text.is_empty()) else {
+            return String::new();
+        };
+        let mut value = self.literals[0].clone();
+        for (i, segment) in self.segments[..=last].iter().enumerate() {
+            value.push_str(&segment.text);
+            if i < last || i + 1 == self.segments.len() {
+                value.push_str(&self.literals[i + 1]);
+            }
+        }
+        value
+    }
+
+    /// Returns the typed text without the literals, e.g. the digits of a card number.
+    pub fn raw_value(&self) -> String {
+        self.segments.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    /// Returns the text typed in each segment.
+    pub fn segments(&self) -> impl Iterator<Item = &str> {
+        self.segments.iter().map(|s| s.text.as_str())
+    }
+
+    /// Replaces the typed text, as if it was typed from the first segment. Returns `false` if
+    /// some characters were rejected.
+    pub fn set_value(&mut self, text: &str) -> bool {
+        self.clear();
+        let typed: Vec<bool> = text.chars().map(|c| self.type_char(c)).collect();
+        !typed.contains(&false)
+    }
+
+    /// Clears all the segments and edits the first one.
+    pub fn clear(&mut self) {
+        for segment in &mut self.segments {
+            segment.text.clear();
+        }
+        self.edited = 0;
+        self.replace = false;
+    }
+
+    /// Returns `true` if all the segments are valid: the segments with a range hold a number in
+    /// the range, and the others have all their slots typed.
+    pub fn is_valid(&self) -> bool {
+        self.segments.iter().all(Segment::is_valid)
+    }
+
+    /// Returns `true` if the given segment is valid, see [`is_valid`](Self::is_valid).
+    pub fn is_segment_valid(&self, segment: usize) -> bool {
+        self.segments.get(segment).is_some_and(Segment::is_valid)
+    }
+
+    /// Returns the index of the segment edited from the keyboard.
+    pub fn edited_segment(&self) -> usize {
+        self.edited
+    }
+
+    /// Edits the given segment, clamped to the segments of the pattern. The next character
+    /// typed replaces its text.
+    pub fn edit_segment(&mut self, segment: usize) {
+        self.edited = segment.min(self.segments.len().saturating_sub(1));
+        self.replace = true;
+    }
+
+    /// Returns `true` if the input has the focus. Defaults to `true`.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Sets whether the input has the focus.
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Returns `true` if the input was [read-only](MaskedInput::read_only) during the last
+    /// render.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns `true` if the input was [disabled](MaskedInput::disabled) during the last render.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Returns the position of the cursor on screen during the last render, if it was visible.
+    pub fn cursor_screen_position(&self) -> Option<Position> {
+        self.screen_cursor
+    }
+
+    /// Types a character in the edited segment, or moves to the next segment if the character
+    /// is the literal following it. Returns `false` if the character is rejected.
+    fn type_char(&mut self, c: char) -> bool {
+        let last = self.segments.len().saturating_sub(1);
+        let Some(segment) = self.segments.get_mut(self.edited) else {
+            return false;
+        };
+        let previous = segment.text.clone();
+        if self.replace && segment.slots[0].accepts(c) {
+            segment.text.clear();
+        }
+        let next_literal = self.literals[self.edited + 1].chars().next();
+        if !segment.is_full() && segment.slots[segment.text.len()].accepts(c) {
+            segment.text.push(c);
+            if segment.exceeds_range() {
+                segment.text = previous;
+                return false;
+            }
+            self.replace = false;
+            if (segment.is_full() || segment.is_saturated()) && self.edited < last {
+                self.edited += 1;
+                self.replace = true;
+            }
+            true
+        } else if Some(c) == next_literal && !segment.text.is_empty() && self.edited < last {
+            self.edited += 1;
+            self.replace = true;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Removes the last character of the edited segment, or of the previous one if it is
+    /// empty. Returns `false` if there is nothing to remove.
+    fn backspace(&mut self) -> bool {
+        if self.edited > 0 && self.segments[self.edited].text.is_empty() {
+            self.edited -= 1;
+        }
+        self.replace = false;
+        self.segments
+            .get_mut(self.edited)
+            .is_some_and(|segment| segment.text.pop().is_some())
+    }
+
+    /// Edits the given segment if it exists and is not the edited one.
+    fn move_to(&mut self, segment: Option<usize>) -> bool {
+        match segment.filter(|&s| s < self.segments.len() && s != self.edited) {
+            Some(segment) => {
+                self.edit_segment(segment);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Focusable for MaskedInputState {
+    fn set_focused(&mut self, focused: bool) {
+        MaskedInputState::set_focused(self, focused);
+    }
+
+    fn is_disabled(&self) -> bool {
+        MaskedInputState::is_disabled(self)
+    }
+}
+
+impl Input for MaskedInputState {
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if self.disabled
+            || key.kind == KeyEventKind::Release
+            || key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return false;
+        }
+        let handled = match key.key {
+            Key::Char(_) | Key::Backspace if self.read_only => false,
+            Key::Char(c) => self.type_char(c),
+            Key::Backspace => self.backspace(),
+            Key::Left => self.move_to(self.edited.checked_sub(1)),
+            Key::Right => self.move_to(Some(self.edited + 1)),
+            Key::Home => self.move_to(Some(0)),
+            Key::End => self.move_to(self.segments.len().checked_sub(1)),
+            _ => false,
+        };
+        RedrawRequest::request_if(handled)
+    }
+
+    /// Types the pasted text from the edited segment, skipping the rejected characters.
+    fn handle_paste(&mut self, text: &str) -> bool {
+        if self.read_only || self.disabled {
+            return false;
+        }
+        let typed: Vec<bool> = text.chars().map(|c| self.type_char(c)).collect();
+        let handled = typed.contains(&true);
+        RedrawRequest::request_if(handled)
+    }
+
+    /// Edits the segment clicked with the left button.
+    fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        if self.disabled || mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return false;
+        }
+        let position = mouse.position();
+        let clicked = self
+            .segment_areas
+            .iter()
+            .position(|area| area.contains(position));
+        let handled = self.move_to(clicked);
+        RedrawRequest::request_if(handled)
+    }
+}
+
+impl CursorProvider for MaskedInputState {
+    fn cursor_position(&self) -> Option<Position> {
+        self.screen_cursor.filter(|_| self.focused)
+    }
+}
+
+impl StatefulWidget for MaskedInput {
+    type State = MaskedInputState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.read_only = self.read_only;
+        state.disabled = self.disabled;
+        buf.set_style(area, self.style);
+        state.segment_areas.clear();
+        state.screen_cursor = None;
+        if area.is_empty() {
+            return;
+        }
+        self.render_segments(area, buf, state);
+        if self.disabled {
+            buf.set_style(area, self.disabled_style);
+        }
+    }
+}
+
+impl MaskedInput {
+    fn render_segments(&self, area: Rect, buf: &mut Buffer, state: &mut MaskedInputState) {
+        let mut x = area.x;
+        for (i, literal) in state.literals.iter().enumerate() {
+            if x >= area.right() {
+                break;
+            }
+            let width = area.right() - x;
+            x = buf
+                .set_stringn(x, area.y, literal, width.into(), self.style)
+                .0;
+            let Some(segment) = state.segments.get(i) else {
+                break;
+            };
+            if x >= area.right() {
+                break;
+            }
+            let edited = state.focused && !self.disabled && i == state.edited;
+            let mut style = self.style;
+            if !edited && !segment.text.is_empty() && !segment.is_valid() {
+                style = style.patch(self.invalid_style);
+            }
+            if edited {
+                style = style.patch(self.focused_style);
+            }
+            let text: String = segment
+                .text
+                .chars()
+                .chain(std::iter::repeat(self.placeholder))
+                .take(segment.slots.len())
+                .collect();
+            let start = x;
+            let width = area.right() - x;
+            x = buf.set_stringn(x, area.y, text, width.into(), style).0;
+            state
+                .segment_areas
+                .push(Rect::new(start, area.y, x - start, 1));
+            if edited {
+                let typed = if state.replace { 0 } else { segment.text.len() };
+                let cursor = start + typed as u16;
+                if cursor < area.right() {
+                    state.screen_cursor = Some(Position::new(cursor, area.y));
+                }
+            }
+        }
+    }
+}
+
+impl Widget for MaskedInput {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = MaskedInputState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    fn type_keys(state: &mut MaskedInputState, keys: &str) -> Vec<bool> {
+        keys.chars()
+            .map(|c| state.handle_key(Key::Char(c).into()))
+            .collect()
+    }
+
+    #[test]
+    fn parses_pattern() {
+        let state = MaskedInputState::new(r"(###) \#a*-##");
+        assert_eq!(state.literals, ["(", ") #", "-", ""]);
+        assert_eq!(
+            state
+                .segments
+                .iter()
+                .map(|s| s.slots.as_slice())
+                .collect::<Vec<_>>(),
+            [
+                &[Slot::Digit; 3][..],
+                &[Slot::Letter, Slot::Any],
+                &[Slot::Digit; 2]
+            ]
+        );
+    }
+
+    #[test]
+    fn typing_skips_literals() {
+        let mut state = MaskedInputState::new("#### ####");
+        assert_eq!(
+            type_keys(&mut state, "12a34"),
+            [true, true, false, true, true]
+        );
+        assert_eq!(state.edited_segment(), 1);
+        // the literal is skipped when typed after a full segment
+        assert_eq!(
+            type_keys(&mut state, " 5678 9"),
+            [false, true, true, true, true, false, false]
+        );
+        assert_eq!(state.value(), "1234 5678");
+        assert!(state.is_valid());
+        assert!(state.handle_key(Key::Backspace.into()));
+        assert!(!state.is_valid());
+    }
+
+    #[test]
+    fn typing_ranges() {
+        let mut state = MaskedInputState::new("###.###.###.###").with_range(0..=255);
+        // `26` is full as `260` would exceed the range
+        type_keys(&mut state, "26");
+        assert_eq!(state.edited_segment(), 1);
+        // `.` moves to the next segment after a shorter number
+        assert_eq!(type_keys(&mut state, "1.2"), [true, true, true]);
+        assert_eq!(type_keys(&mut state, "59"), [true, false]);
+        assert_eq!(state.value(), "26.1.25");
+        assert!(!state.is_valid());
+        type_keys(&mut state, ".0");
+        assert!(state.is_valid());
+        assert_eq!(state.segments().collect::<Vec<_>>(), ["26", "1", "25", "0"]);
+    }
+
+    #[test]
+    fn segment_range_validation() {
+        let mut state = MaskedInputState::new("##/##").with_segment_range(0, 1..=12);
+        assert_eq!(type_keys(&mut state, "0"), [true]);
+        assert!(!state.is_segment_valid(0));
+        assert_eq!(type_keys(&mut state, "7/2"), [true, false, true]);
+        assert_eq!(state.value(), "07/2");
+        // the second segment has no range, it needs both digits
+        assert!(!state.is_valid());
+        type_keys(&mut state, "9");
+        assert!(state.is_valid());
+    }
+
+    #[test]
+    fn editing_segments() {
+        let mut state = MaskedInputState::new("##-##-##");
+        type_keys(&mut state, "1234");
+        // moving back replaces the text of the segment once typed
+        assert!(state.handle_key(Key::Home.into()));
+        assert!(!state.handle_key(Key::Left.into()));
+        type_keys(&mut state, "9");
+        assert_eq!(state.value(), "9-34");
+        // backspace moves to the previous segment once empty
+        assert!(state.handle_key(Key::End.into()));
+        assert!(state.handle_key(Key::Backspace.into()));
+        assert_eq!(state.edited_segment(), 1);
+        assert_eq!(state.value(), "9-3");
+        assert!(!state.set_value("12x3456"));
+        assert_eq!(state.value(), "12-34-56");
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+        let mut state = MaskedInputState::new("(###) ##").with_segment_range(0, 100..=999);
+        state.handle_paste("0");
+        state.edit_segment(1);
+        type_keys(&mut state, "5");
+        StatefulWidget::render(MaskedInput::new(), buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["(0__) 5_    "]);
+        expected.set_style(Rect::new(1, 0, 3, 1), DEFAULT_INVALID_STYLE);
+        expected.set_style(Rect::new(6, 0, 2, 1), DEFAULT_FOCUSED_STYLE);
+        assert_buffer_eq!(buf, expected);
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(7, 0)));
+        // clicking a segment edits it
+        assert!(state.handle_mouse(MouseEvent::new(
+            MouseEventKind::Down(MouseButton::Left),
+            2,
+            0
+        )));
+        assert_eq!(state.edited_segment(), 0);
+    }
+
+    #[test]
+    fn render_narrow() {
+        for width in 1..=10 {
+            let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+            let mut state = MaskedInputState::new("(###) ##");
+            state.handle_paste("123");
+            let input = MaskedInput::new().focused_style(Style::new());
+            StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+            let line = format!("{:width$.width$}", "(123) __", width = width.into());
+            assert_buffer_eq!(buf, Buffer::with_lines(vec![line]));
+        }
+    }
+
+    #[test]
+    fn read_only_and_disabled() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let mut state = MaskedInputState::new("##-##");
+        state.set_value("1234");
+        StatefulWidget::render(
+            MaskedInput::new().read_only(true),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        assert!(state.is_read_only() && !state.is_disabled());
+        assert!(state.handle_key(Key::Home.into()));
+        assert!(!state.handle_key(Key::Char('9').into()));
+        assert!(!state.handle_key(Key::Backspace.into()));
+        assert!(!state.handle_paste("99"));
+        assert_eq!(state.value(), "12-34");
+
+        let mut buf = Buffer::empty(buf.area);
+        StatefulWidget::render(
+            MaskedInput::new().disabled(true),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        assert!(state.is_disabled() && Focusable::is_disabled(&state));
+        assert!(!state.handle_key(Key::End.into()));
+        assert_eq!(state.cursor_screen_position(), None);
+        let mut expected = Buffer::with_lines(vec!["12-34 "]);
+        expected.set_style(expected.area, DEFAULT_DISABLED_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+}
@@ -0,0 +1,762 @@
+#![deny(missing_docs)]
+use std::{fmt, rc::Rc, str::FromStr};
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::{
+    buffer::Buffer,
+    input::{Focusable, Input, Key, KeyEvent, KeyEventKind, KeyModifiers},
+    layout::{Alignment, Position, Rect},
+    style::{Modifier, Style, Styled},
+    terminal::RedrawRequest,
+    widgets::{Block, CursorProvider, StatefulWidget, Widget},
+};
+
+const DEFAULT_CURSOR_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_DISABLED_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+
+/// A number that can be edited with a [`NumberInput`].
+///
+/// This is implemented for all the primitive integer and floating point types.
+pub trait Number: Copy + PartialOrd + fmt::Display + FromStr {
+    /// The default step of the input.
+    const ONE: Self;
+    /// Whether the number has a fractional part, typed after a `.`.
+    const FLOAT: bool;
+    /// Whether the number can be negative, typed after a `-`.
+    const SIGNED: bool;
+
+    /// Adds two numbers, saturating at the bounds of the type.
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    /// Subtracts two numbers, saturating at the bounds of the type.
+    fn saturating_sub(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($signed:literal: $($ty:ty),*) => {
+        $(
+            impl Number for $ty {
+                const ONE: Self = 1;
+                const FLOAT: bool = false;
+                const SIGNED: bool = $signed;
+
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$ty>::saturating_add(self, rhs)
+                }
+
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    <$ty>::saturating_sub(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(true: i8, i16, i32, i64, i128, isize);
+impl_integer!(false: u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_float {
+    ($($ty:ty),*) => {
+        $(
+            impl Number for $ty {
+                const ONE: Self = 1.0;
+                const FLOAT: bool = true;
+                const SIGNED: bool = true;
+
+                fn saturating_add(self, rhs: Self) -> Self {
+                    self + rhs
+                }
+
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    self - rhs
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+/// A formatting hook of a [`NumberInput`].
+type Formatter<'a, T> = Rc<dyn Fn(T) -> String + 'a>;
+
+/// An input for integer or floating point numbers.
+///
+/// The value of the input is stored in a [`NumberInputState`], which also holds the range of the
+/// value and the step used to increment it. The state handles the keyboard input:
+/// - Digits are appended to the value, and `Backspace` removes the last character. For floating
+///   point numbers, a `.` starts the fractional part.
+/// - `Up` and `+` increment the value by the step, `Down` and `-` decrement it. A `-` typed
+///   while the input is empty starts a negative number instead.
+/// - The other keys are rejected: [`Input::handle_key`] returns `false` without changing the
+///   value.
+///
+/// While the input has the focus (see [`NumberInputState::set_focused`]), the text being typed
+/// is shown followed by the cursor. Otherwise the value is shown formatted with the
+/// [`decimals`](Self::decimals) and [`thousands_separator`](Self::thousands_separator), or with
+/// the [`format`](Self::format) hook.
+///
+/// A [`read_only`](Self::read_only) input rejects all the keys and the pasted text, as they all
+/// change the value, while a [`disabled`](Self::disabled) input also hides its cursor and is
+/// restyled with the [`disabled_style`](Self::disabled_style).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     input::{Input, Key},
+///     prelude::*,
+///     widgets::*,
+/// };
+///
+/// # fn ui(frame: &mut Frame, state: &mut NumberInputState<f64>) {
+/// let input = NumberInput::new().decimals(2).thousands_separator(',');
+/// frame.render_stateful_widget(input, Rect::new(0, 0, 12, 1), state);
+/// # }
+/// let mut state = NumberInputState::new(1.5).with_range(0.0, 10.0).with_step(0.5);
+/// assert!(state.handle_key(Key::Up.into()));
+/// assert_eq!(state.value(), 2.0);
+/// assert!(!state.handle_key(Key::Char('x').into()));
+/// ```
+pub struct NumberInput<'a, T> {
+    block: Option<Block<'a>>,
+    style: Style,
+    cursor_style: Style,
+    alignment: Alignment,
+    decimals: Option<usize>,
+    thousands_separator: Option<char>,
+    format: Option<Formatter<'a, T>>,
+    read_only: bool,
+    disabled: bool,
+    disabled_style: Style,
+}
+
+impl<'a, T> fmt::Debug for NumberInput<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NumberInput")
+            .field("block", &self.block)
+            .field("style", &self.style)
+            .field("cursor_style", &self.cursor_style)
+            .field("alignment", &self.alignment)
+            .field("decimals", &self.decimals)
+            .field("thousands_separator", &self.thousands_separator)
+            .field("format", &self.format.as_ref().map(|_| ".."))
+            .field("read_only", &self.read_only)
+            .field("disabled", &self.disabled)
+            .field("disabled_style", &self.disabled_style)
+            .finish()
+    }
+}
+
+impl<'a, T> Clone for NumberInput<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            block: self.block.clone(),
+            style: self.style,
+            cursor_style: self.cursor_style,
+            alignment: self.alignment,
+            decimals: self.decimals,
+            thousands_separator: self.thousands_separator,
+            format: self.format.clone(),
+            read_only: self.read_only,
+            disabled: self.disabled,
+            disabled_style: self.disabled_style,
+        }
+    }
+}
+
+impl<'a, T> Default for NumberInput<'a, T> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            style: Style::default(),
+            cursor_style: DEFAULT_CURSOR_STYLE,
+            alignment: Alignment::Right,
+            decimals: None,
+            thousands_separator: None,
+            format: None,
+            read_only: false,
+            disabled: false,
+            disabled_style: DEFAULT_DISABLED_STYLE,
+        }
+    }
+}
+
+impl<'a, T: Number> NumberInput<'a, T> {
+    /// Creates a new number input.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Surrounds the input with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the input.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the cell of the cursor. Defaults to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn cursor_style(mut self, style: Style) -> Self {
+        self.cursor_style = style;
+        self
+    }
+
+    /// Sets the alignment of the number in the input. Defaults to [`Alignment::Right`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Shows floating point values with the given number of decimals. Integers are not affected.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    /// Separates the thousands of the integer part of the value with the given character, e.g.
+    /// `,` to show `1,234,567`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = Some(separator);
+        self
+    }
+
+    /// Formats the value with the given function instead of the
+    /// [`decimals`](Self::decimals) and [`thousands_separator`](Self::thousands_separator), e.g.
+    /// to add a unit.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn format<F>(mut self, format: F) -> Self
+    where
+        F: Fn(T) -> String + 'a,
+    {
+        self.format = Some(Rc::new(format));
+        self
+    }
+
+    /// Makes the input read-only: the value is shown as usual, but the keys and the pasted text
+    /// are rejected.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Disables the input: it rejects all the input events, shows the formatted value without
+    /// the cursor and is restyled with the [`disabled_style`](Self::disabled_style).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the style patched over the whole input while it is
+    /// [`disabled`](Self::disabled). Defaults to dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = style;
+        self
+    }
+
+    /// Returns the value formatted as it is shown while the input doesn't have the focus.
+    pub fn format_value(&self, value: T) -> String {
+        if let Some(format) = &self.format {
+            return format(value);
+        }
+        let text = match self.decimals {
+            Some(decimals) => format!("{value:.decimals$}"),
+            None => value.to_string(),
+        };
+        match self.thousands_separator {
+            Some(separator) => group_thousands(&text, separator),
+            None => text,
+        }
+    }
+}
+
+impl<'a, T> Styled for NumberInput<'a, T> {
+    type Item = NumberInput<'a, T>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(mut self, style: Style) -> Self::Item {
+        self.style = style;
+        self
+    }
+}
+
+/// The state of a [`NumberInput`].
+///
+/// The state holds the value, kept within the [range](Self::with_range) of the input, and the
+/// text being typed. The value is updated whenever the text is a valid number, clamped to the
+/// range: the text is only replaced by the clamped value when the value is stepped, set or
+/// [committed](Self::commit), so that typing `15` in an input starting at `10` doesn't get
+/// stuck at `1`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberInputState<T> {
+    value: T,
+    text: String,
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+    focused: bool,
+    screen_cursor: Option<Position>,
+    read_only: bool,
+    disabled: bool,
+}
+
+impl<T: Number + Default> Default for NumberInputState<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Number> NumberInputState<T> {
+    /// Creates a new state with the given value, stepping by one.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            text: value.to_string(),
+            min: None,
+            max: None,
+            step: T::ONE,
+            focused: true,
+            screen_cursor: None,
+            read_only: false,
+            disabled: false,
+        }
+    }
+
+    /// Sets the range of the value, clamping the current value.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_range(mut self, min: T, max: T) -> Self {
+        self.min = Some(min);
+        self.max = Some(max);
+        self.set_value(self.value);
+        self
+    }
+
+    /// Sets the step by which the value is incremented and decremented.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Returns the value of the input.
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Replaces the value of the input, clamped to its range, and the text being typed.
+    pub fn set_value(&mut self, value: T) {
+        self.value = self.clamp(value);
+        self.commit();
+    }
+
+    /// Returns the text being typed.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replaces the text being typed with the value, e.g. once the user is done typing.
+    pub fn commit(&mut self) {
+        self.text = self.value.to_string();
+    }
+
+    /// Increments the value by the step, up to the maximum of the range.
+    pub fn increment(&mut self) {
+        self.set_value(self.value.saturating_add(self.step));
+    }
+
+    /// Decrements the value by the step, down to the minimum of the range.
+    pub fn decrement(&mut self) {
+        self.set_value(self.value.saturating_sub(self.step));
+    }
+
+    /// Returns `true` if the input has the focus. Defaults to `true`.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Sets whether the input has the focus. Losing the focus [commits](Self::commit) the text.
+    pub fn set_focused(&mut self, focused: bool) {
+        if self.focused && !focused {
+            self.commit();
+        }
+        self.focused = focused;
+    }
+
+    /// Returns the position of the cursor on screen during the last render, if it was visible.
+    pub fn cursor_screen_position(&self) -> Option<Position> {
+        self.screen_cursor
+    }
+
+    /// Returns `true` if the input was [read-only](NumberInput::read_only) during the last
+    /// render.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns `true` if the input was [disabled](NumberInput::disabled) during the last render.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Replaces the text being typed if it is the start of a number, updating the value. Returns
+    /// `false` otherwise.
+    fn edit_text(&mut self, text: String) -> bool {
+        let partial = matches!(text.as_str(), "" | "-" | "." | "-.");
+        if (text.starts_with('-') && !T::SIGNED) || (text.contains('.') && !T::FLOAT) {
+            return false;
+        }
+        match text.parse::<T>() {
+            Ok(value) => self.value = self.clamp(value),
+            Err(_) if partial => {}
+            Err(_) => return false,
+        }
+        self.text = text;
+        true
+    }
+
+    fn clamp(&self, mut value: T) -> T {
+        if let Some(min) = self.min.filter(|min| value < *min) {
+            value = min;
+        }
+        if let Some(max) = self.max.filter(|max| value > *max) {
+            value = max;
+        }
+        value
+    }
+}
+
+impl<T: Number> Focusable for NumberInputState<T> {
+    fn set_focused(&mut self, focused: bool) {
+        NumberInputState::set_focused(self, focused);
+    }
+
+    fn is_disabled(&self) -> bool {
+        NumberInputState::is_disabled(self)
+    }
+}
+
+impl<T: Number> Input for NumberInputState<T> {
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release
+            || self.read_only
+            || self.disabled
+            || key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return false;
+        }
+        let handled = match key.key {
+            Key::Up | Key::Char('+') => {
+                self.increment();
+                true
+            }
+            Key::Char('-') if !(T::SIGNED && self.text.is_empty()) => {
+                self.decrement();
+                true
+            }
+            Key::Down => {
+                self.decrement();
+                true
+            }
+            Key::Char(c @ ('0'..='9' | '.' | '-')) => {
+                let mut text = self.text.clone();
+                text.push(c);
+                self.edit_text(text)
+            }
+            Key::Backspace => {
+                let mut text = self.text.clone();
+                text.pop().is_some() && self.edit_text(text)
+            }
+            _ => false,
+        };
+        RedrawRequest::request_if(handled)
+    }
+
+    /// Replaces the value with the pasted text if it is a number.
+    fn handle_paste(&mut self, text: &str) -> bool {
+        if self.read_only || self.disabled {
+            return false;
+        }
+        match text.trim().parse() {
+            Ok(value) => {
+                self.set_value(value);
+                RedrawRequest::request_if(true)
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+impl<T: Number> CursorProvider for NumberInputState<T> {
+    fn cursor_position(&self) -> Option<Position> {
+        self.screen_cursor.filter(|_| self.focused)
+    }
+}
+
+impl<'a, T: Number> StatefulWidget for NumberInput<'a, T> {
+    type State = NumberInputState<T>;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.read_only = self.read_only;
+        state.disabled = self.disabled;
+        buf.set_style(area, self.style);
+        if self.disabled {
+            buf.set_style(area, self.disabled_style);
+        }
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        state.screen_cursor = None;
+        if area.is_empty() {
+            return;
+        }
+        // a disabled input is rendered as if it didn't have the focus
+        let focused = state.focused && !state.disabled;
+        let text = if focused {
+            state.text.clone()
+        } else {
+            self.format_value(state.value)
+        };
+        // keep the end of the text visible, and a cell for the cursor after it
+        let available = area.width - u16::from(focused);
+        let mut width = 0;
+        let start = text
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| {
+                width += c.width().unwrap_or(0) as u16;
+                width <= available
+            })
+            .last()
+            .map_or(text.len(), |(i, _)| i);
+        let shown = &text[start..];
+        let width = shown
+            .chars()
+            .map(|c| c.width().unwrap_or(0) as u16)
+            .sum::<u16>();
+        let x = area.x
+            + match self.alignment {
+                Alignment::Left => 0,
+                Alignment::Center => (available - width) / 2,
+                Alignment::Right => available - width,
+            };
+        buf.set_stringn(x, area.y, shown, usize::from(width), Style::default());
+        if focused {
+            let cursor = Position::new(x + width, area.y);
+            state.screen_cursor = Some(cursor);
+            buf.set_style(Rect::new(cursor.x, cursor.y, 1, 1), self.cursor_style);
+        }
+    }
+}
+
+impl<'a, T: Number + Default> Widget for NumberInput<'a, T> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = NumberInputState::default();
+        state.set_focused(false);
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+/// Inserts the separator between the thousands of the integer part of a formatted number.
+fn group_thousands(text: &str, separator: char) -> String {
+    let (sign, digits) = match text.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", text),
+    };
+    let integer_len = digits.find('.').unwrap_or(digits.len());
+    let (integer, fraction) = digits.split_at(integer_len);
+    let mut grouped = String::from(sign);
+    for (i, c) in integer.chars().enumerate() {
+        if i > 0 && (integer_len - i) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(c);
+    }
+    grouped.push_str(fraction);
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    fn type_keys<T: Number>(state: &mut NumberInputState<T>, keys: &str) -> Vec<bool> {
+        keys.chars()
+            .map(|c| state.handle_key(Key::Char(c).into()))
+            .collect()
+    }
+
+    #[test]
+    fn typing() {
+        let mut state = NumberInputState::new(0i32);
+        state.handle_key(Key::Backspace.into());
+        assert_eq!(type_keys(&mut state, "-12a"), [true, true, true, false]);
+        assert_eq!(state.text(), "-12");
+        assert_eq!(state.value(), -12);
+        // the value doesn't change while the text is not a number
+        assert!(state.handle_key(Key::Backspace.into()));
+        assert!(state.handle_key(Key::Backspace.into()));
+        assert_eq!(state.text(), "-");
+        assert_eq!(state.value(), -1);
+        // a dot is rejected for integers
+        assert_eq!(type_keys(&mut state, ".5"), [false, true]);
+        assert_eq!(state.value(), -5);
+    }
+
+    #[test]
+    fn typing_rejects_overflow() {
+        let mut state = NumberInputState::new(25u8);
+        assert_eq!(type_keys(&mut state, "69"), [false, false]);
+        assert_eq!(state.text(), "25");
+        // an unsigned input decrements on `-` even when empty, from the last typed value
+        state.handle_key(Key::Backspace.into());
+        state.handle_key(Key::Backspace.into());
+        assert!(state.handle_key(Key::Char('-').into()));
+        assert_eq!(state.text(), "1");
+    }
+
+    #[test]
+    fn typing_float() {
+        let mut state = NumberInputState::new(0.0f64);
+        state.handle_key(Key::Backspace.into());
+        state.handle_key(Key::Backspace.into());
+        state.handle_key(Key::Backspace.into());
+        assert_eq!(type_keys(&mut state, ".25."), [true, true, true, false]);
+        assert_eq!(state.value(), 0.25);
+    }
+
+    #[test]
+    fn step_and_range() {
+        let mut state = NumberInputState::new(5).with_range(0, 10).with_step(3);
+        state.handle_key(Key::Up.into());
+        assert_eq!(state.value(), 8);
+        state.handle_key(Key::Char('+').into());
+        assert_eq!(state.value(), 10);
+        state.handle_key(Key::Down.into());
+        state.handle_key(Key::Char('-').into());
+        assert_eq!(state.value(), 4);
+        // typed values are clamped, the text is replaced once committed
+        type_keys(&mut state, "2");
+        assert_eq!(state.text(), "42");
+        assert_eq!(state.value(), 10);
+        state.set_focused(false);
+        assert_eq!(state.text(), "10");
+        assert_eq!(
+            NumberInputState::new(i64::MAX).with_step(5).value(),
+            i64::MAX
+        );
+        let mut state = NumberInputState::new(i64::MAX);
+        state.increment();
+        assert_eq!(state.value(), i64::MAX);
+    }
+
+    #[test]
+    fn paste() {
+        let mut state = NumberInputState::new(1).with_range(0, 100);
+        assert!(state.handle_paste(" 250\n"));
+        assert_eq!(state.value(), 100);
+        assert!(!state.handle_paste("12 apples"));
+        assert_eq!(state.value(), 100);
+    }
+
+    #[test]
+    fn format_value() {
+        let input = NumberInput::new().thousands_separator(',');
+        assert_eq!(input.format_value(1234567), "1,234,567");
+        assert_eq!(input.format_value(-123456), "-123,456");
+        assert_eq!(input.format_value(999), "999");
+        let input = NumberInput::new().decimals(2).thousands_separator(' ');
+        assert_eq!(input.format_value(-1234.5), "-1 234.50");
+        let input = NumberInput::new()
+            .decimals(2)
+            .format(|value: f64| format!("{value} €"));
+        assert_eq!(input.format_value(3.5), "3.5 €");
+    }
+
+    #[test]
+    fn render_focused() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        let mut state = NumberInputState::new(1234);
+        let input = NumberInput::new().thousands_separator(',');
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["   1234 "]);
+        expected.set_style(Rect::new(7, 0, 1, 1), DEFAULT_CURSOR_STYLE);
+        assert_buffer_eq!(buf, expected);
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(7, 0)));
+    }
+
+    #[test]
+    fn render_unfocused() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        let mut state = NumberInputState::new(1234);
+        state.set_focused(false);
+        let input = NumberInput::new()
+            .thousands_separator(',')
+            .alignment(Alignment::Left);
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["1,234   "]));
+        assert_eq!(state.cursor_screen_position(), None);
+    }
+
+    #[test]
+    fn render_disabled() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let mut state = NumberInputState::new(1234);
+        let input = NumberInput::new().thousands_separator(',').disabled(true);
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec![" 1,234"]);
+        expected.set_style(buf.area, DEFAULT_DISABLED_STYLE);
+        assert_buffer_eq!(buf, expected);
+        assert_eq!(state.cursor_screen_position(), None);
+        assert!(!state.handle_key(Key::Up.into()));
+        assert!(!state.handle_paste("1"));
+        assert_eq!(state.value(), 1234);
+    }
+
+    #[test]
+    fn read_only() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let mut state = NumberInputState::new(12);
+        let input = NumberInput::new().read_only(true);
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        assert!(state.is_read_only());
+        assert!(!state.handle_key(Key::Char('3').into()));
+        assert!(!state.handle_key(Key::Down.into()));
+        assert_eq!(state.value(), 12);
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(5, 0)));
+    }
+
+    #[test]
+    fn render_keeps_end_visible() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        let mut state = NumberInputState::new(123456);
+        StatefulWidget::render(NumberInput::new(), buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["456 "]);
+        expected.set_style(Rect::new(3, 0, 1, 1), DEFAULT_CURSOR_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+}
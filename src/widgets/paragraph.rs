@@ -4,7 +4,7 @@ use crate::{
     buffer::Buffer,
     layout::{Alignment, Rect},
     style::{Style, Styled},
-    text::{StyledGrapheme, Text},
+    text::{Span, StyledGrapheme, Text},
     widgets::{
         reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine},
         Block, Widget,
@@ -55,6 +55,8 @@ pub struct Paragraph<'a> {
     scroll: (u16, u16),
     /// Alignment of the text
     alignment: Alignment,
+    /// Indentation of the wrapped lines
+    wrap_indent: WrapIndent<'a>,
 }
 
 /// Describes how to wrap text across lines.
@@ -92,6 +94,53 @@ pub struct Wrap {
     pub trim: bool,
 }
 
+/// Describes how to indent the lines of a wrapped text, see [`Paragraph::wrap_indent`].
+///
+/// The first line of a wrapped line is its first row on screen, and its continuation lines are
+/// the rows it is wrapped onto.
+///
+/// ## Examples
+///
+/// ```
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// let text = Text::from("  - A point long enough to wrap\n> A quote long enough to wrap");
+///
+/// // Keeping the indentation and aligning the continuation lines after the bullet (window
+/// // width of 20 chars):
+/// let indent = WrapIndent {
+///     preserve: true,
+///     prefix: Span::raw("  "),
+/// };
+/// Paragraph::new(text.clone())
+///     .wrap(Wrap { trim: true })
+///     .wrap_indent(indent);
+/// //   - A point long
+/// //     enough to wrap
+/// // > A quote long
+/// //   enough to wrap
+///
+/// // Quoting the continuation lines:
+/// let indent = WrapIndent {
+///     preserve: false,
+///     prefix: Span::styled("> ", Style::new().dim()),
+/// };
+/// Paragraph::new(text).wrap(Wrap { trim: true }).wrap_indent(indent);
+/// // - A point long
+/// // > enough to wrap
+/// // > A quote long
+/// // > enough to wrap
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct WrapIndent<'a> {
+    /// Repeats the leading whitespace of a line at the start of each of its wrapped lines,
+    /// instead of wrapping it with the text.
+    pub preserve: bool,
+    /// The hanging indentation inserted at the start of the continuation lines, after the
+    /// preserved indentation, e.g. spaces aligning them after a bullet or `> ` for quotes.
+    pub prefix: Span<'a>,
+}
+
 type Horizontal = u16;
 type Vertical = u16;
 
@@ -122,6 +171,7 @@ impl<'a> Paragraph<'a> {
             text: text.into(),
             scroll: (0, 0),
             alignment: Alignment::Left,
+            wrap_indent: WrapIndent::default(),
         }
     }
 
@@ -173,6 +223,29 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Sets the indentation of the wrapped lines.
+    ///
+    /// This only applies when the text is [wrapped](Self::wrap), see [`WrapIndent`] for more
+    /// information on the different options. The indentation is kept when there is no room left
+    /// for the text after it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let paragraph = Paragraph::new("  indented text")
+    ///     .wrap(Wrap { trim: true })
+    ///     .wrap_indent(WrapIndent {
+    ///         preserve: true,
+    ///         ..Default::default()
+    ///     });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn wrap_indent(mut self, indent: WrapIndent<'a>) -> Paragraph<'a> {
+        self.wrap_indent = indent;
+        self
+    }
+
     /// Set the scroll offset for the given paragraph
     ///
     /// The scroll offset is a tuple of (y, x) offset. The y offset is the number of lines to
@@ -241,7 +314,8 @@ impl<'a> Paragraph<'a> {
                 let alignment = line.alignment.unwrap_or(self.alignment);
                 (graphemes, alignment)
             });
-            let mut line_composer = WordWrapper::new(styled, width, trim);
+            let mut line_composer = WordWrapper::new(styled, width, trim)
+                .with_indent(self.wrap_indent.preserve, self.indent_prefix());
             let mut count = 0;
             while line_composer.next_line().is_some() {
                 count += 1;
@@ -305,7 +379,8 @@ impl<'a> Widget for Paragraph<'a> {
         });
 
         if let Some(Wrap { trim }) = self.wrap {
-            let line_composer = WordWrapper::new(styled, text_area.width, trim);
+            let line_composer = WordWrapper::new(styled, text_area.width, trim)
+                .with_indent(self.wrap_indent.preserve, self.indent_prefix());
             self.render_text(line_composer, text_area, buf);
         } else {
             let mut line_composer = LineTruncator::new(styled, text_area.width);
@@ -316,6 +391,13 @@ impl<'a> Widget for Paragraph<'a> {
 }
 
 impl<'a> Paragraph<'a> {
+    fn indent_prefix(&self) -> Vec<StyledGrapheme<'_>> {
+        self.wrap_indent
+            .prefix
+            .styled_graphemes(self.style)
+            .collect()
+    }
+
     fn render_text<C: LineComposer<'a>>(&self, mut composer: C, area: Rect, buf: &mut Buffer) {
         let mut y = 0;
         while let Some(WrappedLine {
@@ -564,6 +646,50 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_render_paragraph_with_wrap_indent() {
+        let text = "  - A point long enough to wrap\n> A quote long enough to wrap";
+        let aligned = Paragraph::new(text)
+            .wrap(Wrap { trim: true })
+            .wrap_indent(WrapIndent {
+                preserve: true,
+                prefix: Span::raw("  "),
+            });
+        test_case(
+            &aligned,
+            Buffer::with_lines(vec![
+                "  - A point long    ",
+                "    enough to wrap  ",
+                "> A quote long      ",
+                "  enough to wrap    ",
+            ]),
+        );
+        let quoted = Paragraph::new(text)
+            .wrap(Wrap { trim: false })
+            .wrap_indent(WrapIndent {
+                preserve: true,
+                prefix: Span::raw("> ").red(),
+            });
+        let mut expected = Buffer::with_lines(vec![
+            "  - A point long    ",
+            "  > enough to wrap  ",
+            "> A quote long      ",
+            "> enough to wrap    ",
+        ]);
+        expected.set_style(Rect::new(2, 1, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(0, 3, 2, 1), Style::new().red());
+        test_case(&quoted, expected);
+        // the indentation is dropped when there is no room left for the text
+        let narrow = Paragraph::new("    abc")
+            .wrap(Wrap { trim: false })
+            .wrap_indent(WrapIndent {
+                preserve: true,
+                prefix: Span::raw("    "),
+            });
+        test_case(&narrow, Buffer::with_lines(vec!["    ", "abc "]));
+        assert_eq!(aligned.line_count(20), 4);
+    }
+
     #[test]
     fn test_render_paragraph_with_word_wrap() {
         let text = "This is a long line of text that should wrap      and contains a superultramegagigalong word.";
@@ -0,0 +1,564 @@
+#![deny(missing_docs)]
+use std::{
+    env, fs, io,
+    path::{is_separator, PathBuf, MAIN_SEPARATOR, MAIN_SEPARATOR_STR},
+};
+
+use crate::{
+    buffer::Buffer,
+    input::{Focusable, Input, Key, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent, Validator},
+    layout::{Position, Rect},
+    style::{Style, Styled},
+    widgets::{
+        Autocomplete, AutocompleteState, CursorProvider, StatefulWidget, TextInput, TextInputState,
+        Widget,
+    },
+};
+
+/// An input for a file path, completing the entries of the directories on `Tab`.
+/// `(feature: fs)`
+///
+/// The path is edited in a [`TextInput`], and its state is stored in a [`PathInputState`], which
+/// handles the keyboard:
+/// - `Tab` completes the name of the entry being typed, in the directory typed before it or the
+///   current directory. A unique entry is completed, with a trailing separator for a directory,
+///   and several entries are completed up to their common prefix. When there is nothing left to
+///   complete, the entries are shown in a popup below the input.
+/// - While the popup is open, `Up` and `Down` select an entry, `Tab` and `Enter` accept it and
+///   `Esc` closes the popup.
+/// - A leading `~` stands for the home directory of the user, see [`PathInputState::path`].
+///
+/// Hidden entries, whose name starts with a dot, are only completed once the dot is typed.
+/// Nothing is completed while the [`input`](PathInput::input) is
+/// [read-only](TextInput::read_only) or [disabled](TextInput::disabled).
+///
+/// A [`PathValidator`] set with [`validator`](PathInput::validator) checks the path on every
+/// render, e.g. that it exists and is a writable directory, and its error is available with
+/// [`TextInputState::error`].
+///
+/// The popup is drawn in the whole buffer, usually the frame, after the input: rendering other
+/// widgets after the input may cover it.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     input::Input,
+///     prelude::*,
+///     widgets::*,
+/// };
+///
+/// # fn ui(frame: &mut Frame, state: &mut PathInputState) {
+/// let input = PathInput::new()
+///     .input(TextInput::new().block(Block::default().borders(Borders::ALL).title("Output")))
+///     .validator(PathValidator::new().kind(PathKind::Directory).must_exist(true));
+/// frame.render_stateful_widget(input, Rect::new(0, 0, 40, 3), state);
+/// frame.set_cursor_for(state);
+/// if let Some(error) = state.input().error() {
+///     // show the error
+/// }
+/// # }
+/// let state = PathInputState::new("~/Downloads");
+/// assert!(state.path().ends_with("Downloads"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PathInput<'a> {
+    input: TextInput<'a>,
+    validator: Option<PathValidator>,
+    max_completions: u16,
+}
+
+impl<'a> Default for PathInput<'a> {
+    fn default() -> Self {
+        Self {
+            input: TextInput::new(),
+            validator: None,
+            max_completions: 8,
+        }
+    }
+}
+
+impl<'a> PathInput<'a> {
+    /// Creates a new path input.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the input editing the path, e.g. to set its block or its placeholder.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn input(mut self, input: TextInput<'a>) -> Self {
+        self.input = input;
+        self
+    }
+
+    /// Sets the validator checking the path on every render.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn validator(mut self, validator: PathValidator) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    /// Sets the maximum number of entries shown at once in the popup. Defaults to 8.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn max_completions(mut self, max_completions: u16) -> Self {
+        self.max_completions = max_completions;
+        self
+    }
+}
+
+impl<'a> Styled for PathInput<'a> {
+    type Item = PathInput<'a>;
+
+    fn style(&self) -> Style {
+        Styled::style(&self.input)
+    }
+
+    fn set_style(mut self, style: Style) -> Self::Item {
+        self.input = self.input.style(style);
+        self
+    }
+}
+
+/// The kind of entry accepted by a [`PathValidator`]. `(feature: fs)`
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum PathKind {
+    /// Any kind of entry.
+    #[default]
+    Any,
+    /// A file, or a link to a file.
+    File,
+    /// A directory, or a link to a directory.
+    Directory,
+}
+
+/// A [`Validator`] checking a path on the filesystem. `(feature: fs)`
+///
+/// A leading `~` is expanded to the home directory. By default, any path is accepted, unless its
+/// entry can't be read because of its permissions. The path can be required to exist, to be of
+/// a given [`PathKind`] when it exists, and not to be read-only.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     input::Validator,
+///     widgets::{PathKind, PathValidator},
+/// };
+///
+/// let validator = PathValidator::new().kind(PathKind::File).must_exist(true);
+/// assert!(validator.validate("Cargo.toml").is_ok());
+/// assert_eq!(validator.validate("src"), Err("is not a file".to_string()));
+/// assert_eq!(validator.validate("missing"), Err("does not exist".to_string()));
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct PathValidator {
+    kind: PathKind,
+    must_exist: bool,
+    writable: bool,
+}
+
+impl PathValidator {
+    /// Creates a new validator accepting any path.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the kind of entry accepted when the path exists. Defaults to [`PathKind::Any`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn kind(mut self, kind: PathKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets whether the path must exist. Defaults to `false`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn must_exist(mut self, must_exist: bool) -> Self {
+        self.must_exist = must_exist;
+        self
+    }
+
+    /// Sets whether an existing entry must not be read-only. Defaults to `false`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn writable(mut self, writable: bool) -> Self {
+        self.writable = writable;
+        self
+    }
+}
+
+impl Validator for PathValidator {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        let path = expand_home(value);
+        let metadata = match fs::metadata(&path) {
+            Ok(metadata) => metadata,
+            Err(err) if err.kind() == io::ErrorKind::PermissionDenied => {
+                return Err("permission denied".to_string());
+            }
+            Err(_) if self.must_exist => return Err("does not exist".to_string()),
+            Err(_) => return Ok(()),
+        };
+        match self.kind {
+            PathKind::File if !metadata.is_file() => return Err("is not a file".to_string()),
+            PathKind::Directory if !metadata.is_dir() => {
+                return Err("is not a directory".to_string());
+            }
+            _ => {}
+        }
+        if metadata.is_dir() && fs::read_dir(&path).is_err() {
+            return Err("permission denied".to_string());
+        }
+        if self.writable && metadata.permissions().readonly() {
+            return Err("is read-only".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// The state of a [`PathInput`]: the [`TextInputState`] of the path, and the entries shown in
+/// the popup. `(feature: fs)`
+#[derive(Debug, Default, Clone)]
+pub struct PathInputState {
+    input: TextInputState,
+    completions: AutocompleteState,
+}
+
+impl PathInputState {
+    /// Creates a new state with the given path and the cursor at its end.
+    pub fn new<T>(value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            input: TextInputState::new(value),
+            completions: AutocompleteState::default(),
+        }
+    }
+
+    /// Returns the path as typed.
+    pub fn value(&self) -> &str {
+        self.input.value()
+    }
+
+    /// Returns the path, with a leading `~` expanded to the home directory of the user.
+    pub fn path(&self) -> PathBuf {
+        expand_home(self.input.value())
+    }
+
+    /// Returns the state of the input editing the path.
+    pub fn input(&self) -> &TextInputState {
+        &self.input
+    }
+
+    /// Returns the state of the input editing the path, e.g. to set its value.
+    pub fn input_mut(&mut self) -> &mut TextInputState {
+        &mut self.input
+    }
+
+    /// Returns `true` if the popup of the entries is open.
+    pub fn is_completing(&self) -> bool {
+        self.completions.is_open()
+    }
+
+    /// Returns `true` if the input has the focus.
+    pub fn is_focused(&self) -> bool {
+        self.input.is_focused()
+    }
+
+    /// Sets whether the input has the focus. Losing the focus closes the popup.
+    pub fn set_focused(&mut self, focused: bool) {
+        self.input.set_focused(focused);
+        if !focused {
+            self.completions.close();
+        }
+    }
+
+    /// Completes the name of the entry being typed, as `Tab` does. Returns `true` if the path
+    /// was completed or the popup was opened.
+    pub fn complete(&mut self) -> bool {
+        let value = self.input.value();
+        if value == "~" {
+            self.input.replace_value(&format!("~{MAIN_SEPARATOR}"));
+            return true;
+        }
+        let (directory, prefix) = match value.rfind(is_separator) {
+            Some(i) => value.split_at(i + 1),
+            None => ("", value),
+        };
+        let entries = list_entries(directory, prefix);
+        let completed = match entries.as_slice() {
+            [] => return false,
+            [(name, is_dir)] => {
+                let separator = if *is_dir { MAIN_SEPARATOR_STR } else { "" };
+                format!("{directory}{name}{separator}")
+            }
+            [(first, _), others @ ..] => {
+                let common = others.iter().fold(first.as_str(), |common, (name, _)| {
+                    let len = common
+                        .char_indices()
+                        .zip(name.chars())
+                        .find(|((_, a), b)| a != b)
+                        .map_or(common.len().min(name.len()), |((i, _), _)| i);
+                    &common[..len]
+                });
+                format!("{directory}{common}")
+            }
+        };
+        if completed != value {
+            self.input.replace_value(&completed);
+            return true;
+        }
+        let candidates = entries.into_iter().map(|(name, is_dir)| {
+            let separator = if is_dir { MAIN_SEPARATOR_STR } else { "" };
+            format!("{directory}{name}{separator}")
+        });
+        self.completions.set_candidates(candidates);
+        // the entries all start with the value, and are kept sorted by name
+        self.completions.open("")
+    }
+}
+
+impl Focusable for PathInputState {
+    fn set_focused(&mut self, focused: bool) {
+        PathInputState::set_focused(self, focused);
+    }
+
+    fn is_disabled(&self) -> bool {
+        self.input.is_disabled()
+    }
+}
+
+/// Returns the home directory of the user, from the `HOME` or `USERPROFILE` variables.
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .filter(|home| !home.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Returns the path of the value, with a leading `~` expanded to the home directory.
+fn expand_home(value: &str) -> PathBuf {
+    let home = value
+        .strip_prefix('~')
+        .filter(|rest| rest.is_empty() || rest.starts_with(is_separator))
+        .and_then(|rest| Some((home_dir()?, rest)));
+    match home {
+        Some((home, "")) => home,
+        Some((home, rest)) => home.join(rest.trim_start_matches(is_separator)),
+        None => PathBuf::from(value),
+    }
+}
+
+/// Returns the names of the entries of the directory starting with the prefix, sorted, and
+/// whether they are directories.
+fn list_entries(directory: &str, prefix: &str) -> Vec<(String, bool)> {
+    let path = if directory.is_empty() {
+        PathBuf::from(".")
+    } else {
+        expand_home(directory)
+    };
+    let Ok(entries) = fs::read_dir(path) else {
+        return Vec::new();
+    };
+    let mut entries = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let name = entry.file_name().into_string().ok()?;
+            let hidden = name.starts_with('.') && !prefix.starts_with('.');
+            (name.starts_with(prefix) && !hidden).then(|| (name, entry.path().is_dir()))
+        })
+        .collect::<Vec<_>>();
+    entries.sort();
+    entries
+}
+
+impl Input for PathInputState {
+    /// Completes the path on `Tab`, see [`PathInput`]. The other keys edit the path, and close
+    /// the popup.
+    ///
+    /// `Tab` is not handled when there is nothing to complete, e.g. to move the focus.
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release {
+            return false;
+        }
+        if self.completions.is_open() {
+            let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+            match key.key {
+                Key::Up | Key::Down | Key::Tab | Key::Enter | Key::Esc => {
+                    return self.completions.handle_key(&mut self.input, key);
+                }
+                Key::Char('n' | 'p') if ctrl => {
+                    return self.completions.handle_key(&mut self.input, key);
+                }
+                _ => self.completions.close(),
+            }
+        }
+        let editable = !self.input.is_read_only() && !self.input.is_disabled();
+        if key.key == Key::Tab && key.modifiers == KeyModifiers::NONE && editable {
+            return self.complete();
+        }
+        self.input.handle_key(key)
+    }
+
+    fn handle_paste(&mut self, text: &str) -> bool {
+        self.completions.close();
+        self.input.handle_paste(text)
+    }
+
+    fn handle_preedit(&mut self, text: Option<&str>) -> bool {
+        self.input.handle_preedit(text)
+    }
+
+    fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        self.input.handle_mouse(mouse)
+    }
+}
+
+impl CursorProvider for PathInputState {
+    fn cursor_position(&self) -> Option<Position> {
+        self.input.cursor_position()
+    }
+}
+
+impl<'a> StatefulWidget for PathInput<'a> {
+    type State = PathInputState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let mut input = self.input;
+        if let Some(validator) = self.validator {
+            input = input.validator(validator);
+        }
+        StatefulWidget::render(input, area, buf, &mut state.input);
+        if state.input.is_read_only() || state.input.is_disabled() {
+            state.completions.close();
+        }
+        let popup = Autocomplete::new(area).max_height(self.max_completions);
+        let bounds = buf.area;
+        StatefulWidget::render(popup, bounds, buf, &mut state.completions);
+    }
+}
+
+impl<'a> Widget for PathInput<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = PathInputState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    /// Creates a directory with a few entries, removed when dropped.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = env::temp_dir().join(format!("ratatui-{name}-{}", std::process::id()));
+            fs::create_dir_all(path.join("alpine")).unwrap();
+            fs::write(path.join("alpha.txt"), "").unwrap();
+            fs::write(path.join("beta"), "").unwrap();
+            fs::write(path.join(".hidden"), "").unwrap();
+            Self(path)
+        }
+
+        fn join(&self, name: &str) -> String {
+            format!("{}{MAIN_SEPARATOR}{name}", self.0.display())
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn completes_entries() {
+        let dir = TempDir::new("path-input-complete");
+        let mut state = PathInputState::new(dir.join("b"));
+        assert!(state.handle_key(Key::Tab.into()));
+        assert_eq!(state.value(), dir.join("beta"));
+
+        state.input_mut().set_value(dir.join("."));
+        assert!(state.handle_key(Key::Tab.into()));
+        assert_eq!(state.value(), dir.join(".hidden"));
+
+        // completed up to the common prefix, then listed in the popup
+        state.input_mut().set_value(dir.join("a"));
+        assert!(state.handle_key(Key::Tab.into()));
+        assert_eq!(state.value(), dir.join("alp"));
+        assert!(!state.is_completing());
+        assert!(state.handle_key(Key::Tab.into()));
+        assert!(state.is_completing());
+        assert!(state.handle_key(Key::Down.into()));
+        assert!(state.handle_key(Key::Enter.into()));
+        assert_eq!(state.value(), dir.join(&format!("alpine{MAIN_SEPARATOR}")));
+        assert!(!state.is_completing());
+
+        // nothing to complete in an empty directory
+        assert!(!state.handle_key(Key::Tab.into()));
+    }
+
+    #[test]
+    fn typing_closes_the_popup() {
+        let dir = TempDir::new("path-input-typing");
+        let mut state = PathInputState::new(dir.join("alp"));
+        assert!(state.complete());
+        assert!(state.is_completing());
+        assert!(state.handle_key(Key::Char('h').into()));
+        assert!(!state.is_completing());
+        assert!(state.handle_key(Key::Tab.into()));
+        assert_eq!(state.value(), dir.join("alpha.txt"));
+    }
+
+    #[test]
+    fn read_only_inputs_are_not_completed() {
+        let dir = TempDir::new("path-input-read-only");
+        let mut state = PathInputState::new(dir.join("alp"));
+        assert!(state.complete());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let input = PathInput::new().input(TextInput::new().read_only(true));
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        assert!(!state.is_completing());
+        assert!(!state.handle_key(Key::Tab.into()));
+        assert_eq!(state.value(), dir.join("alp"));
+    }
+
+    #[test]
+    fn expands_home() {
+        let Some(home) = home_dir() else {
+            return;
+        };
+        assert_eq!(expand_home("~"), home);
+        assert_eq!(expand_home("~/notes"), home.join("notes"));
+        assert_eq!(expand_home("~notes"), Path::new("~notes"));
+        let mut state = PathInputState::new("~");
+        assert!(state.complete());
+        assert_eq!(state.value(), format!("~{MAIN_SEPARATOR}"));
+    }
+
+    #[test]
+    fn validates_paths() {
+        let dir = TempDir::new("path-input-validate");
+        let directory = PathValidator::new().kind(PathKind::Directory);
+        assert_eq!(directory.validate(&dir.join("alpine")), Ok(()));
+        assert_eq!(
+            directory.validate(&dir.join("beta")),
+            Err("is not a directory".to_string())
+        );
+        assert_eq!(directory.validate(&dir.join("gamma")), Ok(()));
+        let existing = directory.must_exist(true);
+        assert_eq!(
+            existing.validate(&dir.join("gamma")),
+            Err("does not exist".to_string())
+        );
+
+        let path = dir.join("beta");
+        let mut permissions = fs::metadata(&path).unwrap().permissions();
+        permissions.set_readonly(true);
+        fs::set_permissions(&path, permissions).unwrap();
+        let writable = PathValidator::new().writable(true);
+        assert_eq!(writable.validate(&path), Err("is read-only".to_string()));
+    }
+}
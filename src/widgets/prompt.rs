@@ -0,0 +1,304 @@
+#![deny(missing_docs)]
+use crate::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style, Styled},
+    text::Line,
+    widgets::{Block, StatefulWidget, TextInput, TextInputState, Widget},
+};
+
+const DEFAULT_ERROR_STYLE: Style = Style::new().fg(Color::Red);
+const DEFAULT_HINT_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+
+/// A question asked on a few rows: a label, a [`TextInput`] below it, the validation error of
+/// the input and a hint.
+///
+/// The rows are, from top to bottom:
+/// - the label,
+/// - the input, one row plus the borders of its block if it has one,
+/// - the error message of the input (see [`TextInputState::error`]), only when the input has a
+///   [validator](TextInput::validator). The row is kept empty while the value is valid, so that
+///   the layout doesn't move while typing,
+/// - the [`hint`](Prompt::hint), if there is one.
+///
+/// [`height`](Prompt::height) returns the number of rows needed, e.g. for a
+/// [`Constraint::Length`]. The rows that don't fit in the render area are not rendered.
+///
+/// The state of the prompt is the [`TextInputState`] of its input, which handles the keyboard
+/// input. [`Prompt`] is meant for quick one-off questions, while a [`Form`] edits several fields
+/// together.
+///
+/// [`Constraint::Length`]: crate::layout::Constraint::Length
+/// [`Form`]: struct@crate::widgets::Form
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{input::NonEmpty, prelude::*, widgets::*};
+///
+/// fn ui(frame: &mut Frame, state: &mut TextInputState) {
+///     let prompt = Prompt::new("What is your name?")
+///         .input(TextInput::new().validator(NonEmpty))
+///         .hint("Press Enter to continue");
+///     let area = Rect {
+///         height: prompt.height(),
+///         ..frame.size()
+///     };
+///     frame.render_stateful_widget(prompt, area, state);
+///     frame.set_cursor_for(state);
+/// }
+///
+/// # let mut terminal = Terminal::new(backend::TestBackend::new(30, 4))?;
+/// let mut state = TextInputState::default();
+/// terminal.draw(|frame| ui(frame, &mut state))?;
+/// assert_eq!(state.error(), Some("must not be empty"));
+/// state.insert_str("Ann");
+/// terminal.draw(|frame| ui(frame, &mut state))?;
+/// assert!(state.is_valid());
+/// # std::io::Result::Ok(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Prompt<'a> {
+    label: Line<'a>,
+    input: TextInput<'a>,
+    hint: Option<Line<'a>>,
+    block: Option<Block<'a>>,
+    style: Style,
+    label_style: Style,
+    error_style: Style,
+    hint_style: Style,
+}
+
+impl<'a> Prompt<'a> {
+    /// Creates a new prompt with the given label.
+    pub fn new<T>(label: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        Self {
+            label: label.into(),
+            input: TextInput::new(),
+            hint: None,
+            block: None,
+            style: Style::default(),
+            label_style: Style::default(),
+            error_style: DEFAULT_ERROR_STYLE,
+            hint_style: DEFAULT_HINT_STYLE,
+        }
+    }
+
+    /// Sets the input of the prompt, e.g. to validate or mask its value.
+    ///
+    /// Defaults to a [`TextInput`] without any block or validator.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn input(mut self, input: TextInput<'a>) -> Self {
+        self.input = input;
+        self
+    }
+
+    /// Sets the hint shown below the input, e.g. the expected format or the keys to press.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hint<T>(mut self, hint: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// Surrounds the prompt with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the whole prompt.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the label.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label_style(mut self, style: Style) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    /// Sets the style of the error message. Defaults to a red foreground.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn error_style(mut self, style: Style) -> Self {
+        self.error_style = style;
+        self
+    }
+
+    /// Sets the style of the hint, patched by the styles of its spans. Defaults to dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hint_style(mut self, style: Style) -> Self {
+        self.hint_style = style;
+        self
+    }
+
+    /// Returns the number of rows needed to render the whole prompt, including its block.
+    pub fn height(&self) -> u16 {
+        let probe = Rect::new(0, 0, 100, 100);
+        let block_height = self
+            .block
+            .as_ref()
+            .map_or(0, |block| probe.height - block.inner(probe).height);
+        let error_height = u16::from(self.input.has_validator());
+        let hint_height = u16::from(self.hint.is_some());
+        block_height + 1 + self.input.height() + error_height + hint_height
+    }
+}
+
+impl<'a> Styled for Prompt<'a> {
+    type Item = Prompt<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl<'a> StatefulWidget for Prompt<'a> {
+    type State = TextInputState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        // takes the next rows of the area, as many as fit
+        let mut y = area.y;
+        let mut next_rows = |height: u16| {
+            let rows = Rect::new(area.x, y, area.width, height.min(area.bottom() - y));
+            y += rows.height;
+            rows
+        };
+
+        let label_area = next_rows(1);
+        if !label_area.is_empty() {
+            buf.set_style(label_area, self.label_style);
+            buf.set_line(label_area.x, label_area.y, &self.label, label_area.width);
+        }
+        let has_validator = self.input.has_validator();
+        let input_area = next_rows(self.input.height());
+        // render the input even when it is hidden, to validate its value
+        StatefulWidget::render(self.input, input_area, buf, state);
+        if has_validator {
+            let error_area = next_rows(1);
+            if let (false, Some(error)) = (error_area.is_empty(), state.error()) {
+                buf.set_stringn(
+                    error_area.x,
+                    error_area.y,
+                    error,
+                    usize::from(error_area.width),
+                    self.error_style,
+                );
+            }
+        }
+        if let Some(mut hint) = self.hint {
+            let hint_area = next_rows(1);
+            if !hint_area.is_empty() {
+                for span in &mut hint.spans {
+                    span.style = self.hint_style.patch(span.style);
+                }
+                buf.set_line(hint_area.x, hint_area.y, &hint, hint_area.width);
+            }
+        }
+    }
+}
+
+impl<'a> Widget for Prompt<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = TextInputState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_buffer_eq,
+        input::{Input, Key, NonEmpty},
+        style::Stylize,
+        widgets::Borders,
+    };
+
+    fn prompt() -> Prompt<'static> {
+        Prompt::new("Name?")
+            .input(
+                TextInput::new()
+                    .cursor_style(Style::default())
+                    .validator(NonEmpty),
+            )
+            .hint("Enter")
+    }
+
+    #[test]
+    fn height() {
+        assert_eq!(Prompt::new("Name?").height(), 2);
+        assert_eq!(prompt().height(), 4);
+        let input = TextInput::new().block(Block::default().borders(Borders::ALL));
+        let prompt = prompt()
+            .input(input)
+            .block(Block::default().borders(Borders::TOP));
+        assert_eq!(prompt.height(), 6);
+    }
+
+    #[test]
+    fn render() {
+        let mut state = TextInputState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        StatefulWidget::render(prompt(), buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec![
+            "Name?       ",
+            "            ",
+            "must not be ",
+            "Enter       ",
+        ]);
+        expected.set_style(Rect::new(0, 1, 12, 1), Style::new().red());
+        expected.set_style(Rect::new(0, 2, 12, 1), DEFAULT_ERROR_STYLE);
+        expected.set_style(Rect::new(0, 3, 5, 1), DEFAULT_HINT_STYLE);
+        assert_buffer_eq!(buf, expected);
+
+        state.handle_key(Key::Char('A').into());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        StatefulWidget::render(prompt(), buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec![
+            "Name?       ",
+            "A           ",
+            "            ",
+            "Enter       ",
+        ]);
+        expected.set_style(Rect::new(0, 3, 5, 1), DEFAULT_HINT_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_in_a_small_area() {
+        let mut state = TextInputState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+        StatefulWidget::render(
+            prompt().block(Block::default().borders(Borders::TOP).title("Q")),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["Q───────", "Name?   "]));
+        // the hidden input is still validated
+        assert_eq!(state.error(), Some("must not be empty"));
+    }
+}
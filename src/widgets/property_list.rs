@@ -0,0 +1,448 @@
+#![deny(missing_docs)]
+use std::fmt::Display as FmtDisplay;
+
+use strum::{Display, EnumString};
+
+use crate::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style, Styled},
+    text::{Line, Span, StyledGrapheme, Text},
+    widgets::{
+        reflow::{LineComposer, WordWrapper, WrappedLine},
+        Block, Widget,
+    },
+};
+
+/// The kind of value of a [`Property`], used to style values by type.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ValueKind {
+    /// Free text.
+    #[default]
+    Text,
+    /// A number.
+    Number,
+    /// A boolean.
+    Boolean,
+    /// No value. Empty values are shown with a placeholder.
+    Empty,
+}
+
+/// A key-value pair displayed by a [`PropertyList`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::*;
+///
+/// let properties = vec![
+///     Property::new("Name", "nginx"),
+///     Property::number("Replicas", 3),
+///     Property::boolean("Ready", true),
+///     Property::new("Labels", ""),
+/// ];
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Property<'a> {
+    key: Line<'a>,
+    value: Text<'a>,
+    kind: ValueKind,
+}
+
+impl<'a> Property<'a> {
+    /// Creates a new property with a text value.
+    ///
+    /// The kind of the property is [`ValueKind::Empty`] if the value is empty and
+    /// [`ValueKind::Text`] otherwise.
+    pub fn new<K, V>(key: K, value: V) -> Self
+    where
+        K: Into<Line<'a>>,
+        V: Into<Text<'a>>,
+    {
+        let value = value.into();
+        let kind = if value.width() == 0 {
+            ValueKind::Empty
+        } else {
+            ValueKind::Text
+        };
+        Self {
+            key: key.into(),
+            value,
+            kind,
+        }
+    }
+
+    /// Creates a new property with a number value.
+    pub fn number<K, V>(key: K, value: V) -> Self
+    where
+        K: Into<Line<'a>>,
+        V: FmtDisplay,
+    {
+        Self::new(key, value.to_string()).kind(ValueKind::Number)
+    }
+
+    /// Creates a new property with a boolean value.
+    pub fn boolean<K>(key: K, value: bool) -> Self
+    where
+        K: Into<Line<'a>>,
+    {
+        Self::new(key, value.to_string()).kind(ValueKind::Boolean)
+    }
+
+    /// Sets the kind of the value, which determines its style.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn kind(mut self, kind: ValueKind) -> Self {
+        self.kind = kind;
+        self
+    }
+}
+
+/// A group of properties with an optional header.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+struct PropertyGroup<'a> {
+    title: Option<Line<'a>>,
+    properties: Vec<Property<'a>>,
+}
+
+/// A widget displaying key-value pairs in two aligned columns, e.g. for a "details" pane next to
+/// a list or a table.
+///
+/// The width of the key column is the width of the widest key, optionally limited with
+/// [`max_key_width`](PropertyList::max_key_width). Values are wrapped to the remaining width.
+/// Properties can be organized in [groups](PropertyList::group) with a header, and values are
+/// styled according to their [`ValueKind`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// PropertyList::new(vec![Property::new("Name", "nginx")])
+///     .group(
+///         "Status",
+///         vec![
+///             Property::number("Replicas", 3),
+///             Property::boolean("Ready", true),
+///         ],
+///     )
+///     .block(Block::default().borders(Borders::ALL).title("Details"))
+///     .key_style(Style::new().bold())
+///     .value_style(ValueKind::Number, Style::new().green());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PropertyList<'a> {
+    groups: Vec<PropertyGroup<'a>>,
+    block: Option<Block<'a>>,
+    style: Style,
+    key_style: Style,
+    header_style: Style,
+    value_styles: [Style; 4],
+    separator: Span<'a>,
+    placeholder: Span<'a>,
+    max_key_width: Option<u16>,
+}
+
+impl<'a> Default for PropertyList<'a> {
+    fn default() -> Self {
+        Self {
+            groups: vec![],
+            block: None,
+            style: Style::default(),
+            key_style: Style::default(),
+            header_style: Style::new().add_modifier(Modifier::BOLD),
+            value_styles: [
+                Style::new(),
+                Style::new().fg(Color::Cyan),
+                Style::new().fg(Color::Yellow),
+                Style::new().add_modifier(Modifier::DIM),
+            ],
+            separator: Span::raw("  "),
+            placeholder: Span::raw("-"),
+            max_key_width: None,
+        }
+    }
+}
+
+impl<'a> PropertyList<'a> {
+    /// Creates a new property list with the given properties, without header.
+    pub fn new(properties: Vec<Property<'a>>) -> Self {
+        Self {
+            groups: vec![PropertyGroup {
+                title: None,
+                properties,
+            }],
+            ..Self::default()
+        }
+    }
+
+    /// Adds a group of properties shown below a header.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn group<T>(mut self, title: T, properties: Vec<Property<'a>>) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        self.groups.push(PropertyGroup {
+            title: Some(title.into()),
+            properties,
+        });
+        self
+    }
+
+    /// Surrounds the list with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the whole widget.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the keys.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn key_style(mut self, style: Style) -> Self {
+        self.key_style = style;
+        self
+    }
+
+    /// Sets the style of the group headers. Defaults to bold text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn header_style(mut self, style: Style) -> Self {
+        self.header_style = style;
+        self
+    }
+
+    /// Sets the style of the values of the given kind.
+    ///
+    /// By default numbers are cyan, booleans yellow and the placeholder of empty values is
+    /// dimmed.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn value_style(mut self, kind: ValueKind, style: Style) -> Self {
+        self.value_styles[kind as usize] = style;
+        self
+    }
+
+    /// Sets the separator between the key and value columns. Defaults to two spaces.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn separator<T>(mut self, separator: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the placeholder shown for empty values. Defaults to `-`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder<T>(mut self, placeholder: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Limits the width of the key column. Longer keys are truncated.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn max_key_width(mut self, width: u16) -> Self {
+        self.max_key_width = Some(width);
+        self
+    }
+}
+
+impl<'a> Styled for PropertyList<'a> {
+    type Item = PropertyList<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl<'a> Widget for PropertyList<'a> {
+    fn render(mut self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        if area.is_empty() {
+            return;
+        }
+
+        let separator_width = self.separator.width() as u16;
+        let key_width = self
+            .groups
+            .iter()
+            .flat_map(|group| &group.properties)
+            .map(|property| property.key.width() as u16)
+            .max()
+            .unwrap_or(0)
+            .min(self.max_key_width.unwrap_or(u16::MAX))
+            .min(area.width.saturating_sub(separator_width));
+        let value_x = area
+            .x
+            .saturating_add(key_width)
+            .saturating_add(separator_width)
+            .min(area.right());
+        let value_width = area.right().saturating_sub(value_x);
+
+        let mut y = area.y;
+        for group in self.groups {
+            if let Some(title) = &group.title {
+                if y >= area.bottom() {
+                    return;
+                }
+                buf.set_style(Rect::new(area.x, y, area.width, 1), self.header_style);
+                buf.set_line(area.x, y, title, area.width);
+                y += 1;
+            }
+            for property in group.properties {
+                if y >= area.bottom() {
+                    return;
+                }
+                buf.set_style(Rect::new(area.x, y, key_width, 1), self.key_style);
+                buf.set_line(area.x, y, &property.key, key_width);
+                buf.set_span(area.x + key_width, y, &self.separator, separator_width);
+
+                let value_style = self.value_styles[property.kind as usize];
+                let value = if property.kind == ValueKind::Empty {
+                    Text::from(Line::from(self.placeholder.clone()))
+                } else {
+                    property.value
+                };
+                let lines = value.lines.iter().map(|line| {
+                    let graphemes = line
+                        .spans
+                        .iter()
+                        .flat_map(move |span| span.styled_graphemes(value_style));
+                    (graphemes, line.alignment.unwrap_or_default())
+                });
+                let mut composer = WordWrapper::new(lines, value_width, true);
+                let start = y;
+                while let Some(WrappedLine { line, .. }) = composer.next_line() {
+                    if y >= area.bottom() {
+                        return;
+                    }
+                    let mut x = value_x;
+                    for StyledGrapheme { symbol, style } in line {
+                        let width = unicode_width::UnicodeWidthStr::width(*symbol) as u16;
+                        if width == 0 {
+                            continue;
+                        }
+                        buf.get_mut(x, y).set_symbol(symbol).set_style(*style);
+                        x += width;
+                    }
+                    y += 1;
+                }
+                // make sure empty values still take a line
+                y = y.max(start + 1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    fn render(list: PropertyList, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        list.render(buf.area, &mut buf);
+        buf
+    }
+
+    fn unstyled(list: PropertyList) -> PropertyList {
+        list.header_style(Style::new())
+            .value_style(ValueKind::Number, Style::new())
+            .value_style(ValueKind::Boolean, Style::new())
+            .value_style(ValueKind::Empty, Style::new())
+    }
+
+    #[test]
+    fn render_aligned_columns() {
+        let list = unstyled(PropertyList::new(vec![
+            Property::new("Name", "nginx"),
+            Property::number("Replicas", 3),
+            Property::boolean("Ready", true),
+            Property::new("Labels", ""),
+        ]));
+        assert_buffer_eq!(
+            render(list, 16, 4),
+            Buffer::with_lines(vec![
+                "Name      nginx ",
+                "Replicas  3     ",
+                "Ready     true  ",
+                "Labels    -     ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_wraps_values() {
+        let list = unstyled(PropertyList::new(vec![
+            Property::new("Desc", "a long description"),
+            Property::new("Id", "7"),
+        ]));
+        assert_buffer_eq!(
+            render(list, 12, 5),
+            Buffer::with_lines(vec![
+                "Desc  a long",
+                "      descri",
+                "      ption ",
+                "Id    7     ",
+                "            ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_groups() {
+        let list = unstyled(
+            PropertyList::new(vec![Property::new("Id", "1")])
+                .group("Status", vec![Property::boolean("Ok", false)])
+                .separator(": "),
+        );
+        assert_buffer_eq!(
+            render(list, 10, 4),
+            Buffer::with_lines(vec!["Id: 1     ", "Status    ", "Ok: false ", "          "])
+        );
+    }
+
+    #[test]
+    fn render_at_the_edge_of_the_buffer() {
+        let list = unstyled(PropertyList::new(vec![Property::new("Key", "value")]));
+        let area = Rect::new(u16::MAX - 1, 0, 1, 1);
+        let mut buf = Buffer::empty(area);
+        list.render(area, &mut buf);
+        let mut expected = Buffer::with_lines(vec![" "]);
+        expected.area = area;
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_max_key_width() {
+        let list = PropertyList::new(vec![Property::new("Very long key", "v")]).max_key_width(4);
+        assert_buffer_eq!(render(list, 8, 1), Buffer::with_lines(vec!["Very  v "]));
+    }
+
+    #[test]
+    fn render_value_styles() {
+        let list = PropertyList::new(vec![Property::number("n", 1)]).separator(" ");
+        let mut expected = Buffer::with_lines(vec!["n 1"]);
+        expected.set_style(Rect::new(2, 0, 1, 1), Style::new().fg(Color::Cyan));
+        assert_buffer_eq!(render(list, 3, 1), expected);
+    }
+}
@@ -41,6 +41,10 @@ where
     current_line: Vec<StyledGrapheme<'a>>,
     /// Removes the leading whitespace from lines
     trim: bool,
+    /// Repeats the leading whitespace of a line at the start of its wrapped lines
+    preserve_indent: bool,
+    /// Inserted at the start of the continuation lines, after the preserved indentation
+    prefix: Vec<StyledGrapheme<'a>>,
 }
 
 impl<'a, O, I> WordWrapper<'a, O, I>
@@ -56,8 +60,19 @@ where
             current_alignment: Alignment::Left,
             current_line: vec![],
             trim,
+            preserve_indent: false,
+            prefix: vec![],
         }
     }
+
+    /// Indents the wrapped lines: `preserve_indent` repeats the leading whitespace of each line
+    /// at the start of its wrapped lines, and `prefix` is inserted at the start of the
+    /// continuation lines, after the indentation.
+    pub fn with_indent(mut self, preserve_indent: bool, prefix: Vec<StyledGrapheme<'a>>) -> Self {
+        self.preserve_indent = preserve_indent;
+        self.prefix = prefix;
+        self
+    }
 }
 
 impl<'a, O, I> LineComposer<'a> for WordWrapper<'a, O, I>
@@ -101,24 +116,43 @@ where
                     let (mut unfinished_whitespaces, mut whitespace_width) =
                         (VecDeque::<StyledGrapheme>::new(), 0);
 
+                    let mut symbols = line_symbols.collect::<Vec<_>>();
+                    // The preserved indentation starts all the wrapped lines, and the prefix the
+                    // continuation lines, as long as some text fits after them
+                    let mut indent = vec![];
+                    if self.preserve_indent {
+                        let len = symbols
+                            .iter()
+                            .take_while(|grapheme| is_whitespace(grapheme.symbol))
+                            .count();
+                        if width_of(&symbols[..len]) < self.max_line_width {
+                            indent = symbols.drain(..len).collect();
+                        }
+                    }
+                    let mut max_line_width = self.max_line_width - width_of(&indent);
+                    let mut prefix = &self.prefix[..];
+                    if width_of(prefix) >= max_line_width {
+                        prefix = &[];
+                    }
+                    let continuation_width = max_line_width - width_of(prefix);
+
                     let mut has_seen_non_whitespace = false;
-                    for StyledGrapheme { symbol, style } in line_symbols {
-                        let symbol_whitespace =
-                            symbol.chars().all(&char::is_whitespace) && symbol != NBSP;
+                    for StyledGrapheme { symbol, style } in symbols {
+                        let symbol_whitespace = is_whitespace(symbol);
                         let symbol_width = symbol.width() as u16;
                         // Ignore characters wider than the total max width
-                        if symbol_width > self.max_line_width {
+                        if symbol_width > max_line_width {
                             continue;
                         }
 
                         // Append finished word to current line
                         if has_seen_non_whitespace && symbol_whitespace
                             // Append if trimmed (whitespaces removed) word would overflow
-                            || word_width + symbol_width > self.max_line_width && current_line.is_empty() && self.trim
+                            || word_width + symbol_width > max_line_width && current_line.is_empty() && self.trim
                             // Append if removed whitespace would overflow -> reset whitespace counting to prevent overflow
-                            || whitespace_width + symbol_width > self.max_line_width && current_line.is_empty() && self.trim
+                            || whitespace_width + symbol_width > max_line_width && current_line.is_empty() && self.trim
                             // Append if complete word would overflow
-                            || word_width + whitespace_width + symbol_width > self.max_line_width && current_line.is_empty() && !self.trim
+                            || word_width + whitespace_width + symbol_width > max_line_width && current_line.is_empty() && !self.trim
                         {
                             if !current_line.is_empty() || !self.trim {
                                 // Also append whitespaces if not trimming or current line is not
@@ -140,15 +174,15 @@ where
 
                         // Append the unfinished wrapped line to wrapped lines if it is as wide as
                         // max line width
-                        if current_line_width >= self.max_line_width
+                        if current_line_width >= max_line_width
                             // or if it would be too long with the current partially processed word added
-                            || current_line_width + whitespace_width + word_width >= self.max_line_width && symbol_width > 0
+                            || current_line_width + whitespace_width + word_width >= max_line_width && symbol_width > 0
                         {
                             let mut remaining_width =
-                                (self.max_line_width as i32 - current_line_width as i32).max(0)
-                                    as u16;
+                                (max_line_width as i32 - current_line_width as i32).max(0) as u16;
                             wrapped_lines.push(std::mem::take(&mut current_line));
                             current_line_width = 0;
+                            max_line_width = continuation_width;
 
                             // Remove all whitespaces till end of just appended wrapped line + next
                             // whitespace
@@ -198,6 +232,12 @@ where
                         // Append empty line if there was nothing to wrap in the first place
                         wrapped_lines.push(vec![]);
                     }
+                    if !indent.is_empty() || !prefix.is_empty() {
+                        for (i, line) in wrapped_lines.iter_mut().enumerate() {
+                            let prefix = if i == 0 { &[][..] } else { prefix };
+                            line.splice(0..0, indent.iter().chain(prefix).cloned());
+                        }
+                    }
 
                     self.wrapped_lines = Some(wrapped_lines.into_iter());
                 } else {
@@ -220,6 +260,17 @@ where
     }
 }
 
+fn is_whitespace(symbol: &str) -> bool {
+    symbol.chars().all(char::is_whitespace) && symbol != NBSP
+}
+
+fn width_of(graphemes: &[StyledGrapheme]) -> u16 {
+    graphemes
+        .iter()
+        .map(|grapheme| grapheme.symbol.width())
+        .sum::<usize>() as u16
+}
+
 /// A state machine that truncates overhanging lines.
 #[derive(Debug, Default, Clone)]
 pub struct LineTruncator<'a, O, I>
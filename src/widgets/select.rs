@@ -0,0 +1,776 @@
+#![deny(missing_docs)]
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    buffer::Buffer,
+    input::{Focusable, Key, KeyEvent, KeyEventKind, KeyModifiers},
+    layout::Rect,
+    style::{Modifier, Style, Styled},
+    terminal::RedrawRequest,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, StatefulWidget, Widget},
+};
+
+const DEFAULT_FOCUSED_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_DISABLED_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+
+/// An option of a [`Select`], which can be disabled.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SelectOption<'a> {
+    label: Line<'a>,
+    disabled: bool,
+}
+
+impl<'a> SelectOption<'a> {
+    /// Creates a new option with the given label.
+    pub fn new<T>(label: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        Self {
+            label: label.into(),
+            disabled: false,
+        }
+    }
+
+    /// Sets whether the option is disabled. A disabled option is shown with the
+    /// [`disabled_style`](Select::disabled_style) and can't be selected from the keyboard.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl<'a> From<&'a str> for SelectOption<'a> {
+    fn from(label: &'a str) -> Self {
+        Self::new(label)
+    }
+}
+
+impl<'a> From<String> for SelectOption<'a> {
+    fn from(label: String) -> Self {
+        Self::new(label)
+    }
+}
+
+impl<'a> From<Span<'a>> for SelectOption<'a> {
+    fn from(label: Span<'a>) -> Self {
+        Self::new(label)
+    }
+}
+
+impl<'a> From<Line<'a>> for SelectOption<'a> {
+    fn from(label: Line<'a>) -> Self {
+        Self::new(label)
+    }
+}
+
+/// A dropdown showing the selected option inline, e.g. `Medium ▾`.
+///
+/// The first row of the area shows the selected option, or the
+/// [`placeholder`](Select::placeholder) when no option is selected. While the dropdown is open,
+/// the options matching the typed text are listed in the rows below, so the select is usually
+/// rendered last, over the widgets below it.
+///
+/// The selected and highlighted options, the open dropdown and the typed text are stored in a
+/// [`SelectState`], which handles the keyboard input of a focused select:
+/// - `Enter`, `Space` and `Down` open the dropdown. Typing a character opens it and starts
+///   searching.
+/// - In the open dropdown, typing filters the options containing the text, ignoring case, and
+///   `Backspace` removes the last typed character. `Up` and `Down` highlight the previous and
+///   next enabled options, `Enter` selects the highlighted option and `Esc` closes the dropdown
+///   without changing the selection.
+///
+/// A [`read_only`](Select::read_only) select shows its selection and gets the focus, but doesn't
+/// open its dropdown. A [`disabled`](Select::disabled) select also ignores the focus and is
+/// restyled with the [`disabled_style`](Select::disabled_style).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{input::Key, prelude::*, widgets::*};
+///
+/// let select = Select::new(vec![
+///     SelectOption::new("Small"),
+///     SelectOption::new("Medium").disabled(true),
+///     SelectOption::new("Large"),
+/// ]);
+/// let mut state = SelectState::default().with_focused(true);
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 20, 6));
+/// StatefulWidget::render(select, buf.area, &mut buf, &mut state);
+///
+/// state.handle_key(Key::Char('g').into());
+/// state.handle_key(Key::Enter.into());
+/// assert_eq!(state.selected(), Some(2));
+/// assert!(state.take_changed());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Select<'a> {
+    options: Vec<SelectOption<'a>>,
+    placeholder: Line<'a>,
+    symbol: &'a str,
+    dropdown_block: Option<Block<'a>>,
+    style: Style,
+    focused_style: Style,
+    highlight_style: Style,
+    disabled_style: Style,
+    max_height: u16,
+    read_only: bool,
+    disabled: bool,
+}
+
+impl<'a> Select<'a> {
+    /// Creates a new select with the given options.
+    pub fn new<T>(options: Vec<T>) -> Self
+    where
+        T: Into<SelectOption<'a>>,
+    {
+        Self {
+            options: options.into_iter().map(Into::into).collect(),
+            placeholder: Line::default(),
+            symbol: "▾",
+            dropdown_block: Some(Block::default().borders(Borders::ALL)),
+            style: Style::default(),
+            focused_style: DEFAULT_FOCUSED_STYLE,
+            highlight_style: DEFAULT_HIGHLIGHT_STYLE,
+            disabled_style: DEFAULT_DISABLED_STYLE,
+            max_height: 5,
+            read_only: false,
+            disabled: false,
+        }
+    }
+
+    /// Sets the text shown when no option is selected.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder<T>(mut self, placeholder: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the symbol shown at the end of the first row. Defaults to `▾`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn symbol(mut self, symbol: &'a str) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Surrounds the open dropdown with a [`Block`]. Defaults to a block with all borders.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn dropdown_block(mut self, block: Block<'a>) -> Self {
+        self.dropdown_block = Some(block);
+        self
+    }
+
+    /// Sets the style of the select.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style patched on top of the style of the first row while the select has the
+    /// focus. Defaults to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_style(mut self, style: Style) -> Self {
+        self.focused_style = style;
+        self
+    }
+
+    /// Sets the style of the highlighted option in the dropdown. Defaults to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// Sets the style of the disabled options, also patched over the first row while the select
+    /// is [`disabled`](Self::disabled). Defaults to dim text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = style;
+        self
+    }
+
+    /// Makes the select read-only: it still gets the focus, but the keys don't open its dropdown
+    /// and its selection doesn't change.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Disables the select: it ignores the keys, isn't drawn focused and is restyled with the
+    /// [`disabled_style`](Self::disabled_style).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the maximum number of options shown at once in the dropdown. Defaults to 5.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn max_height(mut self, height: u16) -> Self {
+        self.max_height = height.max(1);
+        self
+    }
+}
+
+impl<'a> Styled for Select<'a> {
+    type Item = Select<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// The searchable text of an option, known once the select is rendered.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+struct OptionText {
+    text: String,
+    disabled: bool,
+}
+
+/// The state of a [`Select`].
+///
+/// It holds the index of the selected option, whether the dropdown is open, the typed text and
+/// the highlighted option in the dropdown. The options are known once the select is first
+/// rendered.
+///
+/// Instead of a callback, the state records that the selection changed: the application checks
+/// [`take_changed`](SelectState::take_changed) after handling the input to react to a new
+/// selection.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct SelectState {
+    selected: Option<usize>,
+    highlighted: Option<usize>,
+    open: bool,
+    query: String,
+    focused: bool,
+    /// Whether the dropdown doesn't open, set when rendering
+    read_only: bool,
+    /// Whether all the keys are ignored, set when rendering
+    disabled: bool,
+    changed: bool,
+    /// Index of the first visible match in the dropdown
+    offset: usize,
+    options: Vec<OptionText>,
+}
+
+impl SelectState {
+    /// Selects the option at the given index, without marking the selection as changed.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets whether the select has the focus.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Returns the index of the selected option.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Selects the option at the given index, or no option with `None`.
+    pub fn select(&mut self, index: Option<usize>) {
+        if self.selected != index {
+            self.selected = index;
+            self.changed = true;
+            RedrawRequest::request();
+        }
+    }
+
+    /// Returns `true` if the selection changed since the last call, and resets the flag.
+    pub fn take_changed(&mut self) -> bool {
+        std::mem::take(&mut self.changed)
+    }
+
+    /// Returns the index of the highlighted option in the open dropdown.
+    pub fn highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    /// Returns the text typed to filter the options.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Returns the indices of the options containing the typed text.
+    pub fn matches(&self) -> impl Iterator<Item = usize> + '_ {
+        let query = self.query.to_lowercase();
+        self.options
+            .iter()
+            .enumerate()
+            .filter(move |(_, option)| option.text.to_lowercase().contains(&query))
+            .map(|(i, _)| i)
+    }
+
+    /// Returns `true` if the dropdown is open.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Opens the dropdown, highlighting the selected option.
+    pub fn open(&mut self) {
+        RedrawRequest::request_if(!self.open);
+        self.open = true;
+        self.query.clear();
+        self.offset = 0;
+        self.highlighted = self.selected.filter(|&i| self.is_enabled(i));
+        if self.highlighted.is_none() {
+            let first = self.enabled_matches().next();
+            self.highlighted = first;
+        }
+    }
+
+    /// Closes the dropdown and clears the typed text.
+    pub fn close(&mut self) {
+        RedrawRequest::request_if(self.open);
+        self.open = false;
+        self.query.clear();
+        self.highlighted = None;
+        self.offset = 0;
+    }
+
+    /// Returns `true` if the select has the focus.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Sets whether the select has the focus. Losing the focus closes the dropdown.
+    pub fn set_focused(&mut self, focused: bool) {
+        RedrawRequest::request_if(self.focused != focused);
+        self.focused = focused;
+        if !focused {
+            self.close();
+        }
+    }
+
+    /// Returns `true` if the select was [read-only](Select::read_only) during the last render.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns `true` if the select was [disabled](Select::disabled) during the last render.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Handles a key event and returns `true` if it was handled.
+    ///
+    /// See [`Select`] for the keys handled by a focused select. All the keys are ignored while
+    /// the select is read-only or disabled.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if !self.focused || self.read_only || self.disabled || key.kind == KeyEventKind::Release {
+            return false;
+        }
+        let typed = match key.key {
+            Key::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                Some(c)
+            }
+            _ => None,
+        };
+        if !self.open {
+            return match (key.key, typed) {
+                (Key::Enter | Key::Down | Key::Char(' '), _) => {
+                    self.open();
+                    true
+                }
+                (_, Some(c)) => {
+                    self.open();
+                    self.search(|query| query.push(c));
+                    true
+                }
+                _ => false,
+            };
+        }
+        match (key.key, typed) {
+            (Key::Up, _) => self.highlight(|matches, i| matches.iter().rposition(|&m| m < i)),
+            (Key::Down, _) => self.highlight(|matches, i| matches.iter().position(|&m| m > i)),
+            (Key::Enter, _) => {
+                if let Some(highlighted) = self.highlighted {
+                    self.select(Some(highlighted));
+                }
+                self.close();
+            }
+            (Key::Esc, _) => self.close(),
+            (Key::Backspace, _) => self.search(|query| {
+                query.pop();
+            }),
+            (_, Some(c)) => self.search(|query| query.push(c)),
+            _ => return false,
+        }
+        true
+    }
+
+    fn is_enabled(&self, index: usize) -> bool {
+        self.options
+            .get(index)
+            .is_some_and(|option| !option.disabled)
+    }
+
+    fn enabled_matches(&self) -> impl Iterator<Item = usize> + '_ {
+        self.matches().filter(|&i| self.is_enabled(i))
+    }
+
+    /// Highlights the enabled match found by `find` from the highlighted option.
+    fn highlight(&mut self, find: impl Fn(&[usize], usize) -> Option<usize>) {
+        let matches: Vec<usize> = self.enabled_matches().collect();
+        let next = match self.highlighted {
+            Some(highlighted) => find(&matches, highlighted).map(|i| matches[i]),
+            None => matches.first().copied(),
+        };
+        if let Some(next) = next {
+            RedrawRequest::request_if(self.highlighted != Some(next));
+            self.highlighted = Some(next);
+        }
+    }
+
+    /// Edits the typed text and highlights the first enabled match if the highlighted option
+    /// doesn't match anymore.
+    fn search(&mut self, edit: impl FnOnce(&mut String)) {
+        edit(&mut self.query);
+        self.offset = 0;
+        let still_matches = self
+            .highlighted
+            .is_some_and(|highlighted| self.enabled_matches().any(|i| i == highlighted));
+        if !still_matches {
+            let first = self.enabled_matches().next();
+            self.highlighted = first;
+        }
+        RedrawRequest::request();
+    }
+
+    /// Updates the options with the ones of the rendered select.
+    fn sync(&mut self, options: &[SelectOption]) {
+        self.options = options
+            .iter()
+            .map(|option| OptionText {
+                text: option
+                    .label
+                    .spans
+                    .iter()
+                    .map(|s| s.content.as_ref())
+                    .collect(),
+                disabled: option.disabled,
+            })
+            .collect();
+        self.selected = self.selected.filter(|&i| i < options.len());
+        self.highlighted = self.highlighted.filter(|&i| i < options.len());
+    }
+}
+
+impl Focusable for SelectState {
+    fn set_focused(&mut self, focused: bool) {
+        SelectState::set_focused(self, focused);
+    }
+
+    fn is_disabled(&self) -> bool {
+        SelectState::is_disabled(self)
+    }
+}
+
+impl<'a> StatefulWidget for Select<'a> {
+    type State = SelectState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if area.is_empty() {
+            return;
+        }
+        state.sync(&self.options);
+        state.read_only = self.read_only;
+        state.disabled = self.disabled;
+        if self.read_only || self.disabled {
+            state.close();
+        }
+
+        let field = Rect { height: 1, ..area };
+        let field_style = if self.disabled {
+            self.style.patch(self.disabled_style)
+        } else if state.focused {
+            self.style.patch(self.focused_style)
+        } else {
+            self.style
+        };
+        buf.set_style(field, field_style);
+        let symbol_width = (self.symbol.width() as u16).min(field.width);
+        let text_width = field.width - symbol_width;
+        if state.open && !state.query.is_empty() {
+            buf.set_stringn(
+                field.x,
+                field.y,
+                &state.query,
+                text_width.into(),
+                Style::default(),
+            );
+        } else if let Some(option) = state.selected.and_then(|i| self.options.get(i)) {
+            buf.set_line(field.x, field.y, &option.label, text_width);
+        } else {
+            buf.set_line(field.x, field.y, &self.placeholder, text_width);
+        }
+        buf.set_string(
+            field.right() - symbol_width,
+            field.y,
+            self.symbol,
+            field_style,
+        );
+
+        if !state.open || area.height < 2 {
+            return;
+        }
+        // measure the space taken by the borders and titles of the block
+        let probe = Rect::new(0, 0, 100, 100);
+        let inner_probe = self
+            .dropdown_block
+            .as_ref()
+            .map_or(probe, |b| b.inner(probe));
+        let frame_height = probe.height - inner_probe.height;
+        let matches: Vec<usize> = state.matches().collect();
+        let rows = (matches.len() as u16).min(self.max_height);
+        let dropdown = Rect::new(
+            area.x,
+            area.y + 1,
+            area.width,
+            (rows + frame_height).min(area.height - 1),
+        );
+        Clear.render(dropdown, buf);
+        buf.set_style(dropdown, self.style);
+        let inner = match self.dropdown_block {
+            Some(block) => {
+                let inner = block.inner(dropdown);
+                block.render(dropdown, buf);
+                inner
+            }
+            None => dropdown,
+        };
+        if inner.is_empty() {
+            return;
+        }
+        let visible = usize::from(inner.height);
+        if let Some(position) = state
+            .highlighted
+            .and_then(|highlighted| matches.iter().position(|&i| i == highlighted))
+        {
+            state.offset = state
+                .offset
+                .min(position)
+                .max((position + 1).saturating_sub(visible));
+        }
+        for (row, &i) in matches.iter().skip(state.offset).take(visible).enumerate() {
+            let line = Rect::new(inner.x, inner.y + row as u16, inner.width, 1);
+            let option = &self.options[i];
+            if option.disabled {
+                buf.set_style(line, self.disabled_style);
+            }
+            if state.highlighted == Some(i) {
+                buf.set_style(line, self.highlight_style);
+            }
+            buf.set_line(line.x, line.y, &option.label, line.width);
+        }
+    }
+}
+
+impl<'a> Widget for Select<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = SelectState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    fn select() -> Select<'static> {
+        Select::new(vec![
+            SelectOption::new("Apple"),
+            SelectOption::new("Banana").disabled(true),
+            SelectOption::new("Cherry"),
+            SelectOption::new("Apricot"),
+        ])
+        .placeholder("Fruit")
+        .focused_style(Style::default())
+        .highlight_style(Style::default())
+        .disabled_style(Style::default())
+    }
+
+    fn rendered(state: &mut SelectState) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 7));
+        StatefulWidget::render(select(), buf.area, &mut buf, state);
+        buf
+    }
+
+    fn typed(state: &mut SelectState, text: &str) {
+        for c in text.chars() {
+            state.handle_key(Key::Char(c).into());
+        }
+    }
+
+    #[test]
+    fn render_closed() {
+        let mut state = SelectState::default();
+        assert_buffer_eq!(
+            rendered(&mut state),
+            Buffer::with_lines(vec![
+                "Fruit    ▾",
+                "          ",
+                "          ",
+                "          ",
+                "          ",
+                "          ",
+                "          ",
+            ])
+        );
+        let mut state = SelectState::default().with_selected(Some(2));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        StatefulWidget::render(select(), buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["Cherry   ▾"]));
+    }
+
+    #[test]
+    fn render_open() {
+        let mut state = SelectState::default().with_focused(true);
+        rendered(&mut state);
+        assert!(state.handle_key(Key::Enter.into()));
+        assert_eq!(state.highlighted(), Some(0));
+        assert_buffer_eq!(
+            rendered(&mut state),
+            Buffer::with_lines(vec![
+                "Fruit    ▾",
+                "┌────────┐",
+                "│Apple   │",
+                "│Banana  │",
+                "│Cherry  │",
+                "│Apricot │",
+                "└────────┘",
+            ])
+        );
+    }
+
+    #[test]
+    fn typeahead_filters_options() {
+        let mut state = SelectState::default().with_focused(true);
+        rendered(&mut state);
+        typed(&mut state, "AP");
+        assert!(state.is_open());
+        assert_eq!(state.query(), "AP");
+        assert_eq!(state.matches().collect::<Vec<_>>(), [0, 3]);
+        assert_buffer_eq!(
+            rendered(&mut state),
+            Buffer::with_lines(vec![
+                "AP       ▾",
+                "┌────────┐",
+                "│Apple   │",
+                "│Apricot │",
+                "└────────┘",
+                "          ",
+                "          ",
+            ])
+        );
+        typed(&mut state, "r");
+        assert_eq!(state.highlighted(), Some(3));
+        state.handle_key(Key::Backspace.into());
+        assert_eq!(state.matches().count(), 2);
+        assert!(state.handle_key(Key::Enter.into()));
+        assert_eq!(state.selected(), Some(3));
+        assert!(!state.is_open());
+        assert!(state.take_changed());
+        assert!(!state.take_changed());
+    }
+
+    #[test]
+    fn navigation_skips_disabled_options() {
+        let mut state = SelectState::default().with_focused(true);
+        rendered(&mut state);
+        state.handle_key(Key::Down.into());
+        assert_eq!(state.highlighted(), Some(0));
+        state.handle_key(Key::Down.into());
+        assert_eq!(state.highlighted(), Some(2));
+        state.handle_key(Key::Up.into());
+        assert_eq!(state.highlighted(), Some(0));
+        state.handle_key(Key::Up.into());
+        assert_eq!(state.highlighted(), Some(0));
+
+        typed(&mut state, "ban");
+        assert_eq!(state.highlighted(), None);
+        state.handle_key(Key::Enter.into());
+        assert_eq!(state.selected(), None);
+        assert!(!state.take_changed());
+    }
+
+    #[test]
+    fn escape_keeps_selection() {
+        let mut state = SelectState::default()
+            .with_selected(Some(0))
+            .with_focused(true);
+        rendered(&mut state);
+        state.handle_key(Key::Char(' ').into());
+        state.handle_key(Key::Down.into());
+        assert_eq!(state.highlighted(), Some(2));
+        assert!(state.handle_key(Key::Esc.into()));
+        assert!(!state.is_open());
+        assert_eq!(state.selected(), Some(0));
+        assert!(!state.take_changed());
+    }
+
+    #[test]
+    fn read_only_and_disabled() {
+        let mut state = SelectState::default()
+            .with_selected(Some(0))
+            .with_focused(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+        StatefulWidget::render(select().read_only(true), buf.area, &mut buf, &mut state);
+        assert!(state.is_read_only() && !state.is_disabled());
+        assert!(!state.handle_key(Key::Enter.into()));
+        assert!(!state.handle_key(Key::Char('c').into()));
+        assert!(!state.is_open());
+
+        let select = select()
+            .disabled(true)
+            .disabled_style(Style::new().add_modifier(Modifier::DIM));
+        StatefulWidget::render(select, buf.area, &mut buf, &mut state);
+        assert!(state.is_disabled() && Focusable::is_disabled(&state));
+        assert!(!state.handle_key(Key::Enter.into()));
+        let mut expected = Buffer::with_lines(vec!["Apple    ▾", "          ", "          "]);
+        expected.set_style(
+            Rect::new(0, 0, 10, 1),
+            Style::new().add_modifier(Modifier::DIM),
+        );
+        assert_buffer_eq!(buf, expected);
+        assert_eq!(state.selected(), Some(0));
+    }
+
+    #[test]
+    fn unfocused_ignores_keys() {
+        let mut state = SelectState::default();
+        rendered(&mut state);
+        assert!(!state.handle_key(Key::Enter.into()));
+        state.set_focused(true);
+        state.open();
+        state.set_focused(false);
+        assert!(!state.is_open());
+    }
+}
@@ -0,0 +1,269 @@
+#![deny(missing_docs)]
+use crate::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::{Modifier, Style, Styled},
+    text::grapheme_width,
+    widgets::StatefulWidget,
+};
+
+const DEFAULT_SELECTION_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+
+/// A rectangular selection of cells, as in the visual block mode of Vim or the rectangular
+/// selection of terminal emulators.
+///
+/// The selection spans from its anchor, where it started, to its head, which follows the mouse or
+/// the cursor. Both are in buffer coordinates, i.e. relative to the top left corner of the
+/// terminal, and both corners are part of the selection. The selection is highlighted by
+/// rendering a [`SelectionOverlay`] on top of the widgets, and the text of the selected cells is
+/// copied from the buffer with [`SelectionRect::text`], so that any widget supports rectangular
+/// copy without knowing about the selection.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     input::{MouseButton, MouseEvent, MouseEventKind},
+///     layout::Position,
+///     prelude::*,
+///     widgets::*,
+/// };
+///
+/// fn handle_mouse(selection: &mut Option<SelectionRect>, mouse: MouseEvent) {
+///     match mouse.kind {
+///         MouseEventKind::Down(MouseButton::Left) => {
+///             *selection = Some(SelectionRect::new(mouse.position()));
+///         }
+///         MouseEventKind::Drag(MouseButton::Left) => {
+///             if let Some(selection) = selection {
+///                 selection.set_head(mouse.position());
+///             }
+///         }
+///         _ => {}
+///     }
+/// }
+///
+/// # fn ui(frame: &mut Frame, selection: &mut SelectionRect) {
+/// frame.render_widget(Paragraph::new("Hello world!"), frame.size());
+/// frame.render_stateful_widget(SelectionOverlay::new(), frame.size(), selection);
+/// # }
+/// let buf = Buffer::with_lines(vec!["Hello", "world"]);
+/// let mut selection = SelectionRect::new(Position::new(1, 0));
+/// selection.set_head(Position::new(3, 1));
+/// assert_eq!(selection.text(&buf), "ell\norl");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SelectionRect {
+    anchor: Position,
+    head: Position,
+}
+
+impl SelectionRect {
+    /// Creates a selection of the single cell at the given position.
+    pub fn new(anchor: Position) -> Self {
+        Self {
+            anchor,
+            head: anchor,
+        }
+    }
+
+    /// Sets the head of the selection.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_head(mut self, head: Position) -> Self {
+        self.head = head;
+        self
+    }
+
+    /// Returns the corner where the selection started.
+    pub fn anchor(&self) -> Position {
+        self.anchor
+    }
+
+    /// Returns the corner that follows the mouse or the cursor.
+    pub fn head(&self) -> Position {
+        self.head
+    }
+
+    /// Moves the head of the selection, keeping its anchor.
+    pub fn set_head(&mut self, head: Position) {
+        self.head = head;
+    }
+
+    /// Moves the head of the selection by the given number of columns and rows, keeping it inside
+    /// `bounds`, e.g. in response to the arrow keys.
+    pub fn move_head(&mut self, columns: i32, rows: i32, bounds: Rect) {
+        if bounds.is_empty() {
+            return;
+        }
+        let offset = |position: u16, offset: i32, start: u16, end: u16| {
+            (i32::from(position) + offset).clamp(i32::from(start), i32::from(end) - 1) as u16
+        };
+        self.head = Position::new(
+            offset(self.head.x, columns, bounds.left(), bounds.right()),
+            offset(self.head.y, rows, bounds.top(), bounds.bottom()),
+        );
+    }
+
+    /// Swaps the anchor and the head of the selection, e.g. to extend it from the other corner.
+    pub fn swap(&mut self) {
+        std::mem::swap(&mut self.anchor, &mut self.head);
+    }
+
+    /// Returns the selected area, whose opposite corners are the anchor and the head.
+    pub fn area(&self) -> Rect {
+        let x = self.anchor.x.min(self.head.x);
+        let y = self.anchor.y.min(self.head.y);
+        let width = self.anchor.x.abs_diff(self.head.x).saturating_add(1);
+        let height = self.anchor.y.abs_diff(self.head.y).saturating_add(1);
+        Rect::new(x, y, width, height)
+    }
+
+    /// Returns `true` if the cell at the given position is selected.
+    pub fn contains(&self, position: Position) -> bool {
+        self.area().contains(position)
+    }
+
+    /// Returns the text of the selected cells of the buffer, one line per row of the selection.
+    ///
+    /// As when copying from a terminal, the trailing whitespace of each line is removed. A wide
+    /// character is part of the text if its first cell is selected. The part of the selection
+    /// outside of the buffer is ignored.
+    pub fn text(&self, buf: &Buffer) -> String {
+        let area = self.area().intersection(buf.area);
+        let mut lines = Vec::with_capacity(usize::from(area.height));
+        for y in area.top()..area.bottom() {
+            let mut line = String::new();
+            // the cells hidden by a wide character are skipped, starting from the left of the
+            // buffer to skip the ones hidden by a character that starts before the selection
+            let mut hidden_until = buf.area.left();
+            for x in buf.area.left()..area.right() {
+                if x < hidden_until {
+                    continue;
+                }
+                let symbol = buf.get(x, y).symbol();
+                hidden_until = x.saturating_add(grapheme_width(symbol).max(1) as u16);
+                if x >= area.left() {
+                    line.push_str(symbol);
+                }
+            }
+            lines.push(line.trim_end().to_string());
+        }
+        lines.join("\n")
+    }
+}
+
+/// Highlights a [`SelectionRect`] by patching the style of the selected cells.
+///
+/// The overlay is rendered on top of the widgets that show the selected content, and only
+/// highlights the part of the selection inside the render area.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SelectionOverlay {
+    style: Style,
+}
+
+impl Default for SelectionOverlay {
+    fn default() -> Self {
+        Self {
+            style: DEFAULT_SELECTION_STYLE,
+        }
+    }
+}
+
+impl SelectionOverlay {
+    /// Creates a new overlay highlighting the selection with reversed colors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the style patched on top of the selected cells. Defaults to reversed colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl Styled for SelectionOverlay {
+    type Item = SelectionOverlay;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl StatefulWidget for SelectionOverlay {
+    type State = SelectionRect;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = state.area().intersection(area).intersection(buf.area);
+        buf.set_style(area, self.style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    #[test]
+    fn area() {
+        let selection = SelectionRect::new(Position::new(5, 1)).with_head(Position::new(2, 3));
+        assert_eq!(selection.area(), Rect::new(2, 1, 4, 3));
+        assert!(selection.contains(Position::new(5, 3)));
+        assert!(!selection.contains(Position::new(6, 3)));
+        assert_eq!(
+            SelectionRect::new(Position::new(4, 4)).area(),
+            Rect::new(4, 4, 1, 1)
+        );
+    }
+
+    #[test]
+    fn move_head() {
+        let bounds = Rect::new(1, 1, 5, 5);
+        let mut selection = SelectionRect::new(Position::new(2, 2));
+        selection.move_head(2, 1, bounds);
+        assert_eq!(selection.head(), Position::new(4, 3));
+        selection.move_head(10, -10, bounds);
+        assert_eq!(selection.head(), Position::new(5, 1));
+        assert_eq!(selection.anchor(), Position::new(2, 2));
+
+        selection.swap();
+        assert_eq!(selection.anchor(), Position::new(5, 1));
+        assert_eq!(selection.head(), Position::new(2, 2));
+    }
+
+    #[test]
+    fn render_overlay() {
+        let mut buf = Buffer::with_lines(vec!["abcd", "efgh", "ijkl"]);
+        let mut selection = SelectionRect::new(Position::new(1, 0)).with_head(Position::new(3, 2));
+        // the part of the selection outside of the render area is not highlighted
+        SelectionOverlay::new().render(Rect::new(0, 0, 3, 2), &mut buf, &mut selection);
+        let mut expected = Buffer::with_lines(vec!["abcd", "efgh", "ijkl"]);
+        expected.set_style(Rect::new(1, 0, 2, 2), DEFAULT_SELECTION_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn text() {
+        let buf = Buffer::with_lines(vec!["one   two", "three four", "six"]);
+        let selection = SelectionRect::new(Position::new(2, 0)).with_head(Position::new(6, 5));
+        assert_eq!(selection.text(&buf), "e   t\nree f\nx");
+    }
+
+    #[test]
+    fn text_with_wide_characters() {
+        let buf = Buffer::with_lines(vec!["a称b号c", "abcdefg"]);
+        // the first selected cell is hidden by a wide character
+        let selection = SelectionRect::new(Position::new(2, 0)).with_head(Position::new(4, 1));
+        assert_eq!(selection.text(&buf), "b号\ncde");
+        // the last selected cell starts a wide character
+        let selection = SelectionRect::new(Position::new(0, 0)).with_head(Position::new(1, 0));
+        assert_eq!(selection.text(&buf), "a称");
+    }
+}
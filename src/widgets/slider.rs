@@ -0,0 +1,549 @@
+#![deny(missing_docs)]
+use crate::{
+    buffer::Buffer,
+    input::{Focusable, Key, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind},
+    layout::Rect,
+    style::{Modifier, Style, Styled},
+    terminal::RedrawRequest,
+    text::Span,
+    widgets::{Block, Gauge, StatefulWidget, Widget},
+};
+
+const DEFAULT_FOCUSED_STYLE: Style = Style::new().add_modifier(Modifier::BOLD);
+const DEFAULT_DISABLED_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+
+/// An input adjusting a bounded value, drawn as a [`Gauge`] with a thumb marker.
+///
+/// The track is a gauge filled up to the value, with the value as its centered label unless
+/// another [`label`](Slider::label) is given. The [`thumb`](Slider::thumb) is drawn at the
+/// position of the value, with the [`focused_style`](Slider::focused_style) patched on top of
+/// its style while the slider has the focus.
+///
+/// The value, its range and its step are stored in a [`SliderState`], which handles the input
+/// of the slider:
+/// - `Left` and `Down` decrease the value of a focused slider by a step, `Right` and `Up`
+///   increase it, `Home` and `End` set it to the minimum and the maximum.
+/// - Pressing the left mouse button on the track moves the thumb under the mouse, and dragging
+///   moves it along.
+///
+/// A [`read_only`](Slider::read_only) slider gets the focus but its value doesn't change with the
+/// keys and the mouse. A [`disabled`](Slider::disabled) slider also isn't drawn focused, and is
+/// restyled with the [`disabled_style`](Slider::disabled_style).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{input::Key, prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut SliderState) {
+/// let slider = Slider::default().track_style(Style::new().blue().on_black());
+/// frame.render_stateful_widget(slider, Rect::new(0, 0, 20, 1), state);
+/// # }
+/// let mut state = SliderState::new(0.0, 10.0)
+///     .with_step(0.5)
+///     .with_focused(true);
+/// assert!(state.handle_key(Key::Right.into()));
+/// assert_eq!(state.value(), 0.5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Slider<'a> {
+    block: Option<Block<'a>>,
+    label: Option<Span<'a>>,
+    thumb: &'a str,
+    use_unicode: bool,
+    style: Style,
+    track_style: Style,
+    thumb_style: Style,
+    focused_style: Style,
+    read_only: bool,
+    disabled: bool,
+    disabled_style: Style,
+}
+
+impl<'a> Default for Slider<'a> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            label: None,
+            thumb: "┃",
+            use_unicode: false,
+            style: Style::default(),
+            track_style: Style::default(),
+            thumb_style: Style::default(),
+            focused_style: DEFAULT_FOCUSED_STYLE,
+            read_only: false,
+            disabled: false,
+            disabled_style: DEFAULT_DISABLED_STYLE,
+        }
+    }
+}
+
+impl<'a> Slider<'a> {
+    /// Surrounds the slider with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the label displayed in the center of the track. Defaults to the value.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label<T>(mut self, label: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the symbol of the thumb marking the value. Defaults to `┃`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn thumb(mut self, thumb: &'a str) -> Self {
+        self.thumb = thumb;
+        self
+    }
+
+    /// Sets whether to fill the track with unicode block characters, see
+    /// [`Gauge::use_unicode`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn use_unicode(mut self, unicode: bool) -> Self {
+        self.use_unicode = unicode;
+        self
+    }
+
+    /// Sets the style of the slider, see [`Gauge::style`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the track, see [`Gauge::gauge_style`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn track_style(mut self, style: Style) -> Self {
+        self.track_style = style;
+        self
+    }
+
+    /// Sets the style of the thumb.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn thumb_style(mut self, style: Style) -> Self {
+        self.thumb_style = style;
+        self
+    }
+
+    /// Sets the style patched on top of the style of the thumb while the slider has the focus.
+    /// Defaults to bold text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_style(mut self, style: Style) -> Self {
+        self.focused_style = style;
+        self
+    }
+
+    /// Makes the slider read-only: it still gets the focus, but the keys and the mouse don't
+    /// change its value.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Disables the slider: it ignores the keys and the mouse, isn't drawn focused and is
+    /// restyled with the [`disabled_style`](Self::disabled_style).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the style patched over the whole slider while it is [`disabled`](Self::disabled).
+    /// Defaults to dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = style;
+        self
+    }
+}
+
+impl<'a> Styled for Slider<'a> {
+    type Item = Slider<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// The state of a [`Slider`].
+///
+/// It holds the value, which is kept in the range and on the steps from the minimum, and whether
+/// the slider has the focus or is dragged by the mouse. It also remembers where the track was
+/// last rendered, which is used to map mouse events to values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SliderState {
+    value: f64,
+    min: f64,
+    max: f64,
+    step: f64,
+    focused: bool,
+    /// Whether the input events don't change the value, set when rendering
+    read_only: bool,
+    /// Whether the slider ignores the focus too, set when rendering
+    disabled: bool,
+    dragging: bool,
+    track: Rect,
+}
+
+impl Default for SliderState {
+    /// A slider from 0 to 100 by steps of 1.
+    fn default() -> Self {
+        Self::new(0.0, 100.0)
+    }
+}
+
+impl SliderState {
+    /// Creates a new state for a value between `min` and `max` by steps of 1. The value starts at
+    /// the minimum.
+    ///
+    /// The bounds are swapped if `max` is smaller than `min`.
+    pub fn new(min: f64, max: f64) -> Self {
+        Self {
+            value: min.min(max),
+            min: min.min(max),
+            max: max.max(min),
+            step: 1.0,
+            focused: false,
+            read_only: false,
+            disabled: false,
+            dragging: false,
+            track: Rect::default(),
+        }
+    }
+
+    /// Sets the value, see [`set_value`](Self::set_value).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_value(mut self, value: f64) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    /// Sets the step by which the value changes. Non-positive steps make the value continuous.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_step(mut self, step: f64) -> Self {
+        self.step = step.max(0.0);
+        self.value = self.snap(self.value);
+        self
+    }
+
+    /// Sets whether the slider has the focus.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Sets the value, rounded to the nearest step and clamped to the range. Returns `true` if
+    /// the value changed.
+    pub fn set_value(&mut self, value: f64) -> bool {
+        let value = self.snap(value);
+        let changed = RedrawRequest::request_if(value != self.value);
+        self.value = value;
+        changed
+    }
+
+    /// Returns the minimum value.
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// Returns the maximum value.
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    /// Returns the position of the value in the range, from 0 at the minimum to 1 at the maximum.
+    pub fn ratio(&self) -> f64 {
+        if self.max > self.min {
+            ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Increases the value by a step. Returns `true` if the value changed.
+    pub fn increment(&mut self) -> bool {
+        self.set_value(self.value + self.step)
+    }
+
+    /// Decreases the value by a step. Returns `true` if the value changed.
+    pub fn decrement(&mut self) -> bool {
+        self.set_value(self.value - self.step)
+    }
+
+    /// Returns `true` if the slider has the focus.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Sets whether the slider has the focus.
+    pub fn set_focused(&mut self, focused: bool) {
+        RedrawRequest::request_if(self.focused != focused);
+        self.focused = focused;
+    }
+
+    /// Returns `true` if the slider was [read-only](Slider::read_only) during the last render.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns `true` if the slider was [disabled](Slider::disabled) during the last render.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Returns `true` while the thumb is dragged with the mouse.
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Handles a key event and returns `true` if the value changed.
+    ///
+    /// See [`Slider`] for the keys handled by a focused slider. The keys are ignored while the
+    /// slider is read-only or disabled.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if !self.focused || self.read_only || self.disabled || key.kind == KeyEventKind::Release {
+            return false;
+        }
+        match key.key {
+            Key::Left | Key::Down => self.decrement(),
+            Key::Right | Key::Up => self.increment(),
+            Key::Home => self.set_value(self.min),
+            Key::End => self.set_value(self.max),
+            _ => false,
+        }
+    }
+
+    /// Handles a mouse event and returns `true` if the value changed.
+    ///
+    /// The event position is compared to the area where the track was last rendered. The events
+    /// are ignored while the slider is read-only or disabled.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        if self.read_only || self.disabled {
+            self.dragging = false;
+            return false;
+        }
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.dragging = self.track.contains(mouse.position());
+                self.dragging && self.set_value(self.value_at(mouse.column))
+            }
+            MouseEventKind::Drag(MouseButton::Left) if self.dragging => {
+                self.set_value(self.value_at(mouse.column))
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                self.dragging = false;
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the value at the given column of the track.
+    fn value_at(&self, column: u16) -> f64 {
+        let last = self.track.width.saturating_sub(1);
+        if last == 0 {
+            return self.min;
+        }
+        let offset = column.saturating_sub(self.track.x).min(last);
+        self.min + (self.max - self.min) * f64::from(offset) / f64::from(last)
+    }
+
+    /// Rounds the value to the nearest step from the minimum and clamps it to the range.
+    fn snap(&self, value: f64) -> f64 {
+        let value = if self.step > 0.0 {
+            self.min + ((value - self.min) / self.step).round() * self.step
+        } else {
+            value
+        };
+        value.clamp(self.min, self.max)
+    }
+}
+
+impl Focusable for SliderState {
+    fn set_focused(&mut self, focused: bool) {
+        SliderState::set_focused(self, focused);
+    }
+
+    fn is_disabled(&self) -> bool {
+        SliderState::is_disabled(self)
+    }
+}
+
+impl<'a> StatefulWidget for Slider<'a> {
+    type State = SliderState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.read_only = self.read_only;
+        state.disabled = self.disabled;
+        buf.set_style(area, self.style);
+        let track = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        state.track = track;
+        let ratio = state.ratio();
+        let label = self
+            .label
+            .unwrap_or_else(|| Span::raw(state.value.to_string()));
+        Gauge::default()
+            .ratio(ratio)
+            .label(label)
+            .style(self.style)
+            .gauge_style(self.track_style)
+            .use_unicode(self.use_unicode)
+            .render(track, buf);
+        if !track.is_empty() {
+            let thumb_style = if state.focused && !self.disabled {
+                self.thumb_style.patch(self.focused_style)
+            } else {
+                self.thumb_style
+            };
+            let x = track.x + (ratio * f64::from(track.width - 1)).round() as u16;
+            for y in track.top()..track.bottom() {
+                buf.get_mut(x, y)
+                    .set_symbol(self.thumb)
+                    .set_style(thumb_style);
+            }
+        }
+        if self.disabled {
+            buf.set_style(area, self.disabled_style);
+        }
+    }
+}
+
+impl<'a> Widget for Slider<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = SliderState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    #[test]
+    fn render() {
+        let mut state = SliderState::new(0.0, 10.0).with_value(5.0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+        StatefulWidget::render(Slider::default().thumb("|"), buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["█████|     "]));
+
+        let mut state = SliderState::new(0.0, 10.0).with_value(10.0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+        StatefulWidget::render(
+            Slider::default().label("max"),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["████max ██┃"]));
+    }
+
+    #[test]
+    fn keys_adjust_focused_slider() {
+        let mut state = SliderState::new(0.0, 1.0).with_step(0.25);
+        assert!(!state.handle_key(Key::Right.into()));
+        state.set_focused(true);
+        assert!(state.handle_key(Key::Right.into()));
+        assert!(state.handle_key(Key::Up.into()));
+        assert_eq!(state.value(), 0.5);
+        assert!(state.handle_key(Key::Left.into()));
+        assert_eq!(state.value(), 0.25);
+        assert!(state.handle_key(Key::End.into()));
+        assert!(!state.handle_key(Key::Right.into()));
+        assert_eq!(state.value(), 1.0);
+        assert!(state.handle_key(Key::Home.into()));
+        assert_eq!(state.value(), 0.0);
+    }
+
+    #[test]
+    fn value_is_snapped_and_clamped() {
+        let mut state = SliderState::new(10.0, 0.0).with_step(2.0);
+        assert_eq!((state.min(), state.max()), (0.0, 10.0));
+        state.set_value(4.9);
+        assert_eq!(state.value(), 4.0);
+        state.set_value(42.0);
+        assert_eq!(state.value(), 10.0);
+        assert_eq!(state.ratio(), 1.0);
+    }
+
+    #[test]
+    fn mouse_drag() {
+        let mut state = SliderState::new(0.0, 100.0).with_step(10.0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 13, 1));
+        StatefulWidget::render(
+            Slider::default(),
+            Rect::new(2, 0, 11, 1),
+            &mut buf,
+            &mut state,
+        );
+        let left = MouseButton::Left;
+        assert!(!state.handle_mouse(MouseEvent::new(MouseEventKind::Down(left), 0, 0)));
+        assert!(!state.handle_mouse(MouseEvent::new(MouseEventKind::Drag(left), 5, 0)));
+        assert!(state.handle_mouse(MouseEvent::new(MouseEventKind::Down(left), 5, 0)));
+        assert!(state.is_dragging());
+        assert_eq!(state.value(), 30.0);
+        // dragging beyond the track clamps the value
+        assert!(state.handle_mouse(MouseEvent::new(MouseEventKind::Drag(left), 20, 0)));
+        assert_eq!(state.value(), 100.0);
+        state.handle_mouse(MouseEvent::new(MouseEventKind::Up(left), 20, 0));
+        assert!(!state.is_dragging());
+        assert!(!state.handle_mouse(MouseEvent::new(MouseEventKind::Drag(left), 2, 0)));
+    }
+
+    #[test]
+    fn read_only_and_disabled() {
+        let mut state = SliderState::new(0.0, 10.0).with_focused(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+        let slider = Slider::default().thumb("|").label("");
+        StatefulWidget::render(
+            slider.clone().read_only(true),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        assert!(state.is_read_only() && !state.is_disabled());
+        let click = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 5, 0);
+        assert!(!state.handle_key(Key::Right.into()));
+        assert!(!state.handle_mouse(click));
+        assert_eq!(state.value(), 0.0);
+
+        let mut buf = Buffer::empty(buf.area);
+        StatefulWidget::render(slider.disabled(true), buf.area, &mut buf, &mut state);
+        assert!(state.is_disabled() && Focusable::is_disabled(&state));
+        assert!(!state.handle_key(Key::End.into()));
+        let mut expected = Buffer::with_lines(vec!["|          "]);
+        expected.set_style(buf.area, DEFAULT_DISABLED_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+}
@@ -6,11 +6,21 @@ use strum::{Display, EnumString};
 use unicode_width::UnicodeWidthStr;
 
 use crate::{
-    layout::SegmentSize,
+    layout::{first_visible_item, SegmentSize},
     prelude::*,
-    widgets::{Block, StatefulWidget, Widget},
+    terminal::RedrawRequest,
+    widgets::{
+        decorator::TooltipState,
+        list::{set_line_elided, Truncated},
+        reflow::{LineComposer, WordWrapper},
+        Block, Paragraph, ScrollPosition, StatefulWidget, Widget, Wrap,
+    },
 };
 
+pub mod aggregate;
+mod export;
+pub use export::ExportFormat;
+
 /// A widget to display data in formatted columns.
 ///
 /// A `Table` is a collection of [`Row`]s, each composed of [`Cell`]s:
@@ -200,13 +210,17 @@ pub struct Table<'a> {
 
     /// Controls how to distribute extra space among the columns
     segment_size: SegmentSize,
+
+    /// Whether the lines too wide for their cells are elided with an ellipsis
+    ellipsis: bool,
 }
 
 /// A single row of data to be displayed in a [`Table`] widget.
 ///
 /// A `Row` is a collection of [`Cell`]s.
 ///
-/// By default, a row has a height of 1 but you can change this using [`Row::height`].
+/// By default, a row has a height of 1 but you can change this using [`Row::height`], or derive it
+/// from its tallest cell with [`Row::auto_height`].
 ///
 /// You can set the style of the entire row using [`Row::style`]. This [`Style`] will be combined
 /// with the [`Style`] of each individual [`Cell`] by adding the [`Style`] of the [`Cell`] to the
@@ -258,6 +272,7 @@ pub struct Table<'a> {
 pub struct Row<'a> {
     cells: Vec<Cell<'a>>,
     height: u16,
+    auto_height: bool,
     bottom_margin: u16,
     style: Style,
 }
@@ -269,6 +284,9 @@ pub struct Row<'a> {
 /// [`Style`] of the [`Cell`] by adding the [`Style`] of the [`Text`] content to the [`Style`] of
 /// the [`Cell`]. Styles set on the text content will only affect the content.
 ///
+/// The content can be wrapped within the width of its column with [`Cell::wrap`], and aligned to
+/// the top, middle or bottom of its row with [`Cell::vertical_alignment`].
+///
 /// # Examples
 ///
 /// You can create a `Cell` from anything that can be converted to a [`Text`].
@@ -299,6 +317,23 @@ pub struct Row<'a> {
 pub struct Cell<'a> {
     content: Text<'a>,
     style: Style,
+    wrap: Option<Wrap>,
+    vertical_alignment: VerticalAlignment,
+}
+
+/// Vertical alignment of the content of a [`Cell`] within its [`Row`], or of the label of a
+/// [`Gauge`](crate::widgets::Gauge) within its bar
+#[derive(Debug, Display, EnumString, PartialEq, Eq, Clone, Copy, Default, Hash)]
+pub enum VerticalAlignment {
+    /// Align the content to the top of the row
+    #[default]
+    Top,
+
+    /// Center the content vertically within the row
+    Middle,
+
+    /// Align the content to the bottom of the row
+    Bottom,
 }
 
 /// This option allows the user to configure the "highlight symbol" column width spacing
@@ -335,6 +370,10 @@ pub enum HighlightSpacing {
 /// - [`offset`]: the index of the first row to be displayed
 /// - [`selected`]: the index of the selected row, which can be `None` if no row is selected
 ///
+/// The table also records the number of rows and how many of them were visible when it was last
+/// rendered, so that keyboard paging ([`select_page_down`](TableState::select_page_down),
+/// [`select_last`](TableState::select_last), ...) matches what is on screen.
+///
 /// [`offset`]: TableState::offset()
 /// [`selected`]: TableState::selected()
 ///
@@ -367,6 +406,12 @@ pub enum HighlightSpacing {
 pub struct TableState {
     offset: usize,
     selected: Option<usize>,
+    /// Number of rows during the last render
+    len: usize,
+    /// Number of rows visible during the last render
+    page: usize,
+    /// Cells elided during the last render
+    truncated: Vec<Truncated>,
 }
 
 impl<'a> Table<'a> {
@@ -662,6 +707,34 @@ impl<'a> Table<'a> {
         self.segment_size = segment_size;
         self
     }
+
+    /// Set whether the lines too wide for their cells end with an ellipsis (`…`)
+    ///
+    /// The elided cells of the rows are recorded in the [`TableState`], so that their full content
+    /// can be shown in a popup when the mouse rests on them with
+    /// [`TableState::register_truncated`], or when a key is pressed on the selected row with
+    /// [`TableState::expand_selected`]. The cells wrapping their content are never elided. This
+    /// is `false` by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let rows = [Row::new(["Ferris", "A crab living in the Rust docs"])];
+    /// let table = Table::new(rows, [Constraint::Length(6), Constraint::Length(8)]).ellipsis(true);
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 15, 1));
+    /// let mut state = TableState::default();
+    /// StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+    /// assert_eq!(buf, Buffer::with_lines(vec!["Ferris A crab …"]));
+    /// assert_eq!(state.truncated()[0].column(), Some(1));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn ellipsis(mut self, ellipsis: bool) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
 }
 
 impl<'a> Row<'a> {
@@ -723,7 +796,8 @@ impl<'a> Row<'a> {
 
     /// Set the fixed height of the [`Row`]
     ///
-    /// Any [`Cell`] whose content has more lines than this height will see its content truncated.
+    /// Any [`Cell`] whose content has more lines than this height will see its content truncated,
+    /// unless the row derives its height from its cells with [`Row::auto_height`].
     ///
     /// By default, the height is `1`.
     ///
@@ -742,6 +816,30 @@ impl<'a> Row<'a> {
         self
     }
 
+    /// Set whether the height of the [`Row`] is derived from its tallest [`Cell`]
+    ///
+    /// The height of each cell is measured within the width of its column, including the lines
+    /// added by [`Cell::wrap`], see [`Cell::line_count`]. The row is never shorter than its
+    /// [`height`](Row::height).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let cells = vec![
+    ///     Cell::new("a long description wrapped over several lines").wrap(Wrap { trim: true }),
+    ///     Cell::new("Cell 2"),
+    /// ];
+    /// let row = Row::new(cells).auto_height(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn auto_height(mut self, auto_height: bool) -> Self {
+        self.auto_height = auto_height;
+        self
+    }
+
     /// Set the bottom margin. By default, the bottom margin is `0`.
     ///
     /// The bottom margin is the number of blank lines to be displayed after the row.
@@ -814,7 +912,7 @@ impl<'a> Cell<'a> {
     {
         Self {
             content: content.into(),
-            style: Style::default(),
+            ..Default::default()
         }
     }
 
@@ -871,6 +969,77 @@ impl<'a> Cell<'a> {
         self.style = style;
         self
     }
+
+    /// Set how the content of this cell wraps within the width of its column
+    ///
+    /// By default, the content isn't wrapped and each line is truncated to the width of the
+    /// column. Use [`Row::auto_height`] to grow the row to fit the wrapped content.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// Cell::new("a long description").wrap(Wrap { trim: true });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap = Some(wrap);
+        self
+    }
+
+    /// Set the vertical alignment of the content of this cell within its [`Row`]
+    ///
+    /// This only matters when the row is taller than the content of the cell. By default, the
+    /// content is aligned to the top of the row.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// Cell::new("Cell 1").vertical_alignment(VerticalAlignment::Middle);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn vertical_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Returns the number of lines of the content of this cell when rendered within the given
+    /// width, taking [`wrap`](Cell::wrap) into account.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let cell = Cell::new("Hello World").wrap(Wrap { trim: true });
+    /// assert_eq!(cell.line_count(20), 1);
+    /// assert_eq!(cell.line_count(6), 2);
+    /// ```
+    pub fn line_count(&self, width: u16) -> u16 {
+        let Some(Wrap { trim }) = self.wrap else {
+            return self.content.height() as u16;
+        };
+        if width == 0 {
+            return 0;
+        }
+        let styled = self.content.lines.iter().map(|line| {
+            let graphemes = line
+                .spans
+                .iter()
+                .flat_map(|span| span.styled_graphemes(Style::default()));
+            (graphemes, line.alignment.unwrap_or(Alignment::Left))
+        });
+        let mut line_composer = WordWrapper::new(styled, width, trim);
+        let mut count = 0;
+        while line_composer.next_line().is_some() {
+            count += 1;
+        }
+        count
+    }
 }
 
 impl HighlightSpacing {
@@ -948,6 +1117,13 @@ impl TableState {
 
     /// Mutable reference to the index of the first row to be displayed
     ///
+    /// The state can't see the changes made through the reference, so a redraw is requested with
+    /// [`RedrawRequest`] on every call, even if the offset is only read or left unchanged. Read
+    /// it with [`offset`](Self::offset) instead, so that a [`RedrawScheduler`] doesn't keep
+    /// redrawing.
+    ///
+    /// [`RedrawScheduler`]: crate::terminal::RedrawScheduler
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -956,6 +1132,7 @@ impl TableState {
     /// *state.offset_mut() = 1;
     /// ```
     pub fn offset_mut(&mut self) -> &mut usize {
+        RedrawRequest::request();
         &mut self.offset
     }
 
@@ -978,6 +1155,14 @@ impl TableState {
     ///
     /// Returns `None` if no row is selected
     ///
+    /// The state can't see the changes made through the reference, so a redraw is requested with
+    /// [`RedrawRequest`] on every call, even if the selection is only read or left unchanged.
+    /// Read it with [`selected`](Self::selected) and change it with [`select`](Self::select)
+    /// instead, which only request a redraw when the selection changes, so that a
+    /// [`RedrawScheduler`] doesn't keep redrawing.
+    ///
+    /// [`RedrawScheduler`]: crate::terminal::RedrawScheduler
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -986,6 +1171,7 @@ impl TableState {
     /// *state.selected_mut() = Some(1);
     /// ```
     pub fn selected_mut(&mut self) -> &mut Option<usize> {
+        RedrawRequest::request();
         &mut self.selected
     }
 
@@ -993,6 +1179,8 @@ impl TableState {
     ///
     /// Set to `None` if no row is selected. This will also reset the offset to `0`.
     ///
+    /// A redraw is requested with [`RedrawRequest`] if the selection changed.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -1001,11 +1189,116 @@ impl TableState {
     /// state.select(Some(1));
     /// ```
     pub fn select(&mut self, index: Option<usize>) {
+        if self.selected != index {
+            RedrawRequest::request();
+        }
         self.selected = index;
         if index.is_none() {
             self.offset = 0;
         }
     }
+
+    /// Selects the first row.
+    ///
+    /// A redraw is requested with [`RedrawRequest`] if the selection changed.
+    pub fn select_first(&mut self) {
+        self.select(Some(0));
+    }
+
+    /// Selects the last row, as counted during the last render. Does nothing before the first
+    /// render or when the table is empty.
+    ///
+    /// A redraw is requested with [`RedrawRequest`] if the selection changed.
+    pub fn select_last(&mut self) {
+        if self.len > 0 {
+            self.select(Some(self.len - 1));
+        }
+    }
+
+    /// Selects the row one page below the selected one, and scrolls the table by a page.
+    ///
+    /// A page is the number of rows visible during the last render, see
+    /// [`page_size`](Self::page_size). The selection stops at the last row. Selects the first
+    /// row when nothing is selected.
+    pub fn select_page_down(&mut self) {
+        let page = self.page_size();
+        let Some(selected) = self.selected else {
+            self.select_first();
+            return;
+        };
+        let last = self.len.saturating_sub(1).max(selected);
+        self.select(Some((selected + page).min(last)));
+        self.offset = (self.offset + page).min(self.len.saturating_sub(page));
+    }
+
+    /// Selects the row one page above the selected one, and scrolls the table by a page.
+    ///
+    /// A page is the number of rows visible during the last render, see
+    /// [`page_size`](Self::page_size). The selection stops at the first row.
+    pub fn select_page_up(&mut self) {
+        let page = self.page_size();
+        let selected = self.selected.unwrap_or(0);
+        self.select(Some(selected.saturating_sub(page)));
+        self.offset = self.offset.saturating_sub(page);
+    }
+
+    /// Scrolls the table so that the selected row is at the given position of the viewport,
+    /// as sized during the last render.
+    ///
+    /// Does nothing when no row is selected. A redraw is requested with [`RedrawRequest`] if
+    /// the offset changed.
+    pub fn scroll_to_selected(&mut self, position: ScrollPosition) {
+        let Some(selected) = self.selected else {
+            return;
+        };
+        let page = self.page_size();
+        let offset = match position {
+            ScrollPosition::Top => selected,
+            ScrollPosition::Center => selected.saturating_sub((page - 1) / 2),
+            ScrollPosition::Bottom => (selected + 1).saturating_sub(page),
+        };
+        RedrawRequest::request_if(offset != self.offset);
+        self.offset = offset;
+    }
+
+    /// Returns the number of rows visible during the last render, used as the size of a page
+    /// by [`select_page_down`](Self::select_page_down) and
+    /// [`select_page_up`](Self::select_page_up). It is at least 1, even before the first render.
+    pub fn page_size(&self) -> usize {
+        self.page.max(1)
+    }
+
+    /// Returns the cells elided with an ellipsis during the last render, see [`Table::ellipsis`].
+    pub fn truncated(&self) -> &[Truncated] {
+        &self.truncated
+    }
+
+    /// Registers the full content of the truncated cells in the tooltips, shown in a
+    /// [`TooltipPopup`] when the mouse rests on them. Call this after rendering the table.
+    ///
+    /// [`TooltipPopup`]: crate::widgets::decorator::TooltipPopup
+    pub fn register_truncated(&self, tooltips: &mut TooltipState) {
+        for truncated in &self.truncated {
+            tooltips.register(truncated.area(), truncated.text());
+        }
+    }
+
+    /// Shows the full content of the truncated cells of the selected row in the tooltips right
+    /// away, e.g. when a key is pressed. The cells are shown one after the other, next to the
+    /// area spanning them. Returns `true` if the row had truncated cells during the last render.
+    pub fn expand_selected(&self, tooltips: &mut TooltipState) -> bool {
+        let cells = self
+            .truncated
+            .iter()
+            .filter(|truncated| Some(truncated.index()) == self.selected)
+            .collect_vec();
+        let Some(area) = cells.iter().map(|cell| cell.area()).reduce(Rect::union) else {
+            return false;
+        };
+        let text = cells.iter().map(|cell| cell.text()).join("\n");
+        tooltips.show(area, text);
+        true
+    }
 }
 
 impl<'a> Widget for Table<'a> {
@@ -1019,6 +1312,9 @@ impl<'a> StatefulWidget for Table<'a> {
     type State = TableState;
 
     fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.len = self.rows.len();
+        state.page = 0;
+        state.truncated.clear();
         if area.area() == 0 {
             return;
         }
@@ -1044,13 +1340,14 @@ impl<'a> StatefulWidget for Table<'a> {
 
         // Draw header
         if let Some(ref header) = self.header {
-            let max_header_height = table_area.height.min(header.total_height());
+            let header_height = header.measure_height(&columns_widths);
+            let max_header_height = table_area.height.min(header_height + header.bottom_margin);
             buf.set_style(
                 Rect {
                     x: table_area.left(),
                     y: table_area.top(),
                     width: table_area.width,
-                    height: table_area.height.min(header.height),
+                    height: table_area.height.min(header_height),
                 },
                 header.style,
             );
@@ -1064,6 +1361,7 @@ impl<'a> StatefulWidget for Table<'a> {
                         width: *width,
                         height: max_header_height,
                     },
+                    self.ellipsis,
                 );
             }
             current_height += max_header_height;
@@ -1071,11 +1369,17 @@ impl<'a> StatefulWidget for Table<'a> {
         }
 
         // Draw rows
-        if self.rows.is_empty() {
+        if self.rows.is_empty() || rows_height == 0 {
             return;
         }
-        let (start, end) = self.get_row_bounds(state.selected, state.offset, rows_height);
+        let heights = self
+            .rows
+            .iter()
+            .map(|row| row.measure_height(&columns_widths))
+            .collect_vec();
+        let (start, end) = self.get_row_bounds(&heights, state.selected, state.offset, rows_height);
         state.offset = start;
+        state.page = end - start;
         for (i, table_row) in self
             .rows
             .iter_mut()
@@ -1084,12 +1388,14 @@ impl<'a> StatefulWidget for Table<'a> {
             .take(end - start)
         {
             let (row, inner_offset) = (table_area.top() + current_height, table_area.left());
-            current_height += table_row.total_height();
+            // a row taller than the table is clipped to its bottom
+            let height = heights[i].min(table_area.bottom().saturating_sub(row));
+            current_height += height.saturating_add(table_row.bottom_margin);
             let table_row_area = Rect {
                 x: inner_offset,
                 y: row,
                 width: table_area.width,
-                height: table_row.height,
+                height,
             };
             buf.set_style(table_row_area, table_row.style);
             let is_selected = state.selected.map_or(false, |s| s == i);
@@ -1105,16 +1411,21 @@ impl<'a> StatefulWidget for Table<'a> {
                     table_row.style,
                 );
             };
-            for ((x, width), cell) in columns_widths.iter().zip(table_row.cells.iter()) {
-                cell.render(
-                    buf,
-                    Rect {
-                        x: inner_offset + x,
-                        y: row,
-                        width: *width,
-                        height: table_row.height,
-                    },
-                );
+            for (j, ((x, width), cell)) in columns_widths
+                .iter()
+                .zip(table_row.cells.iter())
+                .enumerate()
+            {
+                let cell_area = Rect {
+                    x: inner_offset + x,
+                    y: row,
+                    width: *width,
+                    height,
+                };
+                if cell.render(buf, cell_area, self.ellipsis) {
+                    let truncated = Truncated::new(i, Some(j), cell_area, &cell.content);
+                    state.truncated.push(truncated);
+                }
             }
             if is_selected {
                 buf.set_style(table_row_area, self.highlight_style);
@@ -1147,40 +1458,31 @@ impl Table<'_> {
             .collect()
     }
 
+    /// Get the range of rows to display, given the measured `heights` of the rows.
+    ///
+    /// A row taller than `max_height` is still displayed when it is the first one, so that it can
+    /// be scrolled to.
     fn get_row_bounds(
         &self,
+        heights: &[u16],
         selected: Option<usize>,
         offset: usize,
         max_height: u16,
     ) -> (usize, usize) {
+        let total_height = |i: usize| heights[i].saturating_add(self.rows[i].bottom_margin);
         let offset = offset.min(self.rows.len().saturating_sub(1));
-        let mut start = offset;
-        let mut end = offset;
-        let mut height = 0;
-        for item in self.rows.iter().skip(offset) {
-            if height + item.height > max_height {
+        let selected = selected.unwrap_or(0).min(self.rows.len() - 1);
+        let start = first_visible_item(offset, selected, usize::from(max_height), |i| {
+            usize::from(total_height(i))
+        });
+        let mut end = start;
+        let mut height: u16 = 0;
+        for (i, row_height) in heights.iter().enumerate().skip(start) {
+            if end > start && height.saturating_add(*row_height) > max_height {
                 break;
             }
-            height += item.total_height();
-            end += 1;
-        }
-
-        let selected = selected.unwrap_or(0).min(self.rows.len() - 1);
-        while selected >= end {
-            height = height.saturating_add(self.rows[end].total_height());
+            height = height.saturating_add(total_height(i));
             end += 1;
-            while height > max_height {
-                height = height.saturating_sub(self.rows[start].total_height());
-                start += 1;
-            }
-        }
-        while selected < start {
-            start -= 1;
-            height = height.saturating_add(self.rows[start].total_height());
-            while height > max_height {
-                end -= 1;
-                height = height.saturating_sub(self.rows[end].total_height());
-            }
         }
         (start, end)
     }
@@ -1199,16 +1501,44 @@ fn ensure_percentages_less_than_100(widths: &[Constraint]) {
 
 // private methods for rendering
 impl Row<'_> {
-    /// Returns the total height of the row.
-    fn total_height(&self) -> u16 {
-        self.height.saturating_add(self.bottom_margin)
+    /// Returns the height of the row, measuring its cells within the columns widths when the
+    /// height is derived from them. The bottom margin isn't included.
+    fn measure_height(&self, columns_widths: &[(u16, u16)]) -> u16 {
+        if !self.auto_height {
+            return self.height;
+        }
+        self.cells
+            .iter()
+            .zip(columns_widths)
+            .map(|(cell, (_, width))| cell.line_count(*width))
+            .fold(self.height, u16::max)
     }
 }
 
 // private methods for rendering
 impl Cell<'_> {
-    fn render(&self, buf: &mut Buffer, area: Rect) {
+    /// Renders the content of the cell, eliding the lines too wide for it with an ellipsis if
+    /// `ellipsis` is set. Returns `true` if a line was elided.
+    fn render(&self, buf: &mut Buffer, area: Rect, ellipsis: bool) -> bool {
         buf.set_style(area, self.style);
+        let height = self.line_count(area.width).min(area.height);
+        let y_offset = match self.vertical_alignment {
+            VerticalAlignment::Top => 0,
+            VerticalAlignment::Middle => (area.height - height) / 2,
+            VerticalAlignment::Bottom => area.height - height,
+        };
+        let area = Rect {
+            y: area.y + y_offset,
+            height: area.height - y_offset,
+            ..area
+        };
+        if let Some(wrap) = self.wrap {
+            Paragraph::new(self.content.clone())
+                .wrap(wrap)
+                .render(area, buf);
+            return false;
+        }
+        let mut elided = false;
         for (i, line) in self.content.lines.iter().enumerate() {
             if i as u16 >= area.height {
                 break;
@@ -1225,8 +1555,13 @@ impl Cell<'_> {
                 continue;
             }
 
-            buf.set_line(x, area.y + i as u16, line, area.width);
+            if ellipsis {
+                elided |= set_line_elided(buf, x, area.y + i as u16, line, area.width);
+            } else {
+                buf.set_line(x, area.y + i as u16, line, area.width);
+            }
         }
+        elided
     }
 }
 
@@ -1235,10 +1570,7 @@ where
     T: Into<Text<'a>>,
 {
     fn from(content: T) -> Cell<'a> {
-        Cell {
-            content: content.into(),
-            style: Style::default(),
-        }
+        Cell::new(content)
     }
 }
 
@@ -1596,6 +1928,114 @@ mod tests {
         assert_eq!(buf, expected);
     }
 
+    #[test]
+    fn render_table_with_wrapped_cells() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 4));
+        let table = Table::new(
+            vec![
+                Row::new(vec![
+                    Cell::new("one two three").wrap(Wrap { trim: true }),
+                    Cell::new("mid").vertical_alignment(VerticalAlignment::Middle),
+                ])
+                .auto_height(true),
+                Row::new(vec!["a", "b"]),
+            ],
+            [Length(5), Length(5)],
+        );
+
+        Widget::render(table, buf.area, &mut buf);
+
+        let expected = Buffer::with_lines(vec![
+            "one        ",
+            "two   mid  ",
+            "three      ",
+            "a     b    ",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_table_scrolls_over_rows_taller_than_the_table() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+        let table = Table::new(
+            vec![
+                Row::new(vec!["a"]),
+                Row::new(vec![Cell::new("b c d").wrap(Wrap { trim: true })]).auto_height(true),
+                Row::new(vec!["e"]),
+            ],
+            [Length(1)],
+        );
+        let mut state = TableState::default().with_selected(Some(1));
+
+        StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+
+        assert_eq!((state.offset, state.page_size()), (1, 1));
+        assert_eq!(buf, Buffer::with_lines(vec!["b  ", "c  "]));
+    }
+
+    #[test]
+    fn render_table_with_ellipsis() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 4));
+        let table = Table::new(
+            vec![
+                Row::new(vec!["a", "cut cell"]),
+                Row::new(vec!["long one", "long two"]),
+                Row::new(vec![
+                    Cell::new("c d e f g").wrap(Wrap { trim: true }),
+                    "f".into(),
+                ]),
+            ],
+            [Length(5), Length(5)],
+        )
+        .header(Row::new(vec!["header", "b"]))
+        .ellipsis(true);
+        let mut state = TableState::default().with_selected(Some(1));
+
+        StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+
+        let expected = Buffer::with_lines(vec![
+            "head… b    ",
+            "a     cut …",
+            "long… long…",
+            "c d e f    ",
+        ]);
+        assert_eq!(buf, expected);
+        // neither the header nor the wrapped cells are recorded
+        let cells = state
+            .truncated()
+            .iter()
+            .map(|cell| (cell.index(), cell.column(), cell.area(), cell.text()))
+            .collect_vec();
+        assert_eq!(
+            cells,
+            vec![
+                (0, Some(1), Rect::new(6, 1, 5, 1), "cut cell"),
+                (1, Some(0), Rect::new(0, 2, 5, 1), "long one"),
+                (1, Some(1), Rect::new(6, 2, 5, 1), "long two"),
+            ]
+        );
+
+        let mut tooltips = TooltipState::default();
+        assert!(state.expand_selected(&mut tooltips));
+        assert_eq!(
+            tooltips.hovered(),
+            Some((Rect::new(0, 2, 11, 1), "long one\nlong two"))
+        );
+        state.select(Some(0));
+        let mut tooltips = TooltipState::default();
+        tooltips.handle_mouse(crate::input::MouseEvent::new(
+            crate::input::MouseEventKind::Moved,
+            7,
+            1,
+        ));
+        state.register_truncated(&mut tooltips);
+        tooltips.end_frame();
+        assert_eq!(
+            tooltips.hovered(),
+            Some((Rect::new(6, 1, 5, 1), "cut cell"))
+        );
+    }
+
     #[test]
     fn test_render_table_when_overflow() {
         let mut buf = Buffer::empty(Rect::new(0, 0, 20, 3));
@@ -1812,6 +2252,15 @@ mod tests {
             assert_eq!(cell.content, Text::from(""));
         }
 
+        #[test]
+        fn cell_line_count() {
+            let cell = Cell::new("one two\nthree");
+            assert_eq!(cell.line_count(5), 2);
+            let cell = cell.wrap(Wrap { trim: true });
+            assert_eq!(cell.line_count(5), 3);
+            assert_eq!(cell.line_count(0), 0);
+        }
+
         #[test]
         fn cell_style() {
             let style = Style::default().red().italic();
@@ -1881,5 +2330,32 @@ mod tests {
             state.select(None);
             assert_eq!(state.selected, None);
         }
+
+        #[test]
+        fn table_state_paging() {
+            let rows = (0..10).map(|i| Row::new(vec![i.to_string()]));
+            let table = Table::new(rows, [Constraint::Length(2)]).header(Row::new(vec!["#"]));
+            let mut state = TableState::new();
+            // before the first render, a page is a single row and the last row is unknown
+            state.select_page_down();
+            assert_eq!(state.selected, Some(0));
+            state.select_last();
+            assert_eq!(state.selected, Some(0));
+
+            let mut buf = Buffer::empty(Rect::new(0, 0, 2, 5));
+            StatefulWidget::render(table.clone(), buf.area, &mut buf, &mut state);
+            assert_eq!(state.page_size(), 4);
+            state.select_page_down();
+            assert_eq!((state.selected, state.offset), (Some(4), 4));
+            state.select_last();
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!((state.selected, state.offset), (Some(9), 6));
+            state.select_page_down();
+            assert_eq!(state.selected, Some(9));
+            state.select_page_up();
+            assert_eq!((state.selected, state.offset), (Some(5), 2));
+            state.select_first();
+            assert_eq!(state.selected, Some(0));
+        }
     }
 }
@@ -0,0 +1,239 @@
+//! Helpers to group the data of a [`Table`] and summarize each group.
+//!
+//! [`group_by`] splits the data into [`Group`]s sharing the same key, which provide common
+//! summaries (count, sum, min, max, mean, or any custom computation). [`rows`] then turns the
+//! groups into [`Row`]s, with an optional header and footer row for each group.
+//!
+//! [`Table`]: super::Table
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::{prelude::*, widgets::{aggregate, *}};
+//!
+//! struct Sale {
+//!     region: &'static str,
+//!     amount: u32,
+//! }
+//!
+//! let sales = [
+//!     Sale { region: "north", amount: 10 },
+//!     Sale { region: "south", amount: 5 },
+//!     Sale { region: "north", amount: 7 },
+//! ];
+//! let groups = aggregate::group_by(&sales, |sale| sale.region);
+//! assert_eq!(groups[0].sum(|sale| sale.amount), 17);
+//! let rows = aggregate::rows(
+//!     &groups,
+//!     |group| Some(Row::new(vec![group.key]).bold()),
+//!     |sale| Row::new(vec![String::new(), sale.amount.to_string()]),
+//!     |group| Some(Row::new(vec!["total".to_string(), group.sum(|s| s.amount).to_string()])),
+//! );
+//! assert_eq!(rows.len(), 7);
+//! let table = Table::new(rows, [Constraint::Length(8), Constraint::Length(6)]);
+//! ```
+use std::{cmp::Ordering, collections::HashMap, hash::Hash, iter::Sum};
+
+use super::Row;
+
+/// Items sharing the same key, as returned by [`group_by`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Group<'d, K, T> {
+    /// The key shared by the items of the group.
+    pub key: K,
+    /// The items of the group, in their original order.
+    pub items: Vec<&'d T>,
+}
+
+impl<'d, K, T> Group<'d, K, T> {
+    /// Returns the number of items in the group.
+    pub fn count(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns the sum of the values extracted from the items.
+    pub fn sum<V, F>(&self, f: F) -> V
+    where
+        V: Sum<V>,
+        F: Fn(&T) -> V,
+    {
+        self.items.iter().map(|item| f(item)).sum()
+    }
+
+    /// Returns the smallest of the values extracted from the items, or `None` if the group is
+    /// empty.
+    ///
+    /// Values that can't be compared (e.g. `NaN`) are ignored.
+    pub fn min<V, F>(&self, f: F) -> Option<V>
+    where
+        V: PartialOrd,
+        F: Fn(&T) -> V,
+    {
+        extremum(self.items.iter().map(|item| f(item)), Ordering::Less)
+    }
+
+    /// Returns the largest of the values extracted from the items, or `None` if the group is
+    /// empty.
+    ///
+    /// Values that can't be compared (e.g. `NaN`) are ignored.
+    pub fn max<V, F>(&self, f: F) -> Option<V>
+    where
+        V: PartialOrd,
+        F: Fn(&T) -> V,
+    {
+        extremum(self.items.iter().map(|item| f(item)), Ordering::Greater)
+    }
+
+    /// Returns the mean of the values extracted from the items, or `None` if the group is empty.
+    pub fn mean<F>(&self, f: F) -> Option<f64>
+    where
+        F: Fn(&T) -> f64,
+    {
+        if self.items.is_empty() {
+            None
+        } else {
+            Some(self.sum(f) / self.items.len() as f64)
+        }
+    }
+
+    /// Computes a custom summary of the items.
+    pub fn summarize<V, F>(&self, f: F) -> V
+    where
+        F: FnOnce(&[&'d T]) -> V,
+    {
+        f(&self.items)
+    }
+}
+
+/// Returns the first value that is `ordering` compared to all the others, ignoring the values
+/// that can't be compared.
+fn extremum<V, I>(values: I, ordering: Ordering) -> Option<V>
+where
+    V: PartialOrd,
+    I: Iterator<Item = V>,
+{
+    values
+        .filter(|v| v.partial_cmp(v).is_some())
+        .reduce(|a, b| {
+            if b.partial_cmp(&a) == Some(ordering) {
+                b
+            } else {
+                a
+            }
+        })
+}
+
+/// Groups the items by the key returned by the given function.
+///
+/// The groups are returned in the order of the first appearance of their key, and the items keep
+/// their original order inside each group.
+pub fn group_by<'d, K, T, I, F>(items: I, key: F) -> Vec<Group<'d, K, T>>
+where
+    K: Eq + Hash + Clone,
+    T: 'd,
+    I: IntoIterator<Item = &'d T>,
+    F: Fn(&T) -> K,
+{
+    let mut groups: Vec<Group<'d, K, T>> = vec![];
+    let mut indices = HashMap::new();
+    for item in items {
+        let key = key(item);
+        let index = *indices.entry(key.clone()).or_insert_with(|| {
+            groups.push(Group { key, items: vec![] });
+            groups.len() - 1
+        });
+        groups[index].items.push(item);
+    }
+    groups
+}
+
+/// Builds the rows of a table from groups of items.
+///
+/// For each group, the rows are the header row (if `header` returns one), a row for each item,
+/// then the footer row (if `footer` returns one), which is typically used to show the summaries
+/// of the group.
+pub fn rows<'a, 'd, K, T, H, R, F>(
+    groups: &[Group<'d, K, T>],
+    mut header: H,
+    mut row: R,
+    mut footer: F,
+) -> Vec<Row<'a>>
+where
+    H: FnMut(&Group<'d, K, T>) -> Option<Row<'a>>,
+    R: FnMut(&T) -> Row<'a>,
+    F: FnMut(&Group<'d, K, T>) -> Option<Row<'a>>,
+{
+    let mut rows = vec![];
+    for group in groups {
+        rows.extend(header(group));
+        rows.extend(group.items.iter().map(|item| row(item)));
+        rows.extend(footer(group));
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::Cell;
+
+    fn groups() -> Vec<Group<'static, bool, f64>> {
+        const VALUES: [f64; 5] = [1.0, 4.0, 2.0, 7.0, f64::NAN];
+        group_by(&VALUES, |v| *v < 3.0)
+    }
+
+    #[test]
+    fn group_by_keeps_order() {
+        let groups = group_by(&[1, 2, 3, 4, 5], |v| v % 3);
+        let groups = groups
+            .iter()
+            .map(|g| (g.key, g.items.iter().map(|v| **v).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+        assert_eq!(groups, [(1, vec![1, 4]), (2, vec![2, 5]), (0, vec![3])]);
+    }
+
+    #[test]
+    fn summaries() {
+        let groups = groups();
+        let small = &groups[0];
+        assert!(small.key);
+        assert_eq!(small.count(), 2);
+        assert_eq!(small.sum(|v| *v), 3.0);
+        assert_eq!(small.min(|v| *v), Some(1.0));
+        assert_eq!(small.max(|v| *v), Some(2.0));
+        assert_eq!(small.mean(|v| *v), Some(1.5));
+        assert_eq!(small.summarize(|items| items.len() * 10), 20);
+
+        // NaN is ignored by min and max
+        let large = &groups[1];
+        assert_eq!(large.count(), 3);
+        assert_eq!(large.min(|v| *v), Some(4.0));
+        assert_eq!(large.max(|v| *v), Some(7.0));
+    }
+
+    #[test]
+    fn empty_group() {
+        let group: Group<(), u8> = Group {
+            key: (),
+            items: vec![],
+        };
+        assert_eq!(group.min(|v| *v), None);
+        assert_eq!(group.mean(|v| f64::from(*v)), None);
+    }
+
+    #[test]
+    fn rows_with_header_and_footer() {
+        let groups = group_by(&["a1", "b1", "a2"], |s| &s[..1]);
+        let rows = rows(
+            &groups,
+            |g| Some(Row::new(vec![g.key])),
+            |s| Row::new(vec![*s]),
+            |g| (g.count() > 1).then(|| Row::new(vec![g.count().to_string()])),
+        );
+        let expected = ["a", "a1", "a2", "2", "b", "b1"]
+            .into_iter()
+            .map(|s| Row::new(vec![Cell::from(s)]))
+            .collect::<Vec<_>>();
+        assert_eq!(rows, expected);
+    }
+}
@@ -0,0 +1,167 @@
+use strum::{Display, EnumString};
+
+use super::{Row, Table, TableState};
+use crate::widgets::list::plain_text;
+
+/// The text formats a [`Table`] can be exported to.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ExportFormat {
+    /// Comma separated values, as described in [RFC 4180].
+    ///
+    /// Fields containing commas, quotes or line breaks are quoted, and quotes inside them are
+    /// doubled.
+    ///
+    /// [RFC 4180]: https://www.rfc-editor.org/rfc/rfc4180
+    #[default]
+    Csv,
+    /// Tab separated values.
+    ///
+    /// Tabs and line breaks inside fields are replaced with spaces. This is the format expected
+    /// by most spreadsheets when pasting from the clipboard.
+    Tsv,
+}
+
+impl ExportFormat {
+    fn write_row(self, row: &Row, out: &mut String) {
+        for (i, cell) in row.cells.iter().enumerate() {
+            if i > 0 {
+                out.push(match self {
+                    ExportFormat::Csv => ',',
+                    ExportFormat::Tsv => '\t',
+                });
+            }
+            let text = plain_text(&cell.content);
+            match self {
+                ExportFormat::Csv if text.contains([',', '"', '\n', '\r']) => {
+                    out.push('"');
+                    out.push_str(&text.replace('"', "\"\""));
+                    out.push('"');
+                }
+                ExportFormat::Csv => out.push_str(&text),
+                ExportFormat::Tsv => out.push_str(&text.replace(['\t', '\n', '\r'], " ")),
+            }
+        }
+        out.push('\n');
+    }
+}
+
+impl Table<'_> {
+    /// Exports the header (if any) and all the rows of the table in the given format.
+    ///
+    /// Only the text content of the cells is exported, styles are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{prelude::*, widgets::*};
+    ///
+    /// let table = Table::new(
+    ///     vec![Row::new(vec!["Alice", "Paris, France"])],
+    ///     [Constraint::Length(10); 2],
+    /// )
+    /// .header(Row::new(vec!["Name", "Address"]));
+    /// assert_eq!(
+    ///     table.export(ExportFormat::Csv),
+    ///     "Name,Address\nAlice,\"Paris, France\"\n"
+    /// );
+    /// ```
+    pub fn export(&self, format: ExportFormat) -> String {
+        let mut out = String::new();
+        for row in self.header.iter().chain(&self.rows) {
+            format.write_row(row, &mut out);
+        }
+        out
+    }
+
+    /// Exports the header (if any) and the selected row of the table in the given format.
+    ///
+    /// Returns `None` if no row is selected.
+    pub fn export_selected(&self, state: &TableState, format: ExportFormat) -> Option<String> {
+        let row = self.rows.get(state.selected()?)?;
+        let mut out = String::new();
+        for row in self.header.iter().chain([row]) {
+            format.write_row(row, &mut out);
+        }
+        Some(out)
+    }
+
+    /// Copies the selected row of the table to the system clipboard as tab separated values,
+    /// without the header, so that it can be pasted into a spreadsheet.
+    ///
+    /// Returns `Ok(false)` if no row is selected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the system clipboard can't be accessed.
+    #[cfg(feature = "clipboard")]
+    pub fn copy_selected(&self, state: &TableState) -> std::io::Result<bool> {
+        let Some(row) = state.selected().and_then(|i| self.rows.get(i)) else {
+            return Ok(false);
+        };
+        let mut text = String::new();
+        ExportFormat::Tsv.write_row(row, &mut text);
+        text.pop();
+        arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.set_text(text))
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{layout::Constraint, style::Stylize, text::Line, widgets::Cell};
+
+    fn table() -> Table<'static> {
+        Table::new(
+            vec![
+                Row::new(vec![Cell::from("a"), Cell::from("b".red())]),
+                Row::new(vec!["say \"hi\"", "1,2"]),
+                Row::new(vec![Cell::from(vec![
+                    Line::from("two"),
+                    Line::from("lines"),
+                ])]),
+                Row::new(vec!["tab\there", "x"]),
+            ],
+            [Constraint::Length(5); 2],
+        )
+        .header(Row::new(vec!["h1", "h2"]))
+    }
+
+    #[test]
+    fn export_csv() {
+        assert_eq!(
+            table().export(ExportFormat::Csv),
+            "h1,h2\na,b\n\"say \"\"hi\"\"\",\"1,2\"\n\"two\nlines\"\ntab\there,x\n"
+        );
+    }
+
+    #[test]
+    fn export_tsv() {
+        assert_eq!(
+            table().export(ExportFormat::Tsv),
+            "h1\th2\na\tb\nsay \"hi\"\t1,2\ntwo lines\ntab here\tx\n"
+        );
+    }
+
+    #[test]
+    fn export_without_header() {
+        let table = Table::new(vec![Row::new(vec!["a", "b"])], [Constraint::Length(1); 2]);
+        assert_eq!(table.export(ExportFormat::Csv), "a,b\n");
+    }
+
+    #[test]
+    fn export_selected() {
+        let table = table();
+        let mut state = TableState::default();
+        assert_eq!(table.export_selected(&state, ExportFormat::Csv), None);
+        state.select(Some(1));
+        assert_eq!(
+            table.export_selected(&state, ExportFormat::Tsv),
+            Some("h1\th2\nsay \"hi\"\t1,2\n".to_string())
+        );
+        state.select(Some(10));
+        assert_eq!(table.export_selected(&state, ExportFormat::Csv), None);
+    }
+}
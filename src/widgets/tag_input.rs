@@ -0,0 +1,504 @@
+#![deny(missing_docs)]
+use crate::{
+    buffer::Buffer,
+    input::{Focusable, Input, Key, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent},
+    layout::{Position, Rect},
+    style::{Color, Style, Styled},
+    terminal::RedrawRequest,
+    text::grapheme_width,
+    widgets::{Block, CursorProvider, StatefulWidget, TextInput, TextInputState, Widget},
+};
+
+const DEFAULT_TAG_STYLE: Style = Style::new().fg(Color::Black).bg(Color::Gray);
+
+/// The smallest width left to the text after the tags of the last row, below which the text
+/// starts on a new row.
+const MIN_TEXT_WIDTH: usize = 8;
+
+/// An input turning the typed text into a list of tags, e.g. the labels of an issue or the
+/// recipients of an email.
+///
+/// The tags and the text being typed are stored in a [`TagInputState`], which handles the
+/// keyboard:
+/// - `Enter` and `,` turn the text into a tag, drawn as a chip with the
+///   [`tag_style`](TagInput::tag_style).
+/// - `Backspace` with the cursor at the start of the text deletes the last tag.
+/// - Pasted text is split on commas, every part but the last one becoming a tag.
+/// - The other keys edit the text as in a [`TextInput`].
+///
+/// The chips are laid out from left to right and wrap onto as many rows as needed, followed by the
+/// text. [`TagInputState::height`] returns the number of rows for a given width, and when the
+/// area is not tall enough, the first rows are hidden so that the text stays visible.
+///
+/// While the [`input`](TagInput::input) is [read-only](TextInput::read_only) or
+/// [disabled](TextInput::disabled), the tags can neither be added nor deleted, and the chips of
+/// a disabled input are dimmed along with its text.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     input::{Input, Key},
+///     prelude::*,
+///     widgets::*,
+/// };
+///
+/// # fn ui(frame: &mut Frame, state: &mut TagInputState) {
+/// let input = TagInput::new().block(Block::default().borders(Borders::ALL).title("Labels"));
+/// let height = state.height(18) + 2;
+/// frame.render_stateful_widget(input, Rect::new(0, 0, 20, height), state);
+/// frame.set_cursor_for(state);
+/// # }
+/// let mut state = TagInputState::default();
+/// state.handle_paste("bug,ui");
+/// state.handle_key(Key::Enter.into());
+/// assert_eq!(state.tags(), ["bug", "ui"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TagInput<'a> {
+    block: Option<Block<'a>>,
+    input: TextInput<'a>,
+    style: Style,
+    tag_style: Style,
+}
+
+impl<'a> Default for TagInput<'a> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            input: TextInput::new(),
+            style: Style::default(),
+            tag_style: DEFAULT_TAG_STYLE,
+        }
+    }
+}
+
+impl<'a> TagInput<'a> {
+    /// Creates a new tag input.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Surrounds the input with a block.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the input rendering the text typed after the tags, e.g. to set its placeholder or
+    /// cursor style.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn input(mut self, input: TextInput<'a>) -> Self {
+        self.input = input;
+        self
+    }
+
+    /// Sets the style of the area of the input.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the chips of the tags. Defaults to black text on a gray background.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn tag_style(mut self, style: Style) -> Self {
+        self.tag_style = style;
+        self
+    }
+}
+
+impl<'a> Styled for TagInput<'a> {
+    type Item = TagInput<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// The state of a [`TagInput`]: its tags, and the [`TextInputState`] of the text being typed.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TagInputState {
+    tags: Vec<String>,
+    input: TextInputState,
+}
+
+/// The positions of the chips and of the text of a [`TagInput`], relative to its area.
+struct TagLayout {
+    /// Column, row and width of each chip
+    chips: Vec<(usize, usize, usize)>,
+    /// Column and row where the text starts
+    text: (usize, usize),
+}
+
+impl TagInputState {
+    /// Creates a new state with the given tags.
+    pub fn new<I>(tags: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let mut state = Self::default();
+        state.set_tags(tags);
+        state
+    }
+
+    /// Returns the tags.
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Replaces the tags. Blank and duplicate tags are ignored, as when they are typed.
+    pub fn set_tags<I>(&mut self, tags: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let previous = std::mem::take(&mut self.tags);
+        for tag in tags {
+            self.push_tag(tag.into());
+        }
+        RedrawRequest::request_if(self.tags != previous);
+    }
+
+    /// Consumes the state and returns the tags.
+    pub fn into_tags(self) -> Vec<String> {
+        self.tags
+    }
+
+    /// Adds a tag after the others, without its surrounding whitespace. Returns `false` if the
+    /// tag is blank or already present.
+    pub fn push_tag<T>(&mut self, tag: T) -> bool
+    where
+        T: AsRef<str>,
+    {
+        let tag = tag.as_ref().trim();
+        if tag.is_empty() || self.tags.iter().any(|t| t == tag) {
+            return false;
+        }
+        self.tags.push(tag.to_string());
+        RedrawRequest::request_if(true)
+    }
+
+    /// Removes and returns the tag at the given index, if any.
+    pub fn remove_tag(&mut self, index: usize) -> Option<String> {
+        let tag = (index < self.tags.len()).then(|| self.tags.remove(index));
+        RedrawRequest::request_if(tag.is_some());
+        tag
+    }
+
+    /// Removes and returns the last tag, if any.
+    pub fn pop_tag(&mut self) -> Option<String> {
+        let tag = self.tags.pop();
+        RedrawRequest::request_if(tag.is_some());
+        tag
+    }
+
+    /// Turns the text into a tag and clears it. Returns `true` if a tag was added.
+    ///
+    /// The text is cleared even if it is blank or a duplicate tag.
+    pub fn commit(&mut self) -> bool {
+        if self.input.value().is_empty() {
+            return false;
+        }
+        let tag = self.input.value().to_string();
+        self.input.clear();
+        RedrawRequest::request_if(true);
+        self.push_tag(tag)
+    }
+
+    /// Returns the state of the text being typed.
+    pub fn input(&self) -> &TextInputState {
+        &self.input
+    }
+
+    /// Returns the state of the text being typed, e.g. to set its maximum length.
+    pub fn input_mut(&mut self) -> &mut TextInputState {
+        &mut self.input
+    }
+
+    /// Returns `true` if the input has the focus.
+    pub fn is_focused(&self) -> bool {
+        self.input.is_focused()
+    }
+
+    /// Sets whether the input has the focus, showing the cursor instead of the placeholder.
+    pub fn set_focused(&mut self, focused: bool) {
+        self.input.set_focused(focused);
+    }
+
+    /// Returns `true` if the text was neither read-only nor disabled during the last render.
+    fn is_editable(&self) -> bool {
+        !self.input.is_read_only() && !self.input.is_disabled()
+    }
+
+    /// Returns the number of rows taken by the tags and the text in the given width, excluding
+    /// the block.
+    pub fn height(&self, width: u16) -> u16 {
+        let (_, row) = self.layout(width).text;
+        row.saturating_add(1).min(usize::from(u16::MAX)) as u16
+    }
+
+    /// Lays out the chips from left to right, starting a new row when a chip doesn't fit, and the
+    /// text after them.
+    fn layout(&self, width: u16) -> TagLayout {
+        let width = usize::from(width).max(1);
+        let (mut x, mut row) = (0, 0);
+        let mut chips = Vec::with_capacity(self.tags.len());
+        for tag in &self.tags {
+            // a space on each side of the tag, and the chips wider than the area are truncated
+            let chip_width = (grapheme_width(tag) + 2).min(width);
+            if x > 0 && x + chip_width > width {
+                (x, row) = (0, row + 1);
+            }
+            chips.push((x, row, chip_width));
+            x += chip_width + 1;
+        }
+        if x > 0 && x + MIN_TEXT_WIDTH.min(width) > width {
+            (x, row) = (0, row + 1);
+        }
+        TagLayout {
+            chips,
+            text: (x, row),
+        }
+    }
+}
+
+impl Focusable for TagInputState {
+    fn set_focused(&mut self, focused: bool) {
+        TagInputState::set_focused(self, focused);
+    }
+
+    fn is_disabled(&self) -> bool {
+        self.input.is_disabled()
+    }
+}
+
+impl Input for TagInputState {
+    /// Turns the text into a tag on `Enter` or `,`, and deletes the last tag on `Backspace` with
+    /// the cursor at the start of the text. The other keys are handled by the text.
+    ///
+    /// `Enter` is not handled while the text is empty, so that the application can submit the
+    /// tags.
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release {
+            return false;
+        }
+        let plain = self.is_editable()
+            && !key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+        match key.key {
+            Key::Enter if plain => {
+                if self.input.value().is_empty() {
+                    return false;
+                }
+                self.commit();
+                true
+            }
+            Key::Char(',') if plain => {
+                self.commit();
+                true
+            }
+            Key::Backspace
+                if plain && self.input.cursor() == 0 && self.input.selection().is_none() =>
+            {
+                self.pop_tag().is_some()
+            }
+            _ => self.input.handle_key(key),
+        }
+    }
+
+    /// Turns every comma separated part of the text but the last one into a tag, the last one
+    /// being inserted at the cursor.
+    fn handle_paste(&mut self, text: &str) -> bool {
+        if !self.is_editable() {
+            return false;
+        }
+        let mut parts = text.split(',');
+        let Some(last) = parts.next_back() else {
+            return false;
+        };
+        let mut handled = false;
+        for part in parts {
+            self.input.insert_str(part);
+            handled |= self.commit();
+        }
+        handled | self.input.handle_paste(last)
+    }
+
+    fn handle_preedit(&mut self, text: Option<&str>) -> bool {
+        self.input.handle_preedit(text)
+    }
+
+    fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        self.input.handle_mouse(mouse)
+    }
+}
+
+impl CursorProvider for TagInputState {
+    fn cursor_position(&self) -> Option<Position> {
+        self.input.cursor_position()
+    }
+}
+
+impl<'a> StatefulWidget for TagInput<'a> {
+    type State = TagInputState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.input.apply_flags(&mut state.input);
+        buf.set_style(area, self.style);
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        if area.is_empty() {
+            return;
+        }
+        let layout = state.layout(area.width);
+        // hide the first rows rather than the text when the area is too short
+        let (text_x, text_row) = layout.text;
+        let hidden_rows = (text_row + 1).saturating_sub(usize::from(area.height));
+        for (tag, &(x, row, width)) in state.tags.iter().zip(&layout.chips) {
+            if row < hidden_rows {
+                continue;
+            }
+            let x = area.x + x as u16;
+            let y = area.y + (row - hidden_rows) as u16;
+            buf.set_style(Rect::new(x, y, width as u16, 1), self.tag_style);
+            if x + 1 < area.right() {
+                buf.set_stringn(x + 1, y, tag, width.saturating_sub(2), Style::default());
+            }
+        }
+        if let Some(style) = self.input.disabled_patch() {
+            buf.set_style(area, style);
+        }
+        let text_area = Rect {
+            x: area.x + text_x as u16,
+            y: area.y + (text_row - hidden_rows) as u16,
+            width: area.width - text_x as u16,
+            height: 1,
+        };
+        StatefulWidget::render(self.input, text_area, buf, &mut state.input);
+    }
+}
+
+impl<'a> Widget for TagInput<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = TagInputState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_buffer_eq, style::Modifier};
+
+    #[test]
+    fn commits_tags() {
+        let mut state = TagInputState::default();
+        for c in "bug,ui".chars() {
+            assert!(state.handle_key(Key::Char(c).into()));
+        }
+        assert_eq!(state.tags(), ["bug"]);
+        assert_eq!(state.input().value(), "ui");
+        assert!(state.handle_key(Key::Enter.into()));
+        assert!(!state.handle_key(Key::Enter.into()));
+        assert_eq!(state.tags(), ["bug", "ui"]);
+
+        // blank and duplicate tags are ignored
+        state.handle_paste(" , bug,docs");
+        assert_eq!(state.tags(), ["bug", "ui"]);
+        assert_eq!(state.input().value(), "docs");
+    }
+
+    #[test]
+    fn backspace_deletes_the_last_tag() {
+        let mut state = TagInputState::new(["bug", "ui"]);
+        state.handle_paste("a");
+        assert!(state.handle_key(Key::Backspace.into()));
+        assert_eq!(state.tags(), ["bug", "ui"]);
+        assert!(state.handle_key(Key::Backspace.into()));
+        assert_eq!(state.tags(), ["bug"]);
+        assert!(state.handle_key(Key::Backspace.into()));
+        assert!(!state.handle_key(Key::Backspace.into()));
+        assert_eq!(state.into_tags(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn render_wraps_the_tags() {
+        let mut state = TagInputState::new(["bug", "feature", "ui"]);
+        assert_eq!(state.height(16), 2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 2));
+        let input = TagInput::new().input(TextInput::new().cursor_style(Style::new()));
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec![" bug   feature  ", " ui             "]);
+        expected.set_style(Rect::new(0, 0, 5, 1), DEFAULT_TAG_STYLE);
+        expected.set_style(Rect::new(6, 0, 9, 1), DEFAULT_TAG_STYLE);
+        expected.set_style(Rect::new(0, 1, 4, 1), DEFAULT_TAG_STYLE);
+        assert_buffer_eq!(buf, expected);
+        assert_eq!(state.cursor_position(), Some(Position::new(5, 1)));
+    }
+
+    #[test]
+    fn render_hides_the_first_rows() {
+        let mut state = TagInputState::new(["one", "two", "three"]);
+        // the text doesn't fit after the last tag
+        assert_eq!(state.height(10), 4);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 2));
+        let bold = Style::new().add_modifier(Modifier::BOLD);
+        let input = TagInput::new()
+            .tag_style(bold)
+            .input(TextInput::new().cursor_style(Style::new()));
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec![" three    ", "          "]);
+        expected.set_style(Rect::new(0, 0, 7, 1), bold);
+        assert_buffer_eq!(buf, expected);
+        assert_eq!(state.cursor_position(), Some(Position::new(0, 1)));
+    }
+
+    #[test]
+    fn render_one_column() {
+        let mut state = TagInputState::new(["bug"]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 2));
+        let input = TagInput::new().input(TextInput::new().cursor_style(Style::new()));
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec![" ", " "]);
+        expected.set_style(Rect::new(0, 0, 1, 1), DEFAULT_TAG_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn read_only_and_disabled() {
+        let mut state = TagInputState::new(["bug"]);
+        state.handle_paste("ui");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let input = TagInput::new().input(TextInput::new().read_only(true));
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        assert!(!state.handle_key(Key::Enter.into()));
+        assert!(!state.handle_key(Key::Char(',').into()));
+        assert!(!state.handle_paste("docs,"));
+        state.input_mut().move_home();
+        assert!(!state.handle_key(Key::Backspace.into()));
+        assert_eq!(state.tags(), ["bug"]);
+
+        let mut buf = Buffer::empty(buf.area);
+        let input = TagInput::new().input(TextInput::new().disabled(true));
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        assert!(Focusable::is_disabled(&state));
+        assert!(buf
+            .content
+            .iter()
+            .all(|cell| cell.modifier.contains(Modifier::DIM)));
+    }
+}
@@ -0,0 +1,369 @@
+#![deny(missing_docs)]
+use strum::{Display, EnumString};
+
+use crate::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    symbols::spinner,
+    text::{Line, Span, Text},
+    widgets::{LineGauge, StatefulWidget, Widget},
+};
+
+/// The maximum width of the gauge shown after the title of running tasks.
+const MAX_GAUGE_WIDTH: u16 = 20;
+
+/// The number of columns the captured output of a task is indented by.
+const OUTPUT_INDENT: u16 = 4;
+
+/// The status of a [`Task`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TaskStatus {
+    /// The task has not started yet.
+    #[default]
+    Pending,
+    /// The task is running.
+    Running,
+    /// The task completed successfully.
+    Ok,
+    /// The task failed.
+    Failed,
+    /// The task was skipped.
+    Skipped,
+}
+
+impl TaskStatus {
+    /// Returns the glyph shown before the title of a task with this status.
+    ///
+    /// Running tasks show a spinner instead, see [`TaskListState::tick`].
+    pub const fn glyph(self) -> &'static str {
+        match self {
+            TaskStatus::Pending => "○",
+            TaskStatus::Running => "●",
+            TaskStatus::Ok => "✔",
+            TaskStatus::Failed => "✖",
+            TaskStatus::Skipped => "⊘",
+        }
+    }
+
+    const fn default_style(self) -> Style {
+        match self {
+            TaskStatus::Pending => Style::new(),
+            TaskStatus::Running => Style::new().fg(Color::Yellow),
+            TaskStatus::Ok => Style::new().fg(Color::Green),
+            TaskStatus::Failed => Style::new().fg(Color::Red),
+            TaskStatus::Skipped => Style::new().fg(Color::DarkGray),
+        }
+    }
+}
+
+/// An entry of a [`TaskList`].
+///
+/// A task has a title, a [`TaskStatus`], an optional progress shown as a gauge while it is
+/// running, and optional captured output (e.g. the log of a failed step) which is shown below
+/// the title when the task is [`expanded`](Task::expanded).
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Task<'a> {
+    title: Line<'a>,
+    status: TaskStatus,
+    progress: Option<f64>,
+    output: Text<'a>,
+    expanded: bool,
+}
+
+impl<'a> Task<'a> {
+    /// Creates a new pending task with the given title.
+    pub fn new<T>(title: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        Self {
+            title: title.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the status of the task.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn status(mut self, status: TaskStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Sets the progress of the task, shown as a gauge while the task is running.
+    ///
+    /// The ratio is clamped between 0 and 1. Running tasks without progress show a spinner.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn progress(mut self, ratio: f64) -> Self {
+        self.progress = Some(ratio.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Sets the captured output of the task.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn output<T>(mut self, output: T) -> Self
+    where
+        T: Into<Text<'a>>,
+    {
+        self.output = output.into();
+        self
+    }
+
+    /// Sets whether the captured output of the task is shown. Defaults to `false`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Returns the number of lines the task takes when rendered.
+    pub fn height(&self) -> usize {
+        if self.expanded {
+            1 + self.output.height()
+        } else {
+            1
+        }
+    }
+}
+
+/// A widget displaying the progress of a list of tasks, like the steps of an installer or a CI
+/// pipeline.
+///
+/// Each [`Task`] is shown on one line with a glyph for its [`TaskStatus`] and its title. Running
+/// tasks show an animated spinner, or a gauge when their progress is known. The captured output
+/// of expanded tasks is shown indented below their title.
+///
+/// The animation of the spinner is driven by the [`TaskListState`]: call
+/// [`TaskListState::tick`] regularly (e.g. on every frame) to advance it.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut TaskListState) {
+/// let tasks = TaskList::new(vec![
+///     Task::new("Download").status(TaskStatus::Ok),
+///     Task::new("Extract").status(TaskStatus::Running).progress(0.4),
+///     Task::new("Configure"),
+///     Task::new("Test")
+///         .status(TaskStatus::Failed)
+///         .output("assertion failed")
+///         .expanded(true),
+/// ]);
+/// frame.render_stateful_widget(tasks, frame.size(), state);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskList<'a> {
+    tasks: Vec<Task<'a>>,
+    style: Style,
+    status_styles: [Style; 5],
+    output_style: Style,
+}
+
+impl<'a> TaskList<'a> {
+    /// Creates a new task list.
+    pub fn new(tasks: Vec<Task<'a>>) -> Self {
+        Self {
+            tasks,
+            style: Style::default(),
+            status_styles: [
+                TaskStatus::Pending.default_style(),
+                TaskStatus::Running.default_style(),
+                TaskStatus::Ok.default_style(),
+                TaskStatus::Failed.default_style(),
+                TaskStatus::Skipped.default_style(),
+            ],
+            output_style: Style::new().add_modifier(Modifier::DIM),
+        }
+    }
+
+    /// Sets the style of the whole widget.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the glyph (and gauge) of the tasks with the given status.
+    ///
+    /// By default running tasks are yellow, successful tasks green, failed tasks red and skipped
+    /// tasks dark gray.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn status_style(mut self, status: TaskStatus, style: Style) -> Self {
+        self.status_styles[status as usize] = style;
+        self
+    }
+
+    /// Sets the style of the captured output. Defaults to dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn output_style(mut self, style: Style) -> Self {
+        self.output_style = style;
+        self
+    }
+}
+
+/// The state of a [`TaskList`], holding the current frame of the spinner animation.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct TaskListState {
+    frame: usize,
+}
+
+impl TaskListState {
+    /// Advances the spinner animation of running tasks by one frame.
+    pub fn tick(&mut self) {
+        self.frame = self.frame.wrapping_add(1);
+    }
+}
+
+impl<'a> StatefulWidget for TaskList<'a> {
+    type State = TaskListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        if area.is_empty() {
+            return;
+        }
+        let mut y = area.top();
+        for task in self.tasks {
+            if y >= area.bottom() {
+                break;
+            }
+            let status_style = self.status_styles[task.status as usize];
+            let glyph = match (task.status, task.progress) {
+                (TaskStatus::Running, None) => {
+                    spinner::BRAILLE[state.frame % spinner::BRAILLE.len()]
+                }
+                (status, _) => status.glyph(),
+            };
+            let glyph = Span::styled(format!("{glyph} "), status_style);
+            let (x, _) = buf.set_span(area.x, y, &glyph, area.width);
+            let (x, _) = buf.set_line(x, y, &task.title, area.right() - x);
+            if let (TaskStatus::Running, Some(ratio)) = (task.status, task.progress) {
+                let gauge_x = x.saturating_add(1);
+                let width = area.right().saturating_sub(gauge_x).min(MAX_GAUGE_WIDTH);
+                LineGauge::default()
+                    .ratio(ratio)
+                    .gauge_style(status_style)
+                    .render(Rect::new(gauge_x, y, width, 1), buf);
+            }
+            y += 1;
+            if !task.expanded {
+                continue;
+            }
+            let output_x = area.x.saturating_add(OUTPUT_INDENT).min(area.right());
+            for line in &task.output.lines {
+                if y >= area.bottom() {
+                    break;
+                }
+                buf.set_style(
+                    Rect::new(output_x, y, area.right() - output_x, 1),
+                    self.output_style,
+                );
+                buf.set_line(output_x, y, line, area.right() - output_x);
+                y += 1;
+            }
+        }
+    }
+}
+
+impl<'a> Widget for TaskList<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = TaskListState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    fn render(list: TaskList, state: &mut TaskListState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        StatefulWidget::render(list, buf.area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn render_statuses() {
+        let list = TaskList::new(vec![
+            Task::new("one").status(TaskStatus::Ok),
+            Task::new("two").status(TaskStatus::Failed),
+            Task::new("three").status(TaskStatus::Skipped),
+            Task::new("four"),
+        ]);
+        let buf = render(list, &mut TaskListState::default(), 10, 4);
+        let mut expected =
+            Buffer::with_lines(vec!["✔ one     ", "✖ two     ", "⊘ three   ", "○ four    "]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().fg(Color::Green));
+        expected.set_style(Rect::new(0, 1, 2, 1), Style::new().fg(Color::Red));
+        expected.set_style(Rect::new(0, 2, 2, 1), Style::new().fg(Color::DarkGray));
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_spinner() {
+        let list = || {
+            TaskList::new(vec![Task::new("build").status(TaskStatus::Running)])
+                .status_style(TaskStatus::Running, Style::new())
+        };
+        let mut state = TaskListState::default();
+        let buf = render(list(), &mut state, 8, 1);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["⠋ build "]));
+        state.tick();
+        let buf = render(list(), &mut state, 8, 1);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["⠙ build "]));
+    }
+
+    #[test]
+    fn render_progress() {
+        let list = TaskList::new(vec![Task::new("copy")
+            .status(TaskStatus::Running)
+            .progress(0.5)])
+        .status_style(TaskStatus::Running, Style::new());
+        let buf = render(list, &mut TaskListState::default(), 18, 1);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["● copy 50% ───────"]));
+    }
+
+    #[test]
+    fn render_empty_area() {
+        let list = TaskList::new(vec![Task::new("copy")
+            .status(TaskStatus::Running)
+            .progress(0.5)]);
+        let buf = render(list, &mut TaskListState::default(), 0, 2);
+        assert_eq!(buf, Buffer::empty(Rect::new(0, 0, 0, 2)));
+    }
+
+    #[test]
+    fn render_expanded_output() {
+        let list = TaskList::new(vec![
+            Task::new("test")
+                .status(TaskStatus::Failed)
+                .output("line 1\nline 2")
+                .expanded(true),
+            Task::new("lint").output("hidden"),
+        ])
+        .status_style(TaskStatus::Failed, Style::new())
+        .output_style(Style::new());
+        let buf = render(list, &mut TaskListState::default(), 12, 5);
+        assert_buffer_eq!(
+            buf,
+            Buffer::with_lines(vec![
+                "✖ test      ",
+                "    line 1  ",
+                "    line 2  ",
+                "○ lint      ",
+                "            ",
+            ])
+        );
+    }
+
+    #[test]
+    fn task_height() {
+        let task = Task::new("a").output("1\n2\n3");
+        assert_eq!(task.height(), 1);
+        assert_eq!(task.expanded(true).height(), 4);
+    }
+}
@@ -0,0 +1,3919 @@
+#![deny(missing_docs)]
+use std::{
+    borrow::Cow,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Range,
+    rc::Rc,
+};
+
+use strum::{Display, EnumString};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    buffer::Buffer,
+    input::{
+        EditCommand, Focusable, Input, Key, KeyEvent, KeyEventKind, KeyModifiers, Keymap, KillRing,
+        MouseButton, MouseEvent, MouseEventKind,
+    },
+    layout::{Position, Rect, Viewport},
+    style::{Color, Modifier, Style, Styled},
+    terminal::RedrawRequest,
+    text::{grapheme_width, Line},
+    widgets::{history::History, words, Block, CursorProvider, StatefulWidget, Widget},
+};
+
+use self::{lines::Lines, wrap::WrapIndex};
+
+mod diagnostic;
+mod highlight;
+mod lines;
+#[cfg(feature = "vim")]
+mod vim;
+mod wrap;
+
+#[cfg(feature = "syntect")]
+pub use self::highlight::SyntectHighlighter;
+#[cfg(feature = "vim")]
+pub use self::vim::VimMode;
+pub use self::{
+    diagnostic::{Diagnostic, Severity},
+    highlight::Highlighter,
+};
+
+const DEFAULT_CURSOR_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_SELECTION_STYLE: Style = Style::new().bg(Color::DarkGray);
+const DEFAULT_MATCH_STYLE: Style = Style::new().fg(Color::Black).bg(Color::Yellow);
+const DEFAULT_PREEDIT_STYLE: Style = Style::new().add_modifier(Modifier::UNDERLINED);
+const DEFAULT_PLACEHOLDER_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+const DEFAULT_LINE_NUMBER_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+const DEFAULT_DISABLED_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+const DEFAULT_WHITESPACE_STYLE: Style = Style::new().fg(Color::DarkGray);
+const DEFAULT_COLUMN_GUIDE_STYLE: Style = Style::new().fg(Color::DarkGray);
+const DEFAULT_COLUMN_GUIDE_SYMBOL: &str = "│";
+
+/// A position in the text, as a line index and a byte offset in that line.
+type Pos = (usize, usize);
+
+/// A multi-line text editor.
+///
+/// The text, the cursor and the vertical scroll offset of the editor are stored in a
+/// [`TextAreaState`], which provides the editing operations. Like [`TextInput`], all the
+/// operations work on grapheme clusters.
+///
+/// Lines longer than the render area are soft wrapped at word boundaries (or anywhere when a
+/// word doesn't fit on a line), and the viewport scrolls vertically to keep the cursor visible.
+/// The position of the cursor on screen is available with
+/// [`TextAreaState::cursor_screen_position`] after rendering.
+///
+/// The cursor moves up and down by line and to the start and end of its line, unless the
+/// [`wrap_navigation`](TextArea::wrap_navigation) is [`WrapNavigation::Visual`]: it then moves by
+/// row on screen, as most graphical editors do with soft wrapped lines.
+///
+/// Moving the cursor with `Shift` held (see [`Keymap`]) selects the text between the cursor and
+/// the position where the selection started, drawn with the
+/// [`selection_style`](TextArea::selection_style). Typing, pasting and deleting then replace the
+/// selected text, and [`TextAreaState::take_selection`] cuts it.
+///
+/// Clicking the text area with the left mouse button moves the cursor to the clicked cell, and
+/// dragging selects text, scrolling when the mouse leaves the text area. The mouse wheel scrolls
+/// the viewport without moving the cursor, see [`Input::handle_mouse`].
+///
+/// The matches of a [search](TextAreaState::search) are drawn with the
+/// [`match_style`](TextArea::match_style), and [`TextAreaState::next_match`] moves the cursor to
+/// the next one, scrolling the viewport to it on the next render.
+///
+/// A [`Highlighter`] set with [`highlighter`](TextArea::highlighter) styles each line before it
+/// is rendered, e.g. to highlight the syntax of some code.
+///
+/// The [`Diagnostic`]s of a linter or a spell checker, set with
+/// [`TextAreaState::set_diagnostics`], are underlined with the
+/// [`diagnostic_style`](TextArea::diagnostic_style) of their [`Severity`], and
+/// [`TextAreaState::next_diagnostic`] moves the cursor to the next one.
+///
+/// The [`line_numbers`](TextArea::line_numbers) can be shown in a gutter on the left of the
+/// text, as wide as the number of the last line.
+///
+/// For code or commit messages, a [`column_guide`](TextArea::column_guide) shows where the lines
+/// should end, and the trailing whitespace, the tabs and the line breaks can be made visible with
+/// the symbols of a [`Whitespace`]. A tab always takes one column.
+///
+/// The state can also close brackets and quotes as they are typed, with
+/// [`TextAreaState::set_auto_pairs`], and carry the indentation over to the new lines, with
+/// [`TextAreaState::set_auto_indent`].
+///
+/// A [`placeholder`](TextArea::placeholder) can be shown while the text is empty and the text
+/// area doesn't have the focus (see [`TextAreaState::set_focused`]).
+///
+/// Like a [`TextInput`], a text area can be [`read_only`](TextArea::read_only), rejecting the
+/// edits typed or pasted but not the motions and the selection, or
+/// [`disabled`](TextArea::disabled), rejecting all the input events and restyled with the
+/// [`disabled_style`](TextArea::disabled_style).
+///
+/// [`TextInput`]: crate::widgets::TextInput
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut TextAreaState) {
+/// let editor = TextArea::new().block(Block::default().borders(Borders::ALL).title("Notes"));
+/// frame.render_stateful_widget(editor, frame.size(), state);
+/// # }
+/// let mut state = TextAreaState::default();
+/// state.insert_str("hello\nworld");
+/// state.move_home();
+/// state.delete_before();
+/// assert_eq!(state.value(), "helloworld");
+/// ```
+#[derive(Clone)]
+pub struct TextArea<'a> {
+    block: Option<Block<'a>>,
+    style: Style,
+    cursor_style: Style,
+    selection_style: Style,
+    match_style: Style,
+    preedit_style: Style,
+    placeholder: Option<Line<'a>>,
+    placeholder_style: Style,
+    wrap_navigation: WrapNavigation,
+    highlighter: Option<Rc<dyn Highlighter + 'a>>,
+    diagnostic_styles: [Style; Severity::COUNT],
+    line_numbers: LineNumbers,
+    line_number_style: Style,
+    whitespace: Whitespace<'a>,
+    whitespace_style: Style,
+    column_guide: Option<usize>,
+    column_guide_symbol: &'a str,
+    column_guide_style: Style,
+    read_only: bool,
+    disabled: bool,
+    disabled_style: Style,
+}
+
+impl<'a> fmt::Debug for TextArea<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextArea")
+            .field("block", &self.block)
+            .field("style", &self.style)
+            .field("cursor_style", &self.cursor_style)
+            .field("selection_style", &self.selection_style)
+            .field("match_style", &self.match_style)
+            .field("preedit_style", &self.preedit_style)
+            .field("placeholder", &self.placeholder)
+            .field("placeholder_style", &self.placeholder_style)
+            .field("wrap_navigation", &self.wrap_navigation)
+            .field("highlighter", &self.highlighter.as_ref().map(|_| ".."))
+            .field("diagnostic_styles", &self.diagnostic_styles)
+            .field("line_numbers", &self.line_numbers)
+            .field("line_number_style", &self.line_number_style)
+            .field("whitespace", &self.whitespace)
+            .field("whitespace_style", &self.whitespace_style)
+            .field("column_guide", &self.column_guide)
+            .field("column_guide_symbol", &self.column_guide_symbol)
+            .field("column_guide_style", &self.column_guide_style)
+            .field("read_only", &self.read_only)
+            .field("disabled", &self.disabled)
+            .field("disabled_style", &self.disabled_style)
+            .finish()
+    }
+}
+
+impl<'a> Default for TextArea<'a> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            style: Style::default(),
+            cursor_style: DEFAULT_CURSOR_STYLE,
+            selection_style: DEFAULT_SELECTION_STYLE,
+            match_style: DEFAULT_MATCH_STYLE,
+            preedit_style: DEFAULT_PREEDIT_STYLE,
+            placeholder: None,
+            placeholder_style: DEFAULT_PLACEHOLDER_STYLE,
+            wrap_navigation: WrapNavigation::default(),
+            highlighter: None,
+            diagnostic_styles: [
+                Severity::Error.default_style(),
+                Severity::Warning.default_style(),
+                Severity::Info.default_style(),
+                Severity::Hint.default_style(),
+            ],
+            line_numbers: LineNumbers::default(),
+            line_number_style: DEFAULT_LINE_NUMBER_STYLE,
+            whitespace: Whitespace::HIDDEN,
+            whitespace_style: DEFAULT_WHITESPACE_STYLE,
+            column_guide: None,
+            column_guide_symbol: DEFAULT_COLUMN_GUIDE_SYMBOL,
+            column_guide_style: DEFAULT_COLUMN_GUIDE_STYLE,
+            read_only: false,
+            disabled: false,
+            disabled_style: DEFAULT_DISABLED_STYLE,
+        }
+    }
+}
+
+impl<'a> TextArea<'a> {
+    /// Creates a new text area.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Surrounds the text area with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the text area.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the cell under the cursor. Defaults to reversed colors.
+    ///
+    /// Use [`Style::default()`] to not draw the cursor, e.g. when showing the terminal cursor
+    /// instead.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn cursor_style(mut self, style: Style) -> Self {
+        self.cursor_style = style;
+        self
+    }
+
+    /// Sets the style of the selected text, see [`TextAreaState::selection`]. Defaults to a dark
+    /// gray background.
+    ///
+    /// The style also applies to the text selected in the visual mode of the vi-style editing.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selection_style(mut self, style: Style) -> Self {
+        self.selection_style = style;
+        self
+    }
+
+    /// Sets the style of the matches of the search, see [`TextAreaState::search`]. Defaults to
+    /// black on yellow.
+    ///
+    /// The selection style takes precedence over this style where they overlap.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn match_style(mut self, style: Style) -> Self {
+        self.match_style = style;
+        self
+    }
+
+    /// Sets the style of the text being composed with an input method, see
+    /// [`TextAreaState::set_preedit`]. Defaults to underlined.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn preedit_style(mut self, style: Style) -> Self {
+        self.preedit_style = style;
+        self
+    }
+
+    /// Sets the text shown while the text is empty and the text area doesn't have the focus.
+    ///
+    /// The placeholder is never part of the text: it disappears as soon as something is typed.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder<T>(mut self, placeholder: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the style of the [`placeholder`](Self::placeholder), patched by the styles of its
+    /// spans. Defaults to dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder_style(mut self, style: Style) -> Self {
+        self.placeholder_style = style;
+        self
+    }
+
+    /// Sets how the cursor moves through the soft wrapped lines. Defaults to
+    /// [`WrapNavigation::Logical`].
+    ///
+    /// The setting is stored in the [`TextAreaState`] when rendering, with the width of the text
+    /// area, so it applies to the moves that follow the first render.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn wrap_navigation(mut self, navigation: WrapNavigation) -> Self {
+        self.wrap_navigation = navigation;
+        self
+    }
+
+    /// Sets the highlighter styling each line of the text before it is rendered.
+    ///
+    /// The styles of the highlighter are patched over the style of the text area, and the
+    /// selection, the matches of the search and the text being composed are drawn over them.
+    /// Highlighters that are expensive to create can be passed by reference.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlighter<H>(mut self, highlighter: H) -> Self
+    where
+        H: Highlighter + 'a,
+    {
+        self.highlighter = Some(Rc::new(highlighter));
+        self
+    }
+
+    /// Sets the style patched over the [`Diagnostic`]s of the given [`Severity`].
+    ///
+    /// Defaults to underlining the text with the color of the severity (red, yellow, blue and
+    /// dark gray), or to drawing it in that color without the `underline-color` feature. The
+    /// selection, the matches of the search and the text being composed are drawn over it.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn diagnostic_style(mut self, severity: Severity, style: Style) -> Self {
+        self.diagnostic_styles[severity as usize] = style;
+        self
+    }
+
+    /// Sets the line numbers shown in a gutter on the left of the text. Defaults to
+    /// [`LineNumbers::None`].
+    ///
+    /// The gutter is as wide as the number of the last line, plus a separating space, and the
+    /// text is wrapped in the remaining width. Only the first row of a wrapped line is numbered.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn line_numbers(mut self, line_numbers: LineNumbers) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Sets the style of the gutter showing the [`line_numbers`](Self::line_numbers). Defaults
+    /// to dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn line_number_style(mut self, style: Style) -> Self {
+        self.line_number_style = style;
+        self
+    }
+
+    /// Makes the text area read-only: the cursor still moves and selects text, which can be
+    /// copied, but the keys and the pasted text editing the text are rejected.
+    ///
+    /// With the vi-style editing, the motions, the visual mode and the yanks still work, while
+    /// the commands changing the text and the insert mode are rejected.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Disables the text area: it rejects all the input events, doesn't draw its cursor and is
+    /// restyled with the [`disabled_style`](Self::disabled_style).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the style patched over the whole text area while it is
+    /// [`disabled`](Self::disabled). Defaults to dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = style;
+        self
+    }
+
+    /// Sets the symbols drawn in place of the trailing whitespace, the tabs and the line breaks.
+    /// Defaults to [`Whitespace::HIDDEN`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn whitespace(mut self, whitespace: Whitespace<'a>) -> Self {
+        self.whitespace = whitespace;
+        self
+    }
+
+    /// Sets the style patched over the symbols of the [`whitespace`](Self::whitespace). Defaults
+    /// to dark gray text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn whitespace_style(mut self, style: Style) -> Self {
+        self.whitespace_style = style;
+        self
+    }
+
+    /// Draws a vertical guide after the given number of columns of text, e.g. `72` for a commit
+    /// message. The guide is drawn in the empty cells of its column, so that the text going past
+    /// it stays readable.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_guide(mut self, column: usize) -> Self {
+        self.column_guide = Some(column);
+        self
+    }
+
+    /// Sets the symbol of the [`column_guide`](Self::column_guide). Defaults to `│`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_guide_symbol(mut self, symbol: &'a str) -> Self {
+        self.column_guide_symbol = symbol;
+        self
+    }
+
+    /// Sets the style of the [`column_guide`](Self::column_guide). Defaults to dark gray text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_guide_style(mut self, style: Style) -> Self {
+        self.column_guide_style = style;
+        self
+    }
+
+    /// Draws the column guide in the given row of the text area, unless the text drawn in the row
+    /// reaches its column.
+    fn render_column_guide(&self, buf: &mut Buffer, area: Rect, y: u16, text_end: u16) {
+        let Some(column) = self.column_guide else {
+            return;
+        };
+        let Some(x) = u16::try_from(column)
+            .ok()
+            .and_then(|column| area.x.checked_add(column))
+        else {
+            return;
+        };
+        if x >= text_end && x < area.right() {
+            buf.set_stringn(x, y, self.column_guide_symbol, 1, self.column_guide_style);
+        }
+    }
+
+    /// Renders the cursors besides the main one, which the terminal cursor can't show, in the
+    /// visible rows.
+    fn render_other_cursors(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        state: &TextAreaState,
+        lines: &Lines,
+        rows: &[WrappedRow],
+        preedit_len: usize,
+    ) {
+        for cursor in &state.cursors {
+            let (line, mut col) = state.snap_position(cursor.position);
+            if line == state.row && col >= state.col {
+                col += preedit_len;
+            }
+            // the row containing the cursor, or the last row of its line at the end of the line
+            let len = lines[line].len();
+            let Some(y) = rows.iter().rposition(|row| {
+                row.line == line && row.start <= col && (col < row.end || row.end == len)
+            }) else {
+                continue;
+            };
+            let x = text_width(&lines[line][rows[y].start..col]).min(area.width as usize - 1);
+            let cell = Rect::new(area.x + x as u16, area.y + y as u16, 1, 1);
+            buf.set_style(cell, self.cursor_style);
+        }
+    }
+
+    /// Draws the number of a line in the gutter, relative to the line of the cursor if needed.
+    fn render_line_number(
+        &self,
+        buf: &mut Buffer,
+        gutter: Rect,
+        y: u16,
+        line: usize,
+        cursor: usize,
+    ) {
+        let number = match self.line_numbers {
+            LineNumbers::None => return,
+            LineNumbers::Relative if line != cursor => line.abs_diff(cursor),
+            LineNumbers::Absolute | LineNumbers::Relative => line + 1,
+        };
+        let digits = usize::from(gutter.width.saturating_sub(1));
+        let number = format!("{number:>digits$} ");
+        buf.set_stringn(gutter.x, y, number, gutter.width.into(), Style::default());
+    }
+}
+
+/// The line numbers shown in the gutter of a [`TextArea`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum LineNumbers {
+    /// No gutter is shown.
+    #[default]
+    None,
+    /// Each line shows its number, starting from 1.
+    Absolute,
+    /// Each line shows its distance to the line of the cursor, which shows its own number, as
+    /// with `relativenumber` and `number` in vi.
+    Relative,
+}
+
+impl LineNumbers {
+    /// Returns the width of the gutter for the given number of lines: the digits of the last
+    /// line number and a separating space.
+    fn gutter_width(self, lines: usize) -> u16 {
+        match self {
+            LineNumbers::None => 0,
+            LineNumbers::Absolute | LineNumbers::Relative => lines.to_string().len() as u16 + 1,
+        }
+    }
+}
+
+/// The symbols drawn by a [`TextArea`] in place of some whitespace, see
+/// [`TextArea::whitespace`]. A `None` symbol leaves that whitespace as it is.
+///
+/// The symbols should take one column, as the whitespace they replace.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::{TextArea, Whitespace};
+///
+/// let text_area = TextArea::new().whitespace(Whitespace {
+///     newline: None,
+///     ..Whitespace::VISIBLE
+/// });
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Whitespace<'a> {
+    /// The symbol of the spaces at the end of a line.
+    pub trailing: Option<&'a str>,
+    /// The symbol of the tabs, wherever they are in a line.
+    pub tab: Option<&'a str>,
+    /// The symbol drawn after the end of each line but the last one.
+    pub newline: Option<&'a str>,
+}
+
+impl<'a> Whitespace<'a> {
+    /// Draws the whitespace as it is.
+    pub const HIDDEN: Self = Self {
+        trailing: None,
+        tab: None,
+        newline: None,
+    };
+
+    /// Draws the trailing spaces as `·`, the tabs as `→` and the line breaks as `↵`.
+    pub const VISIBLE: Self = Self {
+        trailing: Some("·"),
+        tab: Some("→"),
+        newline: Some("↵"),
+    };
+
+    /// Returns the symbol drawn in place of a grapheme, if any.
+    fn symbol(&self, grapheme: &str, trailing: bool) -> Option<&'a str> {
+        match grapheme {
+            "\t" => self.tab.or(self.trailing.filter(|_| trailing)),
+            " " => self.trailing.filter(|_| trailing),
+            _ => None,
+        }
+    }
+}
+
+/// How the cursor of a [`TextArea`] moves through the lines that are soft wrapped on screen.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum WrapNavigation {
+    /// `Up` and `Down` move to the previous and next line of the text, `Home` and `End` to the
+    /// start and end of the line, wherever they are on screen.
+    #[default]
+    Logical,
+    /// `Up` and `Down` move to the previous and next row on screen, `Home` and `End` to the start
+    /// and end of the row of the cursor.
+    Visual,
+}
+
+impl<'a> Styled for TextArea<'a> {
+    type Item = TextArea<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// The state of a [`TextArea`].
+///
+/// The state owns the lines of text and the position of the cursor, given as a line index and a
+/// byte offset in that line, which is always on a grapheme boundary. It also keeps the history
+/// of the edits, which can be reverted with [`undo`](Self::undo) and [`redo`](Self::redo).
+///
+/// Texts of a few thousand lines are stored in a vector of lines. Larger texts, e.g. files of
+/// several megabytes, are stored in chunks of lines shared with the history, so that finding a
+/// line, inserting or removing one and recording an edit don't copy or move the whole text.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TextAreaState {
+    lines: Lines,
+    /// Index of the line of the cursor
+    row: usize,
+    /// Byte offset of the cursor in its line
+    col: usize,
+    /// Position where the selection started, the cursor being its other end
+    anchor: Option<Pos>,
+    /// The other cursors, where the commands are also executed
+    cursors: Vec<Cursor>,
+    /// Index of the first visible row, after wrapping
+    offset: usize,
+    /// The pattern searched in the text, with its matches highlighted
+    search: Option<SearchPattern>,
+    /// The ranges reported by a linter or a spell checker, sorted by their start
+    diagnostics: Vec<Diagnostic>,
+    /// Position of the cursor on screen during the last render
+    screen_cursor: Option<Position>,
+    /// Lines and cursor before the previous edits
+    history: History<(Lines, usize, usize)>,
+    /// Text being composed with an input method, displayed at the cursor
+    preedit: Option<String>,
+    /// Whether the text area has the focus, showing the cursor instead of the placeholder
+    focused: bool,
+    /// How the cursor moves through the wrapped lines, set when rendering
+    wrap_navigation: WrapNavigation,
+    /// Width at which the lines were wrapped during the last render
+    width: usize,
+    /// The rows of the lines wrapped during the last render, without the composed text
+    wrap_index: WrapIndex,
+    /// Whether the edits typed or pasted are rejected, set when rendering
+    read_only: bool,
+    /// Whether all the input events are rejected, set when rendering
+    disabled: bool,
+    /// Area of the text during the last render, where the mouse moves the cursor
+    area: Rect,
+    /// Whether text is being selected by dragging the mouse
+    dragging: bool,
+    /// Whether the last edit killed text, which the next kill joins in the kill ring
+    killed: bool,
+    /// The opening and closing characters closed automatically when typed
+    auto_pairs: Vec<(char, char)>,
+    /// Whether a new line starts with the indentation of the line it was split from
+    auto_indent: bool,
+    /// Position of the cursor when the viewport was scrolled away from it, which keeps the
+    /// viewport where it is until the cursor moves
+    scrolled: Option<Pos>,
+    /// The state of the vi-style editing, if enabled
+    #[cfg(feature = "vim")]
+    vim: Option<vim::Vim>,
+}
+
+impl Default for TextAreaState {
+    fn default() -> Self {
+        Self {
+            lines: Lines::default(),
+            row: 0,
+            col: 0,
+            anchor: None,
+            cursors: vec![],
+            offset: 0,
+            search: None,
+            diagnostics: vec![],
+            screen_cursor: None,
+            history: History::default(),
+            preedit: None,
+            focused: true,
+            wrap_navigation: WrapNavigation::default(),
+            width: 0,
+            wrap_index: WrapIndex::default(),
+            read_only: false,
+            disabled: false,
+            area: Rect::default(),
+            dragging: false,
+            killed: false,
+            auto_pairs: vec![],
+            auto_indent: false,
+            scrolled: None,
+            #[cfg(feature = "vim")]
+            vim: None,
+        }
+    }
+}
+
+impl TextAreaState {
+    /// The brackets and quotes usually closed automatically by code editors, for
+    /// [`set_auto_pairs`](Self::set_auto_pairs).
+    pub const AUTO_PAIRS: [(char, char); 6] = [
+        ('(', ')'),
+        ('[', ']'),
+        ('{', '}'),
+        ('"', '"'),
+        ('\'', '\''),
+        ('`', '`'),
+    ];
+
+    /// Creates a new state with the given text and the cursor at its end.
+    pub fn new<T>(value: T) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let mut state = Self::default();
+        state.set_value(value);
+        state
+    }
+
+    /// Returns the text, with lines separated by `\n`.
+    pub fn value(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Returns an iterator over the lines of text. There is always at least one (possibly empty)
+    /// line.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().map(String::as_str)
+    }
+
+    /// Returns the number of lines of text, which is at least one.
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns the line at the given index, if any.
+    pub fn line(&self, index: usize) -> Option<&str> {
+        (index < self.lines.len()).then(|| self.lines[index].as_str())
+    }
+
+    /// Replaces the text and moves the cursor to its end.
+    ///
+    /// Both `\n` and `\r\n` are accepted as line breaks. The edit history is cleared, as the
+    /// previous edits don't apply to the new text.
+    pub fn set_value<T>(&mut self, value: T)
+    where
+        T: AsRef<str>,
+    {
+        self.lines = value.as_ref().split('\n').map(strip_cr).collect();
+        self.move_to_end();
+        self.anchor = None;
+        self.cursors.clear();
+        self.history.clear();
+    }
+
+    /// Clears the text. This can be undone.
+    pub fn clear(&mut self) {
+        self.record(false);
+        self.lines = Lines::default();
+        self.row = 0;
+        self.col = 0;
+        self.anchor = None;
+        self.cursors.clear();
+        self.offset = 0;
+    }
+
+    /// Returns the position of the cursor, as a line index and a byte offset in that line.
+    pub fn cursor(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+
+    /// Moves the cursor to the given line and byte offset.
+    ///
+    /// The position is clamped to the text, and the offset is moved to the start of the
+    /// grapheme containing it. The [other cursors](Self::add_cursor_below) are removed.
+    pub fn set_cursor(&mut self, row: usize, col: usize) {
+        self.history.break_coalescing();
+        self.anchor = None;
+        self.cursors.clear();
+        self.killed = false;
+        self.snap_cursor(row, col);
+    }
+
+    /// Returns the index in its line of the grapheme at the cursor, i.e. the number of graphemes
+    /// before it in the line.
+    ///
+    /// Unlike the byte offset of [`cursor`](Self::cursor), this counts an emoji, a letter with
+    /// combining marks or a wide character as one.
+    pub fn cursor_grapheme_index(&self) -> usize {
+        self.lines[self.row][..self.col].graphemes(true).count()
+    }
+
+    /// Moves the cursor before the grapheme at the given index in its line, or to the end of the
+    /// line.
+    pub fn set_cursor_grapheme_index(&mut self, index: usize) {
+        let (row, col) = self.grapheme_position(self.row, index);
+        self.set_cursor(row, col);
+    }
+
+    /// Returns the column of the cursor in its line, i.e. the width on screen of the graphemes
+    /// before it.
+    ///
+    /// Wide characters take two columns and combining marks none. The column is relative to the
+    /// start of the line: it ignores the soft wrapping and the scrolling of the widget.
+    pub fn visual_cursor_column(&self) -> usize {
+        self.lines[self.row][..self.col]
+            .graphemes(true)
+            .map(column_width)
+            .sum()
+    }
+
+    /// Returns the selected range, from its first position to its last one, between the cursor
+    /// and the position where the selection started, if it isn't empty.
+    ///
+    /// Text is selected by moving the cursor with the `Select*` commands of the [`Keymap`] (e.g.
+    /// `Shift-Left`), and unselected by moving it without them.
+    pub fn selection(&self) -> Option<((usize, usize), (usize, usize))> {
+        let cursor = self.cursor();
+        let anchor = self.anchor.filter(|&anchor| anchor != cursor)?;
+        Some((anchor.min(cursor), anchor.max(cursor)))
+    }
+
+    /// Returns the selected text, with lines separated by `\n`, if any.
+    pub fn selected_text(&self) -> Option<String> {
+        self.selection()
+            .map(|(start, end)| text_between(&self.lines, start, end))
+    }
+
+    /// Selects the whole text, moving the cursor to its end.
+    pub fn select_all(&mut self) {
+        self.move_to_end();
+        self.anchor = Some((0, 0));
+    }
+
+    /// Unselects the text, leaving the cursor where it is.
+    pub fn clear_selection(&mut self) {
+        self.anchor = None;
+    }
+
+    /// Deletes the selected text. Returns `true` if something was deleted.
+    pub fn delete_selection(&mut self) -> bool {
+        if self.selection().is_none() {
+            self.anchor = None;
+            return false;
+        }
+        self.record(false);
+        self.remove_selection();
+        true
+    }
+
+    /// Deletes the selected text and returns it, e.g. to implement a cut to a clipboard of the
+    /// application.
+    pub fn take_selection(&mut self) -> Option<String> {
+        let text = self.selected_text()?;
+        self.delete_selection();
+        Some(text)
+    }
+
+    /// Copies the selected text to the system clipboard.
+    ///
+    /// Returns `Ok(false)` if there is nothing to copy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the system clipboard can't be accessed.
+    #[cfg(feature = "clipboard")]
+    pub fn copy_selection(&self) -> std::io::Result<bool> {
+        let Some(text) = self.selected_text() else {
+            return Ok(false);
+        };
+        arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.set_text(text))
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))?;
+        Ok(true)
+    }
+
+    /// Moves the selected text to the system clipboard.
+    ///
+    /// Returns `Ok(false)` if there is nothing to cut. The text is only deleted once it has been
+    /// copied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the system clipboard can't be accessed.
+    #[cfg(feature = "clipboard")]
+    pub fn cut_selection(&mut self) -> std::io::Result<bool> {
+        let copied = self.copy_selection()?;
+        Ok(copied && self.delete_selection())
+    }
+
+    /// Removes the selected text without recording the edit, and unselects it.
+    fn remove_selection(&mut self) {
+        if let Some((start, end)) = self.selection() {
+            remove_between(&mut self.lines, start, end);
+            (self.row, self.col) = start;
+        }
+        self.anchor = None;
+    }
+
+    /// Applies a cursor motion extending the selection, which starts at the cursor if there is
+    /// none yet.
+    fn extend_selection<F>(&mut self, motion: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let anchor = self.anchor.unwrap_or(self.cursor());
+        motion(self);
+        self.anchor = Some(anchor);
+    }
+
+    fn snap_cursor(&mut self, row: usize, col: usize) {
+        (self.row, self.col) = self.snap_position((row, col));
+    }
+
+    /// Returns the closest position in the text on a grapheme boundary.
+    fn snap_position(&self, (row, col): Pos) -> Pos {
+        let row = row.min(self.lines.len() - 1);
+        let line = &self.lines[row];
+        let col = if col >= line.len() {
+            line.len()
+        } else {
+            line.grapheme_indices(true)
+                .map(|(i, _)| i)
+                .take_while(|&i| i <= col)
+                .last()
+                .unwrap_or(0)
+        };
+        (row, col)
+    }
+
+    /// Searches the text for the given pattern, highlighting all its matches with the
+    /// [`match_style`](TextArea::match_style) of the widget. Returns the number of matches.
+    ///
+    /// The search is case sensitive and matches don't span several lines. The matches follow the
+    /// edits of the text until the search is [cleared](Self::clear_search), and an empty pattern
+    /// clears it. Calling this method every time the pattern changes gives an incremental search,
+    /// with [`next_match`](Self::next_match) jumping to the matches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TextAreaState;
+    ///
+    /// let mut state = TextAreaState::new("one two\ntwo one");
+    /// assert_eq!(state.search("one"), 2);
+    /// state.move_to_start();
+    /// assert!(state.next_match());
+    /// assert_eq!(state.cursor(), (1, 4));
+    /// assert!(state.next_match());
+    /// assert_eq!(state.cursor(), (0, 0));
+    /// ```
+    pub fn search<T>(&mut self, pattern: T) -> usize
+    where
+        T: Into<String>,
+    {
+        let pattern = pattern.into();
+        self.search = (!pattern.is_empty()).then_some(SearchPattern::Text(pattern));
+        self.search_matches().len()
+    }
+
+    /// Searches the text for the given regular expression, highlighting all its matches. Returns
+    /// the number of matches.
+    ///
+    /// This works as [`search`](Self::search), except that empty matches (e.g. of `a*`) are
+    /// ignored. The search can be made case insensitive with the `(?i)` flag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the regular expression is invalid, leaving the previous search as it
+    /// was.
+    #[cfg(feature = "regex")]
+    pub fn search_regex(&mut self, pattern: &str) -> Result<usize, regex::Error> {
+        let regex = regex::Regex::new(pattern)?;
+        self.search = (!pattern.is_empty()).then_some(SearchPattern::Regex(regex));
+        Ok(self.search_matches().len())
+    }
+
+    /// Returns the pattern of the current search, if any.
+    pub fn search_pattern(&self) -> Option<&str> {
+        self.search.as_ref().map(SearchPattern::as_str)
+    }
+
+    /// Clears the search, removing the highlighting of its matches.
+    pub fn clear_search(&mut self) {
+        self.search = None;
+    }
+
+    /// Returns the ranges of the matches of the search in the text, in order, each from its
+    /// first position to the position after it.
+    pub fn search_matches(&self) -> Vec<((usize, usize), (usize, usize))> {
+        let Some(search) = &self.search else {
+            return vec![];
+        };
+        self.lines
+            .iter()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                search
+                    .find_in(line)
+                    .into_iter()
+                    .map(move |range| ((row, range.start), (row, range.end)))
+            })
+            .collect()
+    }
+
+    /// Moves the cursor to the start of the next match of the search, wrapping around to the
+    /// first match after the last one. Returns `true` if there is a match.
+    ///
+    /// The viewport scrolls to the match on the next render, as it keeps the cursor visible.
+    pub fn next_match(&mut self) -> bool {
+        let matches = self.search_matches();
+        let cursor = self.cursor();
+        let next = matches
+            .iter()
+            .find(|(start, _)| *start > cursor)
+            .or_else(|| matches.first());
+        self.jump_to_match(next.map(|&(start, _)| start))
+    }
+
+    /// Moves the cursor to the start of the previous match of the search, wrapping around to the
+    /// last match before the first one. Returns `true` if there is a match.
+    pub fn prev_match(&mut self) -> bool {
+        let matches = self.search_matches();
+        let cursor = self.cursor();
+        let previous = matches
+            .iter()
+            .rfind(|(start, _)| *start < cursor)
+            .or_else(|| matches.last());
+        self.jump_to_match(previous.map(|&(start, _)| start))
+    }
+
+    fn jump_to_match(&mut self, position: Option<Pos>) -> bool {
+        let Some((row, col)) = position else {
+            return false;
+        };
+        self.set_cursor(row, col);
+        true
+    }
+
+    /// Replaces the diagnostics of the text, drawn with the
+    /// [`diagnostic_style`](TextArea::diagnostic_style) of their severity.
+    ///
+    /// Unlike the matches of the search, the diagnostics don't follow the edits: they are meant to
+    /// be set again once the new text is checked, e.g. after each edit or after a delay. Ranges
+    /// ending before they start are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Diagnostic, Severity, TextAreaState};
+    ///
+    /// let mut state = TextAreaState::new("let x = 1;\nlet y = x +;");
+    /// state.set_diagnostics([
+    ///     Diagnostic::new((1, 10), (1, 11), Severity::Error, "expected an expression"),
+    ///     Diagnostic::new((0, 4), (0, 5), Severity::Hint, "unused variable"),
+    /// ]);
+    /// state.move_to_start();
+    /// assert!(state.next_diagnostic());
+    /// assert_eq!(state.cursor(), (0, 4));
+    /// assert!(state.next_diagnostic());
+    /// assert_eq!(state.cursor(), (1, 10));
+    /// ```
+    pub fn set_diagnostics<I>(&mut self, diagnostics: I)
+    where
+        I: IntoIterator<Item = Diagnostic>,
+    {
+        self.diagnostics = diagnostics
+            .into_iter()
+            .filter(|diagnostic| diagnostic.start <= diagnostic.end)
+            .collect();
+        self.diagnostics
+            .sort_by_key(|diagnostic| (diagnostic.start, diagnostic.severity));
+    }
+
+    /// Removes all the diagnostics.
+    pub fn clear_diagnostics(&mut self) {
+        self.diagnostics.clear();
+    }
+
+    /// Returns the diagnostics, sorted by the start of their range.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// Returns the most severe diagnostic containing the cursor, or starting at it, e.g. to show
+    /// its message in a status line.
+    pub fn diagnostic_at_cursor(&self) -> Option<&Diagnostic> {
+        let cursor = self.cursor();
+        self.diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.start == cursor || diagnostic.contains(cursor))
+            .min_by_key(|diagnostic| diagnostic.severity)
+    }
+
+    /// Moves the cursor to the start of the next diagnostic, wrapping around to the first
+    /// diagnostic after the last one. Returns `true` if there is a diagnostic.
+    ///
+    /// The viewport scrolls to the diagnostic on the next render, as it keeps the cursor visible.
+    pub fn next_diagnostic(&mut self) -> bool {
+        let cursor = self.cursor();
+        let next = self
+            .diagnostics
+            .iter()
+            .find(|diagnostic| diagnostic.start > cursor)
+            .or_else(|| self.diagnostics.first());
+        self.jump_to_match(next.map(|diagnostic| diagnostic.start))
+    }
+
+    /// Moves the cursor to the start of the previous diagnostic, wrapping around to the last
+    /// diagnostic before the first one. Returns `true` if there is a diagnostic.
+    pub fn prev_diagnostic(&mut self) -> bool {
+        let cursor = self.cursor();
+        let previous = self
+            .diagnostics
+            .iter()
+            .rfind(|diagnostic| diagnostic.start < cursor)
+            .or_else(|| self.diagnostics.last());
+        self.jump_to_match(previous.map(|diagnostic| diagnostic.start))
+    }
+
+    /// Returns the most severe severity of the diagnostics containing the given position.
+    fn severity_at(&self, position: Pos) -> Option<Severity> {
+        self.diagnostics
+            .iter()
+            .take_while(|diagnostic| diagnostic.start <= position)
+            .filter(|diagnostic| diagnostic.contains(position))
+            .map(|diagnostic| diagnostic.severity)
+            .min()
+    }
+
+    /// Returns the position of the grapheme at the given index in the given line, or of the end
+    /// of the line, e.g. to convert the grapheme ranges of a spell checker to the byte offsets
+    /// of a [`Diagnostic`].
+    ///
+    /// The line index is clamped to the last line.
+    pub fn grapheme_position(&self, row: usize, index: usize) -> (usize, usize) {
+        let row = row.min(self.lines.len() - 1);
+        let line = &self.lines[row];
+        let col = line
+            .grapheme_indices(true)
+            .nth(index)
+            .map_or(line.len(), |(i, _)| i);
+        (row, col)
+    }
+
+    /// Returns the position of the cursor on screen during the last render, if it was visible.
+    ///
+    /// While text is being composed (see [`set_preedit`](Self::set_preedit)), this is the
+    /// position after the composed text.
+    pub fn cursor_screen_position(&self) -> Option<Position> {
+        self.screen_cursor
+    }
+
+    /// Scrolls the viewport up by the given number of rows without moving the cursor. Returns
+    /// `true` if the viewport moved.
+    ///
+    /// The viewport stays where it is until the cursor moves, and then scrolls back to it.
+    pub fn scroll_up(&mut self, rows: usize) -> bool {
+        self.scroll_to(self.offset.saturating_sub(rows))
+    }
+
+    /// Scrolls the viewport down by the given number of rows without moving the cursor, until
+    /// the last row of the text is at the bottom of the viewport. Returns `true` if the
+    /// viewport moved.
+    ///
+    /// The viewport stays where it is until the cursor moves, and then scrolls back to it.
+    pub fn scroll_down(&mut self, rows: usize) -> bool {
+        self.wrap_index.update(&self.lines, self.width);
+        let total = self.wrap_index.start(self.lines.len());
+        let max_offset = total.saturating_sub(usize::from(self.area.height));
+        self.scroll_to(
+            self.offset
+                .saturating_add(rows)
+                .min(max_offset.max(self.offset)),
+        )
+    }
+
+    fn scroll_to(&mut self, offset: usize) -> bool {
+        if offset == self.offset {
+            return false;
+        }
+        self.offset = offset;
+        self.scrolled = Some(self.cursor());
+        true
+    }
+
+    /// Returns `true` if the text area has the focus. Defaults to `true`.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Sets whether the text area has the focus.
+    ///
+    /// A text area without the focus doesn't draw its cursor, and shows its
+    /// [`placeholder`](TextArea::placeholder) while the text is empty.
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Returns `true` if the text area was [read-only](TextArea::read_only) during the last
+    /// render.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns `true` if the text area was [disabled](TextArea::disabled) during the last
+    /// render.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Returns the text being composed with an input method, if any.
+    pub fn preedit(&self) -> Option<&str> {
+        self.preedit.as_deref()
+    }
+
+    /// Sets the text being composed with an input method (IME), or `None` when the composition
+    /// ends.
+    ///
+    /// The composed text is displayed at the cursor with the
+    /// [`preedit_style`](TextArea::preedit_style) of the widget, wrapping with the rest of the
+    /// line, but is not part of the text and is not recorded in the edit history. Line breaks
+    /// are removed from the composed text.
+    pub fn set_preedit<T>(&mut self, text: Option<T>)
+    where
+        T: Into<String>,
+    {
+        self.preedit = text
+            .map(|text| text.into().replace(['\n', '\r'], ""))
+            .filter(|text| !text.is_empty());
+    }
+
+    /// Sets the maximum number of edits that can be undone. Defaults to 100.
+    pub fn set_history_depth(&mut self, depth: usize) {
+        self.history.set_depth(depth);
+    }
+
+    /// Sets the maximum number of edits that can be undone. Defaults to 100.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_history_depth(mut self, depth: usize) -> Self {
+        self.set_history_depth(depth);
+        self
+    }
+
+    /// Returns the pairs of characters closed automatically when typed.
+    pub fn auto_pairs(&self) -> &[(char, char)] {
+        &self.auto_pairs
+    }
+
+    /// Sets the pairs of opening and closing characters closed automatically when typed, e.g.
+    /// [`AUTO_PAIRS`](Self::AUTO_PAIRS). Defaults to none.
+    ///
+    /// Typing an opening character inserts its closing character after the cursor, when the
+    /// cursor is followed by whitespace, a closing character or the end of the line, and a quote
+    /// (a character closed by itself) is only closed when it doesn't follow a letter or a digit.
+    /// With text selected, the selection is surrounded by the pair instead. Typing a closing
+    /// character moves over the same character following the cursor, and
+    /// [`delete_before`](Self::delete_before) deletes both characters of an empty pair.
+    ///
+    /// Only the characters typed or inserted with [`insert_char`](Self::insert_char) are closed,
+    /// not the pasted text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TextAreaState;
+    ///
+    /// let mut state = TextAreaState::default().with_auto_pairs(TextAreaState::AUTO_PAIRS);
+    /// state.insert_char('(');
+    /// assert_eq!(state.value(), "()");
+    /// state.insert_char('a');
+    /// state.insert_char(')');
+    /// assert_eq!(state.value(), "(a)");
+    /// assert_eq!(state.cursor(), (0, 3));
+    /// ```
+    pub fn set_auto_pairs<I>(&mut self, pairs: I)
+    where
+        I: IntoIterator<Item = (char, char)>,
+    {
+        self.auto_pairs = pairs.into_iter().collect();
+    }
+
+    /// Sets the pairs of characters closed automatically when typed, see
+    /// [`set_auto_pairs`](Self::set_auto_pairs).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_auto_pairs<I>(mut self, pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (char, char)>,
+    {
+        self.set_auto_pairs(pairs);
+        self
+    }
+
+    /// Returns `true` if new lines start with the indentation of the line they are split from.
+    pub fn auto_indent(&self) -> bool {
+        self.auto_indent
+    }
+
+    /// Makes [`insert_newline`](Self::insert_newline) (i.e. `Enter`) carry the leading spaces
+    /// and tabs of the line of the cursor over to the new line. Defaults to `false`.
+    ///
+    /// The pasted line breaks don't add indentation.
+    pub fn set_auto_indent(&mut self, auto_indent: bool) {
+        self.auto_indent = auto_indent;
+    }
+
+    /// Makes the new lines start with the indentation of the line they are split from, see
+    /// [`set_auto_indent`](Self::set_auto_indent).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_auto_indent(mut self, auto_indent: bool) -> Self {
+        self.set_auto_indent(auto_indent);
+        self
+    }
+
+    /// Reverts the last edit. Returns `true` if there was something to undo.
+    ///
+    /// Consecutive characters inserted with [`insert_char`](Self::insert_char) on the same line
+    /// are undone together, unless the cursor was moved in between, as well as the edits made at
+    /// all the cursors by one command. Only the main cursor is restored.
+    pub fn undo(&mut self) -> bool {
+        let current = (self.lines.clone(), self.row, self.col);
+        let Some((lines, row, col)) = self.history.undo(current) else {
+            return false;
+        };
+        self.lines = lines;
+        self.row = row;
+        self.col = col;
+        self.anchor = None;
+        self.cursors.clear();
+        true
+    }
+
+    /// Restores the last undone edit. Returns `true` if there was something to redo.
+    pub fn redo(&mut self) -> bool {
+        let current = (self.lines.clone(), self.row, self.col);
+        let Some((lines, row, col)) = self.history.redo(current) else {
+            return false;
+        };
+        self.lines = lines;
+        self.row = row;
+        self.col = col;
+        self.anchor = None;
+        self.cursors.clear();
+        true
+    }
+
+    fn record(&mut self, coalesce: bool) {
+        self.history
+            .record((self.lines.clone(), self.row, self.col), coalesce);
+        self.killed = false;
+    }
+
+    /// Inserts a character at the cursor, replacing the selected text, and moves the cursor after
+    /// it.
+    ///
+    /// `\n` splits the line at the cursor, `\r` is ignored. The brackets and quotes of the
+    /// [`auto_pairs`](Self::set_auto_pairs) are closed automatically.
+    ///
+    /// The character is inserted at every cursor.
+    pub fn insert_char(&mut self, c: char) {
+        self.for_each_cursor(c != '\n', |state| state.insert_char_at_cursor(c));
+    }
+
+    fn insert_char_at_cursor(&mut self, c: char) {
+        match c {
+            '\n' => self.insert_newline(),
+            '\r' => {}
+            c if self.types_over(c) => {
+                self.history.break_coalescing();
+                self.col += c.len_utf8();
+            }
+            c => {
+                let close = self.closing_pair(c);
+                if let (Some(close), Some((start, end))) = (close, self.selection()) {
+                    self.surround(start, end, c, close);
+                    return;
+                }
+                self.record(true);
+                self.remove_selection();
+                let close = close.filter(|&close| self.can_close(c, close));
+                self.lines[self.row].insert(self.col, c);
+                if let Some(close) = close {
+                    self.lines[self.row].insert(self.col + c.len_utf8(), close);
+                }
+                // the character may have been combined with the previous grapheme
+                self.snap_cursor(self.row, self.col + c.len_utf8());
+            }
+        }
+    }
+
+    /// Returns the closing character of the auto pair opened by the given character, if any.
+    fn closing_pair(&self, open: char) -> Option<char> {
+        self.auto_pairs
+            .iter()
+            .find(|&&(pair_open, _)| pair_open == open)
+            .map(|&(_, close)| close)
+    }
+
+    fn is_closing(&self, c: char) -> bool {
+        self.auto_pairs.iter().any(|&(_, close)| close == c)
+    }
+
+    /// Returns `true` if typing the given character moves over the same closing character after
+    /// the cursor instead of inserting it.
+    fn types_over(&self, c: char) -> bool {
+        self.selection().is_none()
+            && self.is_closing(c)
+            && self.lines[self.row][self.col..].starts_with(c)
+    }
+
+    /// Returns `true` if the given opening character typed at the cursor is closed: when it is
+    /// followed by whitespace, a closing character or the end of the line, and for a quote when
+    /// it doesn't follow a letter or a digit.
+    fn can_close(&self, open: char, close: char) -> bool {
+        let line = &self.lines[self.row];
+        let next = line[self.col..].chars().next();
+        let previous = line[..self.col].chars().next_back();
+        next.map_or(true, |next| next.is_whitespace() || self.is_closing(next))
+            && (open != close || !previous.is_some_and(char::is_alphanumeric))
+    }
+
+    /// Surrounds the text between the given positions with a pair of characters, keeping the
+    /// text inside selected.
+    fn surround(&mut self, start: Pos, end: Pos, open: char, close: char) {
+        self.record(false);
+        self.lines[end.0].insert(end.1, close);
+        self.lines[start.0].insert(start.1, open);
+        let inner_start = (start.0, start.1 + open.len_utf8());
+        let inner_end = if end.0 == start.0 {
+            (end.0, end.1 + open.len_utf8())
+        } else {
+            end
+        };
+        let (anchor, cursor) = if self.cursor() == end {
+            (inner_start, inner_end)
+        } else {
+            (inner_end, inner_start)
+        };
+        (self.row, self.col) = cursor;
+        self.anchor = Some(anchor);
+    }
+
+    /// Returns `true` if the cursor is between the two characters of an auto pair.
+    fn is_in_empty_pair(&self) -> bool {
+        let line = &self.lines[self.row];
+        let previous = line[..self.col].chars().next_back();
+        let next = line[self.col..].chars().next();
+        self.auto_pairs
+            .iter()
+            .any(|&pair| Some(pair.0) == previous && Some(pair.1) == next)
+    }
+
+    /// Returns the leading whitespace of the line of the cursor, before the cursor, if the new
+    /// lines carry the indentation over.
+    fn indentation(&self) -> String {
+        if !self.auto_indent {
+            return String::new();
+        }
+        let before = &self.lines[self.row][..self.col];
+        let text = before.trim_start_matches([' ', '\t']);
+        before[..before.len() - text.len()].to_string()
+    }
+
+    /// Inserts a string at the cursor, replacing the selected text, and moves the cursor after
+    /// it.
+    ///
+    /// The string is inserted at every cursor.
+    pub fn insert_str(&mut self, s: &str) {
+        self.for_each_cursor(false, |state| state.insert_str_at_cursor(s));
+    }
+
+    fn insert_str_at_cursor(&mut self, s: &str) {
+        if s.is_empty() {
+            return;
+        }
+        self.record(false);
+        self.remove_selection();
+        for (i, part) in s.split('\n').enumerate() {
+            if i > 0 {
+                self.split_line();
+            }
+            let part = strip_cr(part);
+            self.lines[self.row].insert_str(self.col, &part);
+            self.snap_cursor(self.row, self.col + part.len());
+        }
+    }
+
+    /// Splits the line at the cursor, replacing the selected text, and moves the cursor to the
+    /// start of the new line, after the indentation of the previous line with
+    /// [`auto_indent`](Self::set_auto_indent).
+    pub fn insert_newline(&mut self) {
+        self.record(false);
+        self.remove_selection();
+        let indentation = self.indentation();
+        self.split_line();
+        self.lines[self.row].insert_str(0, &indentation);
+        self.col = indentation.len();
+    }
+
+    fn split_line(&mut self) {
+        let rest = self.lines[self.row].split_off(self.col);
+        self.row += 1;
+        self.col = 0;
+        self.lines.insert(self.row, rest);
+    }
+
+    /// Deletes the grapheme before the cursor (i.e. `Backspace`), joining the line with the
+    /// previous one when the cursor is at its start, or the selected text. Returns `true` if
+    /// something was deleted.
+    ///
+    /// Between the two characters of one of the [`auto_pairs`](Self::set_auto_pairs), e.g. in
+    /// `()`, both characters are deleted.
+    pub fn delete_before(&mut self) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+        if self.col == 0 && self.row == 0 {
+            return false;
+        }
+        self.record(false);
+        if self.is_in_empty_pair() {
+            let line = &mut self.lines[self.row];
+            let start = line[..self.col]
+                .char_indices()
+                .next_back()
+                .map_or(0, |(i, _)| i);
+            let end = self.col + line[self.col..].chars().next().map_or(0, char::len_utf8);
+            line.replace_range(start..end, "");
+            self.col = start;
+        } else if let Some(start) = self.previous_boundary() {
+            self.lines[self.row].replace_range(start..self.col, "");
+            self.col = start;
+        } else if self.row > 0 {
+            let line = self.lines.remove(self.row);
+            self.row -= 1;
+            self.col = self.lines[self.row].len();
+            self.lines[self.row].push_str(&line);
+        }
+        true
+    }
+
+    /// Deletes the grapheme after the cursor (i.e. `Delete`), joining the next line when the
+    /// cursor is at the end of its line, or the selected text. Returns `true` if something was
+    /// deleted.
+    pub fn delete_after(&mut self) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+        if self.col == self.lines[self.row].len() && self.row + 1 == self.lines.len() {
+            return false;
+        }
+        self.record(false);
+        if let Some(end) = self.next_boundary() {
+            self.lines[self.row].replace_range(self.col..end, "");
+        } else if self.row + 1 < self.lines.len() {
+            let line = self.lines.remove(self.row + 1);
+            self.lines[self.row].push_str(&line);
+        }
+        true
+    }
+
+    /// Moves the cursor one grapheme to the left, or to the end of the previous line. Returns
+    /// `true` if the cursor moved.
+    pub fn move_left(&mut self) -> bool {
+        self.history.break_coalescing();
+        self.anchor = None;
+        if let Some(col) = self.previous_boundary() {
+            self.col = col;
+        } else if self.row > 0 {
+            self.row -= 1;
+            self.col = self.lines[self.row].len();
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Moves the cursor one grapheme to the right, or to the start of the next line. Returns
+    /// `true` if the cursor moved.
+    pub fn move_right(&mut self) -> bool {
+        self.history.break_coalescing();
+        self.anchor = None;
+        if let Some(col) = self.next_boundary() {
+            self.col = col;
+        } else if self.row + 1 < self.lines.len() {
+            self.row += 1;
+            self.col = 0;
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Moves the cursor to the previous line, keeping its column on screen when possible.
+    /// Returns `true` if the cursor moved.
+    ///
+    /// With [`WrapNavigation::Visual`], the cursor moves to the previous row on screen instead.
+    pub fn move_up(&mut self) -> bool {
+        self.history.break_coalescing();
+        self.anchor = None;
+        if self.wraps_visually() {
+            return self.move_wrapped_row(false);
+        }
+        if self.row == 0 {
+            return false;
+        }
+        let column = self.visual_cursor_column();
+        self.row -= 1;
+        self.col = byte_offset_at_column(&self.lines[self.row], column);
+        true
+    }
+
+    /// Moves the cursor to the next line, keeping its column on screen when possible. Returns
+    /// `true` if the cursor moved.
+    ///
+    /// With [`WrapNavigation::Visual`], the cursor moves to the next row on screen instead.
+    pub fn move_down(&mut self) -> bool {
+        self.history.break_coalescing();
+        self.anchor = None;
+        if self.wraps_visually() {
+            return self.move_wrapped_row(true);
+        }
+        if self.row + 1 >= self.lines.len() {
+            return false;
+        }
+        let column = self.visual_cursor_column();
+        self.row += 1;
+        self.col = byte_offset_at_column(&self.lines[self.row], column);
+        true
+    }
+
+    /// Moves the cursor to the start of its line, or of its row on screen with
+    /// [`WrapNavigation::Visual`].
+    pub fn move_home(&mut self) {
+        self.history.break_coalescing();
+        self.anchor = None;
+        self.col = if self.wraps_visually() {
+            let rows = self.wrapped_rows(self.row);
+            rows[cursor_row_index(&rows, self.col)].start
+        } else {
+            0
+        };
+    }
+
+    /// Moves the cursor to the end of its line, or of its row on screen with
+    /// [`WrapNavigation::Visual`].
+    pub fn move_end(&mut self) {
+        self.history.break_coalescing();
+        self.anchor = None;
+        self.col = if self.wraps_visually() {
+            let rows = self.wrapped_rows(self.row);
+            let index = cursor_row_index(&rows, self.col);
+            self.offset_in_row(&rows, index, usize::MAX)
+        } else {
+            self.lines[self.row].len()
+        };
+    }
+
+    /// Returns `true` if the cursor moves by row on screen, once the lines have been wrapped by
+    /// a render.
+    fn wraps_visually(&self) -> bool {
+        self.wrap_navigation == WrapNavigation::Visual && self.width > 0
+    }
+
+    /// Returns the rows of a line wrapped at the width of the last render.
+    fn wrapped_rows(&self, line: usize) -> Vec<WrappedRow> {
+        let mut rows = vec![];
+        wrap_line(&self.lines[line], self.width, line, &mut rows);
+        rows
+    }
+
+    /// Returns the byte offset at the given column on screen of a wrapped row.
+    ///
+    /// The offset stays before the last grapheme of the row unless it is the last row of its
+    /// line, as the end of a row is the start of the next one, where the cursor would be drawn.
+    fn offset_in_row(&self, rows: &[WrappedRow], index: usize, column: usize) -> usize {
+        let row = rows[index];
+        let text = &self.lines[row.line][row.start..row.end];
+        let offset = byte_offset_at_column(text, column);
+        if offset == text.len() && index + 1 < rows.len() {
+            let last = text.grapheme_indices(true).next_back();
+            row.start + last.map_or(0, |(i, _)| i)
+        } else {
+            row.start + offset
+        }
+    }
+
+    /// Returns the position in the text drawn at the given cell during the last render.
+    ///
+    /// The rows above and below the text area map to the rows just outside of the viewport, so
+    /// that dragging the mouse out of the text area scrolls it.
+    fn position_at(&mut self, column: u16, row: u16) -> Pos {
+        let target = if row < self.area.y {
+            self.offset.saturating_sub(1)
+        } else {
+            self.offset + usize::from(row.min(self.area.bottom()) - self.area.y)
+        };
+        let column = usize::from(column.saturating_sub(self.area.x));
+        self.wrap_index.update(&self.lines, self.width);
+        let (line, index) = self.wrap_index.line_at(target);
+        let rows = self.wrapped_rows(line);
+        (
+            line,
+            self.offset_in_row(&rows, index.min(rows.len() - 1), column),
+        )
+    }
+
+    /// Moves the cursor to the previous or next row on screen, keeping its column on screen when
+    /// possible. Returns `true` if the cursor moved.
+    fn move_wrapped_row(&mut self, down: bool) -> bool {
+        let rows = self.wrapped_rows(self.row);
+        let index = cursor_row_index(&rows, self.col);
+        let column = text_width(&self.lines[self.row][rows[index].start..self.col]);
+        if down && index + 1 < rows.len() {
+            self.col = self.offset_in_row(&rows, index + 1, column);
+        } else if !down && index > 0 {
+            self.col = self.offset_in_row(&rows, index - 1, column);
+        } else if down && self.row + 1 < self.lines.len() {
+            self.row += 1;
+            let rows = self.wrapped_rows(self.row);
+            self.col = self.offset_in_row(&rows, 0, column);
+        } else if !down && self.row > 0 {
+            self.row -= 1;
+            let rows = self.wrapped_rows(self.row);
+            self.col = self.offset_in_row(&rows, rows.len() - 1, column);
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Moves the cursor to the start of the text.
+    pub fn move_to_start(&mut self) {
+        self.history.break_coalescing();
+        self.anchor = None;
+        self.row = 0;
+        self.col = 0;
+    }
+
+    /// Moves the cursor to the end of the text.
+    pub fn move_to_end(&mut self) {
+        self.history.break_coalescing();
+        self.anchor = None;
+        self.row = self.lines.len() - 1;
+        self.col = self.lines[self.row].len();
+    }
+
+    /// Moves the cursor to the start of the current or previous word, or to the end of the
+    /// previous line when it is at the start of its line. Returns `true` if the cursor moved.
+    pub fn move_word_left(&mut self) -> bool {
+        if self.col == 0 {
+            return self.move_left();
+        }
+        self.history.break_coalescing();
+        self.anchor = None;
+        self.col = words::previous_word_start(&self.lines[self.row], self.col);
+        true
+    }
+
+    /// Moves the cursor to the end of the current or next word, or to the start of the next line
+    /// when it is at the end of its line. Returns `true` if the cursor moved.
+    pub fn move_word_right(&mut self) -> bool {
+        if self.col == self.lines[self.row].len() {
+            return self.move_right();
+        }
+        self.history.break_coalescing();
+        self.anchor = None;
+        self.col = words::next_word_end(&self.lines[self.row], self.col);
+        true
+    }
+
+    /// Deletes from the start of the current or previous word to the cursor, joining the line
+    /// with the previous one when the cursor is at its start. Returns `true` if something was
+    /// deleted.
+    pub fn delete_word_before(&mut self) -> bool {
+        if self.col == 0 {
+            return self.delete_before();
+        }
+        let start = words::previous_word_start(&self.lines[self.row], self.col);
+        self.delete_range(start, self.col)
+    }
+
+    /// Deletes from the cursor to the end of the current or next word, joining the next line
+    /// when the cursor is at the end of its line. Returns `true` if something was deleted.
+    pub fn delete_word_after(&mut self) -> bool {
+        if self.col == self.lines[self.row].len() {
+            return self.delete_after();
+        }
+        let end = words::next_word_end(&self.lines[self.row], self.col);
+        self.delete_range(self.col, end)
+    }
+
+    /// Deletes from the start of the line to the cursor. Returns `true` if something was
+    /// deleted.
+    pub fn delete_to_start(&mut self) -> bool {
+        self.delete_range(0, self.col)
+    }
+
+    /// Deletes from the cursor to the end of the line, or joins the next line when the cursor is
+    /// at the end of its line. Returns `true` if something was deleted.
+    pub fn delete_to_end(&mut self) -> bool {
+        if self.col == self.lines[self.row].len() {
+            return self.delete_after();
+        }
+        self.delete_range(self.col, self.lines[self.row].len())
+    }
+
+    /// Deletes from the start of the current or previous word to the cursor, and adds the deleted
+    /// text to the [`KillRing`]. The line is joined with the previous one when the cursor is at
+    /// its start. Returns `true` if something was deleted.
+    pub fn kill_word_before(&mut self) -> bool {
+        let start = match (self.row, self.col) {
+            (0, 0) => (0, 0),
+            (row, 0) => (row - 1, self.lines[row - 1].len()),
+            (row, col) => (row, words::previous_word_start(&self.lines[row], col)),
+        };
+        self.kill_between(start, self.cursor())
+    }
+
+    /// Deletes from the cursor to the end of the current or next word, and adds the deleted text
+    /// to the [`KillRing`]. The next line is joined when the cursor is at the end of its line.
+    /// Returns `true` if something was deleted.
+    pub fn kill_word_after(&mut self) -> bool {
+        let end = if self.col == self.lines[self.row].len() {
+            self.line_end_or_next_start()
+        } else {
+            (
+                self.row,
+                words::next_word_end(&self.lines[self.row], self.col),
+            )
+        };
+        self.kill_between(self.cursor(), end)
+    }
+
+    /// Deletes from the start of the line to the cursor, and adds the deleted text to the
+    /// [`KillRing`]. Returns `true` if something was deleted.
+    pub fn kill_to_start(&mut self) -> bool {
+        self.kill_between((self.row, 0), self.cursor())
+    }
+
+    /// Deletes from the cursor to the end of the line, or joins the next line when the cursor is
+    /// at the end of its line, and adds the deleted text to the [`KillRing`]. Returns `true` if
+    /// something was deleted.
+    pub fn kill_to_end(&mut self) -> bool {
+        let end = if self.col == self.lines[self.row].len() {
+            self.line_end_or_next_start()
+        } else {
+            (self.row, self.lines[self.row].len())
+        };
+        self.kill_between(self.cursor(), end)
+    }
+
+    /// Inserts the last text of the [`KillRing`] at the cursor, replacing the selected text, like
+    /// [`insert_str`](Self::insert_str). Returns `false` if the kill ring is empty.
+    pub fn yank(&mut self) -> bool {
+        let Some(text) = KillRing::yank() else {
+            return false;
+        };
+        self.insert_str(&text);
+        true
+    }
+
+    /// Returns the start of the next line, or the end of the last line.
+    fn line_end_or_next_start(&self) -> Pos {
+        if self.row + 1 < self.lines.len() {
+            (self.row + 1, 0)
+        } else {
+            (self.row, self.lines[self.row].len())
+        }
+    }
+
+    /// Deletes the text between the given positions, or the selected text if there is one, and
+    /// adds the deleted text to the kill ring, joined to the last entry if the previous edit was
+    /// also a kill.
+    fn kill_between(&mut self, start: Pos, end: Pos) -> bool {
+        let (start, end) = self.selection().unwrap_or((start, end));
+        if start == end {
+            return false;
+        }
+        let join = self.killed;
+        let backwards = end == self.cursor();
+        KillRing::kill(&text_between(&self.lines, start, end), join, backwards);
+        self.record(false);
+        remove_between(&mut self.lines, start, end);
+        (self.row, self.col) = start;
+        self.anchor = None;
+        self.killed = true;
+        true
+    }
+
+    /// Deletes the given range of the line of the cursor, or the selected text if there is one.
+    fn delete_range(&mut self, start: usize, end: usize) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+        if start == end {
+            return false;
+        }
+        self.record(false);
+        self.lines[self.row].replace_range(start..end, "");
+        self.col = start;
+        true
+    }
+
+    /// Executes an editing command. Returns `true` unless the command
+    /// [edits](EditCommand::is_edit) a read-only text area.
+    ///
+    /// The delete and kill commands delete the selected text instead when there is one. The
+    /// motions and the edits are executed at every cursor, as a single undo step.
+    pub fn execute(&mut self, command: EditCommand) -> bool {
+        if self.read_only && command.is_edit() {
+            return false;
+        }
+        if !command.is_kill() {
+            self.killed = false;
+        }
+        match command {
+            EditCommand::Undo => {
+                self.undo();
+            }
+            EditCommand::Redo => {
+                self.redo();
+            }
+            EditCommand::NextDiagnostic => {
+                self.next_diagnostic();
+            }
+            EditCommand::PrevDiagnostic => {
+                self.prev_diagnostic();
+            }
+            EditCommand::AddCursorAbove => {
+                self.add_cursor_above();
+            }
+            EditCommand::AddCursorBelow => {
+                self.add_cursor_below();
+            }
+            EditCommand::SelectNextOccurrence => {
+                self.select_next_occurrence();
+            }
+            command => self.for_each_cursor(false, |state| state.execute_at_cursor(command)),
+        }
+        true
+    }
+
+    /// Executes a motion or an edit at the cursor.
+    fn execute_at_cursor(&mut self, command: EditCommand) {
+        match command {
+            EditCommand::MoveLeft => {
+                self.move_left();
+            }
+            EditCommand::MoveRight => {
+                self.move_right();
+            }
+            EditCommand::MoveUp => {
+                self.move_up();
+            }
+            EditCommand::MoveDown => {
+                self.move_down();
+            }
+            EditCommand::MoveHome => self.move_home(),
+            EditCommand::MoveEnd => self.move_end(),
+            EditCommand::MoveWordLeft => {
+                self.move_word_left();
+            }
+            EditCommand::MoveWordRight => {
+                self.move_word_right();
+            }
+            EditCommand::SelectLeft => self.extend_selection(|state| {
+                state.move_left();
+            }),
+            EditCommand::SelectRight => self.extend_selection(|state| {
+                state.move_right();
+            }),
+            EditCommand::SelectUp => self.extend_selection(|state| {
+                state.move_up();
+            }),
+            EditCommand::SelectDown => self.extend_selection(|state| {
+                state.move_down();
+            }),
+            EditCommand::SelectHome => self.extend_selection(Self::move_home),
+            EditCommand::SelectEnd => self.extend_selection(Self::move_end),
+            EditCommand::SelectWordLeft => self.extend_selection(|state| {
+                state.move_word_left();
+            }),
+            EditCommand::SelectWordRight => self.extend_selection(|state| {
+                state.move_word_right();
+            }),
+            EditCommand::DeleteBefore => {
+                self.delete_before();
+            }
+            EditCommand::DeleteAfter => {
+                self.delete_after();
+            }
+            EditCommand::DeleteWordBefore => {
+                self.delete_word_before();
+            }
+            EditCommand::DeleteWordAfter => {
+                self.delete_word_after();
+            }
+            EditCommand::DeleteToStart => {
+                self.delete_to_start();
+            }
+            EditCommand::DeleteToEnd => {
+                self.delete_to_end();
+            }
+            EditCommand::KillWordBefore => {
+                self.kill_word_before();
+            }
+            EditCommand::KillWordAfter => {
+                self.kill_word_after();
+            }
+            EditCommand::KillToStart => {
+                self.kill_to_start();
+            }
+            EditCommand::KillToEnd => {
+                self.kill_to_end();
+            }
+            EditCommand::Yank => {
+                self.yank();
+            }
+            EditCommand::InsertNewline => self.insert_newline(),
+            // executed once by `execute`
+            EditCommand::Undo
+            | EditCommand::Redo
+            | EditCommand::NextDiagnostic
+            | EditCommand::PrevDiagnostic
+            | EditCommand::AddCursorAbove
+            | EditCommand::AddCursorBelow
+            | EditCommand::SelectNextOccurrence => {}
+        }
+    }
+
+    /// Returns the positions of all the cursors, in the order of the text. There is only one
+    /// cursor unless [`add_cursor_below`](Self::add_cursor_below),
+    /// [`add_cursor_above`](Self::add_cursor_above) or
+    /// [`select_next_occurrence`](Self::select_next_occurrence) added other ones.
+    pub fn cursors(&self) -> Vec<(usize, usize)> {
+        let mut cursors = self
+            .cursors
+            .iter()
+            .map(|cursor| cursor.position)
+            .chain([self.cursor()])
+            .collect::<Vec<_>>();
+        cursors.sort_unstable();
+        cursors
+    }
+
+    /// Returns the selected ranges of all the cursors, in the order of the text, each from its
+    /// first position to the position after it.
+    pub fn selections(&self) -> Vec<((usize, usize), (usize, usize))> {
+        let mut selections = self
+            .cursors
+            .iter()
+            .filter_map(Cursor::selection)
+            .chain(self.selection())
+            .collect::<Vec<_>>();
+        selections.sort_unstable();
+        selections
+    }
+
+    /// Removes the other cursors, keeping the main one. Returns `true` if there were other
+    /// cursors.
+    pub fn clear_cursors(&mut self) -> bool {
+        let cleared = !self.cursors.is_empty();
+        self.cursors.clear();
+        cleared
+    }
+
+    /// Adds a cursor on the line after the last cursor, at the column of the main cursor.
+    /// Returns `false` if the last cursor is on the last line.
+    ///
+    /// The [commands](Self::execute) and the typed or pasted text then apply at every cursor,
+    /// until [`set_cursor`](Self::set_cursor) or a click moves the cursor, `Esc` is pressed or
+    /// [`clear_cursors`](Self::clear_cursors) is called. The other editing methods only apply at
+    /// the main cursor, which the viewport follows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TextAreaState;
+    ///
+    /// let mut state = TextAreaState::new("let a;\nlet b;");
+    /// state.move_to_start();
+    /// assert!(state.add_cursor_below());
+    /// state.insert_str("pub ");
+    /// assert_eq!(state.value(), "pub let a;\npub let b;");
+    /// assert!(state.undo());
+    /// assert_eq!(state.value(), "let a;\nlet b;");
+    /// ```
+    pub fn add_cursor_below(&mut self) -> bool {
+        let (last, _) = self.cursors().last().copied().unwrap_or(self.cursor());
+        if last + 1 >= self.lines.len() {
+            return false;
+        }
+        self.add_cursor_at_column(last + 1)
+    }
+
+    /// Adds a cursor on the line before the first cursor, at the column of the main cursor.
+    /// Returns `false` if the first cursor is on the first line.
+    ///
+    /// See [`add_cursor_below`](Self::add_cursor_below).
+    pub fn add_cursor_above(&mut self) -> bool {
+        let (first, _) = self.cursors().first().copied().unwrap_or(self.cursor());
+        if first == 0 {
+            return false;
+        }
+        self.add_cursor_at_column(first - 1)
+    }
+
+    fn add_cursor_at_column(&mut self, row: usize) -> bool {
+        let column = text_width(&self.lines[self.row][..self.col]);
+        let col = byte_offset_at_column(&self.lines[row], column);
+        self.history.break_coalescing();
+        self.cursors.push(Cursor {
+            position: (row, col),
+            anchor: None,
+        });
+        true
+    }
+
+    /// Selects the next occurrence of the selected text with a new cursor, or the word at the
+    /// cursor when nothing is selected. Returns `false` if there is no other occurrence.
+    ///
+    /// The search starts after the last selection and wraps around to the start of the text.
+    /// See [`add_cursor_below`](Self::add_cursor_below) for the editing with several cursors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TextAreaState;
+    ///
+    /// let mut state = TextAreaState::new("foo(x) + bar(x)");
+    /// state.set_cursor(0, 4);
+    /// assert!(state.select_next_occurrence());
+    /// assert_eq!(state.selected_text().as_deref(), Some("x"));
+    /// assert!(state.select_next_occurrence());
+    /// state.insert_char('y');
+    /// assert_eq!(state.value(), "foo(y) + bar(y)");
+    /// ```
+    pub fn select_next_occurrence(&mut self) -> bool {
+        let Some((start, end)) = self.selection() else {
+            let word = words::word_at(&self.lines[self.row], self.col);
+            if word.is_empty() {
+                return false;
+            }
+            self.anchor = Some((self.row, word.start));
+            self.col = word.end;
+            return true;
+        };
+        let text = text_between(&self.lines, start, end);
+        let value = self.value();
+        let offset = |(row, col): Pos| {
+            self.lines
+                .range(0..row)
+                .map(|line| line.len() + 1)
+                .sum::<usize>()
+                + col
+        };
+        let selected = self
+            .selections()
+            .into_iter()
+            .map(|(start, _)| offset(start))
+            .collect::<Vec<_>>();
+        let from = selected.last().map_or(0, |&start| start + 1);
+        let next = value
+            .match_indices(&text)
+            .map(|(i, _)| i)
+            .filter(|i| !selected.contains(i))
+            .find(|&i| i >= from)
+            .or_else(|| {
+                value
+                    .match_indices(&text)
+                    .map(|(i, _)| i)
+                    .find(|i| !selected.contains(i))
+            });
+        let Some(next) = next else {
+            return false;
+        };
+        let position = |offset: usize| {
+            let row = value[..offset].matches('\n').count();
+            let line_start = value[..offset].rfind('\n').map_or(0, |i| i + 1);
+            (row, offset - line_start)
+        };
+        let (start, end) = (position(next), position(next + text.len()));
+        let (position, anchor) = if self.cursor() == self.selection().map_or(end, |(_, e)| e) {
+            (end, start)
+        } else {
+            (start, end)
+        };
+        self.history.break_coalescing();
+        self.cursors.push(Cursor {
+            position,
+            anchor: Some(anchor),
+        });
+        true
+    }
+
+    /// Applies a motion or an edit at every cursor, from the last one in the text to the first
+    /// one, recording the edits as a single undo step, coalesced with the previous one if
+    /// `coalesce` is set.
+    ///
+    /// After each edit, the other cursors move with the text it replaced, and the cursors in the
+    /// replaced text or on the same position are merged.
+    fn for_each_cursor<F>(&mut self, coalesce: bool, mut edit: F)
+    where
+        F: FnMut(&mut Self),
+    {
+        if self.cursors.is_empty() {
+            edit(self);
+            return;
+        }
+        let before = (self.lines.clone(), self.row, self.col);
+        let main = Cursor {
+            position: self.cursor(),
+            anchor: self.anchor,
+        };
+        // the cursors left to edit, in the order of the text, with whether each is the main one
+        let mut pending = std::mem::take(&mut self.cursors)
+            .into_iter()
+            .map(|cursor| (false, self.snap_cursor_of(cursor)))
+            .chain([(true, main)])
+            .collect::<Vec<_>>();
+        pending.sort_unstable_by_key(|&(is_main, cursor)| (cursor.position, !is_main));
+        pending.dedup_by_key(|(_, cursor)| cursor.position);
+        // the edits at each cursor are recorded once for all of them
+        let mut history = std::mem::take(&mut self.history);
+        let mut edited: Vec<(bool, Cursor)> = Vec::with_capacity(pending.len());
+        while let Some((is_main, cursor)) = pending.pop() {
+            if let Some(merged) = edited
+                .iter_mut()
+                .find(|(_, other)| other.position == cursor.position)
+            {
+                merged.0 |= is_main;
+                continue;
+            }
+            (self.row, self.col) = cursor.position;
+            self.anchor = cursor.anchor;
+            let lines = self.lines.clone();
+            edit(self);
+            if let Some(replaced) = Replaced::find(&lines, &self.lines, cursor.position) {
+                for (_, other) in pending.iter_mut().chain(edited.iter_mut()) {
+                    *other = replaced.shift_cursor(*other);
+                }
+            }
+            let cursor = Cursor {
+                position: self.cursor(),
+                anchor: self.anchor,
+            };
+            edited.push((is_main, cursor));
+        }
+        std::mem::swap(&mut self.history, &mut history);
+        for (is_main, cursor) in merge_cursors(edited) {
+            if is_main {
+                ((self.row, self.col), self.anchor) = (cursor.position, cursor.anchor);
+            } else {
+                self.cursors.push(cursor);
+            }
+        }
+        if self.lines == before.0 {
+            self.history.break_coalescing();
+        } else {
+            self.history.record(before, coalesce);
+        }
+    }
+
+    /// Applies an edit at the main cursor only, moving the other cursors with the text it
+    /// replaced and merging the ones in the replaced text.
+    #[cfg(feature = "vim")]
+    fn edit_at_main_cursor<F, R>(&mut self, edit: F) -> R
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        if self.cursors.is_empty() {
+            return edit(self);
+        }
+        let (lines, position) = (self.lines.clone(), self.cursor());
+        let result = edit(self);
+        let Some(replaced) = Replaced::find(&lines, &self.lines, position) else {
+            return result;
+        };
+        let main = Cursor {
+            position: self.cursor(),
+            anchor: self.anchor,
+        };
+        let cursors = std::mem::take(&mut self.cursors)
+            .into_iter()
+            .map(|cursor| (false, replaced.shift_cursor(cursor)))
+            .chain([(true, main)])
+            .collect();
+        self.cursors = merge_cursors(cursors)
+            .into_iter()
+            .filter(|&(is_main, _)| !is_main)
+            .map(|(_, cursor)| cursor)
+            .collect();
+        result
+    }
+
+    /// Returns the cursor moved to the closest position in the text, in case an edit applied at
+    /// the main cursor only left it out of its line.
+    fn snap_cursor_of(&self, cursor: Cursor) -> Cursor {
+        Cursor {
+            position: self.snap_position(cursor.position),
+            anchor: cursor.anchor.map(|anchor| self.snap_position(anchor)),
+        }
+    }
+
+    /// Handles a key event with the given keymap. Returns `true` if the event was handled.
+    ///
+    /// Keys bound in the keymap are [executed](Self::execute), and the other characters typed
+    /// without `Ctrl` or `Alt` are inserted. `Esc` removes the
+    /// [other cursors](Self::add_cursor_below), if any. Key release events are ignored, as well
+    /// as all the keys while the text area is disabled and the edits while it is read-only.
+    pub fn handle_key_with(&mut self, keymap: &Keymap, key: KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release || self.disabled {
+            return false;
+        }
+        if let Some(command) = keymap.command(&key) {
+            return self.execute(command);
+        }
+        match key.key {
+            Key::Char(c)
+                if !self.read_only
+                    && !key
+                        .modifiers
+                        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.insert_char(c);
+                true
+            }
+            Key::Esc if key.modifiers == KeyModifiers::NONE => self.clear_cursors(),
+            _ => false,
+        }
+    }
+
+    /// Returns the range drawn with the selection style, from its first position to the position
+    /// after its last grapheme: the text selected in the visual mode of the vi-style editing, or
+    /// the selection.
+    fn highlighted_range(&self) -> Option<(Pos, Pos)> {
+        #[cfg(feature = "vim")]
+        if let Some(range) = self.vim_selection() {
+            return Some(range);
+        }
+        self.selection()
+    }
+
+    fn previous_boundary(&self) -> Option<usize> {
+        self.lines[self.row][..self.col]
+            .grapheme_indices(true)
+            .next_back()
+            .map(|(i, _)| i)
+    }
+
+    fn next_boundary(&self) -> Option<usize> {
+        self.lines[self.row][self.col..]
+            .graphemes(true)
+            .next()
+            .map(|g| self.col + g.len())
+    }
+}
+
+impl Focusable for TextAreaState {
+    fn set_focused(&mut self, focused: bool) {
+        TextAreaState::set_focused(self, focused);
+    }
+
+    fn is_disabled(&self) -> bool {
+        TextAreaState::is_disabled(self)
+    }
+}
+
+/// Returns the text between two positions, with lines separated by `\n`.
+fn text_between(lines: &Lines, start: Pos, end: Pos) -> String {
+    if start.0 == end.0 {
+        return lines[start.0][start.1..end.1].to_string();
+    }
+    let mut text = lines[start.0][start.1..].to_string();
+    for line in lines.range(start.0 + 1..end.0) {
+        text.push('\n');
+        text.push_str(line);
+    }
+    text.push('\n');
+    text.push_str(&lines[end.0][..end.1]);
+    text
+}
+
+/// Removes the text between two positions, joining their lines.
+fn remove_between(lines: &mut Lines, start: Pos, end: Pos) {
+    if start.0 == end.0 {
+        lines[start.0].replace_range(start.1..end.1, "");
+        return;
+    }
+    let tail = lines[end.0][end.1..].to_string();
+    lines[start.0].truncate(start.1);
+    lines[start.0].push_str(&tail);
+    lines.remove_range(start.0 + 1..end.0 + 1);
+}
+
+/// A cursor of a [`TextAreaState`] besides the main one, with its own selection.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct Cursor {
+    position: Pos,
+    /// Position where the selection started, the cursor being its other end
+    anchor: Option<Pos>,
+}
+
+impl Cursor {
+    /// Returns the selected range, from its first position to the position after it.
+    fn selection(&self) -> Option<(Pos, Pos)> {
+        let anchor = self.anchor.filter(|&anchor| anchor != self.position)?;
+        Some((anchor.min(self.position), anchor.max(self.position)))
+    }
+
+    /// Returns the range of the cursor, its selection or its position.
+    fn span(&self) -> (Pos, Pos) {
+        self.selection().unwrap_or((self.position, self.position))
+    }
+}
+
+/// Merges the cursors on the same position or whose selections overlap, given with whether each
+/// is the main one. Returns them in the order of the text.
+fn merge_cursors(mut cursors: Vec<(bool, Cursor)>) -> Vec<(bool, Cursor)> {
+    cursors.sort_unstable_by_key(|(_, cursor)| cursor.span());
+    let mut merged: Vec<(bool, Cursor)> = Vec::with_capacity(cursors.len());
+    for (is_main, cursor) in cursors {
+        let Some((last_is_main, last)) = merged.last_mut() else {
+            merged.push((is_main, cursor));
+            continue;
+        };
+        let (last_start, last_end) = last.span();
+        let (start, end) = cursor.span();
+        if start >= last_end && last.position != cursor.position {
+            merged.push((is_main, cursor));
+            continue;
+        }
+        // the merged cursor selects both ranges, on the side of the kept cursor
+        let end = end.max(last_end);
+        if is_main && !*last_is_main {
+            *last = cursor;
+        }
+        *last_is_main |= is_main;
+        if end != last_start {
+            let forward = last.anchor.map_or(true, |anchor| anchor <= last.position);
+            (last.position, last.anchor) = if forward {
+                (end, Some(last_start))
+            } else {
+                (last_start, Some(end))
+            };
+        }
+    }
+    merged
+}
+
+/// The text replaced by an edit, which moves the positions after it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct Replaced {
+    /// The first position where the text changed
+    start: Pos,
+    /// The position after the replaced text, before the edit
+    old_end: Pos,
+    /// The position after the new text
+    new_end: Pos,
+}
+
+impl Replaced {
+    /// Returns the text replaced by an edit made at the given position, or `None` if the text
+    /// didn't change.
+    ///
+    /// The replaced text is found by comparing the text before and after the edit, and starts
+    /// at the latest at the position of the edit, so that e.g. typing a letter after the same
+    /// letter moves the positions after the cursor.
+    fn find(before: &Lines, after: &Lines, at: Pos) -> Option<Self> {
+        let (prefix, suffix) = before.common_lines(after);
+        if prefix == before.len() && prefix == after.len() {
+            return None;
+        }
+        // the first line that changed, or the last one when lines were added or removed at the
+        // end
+        let row = prefix.min(before.len() - 1).min(after.len() - 1);
+        let start = (row, common_prefix_len(&before[row], &after[row])).min(at);
+        // the unchanged lines at the end, after the start
+        let suffix = suffix.min(before.len().min(after.len()) - 1 - start.0);
+        let (old_row, new_row) = (before.len() - 1 - suffix, after.len() - 1 - suffix);
+        let (old_line, new_line) = (&before[old_row], &after[new_row]);
+        let mut len = common_suffix_len(old_line, new_line);
+        if old_row == start.0 {
+            len = len.min(old_line.len() - start.1);
+        }
+        if new_row == start.0 {
+            len = len.min(new_line.len() - start.1);
+        }
+        Some(Self {
+            start,
+            old_end: (old_row, old_line.len() - len),
+            new_end: (new_row, new_line.len() - len),
+        })
+    }
+
+    /// Returns where a position before the edit is after it. The positions in the replaced text
+    /// move to its start.
+    fn shift(&self, position: Pos) -> Pos {
+        if position < self.start {
+            position
+        } else if position < self.old_end {
+            self.start
+        } else if position.0 == self.old_end.0 {
+            (self.new_end.0, self.new_end.1 + position.1 - self.old_end.1)
+        } else {
+            (position.0 - self.old_end.0 + self.new_end.0, position.1)
+        }
+    }
+
+    fn shift_cursor(&self, cursor: Cursor) -> Cursor {
+        Cursor {
+            position: self.shift(cursor.position),
+            anchor: cursor.anchor.map(|anchor| self.shift(anchor)),
+        }
+    }
+}
+
+/// Returns the length in bytes of the common start of two strings.
+fn common_prefix_len(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .zip(b.chars())
+        .find(|((_, x), y)| x != y)
+        .map_or(a.len().min(b.len()), |((i, _), _)| i)
+}
+
+/// Returns the length in bytes of the common end of two strings.
+fn common_suffix_len(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .rev()
+        .zip(b.chars().rev())
+        .find(|((_, x), y)| x != y)
+        .map_or(a.len().min(b.len()), |((i, x), _)| {
+            a.len() - i - x.len_utf8()
+        })
+}
+
+/// A pattern searched in the text of a [`TextAreaState`].
+#[derive(Debug, Clone)]
+enum SearchPattern {
+    Text(String),
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+impl SearchPattern {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Text(text) => text,
+            #[cfg(feature = "regex")]
+            Self::Regex(regex) => regex.as_str(),
+        }
+    }
+
+    /// Returns the byte ranges of the non-empty matches in a line.
+    fn find_in(&self, line: &str) -> Vec<Range<usize>> {
+        match self {
+            Self::Text(text) => line
+                .match_indices(text.as_str())
+                .map(|(i, text)| i..i + text.len())
+                .collect(),
+            #[cfg(feature = "regex")]
+            Self::Regex(regex) => regex
+                .find_iter(line)
+                .map(|found| found.range())
+                .filter(|range| !range.is_empty())
+                .collect(),
+        }
+    }
+}
+
+// compiled regular expressions are compared by their pattern
+impl PartialEq for SearchPattern {
+    fn eq(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+            && self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for SearchPattern {}
+
+impl Hash for SearchPattern {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        self.as_str().hash(state);
+    }
+}
+
+fn strip_cr(line: &str) -> String {
+    line.replace('\r', "")
+}
+
+/// Returns the number of columns taken by a grapheme in a text area, where a tab takes one
+/// column instead of none, so that it can be seen and the cursor can move over it.
+fn column_width(grapheme: &str) -> usize {
+    if grapheme == "\t" {
+        1
+    } else {
+        grapheme_width(grapheme)
+    }
+}
+
+/// Returns the number of columns taken by a string in a text area, the sum of the
+/// [`column_width`] of its graphemes.
+fn text_width(s: &str) -> usize {
+    s.graphemes(true).map(column_width).sum()
+}
+
+/// Returns the byte offset of the last grapheme boundary of the line at or before the given
+/// column on screen.
+fn byte_offset_at_column(line: &str, column: usize) -> usize {
+    let mut width = 0;
+    for (i, grapheme) in line.grapheme_indices(true) {
+        width += column_width(grapheme);
+        if width > column {
+            return i;
+        }
+    }
+    line.len()
+}
+
+/// Returns the style of the span of a highlighted line containing the given byte offset.
+fn span_style(line: &Line, offset: usize) -> Style {
+    let mut end = 0;
+    for span in &line.spans {
+        end += span.content.len();
+        if offset < end {
+            return span.style;
+        }
+    }
+    Style::default()
+}
+
+/// Returns the index of the row containing the given byte offset among the rows of a line: the
+/// first row ending after it, or the last row when it is at the end of the line.
+fn cursor_row_index(rows: &[WrappedRow], col: usize) -> usize {
+    rows.iter()
+        .position(|row| row.end > col)
+        .unwrap_or(rows.len() - 1)
+}
+
+/// A row of a wrapped line, as a range of byte offsets in the line.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct WrappedRow {
+    line: usize,
+    start: usize,
+    end: usize,
+}
+
+/// Splits a line into rows of at most `width` cells, breaking after whitespace when possible.
+///
+/// Whitespace overflowing a row is kept at the end of it, so that rows never start with the
+/// space that separated them from the previous row.
+fn wrap_line(line: &str, width: usize, index: usize, rows: &mut Vec<WrappedRow>) {
+    let mut start = 0;
+    let mut row_width = 0;
+    // byte offset after the last whitespace of the current row, and the width up to it
+    let mut last_break = None;
+    for (i, grapheme) in line.grapheme_indices(true) {
+        let grapheme_width = column_width(grapheme);
+        let is_whitespace = grapheme.chars().all(char::is_whitespace);
+        if row_width + grapheme_width > width && i > start {
+            if is_whitespace {
+                rows.push(WrappedRow {
+                    line: index,
+                    start,
+                    end: i + grapheme.len(),
+                });
+                start = i + grapheme.len();
+                row_width = 0;
+                last_break = None;
+                continue;
+            }
+            let (end, break_width) = last_break.unwrap_or((i, row_width));
+            rows.push(WrappedRow {
+                line: index,
+                start,
+                end,
+            });
+            start = end;
+            row_width -= break_width;
+            last_break = None;
+        }
+        row_width += grapheme_width;
+        if is_whitespace {
+            last_break = Some((i + grapheme.len(), row_width));
+        }
+    }
+    rows.push(WrappedRow {
+        line: index,
+        start,
+        end: line.len(),
+    });
+}
+
+impl Input for TextAreaState {
+    /// Handles the key with the default [`Keymap`], or with the vi-style editing when it is
+    /// enabled.
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        #[cfg(feature = "vim")]
+        if self.vim.is_some() {
+            return RedrawRequest::request_if(self.handle_vim_key(key));
+        }
+        RedrawRequest::request_if(self.handle_key_with(Keymap::default_ref(), key))
+    }
+
+    fn handle_paste(&mut self, text: &str) -> bool {
+        if self.read_only || self.disabled {
+            return false;
+        }
+        self.insert_str(text);
+        RedrawRequest::request_if(true)
+    }
+
+    fn handle_preedit(&mut self, text: Option<&str>) -> bool {
+        if self.read_only || self.disabled {
+            return false;
+        }
+        self.set_preedit(text);
+        RedrawRequest::request_if(true)
+    }
+
+    /// Moves the cursor to the cell clicked with the left button, selects the text up to the
+    /// cell where the mouse is dragged, and scrolls by one row with the mouse wheel over the
+    /// text area.
+    fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        if self.disabled {
+            return false;
+        }
+        let over = self.area.contains(mouse.position());
+        let handled = match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) if over => {
+                self.dragging = true;
+                let (row, col) = self.position_at(mouse.column, mouse.row);
+                self.set_cursor(row, col);
+                true
+            }
+            MouseEventKind::Drag(MouseButton::Left) if self.dragging => {
+                let (row, col) = self.position_at(mouse.column, mouse.row);
+                self.extend_selection(|state| state.set_cursor(row, col));
+                true
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                self.dragging = false;
+                false
+            }
+            MouseEventKind::ScrollUp if over => self.scroll_up(1),
+            MouseEventKind::ScrollDown if over => self.scroll_down(1),
+            _ => false,
+        };
+        RedrawRequest::request_if(handled)
+    }
+}
+
+impl CursorProvider for TextAreaState {
+    fn cursor_position(&self) -> Option<Position> {
+        self.screen_cursor.filter(|_| self.focused)
+    }
+}
+
+impl<'a> StatefulWidget for TextArea<'a> {
+    type State = TextAreaState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.read_only = self.read_only;
+        state.disabled = self.disabled;
+        buf.set_style(area, self.style);
+        if self.disabled {
+            buf.set_style(area, self.disabled_style);
+        }
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        state.screen_cursor = None;
+        // a disabled text area is rendered as if it didn't have the focus
+        let focused = state.focused && !state.disabled;
+        let gutter_width = self.line_numbers.gutter_width(state.lines.len());
+        let gutter = Rect {
+            width: gutter_width.min(area.width),
+            ..area
+        };
+        buf.set_style(gutter, self.line_number_style);
+        let area = Rect {
+            x: area.x + gutter.width,
+            width: area.width - gutter.width,
+            ..area
+        };
+        state.area = area;
+        if area.is_empty() {
+            return;
+        }
+        if !focused && state.lines.len() == 1 && state.lines[0].is_empty() {
+            self.render_line_number(buf, gutter, area.y, 0, 0);
+            if let Some(mut placeholder) = self.placeholder {
+                for span in &mut placeholder.spans {
+                    span.style = self.placeholder_style.patch(span.style);
+                }
+                buf.set_line(area.x, area.y, &placeholder, area.width);
+            }
+            return;
+        }
+
+        // the composed text is displayed before the cursor, as if it was already inserted
+        let preedit = state.preedit.as_deref().unwrap_or_default();
+        let mut lines = Cow::Borrowed(&state.lines);
+        if !preedit.is_empty() {
+            lines.to_mut()[state.row].insert_str(state.col, preedit);
+        }
+        let preedit_range = state.col..state.col + preedit.len();
+        let cursor_col = preedit_range.end;
+
+        let width = usize::from(area.width);
+        state.width = width;
+        state.wrap_navigation = self.wrap_navigation;
+        state.wrap_index.update(&state.lines, width);
+        // only the line of the cursor is wrapped with the composed text, the other lines keep
+        // their rows in the index
+        let mut cursor_rows = vec![];
+        wrap_line(&lines[state.row], width, state.row, &mut cursor_rows);
+        // the cursor is in the first row containing it, or in the last row of its line when it
+        // is at the end of the line
+        let mut cursor_index = cursor_row_index(&cursor_rows, cursor_col);
+        let row = cursor_rows[cursor_index];
+        let line = &lines[row.line];
+        let mut cursor_x = text_width(&line[row.start..cursor_col]);
+        if cursor_x >= width {
+            if cursor_col == line.len() {
+                // a full last row leaves no room for the cursor: give it its own row
+                cursor_index += 1;
+                cursor_rows.push(WrappedRow {
+                    line: row.line,
+                    start: line.len(),
+                    end: line.len(),
+                });
+                cursor_x = 0;
+            } else {
+                cursor_x = width - 1;
+            }
+        }
+        let index = &state.wrap_index;
+        let cursor_line_start = index.start(state.row);
+        let cursor_row = cursor_line_start + cursor_index;
+        // the rows after the line of the cursor move with the rows of the composed text
+        let after_cursor_line = cursor_line_start + cursor_rows.len();
+        let indexed_after_cursor_line = index.start(state.row + 1);
+        let total_rows = index.start(lines.len()) - indexed_after_cursor_line + after_cursor_line;
+
+        let height = usize::from(area.height);
+        let mut viewport = Viewport::new(0, state.offset, width, height);
+        viewport.clamp_rows(total_rows);
+        // the viewport follows the cursor, unless it was scrolled away and the cursor didn't move
+        if state.scrolled != Some(state.cursor()) {
+            state.scrolled = None;
+            viewport.ensure_rows_visible(cursor_row..cursor_row + 1);
+        }
+        state.offset = viewport.y;
+
+        let (first_line, skipped) = if state.offset < cursor_line_start {
+            index.line_at(state.offset)
+        } else if state.offset < after_cursor_line {
+            (state.row, state.offset - cursor_line_start)
+        } else {
+            index.line_at(state.offset - after_cursor_line + indexed_after_cursor_line)
+        };
+        let mut rows = wrap::rows_from(
+            &lines,
+            width,
+            first_line,
+            skipped + height,
+            (state.row, &cursor_rows),
+        );
+        rows.drain(..skipped.min(rows.len()));
+        rows.truncate(height);
+        let visible_lines = first_line..rows.last().map_or(first_line, |row| row.line + 1);
+
+        let highlighted = self.highlighter.as_ref().map(|highlighter| {
+            let visible = lines.range(visible_lines.clone());
+            highlighter.highlight(&visible.map(String::as_str).collect::<Vec<_>>())
+        });
+        let selections = state
+            .highlighted_range()
+            .into_iter()
+            .chain(state.cursors.iter().filter_map(Cursor::selection))
+            .collect::<Vec<_>>();
+        let matches = state.search_matches();
+        let mut text_rows = area.y..area.bottom();
+        for (y, row) in text_rows.by_ref().zip(&rows) {
+            if row.start == 0 {
+                self.render_line_number(buf, gutter, y, row.line, state.row);
+            }
+            let line = &lines[row.line];
+            let trailing = line.trim_end_matches([' ', '\t']).len();
+            let mut x = area.x;
+            for (i, grapheme) in line[row.start..row.end].grapheme_indices(true) {
+                let grapheme_width = column_width(grapheme) as u16;
+                if x + grapheme_width > area.right() {
+                    break;
+                }
+                // the position in the text, or `None` in the composed text
+                let mut col = Some(row.start + i);
+                if row.line == state.row && row.start + i >= preedit_range.start {
+                    col = (row.start + i)
+                        .checked_sub(preedit_range.end)
+                        .map(|after| preedit_range.start + after);
+                }
+                let mut style = highlighted.as_ref().map_or(Style::default(), |lines| {
+                    span_style(&lines[row.line - visible_lines.start], row.start + i)
+                });
+                let symbol = match self.whitespace.symbol(grapheme, row.start + i >= trailing) {
+                    Some(symbol) => {
+                        style = style.patch(self.whitespace_style);
+                        symbol
+                    }
+                    None if grapheme == "\t" => " ",
+                    None => grapheme,
+                };
+                buf.set_stringn(x, y, symbol, grapheme_width.into(), style);
+                let cell = Rect::new(x, y, grapheme_width, 1);
+                if let Some(severity) = col.and_then(|col| state.severity_at((row.line, col))) {
+                    buf.set_style(cell, self.diagnostic_styles[severity as usize]);
+                }
+                match col {
+                    None => buf.set_style(cell, self.preedit_style),
+                    Some(col)
+                        if selections
+                            .iter()
+                            .any(|(start, end)| (*start..*end).contains(&(row.line, col))) =>
+                    {
+                        buf.set_style(cell, self.selection_style);
+                    }
+                    Some(col)
+                        if matches
+                            .iter()
+                            .any(|(start, end)| (*start..*end).contains(&(row.line, col))) =>
+                    {
+                        buf.set_style(cell, self.match_style);
+                    }
+                    Some(_) => {}
+                }
+                x += grapheme_width;
+            }
+            if let Some(newline) = self.whitespace.newline {
+                if row.end == line.len() && row.line + 1 < lines.len() && x < area.right() {
+                    buf.set_stringn(x, y, newline, 1, self.whitespace_style);
+                    x += 1;
+                }
+            }
+            self.render_column_guide(buf, area, y, x);
+        }
+        for y in text_rows {
+            self.render_column_guide(buf, area, y, area.x);
+        }
+        if focused && (state.offset..state.offset + height).contains(&cursor_row) {
+            let x = area.x + cursor_x as u16;
+            let y = area.y + (cursor_row - state.offset) as u16;
+            state.screen_cursor = Some(Position::new(x, y));
+            buf.set_style(Rect::new(x, y, 1, 1), self.cursor_style);
+        }
+        if focused {
+            self.render_other_cursors(buf, area, state, &lines, &rows, preedit.len());
+        }
+    }
+}
+
+impl<'a> Widget for TextArea<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = TextAreaState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_buffer_eq, style::Stylize, text::Span, widgets::Borders};
+
+    fn render(state: &mut TextAreaState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        let text_area = TextArea::new().cursor_style(Style::default());
+        StatefulWidget::render(text_area, buf.area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn search_highlights_and_jumps_to_matches() {
+        let mut state = TextAreaState::new("ab\nxx\nab ab");
+        assert_eq!(state.search("ab"), 3);
+        assert_eq!(state.search_pattern(), Some("ab"));
+        state.set_cursor(0, 1);
+        assert!(state.next_match());
+        assert_eq!(state.cursor(), (2, 0));
+        assert!(state.prev_match());
+        assert_eq!(state.cursor(), (0, 0));
+        assert!(state.prev_match());
+        assert_eq!(state.cursor(), (2, 3));
+
+        // the viewport follows the cursor to the match
+        state.move_to_start();
+        assert!(state.next_match());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let text_area = TextArea::new()
+            .cursor_style(Style::default())
+            .match_style(Style::new().bg(Color::Yellow));
+        StatefulWidget::render(text_area, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["ab ab"]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().bg(Color::Yellow));
+        expected.set_style(Rect::new(3, 0, 2, 1), Style::new().bg(Color::Yellow));
+        assert_buffer_eq!(buf, expected);
+
+        // the matches follow the edits
+        state.move_right();
+        state.insert_char('x');
+        assert_eq!(state.search_matches(), [((0, 0), (0, 2)), ((2, 4), (2, 6))]);
+        assert_eq!(state.search(""), 0);
+        assert!(!state.next_match());
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn search_regex() {
+        let mut state = TextAreaState::new("Foo fo\nfoo");
+        assert_eq!(state.search_regex("(?i)fo+\\b"), Ok(3));
+        assert!(state.search_regex("(").is_err());
+        assert_eq!(state.search_pattern(), Some("(?i)fo+\\b"));
+        assert_eq!(state.search_regex("x*"), Ok(0));
+    }
+
+    #[test]
+    fn newlines_split_and_join_lines() {
+        let mut state = TextAreaState::new("hello world");
+        state.set_cursor(0, 5);
+        state.insert_char('\n');
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["hello", " world"]);
+        assert_eq!(state.cursor(), (1, 0));
+        assert!(state.delete_before());
+        assert_eq!(state.value(), "hello world");
+        assert_eq!(state.cursor(), (0, 5));
+        state.insert_newline();
+        state.move_left();
+        assert!(state.delete_after());
+        assert_eq!(state.value(), "hello world");
+    }
+
+    #[test]
+    fn insert_str_with_line_breaks() {
+        let mut state = TextAreaState::default();
+        state.insert_str("a\r\nb\nc");
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["a", "b", "c"]);
+        assert_eq!(state.cursor(), (2, 1));
+        state.clear();
+        assert_eq!(state.lines().collect::<Vec<_>>(), [""]);
+        assert!(!state.delete_before());
+        assert!(!state.delete_after());
+    }
+
+    #[test]
+    fn kill_and_yank_across_lines() {
+        KillRing::clear();
+        let mut state = TextAreaState::new("foo bar\nbaz");
+        state.set_cursor(0, 4);
+        assert!(state.execute(EditCommand::KillToEnd));
+        assert!(state.execute(EditCommand::KillToEnd));
+        assert!(state.execute(EditCommand::KillToEnd));
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["foo "]);
+        assert_eq!(KillRing::yank().as_deref(), Some("bar\nbaz"));
+        assert!(!state.kill_word_after());
+        assert_eq!(KillRing::len(), 1);
+
+        state.set_cursor(0, 0);
+        assert!(state.yank());
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["bar", "bazfoo "]);
+        assert_eq!(state.cursor(), (1, 3));
+        assert!(state.execute(EditCommand::KillToStart));
+        assert!(state.execute(EditCommand::KillWordBefore));
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["barfoo "]);
+        assert_eq!(KillRing::yank().as_deref(), Some("\nbaz"));
+        assert!(state.undo());
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["bar", "foo "]);
+    }
+
+    #[test]
+    fn diagnostics() {
+        let mut state = TextAreaState::new("teh cät\nsat on");
+        let cat = state.grapheme_position(0, 5);
+        assert_eq!(cat, (0, 5));
+        assert_eq!(state.grapheme_position(0, 7), (0, 8));
+        assert_eq!(state.grapheme_position(5, 1), (1, 1));
+        state.set_diagnostics([
+            Diagnostic::new((1, 4), (1, 6), Severity::Hint, "on what?"),
+            Diagnostic::new((0, 0), (0, 3), Severity::Warning, "misspelled"),
+            Diagnostic::new((0, 1), (0, 2), Severity::Error, "not a letter"),
+            Diagnostic::new((1, 3), (1, 0), Severity::Error, "ignored"),
+        ]);
+        assert_eq!(state.diagnostics().len(), 3);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 2));
+        let text_area = TextArea::new()
+            .cursor_style(Style::default())
+            .diagnostic_style(Severity::Error, Style::new().red())
+            .diagnostic_style(Severity::Warning, Style::new().yellow())
+            .diagnostic_style(Severity::Hint, Style::new().italic());
+        StatefulWidget::render(text_area, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["teh cät", "sat on"]);
+        expected.set_style(Rect::new(0, 0, 3, 1), Style::new().yellow());
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().red());
+        expected.set_style(Rect::new(4, 1, 2, 1), Style::new().italic());
+        assert_buffer_eq!(buf, expected);
+
+        // F8 and Shift-F8 cycle through the diagnostics
+        state.move_to_start();
+        assert_eq!(
+            state.diagnostic_at_cursor().map(|d| d.severity),
+            Some(Severity::Warning)
+        );
+        assert!(state.handle_key(Key::F(8).into()));
+        assert_eq!(state.cursor(), (0, 1));
+        assert_eq!(
+            state.diagnostic_at_cursor().map(|d| d.severity),
+            Some(Severity::Error)
+        );
+        assert!(state.next_diagnostic());
+        assert_eq!(state.cursor(), (1, 4));
+        assert!(state.next_diagnostic());
+        assert_eq!(state.cursor(), (0, 0));
+        assert!(state.handle_key(KeyEvent::new(Key::F(8), KeyModifiers::SHIFT)));
+        assert_eq!(state.cursor(), (1, 4));
+        state.set_cursor(1, 0);
+        assert_eq!(state.diagnostic_at_cursor(), None);
+
+        state.clear_diagnostics();
+        assert!(!state.next_diagnostic());
+        assert!(!state.prev_diagnostic());
+    }
+
+    #[test]
+    fn render_whitespace() {
+        let mut state = TextAreaState::new("a\tb  \n\tc\n");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+        let text_area = TextArea::new()
+            .cursor_style(Style::default())
+            .whitespace(Whitespace::VISIBLE)
+            .whitespace_style(Style::new().blue());
+        StatefulWidget::render(text_area, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["a→b··↵ ", "→c↵    ", "       "]);
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().blue());
+        expected.set_style(Rect::new(3, 0, 3, 1), Style::new().blue());
+        expected.set_style(Rect::new(0, 1, 1, 1), Style::new().blue());
+        expected.set_style(Rect::new(2, 1, 1, 1), Style::new().blue());
+        assert_buffer_eq!(buf, expected);
+
+        // hidden tabs still take a column
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+        let text_area = TextArea::new().cursor_style(Style::default());
+        StatefulWidget::render(text_area, buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(
+            buf,
+            Buffer::with_lines(vec!["a b    ", " c     ", "       "])
+        );
+        state.set_cursor(1, 1);
+        assert_eq!(state.visual_cursor_column(), 1);
+    }
+
+    #[test]
+    fn render_column_guide() {
+        let mut state = TextAreaState::new("abc\nabcdef");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 3));
+        let text_area = TextArea::new()
+            .cursor_style(Style::default())
+            .column_guide(4)
+            .column_guide_style(Style::new().red());
+        StatefulWidget::render(text_area, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["abc │ ", "abcdef", "    │ "]);
+        expected.set_style(Rect::new(4, 0, 1, 1), Style::new().red());
+        expected.set_style(Rect::new(4, 2, 1, 1), Style::new().red());
+        assert_buffer_eq!(buf, expected);
+
+        // a guide outside of the text area isn't drawn
+        state.set_cursor(1, 0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let text_area = TextArea::new()
+            .cursor_style(Style::default())
+            .column_guide(6)
+            .column_guide_symbol("|");
+        StatefulWidget::render(text_area, buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["abcdef"]));
+    }
+
+    #[test]
+    fn auto_pairs() {
+        let mut state = TextAreaState::default().with_auto_pairs(TextAreaState::AUTO_PAIRS);
+        for c in "f(x[0".chars() {
+            state.insert_char(c);
+        }
+        assert_eq!(state.value(), "f(x[0])");
+        // the closing characters move over the inserted ones
+        state.insert_char(']');
+        state.insert_char(')');
+        assert_eq!(state.value(), "f(x[0])");
+        assert_eq!(state.cursor(), (0, 7));
+
+        // quotes aren't closed after a letter, and brackets before a word
+        state.set_value("it s");
+        state.set_cursor(0, 2);
+        state.insert_char('\'');
+        assert_eq!(state.value(), "it' s");
+        state.set_cursor(0, 4);
+        state.insert_char('(');
+        assert_eq!(state.value(), "it' (s");
+        state.set_cursor(0, 3);
+        state.insert_char('"');
+        assert_eq!(state.value(), "it'\"\" (s");
+
+        // backspace deletes an empty pair, and undo restores it with the typed character
+        assert!(state.delete_before());
+        assert_eq!(state.value(), "it' (s");
+        assert_eq!(state.cursor(), (0, 3));
+        assert!(state.undo());
+        assert_eq!(state.value(), "it'\"\" (s");
+        assert!(state.undo());
+        assert_eq!(state.value(), "it' (s");
+
+        // the selection is surrounded
+        state.set_value("a b\nc");
+        state.set_cursor(0, 2);
+        state.execute(EditCommand::SelectDown);
+        state.insert_char('{');
+        assert_eq!(state.value(), "a {b\nc}");
+        assert_eq!(state.selected_text().as_deref(), Some("b\nc"));
+
+        // the pasted text isn't paired, and pairs can be disabled
+        state.set_value("");
+        state.insert_str("(");
+        assert_eq!(state.value(), "(");
+        state.set_auto_pairs([]);
+        state.insert_char('[');
+        assert_eq!(state.value(), "([");
+        assert!(state.auto_pairs().is_empty());
+    }
+
+    #[test]
+    fn auto_indent() {
+        let mut state = TextAreaState::new("  \tfoo").with_auto_indent(true);
+        assert!(state.auto_indent());
+        assert!(state.execute(EditCommand::InsertNewline));
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["  \tfoo", "  \t"]);
+        assert_eq!(state.cursor(), (1, 3));
+        // only the indentation before the cursor is carried over
+        state.set_cursor(0, 1);
+        state.insert_newline();
+        assert_eq!(state.lines().collect::<Vec<_>>(), [" ", "  \tfoo", "  \t"]);
+        assert_eq!(state.cursor(), (1, 1));
+        // pasted lines aren't indented
+        state.set_value("  a");
+        state.insert_str("\nb");
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["  a", "b"]);
+        state.set_auto_indent(false);
+        state.set_cursor(0, 3);
+        state.insert_char('\n');
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["  a", "", "b"]);
+    }
+
+    #[test]
+    fn large_text() {
+        let text = (0..100_000)
+            .map(|i| format!("line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut state = TextAreaState::new(&text);
+        assert_eq!(state.line_count(), 100_000);
+        assert_eq!(state.line(50_000), Some("line 50000"));
+        assert_eq!(state.line(100_000), None);
+        state.set_cursor(50_000, 4);
+        state.insert_str("\n\nnew");
+        state.insert_char('!');
+        state.execute(EditCommand::DeleteWordBefore);
+        state.set_cursor(10, 0);
+        state.execute(EditCommand::SelectDown);
+        state.execute(EditCommand::SelectDown);
+        state.execute(EditCommand::DeleteBefore);
+        assert_eq!(state.line_count(), 100_000);
+        assert_eq!(state.line(10), Some("line 12"));
+        assert_eq!(state.line(49_998), Some("line"));
+        assert_eq!(state.line(49_999), Some(""));
+        assert_eq!(state.line(50_000), Some(" 50000"));
+        while state.undo() {}
+        assert_eq!(state.value(), text);
+    }
+
+    #[test]
+    fn multiple_cursors() {
+        let mut state = TextAreaState::new("ab\ncd\nx");
+        state.set_cursor(0, 1);
+        assert!(!state.add_cursor_above());
+        assert!(state.execute(EditCommand::AddCursorBelow));
+        assert!(state.add_cursor_below());
+        assert!(!state.add_cursor_below());
+        // the cursor is clamped to the short line
+        assert_eq!(state.cursors(), [(0, 1), (1, 1), (2, 1)]);
+        state.insert_char('-');
+        state.insert_char('-');
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["a--b", "c--d", "x--"]);
+        assert_eq!(state.cursor(), (0, 3));
+        assert_eq!(state.cursors(), [(0, 3), (1, 3), (2, 3)]);
+        // motions move every cursor, and merge the cursors that meet
+        state.execute(EditCommand::MoveHome);
+        state.execute(EditCommand::DeleteAfter);
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["--b", "--d", "--"]);
+        state.execute(EditCommand::MoveUp);
+        assert_eq!(state.cursors(), [(0, 0), (1, 0)]);
+        state.execute(EditCommand::InsertNewline);
+        assert_eq!(
+            state.lines().collect::<Vec<_>>(),
+            ["", "--b", "", "--d", "--"]
+        );
+        assert_eq!(state.cursors(), [(1, 0), (3, 0)]);
+        // one undo step per command, typed characters being coalesced
+        assert!(state.undo());
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["--b", "--d", "--"]);
+        assert!(state.undo());
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["a--b", "c--d", "x--"]);
+        assert!(state.undo());
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["ab", "cd", "x"]);
+        assert_eq!(state.cursors(), [(0, 1)]);
+        assert!(state.add_cursor_below());
+        assert!(state.handle_key(Key::Esc.into()));
+        assert_eq!(state.cursors(), [(0, 1)]);
+        assert!(!state.handle_key(Key::Esc.into()));
+    }
+
+    /// Returns a state with two cursors on the first line, at the end of "ab" and of "abcd".
+    fn two_cursors_on_one_line() -> TextAreaState {
+        let mut state = TextAreaState::new("ab\ncd");
+        state.set_cursor(1, 0);
+        assert!(state.add_cursor_above());
+        state.handle_key(Key::End.into());
+        state.handle_key(Key::Delete.into());
+        assert_eq!(state.value(), "abcd");
+        assert_eq!(state.cursors(), [(0, 2), (0, 4)]);
+        state
+    }
+
+    #[test]
+    fn multiple_cursors_kill_to_start() {
+        let mut state = two_cursors_on_one_line();
+        // the kill at the last cursor removes the first one, which is merged
+        state.handle_key(KeyEvent::new(Key::Char('u'), KeyModifiers::CONTROL));
+        assert_eq!(state.value(), "");
+        assert_eq!(state.cursors(), [(0, 0)]);
+        render(&mut state, 4, 1);
+        state.insert_char('x');
+        assert_eq!(state.value(), "x");
+        assert!(state.undo());
+        assert_eq!(state.value(), "");
+        assert!(state.undo());
+        assert_eq!(state.value(), "abcd");
+    }
+
+    #[test]
+    fn multiple_cursors_kill_to_end() {
+        let mut state = two_cursors_on_one_line();
+        state.handle_key(Key::Left.into());
+        assert_eq!(state.cursors(), [(0, 1), (0, 3)]);
+        // the kill at the first cursor removes the last one, which is merged
+        state.handle_key(KeyEvent::new(Key::Char('k'), KeyModifiers::CONTROL));
+        assert_eq!(state.value(), "a");
+        assert_eq!(state.cursors(), [(0, 1)]);
+        render(&mut state, 4, 1);
+    }
+
+    #[test]
+    fn multiple_cursors_move_with_the_edits() {
+        let mut state = two_cursors_on_one_line();
+        // typing the letter before the cursor moves the last cursor after both insertions
+        state.handle_key(Key::Left.into());
+        state.insert_char('a');
+        assert_eq!(state.value(), "aabcad");
+        assert_eq!(state.cursors(), [(0, 2), (0, 5)]);
+        // the deletions at the cursors don't overlap
+        state.handle_key(Key::Backspace.into());
+        assert_eq!(state.value(), "abcd");
+        assert_eq!(state.cursors(), [(0, 1), (0, 3)]);
+        state.execute(EditCommand::SelectRight);
+        assert_eq!(state.selections(), [((0, 1), (0, 2)), ((0, 3), (0, 4))]);
+        for _ in 0..4 {
+            state.execute(EditCommand::SelectLeft);
+        }
+        // the overlapping selections are merged
+        assert_eq!(state.selections(), [((0, 0), (0, 3))]);
+        assert_eq!(state.cursors(), [(0, 0)]);
+    }
+
+    #[test]
+    fn select_next_occurrence() {
+        let mut state = TextAreaState::new("foo bar\nfoo foobar");
+        state.set_cursor(1, 1);
+        assert!(state.execute(EditCommand::SelectNextOccurrence));
+        assert_eq!(state.selected_text().as_deref(), Some("foo"));
+        assert!(state.select_next_occurrence());
+        assert!(state.select_next_occurrence());
+        assert_eq!(
+            state.selections(),
+            [((0, 0), (0, 3)), ((1, 0), (1, 3)), ((1, 4), (1, 7))]
+        );
+        // every occurrence is selected
+        assert!(!state.select_next_occurrence());
+        state.insert_str("baz");
+        assert_eq!(state.value(), "baz bar\nbaz bazbar");
+        assert!(state.undo());
+        assert_eq!(state.value(), "foo bar\nfoo foobar");
+        state.set_cursor(0, 3);
+        assert!(!TextAreaState::new(" ").select_next_occurrence());
+    }
+
+    #[test]
+    fn render_multiple_cursors() {
+        let mut state = TextAreaState::new("abc\nd\nefg");
+        state.set_cursor(0, 2);
+        state.add_cursor_below();
+        state.add_cursor_below();
+        state.execute(EditCommand::SelectLeft);
+        let text_area = TextArea::new()
+            .cursor_style(Style::new().fg(Color::Red))
+            .selection_style(Style::new().bg(Color::Blue));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        StatefulWidget::render(text_area, buf.area, &mut buf, &mut state);
+        // each cursor is on its selected grapheme
+        let mut expected = Buffer::with_lines(vec!["abc ", "d   ", "efg "]);
+        let style = Style::new().fg(Color::Red).bg(Color::Blue);
+        expected.set_style(Rect::new(1, 0, 1, 1), style);
+        expected.set_style(Rect::new(0, 1, 1, 1), style);
+        expected.set_style(Rect::new(1, 2, 1, 1), style);
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn shift_arrows_select_across_lines() {
+        let shift = |key| KeyEvent::new(key, KeyModifiers::SHIFT);
+        let mut state = TextAreaState::new("one\ntwo\nthree");
+        state.set_cursor(0, 1);
+        assert!(state.handle_key(shift(Key::Down)));
+        assert!(state.handle_key(shift(Key::End)));
+        assert_eq!(state.selection(), Some(((0, 1), (1, 3))));
+        assert_eq!(state.selected_text().as_deref(), Some("ne\ntwo"));
+        assert!(state.handle_key(Key::Left.into()));
+        assert_eq!(state.selection(), None);
+
+        state.select_all();
+        assert_eq!(state.selected_text(), Some(state.value()));
+    }
+
+    #[test]
+    fn edits_replace_selection() {
+        let mut state = TextAreaState::new("one\ntwo\nthree");
+        state.set_cursor(0, 2);
+        state.execute(EditCommand::SelectDown);
+        state.execute(EditCommand::SelectDown);
+        state.insert_char('x');
+        assert_eq!(state.value(), "onxree");
+        assert_eq!(state.cursor(), (0, 3));
+        assert!(state.undo());
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["one", "two", "three"]);
+
+        state.set_cursor(1, 0);
+        state.execute(EditCommand::SelectLeft);
+        assert!(state.delete_word_after());
+        assert_eq!(state.value(), "onetwo\nthree");
+        state.execute(EditCommand::SelectWordRight);
+        state.insert_newline();
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["one", "", "three"]);
+
+        state.set_cursor(2, 0);
+        state.execute(EditCommand::SelectUp);
+        assert_eq!(state.take_selection().as_deref(), Some("\n"));
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["one", "three"]);
+    }
+
+    #[test]
+    fn render_selection() {
+        let mut state = TextAreaState::new("ab\ncd");
+        state.set_cursor(0, 1);
+        state.execute(EditCommand::SelectDown);
+        let text_area = TextArea::new()
+            .cursor_style(Style::default())
+            .selection_style(Style::new().bg(Color::Blue));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+        StatefulWidget::render(text_area, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["ab ", "cd "]);
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().bg(Color::Blue));
+        expected.set_style(Rect::new(0, 1, 1, 1), Style::new().bg(Color::Blue));
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_highlighted_lines() {
+        struct Digits;
+        impl Highlighter for Digits {
+            fn highlight_line<'t>(&self, line: &'t str) -> Line<'t> {
+                let spans = line.char_indices().map(|(i, c)| {
+                    let span = Span::raw(&line[i..i + c.len_utf8()]);
+                    if c.is_ascii_digit() {
+                        span.red()
+                    } else {
+                        span
+                    }
+                });
+                Line::from(spans.collect::<Vec<_>>())
+            }
+        }
+
+        let mut state = TextAreaState::new("a1 b\n2c");
+        state.set_cursor(1, 1);
+        state.execute(EditCommand::SelectRight);
+        let text_area = TextArea::new()
+            .cursor_style(Style::default())
+            .selection_style(Style::new().bg(Color::Blue))
+            .highlighter(&Digits);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        StatefulWidget::render(text_area, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["a1 b", "2c  "]);
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().red());
+        expected.set_style(Rect::new(0, 1, 1, 1), Style::new().red());
+        expected.set_style(Rect::new(1, 1, 1, 1), Style::new().bg(Color::Blue));
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_large_text() {
+        /// Records the lines it highlights.
+        struct Recorder(std::cell::RefCell<Vec<String>>);
+        impl Highlighter for Recorder {
+            fn highlight_line<'t>(&self, line: &'t str) -> Line<'t> {
+                self.0.borrow_mut().push(line.to_string());
+                Line::raw(line)
+            }
+        }
+
+        let text = (0..10_000).map(|i| format!("{i} ab")).collect::<Vec<_>>();
+        let mut state = TextAreaState::new(text.join("\n"));
+        state.set_cursor(5000, 0);
+        let recorder = Recorder(std::cell::RefCell::default());
+        let mut render = |state: &mut TextAreaState| {
+            let text_area = TextArea::new()
+                .cursor_style(Style::new())
+                .preedit_style(Style::new())
+                .highlighter(&recorder);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+            StatefulWidget::render(text_area, buf.area, &mut buf, state);
+            buf
+        };
+        let buf = render(&mut state);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["4999 ", "ab   ", "5000 "]));
+        assert_eq!(recorder.0.take(), ["4999 ab", "5000 ab"]);
+
+        // the rows after a wrapped line move down
+        state.set_cursor(4000, 4);
+        state.insert_str(" cd ef");
+        state.set_cursor(6000, 0);
+        state.set_preedit(Some("xy z "));
+        let buf = render(&mut state);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["ab   ", "xy z ", "6000 "]));
+        assert_eq!(recorder.0.take(), ["5999 ab", "xy z 6000 ab"]);
+        assert!(state.scroll_down(4));
+        let buf = render(&mut state);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["6001 ", "ab   ", "6002 "]));
+        state.set_preedit(None::<String>);
+        assert!(state.scroll_up(12_001));
+        let buf = render(&mut state);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["0 ab ", "1 ab ", "2 ab "]));
+        let click = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 1, 2);
+        assert!(state.handle_mouse(click));
+        assert_eq!(state.cursor(), (2, 1));
+    }
+
+    #[test]
+    fn undo_redo() {
+        let mut state = TextAreaState::new("a");
+        state.insert_char('b');
+        state.insert_char('c');
+        state.insert_newline();
+        state.insert_char('d');
+        assert_eq!(state.value(), "abc\nd");
+        assert!(state.undo());
+        assert_eq!(state.value(), "abc\n");
+        assert!(state.undo());
+        assert_eq!(state.value(), "abc");
+        assert!(state.undo());
+        assert_eq!((state.value(), state.cursor()), ("a".to_string(), (0, 1)));
+        assert!(!state.undo());
+        assert!(state.redo());
+        assert!(state.redo());
+        assert_eq!(
+            (state.value(), state.cursor()),
+            ("abc\n".to_string(), (1, 0))
+        );
+        state.move_to_start();
+        assert!(state.delete_after());
+        assert!(state.undo());
+        assert_eq!(state.value(), "abc\n");
+        assert!(!state.delete_before());
+        state.clear();
+        assert!(state.undo());
+        assert_eq!(state.value(), "abc\n");
+    }
+
+    #[test]
+    fn handle_key() {
+        let mut state = TextAreaState::default();
+        assert!(state.handle_key(Key::Char('a').into()));
+        assert!(state.handle_key(Key::Enter.into()));
+        assert!(state.handle_key(Key::Char('b').into()));
+        assert!(state.handle_key(Key::Up.into()));
+        assert!(state.handle_key(Key::End.into()));
+        assert!(state.handle_key(Key::Delete.into()));
+        assert!(!state.handle_key(KeyEvent::new(Key::Char('c'), KeyModifiers::ALT)));
+        assert!(!state.handle_key(Key::Esc.into()));
+        assert_eq!(state.value(), "ab");
+        assert!(state.handle_paste("1\n2"));
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["a1", "2b"]);
+    }
+
+    #[test]
+    fn cursor_movement() {
+        let mut state = TextAreaState::new("abc\nde\nfghij");
+        assert_eq!(state.cursor(), (2, 5));
+        assert!(state.move_up());
+        assert_eq!(state.cursor(), (1, 2));
+        state.move_home();
+        assert!(state.move_left());
+        assert_eq!(state.cursor(), (0, 3));
+        assert!(state.move_right());
+        assert_eq!(state.cursor(), (1, 0));
+        assert!(state.move_down());
+        assert_eq!(state.cursor(), (2, 0));
+        state.move_end();
+        assert!(!state.move_right());
+        assert!(!state.move_down());
+        state.move_to_start();
+        assert!(!state.move_left());
+        assert!(!state.move_up());
+    }
+
+    #[test]
+    fn grapheme_cursor_accessors() {
+        let mut state = TextAreaState::new("x\na中e\u{301}b");
+        assert_eq!(state.cursor(), (1, 8));
+        assert_eq!(state.cursor_grapheme_index(), 4);
+        assert_eq!(state.visual_cursor_column(), 5);
+        state.set_cursor_grapheme_index(2);
+        assert_eq!(state.cursor(), (1, 4));
+        assert_eq!(state.visual_cursor_column(), 3);
+        assert!(state.move_left());
+        assert_eq!(state.cursor_grapheme_index(), 1);
+        assert_eq!(state.visual_cursor_column(), 1);
+    }
+
+    #[test]
+    fn move_up_keeps_screen_column() {
+        let mut state = TextAreaState::new("日本語\nabcd");
+        state.set_cursor(1, 3);
+        assert!(state.move_up());
+        assert_eq!(state.cursor(), (0, 3));
+    }
+
+    #[test]
+    fn visual_wrap_navigation() {
+        let mut state = TextAreaState::new("one two three\nfour");
+        state.set_cursor(0, 1);
+        // the moves are by line until the lines are wrapped by a render
+        let text_area = TextArea::new().wrap_navigation(WrapNavigation::Visual);
+        assert!(state.move_down());
+        assert_eq!(state.cursor(), (1, 1));
+        state.set_cursor(0, 1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 4));
+        StatefulWidget::render(text_area, buf.area, &mut buf, &mut state);
+        // rows: "one ", "two ", "three", "four"
+        assert!(state.move_down());
+        assert_eq!(state.cursor(), (0, 5));
+        state.move_end();
+        assert_eq!(state.cursor(), (0, 7));
+        state.move_home();
+        assert_eq!(state.cursor(), (0, 4));
+        state.move_end();
+        assert!(state.move_down());
+        assert_eq!(state.cursor(), (0, 11));
+        assert!(state.move_down());
+        assert_eq!(state.cursor(), (1, 3));
+        state.move_end();
+        assert!(state.move_up());
+        assert_eq!(state.cursor(), (0, 12));
+        state.move_end();
+        assert_eq!(state.cursor(), (0, 13));
+        state.set_cursor(0, 2);
+        assert!(!state.move_up());
+
+        // the logical navigation ignores the wrapping
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 4));
+        StatefulWidget::render(TextArea::new(), buf.area, &mut buf, &mut state);
+        assert!(state.move_down());
+        assert_eq!(state.cursor(), (1, 2));
+        state.set_cursor(0, 2);
+        state.move_end();
+        assert_eq!(state.cursor(), (0, 13));
+    }
+
+    #[test]
+    fn emacs_keys() {
+        let ctrl = |c| KeyEvent::new(Key::Char(c), KeyModifiers::CONTROL);
+        let alt = |c| KeyEvent::new(Key::Char(c), KeyModifiers::ALT);
+        let mut state = TextAreaState::new("one two\nthree");
+        state.set_cursor(1, 0);
+        assert!(state.handle_key(alt('b')));
+        assert_eq!(state.cursor(), (0, 7));
+        assert!(state.handle_key(alt('b')));
+        assert_eq!(state.cursor(), (0, 4));
+        assert!(state.handle_key(ctrl('k')));
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["one ", "three"]);
+        assert!(state.handle_key(ctrl('k')));
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["one three"]);
+        assert!(state.handle_key(alt('f')));
+        assert_eq!(state.cursor(), (0, 9));
+        assert!(state.handle_key(ctrl('w')));
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["one "]);
+        assert!(state.handle_key(ctrl('u')));
+        assert_eq!(state.lines().collect::<Vec<_>>(), [""]);
+        assert!(state.handle_key(ctrl('z')));
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["one "]);
+    }
+
+    #[test]
+    fn wrap_line_at_words() {
+        let mut rows = vec![];
+        wrap_line("a long line", 6, 0, &mut rows);
+        let rows = rows.iter().map(|r| (r.start, r.end)).collect::<Vec<_>>();
+        assert_eq!(rows, [(0, 7), (7, 11)]);
+
+        let mut rows = vec![];
+        wrap_line("abcdefgh", 3, 0, &mut rows);
+        let rows = rows.iter().map(|r| (r.start, r.end)).collect::<Vec<_>>();
+        assert_eq!(rows, [(0, 3), (3, 6), (6, 8)]);
+    }
+
+    #[test]
+    fn render_wraps_lines() {
+        let mut state = TextAreaState::new("the quick brown fox\nend");
+        assert_buffer_eq!(
+            render(&mut state, 10, 4),
+            Buffer::with_lines(vec!["the quick ", "brown fox ", "end       ", "          "])
+        );
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(3, 2)));
+    }
+
+    #[test]
+    fn render_line_numbers() {
+        let text = (1..=10).map(|i| format!("line {i}")).collect::<Vec<_>>();
+        let mut state = TextAreaState::new(text.join("\n"));
+        state.set_cursor(1, 4);
+        let render = |state: &mut TextAreaState, line_numbers| {
+            let text_area = TextArea::new()
+                .cursor_style(Style::default())
+                .line_numbers(line_numbers)
+                .line_number_style(Style::default());
+            let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+            StatefulWidget::render(text_area, buf.area, &mut buf, state);
+            buf
+        };
+        // the gutter is 3 columns wide for 10 lines, and the text wraps in the other 5
+        assert_buffer_eq!(
+            render(&mut state, LineNumbers::Absolute),
+            Buffer::with_lines(vec![" 1 line ", "   1    ", " 2 line "])
+        );
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(7, 2)));
+        state.set_cursor(2, 0);
+        assert_buffer_eq!(
+            render(&mut state, LineNumbers::Relative),
+            Buffer::with_lines(vec![" 1 line ", "   2    ", " 3 line "])
+        );
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(3, 2)));
+    }
+
+    #[test]
+    fn handle_mouse() {
+        let mut state = TextAreaState::new("one two three\nfour");
+        // rows: "one ", "two ", "three", "four", the last two being visible
+        render(&mut state, 5, 2);
+        let left = MouseButton::Left;
+        assert!(state.handle_mouse(MouseEvent::new(MouseEventKind::Down(left), 1, 0)));
+        assert_eq!(state.cursor(), (0, 9));
+        assert!(state.handle_mouse(MouseEvent::new(MouseEventKind::Drag(left), 2, 1)));
+        assert!(!state.handle_mouse(MouseEvent::new(MouseEventKind::Up(left), 2, 1)));
+        assert_eq!(state.selected_text().as_deref(), Some("hree\nfo"));
+
+        // the wheel scrolls the viewport away from the cursor, until the cursor moves
+        state.clear_selection();
+        assert!(state.handle_mouse(MouseEvent::new(MouseEventKind::ScrollUp, 0, 0)));
+        assert!(!state.handle_mouse(MouseEvent::new(MouseEventKind::ScrollUp, 9, 9)));
+        assert_buffer_eq!(
+            render(&mut state, 5, 2),
+            Buffer::with_lines(vec!["two  ", "three"])
+        );
+        assert_eq!(state.cursor_screen_position(), None);
+        assert!(state.handle_mouse(MouseEvent::new(MouseEventKind::ScrollDown, 0, 0)));
+        assert!(!state.handle_mouse(MouseEvent::new(MouseEventKind::ScrollDown, 0, 0)));
+        state.scroll_up(2);
+        state.move_left();
+        assert_buffer_eq!(
+            render(&mut state, 5, 2),
+            Buffer::with_lines(vec!["three", "four "])
+        );
+    }
+
+    #[test]
+    fn render_scrolls_to_cursor() {
+        let mut state = TextAreaState::new("1\n2\n3\n4\n5");
+        assert_buffer_eq!(
+            render(&mut state, 3, 2),
+            Buffer::with_lines(vec!["4  ", "5  "])
+        );
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(1, 1)));
+
+        // moving inside the viewport doesn't scroll
+        state.move_up();
+        assert_buffer_eq!(
+            render(&mut state, 3, 2),
+            Buffer::with_lines(vec!["4  ", "5  "])
+        );
+
+        state.move_to_start();
+        assert_buffer_eq!(
+            render(&mut state, 3, 2),
+            Buffer::with_lines(vec!["1  ", "2  "])
+        );
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(0, 0)));
+    }
+
+    #[test]
+    fn render_cursor_after_full_row() {
+        let mut state = TextAreaState::new("abc");
+        assert_buffer_eq!(
+            render(&mut state, 3, 2),
+            Buffer::with_lines(vec!["abc", "   "])
+        );
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(0, 1)));
+    }
+
+    #[test]
+    fn render_with_block() {
+        let mut state = TextAreaState::new("hi");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        let text_area = TextArea::new().block(Block::default().borders(Borders::ALL));
+        StatefulWidget::render(text_area, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["┌───┐", "│hi │", "└───┘"]);
+        expected.set_style(Rect::new(3, 1, 1, 1), DEFAULT_CURSOR_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn paste_is_a_single_edit() {
+        let mut state = TextAreaState::new("ab");
+        state.set_cursor(0, 1);
+        assert!(state.handle_event(&crate::input::Event::Paste("one\r\ntwo\nthree".into())));
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["aone", "two", "threeb"]);
+        assert_eq!(state.cursor(), (2, 5));
+        assert!(state.undo());
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["ab"]);
+        assert_eq!(state.cursor(), (0, 1));
+    }
+
+    #[test]
+    fn read_only_and_disabled() {
+        let mut state = TextAreaState::new("ab\ncd");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        StatefulWidget::render(
+            TextArea::new().read_only(true),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        assert!(state.is_read_only());
+        assert!(!state.handle_key(Key::Char('x').into()));
+        assert!(!state.handle_key(Key::Enter.into()));
+        assert!(!state.handle_paste("x"));
+        assert!(state.handle_key(KeyEvent::new(Key::Up, KeyModifiers::SHIFT)));
+        assert_eq!(state.selected_text().as_deref(), Some("\ncd"));
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["ab", "cd"]);
+
+        let text_area = TextArea::new().disabled(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        StatefulWidget::render(text_area, buf.area, &mut buf, &mut state);
+        assert!(state.is_disabled());
+        assert!(!state.handle_key(Key::Down.into()));
+        assert_eq!(state.cursor_screen_position(), None);
+        let mut expected = Buffer::with_lines(vec!["ab  ", "cd  "]);
+        expected.set_style(buf.area, DEFAULT_DISABLED_STYLE);
+        expected.set_style(Rect::new(0, 1, 2, 1), DEFAULT_SELECTION_STYLE);
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_placeholder() {
+        let mut state = TextAreaState::default();
+        state.set_focused(false);
+        let text_area = TextArea::new().placeholder("notes");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+        StatefulWidget::render(text_area.clone(), buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["notes ", "      "]);
+        expected.set_style(Rect::new(0, 0, 5, 1), DEFAULT_PLACEHOLDER_STYLE);
+        assert_buffer_eq!(buf, expected);
+        assert_eq!(state.cursor_screen_position(), None);
+
+        state.insert_newline();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+        StatefulWidget::render(text_area, buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["      ", "      "]));
+        assert_eq!(state.cursor_screen_position(), None);
+    }
+
+    #[test]
+    fn render_preedit() {
+        let mut state = TextAreaState::new("ab\ncd");
+        state.set_cursor(0, 1);
+        assert!(state.handle_preedit(Some("にほん")));
+        let mut buf = render(&mut state, 5, 3);
+        let mut expected = Buffer::with_lines(vec!["aにほ", "んb  ", "cd   "]);
+        expected.set_style(Rect::new(1, 0, 4, 1), DEFAULT_PREEDIT_STYLE);
+        expected.set_style(Rect::new(0, 1, 2, 1), DEFAULT_PREEDIT_STYLE);
+        assert_buffer_eq!(buf, expected);
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(2, 1)));
+        assert_eq!(state.value(), "ab\ncd");
+
+        state.set_preedit(None::<String>);
+        buf = render(&mut state, 5, 3);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["ab   ", "cd   ", "     "]));
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(1, 0)));
+    }
+}
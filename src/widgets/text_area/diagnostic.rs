@@ -0,0 +1,111 @@
+use strum::{Display, EnumString};
+
+use crate::style::{Color, Modifier, Style};
+
+/// The severity of a [`Diagnostic`], which selects the style it is drawn with, from the most to
+/// the least severe.
+#[derive(
+    Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord,
+)]
+pub enum Severity {
+    /// An error, underlined in red by default.
+    #[default]
+    Error,
+    /// A warning, underlined in yellow by default.
+    Warning,
+    /// An information, underlined in blue by default.
+    Info,
+    /// A hint, e.g. a suggested rewording, underlined in gray by default.
+    Hint,
+}
+
+impl Severity {
+    /// The number of severities, i.e. the number of styles of the diagnostics.
+    pub(crate) const COUNT: usize = 4;
+
+    /// Returns the default style of the diagnostics of this severity: underlined with the color
+    /// of the severity, or drawn in that color when the backends can't color the underline.
+    pub(crate) const fn default_style(self) -> Style {
+        let color = match self {
+            Severity::Error => Color::Red,
+            Severity::Warning => Color::Yellow,
+            Severity::Info => Color::Blue,
+            Severity::Hint => Color::DarkGray,
+        };
+        let style = Style::new().add_modifier(Modifier::UNDERLINED);
+        #[cfg(feature = "underline-color")]
+        return style.underline_color(color);
+        #[cfg(not(feature = "underline-color"))]
+        return style.fg(color);
+    }
+}
+
+/// A range of the text of a [`TextAreaState`] reported by a linter or a spell checker, with its
+/// [`Severity`] and a message.
+///
+/// The range goes from its first position to the position after it, each given as a line index
+/// and a byte offset in that line like the [cursor][`TextAreaState::cursor`].
+/// [`TextAreaState::grapheme_position`] converts the grapheme indexes reported by some checkers
+/// to such positions.
+///
+/// Diagnostics are set with [`TextAreaState::set_diagnostics`], drawn by the [`TextArea`] with
+/// its [`diagnostic_style`][`TextArea::diagnostic_style`], and visited with
+/// [`TextAreaState::next_diagnostic`].
+///
+/// [`TextArea`]: crate::widgets::TextArea
+/// [`TextArea::diagnostic_style`]: crate::widgets::TextArea::diagnostic_style
+/// [`TextAreaState`]: crate::widgets::TextAreaState
+/// [`TextAreaState::cursor`]: crate::widgets::TextAreaState::cursor
+/// [`TextAreaState::grapheme_position`]: crate::widgets::TextAreaState::grapheme_position
+/// [`TextAreaState::set_diagnostics`]: crate::widgets::TextAreaState::set_diagnostics
+/// [`TextAreaState::next_diagnostic`]: crate::widgets::TextAreaState::next_diagnostic
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::{Diagnostic, Severity, TextAreaState};
+///
+/// let mut state = TextAreaState::new("teh cat\nsat");
+/// state.set_diagnostics([Diagnostic::new((0, 0), (0, 3), Severity::Warning, "misspelled")]);
+/// state.move_to_start();
+/// assert_eq!(
+///     state.diagnostic_at_cursor().map(|d| d.message.as_str()),
+///     Some("misspelled")
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Diagnostic {
+    /// The first position of the range.
+    pub start: (usize, usize),
+    /// The position after the range.
+    pub end: (usize, usize),
+    /// The severity, selecting the style of the range.
+    pub severity: Severity,
+    /// The message describing the issue, e.g. to show in a status line.
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Creates a diagnostic of the given range.
+    pub fn new<M>(
+        start: (usize, usize),
+        end: (usize, usize),
+        severity: Severity,
+        message: M,
+    ) -> Self
+    where
+        M: Into<String>,
+    {
+        Self {
+            start,
+            end,
+            severity,
+            message: message.into(),
+        }
+    }
+
+    /// Returns `true` if the given position is in the range.
+    pub fn contains(&self, position: (usize, usize)) -> bool {
+        (self.start..self.end).contains(&position)
+    }
+}
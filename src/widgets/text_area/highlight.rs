@@ -0,0 +1,221 @@
+use crate::text::Line;
+#[cfg(feature = "syntect")]
+use crate::{
+    style::{Color, Modifier, Style},
+    text::Span,
+};
+
+/// Styles the lines of a [`TextArea`] before they are rendered, e.g. to highlight the syntax of
+/// some code.
+///
+/// The spans of each highlighted line must be made of the text of the line, in order: the style
+/// of each span is applied to the graphemes at the same byte offsets in the line. The selection,
+/// the matches of the search and the cursor are drawn over these styles.
+///
+/// Highlighters that only look at one line at a time implement
+/// [`highlight_line`](Self::highlight_line), while highlighters needing the previous lines, e.g.
+/// to know whether a line is inside a block comment, also implement
+/// [`highlight`](Self::highlight). Only the lines visible in the text area are highlighted, so
+/// that rendering a large text stays fast: a block comment opened above the first visible line
+/// isn't seen. With the `syntect` feature, [`SyntectHighlighter`] highlights the syntax of many
+/// languages.
+///
+/// A highlighter is set with [`TextArea::highlighter`], which also accepts a reference to a
+/// highlighter that is expensive to create.
+///
+/// [`TextArea`]: crate::widgets::TextArea
+/// [`TextArea::highlighter`]: crate::widgets::TextArea::highlighter
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// /// Highlights the comments starting with `#`.
+/// struct Comments;
+///
+/// impl Highlighter for Comments {
+///     fn highlight_line<'t>(&self, line: &'t str) -> Line<'t> {
+///         match line.find('#') {
+///             Some(i) => Line::from(vec![line[..i].into(), line[i..].dark_gray()]),
+///             None => Line::raw(line),
+///         }
+///     }
+/// }
+///
+/// let text_area = TextArea::new().highlighter(Comments);
+/// ```
+pub trait Highlighter {
+    /// Returns the styled spans of a line.
+    fn highlight_line<'t>(&self, line: &'t str) -> Line<'t>;
+
+    /// Returns the styled spans of each of the consecutive lines visible in the text area, from
+    /// the first one.
+    ///
+    /// Defaults to highlighting each line on its own with
+    /// [`highlight_line`](Self::highlight_line).
+    fn highlight<'t>(&self, lines: &[&'t str]) -> Vec<Line<'t>> {
+        lines.iter().map(|line| self.highlight_line(line)).collect()
+    }
+}
+
+impl<H> Highlighter for &H
+where
+    H: Highlighter + ?Sized,
+{
+    fn highlight_line<'t>(&self, line: &'t str) -> Line<'t> {
+        (**self).highlight_line(line)
+    }
+
+    fn highlight<'t>(&self, lines: &[&'t str]) -> Vec<Line<'t>> {
+        (**self).highlight(lines)
+    }
+}
+
+/// A [`Highlighter`] highlighting the syntax of some code with the [Syntect crate].
+///
+/// The foreground colors and the font styles of the theme are used, while its background is left
+/// to the style of the [`TextArea`]. Loading the syntaxes and the themes takes a while, so the
+/// highlighter is best created once and passed by reference to [`TextArea::highlighter`].
+///
+/// [Syntect crate]: https://crates.io/crates/syntect
+/// [`TextArea`]: crate::widgets::TextArea
+/// [`TextArea::highlighter`]: crate::widgets::TextArea::highlighter
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut TextAreaState) {
+/// let highlighter = SyntectHighlighter::for_extension("rs").unwrap();
+/// let editor = TextArea::new().highlighter(&highlighter);
+/// frame.render_stateful_widget(editor, frame.size(), state);
+/// # }
+/// ```
+#[cfg(feature = "syntect")]
+#[derive(Debug, Clone)]
+pub struct SyntectHighlighter {
+    syntax_set: syntect::parsing::SyntaxSet,
+    syntax: syntect::parsing::SyntaxReference,
+    theme: syntect::highlighting::Theme,
+}
+
+#[cfg(feature = "syntect")]
+impl SyntectHighlighter {
+    /// The theme used by [`for_extension`](Self::for_extension).
+    pub const DEFAULT_THEME: &'static str = "base16-ocean.dark";
+
+    /// Creates a highlighter for the given syntax of a syntax set, with the given theme.
+    ///
+    /// The syntax set must be loaded without the newlines at the end of the lines (e.g. with
+    /// [`SyntaxSet::load_defaults_nonewlines`]), as the lines of a [`TextArea`] don't have them.
+    ///
+    /// [`SyntaxSet::load_defaults_nonewlines`]: syntect::parsing::SyntaxSet::load_defaults_nonewlines
+    /// [`TextArea`]: crate::widgets::TextArea
+    pub fn new(
+        syntax_set: syntect::parsing::SyntaxSet,
+        syntax: syntect::parsing::SyntaxReference,
+        theme: syntect::highlighting::Theme,
+    ) -> Self {
+        Self {
+            syntax_set,
+            syntax,
+            theme,
+        }
+    }
+
+    /// Creates a highlighter for the files with the given extension (e.g. `rs` or `py`), with
+    /// the default syntaxes of Syntect and its [`DEFAULT_THEME`](Self::DEFAULT_THEME).
+    ///
+    /// Returns `None` if no default syntax matches the extension.
+    pub fn for_extension(extension: &str) -> Option<Self> {
+        let syntax_set = syntect::parsing::SyntaxSet::load_defaults_nonewlines();
+        let syntax = syntax_set.find_syntax_by_extension(extension)?.clone();
+        let mut themes = syntect::highlighting::ThemeSet::load_defaults().themes;
+        let theme = themes.remove(Self::DEFAULT_THEME)?;
+        Some(Self::new(syntax_set, syntax, theme))
+    }
+
+    /// Sets the theme of the highlighter.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn theme(mut self, theme: syntect::highlighting::Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Highlights a line, continuing from the state of the previous lines.
+    fn highlight_with<'t>(
+        &self,
+        highlighter: &mut syntect::easy::HighlightLines,
+        line: &'t str,
+    ) -> Line<'t> {
+        match highlighter.highlight_line(line, &self.syntax_set) {
+            Ok(ranges) => Line::from(
+                ranges
+                    .into_iter()
+                    .map(|(style, text)| Span::styled(text, syntect_style(style)))
+                    .collect::<Vec<_>>(),
+            ),
+            Err(_) => Line::raw(line),
+        }
+    }
+}
+
+#[cfg(feature = "syntect")]
+impl Highlighter for SyntectHighlighter {
+    fn highlight_line<'t>(&self, line: &'t str) -> Line<'t> {
+        let mut highlighter = syntect::easy::HighlightLines::new(&self.syntax, &self.theme);
+        self.highlight_with(&mut highlighter, line)
+    }
+
+    fn highlight<'t>(&self, lines: &[&'t str]) -> Vec<Line<'t>> {
+        let mut highlighter = syntect::easy::HighlightLines::new(&self.syntax, &self.theme);
+        lines
+            .iter()
+            .map(|line| self.highlight_with(&mut highlighter, line))
+            .collect()
+    }
+}
+
+/// Converts the foreground color and the font style of a Syntect style.
+#[cfg(feature = "syntect")]
+fn syntect_style(style: syntect::highlighting::Style) -> Style {
+    use syntect::highlighting::FontStyle;
+
+    let mut modifier = Modifier::empty();
+    if style.font_style.contains(FontStyle::BOLD) {
+        modifier |= Modifier::BOLD;
+    }
+    if style.font_style.contains(FontStyle::ITALIC) {
+        modifier |= Modifier::ITALIC;
+    }
+    if style.font_style.contains(FontStyle::UNDERLINE) {
+        modifier |= Modifier::UNDERLINED;
+    }
+    let color = style.foreground;
+    Style::new()
+        .fg(Color::Rgb(color.r, color.g, color.b))
+        .add_modifier(modifier)
+}
+
+#[cfg(all(test, feature = "syntect"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn syntect_highlights_the_text_of_each_line() {
+        let highlighter = SyntectHighlighter::for_extension("rs").unwrap();
+        let lines = ["/* a", "b */ fn"];
+        let highlighted = highlighter.highlight(&lines);
+        for (line, highlighted) in lines.iter().zip(&highlighted) {
+            let text: String = highlighted.spans.iter().map(|s| &*s.content).collect();
+            assert_eq!(&text, line);
+        }
+        // the second line starts inside the comment opened by the first one
+        let comment = highlighted[0].spans[0].style;
+        assert_eq!(highlighted[1].spans[0].style, comment);
+        assert_ne!(highlighted[1].spans.last().unwrap().style, comment);
+        assert!(SyntectHighlighter::for_extension("not-a-language").is_none());
+    }
+}
@@ -0,0 +1,473 @@
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Index, IndexMut, Range},
+    sync::Arc,
+};
+
+use itertools::Either;
+
+/// The number of lines above which the lines are split into chunks.
+const MAX_SIMPLE_LINES: usize = 2048;
+
+/// The number of lines of the chunks when they are created. A chunk is split in two when it
+/// grows to twice this size.
+const CHUNK_LINES: usize = 512;
+
+/// The lines of text of a [`TextAreaState`](super::TextAreaState).
+///
+/// Small texts keep their lines in a vector. Larger texts keep them in chunks shared with the
+/// snapshots of the undo history, so that inserting or removing a line only moves the lines of
+/// its chunk and the index of the chunks, and recording an edit only copies the chunk it
+/// changed instead of the whole text.
+#[derive(Clone)]
+pub(crate) enum Lines {
+    /// All the lines in one vector, for the texts of up to [`MAX_SIMPLE_LINES`] lines.
+    Simple(Vec<String>),
+    /// The lines in chunks, for the larger texts.
+    Chunked(Chunks),
+}
+
+/// Lines split into chunks, with the index of the line after each chunk to find the chunk of a
+/// line with a binary search.
+#[derive(Clone)]
+pub(crate) struct Chunks {
+    chunks: Vec<Arc<Vec<String>>>,
+    ends: Vec<usize>,
+}
+
+impl Lines {
+    /// Returns the number of lines.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Simple(lines) => lines.len(),
+            Self::Chunked(chunks) => chunks.ends.last().copied().unwrap_or(0),
+        }
+    }
+
+    /// Returns an iterator over the lines.
+    pub fn iter(&self) -> impl Iterator<Item = &String> {
+        self.range(0..self.len())
+    }
+
+    /// Returns an iterator over a range of lines.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn range(&self, range: Range<usize>) -> impl Iterator<Item = &String> {
+        match self {
+            Self::Simple(lines) => Either::Left(lines[range].iter()),
+            Self::Chunked(chunks) => {
+                assert!(range.end <= self.len(), "line range out of bounds");
+                let (chunk, index) = chunks.locate(range.start);
+                let lines = chunks.chunks[chunk..]
+                    .iter()
+                    .flat_map(|chunk| chunk.iter())
+                    .skip(index)
+                    .take(range.len());
+                Either::Right(lines)
+            }
+        }
+    }
+
+    /// Returns the lines joined with a separator.
+    pub fn join(&self, separator: &str) -> String {
+        let len = self.iter().map(String::len).sum::<usize>()
+            + separator.len() * self.len().saturating_sub(1);
+        let mut text = String::with_capacity(len);
+        for (i, line) in self.iter().enumerate() {
+            if i > 0 {
+                text.push_str(separator);
+            }
+            text.push_str(line);
+        }
+        text
+    }
+
+    /// Returns the number of lines equal in both texts at their start, and at their end without
+    /// counting the lines at the start again.
+    ///
+    /// The chunks shared by both texts are skipped without comparing their lines.
+    pub fn common_lines(&self, other: &Self) -> (usize, usize) {
+        let len = self.len().min(other.len());
+        let (mut prefix, mut suffix) = match (self, other) {
+            (Self::Chunked(chunks), Self::Chunked(other)) => {
+                let shared = |(chunk, other): (&Arc<Vec<String>>, &Arc<Vec<String>>)| {
+                    Arc::ptr_eq(chunk, other).then_some(chunk.len())
+                };
+                let zipped = || chunks.chunks.iter().zip(&other.chunks);
+                let rzipped = || chunks.chunks.iter().rev().zip(other.chunks.iter().rev());
+                (
+                    zipped().map_while(shared).sum(),
+                    rzipped().map_while(shared).sum(),
+                )
+            }
+            _ => (0, 0),
+        };
+        while prefix < len && self[prefix] == other[prefix] {
+            prefix += 1;
+        }
+        suffix = suffix.min(len - prefix);
+        while suffix < len - prefix
+            && self[self.len() - 1 - suffix] == other[other.len() - 1 - suffix]
+        {
+            suffix += 1;
+        }
+        (prefix, suffix)
+    }
+
+    /// Inserts a line before the given index, or at the end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is greater than the number of lines.
+    pub fn insert(&mut self, index: usize, line: String) {
+        self.insert_lines(index, [line]);
+    }
+
+    /// Inserts lines before the given index, or at the end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is greater than the number of lines.
+    pub fn insert_lines<I>(&mut self, index: usize, lines: I)
+    where
+        I: IntoIterator<Item = String>,
+    {
+        match self {
+            Self::Simple(simple) => {
+                simple.splice(index..index, lines);
+                if simple.len() > MAX_SIMPLE_LINES {
+                    *self = Self::Chunked(Chunks::new(std::mem::take(simple)));
+                }
+            }
+            Self::Chunked(chunks) => chunks.insert_lines(index, lines),
+        }
+    }
+
+    /// Removes a line and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn remove(&mut self, index: usize) -> String {
+        match self {
+            Self::Simple(lines) => lines.remove(index),
+            Self::Chunked(chunks) => chunks.remove(index),
+        }
+    }
+
+    /// Removes a range of lines.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn remove_range(&mut self, range: Range<usize>) {
+        match self {
+            Self::Simple(lines) => {
+                lines.drain(range);
+            }
+            Self::Chunked(chunks) => chunks.remove_range(range),
+        }
+    }
+}
+
+impl Chunks {
+    fn new(lines: Vec<String>) -> Self {
+        let mut chunks = Self {
+            chunks: vec![],
+            ends: vec![],
+        };
+        chunks.insert_lines(0, lines);
+        chunks
+    }
+
+    /// Returns the index of the chunk containing a line and the index of the line in the chunk.
+    /// The index after the last line is located after the last line of the last chunk.
+    fn locate(&self, line: usize) -> (usize, usize) {
+        let chunk = self
+            .ends
+            .partition_point(|&end| end <= line)
+            .min(self.chunks.len().saturating_sub(1));
+        (chunk, line - self.start(chunk))
+    }
+
+    /// Returns the index of the first line of a chunk.
+    fn start(&self, chunk: usize) -> usize {
+        chunk
+            .checked_sub(1)
+            .map_or(0, |previous| self.ends[previous])
+    }
+
+    /// Splits the chunk containing a line so that a chunk starts at this line, and returns the
+    /// index of that chunk, or the number of chunks for the index after the last line.
+    fn split_at(&mut self, line: usize) -> usize {
+        if line == self.ends.last().copied().unwrap_or(0) {
+            return self.chunks.len();
+        }
+        let (chunk, index) = self.locate(line);
+        if index == 0 {
+            return chunk;
+        }
+        let tail = Arc::make_mut(&mut self.chunks[chunk]).split_off(index);
+        self.chunks.insert(chunk + 1, Arc::new(tail));
+        self.ends.insert(chunk, line);
+        chunk + 1
+    }
+
+    /// Recomputes the ends of the chunks from the given chunk.
+    fn update_ends(&mut self, from: usize) {
+        self.ends.truncate(from);
+        let mut end = self.start(from);
+        for chunk in &self.chunks[from..] {
+            end += chunk.len();
+            self.ends.push(end);
+        }
+    }
+
+    fn insert_lines<I>(&mut self, index: usize, lines: I)
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut lines = lines.into_iter();
+        let Some(first) = lines.next() else {
+            return;
+        };
+        let Some(second) = lines.next() else {
+            // a single line is inserted in its chunk
+            if self.chunks.is_empty() {
+                self.chunks.push(Arc::new(vec![]));
+                self.ends.push(0);
+            }
+            let (chunk, i) = self.locate(index);
+            let lines = Arc::make_mut(&mut self.chunks[chunk]);
+            lines.insert(i, first);
+            let len = lines.len();
+            for end in &mut self.ends[chunk..] {
+                *end += 1;
+            }
+            if len >= 2 * CHUNK_LINES {
+                self.split_at(self.start(chunk) + CHUNK_LINES);
+            }
+            return;
+        };
+        // several lines are inserted as new chunks
+        let at = self.split_at(index);
+        let mut new_chunks = vec![];
+        let mut chunk = vec![first, second];
+        for line in lines {
+            if chunk.len() == CHUNK_LINES {
+                new_chunks.push(Arc::new(std::mem::take(&mut chunk)));
+            }
+            chunk.push(line);
+        }
+        new_chunks.push(Arc::new(chunk));
+        self.chunks.splice(at..at, new_chunks);
+        self.update_ends(at);
+    }
+
+    fn remove(&mut self, index: usize) -> String {
+        assert!(
+            index < self.ends.last().copied().unwrap_or(0),
+            "line index out of bounds"
+        );
+        let (chunk, i) = self.locate(index);
+        let lines = Arc::make_mut(&mut self.chunks[chunk]);
+        let line = lines.remove(i);
+        if lines.is_empty() {
+            self.chunks.remove(chunk);
+            self.ends.remove(chunk);
+        }
+        for end in &mut self.ends[chunk..] {
+            *end -= 1;
+        }
+        line
+    }
+
+    fn remove_range(&mut self, range: Range<usize>) {
+        assert!(
+            range.start <= range.end && range.end <= self.ends.last().copied().unwrap_or(0),
+            "line range out of bounds"
+        );
+        if range.is_empty() {
+            return;
+        }
+        let start = self.split_at(range.start);
+        let end = self.split_at(range.end);
+        self.chunks.drain(start..end);
+        self.update_ends(start);
+    }
+}
+
+impl Default for Lines {
+    fn default() -> Self {
+        Self::Simple(vec![String::new()])
+    }
+}
+
+impl From<Vec<String>> for Lines {
+    fn from(lines: Vec<String>) -> Self {
+        if lines.len() > MAX_SIMPLE_LINES {
+            Self::Chunked(Chunks::new(lines))
+        } else {
+            Self::Simple(lines)
+        }
+    }
+}
+
+impl FromIterator<String> for Lines {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = String>,
+    {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl Index<usize> for Lines {
+    type Output = String;
+
+    fn index(&self, index: usize) -> &String {
+        match self {
+            Self::Simple(lines) => &lines[index],
+            Self::Chunked(chunks) => {
+                assert!(index < self.len(), "line index out of bounds");
+                let (chunk, i) = chunks.locate(index);
+                &chunks.chunks[chunk][i]
+            }
+        }
+    }
+}
+
+impl IndexMut<usize> for Lines {
+    fn index_mut(&mut self, index: usize) -> &mut String {
+        let len = self.len();
+        match self {
+            Self::Simple(lines) => &mut lines[index],
+            Self::Chunked(chunks) => {
+                assert!(index < len, "line index out of bounds");
+                let (chunk, i) = chunks.locate(index);
+                &mut Arc::make_mut(&mut chunks.chunks[chunk])[i]
+            }
+        }
+    }
+}
+
+impl PartialEq for Lines {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Simple(lines), Self::Simple(other)) => lines == other,
+            // the chunks shared with a snapshot are equal without comparing their lines
+            (Self::Chunked(chunks), Self::Chunked(other)) if chunks.ends == other.ends => chunks
+                .chunks
+                .iter()
+                .zip(&other.chunks)
+                .all(|(chunk, other)| Arc::ptr_eq(chunk, other) || chunk == other),
+            _ => self.len() == other.len() && self.iter().eq(other.iter()),
+        }
+    }
+}
+
+impl Eq for Lines {}
+
+impl Hash for Lines {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for line in self.iter() {
+            line.hash(state);
+        }
+    }
+}
+
+impl fmt::Debug for Lines {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered(range: Range<usize>) -> Vec<String> {
+        range.map(|i| i.to_string()).collect()
+    }
+
+    #[test]
+    fn small_texts_are_simple() {
+        let mut lines = Lines::from(numbered(0..MAX_SIMPLE_LINES));
+        assert!(matches!(lines, Lines::Simple(_)));
+        lines.insert(lines.len(), "end".into());
+        assert!(matches!(lines, Lines::Chunked(_)));
+        assert_eq!(lines.len(), MAX_SIMPLE_LINES + 1);
+        assert_eq!(lines[MAX_SIMPLE_LINES], "end");
+    }
+
+    #[test]
+    fn chunked_edits() {
+        let count = 5 * CHUNK_LINES + 7;
+        let mut expected = numbered(0..count);
+        let mut lines = Lines::Chunked(Chunks::new(expected.clone()));
+        let snapshot = lines.clone();
+
+        for i in [0, CHUNK_LINES - 1, CHUNK_LINES, 3 * CHUNK_LINES + 1, count] {
+            lines.insert(i, format!("new {i}"));
+            expected.insert(i, format!("new {i}"));
+        }
+        // growing a chunk splits it
+        for _ in 0..2 * CHUNK_LINES {
+            lines.insert(10, "grown".into());
+            expected.insert(10, "grown".into());
+        }
+        lines.insert_lines(CHUNK_LINES + 3, numbered(0..CHUNK_LINES + 2));
+        expected.splice(
+            CHUNK_LINES + 3..CHUNK_LINES + 3,
+            numbered(0..CHUNK_LINES + 2),
+        );
+        assert_eq!(
+            lines.remove(2 * CHUNK_LINES),
+            expected.remove(2 * CHUNK_LINES)
+        );
+        lines.remove_range(5..3 * CHUNK_LINES + 11);
+        expected.drain(5..3 * CHUNK_LINES + 11);
+        lines[7].push('!');
+        expected[7].push('!');
+
+        assert_eq!(lines.len(), expected.len());
+        assert!(lines.iter().eq(&expected));
+        assert!(lines.range(3..CHUNK_LINES).eq(&expected[3..CHUNK_LINES]));
+        assert_eq!(lines.join("\n"), expected.join("\n"));
+        assert_eq!(lines, Lines::Simple(expected));
+        // the snapshot is left unchanged
+        assert!(snapshot.iter().eq(&numbered(0..count)));
+
+        lines.remove_range(0..lines.len());
+        assert_eq!(lines.len(), 0);
+        lines.insert(0, "last".into());
+        assert_eq!(lines, Lines::Simple(vec!["last".into()]));
+    }
+
+    #[test]
+    fn common_lines() {
+        let simple = Lines::from(numbered(0..10));
+        let mut edited = simple.clone();
+        edited[4].push('!');
+        edited.insert(6, "new".into());
+        assert_eq!(simple.common_lines(&edited), (4, 4));
+        assert_eq!(simple.common_lines(&simple), (10, 0));
+        let shorter = Lines::from(numbered(0..8));
+        assert_eq!(simple.common_lines(&shorter), (8, 0));
+
+        let count = 5 * CHUNK_LINES;
+        let chunked = Lines::Chunked(Chunks::new(numbered(0..count)));
+        let mut edited = chunked.clone();
+        edited[2 * CHUNK_LINES + 3].push('!');
+        assert_eq!(
+            chunked.common_lines(&edited),
+            (2 * CHUNK_LINES + 3, 3 * CHUNK_LINES - 4)
+        );
+        edited.remove_range(10..12);
+        assert_eq!(chunked.common_lines(&edited), (10, 3 * CHUNK_LINES - 4));
+    }
+}
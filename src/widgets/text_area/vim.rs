@@ -0,0 +1,884 @@
+//! Vi-style modal editing for the [`TextArea`](super::TextArea).
+use std::collections::BTreeMap;
+
+use strum::{Display, EnumString};
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::{
+    byte_offset_at_column, lines::Lines, remove_between, text_between, text_width, Pos,
+    TextAreaState,
+};
+use crate::input::{Key, KeyEvent, KeyEventKind, KeyModifiers, Keymap};
+
+/// The name of the register used when no register is given.
+const UNNAMED_REGISTER: char = '"';
+
+/// The mode of the vi-style editing of a [`TextArea`](super::TextArea).
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum VimMode {
+    /// Keys are commands, e.g. motions and operators.
+    #[default]
+    Normal,
+    /// Keys insert text, until `Esc` goes back to normal mode.
+    Insert,
+    /// Motions extend a selection, on which the operators apply.
+    Visual,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum Operator {
+    Delete,
+    Change,
+    Yank,
+}
+
+/// How a motion selects text when used after an operator.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum MotionKind {
+    /// From the cursor to the target, excluding the target (e.g. `w`).
+    Exclusive,
+    /// From the cursor to the target, including the target (e.g. `e`).
+    Inclusive,
+    /// The whole lines from the cursor to the target (e.g. `j`).
+    Linewise,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Register {
+    text: String,
+    linewise: bool,
+}
+
+/// The state of the vi-style editing: the mode, the partially typed command and the registers.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub(super) struct Vim {
+    mode: VimMode,
+    count: Option<usize>,
+    /// The pending operator and the count typed before it
+    operator: Option<(Operator, Option<usize>)>,
+    register: Option<char>,
+    awaiting_register: bool,
+    g_prefix: bool,
+    /// The position where the visual selection started
+    anchor: Pos,
+    registers: BTreeMap<char, Register>,
+}
+
+impl TextAreaState {
+    /// Enables or disables the vi-style modal editing, which starts in normal mode.
+    ///
+    /// When enabled, [`Input::handle_key`](crate::input::Input::handle_key) interprets keys like
+    /// vi does: motions (`h`, `j`, `k`, `l`, `w`, `b`, `e`, `0`, `$`, `gg`, `G`), operators
+    /// (`d`, `c`, `y`, doubled to apply to whole lines), counts (e.g. `3w`, `2dd`), registers
+    /// (e.g. `"ayy`, `"ap`), `x`, `p`, `P`, `u`, `Ctrl-R`, the insert commands (`i`, `a`, `I`,
+    /// `A`, `o`, `O`) and the visual mode (`v`). In insert mode the keys are handled as without
+    /// vi-style editing, and `Esc` goes back to normal mode.
+    pub fn set_vim(&mut self, enabled: bool) {
+        if enabled == self.vim.is_some() {
+            return;
+        }
+        self.vim = enabled.then(Vim::default);
+        self.clamp_normal_cursor();
+    }
+
+    /// Enables or disables the vi-style modal editing. See [`set_vim`](Self::set_vim).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_vim(mut self, enabled: bool) -> Self {
+        self.set_vim(enabled);
+        self
+    }
+
+    /// Returns the current mode of the vi-style editing, or `None` if it is disabled.
+    pub fn vim_mode(&self) -> Option<VimMode> {
+        self.vim.as_ref().map(|vim| vim.mode)
+    }
+
+    /// Returns the content of the given register, `"` being the unnamed register. Lines yanked
+    /// or deleted as a whole are separated by `\n`.
+    pub fn register(&self, name: char) -> Option<&str> {
+        self.vim
+            .as_ref()
+            .and_then(|vim| vim.registers.get(&name))
+            .map(|register| register.text.as_str())
+    }
+
+    /// Returns the range selected in visual mode, from its first position to the position after
+    /// its last grapheme.
+    pub(super) fn vim_selection(&self) -> Option<(Pos, Pos)> {
+        let vim = self.vim.as_ref()?;
+        (vim.mode == VimMode::Visual).then(|| vim.selection(self))
+    }
+
+    /// Handles a key with the vi-style editing, which must be enabled.
+    pub(super) fn handle_vim_key(&mut self, key: KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release || self.disabled {
+            return false;
+        }
+        let Some(mut vim) = self.vim.take() else {
+            return false;
+        };
+        let handled = match vim.mode {
+            VimMode::Insert if key.key == Key::Esc => {
+                vim.mode = VimMode::Normal;
+                self.anchor = None;
+                self.move_left_in_line();
+                true
+            }
+            VimMode::Insert => self.handle_key_with(Keymap::default_ref(), key),
+            // the commands of the normal and visual modes only apply at the main cursor
+            VimMode::Normal | VimMode::Visual => {
+                self.edit_at_main_cursor(|state| vim.handle_command(state, key))
+            }
+        };
+        self.vim = Some(vim);
+        self.clamp_normal_cursor();
+        handled
+    }
+
+    /// Moves the cursor from the end of its line to the last grapheme, as the cursor is on a
+    /// grapheme in normal mode.
+    fn clamp_normal_cursor(&mut self) {
+        if self.vim_mode().is_some_and(|mode| mode != VimMode::Insert)
+            && self.col == self.lines[self.row].len()
+        {
+            self.move_left_in_line();
+        }
+    }
+
+    fn move_left_in_line(&mut self) {
+        if let Some(col) = self.previous_boundary() {
+            self.col = col;
+        }
+    }
+}
+
+impl Vim {
+    /// Returns the range between the anchor and the cursor, including the grapheme under the
+    /// cursor.
+    fn selection(&self, state: &TextAreaState) -> (Pos, Pos) {
+        let cursor = state.cursor();
+        let start = self.anchor.min(cursor);
+        let end = self.anchor.max(cursor);
+        (start, after(&state.lines, end))
+    }
+
+    fn reset_pending(&mut self) {
+        self.count = None;
+        self.operator = None;
+        self.register = None;
+        self.awaiting_register = false;
+        self.g_prefix = false;
+    }
+
+    /// Handles a key in normal or visual mode.
+    fn handle_command(&mut self, state: &mut TextAreaState, key: KeyEvent) -> bool {
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.reset_pending();
+            return match key.key {
+                Key::Char('r') if !state.read_only => state.redo(),
+                _ => false,
+            };
+        }
+        let c = match key.key {
+            Key::Char(c) => c,
+            Key::Left => 'h',
+            Key::Right => 'l',
+            Key::Up => 'k',
+            Key::Down => 'j',
+            Key::Home => '0',
+            Key::End => '$',
+            Key::Esc => {
+                self.reset_pending();
+                self.mode = VimMode::Normal;
+                return true;
+            }
+            _ => return false,
+        };
+        if self.awaiting_register {
+            self.awaiting_register = false;
+            self.register = Some(c);
+            return true;
+        }
+        if self.g_prefix {
+            self.g_prefix = false;
+            if c != 'g' {
+                self.reset_pending();
+                return false;
+            }
+        } else {
+            match c {
+                '1'..='9' | '0' if c != '0' || self.count.is_some() => {
+                    let digit = c.to_digit(10).unwrap_or(0) as usize;
+                    self.count = Some(self.count.unwrap_or(0).saturating_mul(10) + digit);
+                    return true;
+                }
+                '"' => {
+                    self.awaiting_register = true;
+                    return true;
+                }
+                'g' => {
+                    self.g_prefix = true;
+                    return true;
+                }
+                _ => {}
+            }
+        }
+        let count = self.count.take();
+        if self.mode == VimMode::Visual {
+            self.visual_command(state, c, count);
+        } else {
+            self.normal_command(state, c, count);
+        }
+        true
+    }
+
+    fn normal_command(&mut self, state: &mut TextAreaState, c: char, count: Option<usize>) {
+        let operator = match c {
+            'd' => Some(Operator::Delete),
+            'c' => Some(Operator::Change),
+            'y' => Some(Operator::Yank),
+            _ => None,
+        };
+        if let Some(operator) = operator {
+            match self.operator {
+                // doubled operator: whole lines
+                Some((pending, pending_count)) if pending == operator => {
+                    let lines = pending_count.unwrap_or(1) * count.unwrap_or(1);
+                    let start = (state.row, 0);
+                    let end = ((state.row + lines - 1).min(state.lines.len() - 1), 0);
+                    self.operate(state, operator, start, end, MotionKind::Linewise);
+                }
+                Some(_) => self.reset_pending(),
+                None => self.operator = Some((operator, count)),
+            }
+            return;
+        }
+        if let Some((operator, pending_count)) = self.operator {
+            let counted = count.is_some() || pending_count.is_some();
+            let count = pending_count.unwrap_or(1) * count.unwrap_or(1);
+            // `cw` changes up to the end of the word, like `ce`
+            let c = if operator == Operator::Change && c == 'w' {
+                'e'
+            } else {
+                c
+            };
+            match motion(&state.lines, state.cursor(), c, count, counted) {
+                Some((mut target, kind)) => {
+                    if c == 'w' && target.0 > state.row {
+                        // `dw` on the last word of a line stops at the end of the line
+                        target = (state.row, state.lines[state.row].len());
+                    }
+                    self.operate(state, operator, state.cursor(), target, kind);
+                }
+                None => self.reset_pending(),
+            }
+            return;
+        }
+        let repeat = count.unwrap_or(1);
+        match c {
+            // the commands changing the text, as `x`, `D` and `C` are rejected by `operate`
+            'i' | 'a' | 'I' | 'A' | 'o' | 'O' | 'p' | 'P' | 'u' if state.read_only => {}
+            'i' => self.insert(state),
+            'a' => {
+                state.col = after(&state.lines, state.cursor()).1;
+                self.insert(state);
+            }
+            'I' => {
+                state.col = first_non_blank(&state.lines[state.row]);
+                self.insert(state);
+            }
+            'A' => {
+                state.col = state.lines[state.row].len();
+                self.insert(state);
+            }
+            'o' => {
+                state.col = state.lines[state.row].len();
+                state.insert_newline();
+                self.insert(state);
+            }
+            'O' => {
+                state.col = 0;
+                state.insert_newline();
+                state.row -= 1;
+                self.insert(state);
+            }
+            'v' => {
+                self.mode = VimMode::Visual;
+                self.anchor = state.cursor();
+            }
+            'x' => {
+                let mut end = state.cursor();
+                for _ in 0..repeat {
+                    end = after(&state.lines, end);
+                }
+                self.operate(
+                    state,
+                    Operator::Delete,
+                    state.cursor(),
+                    end,
+                    MotionKind::Exclusive,
+                );
+            }
+            'D' | 'C' => {
+                let operator = if c == 'D' {
+                    Operator::Delete
+                } else {
+                    Operator::Change
+                };
+                let end = (state.row, state.lines[state.row].len());
+                self.operate(state, operator, state.cursor(), end, MotionKind::Exclusive);
+            }
+            'p' | 'P' => self.paste(state, c == 'p', repeat),
+            'u' => {
+                for _ in 0..repeat {
+                    state.undo();
+                }
+            }
+            c => {
+                if let Some((target, _)) =
+                    motion(&state.lines, state.cursor(), c, repeat, count.is_some())
+                {
+                    state.history.break_coalescing();
+                    state.row = target.0;
+                    state.col = target.1;
+                }
+            }
+        }
+        self.reset_pending();
+    }
+
+    fn visual_command(&mut self, state: &mut TextAreaState, c: char, count: Option<usize>) {
+        let operator = match c {
+            'd' | 'x' => Some(Operator::Delete),
+            'c' => Some(Operator::Change),
+            'y' => Some(Operator::Yank),
+            _ => None,
+        };
+        match operator {
+            Some(operator) => {
+                let (start, end) = self.selection(state);
+                self.mode = VimMode::Normal;
+                self.operate(state, operator, start, end, MotionKind::Exclusive);
+            }
+            None if c == 'v' => {
+                self.mode = VimMode::Normal;
+                self.reset_pending();
+            }
+            None => {
+                let repeat = count.unwrap_or(1);
+                if let Some((target, _)) =
+                    motion(&state.lines, state.cursor(), c, repeat, count.is_some())
+                {
+                    state.row = target.0;
+                    state.col = target.1;
+                }
+                self.reset_pending();
+            }
+        }
+    }
+
+    fn insert(&mut self, state: &mut TextAreaState) {
+        self.mode = VimMode::Insert;
+        state.history.break_coalescing();
+    }
+
+    /// Applies an operator to the text between two positions, in any order.
+    fn operate(
+        &mut self,
+        state: &mut TextAreaState,
+        operator: Operator,
+        from: Pos,
+        to: Pos,
+        kind: MotionKind,
+    ) {
+        if state.read_only && operator != Operator::Yank {
+            self.reset_pending();
+            return;
+        }
+        let (mut start, mut end) = (from.min(to), from.max(to));
+        let linewise = kind == MotionKind::Linewise;
+        if linewise {
+            start = (start.0, 0);
+            end = (end.0, state.lines[end.0].len());
+        } else if kind == MotionKind::Inclusive {
+            end = after(&state.lines, end);
+        }
+        let text = text_between(&state.lines, start, end);
+        self.store(Register { text, linewise });
+        match operator {
+            Operator::Yank => {
+                state.row = start.0;
+                state.col = start.1;
+            }
+            Operator::Delete if linewise => {
+                state.record(false);
+                state.lines.remove_range(start.0..end.0 + 1);
+                if state.lines.len() == 0 {
+                    state.lines = Lines::default();
+                }
+                state.row = start.0.min(state.lines.len() - 1);
+                state.col = first_non_blank(&state.lines[state.row]);
+            }
+            Operator::Delete | Operator::Change => {
+                if start != end {
+                    state.record(false);
+                    remove_between(&mut state.lines, start, end);
+                }
+                state.row = start.0;
+                state.col = start.1;
+                if operator == Operator::Change {
+                    self.insert(state);
+                }
+            }
+        }
+        self.reset_pending();
+    }
+
+    /// Stores the text in the selected register, and in the unnamed register.
+    fn store(&mut self, register: Register) {
+        let name = self.register.unwrap_or(UNNAMED_REGISTER);
+        if name != UNNAMED_REGISTER {
+            self.registers.insert(name, register.clone());
+        }
+        self.registers.insert(UNNAMED_REGISTER, register);
+    }
+
+    /// Pastes the selected register after or before the cursor.
+    fn paste(&mut self, state: &mut TextAreaState, after_cursor: bool, repeat: usize) {
+        let name = self.register.unwrap_or(UNNAMED_REGISTER);
+        let Some(register) = self.registers.get(&name) else {
+            return;
+        };
+        if register.text.is_empty() && !register.linewise {
+            return;
+        }
+        let text = vec![register.text.as_str(); repeat];
+        if register.linewise {
+            let row = if after_cursor {
+                state.row + 1
+            } else {
+                state.row
+            };
+            state.record(false);
+            let lines = text.join("\n");
+            let lines = lines.split('\n').map(String::from).collect::<Vec<_>>();
+            state.lines.insert_lines(row, lines);
+            state.row = row;
+            state.col = first_non_blank(&state.lines[row]);
+        } else {
+            if after_cursor {
+                state.col = after(&state.lines, state.cursor()).1;
+            }
+            state.insert_str_at_cursor(&text.concat());
+            state.move_left_in_line();
+        }
+    }
+}
+
+/// Returns the target of a motion and how it selects text, or `None` if the key is not a motion.
+fn motion(
+    lines: &Lines,
+    pos: Pos,
+    key: char,
+    count: usize,
+    counted: bool,
+) -> Option<(Pos, MotionKind)> {
+    let repeat = |step: fn(&Lines, Pos) -> Pos| (0..count).fold(pos, |pos, _| step(lines, pos));
+    let target = match key {
+        'h' => (
+            pos.0,
+            (0..count).fold(pos.1, |col, _| {
+                lines[pos.0][..col]
+                    .grapheme_indices(true)
+                    .next_back()
+                    .map_or(col, |(i, _)| i)
+            }),
+        ),
+        'l' => (
+            pos.0,
+            (0..count).fold(pos.1, |col, _| after(lines, (pos.0, col)).1),
+        ),
+        'j' | 'k' => {
+            let row = if key == 'j' {
+                (pos.0 + count).min(lines.len() - 1)
+            } else {
+                pos.0.saturating_sub(count)
+            };
+            let column = text_width(&lines[pos.0][..pos.1]);
+            return Some((
+                (row, byte_offset_at_column(&lines[row], column)),
+                MotionKind::Linewise,
+            ));
+        }
+        'w' => repeat(word_forward),
+        'b' => repeat(word_backward),
+        'e' => return Some((repeat(word_end), MotionKind::Inclusive)),
+        '0' => (pos.0, 0),
+        '$' => return Some(((pos.0, lines[pos.0].len()), MotionKind::Exclusive)),
+        // `gg` and `G` go to the given line, or to the first / last line
+        'g' | 'G' => {
+            let row = if counted {
+                count.clamp(1, lines.len()) - 1
+            } else if key == 'g' {
+                0
+            } else {
+                lines.len() - 1
+            };
+            return Some(((row, first_non_blank(&lines[row])), MotionKind::Linewise));
+        }
+        _ => return None,
+    };
+    Some((target, MotionKind::Exclusive))
+}
+
+/// The class of a grapheme, words being runs of graphemes of the same class.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum Class {
+    Blank,
+    Word,
+    Punctuation,
+    /// An empty line, which is a word on its own.
+    EmptyLine,
+}
+
+fn class_at(lines: &Lines, (row, col): Pos) -> Class {
+    let line = &lines[row];
+    if line.is_empty() {
+        return Class::EmptyLine;
+    }
+    match line[col..].chars().next() {
+        // the end of a line is a line break
+        None => Class::Blank,
+        Some(c) if c.is_whitespace() => Class::Blank,
+        Some(c) if c.is_alphanumeric() || c == '_' => Class::Word,
+        Some(_) => Class::Punctuation,
+    }
+}
+
+/// Returns the position of the next grapheme, the end of a line being a position before the
+/// next line.
+fn next(lines: &Lines, (row, col): Pos) -> Option<Pos> {
+    match lines[row][col..].graphemes(true).next() {
+        Some(grapheme) => Some((row, col + grapheme.len())),
+        None if row + 1 < lines.len() => Some((row + 1, 0)),
+        None => None,
+    }
+}
+
+/// Returns the position of the previous grapheme, see [`next`].
+fn previous(lines: &Lines, (row, col): Pos) -> Option<Pos> {
+    match lines[row][..col].grapheme_indices(true).next_back() {
+        Some((i, _)) => Some((row, i)),
+        None if row > 0 => Some((row - 1, lines[row - 1].len())),
+        None => None,
+    }
+}
+
+/// Returns the position after the grapheme at the given position, staying on the same line.
+fn after(lines: &Lines, pos: Pos) -> Pos {
+    match next(lines, pos) {
+        Some(next) if next.0 == pos.0 => next,
+        _ => pos,
+    }
+}
+
+fn first_non_blank(line: &str) -> usize {
+    line.find(|c: char| !c.is_whitespace()).unwrap_or(0)
+}
+
+/// The `w` motion: the start of the next word.
+fn word_forward(lines: &Lines, pos: Pos) -> Pos {
+    let class = class_at(lines, pos);
+    let mut pos = pos;
+    if matches!(class, Class::Word | Class::Punctuation) {
+        while class_at(lines, pos) == class {
+            match next(lines, pos) {
+                Some(next) => pos = next,
+                None => return pos,
+            }
+        }
+    } else {
+        match next(lines, pos) {
+            Some(next) => pos = next,
+            None => return pos,
+        }
+    }
+    while class_at(lines, pos) == Class::Blank {
+        match next(lines, pos) {
+            Some(next) => pos = next,
+            None => return pos,
+        }
+    }
+    pos
+}
+
+/// The `e` motion: the last grapheme of the current or next word.
+fn word_end(lines: &Lines, pos: Pos) -> Pos {
+    let Some(mut pos) = next(lines, pos) else {
+        return pos;
+    };
+    while matches!(class_at(lines, pos), Class::Blank | Class::EmptyLine) {
+        match next(lines, pos) {
+            Some(next) => pos = next,
+            None => return pos,
+        }
+    }
+    let class = class_at(lines, pos);
+    loop {
+        match next(lines, pos) {
+            Some(next) if class_at(lines, next) == class => pos = next,
+            _ => return pos,
+        }
+    }
+}
+
+/// The `b` motion: the start of the current or previous word.
+fn word_backward(lines: &Lines, pos: Pos) -> Pos {
+    let Some(mut pos) = previous(lines, pos) else {
+        return pos;
+    };
+    while class_at(lines, pos) == Class::Blank {
+        match previous(lines, pos) {
+            Some(previous) => pos = previous,
+            None => return pos,
+        }
+    }
+    let class = class_at(lines, pos);
+    if class == Class::EmptyLine {
+        return pos;
+    }
+    loop {
+        match previous(lines, pos) {
+            Some(previous) if class_at(lines, previous) == class => pos = previous,
+            _ => return pos,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_buffer_eq,
+        buffer::Buffer,
+        input::Input,
+        layout::Rect,
+        style::{Color, Style},
+        widgets::{StatefulWidget, TextArea},
+    };
+
+    fn vim(text: &str) -> TextAreaState {
+        let mut state = TextAreaState::new(text).with_vim(true);
+        state.set_cursor(0, 0);
+        state
+    }
+
+    fn keys(state: &mut TextAreaState, keys: &str) {
+        for c in keys.chars() {
+            let key = match c {
+                '⎋' => Key::Esc,
+                c => Key::Char(c),
+            };
+            state.handle_key(key.into());
+        }
+    }
+
+    #[test]
+    fn word_motions() {
+        let lines = Lines::from(vec![
+            "foo.bar  baz".to_string(),
+            String::new(),
+            "qux".to_string(),
+        ]);
+        assert_eq!(word_forward(&lines, (0, 0)), (0, 3));
+        assert_eq!(word_forward(&lines, (0, 3)), (0, 4));
+        assert_eq!(word_forward(&lines, (0, 4)), (0, 9));
+        assert_eq!(word_forward(&lines, (0, 9)), (1, 0));
+        assert_eq!(word_forward(&lines, (1, 0)), (2, 0));
+        assert_eq!(word_end(&lines, (0, 0)), (0, 2));
+        assert_eq!(word_end(&lines, (0, 6)), (0, 11));
+        assert_eq!(word_end(&lines, (0, 11)), (2, 2));
+        assert_eq!(word_backward(&lines, (2, 0)), (1, 0));
+        assert_eq!(word_backward(&lines, (1, 0)), (0, 9));
+        assert_eq!(word_backward(&lines, (0, 9)), (0, 4));
+        assert_eq!(word_backward(&lines, (0, 2)), (0, 0));
+    }
+
+    #[test]
+    fn normal_motions() {
+        let mut state = vim("one two three\nfour\nfive");
+        keys(&mut state, "w");
+        assert_eq!(state.cursor(), (0, 4));
+        keys(&mut state, "$");
+        assert_eq!(state.cursor(), (0, 12));
+        keys(&mut state, "0e");
+        assert_eq!(state.cursor(), (0, 2));
+        keys(&mut state, "G");
+        assert_eq!(state.cursor(), (2, 0));
+        keys(&mut state, "gg");
+        assert_eq!(state.cursor(), (0, 0));
+        keys(&mut state, "2G");
+        assert_eq!(state.cursor(), (1, 0));
+        keys(&mut state, "b");
+        assert_eq!(state.cursor(), (0, 8));
+        keys(&mut state, "2l");
+        assert_eq!(state.cursor(), (0, 10));
+    }
+
+    #[test]
+    fn modes() {
+        let mut state = vim("ac");
+        assert_eq!(state.vim_mode(), Some(VimMode::Normal));
+        keys(&mut state, "ab");
+        assert_eq!(state.vim_mode(), Some(VimMode::Insert));
+        assert_eq!(state.value(), "abc");
+        keys(&mut state, "⎋");
+        assert_eq!(state.vim_mode(), Some(VimMode::Normal));
+        assert_eq!(state.cursor(), (0, 1));
+        keys(&mut state, "Ad⎋");
+        assert_eq!(state.value(), "abcd");
+        assert_eq!(state.cursor(), (0, 3));
+        keys(&mut state, "onew⎋Otop⎋");
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["abcd", "top", "new"]);
+        state.set_vim(false);
+        assert_eq!(state.vim_mode(), None);
+    }
+
+    #[test]
+    fn operators() {
+        let mut state = vim("one two three");
+        keys(&mut state, "dw");
+        assert_eq!(state.value(), "two three");
+        assert_eq!(state.register('"'), Some("one "));
+        keys(&mut state, "cwfour⎋");
+        assert_eq!(state.value(), "four three");
+        keys(&mut state, "w\"ayeP");
+        assert_eq!(state.register('a'), Some("three"));
+        assert_eq!(state.value(), "four threethree");
+        keys(&mut state, "0d$");
+        assert_eq!(state.value(), "");
+        keys(&mut state, "u");
+        assert_eq!(state.value(), "four threethree");
+        keys(&mut state, "\"ap");
+        assert_eq!(state.value(), "fthreeour threethree");
+    }
+
+    #[test]
+    fn linewise_operators() {
+        let mut state = vim("1\n2\n3\n4");
+        keys(&mut state, "2dd");
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["3", "4"]);
+        assert_eq!(state.register('"'), Some("1\n2"));
+        keys(&mut state, "p");
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["3", "1", "2", "4"]);
+        assert_eq!(state.cursor(), (1, 0));
+        keys(&mut state, "yjGP");
+        assert_eq!(
+            state.lines().collect::<Vec<_>>(),
+            ["3", "1", "2", "1", "2", "4"]
+        );
+        keys(&mut state, "ggdG");
+        assert_eq!(state.lines().collect::<Vec<_>>(), [""]);
+        keys(&mut state, "u");
+        keys(&mut state, "ccx⎋");
+        assert_eq!(
+            state.lines().collect::<Vec<_>>(),
+            ["x", "1", "2", "1", "2", "4"]
+        );
+    }
+
+    #[test]
+    fn visual_mode() {
+        let mut state = vim("hello world");
+        keys(&mut state, "lve");
+        assert_eq!(state.vim_mode(), Some(VimMode::Visual));
+        assert_eq!(state.vim_selection(), Some(((0, 1), (0, 5))));
+        keys(&mut state, "y");
+        assert_eq!(state.vim_mode(), Some(VimMode::Normal));
+        assert_eq!(state.register('"'), Some("ello"));
+        assert_eq!(state.cursor(), (0, 1));
+        keys(&mut state, "wvlx");
+        assert_eq!(state.value(), "hello rld");
+        keys(&mut state, "vbc_⎋");
+        assert_eq!(state.value(), "_ld");
+    }
+
+    #[test]
+    fn read_only() {
+        let mut state = vim("ab\ncd");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        StatefulWidget::render(
+            TextArea::new().read_only(true),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        keys(&mut state, "xddiApou");
+        assert_eq!(state.lines().collect::<Vec<_>>(), ["ab", "cd"]);
+        assert_eq!(state.vim_mode(), Some(VimMode::Normal));
+        keys(&mut state, "yyjvly");
+        assert_eq!(state.register('"'), Some("cd"));
+        assert_eq!(state.cursor(), (1, 0));
+    }
+
+    #[test]
+    fn render_selection() {
+        let mut state = vim("abcd");
+        keys(&mut state, "lvl");
+        let text_area = TextArea::new()
+            .cursor_style(Style::default())
+            .selection_style(Style::new().bg(Color::Blue));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        StatefulWidget::render(text_area, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["abcd "]);
+        expected.set_style(Rect::new(1, 0, 2, 1), Style::new().bg(Color::Blue));
+        assert_buffer_eq!(buf, expected);
+    }
+
+    /// Returns a state in the normal mode with the main cursor on the "b" of "abcd" and another
+    /// cursor after the "d".
+    fn two_cursors_on_one_line() -> TextAreaState {
+        let mut state = vim("ab\ncd");
+        assert!(state.add_cursor_below());
+        keys(&mut state, "i");
+        state.handle_key(Key::End.into());
+        state.handle_key(Key::Delete.into());
+        keys(&mut state, "⎋");
+        assert_eq!(state.value(), "abcd");
+        assert_eq!(state.cursor(), (0, 1));
+        assert_eq!(state.cursors(), [(0, 1), (0, 4)]);
+        state
+    }
+
+    #[test]
+    fn delete_with_other_cursors() {
+        let mut state = two_cursors_on_one_line();
+        // the other cursor moves with the text after the deleted one
+        keys(&mut state, "x");
+        assert_eq!(state.value(), "acd");
+        assert_eq!(state.cursors(), [(0, 1), (0, 3)]);
+        // the other cursor in the deleted text is merged
+        keys(&mut state, "d$");
+        assert_eq!(state.value(), "a");
+        assert_eq!(state.cursors(), [(0, 0)]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        StatefulWidget::render(TextArea::new(), buf.area, &mut buf, &mut state);
+    }
+
+    #[test]
+    fn change_with_other_cursors() {
+        let mut state = two_cursors_on_one_line();
+        keys(&mut state, "cl");
+        assert_eq!(state.value(), "acd");
+        assert_eq!(state.cursors(), [(0, 1), (0, 3)]);
+        // the insert mode types at every cursor
+        keys(&mut state, "X⎋");
+        assert_eq!(state.value(), "aXcdX");
+        // the other cursor in the changed text is merged
+        keys(&mut state, "0c$");
+        assert_eq!(state.value(), "");
+        assert_eq!(state.cursors(), [(0, 0)]);
+        keys(&mut state, "Y");
+        assert_eq!(state.value(), "Y");
+    }
+}
@@ -0,0 +1,142 @@
+use super::{lines::Lines, wrap_line, WrappedRow};
+
+/// The number of rows of each line wrapped at the width of the last render, so that rendering
+/// only wraps the lines of the viewport.
+///
+/// The index keeps the lines it was built for, and only wraps the lines changed since then when
+/// it is updated: the chunks of a large text shared with the index are skipped without comparing
+/// their lines.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub(crate) struct WrapIndex {
+    width: usize,
+    lines: Lines,
+    /// Index of the first row of each line, followed by the total number of rows
+    starts: Vec<usize>,
+}
+
+impl WrapIndex {
+    /// Updates the rows of the lines changed since the last update, or of all the lines when
+    /// the width changed.
+    pub fn update(&mut self, lines: &Lines, width: usize) {
+        let mut rows = vec![];
+        let mut count = |line: &String, index: usize| {
+            rows.clear();
+            wrap_line(line, width, index, &mut rows);
+            rows.len()
+        };
+        if width != self.width || self.starts.is_empty() {
+            self.starts.clear();
+            self.starts.push(0);
+            let mut total = 0;
+            for (i, line) in lines.iter().enumerate() {
+                total += count(line, i);
+                self.starts.push(total);
+            }
+        } else {
+            let (prefix, suffix) = self.lines.common_lines(lines);
+            if prefix == lines.len() && prefix == self.lines.len() {
+                return;
+            }
+            let old_end = self.lines.len() - suffix;
+            let new_end = lines.len() - suffix;
+            let mut total = self.starts[prefix];
+            let mut starts = Vec::with_capacity(new_end - prefix);
+            for (i, line) in lines.range(prefix..new_end).enumerate() {
+                total += count(line, prefix + i);
+                starts.push(total);
+            }
+            // the starts of the lines after the changed ones move by the rows added or removed
+            let old_total = self.starts[old_end];
+            for start in &mut self.starts[old_end + 1..] {
+                *start = *start - old_total + total;
+            }
+            self.starts.splice(prefix + 1..old_end + 1, starts);
+        }
+        self.width = width;
+        self.lines = lines.clone();
+    }
+
+    /// Returns the index of the first row of a line, or the total number of rows after the last
+    /// line.
+    pub fn start(&self, line: usize) -> usize {
+        self.starts[line]
+    }
+
+    /// Returns the line containing the given row, and the index of the row among the rows of
+    /// the line. The rows after the last one are in the last line.
+    pub fn line_at(&self, row: usize) -> (usize, usize) {
+        let line = self
+            .starts
+            .partition_point(|&start| start <= row)
+            .saturating_sub(1)
+            .min(self.starts.len().saturating_sub(2));
+        (line, row - self.starts[line])
+    }
+}
+
+/// Returns the rows of the lines from the given one, until there are `count` rows or no more
+/// lines, using the given rows for the line of the cursor.
+pub(crate) fn rows_from(
+    lines: &Lines,
+    width: usize,
+    first: usize,
+    count: usize,
+    (cursor_line, cursor_rows): (usize, &[WrappedRow]),
+) -> Vec<WrappedRow> {
+    let mut rows = vec![];
+    for line in first..lines.len() {
+        if rows.len() >= count {
+            break;
+        }
+        if line == cursor_line {
+            rows.extend_from_slice(cursor_rows);
+        } else {
+            wrap_line(&lines[line], width, line, &mut rows);
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Lines {
+        text.split('\n').map(String::from).collect()
+    }
+
+    #[test]
+    fn update() {
+        let mut index = WrapIndex::default();
+        index.update(&lines("ab cd\nef\n\ngh ij kl"), 3);
+        assert_eq!(index.starts, [0, 2, 3, 4, 7]);
+        assert_eq!(index.line_at(0), (0, 0));
+        assert_eq!(index.line_at(4), (3, 0));
+        assert_eq!(index.line_at(6), (3, 2));
+        assert_eq!(index.line_at(9), (3, 5));
+
+        // the rows of the lines after the changed one are moved
+        index.update(&lines("ab cd\nef gh ij\n\ngh ij kl"), 3);
+        assert_eq!(index.starts, [0, 2, 5, 6, 9]);
+        index.update(&lines("ab cd\n\ngh ij kl"), 3);
+        assert_eq!(index.starts, [0, 2, 3, 6]);
+        assert_eq!(index.start(3) - index.start(2), 3);
+        index.update(&lines("ab cd\n\ngh ij kl"), 6);
+        assert_eq!(index.starts, [0, 1, 2, 4]);
+    }
+
+    #[test]
+    fn update_chunks() {
+        let text = (0..5000).map(|i| format!("line {i}")).collect::<Vec<_>>();
+        let mut lines = Lines::from(text);
+        let mut index = WrapIndex::default();
+        index.update(&lines, 5);
+        assert_eq!(index.start(5000), 10000);
+        lines[2500].push_str(" and more");
+        lines.insert(10, String::new());
+        index.update(&lines, 5);
+        assert_eq!(index.start(5001), 10003);
+        assert_eq!(index.start(2502) - index.start(2501), 4);
+        assert_eq!(index.line_at(5022), (2510, 1));
+    }
+}
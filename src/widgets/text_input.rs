@@ -0,0 +1,1963 @@
+#![deny(missing_docs)]
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Range,
+    rc::Rc,
+};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthChar;
+
+use crate::{
+    buffer::Buffer,
+    input::{
+        EditCommand, Focusable, Input, Key, KeyEvent, KeyEventKind, KeyModifiers, Keymap, KillRing,
+        MouseButton, MouseEvent, MouseEventKind, Validator,
+    },
+    layout::{Position, Rect},
+    style::{Color, Modifier, Style, Styled},
+    terminal::RedrawRequest,
+    text::{grapheme_width, Line},
+    widgets::{history::History, words, Block, CursorProvider, StatefulWidget, Widget},
+};
+
+const DEFAULT_CURSOR_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_SELECTION_STYLE: Style = Style::new().bg(Color::DarkGray);
+const DEFAULT_INVALID_STYLE: Style = Style::new().fg(Color::Red);
+const DEFAULT_PREEDIT_STYLE: Style = Style::new().add_modifier(Modifier::UNDERLINED);
+const DEFAULT_PLACEHOLDER_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+const DEFAULT_DISABLED_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+
+/// A single line text input.
+///
+/// The text, the cursor and the horizontal scroll offset of the input are stored in a
+/// [`TextInputState`], which provides the editing operations (inserting and deleting text,
+/// moving the cursor). All the operations work on grapheme clusters, so that characters made of
+/// several code points (e.g. `e` followed by a combining accent, or emoji sequences) are never
+/// split.
+///
+/// When the value is wider than the render area, the input scrolls horizontally to keep the
+/// cursor visible. The cursor is drawn with the [`cursor_style`](TextInput::cursor_style), and
+/// its position on screen is available with [`TextInputState::cursor_screen_position`] after
+/// rendering. The state is a [`CursorProvider`], so the terminal cursor is shown there with
+/// [`Frame::set_cursor_for`].
+///
+/// Moving the cursor with `Shift` held (see [`Keymap`]) selects the text between the cursor and
+/// the position where the selection started, drawn with the
+/// [`selection_style`](TextInput::selection_style). Typing, pasting and deleting then replace the
+/// selected text, and [`TextInputState::take_selection`] cuts it.
+///
+/// Clicking the input with the left mouse button moves the cursor to the clicked cell, and
+/// dragging selects text, see [`Input::handle_mouse`].
+///
+/// A [`Validator`] can be configured with [`validator`](TextInput::validator): the value is then
+/// validated on every render, the error message is available with [`TextInputState::error`], and
+/// the input is restyled with the [`invalid_style`](TextInput::invalid_style) while it is
+/// invalid.
+///
+/// A [`placeholder`](TextInput::placeholder) can be shown in place of the value while the value
+/// is empty and the input doesn't have the focus (see [`TextInputState::set_focused`]).
+///
+/// A [`read_only`](TextInput::read_only) input moves the cursor and selects text, but rejects
+/// the edits typed or pasted. A [`disabled`](TextInput::disabled) input rejects all the input
+/// events, doesn't draw its cursor and is restyled with the
+/// [`disabled_style`](TextInput::disabled_style). The flags only apply to the events, from the
+/// render on (see [`Focusable::is_disabled`]): the editing methods of [`TextInputState`] (e.g.
+/// [`TextInputState::insert_str`]) still change the value.
+///
+/// [`Frame::set_cursor_for`]: crate::Frame::set_cursor_for
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut TextInputState) {
+/// let input = TextInput::new().block(Block::default().borders(Borders::ALL).title("Name"));
+/// frame.render_stateful_widget(input, Rect::new(0, 0, 20, 3), state);
+/// frame.set_cursor_for(state);
+/// # }
+/// let mut state = TextInputState::default();
+/// state.insert_str("hello");
+/// state.move_home();
+/// state.delete_after();
+/// assert_eq!(state.value(), "ello");
+/// ```
+#[derive(Clone)]
+pub struct TextInput<'a> {
+    block: Option<Block<'a>>,
+    style: Style,
+    cursor_style: Style,
+    selection_style: Style,
+    mask_char: Option<char>,
+    validator: Option<Rc<dyn Validator + 'a>>,
+    invalid_style: Style,
+    preedit_style: Style,
+    placeholder: Option<Line<'a>>,
+    placeholder_style: Style,
+    read_only: bool,
+    disabled: bool,
+    disabled_style: Style,
+}
+
+impl<'a> fmt::Debug for TextInput<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextInput")
+            .field("block", &self.block)
+            .field("style", &self.style)
+            .field("cursor_style", &self.cursor_style)
+            .field("selection_style", &self.selection_style)
+            .field("mask_char", &self.mask_char)
+            .field("validator", &self.validator.as_ref().map(|_| ".."))
+            .field("invalid_style", &self.invalid_style)
+            .field("preedit_style", &self.preedit_style)
+            .field("placeholder", &self.placeholder)
+            .field("placeholder_style", &self.placeholder_style)
+            .field("read_only", &self.read_only)
+            .field("disabled", &self.disabled)
+            .field("disabled_style", &self.disabled_style)
+            .finish()
+    }
+}
+
+impl<'a> Default for TextInput<'a> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            style: Style::default(),
+            cursor_style: DEFAULT_CURSOR_STYLE,
+            selection_style: DEFAULT_SELECTION_STYLE,
+            mask_char: None,
+            validator: None,
+            invalid_style: DEFAULT_INVALID_STYLE,
+            preedit_style: DEFAULT_PREEDIT_STYLE,
+            placeholder: None,
+            placeholder_style: DEFAULT_PLACEHOLDER_STYLE,
+            read_only: false,
+            disabled: false,
+            disabled_style: DEFAULT_DISABLED_STYLE,
+        }
+    }
+}
+
+impl<'a> TextInput<'a> {
+    /// Creates a new text input.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Surrounds the input with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the input.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the cell under the cursor. Defaults to reversed colors.
+    ///
+    /// Use [`Style::default()`] to not draw the cursor, e.g. when showing the terminal cursor
+    /// instead.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn cursor_style(mut self, style: Style) -> Self {
+        self.cursor_style = style;
+        self
+    }
+
+    /// Sets the style of the selected text, see [`TextInputState::selection`]. Defaults to a dark
+    /// gray background.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selection_style(mut self, style: Style) -> Self {
+        self.selection_style = style;
+        self
+    }
+
+    /// Renders each grapheme of the value as the given character, e.g. `*` for passwords.
+    ///
+    /// Only the rendering is affected: [`TextInputState::value`] still returns the real value,
+    /// and the cursor moves over its graphemes.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn mask_char(mut self, mask_char: char) -> Self {
+        self.mask_char = Some(mask_char);
+        self
+    }
+
+    /// Sets the validator checking the value of the input on every render.
+    ///
+    /// See [`TextInputState::error`] for the result of the validation.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn validator<V>(mut self, validator: V) -> Self
+    where
+        V: Validator + 'a,
+    {
+        self.validator = Some(Rc::new(validator));
+        self
+    }
+
+    /// Sets the style applied while the value is invalid. Defaults to a red foreground.
+    ///
+    /// The style replaces the border style of the [`Block`] if there is one, and is patched
+    /// over the text otherwise.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn invalid_style(mut self, style: Style) -> Self {
+        self.invalid_style = style;
+        self
+    }
+
+    /// Sets the style of the text being composed with an input method, see
+    /// [`TextInputState::set_preedit`]. Defaults to underlined.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn preedit_style(mut self, style: Style) -> Self {
+        self.preedit_style = style;
+        self
+    }
+
+    /// Sets the text shown while the value is empty and the input doesn't have the focus, e.g. a
+    /// hint of the expected value.
+    ///
+    /// The placeholder is never part of the value: it disappears as soon as something is typed.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder<T>(mut self, placeholder: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the style of the [`placeholder`](Self::placeholder), patched by the styles of its
+    /// spans. Defaults to dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder_style(mut self, style: Style) -> Self {
+        self.placeholder_style = style;
+        self
+    }
+
+    /// Makes the input read-only: the cursor still moves and selects text, which can be copied,
+    /// but the keys and the pasted text editing the value are rejected.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Disables the input: it rejects all the input events, doesn't draw its cursor and is
+    /// restyled with the [`disabled_style`](Self::disabled_style).
+    ///
+    /// A disabled field of a [`Form`](struct@crate::widgets::Form) is skipped when moving the focus.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the style patched over the whole input while it is
+    /// [`disabled`](Self::disabled). Defaults to dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = style;
+        self
+    }
+
+    /// Records the read-only and disabled flags in the state, which rejects the events
+    /// accordingly.
+    pub(crate) fn apply_flags(&self, state: &mut TextInputState) {
+        state.read_only = self.read_only;
+        state.disabled = self.disabled;
+    }
+
+    /// Returns the [`disabled_style`](Self::disabled_style) if the input is disabled, for the
+    /// widgets wrapping it to dim the rest of their area.
+    pub(crate) fn disabled_patch(&self) -> Option<Style> {
+        self.disabled.then_some(self.disabled_style)
+    }
+
+    /// Returns the number of rows taken by the input: one, plus the borders and the padding of
+    /// its block.
+    pub(crate) fn height(&self) -> u16 {
+        let probe = Rect::new(0, 0, 100, 100);
+        let block_height = self
+            .block
+            .as_ref()
+            .map_or(0, |block| probe.height - block.inner(probe).height);
+        block_height + 1
+    }
+
+    /// Returns `true` if the input has a validator.
+    pub(crate) fn has_validator(&self) -> bool {
+        self.validator.is_some()
+    }
+}
+
+impl<'a> Styled for TextInput<'a> {
+    type Item = TextInput<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// What happened to a [`TextInputState`] while handling the input, as returned by
+/// [`TextInputState::take_event`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TextInputEvent {
+    /// The value was edited.
+    Changed,
+    /// `Enter` was pressed.
+    Submitted,
+    /// `Esc` was pressed.
+    Cancelled,
+}
+
+/// The state of a [`TextInput`].
+///
+/// The state owns the value of the input and the position of the cursor, which is always on a
+/// grapheme boundary. It also keeps the history of the edits, which can be reverted with
+/// [`undo`](Self::undo) and [`redo`](Self::redo).
+///
+/// Instead of comparing the value after each event, the application can react to the edits and
+/// to `Enter` and `Esc` with [`take_event`](Self::take_event):
+///
+/// ```rust
+/// use ratatui::{
+///     input::{Input, Key},
+///     widgets::{TextInputEvent, TextInputState},
+/// };
+///
+/// let mut state = TextInputState::default();
+/// state.handle_key(Key::Char('a').into());
+/// state.handle_key(Key::Char('b').into());
+/// state.handle_key(Key::Enter.into());
+/// assert_eq!(state.take_event(), Some(TextInputEvent::Changed));
+/// assert_eq!(state.take_event(), Some(TextInputEvent::Submitted));
+/// assert_eq!(state.take_event(), None);
+/// ```
+///
+/// The text typed or pasted can be restricted to a [maximum length](Self::with_max_length) and to
+/// the characters accepted by a [filter](Self::with_filter), e.g. for a field only taking a few
+/// digits:
+///
+/// ```rust
+/// use ratatui::widgets::TextInputState;
+///
+/// let mut state = TextInputState::default()
+///     .with_max_length(4)
+///     .with_filter(|c| c.is_ascii_digit());
+/// state.insert_str("12-34-56");
+/// assert_eq!(state.value(), "1234");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TextInputState {
+    value: String,
+    /// Byte offset of the cursor in the value
+    cursor: usize,
+    /// Byte offset where the selection started, the cursor being its other end
+    anchor: Option<usize>,
+    /// Index of the first visible grapheme
+    offset: usize,
+    /// Position of the cursor on screen during the last render
+    screen_cursor: Option<Position>,
+    /// Error message of the last validation
+    error: Option<String>,
+    /// Value and cursor before the previous edits
+    history: History<(String, usize)>,
+    /// Text being composed with an input method, displayed at the cursor
+    preedit: Option<String>,
+    /// Whether the input has the focus, showing the cursor instead of the placeholder
+    focused: bool,
+    /// Maximum number of graphemes of the value accepted by the edits
+    max_length: Option<usize>,
+    /// Characters accepted by the edits
+    filter: Option<CharFilter>,
+    /// Whether the edits typed or pasted are rejected, set when rendering
+    read_only: bool,
+    /// Whether all the input events are rejected, set when rendering
+    disabled: bool,
+    /// Area of the text during the last render, where the mouse moves the cursor
+    area: Rect,
+    /// Mask character of the last render, whose width maps the columns to the value
+    mask_char: Option<char>,
+    /// Whether text is being selected by dragging the mouse
+    dragging: bool,
+    /// Whether the last edit killed text, which the next kill joins in the kill ring
+    killed: bool,
+    /// Whether the value was edited since the last `take_event`
+    changed: bool,
+    /// Whether `Enter` or `Esc` was pressed since the last `take_event`
+    outcome: Option<TextInputEvent>,
+}
+
+/// A filter of the characters accepted by the edits of a [`TextInputState`], compared by address.
+#[derive(Debug, Clone, Copy, Eq)]
+struct CharFilter(fn(char) -> bool);
+
+impl PartialEq for CharFilter {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 as usize == other.0 as usize
+    }
+}
+
+impl Hash for CharFilter {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.0 as usize).hash(state);
+    }
+}
+
+impl Default for TextInputState {
+    fn default() -> Self {
+        Self {
+            value: String::new(),
+            cursor: 0,
+            anchor: None,
+            offset: 0,
+            screen_cursor: None,
+            error: None,
+            history: History::default(),
+            preedit: None,
+            focused: true,
+            max_length: None,
+            filter: None,
+            read_only: false,
+            disabled: false,
+            area: Rect::default(),
+            mask_char: None,
+            dragging: false,
+            killed: false,
+            changed: false,
+            outcome: None,
+        }
+    }
+}
+
+impl TextInputState {
+    /// Creates a new state with the given value and the cursor at its end.
+    pub fn new<T>(value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        let mut state = Self::default();
+        state.set_value(value);
+        state
+    }
+
+    /// Returns the value of the input.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Replaces the value of the input and moves the cursor to its end.
+    ///
+    /// Line breaks are removed from the value. The edit history is cleared, as the previous
+    /// edits don't apply to the new value.
+    pub fn set_value<T>(&mut self, value: T)
+    where
+        T: Into<String>,
+    {
+        self.value = value.into();
+        self.value.retain(|c| c != '\n' && c != '\r');
+        self.cursor = self.value.len();
+        self.anchor = None;
+        self.history.clear();
+    }
+
+    /// Clears the value of the input. This can be undone.
+    pub fn clear(&mut self) {
+        if self.value.is_empty() {
+            return;
+        }
+        self.record(false);
+        self.value.clear();
+        self.cursor = 0;
+        self.anchor = None;
+        self.offset = 0;
+    }
+
+    /// Replaces the value and moves the cursor to its end, as a single edit that can be undone.
+    pub(crate) fn replace_value(&mut self, value: &str) {
+        self.record(false);
+        self.value = value.replace(['\n', '\r'], "");
+        self.cursor = self.value.len();
+        self.anchor = None;
+    }
+
+    /// Returns the byte offset of the cursor in the value.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Moves the cursor to the given byte offset, or to the start of the grapheme containing it.
+    pub fn set_cursor(&mut self, cursor: usize) {
+        self.history.break_coalescing();
+        self.anchor = None;
+        self.killed = false;
+        self.snap_cursor(cursor);
+    }
+
+    /// Returns the index of the grapheme at the cursor, i.e. the number of graphemes before it.
+    ///
+    /// Unlike the byte offset of [`cursor`](Self::cursor), this counts an emoji, a letter with
+    /// combining marks or a wide character as one.
+    pub fn cursor_grapheme_index(&self) -> usize {
+        self.value[..self.cursor].graphemes(true).count()
+    }
+
+    /// Moves the cursor before the grapheme at the given index, or to the end of the value.
+    pub fn set_cursor_grapheme_index(&mut self, index: usize) {
+        let cursor = self
+            .value
+            .grapheme_indices(true)
+            .nth(index)
+            .map_or(self.value.len(), |(i, _)| i);
+        self.set_cursor(cursor);
+    }
+
+    /// Returns the column of the cursor, i.e. the width on screen of the graphemes before it.
+    ///
+    /// Wide characters take two columns and combining marks none. The column is relative to the
+    /// start of the value: it ignores the scrolling and the mask character of the widget.
+    pub fn visual_cursor_column(&self) -> usize {
+        self.value[..self.cursor]
+            .graphemes(true)
+            .map(grapheme_width)
+            .sum()
+    }
+
+    /// Returns the selected range of bytes, between the cursor and the position where the
+    /// selection started, if it isn't empty.
+    ///
+    /// Text is selected by moving the cursor with the `Select*` commands of the [`Keymap`] (e.g.
+    /// `Shift-Left`), and unselected by moving it without them.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.anchor.filter(|&anchor| anchor != self.cursor)?;
+        Some(anchor.min(self.cursor)..anchor.max(self.cursor))
+    }
+
+    /// Returns the selected text, if any.
+    pub fn selected_text(&self) -> Option<&str> {
+        self.selection().map(|range| &self.value[range])
+    }
+
+    /// Selects the whole value, moving the cursor to its end.
+    pub fn select_all(&mut self) {
+        self.move_end();
+        self.anchor = Some(0);
+    }
+
+    /// Unselects the text, leaving the cursor where it is.
+    pub fn clear_selection(&mut self) {
+        self.anchor = None;
+    }
+
+    /// Deletes the selected text. Returns `true` if something was deleted.
+    pub fn delete_selection(&mut self) -> bool {
+        if self.selection().is_none() {
+            self.anchor = None;
+            return false;
+        }
+        self.record(false);
+        self.remove_selection();
+        true
+    }
+
+    /// Deletes the selected text and returns it, e.g. to implement a cut to a clipboard of the
+    /// application.
+    pub fn take_selection(&mut self) -> Option<String> {
+        let text = self.selected_text()?.to_string();
+        self.delete_selection();
+        Some(text)
+    }
+
+    /// Copies the selected text to the system clipboard.
+    ///
+    /// Returns `Ok(false)` if there is nothing to copy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the system clipboard can't be accessed.
+    #[cfg(feature = "clipboard")]
+    pub fn copy_selection(&self) -> std::io::Result<bool> {
+        let Some(text) = self.selected_text() else {
+            return Ok(false);
+        };
+        arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.set_text(text))
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))?;
+        Ok(true)
+    }
+
+    /// Moves the selected text to the system clipboard.
+    ///
+    /// Returns `Ok(false)` if there is nothing to cut. The text is only deleted once it has been
+    /// copied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the system clipboard can't be accessed.
+    #[cfg(feature = "clipboard")]
+    pub fn cut_selection(&mut self) -> std::io::Result<bool> {
+        let copied = self.copy_selection()?;
+        Ok(copied && self.delete_selection())
+    }
+
+    /// Removes the selected text without recording the edit, and unselects it.
+    fn remove_selection(&mut self) {
+        if let Some(range) = self.selection() {
+            self.cursor = range.start;
+            self.value.replace_range(range, "");
+        }
+        self.anchor = None;
+    }
+
+    /// Applies a cursor motion extending the selection, which starts at the cursor if there is
+    /// none yet.
+    fn extend_selection<F>(&mut self, motion: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let anchor = self.anchor.unwrap_or(self.cursor);
+        motion(self);
+        self.anchor = Some(anchor);
+    }
+
+    fn snap_cursor(&mut self, cursor: usize) {
+        self.cursor = self
+            .value
+            .grapheme_indices(true)
+            .map(|(i, _)| i)
+            .take_while(|&i| i <= cursor)
+            .last()
+            .unwrap_or(0);
+        if cursor >= self.value.len() {
+            self.cursor = self.value.len();
+        }
+    }
+
+    /// Returns the position of the cursor on screen during the last render, if it was visible.
+    ///
+    /// While text is being composed (see [`set_preedit`](Self::set_preedit)), this is the
+    /// position after the composed text.
+    pub fn cursor_screen_position(&self) -> Option<Position> {
+        self.screen_cursor
+    }
+
+    /// Returns `true` if the input has the focus. Defaults to `true`.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Sets whether the input has the focus.
+    ///
+    /// An input without the focus doesn't draw its cursor, and shows its
+    /// [`placeholder`](TextInput::placeholder) while the value is empty.
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Returns `true` if the input was [read-only](TextInput::read_only) during the last render.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns `true` if the input was [disabled](TextInput::disabled) during the last render.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Returns the text being composed with an input method, if any.
+    pub fn preedit(&self) -> Option<&str> {
+        self.preedit.as_deref()
+    }
+
+    /// Sets the text being composed with an input method (IME), e.g. the phonetic input of a
+    /// CJK character before it is chosen, or `None` when the composition ends.
+    ///
+    /// The composed text is displayed at the cursor with the
+    /// [`preedit_style`](TextInput::preedit_style) of the widget, but is not part of the value
+    /// and is not recorded in the edit history: the final text is inserted as usual (e.g. with
+    /// [`insert_str`](Self::insert_str)) once the input method commits it. Line breaks are
+    /// removed from the composed text.
+    pub fn set_preedit<T>(&mut self, text: Option<T>)
+    where
+        T: Into<String>,
+    {
+        self.preedit = text
+            .map(|text| text.into().replace(['\n', '\r'], ""))
+            .filter(|text| !text.is_empty());
+    }
+
+    /// Validates the value with the given validator, and returns `true` if it is valid.
+    ///
+    /// The error message is then available with [`error`](Self::error).
+    pub fn validate(&mut self, validator: &dyn Validator) -> bool {
+        self.error = validator.validate(&self.value).err();
+        self.error.is_none()
+    }
+
+    /// Returns the error message of the last validation, if the value was invalid.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Returns `true` if the value was valid during the last validation.
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Sets the maximum number of edits that can be undone. Defaults to 100.
+    pub fn set_history_depth(&mut self, depth: usize) {
+        self.history.set_depth(depth);
+    }
+
+    /// Sets the maximum number of edits that can be undone. Defaults to 100.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_history_depth(mut self, depth: usize) -> Self {
+        self.set_history_depth(depth);
+        self
+    }
+
+    /// Returns the maximum number of graphemes that can be typed or pasted, if any.
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// Sets the maximum number of graphemes that can be typed or pasted, or `None` for no limit.
+    ///
+    /// Insertions beyond the limit are truncated. The limit doesn't apply to the value given to
+    /// [`set_value`](Self::set_value), which is kept as is.
+    pub fn set_max_length(&mut self, max_length: Option<usize>) {
+        self.max_length = max_length;
+    }
+
+    /// Sets the maximum number of graphemes that can be typed or pasted, see
+    /// [`set_max_length`](Self::set_max_length).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.set_max_length(Some(max_length));
+        self
+    }
+
+    /// Sets the filter of the characters that can be typed or pasted, or `None` to accept all of
+    /// them.
+    ///
+    /// The characters rejected by the filter are dropped from the insertions, e.g. with
+    /// `|c| !c.is_whitespace()` or `|c| c.is_ascii()`. Like the maximum length, the filter
+    /// doesn't apply to the value given to [`set_value`](Self::set_value).
+    pub fn set_filter(&mut self, filter: Option<fn(char) -> bool>) {
+        self.filter = filter.map(CharFilter);
+    }
+
+    /// Sets the filter of the characters that can be typed or pasted, see
+    /// [`set_filter`](Self::set_filter).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_filter(mut self, filter: fn(char) -> bool) -> Self {
+        self.set_filter(Some(filter));
+        self
+    }
+
+    /// Returns `true` if the character can be inserted at the cursor: it isn't a line break, the
+    /// filter accepts it and the value isn't at its maximum length.
+    fn can_insert(&self, c: char) -> bool {
+        c != '\n' && c != '\r' && self.accepts(c) && self.room() > 0
+    }
+
+    fn accepts(&self, c: char) -> bool {
+        self.filter.map_or(true, |filter| (filter.0)(c))
+    }
+
+    /// Returns the number of graphemes that can still be inserted, replacing the selected text.
+    fn room(&self) -> usize {
+        let Some(max_length) = self.max_length else {
+            return usize::MAX;
+        };
+        let selected = self
+            .selected_text()
+            .map_or(0, |text| text.graphemes(true).count());
+        max_length.saturating_sub(self.value.graphemes(true).count() - selected)
+    }
+
+    /// Reverts the last edit. Returns `true` if there was something to undo.
+    ///
+    /// Consecutive characters inserted with [`insert_char`](Self::insert_char) are undone
+    /// together, unless the cursor was moved in between.
+    pub fn undo(&mut self) -> bool {
+        let current = (self.value.clone(), self.cursor);
+        let Some((value, cursor)) = self.history.undo(current) else {
+            return false;
+        };
+        self.value = value;
+        self.cursor = cursor;
+        self.anchor = None;
+        self.changed = true;
+        true
+    }
+
+    /// Restores the last undone edit. Returns `true` if there was something to redo.
+    pub fn redo(&mut self) -> bool {
+        let current = (self.value.clone(), self.cursor);
+        let Some((value, cursor)) = self.history.redo(current) else {
+            return false;
+        };
+        self.value = value;
+        self.cursor = cursor;
+        self.anchor = None;
+        self.changed = true;
+        true
+    }
+
+    /// Records the value before an edit in the history, and the edit for
+    /// [`take_event`](Self::take_event).
+    fn record(&mut self, coalesce: bool) {
+        self.history
+            .record((self.value.clone(), self.cursor), coalesce);
+        self.changed = true;
+        self.killed = false;
+    }
+
+    /// Returns what happened to the input since the last call, and forgets it. Call it until it
+    /// returns `None` after handling the input.
+    ///
+    /// [`TextInputEvent::Changed`] is returned once for all the edits since the last call, by the
+    /// input events or the editing methods such as [`insert_str`](Self::insert_str), but not for
+    /// [`set_value`](Self::set_value). It comes before the last [`TextInputEvent::Submitted`] or
+    /// [`TextInputEvent::Cancelled`], recorded when `Enter` or `Esc` is pressed without modifiers.
+    pub fn take_event(&mut self) -> Option<TextInputEvent> {
+        if std::mem::take(&mut self.changed) {
+            return Some(TextInputEvent::Changed);
+        }
+        self.outcome.take()
+    }
+
+    /// Inserts a character at the cursor, replacing the selected text, and moves the cursor after
+    /// it.
+    ///
+    /// Line breaks are ignored, as well as the characters rejected by the
+    /// [filter](Self::set_filter) or exceeding the [maximum length](Self::set_max_length).
+    pub fn insert_char(&mut self, c: char) {
+        if !self.can_insert(c) {
+            return;
+        }
+        self.record(true);
+        self.remove_selection();
+        self.value.insert(self.cursor, c);
+        // the character may have been combined with the previous grapheme
+        self.snap_cursor(self.cursor + c.len_utf8());
+    }
+
+    /// Inserts a string at the cursor, replacing the selected text, and moves the cursor after
+    /// it.
+    ///
+    /// Line breaks and the characters rejected by the [filter](Self::set_filter) are removed from
+    /// the inserted string, which is truncated to the [maximum length](Self::set_max_length).
+    pub fn insert_str(&mut self, s: &str) {
+        let mut s = s
+            .chars()
+            .filter(|&c| c != '\n' && c != '\r' && self.accepts(c))
+            .collect::<String>();
+        if let Some((end, _)) = s.grapheme_indices(true).nth(self.room()) {
+            s.truncate(end);
+        }
+        if s.is_empty() {
+            return;
+        }
+        self.record(false);
+        self.remove_selection();
+        self.value.insert_str(self.cursor, &s);
+        self.snap_cursor(self.cursor + s.len());
+    }
+
+    /// Deletes the grapheme before the cursor (i.e. `Backspace`), or the selected text. Returns
+    /// `true` if something was deleted.
+    pub fn delete_before(&mut self) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+        let Some(start) = self.previous_boundary() else {
+            return false;
+        };
+        self.record(false);
+        self.value.replace_range(start..self.cursor, "");
+        self.cursor = start;
+        true
+    }
+
+    /// Deletes the grapheme after the cursor (i.e. `Delete`), or the selected text. Returns
+    /// `true` if something was deleted.
+    pub fn delete_after(&mut self) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+        let Some(end) = self.next_boundary() else {
+            return false;
+        };
+        self.record(false);
+        self.value.replace_range(self.cursor..end, "");
+        true
+    }
+
+    /// Moves the cursor one grapheme to the left. Returns `true` if the cursor moved.
+    pub fn move_left(&mut self) -> bool {
+        self.history.break_coalescing();
+        self.anchor = None;
+        self.previous_boundary()
+            .map(|cursor| self.cursor = cursor)
+            .is_some()
+    }
+
+    /// Moves the cursor one grapheme to the right. Returns `true` if the cursor moved.
+    pub fn move_right(&mut self) -> bool {
+        self.history.break_coalescing();
+        self.anchor = None;
+        self.next_boundary()
+            .map(|cursor| self.cursor = cursor)
+            .is_some()
+    }
+
+    /// Moves the cursor to the start of the value.
+    pub fn move_home(&mut self) {
+        self.history.break_coalescing();
+        self.anchor = None;
+        self.cursor = 0;
+    }
+
+    /// Moves the cursor to the end of the value.
+    pub fn move_end(&mut self) {
+        self.history.break_coalescing();
+        self.anchor = None;
+        self.cursor = self.value.len();
+    }
+
+    /// Moves the cursor to the start of the current or previous word.
+    pub fn move_word_left(&mut self) {
+        self.history.break_coalescing();
+        self.anchor = None;
+        self.cursor = words::previous_word_start(&self.value, self.cursor);
+    }
+
+    /// Moves the cursor to the end of the current or next word.
+    pub fn move_word_right(&mut self) {
+        self.history.break_coalescing();
+        self.anchor = None;
+        self.cursor = words::next_word_end(&self.value, self.cursor);
+    }
+
+    /// Deletes from the start of the current or previous word to the cursor. Returns `true` if
+    /// something was deleted.
+    pub fn delete_word_before(&mut self) -> bool {
+        self.delete_range(
+            words::previous_word_start(&self.value, self.cursor),
+            self.cursor,
+        )
+    }
+
+    /// Deletes from the cursor to the end of the current or next word. Returns `true` if
+    /// something was deleted.
+    pub fn delete_word_after(&mut self) -> bool {
+        self.delete_range(self.cursor, words::next_word_end(&self.value, self.cursor))
+    }
+
+    /// Deletes from the start of the value to the cursor. Returns `true` if something was
+    /// deleted.
+    pub fn delete_to_start(&mut self) -> bool {
+        self.delete_range(0, self.cursor)
+    }
+
+    /// Deletes from the cursor to the end of the value. Returns `true` if something was deleted.
+    pub fn delete_to_end(&mut self) -> bool {
+        self.delete_range(self.cursor, self.value.len())
+    }
+
+    /// Deletes from the start of the current or previous word to the cursor, and adds the deleted
+    /// text to the [`KillRing`]. Returns `true` if something was deleted.
+    pub fn kill_word_before(&mut self) -> bool {
+        self.kill_range(
+            words::previous_word_start(&self.value, self.cursor),
+            self.cursor,
+        )
+    }
+
+    /// Deletes from the cursor to the end of the current or next word, and adds the deleted text
+    /// to the [`KillRing`]. Returns `true` if something was deleted.
+    pub fn kill_word_after(&mut self) -> bool {
+        self.kill_range(self.cursor, words::next_word_end(&self.value, self.cursor))
+    }
+
+    /// Deletes from the start of the value to the cursor, and adds the deleted text to the
+    /// [`KillRing`]. Returns `true` if something was deleted.
+    pub fn kill_to_start(&mut self) -> bool {
+        self.kill_range(0, self.cursor)
+    }
+
+    /// Deletes from the cursor to the end of the value, and adds the deleted text to the
+    /// [`KillRing`]. Returns `true` if something was deleted.
+    pub fn kill_to_end(&mut self) -> bool {
+        self.kill_range(self.cursor, self.value.len())
+    }
+
+    /// Inserts the last text of the [`KillRing`] at the cursor, replacing the selected text, like
+    /// [`insert_str`](Self::insert_str). Returns `false` if the kill ring is empty.
+    pub fn yank(&mut self) -> bool {
+        let Some(text) = KillRing::yank() else {
+            return false;
+        };
+        self.insert_str(&text);
+        true
+    }
+
+    /// Deletes the given range, or the selected text if there is one, and adds the deleted text
+    /// to the kill ring, joined to the last entry if the previous edit was also a kill.
+    fn kill_range(&mut self, start: usize, end: usize) -> bool {
+        let range = self.selection().unwrap_or(start..end);
+        if range.is_empty() {
+            return false;
+        }
+        let join = self.killed;
+        KillRing::kill(&self.value[range.clone()], join, range.end == self.cursor);
+        self.delete_range(range.start, range.end);
+        self.killed = true;
+        true
+    }
+
+    /// Deletes the given range, or the selected text if there is one.
+    fn delete_range(&mut self, start: usize, end: usize) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+        if start == end {
+            return false;
+        }
+        self.record(false);
+        self.value.replace_range(start..end, "");
+        self.cursor = start;
+        true
+    }
+
+    /// Executes an editing command. Returns `false` if the command doesn't apply to a single
+    /// line input, i.e. for [`MoveUp`], [`MoveDown`], [`SelectUp`], [`SelectDown`], the
+    /// commands of the diagnostics and of the cursors of a text area, and [`InsertNewline`], or if
+    /// it [edits](EditCommand::is_edit) a read-only input.
+    ///
+    /// The delete and kill commands delete the selected text instead when there is one.
+    ///
+    /// [`MoveUp`]: EditCommand::MoveUp
+    /// [`MoveDown`]: EditCommand::MoveDown
+    /// [`SelectUp`]: EditCommand::SelectUp
+    /// [`SelectDown`]: EditCommand::SelectDown
+    /// [`InsertNewline`]: EditCommand::InsertNewline
+    pub fn execute(&mut self, command: EditCommand) -> bool {
+        if self.read_only && command.is_edit() {
+            return false;
+        }
+        if !command.is_kill() {
+            self.killed = false;
+        }
+        match command {
+            EditCommand::MoveLeft => {
+                self.move_left();
+            }
+            EditCommand::MoveRight => {
+                self.move_right();
+            }
+            EditCommand::MoveHome => self.move_home(),
+            EditCommand::MoveEnd => self.move_end(),
+            EditCommand::MoveWordLeft => self.move_word_left(),
+            EditCommand::MoveWordRight => self.move_word_right(),
+            EditCommand::SelectLeft => self.extend_selection(|state| {
+                state.move_left();
+            }),
+            EditCommand::SelectRight => self.extend_selection(|state| {
+                state.move_right();
+            }),
+            EditCommand::SelectHome => self.extend_selection(Self::move_home),
+            EditCommand::SelectEnd => self.extend_selection(Self::move_end),
+            EditCommand::SelectWordLeft => self.extend_selection(Self::move_word_left),
+            EditCommand::SelectWordRight => self.extend_selection(Self::move_word_right),
+            EditCommand::DeleteBefore => {
+                self.delete_before();
+            }
+            EditCommand::DeleteAfter => {
+                self.delete_after();
+            }
+            EditCommand::DeleteWordBefore => {
+                self.delete_word_before();
+            }
+            EditCommand::DeleteWordAfter => {
+                self.delete_word_after();
+            }
+            EditCommand::DeleteToStart => {
+                self.delete_to_start();
+            }
+            EditCommand::DeleteToEnd => {
+                self.delete_to_end();
+            }
+            EditCommand::KillWordBefore => {
+                self.kill_word_before();
+            }
+            EditCommand::KillWordAfter => {
+                self.kill_word_after();
+            }
+            EditCommand::KillToStart => {
+                self.kill_to_start();
+            }
+            EditCommand::KillToEnd => {
+                self.kill_to_end();
+            }
+            EditCommand::Yank => {
+                self.yank();
+            }
+            EditCommand::Undo => {
+                self.undo();
+            }
+            EditCommand::Redo => {
+                self.redo();
+            }
+            EditCommand::MoveUp
+            | EditCommand::MoveDown
+            | EditCommand::SelectUp
+            | EditCommand::SelectDown
+            | EditCommand::NextDiagnostic
+            | EditCommand::PrevDiagnostic
+            | EditCommand::AddCursorAbove
+            | EditCommand::AddCursorBelow
+            | EditCommand::SelectNextOccurrence
+            | EditCommand::InsertNewline => return false,
+        }
+        true
+    }
+
+    /// Handles a key event with the given keymap. Returns `true` if the event was handled.
+    ///
+    /// Keys bound in the keymap are [executed](Self::execute), and the other characters typed
+    /// without `Ctrl` or `Alt` are inserted. Key release events are ignored, as well as all the
+    /// keys while the input is disabled and the edits while it is read-only.
+    ///
+    /// `Enter` and `Esc` don't change the input and return `false`, so that a parent widget or
+    /// the application still acts on them, but they are recorded for
+    /// [`take_event`](Self::take_event).
+    pub fn handle_key_with(&mut self, keymap: &Keymap, key: KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release || self.disabled {
+            return false;
+        }
+        if key.modifiers == KeyModifiers::NONE {
+            match key.key {
+                Key::Enter => self.outcome = Some(TextInputEvent::Submitted),
+                Key::Esc => self.outcome = Some(TextInputEvent::Cancelled),
+                _ => {}
+            }
+        }
+        if let Some(command) = keymap.command(&key) {
+            return self.execute(command);
+        }
+        match key.key {
+            Key::Char(c)
+                if !self.read_only
+                    && !key
+                        .modifiers
+                        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                let inserted = self.can_insert(c);
+                self.insert_char(c);
+                inserted
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the byte offset before the grapheme drawn at the given column during the last
+    /// render, or the end of the value after its last grapheme.
+    fn offset_at_column(&self, column: u16) -> usize {
+        let mask_width = self.mask_char.map(|c| c.width().unwrap_or(0));
+        let mut x = self.area.x;
+        for (i, grapheme) in self.value.grapheme_indices(true).skip(self.offset) {
+            x = x.saturating_add(mask_width.unwrap_or_else(|| grapheme_width(grapheme)) as u16);
+            if column < x {
+                return i;
+            }
+        }
+        self.value.len()
+    }
+
+    fn previous_boundary(&self) -> Option<usize> {
+        self.value[..self.cursor]
+            .grapheme_indices(true)
+            .next_back()
+            .map(|(i, _)| i)
+    }
+
+    fn next_boundary(&self) -> Option<usize> {
+        self.value[self.cursor..]
+            .graphemes(true)
+            .next()
+            .map(|g| self.cursor + g.len())
+    }
+}
+
+impl Focusable for TextInputState {
+    fn set_focused(&mut self, focused: bool) {
+        TextInputState::set_focused(self, focused);
+    }
+
+    fn is_disabled(&self) -> bool {
+        TextInputState::is_disabled(self)
+    }
+}
+
+impl Input for TextInputState {
+    /// Handles the key with the default [`Keymap`].
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        RedrawRequest::request_if(self.handle_key_with(Keymap::default_ref(), key))
+    }
+
+    fn handle_paste(&mut self, text: &str) -> bool {
+        if self.read_only || self.disabled {
+            return false;
+        }
+        self.insert_str(text);
+        RedrawRequest::request_if(true)
+    }
+
+    fn handle_preedit(&mut self, text: Option<&str>) -> bool {
+        if self.read_only || self.disabled {
+            return false;
+        }
+        self.set_preedit(text);
+        RedrawRequest::request_if(true)
+    }
+
+    /// Moves the cursor to the cell clicked with the left button, and selects the text up to
+    /// the cell where the mouse is dragged.
+    fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        if self.disabled {
+            return false;
+        }
+        let handled = match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) if self.area.contains(mouse.position()) => {
+                self.dragging = true;
+                self.set_cursor(self.offset_at_column(mouse.column));
+                true
+            }
+            MouseEventKind::Drag(MouseButton::Left) if self.dragging => {
+                let cursor = self.offset_at_column(mouse.column);
+                self.extend_selection(|state| state.set_cursor(cursor));
+                true
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                self.dragging = false;
+                false
+            }
+            _ => false,
+        };
+        RedrawRequest::request_if(handled)
+    }
+}
+
+impl CursorProvider for TextInputState {
+    fn cursor_position(&self) -> Option<Position> {
+        self.screen_cursor.filter(|_| self.focused)
+    }
+}
+
+impl<'a> StatefulWidget for TextInput<'a> {
+    type State = TextInputState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.apply_flags(state);
+        if let Some(validator) = &self.validator {
+            state.validate(validator.as_ref());
+        }
+        let invalid = self.validator.is_some() && !state.is_valid();
+        buf.set_style(area, self.style);
+        if self.disabled {
+            buf.set_style(area, self.disabled_style);
+        }
+        let has_block = self.block.is_some();
+        let area = match self.block.take() {
+            Some(block) if invalid => {
+                let block = block.border_style(self.invalid_style);
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        state.screen_cursor = None;
+        state.area = area;
+        state.mask_char = self.mask_char;
+        if area.is_empty() {
+            return;
+        }
+        if invalid && !has_block {
+            buf.set_style(area, self.invalid_style);
+        }
+        // a disabled input is rendered as if it didn't have the focus
+        let focused = state.focused && !state.disabled;
+        if !focused && state.value.is_empty() {
+            if let Some(mut placeholder) = self.placeholder {
+                for span in &mut placeholder.spans {
+                    span.style = self.placeholder_style.patch(span.style);
+                }
+                buf.set_line(area.x, area.y, &placeholder, area.width);
+            }
+            return;
+        }
+
+        let mask = self.mask_char.map(|c| c.to_string());
+        let mut graphemes = state
+            .value
+            .graphemes(true)
+            .map(|g| mask.as_deref().unwrap_or(g))
+            .collect::<Vec<_>>();
+        // the composed text is displayed before the cursor, as if it was already inserted
+        let preedit_start = state.cursor_grapheme_index();
+        let preedit = state
+            .preedit
+            .as_deref()
+            .unwrap_or_default()
+            .graphemes(true)
+            .map(|g| mask.as_deref().unwrap_or(g))
+            .collect::<Vec<_>>();
+        let preedit_len = preedit.len();
+        let cursor = preedit_start + preedit_len;
+        // the selection as a range of grapheme indices in the value
+        let selection = state.selection().map(|range| {
+            let index = |offset| state.value[..offset].graphemes(true).count();
+            index(range.start)..index(range.end)
+        });
+        graphemes.splice(preedit_start..preedit_start, preedit);
+        let width = usize::from(area.width);
+        let width_between = |from: usize, to: usize| -> usize {
+            graphemes[from..to].iter().map(|g| grapheme_width(g)).sum()
+        };
+        // scroll so that the cursor (and the cell it takes) is visible, then scroll back if
+        // there is room left after the end of the value
+        state.offset = state.offset.min(cursor);
+        while state.offset < cursor && width_between(state.offset, cursor) >= width {
+            state.offset += 1;
+        }
+        while state.offset > 0 && width_between(state.offset - 1, graphemes.len()) < width {
+            state.offset -= 1;
+        }
+
+        let mut x = area.x;
+        for (i, grapheme) in graphemes.iter().enumerate().skip(state.offset) {
+            if i == cursor {
+                state.screen_cursor = Some(Position::new(x, area.y));
+            }
+            let grapheme_width = grapheme_width(grapheme) as u16;
+            if x + grapheme_width > area.right() {
+                break;
+            }
+            buf.set_stringn(x, area.y, grapheme, grapheme_width.into(), Style::default());
+            let cell = Rect::new(x, area.y, grapheme_width, 1);
+            if (preedit_start..cursor).contains(&i) {
+                buf.set_style(cell, self.preedit_style);
+            } else if selection.as_ref().is_some_and(|selection| {
+                // the index in the value, after the composed text
+                let index = if i < preedit_start {
+                    i
+                } else {
+                    i - preedit_len
+                };
+                selection.contains(&index)
+            }) {
+                buf.set_style(cell, self.selection_style);
+            }
+            x += grapheme_width;
+        }
+        if cursor == graphemes.len() && x < area.right() {
+            state.screen_cursor = Some(Position::new(x, area.y));
+        }
+        if !focused {
+            state.screen_cursor = None;
+        }
+        if let Some(Position { x, y }) = state.screen_cursor {
+            buf.set_style(Rect::new(x, y, 1, 1), self.cursor_style);
+        }
+    }
+}
+
+impl<'a> Widget for TextInput<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = TextInputState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_buffer_eq, widgets::Borders};
+
+    #[test]
+    fn insert_and_delete() {
+        let mut state = TextInputState::default();
+        state.insert_str("hllo");
+        state.move_home();
+        assert!(state.move_right());
+        state.insert_char('e');
+        assert_eq!(state.value(), "hello");
+        assert_eq!(state.cursor(), 2);
+        assert!(state.delete_before());
+        assert!(state.delete_after());
+        assert_eq!(state.value(), "hlo");
+        state.move_end();
+        assert!(!state.delete_after());
+        assert!(!state.move_right());
+        state.move_home();
+        assert!(!state.delete_before());
+        assert!(!state.move_left());
+    }
+
+    #[test]
+    fn line_breaks_are_removed() {
+        let mut state = TextInputState::new("a\nb");
+        state.insert_char('\n');
+        state.insert_str("c\r\nd");
+        assert_eq!(state.value(), "abcd");
+    }
+
+    #[test]
+    fn graphemes_are_not_split() {
+        // "e" + combining acute accent, and a family emoji made of several code points
+        let mut state = TextInputState::new("e\u{301}👨‍👩‍👧");
+        assert!(state.delete_before());
+        assert_eq!(state.value(), "e\u{301}");
+        assert!(state.move_left());
+        assert_eq!(state.cursor(), 0);
+        assert!(state.move_right());
+        assert_eq!(state.cursor(), 3);
+        state.move_home();
+        assert!(state.delete_after());
+        assert_eq!(state.value(), "");
+    }
+
+    #[cfg(feature = "emoji")]
+    #[test]
+    fn typed_emoji_sequence_is_one_unit() {
+        let mut state = TextInputState::default();
+        for c in "👩\u{200D}💻❤\u{FE0F}".chars() {
+            state.insert_char(c);
+        }
+        assert_eq!(state.cursor_grapheme_index(), 2);
+        assert_eq!(state.visual_cursor_column(), 4);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        StatefulWidget::render(
+            TextInput::new().cursor_style(Style::default()),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["👩\u{200D}💻❤\u{FE0F}  "]));
+        assert!(state.delete_before());
+        assert_eq!(state.value(), "👩\u{200D}💻");
+    }
+
+    #[test]
+    fn grapheme_cursor_accessors() {
+        let mut state = TextInputState::new("a中e\u{301}b");
+        assert_eq!(state.cursor(), 8);
+        assert_eq!(state.cursor_grapheme_index(), 4);
+        assert_eq!(state.visual_cursor_column(), 5);
+        state.set_cursor_grapheme_index(2);
+        assert_eq!(state.cursor(), 4);
+        assert_eq!(state.visual_cursor_column(), 3);
+        assert!(state.delete_before());
+        assert_eq!(state.value(), "ae\u{301}b");
+        assert_eq!(state.visual_cursor_column(), 1);
+        state.set_cursor_grapheme_index(10);
+        assert_eq!(state.cursor(), 5);
+    }
+
+    #[test]
+    fn combining_character_joins_previous_grapheme() {
+        let mut state = TextInputState::new("e");
+        state.insert_char('\u{301}');
+        assert!(state.move_left());
+        assert_eq!(state.cursor(), 0);
+    }
+
+    #[test]
+    fn set_cursor_snaps_to_grapheme_boundary() {
+        let mut state = TextInputState::new("ae\u{301}b");
+        state.set_cursor(2);
+        assert_eq!(state.cursor(), 1);
+        state.set_cursor(100);
+        assert_eq!(state.cursor(), 5);
+    }
+
+    #[test]
+    fn undo_redo() {
+        let mut state = TextInputState::new("ab");
+        assert!(!state.undo());
+        state.insert_char('c');
+        state.insert_char('d');
+        state.move_left();
+        state.insert_char('x');
+        state.delete_before();
+        assert_eq!(state.value(), "abcd");
+        assert!(state.undo());
+        assert_eq!((state.value(), state.cursor()), ("abcxd", 4));
+        assert!(state.undo());
+        assert_eq!((state.value(), state.cursor()), ("abcd", 3));
+        assert!(state.undo());
+        assert_eq!((state.value(), state.cursor()), ("ab", 2));
+        assert!(!state.undo());
+        assert!(state.redo());
+        assert!(state.redo());
+        assert_eq!(state.value(), "abcxd");
+        state.clear();
+        assert!(!state.redo());
+        assert!(state.undo());
+        assert_eq!(state.value(), "abcxd");
+    }
+
+    #[test]
+    fn max_length_and_filter() {
+        let mut state = TextInputState::default()
+            .with_max_length(3)
+            .with_filter(|c| !c.is_whitespace());
+        assert_eq!(state.max_length(), Some(3));
+        assert!(state.handle_key(Key::Char('a').into()));
+        assert!(!state.handle_key(Key::Char(' ').into()));
+        state.insert_str("b c d");
+        assert_eq!(state.value(), "abc");
+        assert!(!state.handle_key(Key::Char('d').into()));
+        state.insert_str("d");
+        assert_eq!(state.value(), "abc");
+
+        // the selected text makes room for its replacement
+        state.execute(EditCommand::SelectLeft);
+        state.execute(EditCommand::SelectLeft);
+        state.insert_str("éf🙂");
+        assert_eq!(state.value(), "aéf");
+        state.set_max_length(None);
+        state.set_filter(None);
+        state.insert_str(" g");
+        assert_eq!(state.value(), "aéf g");
+    }
+
+    #[test]
+    fn history_depth() {
+        let mut state = TextInputState::default().with_history_depth(1);
+        state.insert_str("a");
+        state.insert_str("b");
+        assert!(state.undo());
+        assert!(!state.undo());
+        assert_eq!(state.value(), "a");
+    }
+
+    #[test]
+    fn take_event() {
+        let mut state = TextInputState::new("ab");
+        assert_eq!(state.take_event(), None);
+        state.handle_key(Key::Left.into());
+        state.clear();
+        assert_eq!(state.take_event(), Some(TextInputEvent::Changed));
+        state.clear();
+        assert_eq!(state.take_event(), None);
+
+        assert!(!state.handle_key(Key::Enter.into()));
+        assert!(!state.handle_key(Key::Esc.into()));
+        assert!(!state.handle_key(KeyEvent::new(Key::Enter, KeyModifiers::ALT)));
+        assert_eq!(state.take_event(), Some(TextInputEvent::Cancelled));
+        assert_eq!(state.take_event(), None);
+
+        state.handle_paste("cd");
+        state.handle_key(Key::Enter.into());
+        assert!(state.undo());
+        assert_eq!(state.take_event(), Some(TextInputEvent::Changed));
+        assert_eq!(state.take_event(), Some(TextInputEvent::Submitted));
+        state.set_value("ef");
+        assert_eq!(state.take_event(), None);
+    }
+
+    #[test]
+    fn handle_key() {
+        let mut state = TextInputState::default();
+        for c in "abc".chars() {
+            assert!(state.handle_key(Key::Char(c).into()));
+        }
+        assert!(state.handle_key(Key::Left.into()));
+        assert!(state.handle_key(Key::Backspace.into()));
+        assert!(state.handle_key(KeyEvent::new(Key::Char('X'), KeyModifiers::SHIFT)));
+        assert!(!state.handle_key(KeyEvent::new(Key::Char('x'), KeyModifiers::CONTROL)));
+        assert!(!state.handle_key(KeyEvent::from(Key::Char('y')).kind(KeyEventKind::Release)));
+        assert!(!state.handle_key(Key::Enter.into()));
+        assert!(state.handle_key(Key::Home.into()));
+        assert!(state.handle_key(Key::Delete.into()));
+        assert_eq!(state.value(), "Xc");
+        assert!(state.handle_event(&crate::input::Event::Paste("d\ne".to_string())));
+        assert_eq!(state.value(), "deXc");
+    }
+
+    #[test]
+    fn read_only_and_disabled() {
+        let mut state = TextInputState::new("abc");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        StatefulWidget::render(
+            TextInput::new().read_only(true),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        assert!(state.is_read_only());
+        assert!(!state.handle_key(Key::Char('x').into()));
+        assert!(!state.handle_key(Key::Backspace.into()));
+        assert!(!state.handle_paste("x"));
+        assert!(state.handle_key(KeyEvent::new(Key::Left, KeyModifiers::SHIFT)));
+        assert_eq!(state.value(), "abc");
+        assert_eq!(state.selected_text(), Some("c"));
+
+        StatefulWidget::render(
+            TextInput::new().disabled(true),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        assert!(state.is_disabled() && !state.is_read_only());
+        assert!(!state.handle_key(Key::Left.into()));
+        assert!(!state.handle_paste("x"));
+        assert_eq!(state.cursor(), 2);
+    }
+
+    #[test]
+    fn handle_mouse() {
+        let mut state = TextInputState::new("hello world");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+        let input = TextInput::new().block(Block::default().borders(Borders::ALL));
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        // the value is scrolled to show the cursor at its end: "world" is visible
+        let left = MouseButton::Left;
+        assert!(state.handle_mouse(MouseEvent::new(MouseEventKind::Down(left), 1, 1)));
+        assert_eq!(state.cursor(), 6);
+        assert!(state.handle_mouse(MouseEvent::new(MouseEventKind::Drag(left), 20, 1)));
+        assert_eq!(state.selected_text(), Some("world"));
+        assert!(!state.handle_mouse(MouseEvent::new(MouseEventKind::Up(left), 20, 1)));
+        assert!(!state.handle_mouse(MouseEvent::new(MouseEventKind::Drag(left), 2, 1)));
+        assert!(!state.handle_mouse(MouseEvent::new(MouseEventKind::Down(left), 0, 0)));
+        assert_eq!(state.selected_text(), Some("world"));
+    }
+
+    #[test]
+    fn emacs_keys() {
+        let ctrl = |c| KeyEvent::new(Key::Char(c), KeyModifiers::CONTROL);
+        let alt = |c| KeyEvent::new(Key::Char(c), KeyModifiers::ALT);
+        let mut state = TextInputState::new("foo bar baz");
+        assert!(state.handle_key(ctrl('w')));
+        assert_eq!(state.value(), "foo bar ");
+        assert!(state.handle_key(alt('b')));
+        assert_eq!(state.cursor(), 4);
+        assert!(state.handle_key(alt('f')));
+        assert_eq!(state.cursor(), 7);
+        assert!(state.handle_key(ctrl('a')));
+        assert!(state.handle_key(alt('d')));
+        assert_eq!(state.value(), " bar ");
+        assert!(state.handle_key(ctrl('e')));
+        assert!(state.handle_key(ctrl('b')));
+        assert!(state.handle_key(ctrl('k')));
+        assert_eq!(state.value(), " bar");
+        assert!(state.handle_key(ctrl('u')));
+        assert_eq!(state.value(), "");
+        assert!(state.handle_key(ctrl('z')));
+        assert_eq!(state.value(), " bar");
+    }
+
+    #[test]
+    fn kill_and_yank() {
+        let ctrl = |c| KeyEvent::new(Key::Char(c), KeyModifiers::CONTROL);
+        KillRing::clear();
+        let mut state = TextInputState::new("foo bar baz");
+        assert!(!state.yank());
+        // consecutive kills are yanked back at once
+        assert!(state.handle_key(ctrl('w')));
+        assert!(state.handle_key(ctrl('w')));
+        assert_eq!(state.value(), "foo ");
+        assert_eq!(KillRing::yank().as_deref(), Some("bar baz"));
+        assert!(state.handle_key(ctrl('y')));
+        assert_eq!(state.value(), "foo bar baz");
+
+        // a motion starts a new entry
+        assert!(state.handle_key(ctrl('a')));
+        assert!(state.handle_key(ctrl('k')));
+        assert_eq!(state.value(), "");
+        assert_eq!(KillRing::len(), 2);
+        assert!(state.handle_key(ctrl('y')));
+        assert!(state.handle_key(ctrl('y')));
+        assert_eq!(state.value(), "foo bar bazfoo bar baz");
+
+        // the selection is killed instead, and nothing is killed by the delete commands
+        state.select_all();
+        assert!(state.execute(EditCommand::KillToStart));
+        assert_eq!(state.value(), "");
+        assert_eq!(KillRing::len(), 3);
+        state.insert_str("abc");
+        assert!(state.execute(EditCommand::DeleteToStart));
+        assert_eq!(KillRing::len(), 3);
+
+        // a read-only input still doesn't change
+        state.read_only = true;
+        assert!(!state.execute(EditCommand::Yank));
+        assert_eq!(state.value(), "");
+    }
+
+    #[test]
+    fn shift_arrows_select() {
+        let shift = |key| KeyEvent::new(key, KeyModifiers::SHIFT);
+        let mut state = TextInputState::new("hello world");
+        assert!(state.handle_key(shift(Key::Left)));
+        assert!(state.handle_key(KeyEvent::new(
+            Key::Left,
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT
+        )));
+        assert_eq!(state.selection(), Some(6..11));
+        assert_eq!(state.selected_text(), Some("world"));
+        assert!(!state.handle_key(shift(Key::Up)));
+
+        // moving without shift unselects
+        assert!(state.handle_key(Key::Right.into()));
+        assert_eq!(state.selection(), None);
+        assert!(state.handle_key(shift(Key::Home)));
+        assert_eq!(state.selected_text(), Some("hello w"));
+    }
+
+    #[test]
+    fn edits_replace_selection() {
+        let mut state = TextInputState::new("hello world");
+        state.set_cursor(6);
+        state.execute(EditCommand::SelectEnd);
+        state.insert_char('t');
+        state.insert_char('o');
+        assert_eq!(state.value(), "hello to");
+        assert_eq!(state.selection(), None);
+        assert!(state.undo());
+        assert_eq!(state.value(), "hello world");
+
+        state.select_all();
+        assert!(state.delete_word_before());
+        assert_eq!(state.value(), "");
+        assert!(state.undo());
+
+        state.set_cursor(0);
+        state.execute(EditCommand::SelectWordRight);
+        assert_eq!(state.take_selection().as_deref(), Some("hello"));
+        assert_eq!((state.value(), state.cursor()), (" world", 0));
+        assert_eq!(state.take_selection(), None);
+        state.execute(EditCommand::SelectRight);
+        state.insert_str("big ");
+        assert_eq!(state.value(), "big world");
+        state.execute(EditCommand::SelectLeft);
+        state.clear_selection();
+        assert!(!state.delete_selection());
+    }
+
+    #[test]
+    fn render_selection() {
+        let mut state = TextInputState::new("abcd");
+        state.set_cursor(1);
+        state.execute(EditCommand::SelectRight);
+        state.execute(EditCommand::SelectRight);
+        let input = TextInput::new()
+            .cursor_style(Style::default())
+            .selection_style(Style::new().bg(Color::Blue));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["abcd  "]);
+        expected.set_style(Rect::new(1, 0, 2, 1), Style::new().bg(Color::Blue));
+        assert_buffer_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render() {
+        let mut state = TextInputState::new("hello");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        StatefulWidget::render(TextInput::new(), buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["hello     "]);
+        expected.set_style(Rect::new(5, 0, 1, 1), DEFAULT_CURSOR_STYLE);
+        assert_buffer_eq!(buf, expected);
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(5, 0)));
+    }
+
+    #[test]
+    fn render_with_block() {
+        let mut state = TextInputState::new("hi");
+        state.move_home();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 3));
+        let input = TextInput::new().block(Block::default().borders(Borders::ALL));
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["┌────┐", "│hi  │", "└────┘"]);
+        expected.set_style(Rect::new(1, 1, 1, 1), DEFAULT_CURSOR_STYLE);
+        assert_buffer_eq!(buf, expected);
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(1, 1)));
+    }
+
+    #[test]
+    fn render_scrolls_horizontally() {
+        let mut state = TextInputState::new("0123456789");
+        let input = TextInput::new().cursor_style(Style::default());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        StatefulWidget::render(input.clone(), buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["6789 "]));
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(4, 0)));
+
+        // moving the cursor inside the visible part doesn't scroll
+        state.set_cursor(7);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        StatefulWidget::render(input.clone(), buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["6789 "]));
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(1, 0)));
+
+        // moving the cursor before the visible part scrolls back
+        state.set_cursor(2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["23456"]));
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(0, 0)));
+    }
+
+    #[test]
+    fn render_masked() {
+        let mut state = TextInputState::new("sécret");
+        state.set_cursor(1);
+        let input = TextInput::new()
+            .mask_char('*')
+            .cursor_style(Style::default());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["******  "]));
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(1, 0)));
+        assert!(state.move_right());
+        assert_eq!(state.cursor(), 3);
+        assert_eq!(state.value(), "sécret");
+    }
+
+    #[test]
+    fn validate() {
+        let mut state = TextInputState::new("12a");
+        assert!(!state.validate(&crate::input::Integer));
+        assert_eq!(state.error(), Some("must be an integer"));
+        state.delete_before();
+        assert!(state.validate(&crate::input::Integer));
+        assert_eq!(state.error(), None);
+    }
+
+    #[test]
+    fn render_invalid() {
+        let mut state = TextInputState::new("");
+        let input = TextInput::new()
+            .cursor_style(Style::default())
+            .validator(crate::input::NonEmpty)
+            .block(Block::default().borders(Borders::ALL));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        StatefulWidget::render(input.clone(), buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["┌──┐", "│  │", "└──┘"]);
+        expected.set_style(buf.area, DEFAULT_INVALID_STYLE);
+        expected.set_style(Rect::new(1, 1, 2, 1), Style::reset());
+        assert_buffer_eq!(buf, expected);
+        assert_eq!(state.error(), Some("must not be empty"));
+
+        state.insert_char('a');
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["┌──┐", "│a │", "└──┘"]));
+        assert!(state.is_valid());
+    }
+
+    #[test]
+    fn render_wide_characters() {
+        let mut state = TextInputState::new("日本語");
+        let input = TextInput::new().cursor_style(Style::default());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["本語 "]));
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(4, 0)));
+    }
+
+    #[test]
+    fn render_placeholder() {
+        let mut state = TextInputState::default();
+        state.set_focused(false);
+        let input = TextInput::new().placeholder("name");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        StatefulWidget::render(input.clone(), buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["name  "]);
+        expected.set_style(Rect::new(0, 0, 4, 1), DEFAULT_PLACEHOLDER_STYLE);
+        assert_buffer_eq!(buf, expected);
+        assert_eq!(state.cursor_screen_position(), None);
+
+        // the placeholder is hidden with the focus, or as soon as there is a value
+        state.set_focused(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        StatefulWidget::render(input.clone(), buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["      "]);
+        expected.set_style(Rect::new(0, 0, 1, 1), DEFAULT_CURSOR_STYLE);
+        assert_buffer_eq!(buf, expected);
+
+        state.insert_char('a');
+        state.set_focused(false);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["a     "]));
+        assert_eq!(state.cursor_screen_position(), None);
+    }
+
+    #[test]
+    fn render_disabled() {
+        let mut state = TextInputState::new("ab");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        StatefulWidget::render(
+            TextInput::new().disabled(true),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        let mut expected = Buffer::with_lines(vec!["ab  "]);
+        expected.set_style(buf.area, DEFAULT_DISABLED_STYLE);
+        assert_buffer_eq!(buf, expected);
+        assert_eq!(state.cursor_screen_position(), None);
+        assert!(state.is_focused());
+    }
+
+    #[test]
+    fn render_preedit() {
+        let mut state = TextInputState::new("ab");
+        state.set_cursor(1);
+        assert!(state.handle_preedit(Some("にほ")));
+        let input = TextInput::new().cursor_style(Style::default());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        StatefulWidget::render(input.clone(), buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["aにほb  "]);
+        expected.set_style(Rect::new(1, 0, 4, 1), DEFAULT_PREEDIT_STYLE);
+        assert_buffer_eq!(buf, expected);
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(5, 0)));
+        assert_eq!(state.value(), "ab");
+
+        // committing the composition is a single edit
+        state.set_preedit(None::<&str>);
+        state.insert_str("日本");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        StatefulWidget::render(input, buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(buf, Buffer::with_lines(vec!["a日本b  "]));
+        assert_eq!(state.cursor_screen_position(), Some(Position::new(5, 0)));
+        assert!(state.undo());
+        assert_eq!(state.value(), "ab");
+    }
+}
@@ -0,0 +1,704 @@
+#![deny(missing_docs)]
+use std::{borrow::Cow, fmt, rc::Rc};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    buffer::Buffer,
+    input::{Key, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind},
+    layout::{Position, Rect},
+    style::{Color, Modifier, Style, Styled},
+    widgets::{Block, StatefulWidget, Widget},
+};
+
+/// The colors used for the nodes when no [`color`](TreeMap::color) callback is set.
+const PALETTE: [Color; 6] = [
+    Color::Cyan,
+    Color::Green,
+    Color::Yellow,
+    Color::Magenta,
+    Color::Blue,
+    Color::Red,
+];
+
+/// A node of the hierarchy displayed by a [`TreeMap`].
+///
+/// A leaf has its own weight, e.g. the size of a file, and a parent weighs the sum of its
+/// children. The optional value is not used for the layout and is meant to drive the color of
+/// the node, e.g. the CPU usage of a process.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::*;
+///
+/// let root = TreeMapNode::parent(
+///     "src",
+///     vec![
+///         TreeMapNode::new("main.rs", 120.0),
+///         TreeMapNode::new("lib.rs", 300.0).value(0.8),
+///     ],
+/// );
+/// assert_eq!(root.weight(), 420.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeMapNode<'a> {
+    label: Cow<'a, str>,
+    weight: f64,
+    value: Option<f64>,
+    children: Vec<TreeMapNode<'a>>,
+}
+
+impl<'a> TreeMapNode<'a> {
+    /// Creates a new leaf with the given weight. Negative weights are treated as zero.
+    pub fn new<T>(label: T, weight: f64) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Self {
+            label: label.into(),
+            weight: weight.max(0.0),
+            value: None,
+            children: vec![],
+        }
+    }
+
+    /// Creates a new parent node weighing the sum of its children.
+    pub fn parent<T>(label: T, children: Vec<TreeMapNode<'a>>) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Self {
+            label: label.into(),
+            weight: children.iter().map(TreeMapNode::weight).sum(),
+            value: None,
+            children,
+        }
+    }
+
+    /// Sets the value of the node, passed to the [`color`](TreeMap::color) callback.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn value(mut self, value: f64) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Returns the label of the node.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the weight of the node.
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    /// Returns the value of the node, if set.
+    pub fn get_value(&self) -> Option<f64> {
+        self.value
+    }
+
+    /// Returns the children of the node.
+    pub fn children(&self) -> &[TreeMapNode<'a>] {
+        &self.children
+    }
+
+    /// Returns the node at the given path of child indices, starting from this node.
+    pub fn get(&self, path: &[usize]) -> Option<&TreeMapNode<'a>> {
+        path.iter()
+            .try_fold(self, |node, &index| node.children.get(index))
+    }
+}
+
+/// The callback computing the color of a node.
+type ColorFn<'a> = Rc<dyn Fn(&TreeMapNode<'a>) -> Color + 'a>;
+
+/// A widget displaying weighted hierarchical data as nested rectangles, e.g. the disk usage of a
+/// directory or the time spent in functions by a profiled program.
+///
+/// The area of each rectangle is proportional to the weight of its node. The children of the
+/// root fill the whole area, and each parent tall enough shows its label on its first row and
+/// its children below it. The rectangles are laid out with the squarified algorithm, so that
+/// they stay as close as possible to squares on screen. Labels that don't fit in their rectangle
+/// are elided with `…`.
+///
+/// The background of each rectangle is given by the [`color`](TreeMap::color) callback, which
+/// defaults to alternating colors. The [`TreeMapState`] keeps the selected node, which can be
+/// changed with the arrow keys or by clicking on a rectangle.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, root: &TreeMapNode, state: &mut TreeMapState) {
+/// let tree_map = TreeMap::new(root)
+///     .block(Block::default().title("Disk usage").borders(Borders::ALL))
+///     .color(|node| match node.get_value() {
+///         Some(v) if v > 0.5 => Color::Red,
+///         _ => Color::Green,
+///     });
+/// frame.render_stateful_widget(tree_map, frame.size(), state);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TreeMap<'a> {
+    root: &'a TreeMapNode<'a>,
+    block: Option<Block<'a>>,
+    style: Style,
+    highlight_style: Style,
+    color: Option<ColorFn<'a>>,
+}
+
+impl<'a> fmt::Debug for TreeMap<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TreeMap")
+            .field("root", &self.root)
+            .field("block", &self.block)
+            .field("style", &self.style)
+            .field("highlight_style", &self.highlight_style)
+            .field("color", &self.color.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+impl<'a> TreeMap<'a> {
+    /// Creates a new tree map displaying the children of the given root node.
+    pub fn new(root: &'a TreeMapNode<'a>) -> Self {
+        Self {
+            root,
+            block: None,
+            style: Style::new().fg(Color::Black),
+            highlight_style: Style::new().add_modifier(Modifier::REVERSED),
+            color: None,
+        }
+    }
+
+    /// Surrounds the tree map with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the widget, on top of which the color of each node is applied.
+    /// Defaults to black text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style patched on the rectangle of the selected node. Defaults to reversed
+    /// colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// Sets the callback computing the background color of each node, e.g. from its
+    /// [value](TreeMapNode::value).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn color<F>(mut self, color: F) -> Self
+    where
+        F: Fn(&TreeMapNode<'a>) -> Color + 'a,
+    {
+        self.color = Some(Rc::new(color));
+        self
+    }
+
+    fn node_color(&self, node: &TreeMapNode<'a>, path: &[usize]) -> Color {
+        match &self.color {
+            Some(color) => color(node),
+            // adding the depth makes the first child differ from its parent
+            None => PALETTE[(path.iter().sum::<usize>() + path.len()) % PALETTE.len()],
+        }
+    }
+
+    fn render_node(
+        &self,
+        node: &TreeMapNode<'a>,
+        path: &mut Vec<usize>,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut TreeMapState,
+    ) {
+        let style = self.style.bg(self.node_color(node, path));
+        buf.set_style(area, style);
+        let label = elide(&node.label, area.width);
+        buf.set_string(area.x, area.y, label, style);
+        let subdivided = !node.children.is_empty() && area.height >= 2;
+        state.areas.push(NodeArea {
+            path: path.clone(),
+            area,
+            subdivided,
+        });
+        if subdivided {
+            let inner = Rect::new(area.x, area.y + 1, area.width, area.height - 1);
+            self.render_children(node, path, inner, buf, state);
+        }
+    }
+
+    fn render_children(
+        &self,
+        node: &TreeMapNode<'a>,
+        path: &mut Vec<usize>,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut TreeMapState,
+    ) {
+        let weights = node
+            .children
+            .iter()
+            .map(TreeMapNode::weight)
+            .collect::<Vec<_>>();
+        for (i, rect) in squarify(&weights, area).into_iter().enumerate() {
+            if rect.is_empty() {
+                continue;
+            }
+            path.push(i);
+            self.render_node(&node.children[i], path, rect, buf, state);
+            path.pop();
+        }
+    }
+}
+
+impl<'a> Styled for TreeMap<'a> {
+    type Item = TreeMap<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// The area where a node was last rendered.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct NodeArea {
+    path: Vec<usize>,
+    area: Rect,
+    /// Whether the children of the node were rendered in its area.
+    subdivided: bool,
+}
+
+/// The state of a [`TreeMap`]: the selected node and the areas where the nodes were last
+/// rendered, used for hit-testing.
+///
+/// Nodes are identified by their path, i.e. the indices of the children to follow from the root.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TreeMapState {
+    selected: Option<Vec<usize>>,
+    areas: Vec<NodeArea>,
+}
+
+impl TreeMapState {
+    /// Returns the path of the selected node.
+    pub fn selected(&self) -> Option<&[usize]> {
+        self.selected.as_deref()
+    }
+
+    /// Selects the node at the given path, or removes the selection with `None`.
+    pub fn select(&mut self, path: Option<Vec<usize>>) {
+        self.selected = path;
+    }
+
+    /// Selects the parent of the selected node. Returns `true` if the selection changed.
+    pub fn select_parent(&mut self) -> bool {
+        match &mut self.selected {
+            Some(path) if path.len() > 1 => {
+                path.pop();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the path of the deepest node rendered at the given position, if any.
+    pub fn node_at(&self, column: u16, row: u16) -> Option<&[usize]> {
+        self.areas
+            .iter()
+            .rev()
+            .find(|node| node.area.contains(Position::new(column, row)))
+            .map(|node| node.path.as_slice())
+    }
+
+    /// Returns the area where the node at the given path was last rendered, if any.
+    pub fn area_of(&self, path: &[usize]) -> Option<Rect> {
+        self.areas
+            .iter()
+            .find(|node| node.path == path)
+            .map(|node| node.area)
+    }
+
+    /// Handles a key event and returns `true` if the selection changed.
+    ///
+    /// The arrow keys select the nearest rectangle in their direction, and `Backspace` selects
+    /// the parent of the selected node. When nothing is selected, any arrow selects the first
+    /// rectangle.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release {
+            return false;
+        }
+        match key.key {
+            Key::Left => self.select_towards(-1, 0),
+            Key::Right => self.select_towards(1, 0),
+            Key::Up => self.select_towards(0, -1),
+            Key::Down => self.select_towards(0, 1),
+            Key::Backspace => self.select_parent(),
+            _ => false,
+        }
+    }
+
+    /// Handles a mouse event and returns `true` if the selection changed.
+    ///
+    /// Clicking with the left button selects the deepest node under the mouse.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> bool {
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return false;
+        }
+        let Some(path) = self.node_at(mouse.column, mouse.row).map(<[usize]>::to_vec) else {
+            return false;
+        };
+        let changed = self.selected.as_ref() != Some(&path);
+        self.selected = Some(path);
+        changed
+    }
+
+    /// Selects the nearest leaf rectangle in the given direction from the selected one.
+    fn select_towards(&mut self, dx: i32, dy: i32) -> bool {
+        let mut leaves = self.areas.iter().filter(|node| !node.subdivided);
+        let Some(current) = self.selected.as_deref().and_then(|path| self.area_of(path)) else {
+            self.selected = leaves.next().map(|node| node.path.clone());
+            return self.selected.is_some();
+        };
+        let center = |rect: Rect| {
+            (
+                i32::from(rect.x) * 2 + i32::from(rect.width),
+                i32::from(rect.y) * 2 + i32::from(rect.height),
+            )
+        };
+        let (cx, cy) = center(current);
+        let next = leaves
+            .filter(|node| match (dx, dy) {
+                (-1, _) => node.area.right() <= current.left(),
+                (1, _) => node.area.left() >= current.right(),
+                (_, -1) => node.area.bottom() <= current.top(),
+                _ => node.area.top() >= current.bottom(),
+            })
+            .min_by_key(|node| {
+                let (x, y) = center(node.area);
+                // prefer the rectangles aligned with the current one
+                let (along, across) = if dx != 0 {
+                    ((x - cx).abs(), (y - cy).abs())
+                } else {
+                    ((y - cy).abs(), (x - cx).abs())
+                };
+                along + 2 * across
+            });
+        match next {
+            Some(node) => {
+                self.selected = Some(node.path.clone());
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<'a> StatefulWidget for TreeMap<'a> {
+    type State = TreeMapState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        state.areas.clear();
+        if area.is_empty() {
+            return;
+        }
+        self.render_children(self.root, &mut vec![], area, buf, state);
+        if let Some(selected) = state.selected().and_then(|path| state.area_of(path)) {
+            buf.set_style(selected, self.highlight_style);
+        }
+    }
+}
+
+impl<'a> Widget for TreeMap<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = TreeMapState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+/// Truncates the label to the given width, replacing its end with `…` if it doesn't fit.
+fn elide(label: &str, width: u16) -> Cow<'_, str> {
+    let width = usize::from(width);
+    if label.width() <= width {
+        return Cow::Borrowed(label);
+    }
+    if width == 0 {
+        return Cow::Borrowed("");
+    }
+    let mut elided = String::new();
+    let mut used = 0;
+    for grapheme in label.graphemes(true) {
+        used += grapheme.width();
+        if used >= width {
+            break;
+        }
+        elided.push_str(grapheme);
+    }
+    elided.push('…');
+    Cow::Owned(elided)
+}
+
+/// A rectangle with fractional coordinates, used during the layout.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct Area {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+/// Splits the area in rectangles with areas proportional to the given weights, using the
+/// squarified treemap algorithm.
+///
+/// The rectangles are returned in the order of the weights. Nodes without weight get an empty
+/// rectangle.
+fn squarify(weights: &[f64], area: Rect) -> Vec<Rect> {
+    let mut rects = vec![Rect::new(area.x, area.y, 0, 0); weights.len()];
+    let total = weights.iter().filter(|w| **w > 0.0).sum::<f64>();
+    if total <= 0.0 || area.is_empty() {
+        return rects;
+    }
+    // cells are about twice as high as wide: lay out in a space where they are square
+    let mut rest = Area {
+        x: 0.0,
+        y: 0.0,
+        width: f64::from(area.width) / 2.0,
+        height: f64::from(area.height),
+    };
+    let scale = rest.width * rest.height / total;
+    let mut order = (0..weights.len())
+        .filter(|&i| weights[i] > 0.0)
+        .collect::<Vec<_>>();
+    order.sort_by(|&a, &b| weights[b].total_cmp(&weights[a]));
+    let sizes = order
+        .iter()
+        .map(|&i| weights[i] * scale)
+        .collect::<Vec<_>>();
+
+    let mut placed = vec![Area::default(); weights.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let side = rest.width.min(rest.height);
+        let mut end = start + 1;
+        let mut worst = worst_ratio(&sizes[start..end], side);
+        while end < order.len() {
+            let ratio = worst_ratio(&sizes[start..=end], side);
+            if ratio > worst {
+                break;
+            }
+            worst = ratio;
+            end += 1;
+        }
+        let row_size = sizes[start..end].iter().sum::<f64>();
+        if rest.width >= rest.height {
+            // a column on the left of the remaining area
+            let width = row_size / rest.height;
+            let mut y = rest.y;
+            for (&i, size) in order[start..end].iter().zip(&sizes[start..end]) {
+                let height = size / width;
+                placed[i] = Area {
+                    x: rest.x,
+                    y,
+                    width,
+                    height,
+                };
+                y += height;
+            }
+            rest.x += width;
+            rest.width -= width;
+        } else {
+            // a row on the top of the remaining area
+            let height = row_size / rest.width;
+            let mut x = rest.x;
+            for (&i, size) in order[start..end].iter().zip(&sizes[start..end]) {
+                let width = size / height;
+                placed[i] = Area {
+                    x,
+                    y: rest.y,
+                    width,
+                    height,
+                };
+                x += width;
+            }
+            rest.y += height;
+            rest.height -= height;
+        }
+        start = end;
+    }
+
+    // round the edges rather than the sizes, so that the rectangles tile the area
+    let to_cells = |value: f64, max: u16| (value.round() as u16).min(max);
+    for &i in &order {
+        let placed = placed[i];
+        let left = to_cells(placed.x * 2.0, area.width);
+        let right = to_cells((placed.x + placed.width) * 2.0, area.width);
+        let top = to_cells(placed.y, area.height);
+        let bottom = to_cells(placed.y + placed.height, area.height);
+        rects[i] = Rect::new(
+            area.x + left,
+            area.y + top,
+            right.saturating_sub(left),
+            bottom.saturating_sub(top),
+        );
+    }
+    rects
+}
+
+/// Returns the worst aspect ratio of the rectangles of the given sizes laid out in a row along a
+/// side of the given length.
+fn worst_ratio(sizes: &[f64], side: f64) -> f64 {
+    let sum = sizes.iter().sum::<f64>();
+    let (min, max) = sizes
+        .iter()
+        .fold((f64::INFINITY, 0.0_f64), |(min, max), &size| {
+            (min.min(size), max.max(size))
+        });
+    let side = side * side;
+    let sum = sum * sum;
+    (side * max / sum).max(sum / (side * min))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    fn files() -> TreeMapNode<'static> {
+        TreeMapNode::parent(
+            "/",
+            vec![
+                TreeMapNode::new("a", 2.0),
+                TreeMapNode::parent(
+                    "src",
+                    vec![TreeMapNode::new("b", 1.0), TreeMapNode::new("c", 1.0)],
+                ),
+            ],
+        )
+    }
+
+    #[test]
+    fn node_weight_and_path() {
+        let root = files();
+        assert_eq!(root.weight(), 4.0);
+        assert_eq!(root.get(&[1, 0]).map(TreeMapNode::label), Some("b"));
+        assert_eq!(root.get(&[2]), None);
+    }
+
+    #[test]
+    fn squarify_tiles_area() {
+        let area = Rect::new(1, 1, 8, 4);
+        let rects = squarify(&[3.0, 0.0, 1.0], area);
+        assert_eq!(rects[0], Rect::new(1, 1, 6, 4));
+        assert!(rects[1].is_empty());
+        assert_eq!(rects[2], Rect::new(7, 1, 2, 4));
+
+        let rects = squarify(&[1.0; 4], Rect::new(0, 0, 8, 4));
+        let total = rects.iter().map(|r| r.area()).sum::<u16>();
+        assert_eq!(total, 32);
+        assert!(rects.iter().all(|r| r.width == 4 && r.height == 2));
+    }
+
+    #[test]
+    fn elide_labels() {
+        assert_eq!(elide("hello", 5), "hello");
+        assert_eq!(elide("hello", 4), "hel…");
+        assert_eq!(elide("hello", 1), "…");
+        assert_eq!(elide("hello", 0), "");
+    }
+
+    #[test]
+    fn render() {
+        let root = files();
+        let tree_map = TreeMap::new(&root)
+            .style(Style::new())
+            .color(|_| Color::Reset);
+        let mut state = TreeMapState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+        StatefulWidget::render(tree_map, buf.area, &mut buf, &mut state);
+        assert_buffer_eq!(
+            buf,
+            Buffer::with_lines(vec!["a   src ", "    b   ", "    c   "])
+        );
+        assert_eq!(state.node_at(5, 0), Some(&[1][..]));
+        assert_eq!(state.node_at(5, 2), Some(&[1, 1][..]));
+    }
+
+    #[test]
+    fn keyboard_selection() {
+        let root = files();
+        let mut state = TreeMapState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+        StatefulWidget::render(TreeMap::new(&root), buf.area, &mut buf, &mut state);
+        assert!(state.handle_key(Key::Right.into()));
+        assert_eq!(state.selected(), Some(&[0][..]));
+        assert!(state.handle_key(Key::Right.into()));
+        assert_eq!(state.selected(), Some(&[1, 0][..]));
+        assert!(state.handle_key(Key::Down.into()));
+        assert_eq!(state.selected(), Some(&[1, 1][..]));
+        assert!(!state.handle_key(Key::Right.into()));
+        assert!(state.handle_key(Key::Left.into()));
+        assert_eq!(state.selected(), Some(&[0][..]));
+        state.select(Some(vec![1, 1]));
+        assert!(state.handle_key(Key::Backspace.into()));
+        assert_eq!(state.selected(), Some(&[1][..]));
+    }
+
+    #[test]
+    fn mouse_selection() {
+        let root = files();
+        let mut state = TreeMapState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+        StatefulWidget::render(TreeMap::new(&root), buf.area, &mut buf, &mut state);
+        let click =
+            |column, row| MouseEvent::new(MouseEventKind::Down(MouseButton::Left), column, row);
+        assert!(state.handle_mouse(click(6, 1)));
+        assert_eq!(state.selected(), Some(&[1, 0][..]));
+        assert!(!state.handle_mouse(click(7, 1)));
+        assert!(state.handle_mouse(click(6, 0)));
+        assert_eq!(state.selected(), Some(&[1][..]));
+    }
+
+    #[test]
+    fn render_highlights_selection() {
+        let root = files();
+        let tree_map = TreeMap::new(&root)
+            .style(Style::new())
+            .color(|_| Color::Reset);
+        let mut state = TreeMapState::default();
+        state.select(Some(vec![1, 0]));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+        StatefulWidget::render(tree_map, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(vec!["a   src ", "    b   ", "    c   "]);
+        expected.set_style(Rect::new(0, 0, 8, 3), Style::new().bg(Color::Reset));
+        expected.set_style(
+            Rect::new(4, 1, 4, 1),
+            Style::new().add_modifier(Modifier::REVERSED),
+        );
+        assert_buffer_eq!(buf, expected);
+    }
+}
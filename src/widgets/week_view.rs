@@ -0,0 +1,389 @@
+#![deny(missing_docs)]
+use std::{ops::Range, time::Duration};
+
+use crate::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style, Styled},
+    text::Line,
+    widgets::{Block, Widget},
+};
+
+/// The width of the column showing the time of each row, e.g. `08:00 `.
+const TIME_WIDTH: u16 = 6;
+
+const HOUR: Duration = Duration::from_secs(3600);
+
+/// An event shown in a [`WeekView`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct WeekEvent<'a> {
+    day: usize,
+    start: Duration,
+    duration: Duration,
+    label: Line<'a>,
+    style: Style,
+}
+
+impl<'a> WeekEvent<'a> {
+    /// Creates a new event on the given day of the week (0 for the first column), starting at the
+    /// given time since midnight and lasting the given duration.
+    pub fn new<T>(day: usize, start: Duration, duration: Duration, label: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        Self {
+            day,
+            start,
+            duration,
+            label: label.into(),
+            style: Style::new().fg(Color::Black).bg(Color::Cyan),
+        }
+    }
+
+    /// Sets the style of the event. Defaults to black text on a cyan background.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn end(&self) -> Duration {
+        self.start + self.duration
+    }
+}
+
+/// A widget displaying the events of a week in a grid, with a column per day and a row per time
+/// slot, like the week view of calendar applications.
+///
+/// Each [`WeekEvent`] is drawn as a block spanning the rows of its time slots. Overlapping
+/// events of the same day are placed side by side in lanes sharing the width of the day column.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// let hour = |h: u64| Duration::from_secs(h * 3600);
+/// let week = WeekView::new(vec![
+///     WeekEvent::new(0, hour(9), hour(1), "Standup"),
+///     WeekEvent::new(2, hour(13), hour(2), "Review").style(Style::new().on_magenta()),
+/// ])
+/// .hours(8..18)
+/// .rows_per_hour(2);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct WeekView<'a> {
+    events: Vec<WeekEvent<'a>>,
+    day_labels: [Line<'a>; 7],
+    hours: Range<u8>,
+    rows_per_hour: u16,
+    block: Option<Block<'a>>,
+    style: Style,
+    header_style: Style,
+    time_style: Style,
+}
+
+impl<'a> WeekView<'a> {
+    /// Creates a new week view showing the given events.
+    pub fn new(events: Vec<WeekEvent<'a>>) -> Self {
+        Self {
+            events,
+            day_labels: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"].map(Line::from),
+            hours: 8..18,
+            rows_per_hour: 1,
+            block: None,
+            style: Style::default(),
+            header_style: Style::new().add_modifier(Modifier::BOLD),
+            time_style: Style::new().add_modifier(Modifier::DIM),
+        }
+    }
+
+    /// Sets the labels shown above the day columns. Defaults to `Mon` to `Sun`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn day_labels<T>(mut self, labels: [T; 7]) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        self.day_labels = labels.map(Into::into);
+        self
+    }
+
+    /// Sets the range of hours shown by the view. Defaults to `8..18`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hours(mut self, hours: Range<u8>) -> Self {
+        self.hours = hours.start.min(24)..hours.end.min(24);
+        self
+    }
+
+    /// Sets the number of rows used for each hour, e.g. 2 for half hour slots. Defaults to 1.
+    ///
+    /// The number of rows is clamped between 1 and 60, a row per minute.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn rows_per_hour(mut self, rows: u16) -> Self {
+        self.rows_per_hour = rows.clamp(1, 60);
+        self
+    }
+
+    /// Surrounds the view with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the whole widget.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the day labels. Defaults to bold text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn header_style(mut self, style: Style) -> Self {
+        self.header_style = style;
+        self
+    }
+
+    /// Sets the style of the times shown on the left. Defaults to dimmed text.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn time_style(mut self, style: Style) -> Self {
+        self.time_style = style;
+        self
+    }
+
+    /// Returns the time at which the given row starts, from the first hour shown.
+    fn row_start(&self, row: u16) -> Duration {
+        HOUR * u32::from(row) / u32::from(self.rows_per_hour)
+    }
+
+    /// Returns the row containing the given time from the first hour shown, or the next row if
+    /// `round_up` is set and the time is inside the row.
+    fn row_of(&self, time: Duration, round_up: bool) -> u64 {
+        let scaled = time.as_nanos() * u128::from(self.rows_per_hour);
+        let hour = HOUR.as_nanos();
+        let row = scaled / hour;
+        let row = if round_up && scaled % hour > 0 {
+            row + 1
+        } else {
+            row
+        };
+        u64::try_from(row).unwrap_or(u64::MAX)
+    }
+}
+
+impl<'a> Styled for WeekView<'a> {
+    type Item = WeekView<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// Assigns a lane to each of the given time ranges, so that overlapping ranges are in different
+/// lanes. The ranges must be sorted by start.
+///
+/// Returns the lane of each range and the number of lanes of the group of overlapping ranges it
+/// belongs to.
+fn assign_lanes(ranges: &[(Duration, Duration)]) -> Vec<(usize, usize)> {
+    let mut lanes = vec![(0, 0); ranges.len()];
+    let mut group_start = 0;
+    // end of the last range of each lane of the current group
+    let mut lane_ends: Vec<Duration> = vec![];
+    for (i, &(start, end)) in ranges.iter().enumerate() {
+        if lane_ends.iter().all(|&lane_end| lane_end <= start) {
+            // no overlap with the current group: close it
+            for lane in &mut lanes[group_start..i] {
+                lane.1 = lane_ends.len();
+            }
+            group_start = i;
+            lane_ends.clear();
+        }
+        let lane = match lane_ends.iter().position(|&lane_end| lane_end <= start) {
+            Some(lane) => lane,
+            None => {
+                lane_ends.push(Duration::ZERO);
+                lane_ends.len() - 1
+            }
+        };
+        lane_ends[lane] = end;
+        lanes[i].0 = lane;
+    }
+    for lane in &mut lanes[group_start..] {
+        lane.1 = lane_ends.len();
+    }
+    lanes
+}
+
+impl<'a> Widget for WeekView<'a> {
+    fn render(mut self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        if area.width <= TIME_WIDTH || area.height < 2 {
+            return;
+        }
+
+        let day_width = (area.width - TIME_WIDTH) / 7;
+        let day_x = |day: usize| area.x + TIME_WIDTH + day as u16 * day_width;
+        for (day, label) in self.day_labels.iter().enumerate() {
+            let header = Rect::new(day_x(day), area.y, day_width, 1);
+            buf.set_style(header, self.header_style);
+            buf.set_line(header.x, header.y, label, day_width);
+        }
+
+        let grid_top = area.y + 1;
+        let rows = area.bottom() - grid_top;
+        let first = HOUR * u32::from(self.hours.start);
+        // at most 24 hours of 60 rows
+        let slots = u16::from(self.hours.end.saturating_sub(self.hours.start)) * self.rows_per_hour;
+        for row in 0..rows.min(slots) {
+            let time = first + self.row_start(row);
+            let minutes = time.as_secs() / 60;
+            let label = format!("{:02}:{:02}", minutes / 60, minutes % 60);
+            buf.set_string(area.x, grid_top + row, label, self.time_style);
+        }
+
+        let row_of = |time: Duration, round_up: bool| -> u16 {
+            let row = self.row_of(time.saturating_sub(first), round_up);
+            row.min(u64::from(slots.min(rows))) as u16
+        };
+        let mut events = self.events.iter().filter(|e| e.day < 7).collect::<Vec<_>>();
+        events.sort_by_key(|event| (event.day, event.start));
+        for day in 0..7 {
+            let day_events = events
+                .iter()
+                .filter(|event| event.day == day)
+                .collect::<Vec<_>>();
+            let ranges = day_events
+                .iter()
+                .map(|event| (event.start, event.end()))
+                .collect::<Vec<_>>();
+            for (event, (lane, lanes)) in day_events.iter().zip(assign_lanes(&ranges)) {
+                let top = row_of(event.start, false);
+                // events shorter than a slot still take a row
+                let bottom = row_of(event.end(), true).max(top + 1).min(slots.min(rows));
+                if top >= bottom {
+                    continue;
+                }
+                let lane_width = day_width / lanes as u16;
+                if lane_width == 0 {
+                    continue;
+                }
+                let x = day_x(day) + lane as u16 * lane_width;
+                // leave a column between days, unless the lane is too narrow
+                let width = if lane == lanes - 1 && lane_width > 1 {
+                    lane_width - 1
+                } else {
+                    lane_width
+                };
+                let rect = Rect::new(x, grid_top + top, width, bottom - top);
+                buf.set_style(rect, event.style);
+                buf.set_line(rect.x, rect.y, &event.label, rect.width);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    fn hours(h: f64) -> Duration {
+        Duration::from_secs_f64(h * 3600.0)
+    }
+
+    #[test]
+    fn lanes() {
+        let ranges = [
+            (hours(9.0), hours(11.0)),
+            (hours(10.0), hours(12.0)),
+            (hours(11.0), hours(13.0)),
+            (hours(14.0), hours(15.0)),
+        ];
+        assert_eq!(assign_lanes(&ranges), [(0, 2), (1, 2), (0, 2), (0, 1)]);
+    }
+
+    #[test]
+    fn render() {
+        let view = WeekView::new(vec![
+            WeekEvent::new(0, hours(9.0), hours(2.0), "Meet").style(Style::new()),
+            WeekEvent::new(1, hours(8.5), hours(0.25), "Call").style(Style::new()),
+        ])
+        .day_labels(["M", "T", "W", "T", "F", "S", "S"])
+        .hours(8..11)
+        .header_style(Style::new())
+        .time_style(Style::new());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 27, 4));
+        view.render(buf.area, &mut buf);
+        assert_buffer_eq!(
+            buf,
+            Buffer::with_lines(vec![
+                "      M  T  W  T  F  S  S  ",
+                "08:00    Ca                ",
+                "09:00 Me                   ",
+                "10:00                      ",
+            ])
+        );
+    }
+
+    #[test]
+    fn rows_per_hour() {
+        let view = WeekView::new(vec![]).rows_per_hour(4000);
+        assert_eq!(view.rows_per_hour, 60);
+        assert_eq!(WeekView::new(vec![]).rows_per_hour(0).rows_per_hour, 1);
+        // a row is 3600/7 seconds, which isn't a whole number
+        let view = WeekView::new(vec![]).rows_per_hour(7);
+        assert_eq!(view.row_start(7), HOUR);
+        assert_eq!(view.row_of(HOUR, false), 7);
+        assert_eq!(view.row_of(hours(0.5), false), 3);
+        assert_eq!(view.row_of(hours(0.5), true), 4);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 4));
+        WeekView::new(vec![WeekEvent::new(0, hours(8.0), hours(1.0), "A")])
+            .rows_per_hour(4000)
+            .render(buf.area, &mut buf);
+        let time: String = (0..5).map(|x| buf.get(x, 3).symbol()).collect();
+        assert_eq!(time, "08:02");
+    }
+
+    #[test]
+    fn render_overlapping_events() {
+        let red = Style::new().bg(Color::Red);
+        let blue = Style::new().bg(Color::Blue);
+        let view = WeekView::new(vec![
+            WeekEvent::new(0, hours(8.0), hours(2.0), "A").style(red),
+            WeekEvent::new(0, hours(9.0), hours(1.0), "B").style(blue),
+        ])
+        .day_labels([""; 7])
+        .hours(8..10)
+        .time_style(Style::new());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6 + 7 * 4, 3));
+        view.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(vec![
+            "                                  ",
+            "08:00 A                           ",
+            "09:00   B                         ",
+        ]);
+        expected.set_style(
+            Rect::new(6, 0, 28, 1),
+            Style::new().add_modifier(Modifier::BOLD),
+        );
+        expected.set_style(Rect::new(6, 1, 2, 2), red);
+        expected.set_style(Rect::new(8, 2, 1, 1), blue);
+        assert_buffer_eq!(buf, expected);
+    }
+}
@@ -0,0 +1,86 @@
+//! Word boundaries used by the word commands of the text editing widgets.
+use std::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Returns `true` if the grapheme is part of a word, i.e. it is alphanumeric or `_`.
+fn is_word(grapheme: &str) -> bool {
+    grapheme
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Returns the byte offset of the start of the word before `cursor`, skipping the non word
+/// graphemes right before it.
+pub(crate) fn previous_word_start(s: &str, cursor: usize) -> usize {
+    let mut graphemes = s[..cursor].grapheme_indices(true).rev().peekable();
+    while graphemes.next_if(|(_, g)| !is_word(g)).is_some() {}
+    let mut start = graphemes.peek().map_or(0, |(i, g)| i + g.len());
+    while let Some((i, _)) = graphemes.next_if(|(_, g)| is_word(g)) {
+        start = i;
+    }
+    start
+}
+
+/// Returns the byte offset of the end of the word after `cursor`, skipping the non word
+/// graphemes right after it.
+pub(crate) fn next_word_end(s: &str, cursor: usize) -> usize {
+    let mut graphemes = s[cursor..].grapheme_indices(true).peekable();
+    while graphemes.next_if(|(_, g)| !is_word(g)).is_some() {}
+    while graphemes.next_if(|(_, g)| is_word(g)).is_some() {}
+    graphemes.peek().map_or(s.len(), |(i, _)| cursor + i)
+}
+
+/// Returns the byte range of the word containing `cursor` or ending at it, which is empty if
+/// there is no word around the cursor.
+pub(crate) fn word_at(s: &str, cursor: usize) -> Range<usize> {
+    let start = s[..cursor]
+        .grapheme_indices(true)
+        .rev()
+        .take_while(|(_, g)| is_word(g))
+        .last()
+        .map_or(cursor, |(i, _)| i);
+    let end = s[cursor..]
+        .grapheme_indices(true)
+        .take_while(|(_, g)| is_word(g))
+        .last()
+        .map_or(cursor, |(i, g)| cursor + i + g.len());
+    start..end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn previous_word() {
+        let s = "foo bar, baz";
+        assert_eq!(previous_word_start(s, 12), 9);
+        assert_eq!(previous_word_start(s, 9), 4);
+        assert_eq!(previous_word_start(s, 6), 4);
+        assert_eq!(previous_word_start(s, 4), 0);
+        assert_eq!(previous_word_start(s, 0), 0);
+        assert_eq!(previous_word_start("  ", 2), 0);
+    }
+
+    #[test]
+    fn next_word() {
+        let s = "foo bar, baz";
+        assert_eq!(next_word_end(s, 0), 3);
+        assert_eq!(next_word_end(s, 3), 7);
+        assert_eq!(next_word_end(s, 7), 12);
+        assert_eq!(next_word_end(s, 12), 12);
+        assert_eq!(next_word_end("é_1 x", 0), 4);
+    }
+
+    #[test]
+    fn word_around() {
+        let s = "foo bar, baz";
+        assert_eq!(word_at(s, 0), 0..3);
+        assert_eq!(word_at(s, 5), 4..7);
+        assert_eq!(word_at(s, 7), 4..7);
+        assert_eq!(word_at(s, 8), 8..8);
+        assert_eq!(word_at(s, 12), 9..12);
+    }
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "derive")]
+use ratatui::{
+    buffer::Buffer,
+    input::{Key, MaxLength},
+    layout::Rect,
+    widgets::{Form, FormData, StatefulWidget},
+};
+
+#[derive(Debug, Default, PartialEq, Form)]
+struct Settings {
+    #[form(label = "Name", placeholder = "anonymous", validator = MaxLength(8))]
+    user_name: String,
+    #[form(mask = '*')]
+    password: String,
+    port: u16,
+    timeout: Option<f64>,
+    #[form(read_only)]
+    r#type: String,
+    #[form(skip)]
+    version: u32,
+}
+
+fn settings() -> Settings {
+    Settings {
+        user_name: "admin".into(),
+        password: "hunter2".into(),
+        port: 8080,
+        timeout: None,
+        r#type: "local".into(),
+        version: 3,
+    }
+}
+
+#[test]
+fn form_derive_renders_the_fields() {
+    let mut state = settings().form_state();
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 5));
+    Settings::form().render(buf.area, &mut buf, &mut state);
+    let lines: Vec<String> = (0..5)
+        .map(|y| (0..20).map(|x| buf.get(x, y).symbol()).collect())
+        .collect();
+    assert_eq!(
+        lines,
+        [
+            "Name     admin      ",
+            "Password *******    ",
+            "Port     8080       ",
+            "Timeout             ",
+            "Type     local      ",
+        ]
+    );
+    assert!(state.field("type").unwrap().is_read_only());
+    assert!(state.field("version").is_none());
+}
+
+#[test]
+fn form_derive_validates_the_values() {
+    let mut state = settings().form_state();
+    state.set_value("port", "http");
+    state.set_value("user_name", "administrator");
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 5));
+    Settings::form().render(buf.area, &mut buf, &mut state);
+    assert_eq!(
+        state.field("port").unwrap().error(),
+        Some("invalid digit found in string")
+    );
+    assert_eq!(
+        state.field("user_name").unwrap().error(),
+        Some("must be at most 8 characters long")
+    );
+    assert_eq!(state.submit(), None);
+    assert_eq!(state.focused_name(), Some("user_name"));
+}
+
+#[test]
+fn form_derive_updates_the_struct() {
+    let mut settings = settings();
+    let mut state = settings.form_state();
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 5));
+    state.set_value("port", "443");
+    state.set_value("timeout", "1.5");
+    Settings::form().render(buf.area, &mut buf, &mut state);
+    state.focus(4);
+    let values = state.handle_key(Key::Enter.into()).unwrap();
+    settings.update(&values).unwrap();
+    assert_eq!(settings.port, 443);
+    assert_eq!(settings.timeout, Some(1.5));
+    assert_eq!(settings.version, 3);
+
+    // nothing is written when a value doesn't parse
+    let mut values = values;
+    values.insert("password".into(), "secret".into());
+    values.insert("port".into(), "-1".into());
+    let error = settings.update(&values).unwrap_err();
+    assert_eq!(error.name(), "port");
+    assert_eq!(settings.password, "hunter2");
+}
@@ -1,11 +1,14 @@
-use std::error::Error;
+use std::{collections::BTreeSet, error::Error, io, ops::Range};
 
 use ratatui::{
     assert_buffer_eq,
-    backend::{Backend, TestBackend},
+    backend::{Backend, Capabilities, TestBackend, WindowSize},
+    buffer::Cell,
     layout::Rect,
     prelude::Buffer,
-    widgets::{Paragraph, Widget},
+    style::{Modifier, Style},
+    text::Span,
+    widgets::{Block, BorderType, Borders, Gauge, Paragraph, Widget},
     Terminal, TerminalOptions, Viewport,
 };
 
@@ -50,6 +53,161 @@ fn terminal_draw_returns_the_completed_frame() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn terminal_draw_falls_back_on_limited_terminals() -> Result<(), Box<dyn Error>> {
+    let mut backend = TestBackend::new(10, 3);
+    backend.set_capabilities(Capabilities::for_terminal("linux", None));
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+        let label = Span::styled("x", Style::new().add_modifier(Modifier::ITALIC));
+        let gauge = Gauge::default()
+            .block(block)
+            .use_unicode(true)
+            .ratio(0.55)
+            .label(label);
+        f.render_widget(gauge, f.size());
+    })?;
+    // no rounded corners, eighths of blocks or italic text on the Linux console
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "┌────────┐",
+        "│███x    │",
+        "└────────┘",
+    ]));
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_falls_back_to_ascii() -> Result<(), Box<dyn Error>> {
+    let mut backend = TestBackend::new(10, 3);
+    backend.set_capabilities(Capabilities::for_terminal("dumb", None));
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| {
+        let paragraph = Paragraph::new("▶ ok ⣿⡀\n日本 ✔").block(Block::new().borders(Borders::TOP));
+        f.render_widget(paragraph, f.size());
+    })?;
+    // the borders are ASCII already, the other symbols are replaced when flushing
+    terminal.backend().assert_buffer(&Buffer::with_lines(vec![
+        "----------",
+        "> ok :.   ",
+        "? ?  x    ",
+    ]));
+    Ok(())
+}
+
+/// A [`TestBackend`] recording the scrolled regions and the rows drawn since the last
+/// [`take_drawn_rows`](ScrollRecorder::take_drawn_rows).
+struct ScrollRecorder {
+    inner: TestBackend,
+    /// The scrolled regions and line counts, negative when scrolled down
+    scrolls: Vec<(Range<u16>, i32)>,
+    drawn_rows: BTreeSet<u16>,
+}
+
+impl ScrollRecorder {
+    fn new(width: u16, height: u16) -> Self {
+        Self {
+            inner: TestBackend::new(width, height),
+            scrolls: Vec::new(),
+            drawn_rows: BTreeSet::new(),
+        }
+    }
+
+    fn take_drawn_rows(&mut self) -> Vec<u16> {
+        std::mem::take(&mut self.drawn_rows).into_iter().collect()
+    }
+}
+
+impl Backend for ScrollRecorder {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        let content = content.collect::<Vec<_>>();
+        self.drawn_rows.extend(content.iter().map(|(_, y, _)| *y));
+        self.inner.draw(content.into_iter())
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.inner.hide_cursor()
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.inner.show_cursor()
+    }
+
+    fn get_cursor(&mut self) -> io::Result<(u16, u16)> {
+        self.inner.get_cursor()
+    }
+
+    fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.inner.set_cursor(x, y)
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.inner.clear()
+    }
+
+    fn scroll_region_up(&mut self, region: Range<u16>, line_count: u16) -> io::Result<()> {
+        self.scrolls.push((region.clone(), i32::from(line_count)));
+        self.inner.scroll_region_up(region, line_count)
+    }
+
+    fn scroll_region_down(&mut self, region: Range<u16>, line_count: u16) -> io::Result<()> {
+        self.scrolls.push((region.clone(), -i32::from(line_count)));
+        self.inner.scroll_region_down(region, line_count)
+    }
+
+    fn size(&self) -> io::Result<Rect> {
+        self.inner.size()
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        self.inner.window_size()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.inner.capabilities()
+    }
+}
+
+#[test]
+fn terminal_draw_scrolls_shifted_lines() -> Result<(), Box<dyn Error>> {
+    let mut terminal = Terminal::new(ScrollRecorder::new(10, 5))?;
+    let lines = (0..8).map(|i| format!("line {i}")).collect::<Vec<_>>();
+    for start in 0..4 {
+        terminal.draw(|f| {
+            let paragraph = Paragraph::new(lines[start..start + 4].join("\n"));
+            f.render_widget(paragraph, Rect::new(0, 1, 10, 4));
+            f.render_widget(Paragraph::new("header"), Rect::new(0, 0, 10, 1));
+        })?;
+        let drawn_rows = terminal.backend_mut().take_drawn_rows();
+        if start > 0 {
+            // the lines moved up by one row are scrolled, only the new last line is drawn
+            assert_eq!(terminal.backend().scrolls.last(), Some(&(1..5, 1)));
+            assert_eq!(drawn_rows, [4]);
+        }
+    }
+    assert_eq!(terminal.backend().scrolls.len(), 3);
+    terminal
+        .backend()
+        .inner
+        .assert_buffer(&Buffer::with_lines(vec![
+            "header    ",
+            "line 3    ",
+            "line 4    ",
+            "line 5    ",
+            "line 6    ",
+        ]));
+    Ok(())
+}
+
 #[test]
 fn terminal_insert_before_moves_viewport() -> Result<(), Box<dyn Error>> {
     // When we have a terminal with 5 lines, and a single line viewport, if we insert a